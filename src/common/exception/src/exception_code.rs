@@ -135,6 +135,7 @@ build_exceptions! {
     ReadTableDataError(1107),
     AddColumnExistError(1108),
     DropColumnEmptyError(1109),
+    AlterTableError(1110),
 
     // Data Related Errors
 
@@ -156,6 +157,22 @@ build_exceptions! {
     ///
     /// For example: try to with 3 columns into a table with 4 columns.
     TableSchemaMismatch(1303),
+    /// UnknownAggregatingIndex is used when the aggregating index of a table does not exist.
+    UnknownAggregatingIndex(1304),
+    /// AggregatingIndexAlreadyExists is used when creating an aggregating index with the
+    /// name of an existing one of the table.
+    AggregatingIndexAlreadyExists(1305),
+
+    // Transaction related errors starts here.
+
+    /// InvalidTransaction is used when a transaction statement is not allowed in
+    /// the current transaction state.
+    ///
+    /// For example: `BEGIN` in a transaction.
+    InvalidTransaction(1401),
+    /// TransactionConflict is used when the tables mutated in a transaction have
+    /// been changed by others before the transaction commits.
+    TransactionConflict(1402),
 }
 
 // Meta service errors [2001, 3000].
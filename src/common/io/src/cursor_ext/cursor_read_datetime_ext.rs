@@ -19,7 +19,9 @@ use chrono::DateTime;
 use chrono::Datelike;
 use chrono::Duration;
 use chrono::FixedOffset;
+use chrono::LocalResult;
 use chrono::NaiveDate;
+use chrono::NaiveDateTime;
 use chrono::Offset;
 use chrono::TimeZone;
 use chrono_tz::Tz;
@@ -56,6 +58,26 @@ fn parse_time_part(buf: &[u8], size: usize) -> Result<u32> {
     }
 }
 
+/// Resolve a local date time in `tz` without panicking on DST transitions.
+///
+/// An ambiguous local time (clocks turned back) resolves to the earlier instant,
+/// a local time that falls into a gap (clocks turned forward) is interpreted with
+/// the offset in effect before the gap, e.g. `02:30` becomes `03:30` on the day
+/// `America/New_York` switches to summer time.
+pub fn unwrap_local_time(tz: &Tz, naive: &NaiveDateTime) -> DateTime<Tz> {
+    match tz.from_local_datetime(naive) {
+        LocalResult::Single(dt) => dt,
+        LocalResult::Ambiguous(earliest, _) => earliest,
+        LocalResult::None => {
+            let before = tz
+                .offset_from_utc_datetime(&(*naive - Duration::days(1)))
+                .fix()
+                .local_minus_utc();
+            tz.from_utc_datetime(&(*naive - Duration::seconds(before as i64)))
+        }
+    }
+}
+
 // fn calc_offset(current_tz_sec: i64, val_tz_sec: i64, dt: &DateTime<Tz>, tz: &Tz) -> () {
 //     let offset = (current_tz_sec - val_tz_sec) * 1000 * 1000;
 //     let mut ts = dt.timestamp_micros();
@@ -97,7 +119,7 @@ where T: AsRef<[u8]>
             .map_err_to_code(ErrorCode::BadBytes, || {
                 format!("Cannot parse value:{} to Date type", v)
             })?;
-        let mut dt = tz.from_local_datetime(&d.and_hms(0, 0, 0)).unwrap();
+        let dt = unwrap_local_time(tz, &d.and_hms(0, 0, 0));
 
         let less_1000 = |dt: DateTime<Tz>| {
             // convert timestamp less than `1000-01-01 00:00:00` to `1000-01-01 00:00:00`
@@ -132,18 +154,14 @@ where T: AsRef<[u8]>
             // Examples: '2022-02-02T', '2022-02-02 ', '2022-02-02T02', '2022-02-02T3:', '2022-02-03T03:13', '2022-02-03T03:13:'
             if times.len() < 3 {
                 times.resize(3, 0);
-                dt = tz
-                    .from_local_datetime(&d.and_hms(times[0], times[1], times[2]))
-                    .unwrap();
+                let dt = unwrap_local_time(tz, &d.and_hms(times[0], times[1], times[2]));
                 return less_1000(dt);
             }
 
-            dt = tz
-                .from_local_datetime(&d.and_hms(times[0], times[1], times[2]))
-                .unwrap();
+            let naive = d.and_hms(times[0], times[1], times[2]);
 
             // ms .microseconds
-            let dt = if self.ignore_byte(b'.') {
+            let naive = if self.ignore_byte(b'.') {
                 buf.clear();
                 let size = self.keep_read(&mut buf, |f| f.is_ascii_digit());
                 if size == 0 {
@@ -154,20 +172,18 @@ where T: AsRef<[u8]>
                 let scales: i64 = lexical_core::FromLexical::from_lexical(buf.as_slice()).unwrap();
 
                 if size >= 9 {
-                    dt.checked_add_signed(Duration::nanoseconds(scales))
-                        .unwrap()
+                    naive + Duration::nanoseconds(scales)
                 } else if size >= 6 {
-                    dt.checked_add_signed(Duration::microseconds(scales))
-                        .unwrap()
+                    naive + Duration::microseconds(scales)
                 } else if size >= 3 {
-                    dt.checked_add_signed(Duration::milliseconds(scales))
-                        .unwrap()
+                    naive + Duration::milliseconds(scales)
                 } else {
-                    dt
+                    naive
                 }
             } else {
-                dt
+                naive
             };
+            let dt = unwrap_local_time(tz, &naive);
 
             // Timezone 2022-02-02T03:00:03.123[z/Z[+/-08:00]]
             buf.clear();
@@ -196,6 +212,17 @@ where T: AsRef<[u8]>
                 self.parse_time_offset(tz, &mut buf, &dt, false, calc_offset)
             } else if self.ignore_byte(b'-') {
                 self.parse_time_offset(tz, &mut buf, &dt, true, calc_offset)
+            } else if self.ignore_byte(b' ')
+                && self.keep_read(&mut buf, |b| {
+                    b.is_ascii_alphanumeric() || matches!(b, b'/' | b'_' | b'+' | b'-')
+                }) > 0
+            {
+                // Named timezone 2022-02-02 03:00:03 America/New_York
+                let name = std::str::from_utf8(&buf).unwrap();
+                let val_tz = name.parse::<Tz>().map_err(|_| {
+                    ErrorCode::BadBytes(format!("err with parse timezone name: {:?}", name))
+                })?;
+                less_1000(unwrap_local_time(&val_tz, &naive).with_timezone(tz))
             } else {
                 // only datetime part
                 less_1000(dt)
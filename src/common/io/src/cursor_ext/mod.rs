@@ -20,6 +20,7 @@ mod cursor_read_string_ext;
 
 pub use cursor_checkpoint_ext::ReadCheckPointExt;
 pub use cursor_read_bytes_ext::ReadBytesExt;
+pub use cursor_read_datetime_ext::unwrap_local_time;
 pub use cursor_read_datetime_ext::BufferReadDateTimeExt;
 pub use cursor_read_number_ext::collect_number;
 pub use cursor_read_number_ext::ReadNumberExt;
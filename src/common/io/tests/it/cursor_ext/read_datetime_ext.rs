@@ -58,6 +58,32 @@ fn test_read_timestamp_text() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_read_timestamp_text_with_timezone() -> Result<()> {
+    let mut reader = Cursor::new(
+        "2022-02-02 12:00:00 Asia/Shanghai,2022-07-01 12:00:00.500 America/New_York,2023-03-12 02:30:00,2023-11-05 01:30:00,2023-03-12 02:30:00 UTC".as_bytes(),
+    );
+    let tz: Tz = "America/New_York".parse().unwrap();
+    let expected = vec![
+        "2022-02-01T23:00:00EST",
+        "2022-07-01T12:00:00.500EDT",
+        "2023-03-12T03:30:00EDT",
+        "2023-11-05T01:30:00EDT",
+        "2023-03-11T21:30:00EST",
+    ];
+    let mut res = vec![];
+    for _ in 0..expected.len() {
+        let time = reader.read_timestamp_text(&tz)?;
+        res.push(format!("{:?}", time));
+        reader.ignore_byte(b',');
+    }
+    assert_eq!(res, expected);
+
+    let mut reader = Cursor::new("2022-02-02 12:00:00 Mars/Olympus".as_bytes());
+    assert!(reader.read_timestamp_text(&tz).is_err());
+    Ok(())
+}
+
 #[test]
 fn test_read_date_text() -> Result<()> {
     let mut reader = Cursor::new("2009-01-01,1000-01-01,2023-03-03,2022-02-02,2009-01-01 3:2:1.123,2009-01-01 0:00:00,2009-01-01 00:00:00.123,2009-01-01 00:00:00.123456,0002-03-03T00:01:02,2022-03-04T00:01:02+08:00,2022-03-04T00:01:02-08:00,0000-00-00,0000-00-00 00:00:00,0001-01-01 00:00:00,2020-01-01T11:11:11Z,2009-01-03 00:00:00,2020-01-01T11:11:11.123Z,2055-02-03 10:00:20.234+08:00,2055-02-03 10:00:20.234-08:00,1022-05-16T03:25:02.000000+08:00,2055-01-01".as_bytes());
@@ -351,6 +351,84 @@ pub fn object_keys(value: &[u8]) -> Option<Vec<u8>> {
     }
 }
 
+/// Get the items of a `JSONB` array.
+pub fn array_values(value: &[u8]) -> Option<Vec<Vec<u8>>> {
+    if !is_jsonb(value) {
+        let json_value = decode_value(value).unwrap();
+        return match json_value {
+            Value::Array(vals) => Some(vals.iter().map(Value::to_vec).collect()),
+            _ => None,
+        };
+    }
+
+    let header = read_u32(value, 0).unwrap();
+    match header & CONTAINER_HEADER_TYPE_MASK {
+        ARRAY_CONTAINER_TAG => {
+            let length = (header & CONTAINER_HEADER_LEN_MASK) as usize;
+            let mut jentry_offset = 4;
+            let mut val_offset = 4 * length + 4;
+            let mut items = Vec::with_capacity(length);
+            for _ in 0..length {
+                let encoded = read_u32(value, jentry_offset).unwrap();
+                let jentry = JEntry::decode_jentry(encoded);
+                let val_length = jentry.length as usize;
+                items.push(extract_by_jentry(&jentry, encoded, val_offset, value));
+                jentry_offset += 4;
+                val_offset += val_length;
+            }
+            Some(items)
+        }
+        _ => None,
+    }
+}
+
+/// Get the keys and values of a `JSONB` object.
+pub fn object_each(value: &[u8]) -> Option<Vec<(Vec<u8>, Vec<u8>)>> {
+    if !is_jsonb(value) {
+        let json_value = decode_value(value).unwrap();
+        return match json_value {
+            Value::Object(obj) => Some(
+                obj.iter()
+                    .map(|(k, v)| (k.as_bytes().to_vec(), v.to_vec()))
+                    .collect(),
+            ),
+            _ => None,
+        };
+    }
+
+    let header = read_u32(value, 0).unwrap();
+    match header & CONTAINER_HEADER_TYPE_MASK {
+        OBJECT_CONTAINER_TAG => {
+            let length = (header & CONTAINER_HEADER_LEN_MASK) as usize;
+            let mut jentry_offset = 4;
+            let mut key_offset = 8 * length + 4;
+            let mut keys = Vec::with_capacity(length);
+            for _ in 0..length {
+                let encoded = read_u32(value, jentry_offset).unwrap();
+                let key_jentry = JEntry::decode_jentry(encoded);
+                let key_length = key_jentry.length as usize;
+                keys.push(value[key_offset..key_offset + key_length].to_vec());
+                jentry_offset += 4;
+                key_offset += key_length;
+            }
+
+            let mut val_offset = key_offset;
+            let mut items = Vec::with_capacity(length);
+            for key in keys {
+                let encoded = read_u32(value, jentry_offset).unwrap();
+                let jentry = JEntry::decode_jentry(encoded);
+                let val_length = jentry.length as usize;
+                let val = extract_by_jentry(&jentry, encoded, val_offset, value);
+                items.push((key, val));
+                jentry_offset += 4;
+                val_offset += val_length;
+            }
+            Some(items)
+        }
+        _ => None,
+    }
+}
+
 /// `JSONB` values supports partial decode for comparison,
 /// if the values are found to be unequal, the result will be returned immediately.
 /// In first level header, values compare as the following order:
@@ -1097,3 +1175,21 @@ fn read_u32(buf: &[u8], idx: usize) -> Result<u32, Error> {
         .unwrap();
     Ok(u32::from_be_bytes(bytes))
 }
+
+/// Extract the value of a `JEntry` as an independent `JSONB` value,
+/// the scalar value is wrapped in a scalar container.
+fn extract_by_jentry(jentry: &JEntry, encoded: u32, offset: usize, value: &[u8]) -> Vec<u8> {
+    let length = jentry.length as usize;
+    match jentry.type_code {
+        CONTAINER_TAG => value[offset..offset + length].to_vec(),
+        _ => {
+            let mut buf = Vec::with_capacity(8 + length);
+            buf.extend_from_slice(&SCALAR_CONTAINER_TAG.to_be_bytes());
+            buf.extend_from_slice(&encoded.to_be_bytes());
+            if length > 0 {
+                buf.extend_from_slice(&value[offset..offset + length]);
+            }
+            buf
+        }
+    }
+}
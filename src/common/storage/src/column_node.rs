@@ -22,6 +22,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::ColumnId;
 use common_expression::FieldIndex;
+use common_expression::TableField;
 use common_expression::TableSchema;
 
 #[derive(Debug, Clone)]
@@ -35,11 +36,26 @@ impl ColumnNodes {
         let mut column_nodes = Vec::with_capacity(schema.fields.len());
 
         let leaf_column_ids = table_schema.map(|table_schema| table_schema.to_leaf_column_ids());
-        for field in &schema.fields {
+        for (i, field) in schema.fields.iter().enumerate() {
             let mut column_node = Self::traverse_fields_dfs(field, false, &mut leaf_id);
             if let Some(ref leaf_column_ids) = leaf_column_ids {
                 column_node.build_leaf_column_ids(leaf_column_ids);
             }
+            if let Some(table_schema) = table_schema {
+                let table_field = table_schema.field(i);
+                column_node.evolved_from = table_field
+                    .evolved_from()
+                    .iter()
+                    .map(|(column_id, data_type)| {
+                        let field = TableField::new_from_column_id(
+                            table_field.name(),
+                            data_type.clone(),
+                            *column_id,
+                        );
+                        (*column_id, ArrowField::from(&field))
+                    })
+                    .collect();
+            }
             column_nodes.push(column_node);
         }
 
@@ -146,6 +162,9 @@ pub struct ColumnNode {
     // Optional children column for nested types.
     pub children: Option<Vec<ColumnNode>>,
     pub leaf_column_ids: Vec<ColumnId>,
+    // Previous versions of a non-nested column whose data type has been modified,
+    // the most recent first. Blocks written before the modification store one of them.
+    pub evolved_from: Vec<(ColumnId, ArrowField)>,
 }
 
 impl ColumnNode {
@@ -161,6 +180,7 @@ impl ColumnNode {
             leaf_indices,
             children,
             leaf_column_ids: vec![],
+            evolved_from: vec![],
         }
     }
 
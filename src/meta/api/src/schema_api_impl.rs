@@ -88,6 +88,8 @@ use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateMultiTableMetaReply;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpsertTableCopiedFileReply;
@@ -2124,6 +2126,63 @@ impl<KV: kvapi::KVApi<Error = MetaError>> SchemaApi for KV {
         }
     }
 
+    #[tracing::instrument(level = "debug", ret, skip_all)]
+    async fn update_multi_table_meta(
+        &self,
+        req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply, KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
+
+        loop {
+            let mut condition = Vec::with_capacity(req.update_table_metas.len());
+            let mut if_then = Vec::with_capacity(req.update_table_metas.len());
+            for update in &req.update_table_metas {
+                let tbid = TableId {
+                    table_id: update.table_id,
+                };
+                let (tb_meta_seq, table_meta): (_, Option<TableMeta>) =
+                    get_struct_value(self, &tbid).await?;
+
+                debug!(ident = display(&tbid), "update_multi_table_meta");
+
+                if tb_meta_seq == 0 || table_meta.is_none() {
+                    return Err(KVAppError::AppError(AppError::UnknownTableId(
+                        UnknownTableId::new(update.table_id, "update_multi_table_meta"),
+                    )));
+                }
+                if update.seq.match_seq(tb_meta_seq).is_err() {
+                    return Err(KVAppError::AppError(AppError::from(
+                        TableVersionMismatched::new(
+                            update.table_id,
+                            update.seq,
+                            tb_meta_seq,
+                            "update_multi_table_meta",
+                        ),
+                    )));
+                }
+
+                // table is not changed
+                condition.push(txn_cond_seq(&tbid, Eq, tb_meta_seq));
+                // tb_id -> tb_meta
+                if_then.push(txn_op_put(&tbid, serialize_struct(&update.new_table_meta)?));
+            }
+
+            let txn_req = TxnRequest {
+                condition,
+                if_then,
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(succ = display(succ), "update_multi_table_meta");
+
+            if succ {
+                return Ok(UpdateMultiTableMetaReply {});
+            }
+        }
+    }
+
     #[tracing::instrument(level = "debug", ret, err, skip_all)]
     async fn gc_dropped_data(
         &self,
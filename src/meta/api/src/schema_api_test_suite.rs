@@ -59,6 +59,7 @@ use common_meta_app::schema::TableStatistics;
 use common_meta_app::schema::TruncateTableReq;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpsertTableCopiedFileReq;
 use common_meta_app::schema::UpsertTableOptionReq;
@@ -2052,6 +2053,66 @@ impl SchemaApiTestSuite {
 
                 assert_eq!(ErrorCode::TABLE_VERSION_MISMATCHED, err.code());
             }
+
+            info!("--- update multi table meta");
+            {
+                let req = CreateTableReq {
+                    if_not_exists: false,
+                    name_ident: TableNameIdent {
+                        tenant: tenant.to_string(),
+                        db_name: db_name.to_string(),
+                        table_name: "tb3".to_string(),
+                    },
+                    table_meta: table_meta(Utc::now()),
+                };
+                mt.create_table(req).await?;
+
+                let tb2 = mt.get_table((tenant, "db1", "tb2").into()).await?;
+                let tb3 = mt.get_table((tenant, "db1", "tb3").into()).await?;
+                let update = |table: &TableInfo, seq: u64, data_bytes: u64| {
+                    let mut new_table_meta = table.meta.clone();
+                    new_table_meta.statistics = TableStatistics {
+                        data_bytes,
+                        ..Default::default()
+                    };
+                    UpdateTableMetaReq {
+                        table_id: table.ident.table_id,
+                        seq: MatchSeq::Exact(seq),
+                        new_table_meta,
+                    }
+                };
+
+                info!("--- update multi table meta: version mismatch");
+                let res = mt
+                    .update_multi_table_meta(UpdateMultiTableMetaReq {
+                        update_table_metas: vec![
+                            update(&tb2, tb2.ident.seq, 2),
+                            update(&tb3, tb3.ident.seq + 1, 2),
+                        ],
+                    })
+                    .await;
+                let err = ErrorCode::from(res.unwrap_err());
+                assert_eq!(ErrorCode::TABLE_VERSION_MISMATCHED, err.code());
+
+                // nothing is changed
+                let got = mt.get_table((tenant, "db1", "tb2").into()).await?;
+                assert_eq!(tb2.ident.seq, got.ident.seq);
+                assert_eq!(tb2.meta, got.meta);
+
+                info!("--- update multi table meta, normal case");
+                mt.update_multi_table_meta(UpdateMultiTableMetaReq {
+                    update_table_metas: vec![
+                        update(&tb2, tb2.ident.seq, 3),
+                        update(&tb3, tb3.ident.seq, 3),
+                    ],
+                })
+                .await?;
+
+                let got = mt.get_table((tenant, "db1", "tb2").into()).await?;
+                assert_eq!(3, got.meta.statistics.data_bytes);
+                let got = mt.get_table((tenant, "db1", "tb3").into()).await?;
+                assert_eq!(3, got.meta.statistics.data_bytes);
+            }
         }
         Ok(())
     }
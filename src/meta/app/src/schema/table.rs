@@ -23,6 +23,7 @@ use std::sync::Arc;
 use chrono::DateTime;
 use chrono::Utc;
 use common_exception::Result;
+use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchema;
 use common_meta_types::MatchSeq;
@@ -244,6 +245,20 @@ impl TableMeta {
         self.schema = Arc::new(new_schema);
         Ok(())
     }
+
+    pub fn rename_column(&mut self, column: &str, new_column: &str) -> Result<()> {
+        let mut new_schema = self.schema.as_ref().to_owned();
+        new_schema.rename_column(column, new_column)?;
+        self.schema = Arc::new(new_schema);
+        Ok(())
+    }
+
+    pub fn modify_column_type(&mut self, column: &str, data_type: TableDataType) -> Result<()> {
+        let mut new_schema = self.schema.as_ref().to_owned();
+        new_schema.modify_column_type(column, data_type)?;
+        self.schema = Arc::new(new_schema);
+        Ok(())
+    }
 }
 
 impl TableInfo {
@@ -590,6 +605,18 @@ pub struct UpsertTableOptionReply {}
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct UpdateTableMetaReply {}
 
+/// Update the metas of several tables atomically.
+///
+/// Either all the tables are updated, or none of them is updated if the version of any
+/// table does not match.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateMultiTableMetaReq {
+    pub update_table_metas: Vec<UpdateTableMetaReq>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateMultiTableMetaReply {}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct GetTableReq {
     pub inner: TableNameIdent,
@@ -79,7 +79,16 @@ impl FromToProto for ex::TableField {
             p.column_id,
         )
         .with_default_expr(p.default_expr);
-        Ok(v)
+
+        let mut evolved_from = Vec::with_capacity(p.evolved_from.len());
+        for c in p.evolved_from {
+            reader_check_msg(c.ver, c.min_reader_ver)?;
+            let data_type = c.data_type.ok_or_else(|| Incompatible {
+                reason: "EvolvedColumn.data_type can not be None".to_string(),
+            })?;
+            evolved_from.push((c.column_id, ex::TableDataType::from_pb(data_type)?));
+        }
+        Ok(v.with_evolved_from(evolved_from))
     }
 
     fn to_pb(&self) -> Result<pb::DataField, Incompatible> {
@@ -90,6 +99,18 @@ impl FromToProto for ex::TableField {
             default_expr: self.default_expr().cloned(),
             data_type: Some(self.data_type().to_pb()?),
             column_id: self.column_id(),
+            evolved_from: self
+                .evolved_from()
+                .iter()
+                .map(|(column_id, data_type)| {
+                    Ok(pb::EvolvedColumn {
+                        ver: VER,
+                        min_reader_ver: MIN_READER_VER,
+                        column_id: *column_id,
+                        data_type: Some(data_type.to_pb()?),
+                    })
+                })
+                .collect::<Result<Vec<_>, Incompatible>>()?,
         };
         Ok(p)
     }
@@ -210,6 +231,7 @@ impl FromToProto for ex::TableDataType {
                         ex::TableDataType::Decimal(ex::types::decimal::DecimalDataType::from_pb(x)?)
                     }
                     Dt24::EmptyMapT(_) => ex::TableDataType::EmptyMap,
+                    Dt24::BitmapT(_) => ex::TableDataType::Bitmap,
                 };
                 Ok(x)
             }
@@ -269,6 +291,7 @@ impl FromToProto for ex::TableDataType {
                 new_pb_dt24(Dt24::TupleT(x))
             }
             TableDataType::Variant => new_pb_dt24(Dt24::VariantT(pb::Empty {})),
+            TableDataType::Bitmap => new_pb_dt24(Dt24::BitmapT(pb::Empty {})),
         };
         Ok(x)
     }
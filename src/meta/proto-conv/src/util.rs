@@ -91,6 +91,11 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
         29,
         "2023-02-23: Add: metadata.proto/DataType EmptyMap types",
     ),
+    (30, "2023-03-06: Add: metadata.proto/DataType Bitmap type"),
+    (
+        31,
+        "2023-03-20: Add: metadata.proto/DataField::evolved_from",
+    ),
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
@@ -0,0 +1,54 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::types::NumberDataType;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_build_pb_buf()`
+#[test]
+fn test_decode_v30_schema() -> anyhow::Result<()> {
+    let schema_v30 = [
+        10, 28, 10, 1, 97, 26, 17, 154, 2, 8, 34, 0, 160, 6, 30, 168, 6, 24, 160, 6, 30, 168, 6,
+        24, 160, 6, 30, 168, 6, 24, 10, 22, 10, 1, 98, 26, 9, 234, 2, 0, 160, 6, 30, 168, 6, 24,
+        32, 1, 160, 6, 30, 168, 6, 24, 10, 31, 10, 1, 99, 26, 18, 178, 2, 9, 234, 2, 0, 160, 6, 30,
+        168, 6, 24, 160, 6, 30, 168, 6, 24, 32, 2, 160, 6, 30, 168, 6, 24, 24, 3, 160, 6, 30, 168,
+        6, 24,
+    ];
+
+    let fields = vec![
+        TableField::new("a", TableDataType::Number(NumberDataType::UInt64)),
+        TableField::new("b", TableDataType::Bitmap),
+        TableField::new(
+            "c",
+            TableDataType::Nullable(Box::new(TableDataType::Bitmap)),
+        ),
+    ];
+    let want = || TableSchema::new(fields.clone());
+    common::test_load_old(func_name!(), schema_v30.as_slice(), 30, want())?;
+    common::test_pb_from_to(func_name!(), want())?;
+    Ok(())
+}
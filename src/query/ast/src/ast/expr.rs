@@ -22,6 +22,7 @@ use common_exception::Span;
 use crate::ast::write_comma_separated_list;
 use crate::ast::write_period_separated_list;
 use crate::ast::Identifier;
+use crate::ast::OrderByExpr;
 use crate::ast::Query;
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -138,7 +139,7 @@ pub enum Expr {
     /// A literal value, such as string, number, date or NULL
     Literal { span: Span, lit: Literal },
     /// `COUNT(*)` expression
-    CountAll { span: Span },
+    CountAll { span: Span, window: Option<Window> },
     /// `(foo, bar)`
     Tuple { span: Span, exprs: Vec<Expr> },
     /// Scalar function call
@@ -149,6 +150,10 @@ pub enum Expr {
         name: Identifier,
         args: Vec<Expr>,
         params: Vec<Literal>,
+        /// Sort keys of aggregate function, like `ARRAY_AGG(a ORDER BY b)`
+        order_by: Vec<OrderByExpr>,
+        /// Window specification of window function, like `ROW_NUMBER() OVER (PARTITION BY a ORDER BY b)`
+        window: Option<Window>,
     },
     /// `CASE ... WHEN ... ELSE ...` expression
     Case {
@@ -208,6 +213,58 @@ pub enum Expr {
         unit: IntervalKind,
         date: Box<Expr>,
     },
+    /// The lambda argument of a higher-order function, like `x -> x + 1` or `(acc, x) -> acc + x`
+    Lambda {
+        span: Span,
+        params: Vec<Identifier>,
+        expr: Box<Expr>,
+    },
+}
+
+/// The window of a window function, either a reference to a named window
+/// defined in `WINDOW` clause or an inline window specification.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Window {
+    WindowReference(WindowRef),
+    WindowSpec(WindowSpec),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindowRef {
+    pub window_name: Identifier,
+}
+
+/// `[existing_window_name] [PARTITION BY ...] [ORDER BY ...] [frame_clause]`
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindowSpec {
+    pub existing_window_name: Option<Identifier>,
+    pub partition_by: Vec<Expr>,
+    pub order_by: Vec<OrderByExpr>,
+    pub window_frame: Option<WindowFrame>,
+}
+
+/// `ROWS | RANGE BETWEEN start_bound AND end_bound`
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindowFrame {
+    pub units: WindowFrameUnits,
+    pub start_bound: WindowFrameBound,
+    pub end_bound: WindowFrameBound,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WindowFrameUnits {
+    Rows,
+    Range,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum WindowFrameBound {
+    /// `CURRENT ROW`
+    CurrentRow,
+    /// `<N> PRECEDING` or `UNBOUNDED PRECEDING`
+    Preceding(Option<Box<Expr>>),
+    /// `<N> FOLLOWING` or `UNBOUNDED FOLLOWING`
+    Following(Option<Box<Expr>>),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -271,6 +328,7 @@ pub enum TypeName {
     },
     Object,
     Variant,
+    Bitmap,
     Nullable(Box<TypeName>),
 }
 
@@ -381,7 +439,7 @@ impl Expr {
             | Expr::Substring { span, .. }
             | Expr::Trim { span, .. }
             | Expr::Literal { span, .. }
-            | Expr::CountAll { span }
+            | Expr::CountAll { span, .. }
             | Expr::Tuple { span, .. }
             | Expr::FunctionCall { span, .. }
             | Expr::Case { span, .. }
@@ -393,7 +451,8 @@ impl Expr {
             | Expr::Interval { span, .. }
             | Expr::DateAdd { span, .. }
             | Expr::DateSub { span, .. }
-            | Expr::DateTrunc { span, .. } => *span,
+            | Expr::DateTrunc { span, .. }
+            | Expr::Lambda { span, .. } => *span,
         }
     }
 }
@@ -414,6 +473,82 @@ impl Display for IntervalKind {
     }
 }
 
+impl Display for Window {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Window::WindowReference(window_ref) => write!(f, "{}", window_ref.window_name),
+            Window::WindowSpec(window_spec) => write!(f, "({window_spec})"),
+        }
+    }
+}
+
+impl Display for WindowSpec {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut first = true;
+        if let Some(existing_window_name) = &self.existing_window_name {
+            write!(f, "{existing_window_name}")?;
+            first = false;
+        }
+
+        if !self.partition_by.is_empty() {
+            if !first {
+                write!(f, " ")?;
+            }
+            first = false;
+            write!(f, "PARTITION BY ")?;
+            write_comma_separated_list(f, &self.partition_by)?;
+        }
+
+        if !self.order_by.is_empty() {
+            if !first {
+                write!(f, " ")?;
+            }
+            first = false;
+            write!(f, "ORDER BY ")?;
+            write_comma_separated_list(f, &self.order_by)?;
+        }
+
+        if let Some(frame) = &self.window_frame {
+            if !first {
+                write!(f, " ")?;
+            }
+            write!(f, "{frame}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for WindowFrame {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} BETWEEN {} AND {}",
+            self.units, self.start_bound, self.end_bound
+        )
+    }
+}
+
+impl Display for WindowFrameUnits {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WindowFrameUnits::Rows => write!(f, "ROWS"),
+            WindowFrameUnits::Range => write!(f, "RANGE"),
+        }
+    }
+}
+
+impl Display for WindowFrameBound {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WindowFrameBound::CurrentRow => write!(f, "CURRENT ROW"),
+            WindowFrameBound::Preceding(None) => write!(f, "UNBOUNDED PRECEDING"),
+            WindowFrameBound::Preceding(Some(n)) => write!(f, "{n} PRECEDING"),
+            WindowFrameBound::Following(None) => write!(f, "UNBOUNDED FOLLOWING"),
+            WindowFrameBound::Following(Some(n)) => write!(f, "{n} FOLLOWING"),
+        }
+    }
+}
+
 impl Display for SubqueryModifier {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -610,6 +745,9 @@ impl Display for TypeName {
             TypeName::Variant => {
                 write!(f, "VARIANT")?;
             }
+            TypeName::Bitmap => {
+                write!(f, "BITMAP")?;
+            }
             TypeName::Nullable(ty) => {
                 write!(f, "{} NULL", ty)?;
             }
@@ -795,8 +933,12 @@ impl Display for Expr {
             Expr::Literal { lit, .. } => {
                 write!(f, "{lit}")?;
             }
-            Expr::CountAll { .. } => {
+            Expr::CountAll { window, .. } => {
                 write!(f, "COUNT(*)")?;
+
+                if let Some(window) = window {
+                    write!(f, " OVER {window}")?;
+                }
             }
             Expr::Tuple { exprs, .. } => {
                 write!(f, "(")?;
@@ -811,6 +953,8 @@ impl Display for Expr {
                 name,
                 args,
                 params,
+                order_by,
+                window,
                 ..
             } => {
                 write!(f, "{name}")?;
@@ -824,7 +968,15 @@ impl Display for Expr {
                     write!(f, "DISTINCT ")?;
                 }
                 write_comma_separated_list(f, args)?;
+                if !order_by.is_empty() {
+                    write!(f, " ORDER BY ")?;
+                    write_comma_separated_list(f, order_by)?;
+                }
                 write!(f, ")")?;
+
+                if let Some(window) = window {
+                    write!(f, " OVER {window}")?;
+                }
             }
             Expr::Case {
                 operand,
@@ -915,6 +1067,16 @@ impl Display for Expr {
             Expr::DateTrunc { unit, date, .. } => {
                 write!(f, "DATE_TRUNC({unit}, {date})")?;
             }
+            Expr::Lambda { params, expr, .. } => {
+                if params.len() == 1 {
+                    write!(f, "{}", params[0])?;
+                } else {
+                    write!(f, "(")?;
+                    write_comma_separated_list(f, params)?;
+                    write!(f, ")")?;
+                }
+                write!(f, " -> {expr}")?;
+            }
         }
 
         Ok(())
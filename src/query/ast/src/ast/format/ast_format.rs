@@ -404,7 +404,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
-    fn visit_count_all(&mut self, _span: Span) {
+    fn visit_count_all(&mut self, _span: Span, _window: &'ast Option<Window>) {
         let name = "Function CountAll".to_string();
         let format_ctx = AstFormatContext::new(name);
         let node = FormatTreeNode::new(format_ctx);
@@ -430,12 +430,18 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         name: &'ast Identifier,
         args: &'ast [Expr],
         _params: &'ast [Literal],
+        order_by: &'ast [OrderByExpr],
+        _window: &'ast Option<Window>,
     ) {
-        let mut children = Vec::with_capacity(args.len());
+        let mut children = Vec::with_capacity(args.len() + order_by.len());
         for arg in args.iter() {
             self.visit_expr(arg);
             children.push(self.children.pop().unwrap());
         }
+        for order_by in order_by.iter() {
+            self.visit_order_by(order_by);
+            children.push(self.children.pop().unwrap());
+        }
         let node_name = if distinct {
             format!("Function {name}Distinct")
         } else {
@@ -811,6 +817,27 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_begin(&mut self) {
+        let name = "Begin".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
+    fn visit_commit(&mut self) {
+        let name = "Commit".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
+    fn visit_abort(&mut self) {
+        let name = "Abort".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
     fn visit_show_metrics(&mut self) {
         let name = "ShowMetrics".to_string();
         let format_ctx = AstFormatContext::new(name);
@@ -960,6 +987,44 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_replace(&mut self, replace: &'ast ReplaceStmt) {
+        let mut children = Vec::new();
+        self.visit_table_ref(&replace.catalog, &replace.database, &replace.table);
+        children.push(self.children.pop().unwrap());
+
+        let mut on_conflict_children = Vec::with_capacity(replace.on_conflict_columns.len());
+        for column in replace.on_conflict_columns.iter() {
+            self.visit_identifier(column);
+            on_conflict_children.push(self.children.pop().unwrap());
+        }
+        let on_conflict_name = "OnConflictColumns".to_string();
+        let on_conflict_format_ctx =
+            AstFormatContext::with_children(on_conflict_name, on_conflict_children.len());
+        let on_conflict_node =
+            FormatTreeNode::with_children(on_conflict_format_ctx, on_conflict_children);
+        children.push(on_conflict_node);
+
+        if !replace.columns.is_empty() {
+            let mut columns_children = Vec::with_capacity(replace.columns.len());
+            for column in replace.columns.iter() {
+                self.visit_identifier(column);
+                columns_children.push(self.children.pop().unwrap());
+            }
+            let columns_name = "Columns".to_string();
+            let columns_format_ctx =
+                AstFormatContext::with_children(columns_name, columns_children.len());
+            let columns_node = FormatTreeNode::with_children(columns_format_ctx, columns_children);
+            children.push(columns_node);
+        }
+        self.visit_insert_source(&replace.source);
+        children.push(self.children.pop().unwrap());
+
+        let name = "Replace".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
     fn visit_delete(
         &mut self,
         table_reference: &'ast TableReference,
@@ -1000,6 +1065,57 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_merge_into(&mut self, merge_into: &'ast MergeIntoStmt) {
+        let mut children = Vec::new();
+        self.visit_table_ref(&merge_into.catalog, &merge_into.database, &merge_into.table);
+        children.push(self.children.pop().unwrap());
+        self.visit_table_reference(&merge_into.source);
+        children.push(self.children.pop().unwrap());
+        self.visit_expr(&merge_into.join_expr);
+        children.push(self.children.pop().unwrap());
+
+        for option in merge_into.merge_options.iter() {
+            let mut option_children = Vec::new();
+            let (name, selection) = match option {
+                MergeOption::Match(clause) => match &clause.operation {
+                    MatchOperation::Update { update_list } => {
+                        for update_expr in update_list.iter() {
+                            self.visit_identifier(&update_expr.name);
+                            option_children.push(self.children.pop().unwrap());
+                            self.visit_expr(&update_expr.expr);
+                            option_children.push(self.children.pop().unwrap());
+                        }
+                        ("MatchedUpdate", &clause.selection)
+                    }
+                    MatchOperation::Delete => ("MatchedDelete", &clause.selection),
+                },
+                MergeOption::Unmatch(clause) => {
+                    for column in clause.columns.iter() {
+                        self.visit_identifier(column);
+                        option_children.push(self.children.pop().unwrap());
+                    }
+                    for value in clause.values.iter() {
+                        self.visit_expr(value);
+                        option_children.push(self.children.pop().unwrap());
+                    }
+                    ("UnmatchedInsert", &clause.selection)
+                }
+            };
+            if let Some(selection) = selection {
+                self.visit_expr(selection);
+                option_children.push(self.children.pop().unwrap());
+            }
+            let format_ctx =
+                AstFormatContext::with_children(name.to_string(), option_children.len());
+            children.push(FormatTreeNode::with_children(format_ctx, option_children));
+        }
+
+        let name = "MergeInto".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
     fn visit_show_databases(&mut self, stmt: &'ast ShowDatabasesStmt) {
         let mut children = Vec::new();
         if let Some(limit) = &stmt.limit {
@@ -1229,6 +1345,24 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let node = FormatTreeNode::with_children(format_ctx, vec![child]);
                 self.children.push(node);
             }
+            CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                travel_point,
+            } => {
+                let mut children = Vec::with_capacity(2);
+                self.visit_table_ref(catalog, database, table);
+                children.push(self.children.pop().unwrap());
+                if let Some(travel_point) = travel_point {
+                    self.visit_time_travel_point(travel_point);
+                    children.push(self.children.pop().unwrap());
+                }
+                let name = "CloneTable".to_string();
+                let format_ctx = AstFormatContext::with_children(name, children.len());
+                let node = FormatTreeNode::with_children(format_ctx, children);
+                self.children.push(node);
+            }
         }
     }
 
@@ -1283,6 +1417,19 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::new(action_name);
                 FormatTreeNode::new(action_format_ctx)
             }
+            AlterTableAction::RenameColumn {
+                old_column,
+                new_column,
+            } => {
+                let action_name = format!("Action Rename column {} to {}", old_column, new_column);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::ModifyColumnType { column, data_type } => {
+                let action_name = format!("Action Modify column {} type {}", column, data_type);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
             AlterTableAction::AlterTableClusterKey { cluster_by } => {
                 let mut cluster_by_children = Vec::with_capacity(cluster_by.len());
                 for cluster_by_expr in cluster_by.iter() {
@@ -1416,6 +1563,52 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_stream(&mut self, stmt: &'ast CreateStreamStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.stream);
+        let stream_child = self.children.pop().unwrap();
+        self.visit_table_ref(&None, &stmt.table_database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let name = "CreateStream".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![stream_child, table_child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_stream(&mut self, stmt: &'ast DropStreamStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.stream);
+        let child = self.children.pop().unwrap();
+
+        let name = "DropStream".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_aggregating_index(&mut self, stmt: &'ast CreateAggregatingIndexStmt) {
+        self.visit_identifier(&stmt.index_name);
+        let index_child = self.children.pop().unwrap();
+        self.visit_query(&stmt.query);
+        let query_child = self.children.pop().unwrap();
+
+        let name = "CreateAggregatingIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![index_child, query_child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_aggregating_index(&mut self, stmt: &'ast DropAggregatingIndexStmt) {
+        self.visit_identifier(&stmt.index_name);
+        let index_child = self.children.pop().unwrap();
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let name = "DropAggregatingIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![index_child, table_child]);
+        self.children.push(node);
+    }
+
     fn visit_show_users(&mut self) {
         let name = "ShowUsers".to_string();
         let format_ctx = AstFormatContext::new(name);
@@ -2059,13 +2252,21 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 FormatTreeNode::with_children(selection_format_ctx, vec![selection_child]);
             children.push(selection_node);
         }
-        if !stmt.group_by.is_empty() {
-            let mut group_by_list_children = Vec::with_capacity(stmt.group_by.len());
-            for group_by in stmt.group_by.iter() {
-                self.visit_expr(group_by);
+        if let Some(group_by) = &stmt.group_by {
+            let (group_by_list_name, exprs): (&str, Vec<&Expr>) = match group_by {
+                GroupBy::Normal(exprs) => ("GroupByList", exprs.iter().collect()),
+                GroupBy::GroupingSets(sets) => {
+                    ("GroupByGroupingSets", sets.iter().flatten().collect())
+                }
+                GroupBy::Rollup(exprs) => ("GroupByRollup", exprs.iter().collect()),
+                GroupBy::Cube(exprs) => ("GroupByCube", exprs.iter().collect()),
+            };
+            let mut group_by_list_children = Vec::with_capacity(exprs.len());
+            for expr in exprs {
+                self.visit_expr(expr);
                 group_by_list_children.push(self.children.pop().unwrap());
             }
-            let group_by_list_name = "GroupByList".to_string();
+            let group_by_list_name = group_by_list_name.to_string();
             let group_by_list_format_ctx =
                 AstFormatContext::with_children(group_by_list_name, group_by_list_children.len());
             let group_by_list_node =
@@ -2167,6 +2368,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             }
             TableReference::TableFunction {
                 span: _,
+                lateral,
                 name,
                 params,
                 named_params,
@@ -2186,7 +2388,11 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                     );
                     children.push(node);
                 }
-                let func_name = format!("TableFunction {}", name);
+                let func_name = if *lateral {
+                    format!("LateralTableFunction {}", name)
+                } else {
+                    format!("TableFunction {}", name)
+                };
                 let format_ctx = if let Some(alias) = alias {
                     AstFormatContext::with_children_alias(
                         func_name,
@@ -131,6 +131,34 @@ fn pretty_table_source(source: CreateTableSource) -> RcDoc<'static> {
                 RcDoc::nil()
             })
             .append(RcDoc::text(table.to_string())),
+        CreateTableSource::Clone {
+            catalog,
+            database,
+            table,
+            travel_point,
+        } => RcDoc::space()
+            .append(RcDoc::text("CLONE"))
+            .append(RcDoc::space())
+            .append(if let Some(catalog) = catalog {
+                RcDoc::text(catalog.to_string()).append(RcDoc::text("."))
+            } else {
+                RcDoc::nil()
+            })
+            .append(if let Some(database) = database {
+                RcDoc::text(database.to_string()).append(RcDoc::text("."))
+            } else {
+                RcDoc::nil()
+            })
+            .append(RcDoc::text(table.to_string()))
+            .append(match travel_point {
+                Some(TimeTravelPoint::Snapshot(sid)) => {
+                    RcDoc::text(format!(" AT (SNAPSHOT => {sid})"))
+                }
+                Some(TimeTravelPoint::Timestamp(ts)) => {
+                    RcDoc::text(format!(" AT (TIMESTAMP => {ts})"))
+                }
+                None => RcDoc::nil(),
+            }),
     }
 }
 
@@ -160,6 +188,19 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
         AlterTableAction::DropColumn { column } => RcDoc::line()
             .append(RcDoc::text("DROP COLUMN "))
             .append(RcDoc::text(column.to_string())),
+        AlterTableAction::RenameColumn {
+            old_column,
+            new_column,
+        } => RcDoc::line()
+            .append(RcDoc::text("RENAME COLUMN "))
+            .append(RcDoc::text(old_column.to_string()))
+            .append(RcDoc::text(" TO "))
+            .append(RcDoc::text(new_column.to_string())),
+        AlterTableAction::ModifyColumnType { column, data_type } => RcDoc::line()
+            .append(RcDoc::text("MODIFY COLUMN "))
+            .append(RcDoc::text(column.to_string()))
+            .append(RcDoc::text(" TYPE "))
+            .append(RcDoc::text(data_type.to_string())),
         AlterTableAction::AlterTableClusterKey { cluster_by } => RcDoc::line()
             .append(RcDoc::text("CLUSTER BY "))
             .append(parenthenized(
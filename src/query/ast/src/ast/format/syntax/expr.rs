@@ -22,6 +22,7 @@ use crate::ast::format::syntax::NEST_FACTOR;
 use crate::ast::BinaryOperator;
 use crate::ast::Expr;
 use crate::ast::MapAccessor;
+use crate::ast::Window;
 
 pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
     match expr {
@@ -220,7 +221,7 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
             .append(pretty_expr(*expr))
             .append(RcDoc::text(")")),
         Expr::Literal { lit, .. } => RcDoc::text(lit.to_string()),
-        Expr::CountAll { .. } => RcDoc::text("COUNT(*)"),
+        Expr::CountAll { window, .. } => RcDoc::text("COUNT(*)").append(pretty_window(window)),
         Expr::Tuple { exprs, .. } => RcDoc::text("(")
             .append(inline_comma(exprs.into_iter().map(pretty_expr)))
             .append(RcDoc::text(")")),
@@ -229,6 +230,8 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
             name,
             args,
             params,
+            order_by,
+            window,
             ..
         } => RcDoc::text(name.to_string())
             .append(if !params.is_empty() {
@@ -249,7 +252,19 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
                 RcDoc::nil()
             })
             .append(inline_comma(args.into_iter().map(pretty_expr)))
-            .append(RcDoc::text(")")),
+            .append(if !order_by.is_empty() {
+                RcDoc::text(" ORDER BY")
+                    .append(RcDoc::space())
+                    .append(inline_comma(
+                        order_by
+                            .into_iter()
+                            .map(|order_by| RcDoc::text(order_by.to_string())),
+                    ))
+            } else {
+                RcDoc::nil()
+            })
+            .append(RcDoc::text(")"))
+            .append(pretty_window(window)),
         Expr::Case {
             operand,
             conditions,
@@ -400,5 +415,34 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
             .append(RcDoc::space())
             .append(pretty_expr(*date))
             .append(RcDoc::text(")")),
+        Expr::Lambda { params, expr, .. } => {
+            let params = if params.len() == 1 {
+                RcDoc::text(params[0].to_string())
+            } else {
+                RcDoc::text("(")
+                    .append(inline_comma(
+                        params
+                            .into_iter()
+                            .map(|param| RcDoc::text(param.to_string())),
+                    ))
+                    .append(RcDoc::text(")"))
+            };
+            params
+                .append(RcDoc::space())
+                .append(RcDoc::text("->"))
+                .append(RcDoc::space())
+                .append(pretty_expr(*expr))
+        }
+    }
+}
+
+fn pretty_window(window: Option<Window>) -> RcDoc<'static> {
+    if let Some(window) = window {
+        RcDoc::space()
+            .append(RcDoc::text("OVER"))
+            .append(RcDoc::space())
+            .append(RcDoc::text(window.to_string()))
+    } else {
+        RcDoc::nil()
     }
 }
@@ -21,6 +21,7 @@ use crate::ast::format::syntax::interweave_comma;
 use crate::ast::format::syntax::parenthenized;
 use crate::ast::format::syntax::NEST_FACTOR;
 use crate::ast::Expr;
+use crate::ast::GroupBy;
 use crate::ast::JoinCondition;
 use crate::ast::JoinOperator;
 use crate::ast::OrderByExpr;
@@ -30,6 +31,7 @@ use crate::ast::SetExpr;
 use crate::ast::SetOperator;
 use crate::ast::TableReference;
 use crate::ast::TimeTravelPoint;
+use crate::ast::WindowDefinition;
 use crate::ast::With;
 use crate::ast::CTE;
 
@@ -79,7 +81,8 @@ fn pretty_body(body: SetExpr) -> RcDoc<'static> {
         .append(pretty_from(select_stmt.from))
         .append(pretty_selection(select_stmt.selection))
         .append(pretty_group_by(select_stmt.group_by))
-        .append(pretty_having(select_stmt.having)),
+        .append(pretty_having(select_stmt.having))
+        .append(pretty_window_list(select_stmt.window_list)),
         SetExpr::Query(query) => parenthenized(pretty_query(*query)),
         SetExpr::SetOperation(set_operation) => pretty_body(*set_operation.left)
             .append(
@@ -194,24 +197,49 @@ fn pretty_selection(selection: Option<Expr>) -> RcDoc<'static> {
     }
 }
 
-fn pretty_group_by(group_by: Vec<Expr>) -> RcDoc<'static> {
-    if !group_by.is_empty() {
-        RcDoc::line()
-            .append(
-                RcDoc::text("GROUP BY").append(
-                    if group_by.len() > 1 {
-                        RcDoc::line()
-                    } else {
-                        RcDoc::space()
-                    }
-                    .nest(NEST_FACTOR),
+fn pretty_group_by(group_by: Option<GroupBy>) -> RcDoc<'static> {
+    if let Some(group_by) = group_by {
+        match group_by {
+            GroupBy::Normal(exprs) => RcDoc::line()
+                .append(
+                    RcDoc::text("GROUP BY").append(
+                        if exprs.len() > 1 {
+                            RcDoc::line()
+                        } else {
+                            RcDoc::space()
+                        }
+                        .nest(NEST_FACTOR),
+                    ),
+                )
+                .append(
+                    interweave_comma(exprs.into_iter().map(pretty_expr))
+                        .nest(NEST_FACTOR)
+                        .group(),
                 ),
-            )
-            .append(
-                interweave_comma(group_by.into_iter().map(pretty_expr))
+            GroupBy::GroupingSets(sets) => RcDoc::line()
+                .append(RcDoc::text("GROUP BY GROUPING SETS").append(RcDoc::space()))
+                .append(parenthenized(
+                    interweave_comma(sets.into_iter().map(|set| {
+                        parenthenized(interweave_comma(set.into_iter().map(pretty_expr)))
+                    }))
                     .nest(NEST_FACTOR)
                     .group(),
-            )
+                )),
+            GroupBy::Rollup(exprs) => RcDoc::line()
+                .append(RcDoc::text("GROUP BY ROLLUP").append(RcDoc::space()))
+                .append(parenthenized(
+                    interweave_comma(exprs.into_iter().map(pretty_expr))
+                        .nest(NEST_FACTOR)
+                        .group(),
+                )),
+            GroupBy::Cube(exprs) => RcDoc::line()
+                .append(RcDoc::text("GROUP BY CUBE").append(RcDoc::space()))
+                .append(parenthenized(
+                    interweave_comma(exprs.into_iter().map(pretty_expr))
+                        .nest(NEST_FACTOR)
+                        .group(),
+                )),
+        }
     } else {
         RcDoc::nil()
     }
@@ -227,6 +255,24 @@ fn pretty_having(having: Option<Expr>) -> RcDoc<'static> {
     }
 }
 
+fn pretty_window_list(window_list: Option<Vec<WindowDefinition>>) -> RcDoc<'static> {
+    if let Some(window_list) = window_list {
+        RcDoc::line()
+            .append(RcDoc::text("WINDOW").append(RcDoc::line().nest(NEST_FACTOR)))
+            .append(
+                interweave_comma(
+                    window_list
+                        .into_iter()
+                        .map(|window| RcDoc::text(window.to_string())),
+                )
+                .nest(NEST_FACTOR)
+                .group(),
+            )
+    } else {
+        RcDoc::nil()
+    }
+}
+
 pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
     match table {
         TableReference::Table {
@@ -270,6 +316,7 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
         }),
         TableReference::TableFunction {
             span: _,
+            lateral,
             name,
             params,
             named_params,
@@ -280,7 +327,8 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
             } else {
                 RcDoc::nil()
             };
-            RcDoc::text(name.to_string())
+            RcDoc::text(if lateral { "LATERAL " } else { "" })
+                .append(RcDoc::text(name.to_string()))
                 .append(RcDoc::text("("))
                 .append(inline_comma(params.into_iter().map(pretty_expr)))
                 .append(separator)
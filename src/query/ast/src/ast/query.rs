@@ -23,6 +23,7 @@ use crate::ast::Expr;
 use crate::ast::FileLocation;
 use crate::ast::Identifier;
 use crate::ast::SelectStageOptions;
+use crate::ast::WindowSpec;
 
 /// Root node of a query tree
 #[derive(Debug, Clone, PartialEq)]
@@ -56,6 +57,8 @@ pub struct With {
 pub struct CTE {
     pub span: Span,
     pub alias: TableAlias,
+    /// `AS MATERIALIZED`, the CTE is always materialized instead of being inlined.
+    pub materialized: bool,
     pub query: Query,
 }
 
@@ -82,9 +85,31 @@ pub struct SelectStmt {
     // `WHERE` clause
     pub selection: Option<Expr>,
     // `GROUP BY` clause
-    pub group_by: Vec<Expr>,
+    pub group_by: Option<GroupBy>,
     // `HAVING` clause
     pub having: Option<Expr>,
+    // `WINDOW` clause
+    pub window_list: Option<Vec<WindowDefinition>>,
+}
+
+/// The items of `GROUP BY` clause
+#[derive(Debug, Clone, PartialEq)]
+pub enum GroupBy {
+    /// GROUP BY expr [, expr]*
+    Normal(Vec<Expr>),
+    /// GROUP BY GROUPING SETS ( (expr [, expr]*) [, (expr [, expr]*)]* )
+    GroupingSets(Vec<Vec<Expr>>),
+    /// GROUP BY ROLLUP ( expr [, expr]* )
+    Rollup(Vec<Expr>),
+    /// GROUP BY CUBE ( expr [, expr]* )
+    Cube(Vec<Expr>),
+}
+
+/// A named window defined in `WINDOW` clause, like `w AS (PARTITION BY a ORDER BY b)`
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindowDefinition {
+    pub name: Identifier,
+    pub spec: WindowSpec,
 }
 
 /// A relational set expression, like `SELECT ... FROM ... {UNION|EXCEPT|INTERSECT} SELECT ... FROM ...`
@@ -161,9 +186,10 @@ pub enum TableReference {
         alias: Option<TableAlias>,
         travel_point: Option<TimeTravelPoint>,
     },
-    // `TABLE(expr)[ AS alias ]`
+    // `[LATERAL] TABLE(expr)[ AS alias ]`
     TableFunction {
         span: Span,
+        lateral: bool,
         name: Identifier,
         params: Vec<Expr>,
         named_params: Vec<(String, Expr)>,
@@ -287,11 +313,15 @@ impl Display for TableReference {
             }
             TableReference::TableFunction {
                 span: _,
+                lateral,
                 name,
                 params,
                 named_params,
                 alias,
             } => {
+                if *lateral {
+                    write!(f, "LATERAL ")?;
+                }
                 write!(f, "{name}(")?;
                 write_comma_separated_list(f, params)?;
                 if !params.is_empty() && !named_params.is_empty() {
@@ -448,9 +478,8 @@ impl Display for SelectStmt {
         }
 
         // GROUP BY clause
-        if !self.group_by.is_empty() {
-            write!(f, " GROUP BY ")?;
-            write_comma_separated_list(f, &self.group_by)?;
+        if let Some(group_by) = &self.group_by {
+            write!(f, " GROUP BY {group_by}")?;
         }
 
         // HAVING clause
@@ -458,10 +487,52 @@ impl Display for SelectStmt {
             write!(f, " HAVING {having}")?;
         }
 
+        // WINDOW clause
+        if let Some(windows) = &self.window_list {
+            write!(f, " WINDOW ")?;
+            write_comma_separated_list(f, windows)?;
+        }
+
         Ok(())
     }
 }
 
+impl Display for GroupBy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GroupBy::Normal(exprs) => write_comma_separated_list(f, exprs),
+            GroupBy::GroupingSets(sets) => {
+                write!(f, "GROUPING SETS (")?;
+                for (i, set) in sets.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "(")?;
+                    write_comma_separated_list(f, set)?;
+                    write!(f, ")")?;
+                }
+                write!(f, ")")
+            }
+            GroupBy::Rollup(exprs) => {
+                write!(f, "ROLLUP (")?;
+                write_comma_separated_list(f, exprs)?;
+                write!(f, ")")
+            }
+            GroupBy::Cube(exprs) => {
+                write!(f, "CUBE (")?;
+                write_comma_separated_list(f, exprs)?;
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+impl Display for WindowDefinition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} AS ({})", self.name, self.spec)
+    }
+}
+
 impl Display for SetExpr {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -496,7 +567,11 @@ impl Display for SetExpr {
 
 impl Display for CTE {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} AS ({})", self.alias, self.query)?;
+        write!(f, "{} AS ", self.alias)?;
+        if self.materialized {
+            write!(f, "MATERIALIZED ")?;
+        }
+        write!(f, "({})", self.query)?;
         Ok(())
     }
 }
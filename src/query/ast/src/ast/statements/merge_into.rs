@@ -0,0 +1,120 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crate::ast::write_comma_separated_list;
+use crate::ast::write_period_separated_list;
+use crate::ast::Expr;
+use crate::ast::Identifier;
+use crate::ast::TableAlias;
+use crate::ast::TableReference;
+use crate::ast::UpdateExpr;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MergeIntoStmt {
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub table: Identifier,
+    pub target_alias: Option<TableAlias>,
+    pub source: TableReference,
+    pub join_expr: Expr,
+    pub merge_options: Vec<MergeOption>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MergeOption {
+    Match(MatchedClause),
+    Unmatch(UnmatchedClause),
+}
+
+/// `WHEN MATCHED [AND <condition>] THEN (UPDATE SET ... | DELETE)`
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchedClause {
+    pub selection: Option<Expr>,
+    pub operation: MatchOperation,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MatchOperation {
+    Update { update_list: Vec<UpdateExpr> },
+    Delete,
+}
+
+/// `WHEN NOT MATCHED [AND <condition>] THEN INSERT [(<column>, ...)] VALUES (<expr>, ...)`
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnmatchedClause {
+    pub selection: Option<Expr>,
+    pub columns: Vec<Identifier>,
+    pub values: Vec<Expr>,
+}
+
+impl Display for MergeIntoStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "MERGE INTO ")?;
+        write_period_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.table)),
+        )?;
+        if let Some(alias) = &self.target_alias {
+            write!(f, " AS {alias}")?;
+        }
+        write!(f, " USING {} ON {}", self.source, self.join_expr)?;
+        for option in &self.merge_options {
+            write!(f, " {option}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for MergeOption {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            MergeOption::Match(clause) => {
+                write!(f, "WHEN MATCHED")?;
+                if let Some(selection) = &clause.selection {
+                    write!(f, " AND {selection}")?;
+                }
+                write!(f, " THEN ")?;
+                match &clause.operation {
+                    MatchOperation::Update { update_list } => {
+                        write!(f, "UPDATE SET ")?;
+                        write_comma_separated_list(f, update_list)?;
+                    }
+                    MatchOperation::Delete => write!(f, "DELETE")?,
+                }
+            }
+            MergeOption::Unmatch(clause) => {
+                write!(f, "WHEN NOT MATCHED")?;
+                if let Some(selection) = &clause.selection {
+                    write!(f, " AND {selection}")?;
+                }
+                write!(f, " THEN INSERT")?;
+                if !clause.columns.is_empty() {
+                    write!(f, " (")?;
+                    write_comma_separated_list(f, &clause.columns)?;
+                    write!(f, ")")?;
+                }
+                write!(f, " VALUES (")?;
+                write_comma_separated_list(f, &clause.values)?;
+                write!(f, ")")?;
+            }
+        }
+        Ok(())
+    }
+}
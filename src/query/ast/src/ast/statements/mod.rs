@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod aggregating_index;
 mod call;
 mod catalog;
 mod copy;
@@ -19,17 +20,21 @@ mod database;
 mod explain;
 mod insert;
 mod kill;
+mod merge_into;
 mod presign;
+mod replace;
 mod share;
 mod show;
 mod stage;
 mod statement;
+mod stream;
 mod table;
 mod unset;
 mod update;
 mod user;
 mod view;
 
+pub use aggregating_index::*;
 pub use call::*;
 pub use catalog::*;
 pub use copy::*;
@@ -37,11 +42,14 @@ pub use database::*;
 pub use explain::*;
 pub use insert::*;
 pub use kill::*;
+pub use merge_into::*;
 pub use presign::*;
+pub use replace::*;
 pub use share::*;
 pub use show::*;
 pub use stage::*;
 pub use statement::*;
+pub use stream::*;
 pub use table::*;
 pub use unset::*;
 pub use update::*;
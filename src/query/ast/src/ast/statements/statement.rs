@@ -72,6 +72,8 @@ pub enum Statement {
 
     Insert(InsertStmt),
 
+    Replace(ReplaceStmt),
+
     Delete {
         table_reference: TableReference,
         selection: Option<Expr>,
@@ -79,6 +81,13 @@ pub enum Statement {
 
     Update(UpdateStmt),
 
+    MergeInto(MergeIntoStmt),
+
+    // Transactions
+    Begin,
+    Commit,
+    Abort,
+
     // Catalogs
     ShowCatalogs(ShowCatalogsStmt),
     ShowCreateCatalog(ShowCreateCatalogStmt),
@@ -116,6 +125,14 @@ pub enum Statement {
     AlterView(AlterViewStmt),
     DropView(DropViewStmt),
 
+    // Streams
+    CreateStream(CreateStreamStmt),
+    DropStream(DropStreamStmt),
+
+    // Aggregating indexes
+    CreateAggregatingIndex(CreateAggregatingIndexStmt),
+    DropAggregatingIndex(DropAggregatingIndexStmt),
+
     // User
     ShowUsers,
     CreateUser(CreateUserStmt),
@@ -231,6 +248,7 @@ impl Display for Statement {
             }
             Statement::Query(query) => write!(f, "{query}")?,
             Statement::Insert(insert) => write!(f, "{insert}")?,
+            Statement::Replace(replace) => write!(f, "{replace}")?,
             Statement::Delete {
                 table_reference,
                 selection,
@@ -242,6 +260,10 @@ impl Display for Statement {
                 }
             }
             Statement::Update(update) => write!(f, "{update}")?,
+            Statement::MergeInto(merge_into) => write!(f, "{merge_into}")?,
+            Statement::Begin => write!(f, "BEGIN")?,
+            Statement::Commit => write!(f, "COMMIT")?,
+            Statement::Abort => write!(f, "ROLLBACK")?,
             Statement::Copy(stmt) => write!(f, "{stmt}")?,
             Statement::ShowSettings { like } => {
                 write!(f, "SHOW SETTINGS")?;
@@ -319,6 +341,10 @@ impl Display for Statement {
             Statement::CreateView(stmt) => write!(f, "{stmt}")?,
             Statement::AlterView(stmt) => write!(f, "{stmt}")?,
             Statement::DropView(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateStream(stmt) => write!(f, "{stmt}")?,
+            Statement::DropStream(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateAggregatingIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::DropAggregatingIndex(stmt) => write!(f, "{stmt}")?,
             Statement::ShowUsers => write!(f, "SHOW USERS")?,
             Statement::ShowRoles => write!(f, "SHOW ROLES")?,
             Statement::CreateUser(stmt) => write!(f, "{stmt}")?,
@@ -168,6 +168,12 @@ pub enum CreateTableSource {
         database: Option<Identifier>,
         table: Identifier,
     },
+    Clone {
+        catalog: Option<Identifier>,
+        database: Option<Identifier>,
+        table: Identifier,
+        travel_point: Option<TimeTravelPoint>,
+    },
 }
 
 impl Display for CreateTableSource {
@@ -186,6 +192,19 @@ impl Display for CreateTableSource {
                 write!(f, "LIKE ")?;
                 write_period_separated_list(f, catalog.iter().chain(database).chain(Some(table)))
             }
+            CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                travel_point,
+            } => {
+                write!(f, "CLONE ")?;
+                write_period_separated_list(f, catalog.iter().chain(database).chain(Some(table)))?;
+                if let Some(travel_point) = travel_point {
+                    write!(f, " AT{travel_point}")?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -288,6 +307,14 @@ pub enum AlterTableAction {
     DropColumn {
         column: Identifier,
     },
+    RenameColumn {
+        old_column: Identifier,
+        new_column: Identifier,
+    },
+    ModifyColumnType {
+        column: Identifier,
+        data_type: TypeName,
+    },
     AlterTableClusterKey {
         cluster_by: Vec<Expr>,
     },
@@ -313,6 +340,15 @@ impl Display for AlterTableAction {
             AlterTableAction::DropColumn { column } => {
                 write!(f, "DROP COLUMN {column}")
             }
+            AlterTableAction::RenameColumn {
+                old_column,
+                new_column,
+            } => {
+                write!(f, "RENAME COLUMN {old_column} TO {new_column}")
+            }
+            AlterTableAction::ModifyColumnType { column, data_type } => {
+                write!(f, "MODIFY COLUMN {column} TYPE {data_type}")
+            }
             AlterTableAction::AlterTableClusterKey { cluster_by } => {
                 write!(f, "CLUSTER BY ")?;
                 write_comma_separated_list(f, cluster_by)
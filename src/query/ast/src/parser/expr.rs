@@ -51,6 +51,28 @@ pub fn values_with_placeholder(i: Input) -> IResult<Vec<Option<Expr>>> {
     map(rule! { ( "(" ~  #values ~ ")" ) }, |(_, v, _)| v)(i)
 }
 
+/// The argument of a function call, which can also be a lambda like `x -> x + 1`
+/// or `(acc, x) -> acc + x`.
+fn function_arg(i: Input) -> IResult<Expr> {
+    let lambda_params = alt((
+        map(ident, |param| vec![param]),
+        map(
+            rule! { "(" ~ #comma_separated_list1(ident) ~ ")" },
+            |(_, params, _)| params,
+        ),
+    ));
+    let lambda = map(
+        consumed(rule! { #lambda_params ~ "->" ~ #subexpr(0) }),
+        |(span, (params, _, expr))| Expr::Lambda {
+            span: transform_span(span.0),
+            params,
+            expr: Box::new(expr),
+        },
+    );
+
+    rule!(#lambda | #subexpr(0))(i)
+}
+
 pub fn subexpr(min_precedence: u32) -> impl FnMut(Input) -> IResult<Expr> {
     move |i| {
         let higher_prec_expr_element =
@@ -238,7 +260,9 @@ pub enum ExprElement {
         lit: Literal,
     },
     /// `Count(*)` expression
-    CountAll,
+    CountAll {
+        window: Option<Window>,
+    },
     /// `(foo, bar)`
     Tuple {
         exprs: Vec<Expr>,
@@ -250,6 +274,8 @@ pub enum ExprElement {
         name: Identifier,
         args: Vec<Expr>,
         params: Vec<Literal>,
+        order_by: Vec<OrderByExpr>,
+        window: Option<Window>,
     },
     /// `CASE ... WHEN ... ELSE ...` expression
     Case {
@@ -423,8 +449,9 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                 span: transform_span(elem.span.0),
                 lit,
             },
-            ExprElement::CountAll => Expr::CountAll {
+            ExprElement::CountAll { window } => Expr::CountAll {
                 span: transform_span(elem.span.0),
+                window,
             },
             ExprElement::Tuple { exprs } => Expr::Tuple {
                 span: transform_span(elem.span.0),
@@ -435,12 +462,16 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                 name,
                 args,
                 params,
+                order_by,
+                window,
             } => Expr::FunctionCall {
                 span: transform_span(elem.span.0),
                 distinct,
                 name,
                 args,
                 params,
+                order_by,
+                window,
             },
             ExprElement::Case {
                 operand,
@@ -767,9 +798,15 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             trim_where: Some((trim_where, Box::new(trim_str))),
         },
     );
-    let count_all = value(ExprElement::CountAll, rule! {
-        COUNT ~ "(" ~ "*" ~ ^")"
-    });
+    let count_all = map(
+        rule! {
+            COUNT ~ "(" ~ "*" ~ ^")"
+            ~ ( OVER ~ #window_spec_ident )?
+        },
+        |(_, _, _, _, window)| ExprElement::CountAll {
+            window: window.map(|w| w.1),
+        },
+    );
     let tuple = map(
         rule! {
             "(" ~ #comma_separated_list0_ignore_trailing(subexpr(0)) ~ ","? ~ ^")"
@@ -787,14 +824,20 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             #function_name
             ~ "("
             ~ DISTINCT?
-            ~ #comma_separated_list0(subexpr(0))?
+            ~ #comma_separated_list0(function_arg)?
+            ~ ( ORDER ~ ^BY ~ ^#comma_separated_list1(order_by_expr) )?
             ~ ")"
+            ~ ( OVER ~ #window_spec_ident )?
         },
-        |(name, _, opt_distinct, opt_args, _)| ExprElement::FunctionCall {
+        |(name, _, opt_distinct, opt_args, opt_order_by, _, window)| ExprElement::FunctionCall {
             distinct: opt_distinct.is_some(),
             name,
             args: opt_args.unwrap_or_default(),
             params: vec![],
+            order_by: opt_order_by
+                .map(|(_, _, order_by)| order_by)
+                .unwrap_or_default(),
+            window: window.map(|w| w.1),
         },
     );
     let function_call_with_param = map(
@@ -802,12 +845,42 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             #function_name
             ~ "(" ~ #comma_separated_list1(literal) ~ ")"
             ~ "(" ~ DISTINCT? ~ #comma_separated_list0(subexpr(0))? ~ ")"
+            ~ ( OVER ~ #window_spec_ident )?
         },
-        |(name, _, params, _, _, opt_distinct, opt_args, _)| ExprElement::FunctionCall {
+        |(name, _, params, _, _, opt_distinct, opt_args, _, window)| ExprElement::FunctionCall {
             distinct: opt_distinct.is_some(),
             name,
             args: opt_args.unwrap_or_default(),
             params,
+            order_by: vec![],
+            window: window.map(|w| w.1),
+        },
+    );
+    // `PERCENTILE_CONT(0.5) WITHIN GROUP (ORDER BY x)` is the standard form of
+    // `PERCENTILE_CONT(0.5)(x)`, so it is parsed as a function call with parameters.
+    // Descending order counts the levels from the other end, so level `p` becomes `1 - p`.
+    let function_call_within_group = map(
+        rule! {
+            #function_name
+            ~ "(" ~ #comma_separated_list1(literal) ~ ")"
+            ~ WITHIN ~ GROUP
+            ~ "(" ~ ORDER ~ BY ~ #subexpr(0) ~ ( ASC | DESC )? ~ ")"
+        },
+        |(name, _, params, _, _, _, _, _, _, arg, order, _)| {
+            let desc = matches!(order, Some(token) if token.kind == DESC);
+            let params = if desc {
+                params.into_iter().map(invert_level).collect()
+            } else {
+                params
+            };
+            ExprElement::FunctionCall {
+                distinct: false,
+                name,
+                args: vec![arg],
+                params,
+                order_by: vec![],
+                window: None,
+            }
         },
     );
     let case = map(
@@ -956,6 +1029,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
         rule!(
             #is_distinct_from: "`... IS [NOT] DISTINCT FROM ...`"
             | #count_all : "COUNT(*)"
+            | #function_call_within_group : "<function>"
             | #function_call_with_param : "<function>"
             | #function_call : "<function>"
             | #case : "`CASE ... END`"
@@ -971,6 +1045,96 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
     Ok((rest, WithSpan { span, elem }))
 }
 
+pub fn window_spec_ident(i: Input) -> IResult<Window> {
+    alt((
+        map(
+            rule! {
+                "(" ~ #window_spec ~ ^")"
+            },
+            |(_, spec, _)| Window::WindowSpec(spec),
+        ),
+        map(rule! { #ident }, |window_name| {
+            Window::WindowReference(WindowRef { window_name })
+        }),
+    ))(i)
+}
+
+pub fn window_spec(i: Input) -> IResult<WindowSpec> {
+    map(
+        rule! {
+            #window_name?
+            ~ ( PARTITION ~ ^BY ~ ^#comma_separated_list1(subexpr(0)) )?
+            ~ ( ORDER ~ ^BY ~ ^#comma_separated_list1(order_by_expr) )?
+            ~ #window_frame?
+        },
+        |(existing_window_name, opt_partition, opt_order, window_frame)| WindowSpec {
+            existing_window_name,
+            partition_by: opt_partition.map(|p| p.2).unwrap_or_default(),
+            order_by: opt_order.map(|o| o.2).unwrap_or_default(),
+            window_frame,
+        },
+    )(i)
+}
+
+/// The name of an existing window at the beginning of a window specification.
+/// `PARTITION` starts the partition clause, so it's not accepted as a window name here.
+fn window_name(i: Input) -> IResult<Identifier> {
+    match i.0.get(0).map(|token| &token.kind) {
+        Some(PARTITION) => Err(nom::Err::Error(Error::from_error_kind(
+            i,
+            ErrorKind::Other("expected window name"),
+        ))),
+        _ => ident(i),
+    }
+}
+
+pub fn window_frame(i: Input) -> IResult<WindowFrame> {
+    let units = alt((
+        value(WindowFrameUnits::Rows, rule! { ROWS }),
+        value(WindowFrameUnits::Range, rule! { RANGE }),
+    ));
+    let between = map(
+        rule! {
+            BETWEEN ~ ^#window_frame_bound ~ ^AND ~ ^#window_frame_bound
+        },
+        |(_, start_bound, _, end_bound)| (start_bound, end_bound),
+    );
+    // `ROWS <start_bound>` is the shorthand of `ROWS BETWEEN <start_bound> AND CURRENT ROW`
+    let start_only = map(window_frame_bound, |start_bound| {
+        (start_bound, WindowFrameBound::CurrentRow)
+    });
+    map(
+        rule! {
+            #units ~ ( #between | #start_only )
+        },
+        |(units, (start_bound, end_bound))| WindowFrame {
+            units,
+            start_bound,
+            end_bound,
+        },
+    )(i)
+}
+
+pub fn window_frame_bound(i: Input) -> IResult<WindowFrameBound> {
+    alt((
+        value(WindowFrameBound::CurrentRow, rule! { CURRENT ~ ROW }),
+        value(
+            WindowFrameBound::Preceding(None),
+            rule! { UNBOUNDED ~ PRECEDING },
+        ),
+        value(
+            WindowFrameBound::Following(None),
+            rule! { UNBOUNDED ~ FOLLOWING },
+        ),
+        map(rule! { #subexpr(0) ~ PRECEDING }, |(expr, _)| {
+            WindowFrameBound::Preceding(Some(Box::new(expr)))
+        }),
+        map(rule! { #subexpr(0) ~ FOLLOWING }, |(expr, _)| {
+            WindowFrameBound::Following(Some(Box::new(expr)))
+        }),
+    ))(i)
+}
+
 pub fn unary_op(i: Input) -> IResult<UnaryOperator> {
     // Plus and Minus are parsed as binary op at first.
     value(UnaryOperator::Not, rule! { NOT })(i)
@@ -1010,6 +1174,17 @@ pub fn binary_op(i: Input) -> IResult<BinaryOperator> {
     ))(i)
 }
 
+/// Map the quantile level `p` to `1 - p`. Levels out of range are kept for the
+/// function to report.
+fn invert_level(level: Literal) -> Literal {
+    match level {
+        Literal::Integer(0) => Literal::Integer(1),
+        Literal::Integer(1) => Literal::Integer(0),
+        Literal::Float(p) if (0.0..=1.0).contains(&p) => Literal::Float(1.0 - p),
+        level => level,
+    }
+}
+
 pub fn literal(i: Input) -> IResult<Literal> {
     let string = map(literal_string, Literal::String);
     let integer = map(literal_u64, Literal::Integer);
@@ -1228,6 +1403,7 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
     );
     let ty_object = value(TypeName::Object, rule! { OBJECT | MAP });
     let ty_variant = value(TypeName::Variant, rule! { VARIANT | JSON });
+    let ty_bitmap = value(TypeName::Bitmap, rule! { BITMAP });
     map(
         rule! {
             ( #ty_boolean
@@ -1249,6 +1425,7 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
             | #ty_string
             | #ty_object
             | #ty_variant
+            | #ty_bitmap
             | #ty_nullable
             ) ~ NULL? : "type name"
         },
@@ -64,11 +64,12 @@ pub fn query(i: Input) -> IResult<Query> {
 pub fn with(i: Input) -> IResult<With> {
     let cte = map(
         consumed(rule! {
-            #table_alias ~ AS ~ "(" ~ #query ~ ")"
+            #table_alias ~ AS ~ MATERIALIZED? ~ "(" ~ #query ~ ")"
         }),
-        |(span, (table_alias, _, _, query, _))| CTE {
+        |(span, (table_alias, _, materialized, _, query, _))| CTE {
             span: transform_span(span.0),
             alias: table_alias,
+            materialized: materialized.is_some(),
             query,
         },
     );
@@ -262,8 +263,9 @@ pub enum TableReferenceElement {
         alias: Option<TableAlias>,
         travel_point: Option<TimeTravelPoint>,
     },
-    // `TABLE(expr)[ AS alias ]`
+    // `[LATERAL] TABLE(expr)[ AS alias ]`
     TableFunction {
+        lateral: bool,
         name: Identifier,
         params: Vec<TableFunctionParam>,
         alias: Option<TableAlias>,
@@ -303,9 +305,10 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
     );
     let table_function = map(
         rule! {
-            #ident ~ "(" ~ #comma_separated_list0(table_function_param) ~ ")" ~ #table_alias?
+            LATERAL? ~ #function_name ~ "(" ~ #comma_separated_list0(table_function_param) ~ ")" ~ #table_alias?
         },
-        |(name, _, params, _, alias)| TableReferenceElement::TableFunction {
+        |(lateral, name, _, params, _, alias)| TableReferenceElement::TableFunction {
+            lateral: lateral.is_some(),
             name,
             params,
             alias,
@@ -432,6 +435,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                 travel_point,
             },
             TableReferenceElement::TableFunction {
+                lateral,
                 name,
                 params,
                 alias,
@@ -452,6 +456,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                     .collect();
                 TableReference::TableFunction {
                     span: transform_span(input.span.0),
+                    lateral,
                     name,
                     params: normal_params,
                     named_params,
@@ -542,6 +547,47 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
     }
 }
 
+pub fn window_clause(i: Input) -> IResult<WindowDefinition> {
+    map(
+        rule! {
+            #ident ~ AS ~ "(" ~ #window_spec ~ ^")"
+        },
+        |(name, _, _, spec, _)| WindowDefinition { name, spec },
+    )(i)
+}
+
+pub fn group_by_items(i: Input) -> IResult<GroupBy> {
+    let normal = map(rule! { #comma_separated_list1(expr) }, GroupBy::Normal);
+    let rollup = map(
+        rule! {
+            ROLLUP ~ "(" ~ ^#comma_separated_list1(expr) ~ ^")"
+        },
+        |(_, _, exprs, _)| GroupBy::Rollup(exprs),
+    );
+    let cube = map(
+        rule! {
+            CUBE ~ "(" ~ ^#comma_separated_list1(expr) ~ ^")"
+        },
+        |(_, _, exprs, _)| GroupBy::Cube(exprs),
+    );
+    let grouping_set = alt((
+        map(rule! { "(" ~ ")" }, |(_, _)| vec![]),
+        map(
+            rule! { "(" ~ #comma_separated_list1(expr) ~ ")" },
+            |(_, exprs, _)| exprs,
+        ),
+        map(expr, |expr| vec![expr]),
+    ));
+    let grouping_sets = map(
+        rule! {
+            GROUPING ~ SETS ~ "(" ~ ^#comma_separated_list1(grouping_set) ~ ^")"
+        },
+        |(_, _, _, sets, _)| GroupBy::GroupingSets(sets),
+    );
+
+    rule!(#grouping_sets | #rollup | #cube | #normal)(i)
+}
+
 pub fn set_operation(i: Input) -> IResult<SetExpr> {
     let (rest, set_operation_elements) = rule!(#set_operation_element+)(i)?;
     let iter = &mut set_operation_elements.into_iter();
@@ -555,8 +601,9 @@ pub enum SetOperationElement {
         select_list: Box<Vec<SelectTarget>>,
         from: Box<Vec<TableReference>>,
         selection: Box<Option<Expr>>,
-        group_by: Box<Vec<Expr>>,
+        group_by: Box<Option<GroupBy>>,
         having: Box<Option<Expr>>,
+        window_list: Box<Option<Vec<WindowDefinition>>>,
     },
     SetOperation {
         op: SetOperator,
@@ -588,8 +635,9 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
              SELECT ~ DISTINCT? ~ ^#comma_separated_list1(select_target)
                 ~ ( FROM ~ ^#comma_separated_list1(table_reference) )?
                 ~ ( WHERE ~ ^#expr )?
-                ~ ( GROUP ~ ^BY ~ ^#comma_separated_list1(expr) )?
+                ~ ( GROUP ~ ^BY ~ ^#group_by_items )?
                 ~ ( HAVING ~ ^#expr )?
+                ~ ( WINDOW ~ ^#comma_separated_list1(window_clause) )?
         },
         |(
             _select,
@@ -599,6 +647,7 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
             opt_where_block,
             opt_group_by_block,
             opt_having_block,
+            opt_window_block,
         )| {
             SetOperationElement::SelectStmt {
                 distinct: opt_distinct.is_some(),
@@ -609,12 +658,9 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
                         .unwrap_or_default(),
                 ),
                 selection: Box::new(opt_where_block.map(|(_, selection)| selection)),
-                group_by: Box::new(
-                    opt_group_by_block
-                        .map(|(_, _, group_by)| group_by)
-                        .unwrap_or_default(),
-                ),
+                group_by: Box::new(opt_group_by_block.map(|(_, _, group_by)| group_by)),
                 having: Box::new(opt_having_block.map(|(_, having)| having)),
+                window_list: Box::new(opt_window_block.map(|(_, windows)| windows)),
             }
         },
     );
@@ -661,6 +707,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, SetOperationElement>>> PrattParser<I>
                 selection,
                 group_by,
                 having,
+                window_list,
             } => SetExpr::Select(Box::new(SelectStmt {
                 span: transform_span(input.span.0),
                 distinct,
@@ -669,6 +716,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, SetOperationElement>>> PrattParser<I>
                 selection: *selection,
                 group_by: *group_by,
                 having: *having,
+                window_list: *window_list,
             })),
             _ => unreachable!(),
         };
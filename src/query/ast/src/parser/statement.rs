@@ -112,6 +112,39 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let replace = map(
+        rule! {
+            REPLACE ~ INTO?
+            ~ #period_separated_idents_1_to_3
+            ~ ( "(" ~ #comma_separated_list1(ident) ~ ")" )?
+            ~ ON ~ CONFLICT? ~ "(" ~ #comma_separated_list1(ident) ~ ")"
+            ~ #insert_source
+        },
+        |(
+            _,
+            _,
+            (catalog, database, table),
+            opt_columns,
+            _,
+            _,
+            _,
+            on_conflict_columns,
+            _,
+            source,
+        )| {
+            Statement::Replace(ReplaceStmt {
+                catalog,
+                database,
+                table,
+                on_conflict_columns,
+                columns: opt_columns
+                    .map(|(_, columns, _)| columns)
+                    .unwrap_or_default(),
+                source,
+            })
+        },
+    );
+
     let delete = map(
         rule! {
             DELETE ~ FROM ~ #table_reference_only
@@ -138,6 +171,36 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let merge_into = map(
+        rule! {
+            MERGE ~ INTO ~ #period_separated_idents_1_to_3 ~ #table_alias?
+            ~ USING ~ ^#merge_source
+            ~ ON ~ ^#expr
+            ~ #merge_option+
+        },
+        |(
+            _,
+            _,
+            (catalog, database, table),
+            target_alias,
+            _,
+            source,
+            _,
+            join_expr,
+            merge_options,
+        )| {
+            Statement::MergeInto(MergeIntoStmt {
+                catalog,
+                database,
+                table,
+                target_alias,
+                source,
+                join_expr,
+                merge_options,
+            })
+        },
+    );
+
     let show_settings = map(
         rule! {
             SHOW ~ SETTINGS ~ (LIKE ~ #literal_string)?
@@ -146,6 +209,12 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             like: opt_like.map(|(_, like)| like),
         },
     );
+    let begin = value(
+        Statement::Begin,
+        rule! { BEGIN ~ TRANSACTION? | START ~ TRANSACTION },
+    );
+    let commit = value(Statement::Commit, rule! { COMMIT });
+    let abort = value(Statement::Abort, rule! { ROLLBACK });
     let show_stages = value(Statement::ShowStages, rule! { SHOW ~ STAGES });
     let show_process_list = value(Statement::ShowProcessList, rule! { SHOW ~ PROCESSLIST });
     let show_metrics = value(Statement::ShowMetrics, rule! { SHOW ~ METRICS });
@@ -614,6 +683,64 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             })
         },
     );
+    let create_stream = map(
+        rule! {
+            CREATE ~ STREAM ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #period_separated_idents_1_to_3
+            ~ ON ~ TABLE ~ #period_separated_idents_1_to_2
+        },
+        |(_, _, opt_if_not_exists, (catalog, database, stream), _, _, (table_database, table))| {
+            Statement::CreateStream(CreateStreamStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                catalog,
+                database,
+                stream,
+                table_database,
+                table,
+            })
+        },
+    );
+    let drop_stream = map(
+        rule! {
+            DROP ~ STREAM ~ ( IF ~ EXISTS )? ~ #period_separated_idents_1_to_3
+        },
+        |(_, _, opt_if_exists, (catalog, database, stream))| {
+            Statement::DropStream(DropStreamStmt {
+                if_exists: opt_if_exists.is_some(),
+                catalog,
+                database,
+                stream,
+            })
+        },
+    );
+    let create_aggregating_index = map(
+        rule! {
+            CREATE ~ AGGREGATING ~ INDEX ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #ident ~ AS ~ #query
+        },
+        |(_, _, _, opt_if_not_exists, index_name, _, query)| {
+            Statement::CreateAggregatingIndex(CreateAggregatingIndexStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                index_name,
+                query: Box::new(query),
+            })
+        },
+    );
+    let drop_aggregating_index = map(
+        rule! {
+            DROP ~ AGGREGATING ~ INDEX ~ ( IF ~ EXISTS )? ~ #ident
+            ~ ON ~ #period_separated_idents_1_to_3
+        },
+        |(_, _, _, opt_if_exists, index_name, _, (catalog, database, table))| {
+            Statement::DropAggregatingIndex(DropAggregatingIndexStmt {
+                if_exists: opt_if_exists.is_some(),
+                index_name,
+                catalog,
+                database,
+                table,
+            })
+        },
+    );
     let show_users = value(Statement::ShowUsers, rule! { SHOW ~ USERS });
     let create_user = map(
         rule! {
@@ -1032,6 +1159,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             | #insert : "`INSERT INTO [TABLE] <table> [(<column>, ...)] (FORMAT <format> | VALUES <values> | <query>)`"
             | #delete : "`DELETE FROM <table> [WHERE ...]`"
             | #update : "`UPDATE <table> SET <column> = <expr> [, <column> = <expr> , ... ] [WHERE ...]`"
+            | #merge_into : "`MERGE INTO <table> [AS <alias>] USING <source> ON <expr> WHEN [NOT] MATCHED [AND <expr>] THEN ...`"
             | #show_settings : "`SHOW SETTINGS [<show_limit>]`"
             | #show_stages : "`SHOW STAGES`"
             | #show_engines : "`SHOW ENGINES`"
@@ -1048,6 +1176,12 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             | #alter_database : "`ALTER DATABASE [IF EXISTS] <action>`"
             | #use_database : "`USE <database>`"
         ),
+        rule!(
+            #replace : "`REPLACE INTO <table> [(<column>, ...)] ON [CONFLICT] (<column>, ...) (FORMAT <format> | VALUES <values> | <query>)`"
+            | #begin : "`BEGIN [TRANSACTION]` | `START TRANSACTION`"
+            | #commit : "`COMMIT`"
+            | #abort : "`ROLLBACK`"
+        ),
         rule!(
             #set_variable : "`SET <variable> = <value>`"
             | #unset_variable : "`UNSET <variable>`"
@@ -1073,6 +1207,14 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             | #drop_view : "`DROP VIEW [IF EXISTS] [<database>.]<view>`"
             | #alter_view : "`ALTER VIEW [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
         ),
+        rule!(
+            #create_stream : "`CREATE STREAM [IF NOT EXISTS] [<database>.]<stream> ON TABLE [<database>.]<table>`"
+            | #drop_stream : "`DROP STREAM [IF EXISTS] [<database>.]<stream>`"
+        ),
+        rule!(
+            #create_aggregating_index : "`CREATE AGGREGATING INDEX [IF NOT EXISTS] <index> AS SELECT ...`"
+            | #drop_aggregating_index : "`DROP AGGREGATING INDEX [IF EXISTS] <index> ON [<database>.]<table>`"
+        ),
         rule!(
             #show_users : "`SHOW USERS`"
             | #create_user : "`CREATE USER [IF NOT EXISTS] '<username>'@'hostname' IDENTIFIED [WITH <auth_type>] [BY <password>] [WITH <user_option>, ...]`"
@@ -1467,9 +1609,22 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
         },
     );
 
+    let clone = map(
+        rule! {
+            CLONE ~ #period_separated_idents_1_to_3 ~ (AT ~ #travel_point)?
+        },
+        |(_, (catalog, database, table), travel_point)| CreateTableSource::Clone {
+            catalog,
+            database,
+            table,
+            travel_point: travel_point.map(|(_, p)| p),
+        },
+    );
+
     rule!(
         #columns
         | #like
+        | #clone
     )(i)
 }
 
@@ -1505,6 +1660,21 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         },
         |(_, _, column)| AlterTableAction::DropColumn { column },
     );
+    let rename_column = map(
+        rule! {
+            RENAME ~ COLUMN ~ #ident ~ TO ~ #ident
+        },
+        |(_, _, old_column, _, new_column)| AlterTableAction::RenameColumn {
+            old_column,
+            new_column,
+        },
+    );
+    let modify_column_type = map(
+        rule! {
+            MODIFY ~ COLUMN ~ #ident ~ TYPE? ~ #type_name
+        },
+        |(_, _, column, _, data_type)| AlterTableAction::ModifyColumnType { column, data_type },
+    );
     let alter_table_cluster_key = map(
         rule! {
             CLUSTER ~ ^BY ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")"
@@ -1538,8 +1708,10 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
 
     rule!(
         #rename_table
+        | #rename_column
         | #add_column
         | #drop_column
+        | #modify_column_type
         | #alter_table_cluster_key
         | #drop_table_cluster_key
         | #recluster_table
@@ -1856,3 +2028,77 @@ pub fn update_expr(i: Input) -> IResult<UpdateExpr> {
         UpdateExpr { name, expr }
     })(i)
 }
+
+pub fn merge_source(i: Input) -> IResult<TableReference> {
+    let source_table = map(
+        consumed(rule! {
+            #period_separated_idents_1_to_3 ~ #table_alias?
+        }),
+        |(span, ((catalog, database, table), alias))| TableReference::Table {
+            span: transform_span(span.0),
+            catalog,
+            database,
+            table,
+            alias,
+            travel_point: None,
+        },
+    );
+    let source_query = map(
+        consumed(rule! {
+            #parenthesized_query ~ #table_alias?
+        }),
+        |(span, (subquery, alias))| TableReference::Subquery {
+            span: transform_span(span.0),
+            subquery: Box::new(subquery),
+            alias,
+        },
+    );
+
+    rule!(
+        #source_query
+        | #source_table
+    )(i)
+}
+
+pub fn merge_option(i: Input) -> IResult<MergeOption> {
+    let matched_update = map(
+        rule! {
+            UPDATE ~ SET ~ ^#comma_separated_list1(update_expr)
+        },
+        |(_, _, update_list)| MatchOperation::Update { update_list },
+    );
+    let matched_delete = value(MatchOperation::Delete, rule! { DELETE });
+    let matched = map(
+        rule! {
+            WHEN ~ MATCHED ~ ( AND ~ ^#expr )? ~ THEN
+            ~ ^( #matched_update | #matched_delete )
+        },
+        |(_, _, opt_selection, _, operation)| {
+            MergeOption::Match(MatchedClause {
+                selection: opt_selection.map(|(_, selection)| selection),
+                operation,
+            })
+        },
+    );
+    let unmatched = map(
+        rule! {
+            WHEN ~ NOT ~ MATCHED ~ ( AND ~ ^#expr )? ~ THEN
+            ~ ^INSERT ~ ( "(" ~ #comma_separated_list1(ident) ~ ")" )?
+            ~ ^VALUES ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")"
+        },
+        |(_, _, _, opt_selection, _, _, opt_columns, _, _, values, _)| {
+            MergeOption::Unmatch(UnmatchedClause {
+                selection: opt_selection.map(|(_, selection)| selection),
+                columns: opt_columns
+                    .map(|(_, columns, _)| columns)
+                    .unwrap_or_default(),
+                values,
+            })
+        },
+    );
+
+    rule!(
+        #matched
+        | #unmatched
+    )(i)
+}
@@ -245,6 +245,8 @@ pub enum TokenKind {
     ACCESS_KEY_SECRET,
     #[token("ADD", ignore(ascii_case))]
     ADD,
+    #[token("AGGREGATING", ignore(ascii_case))]
+    AGGREGATING,
     #[token("ANY", ignore(ascii_case))]
     ANY,
     #[token("AUTO", ignore(ascii_case))]
@@ -275,10 +277,14 @@ pub enum TokenKind {
     ANTI,
     #[token("BEFORE", ignore(ascii_case))]
     BEFORE,
+    #[token("BEGIN", ignore(ascii_case))]
+    BEGIN,
     #[token("BETWEEN", ignore(ascii_case))]
     BETWEEN,
     #[token("BIGINT", ignore(ascii_case))]
     BIGINT,
+    #[token("BITMAP", ignore(ascii_case))]
+    BITMAP,
     #[token("BOOL", ignore(ascii_case))]
     BOOL,
     #[token("BOOLEAN", ignore(ascii_case))]
@@ -303,14 +309,20 @@ pub enum TokenKind {
     CATALOGS,
     #[token("CENTURY", ignore(ascii_case))]
     CENTURY,
+    #[token("CLONE", ignore(ascii_case))]
+    CLONE,
     #[token("CLUSTER", ignore(ascii_case))]
     CLUSTER,
     #[token("COMMENT", ignore(ascii_case))]
     COMMENT,
     #[token("COMMENTS", ignore(ascii_case))]
     COMMENTS,
+    #[token("COMMIT", ignore(ascii_case))]
+    COMMIT,
     #[token("COMPACT", ignore(ascii_case))]
     COMPACT,
+    #[token("CONFLICT", ignore(ascii_case))]
+    CONFLICT,
     #[token("CONNECTION", ignore(ascii_case))]
     CONNECTION,
     #[token("CONTENT_TYPE", ignore(ascii_case))]
@@ -337,6 +349,10 @@ pub enum TokenKind {
     CROSS,
     #[token("CSV", ignore(ascii_case))]
     CSV,
+    #[token("CUBE", ignore(ascii_case))]
+    CUBE,
+    #[token("CURRENT", ignore(ascii_case))]
+    CURRENT,
     #[token("CURRENT_TIMESTAMP", ignore(ascii_case))]
     CURRENT_TIMESTAMP,
     #[token("DATABASE", ignore(ascii_case))]
@@ -441,6 +457,8 @@ pub enum TokenKind {
     FOR,
     #[token("FORCE", ignore(ascii_case))]
     FORCE,
+    #[token("FOLLOWING", ignore(ascii_case))]
+    FOLLOWING,
     #[token("FORMAT", ignore(ascii_case))]
     FORMAT,
     #[token("FORMAT_NAME", ignore(ascii_case))]
@@ -465,6 +483,8 @@ pub enum TokenKind {
     GRAPH,
     #[token("GROUP", ignore(ascii_case))]
     GROUP,
+    #[token("GROUPING", ignore(ascii_case))]
+    GROUPING,
     #[token("GZIP", ignore(ascii_case))]
     GZIP,
     #[token("HAVING", ignore(ascii_case))]
@@ -485,6 +505,8 @@ pub enum TokenKind {
     IF,
     #[token("IN", ignore(ascii_case))]
     IN,
+    #[token("INDEX", ignore(ascii_case))]
+    INDEX,
     #[token("INNER", ignore(ascii_case))]
     INNER,
     #[token("INSERT", ignore(ascii_case))]
@@ -527,6 +549,8 @@ pub enum TokenKind {
     LOCATION_PREFIX,
     #[token("ROLES", ignore(ascii_case))]
     ROLES,
+    #[token("LATERAL", ignore(ascii_case))]
+    LATERAL,
     #[token("LEADING", ignore(ascii_case))]
     LEADING,
     #[token("LEFT", ignore(ascii_case))]
@@ -541,6 +565,10 @@ pub enum TokenKind {
     LZO,
     #[token("MAP", ignore(ascii_case))]
     MAP,
+    #[token("MATCHED", ignore(ascii_case))]
+    MATCHED,
+    #[token("MATERIALIZED", ignore(ascii_case))]
+    MATERIALIZED,
     #[token("MAX_FILE_SIZE", ignore(ascii_case))]
     MAX_FILE_SIZE,
     #[token("MASTER_KEY", ignore(ascii_case))]
@@ -549,6 +577,8 @@ pub enum TokenKind {
     MEMO,
     #[token("MEMORY", ignore(ascii_case))]
     MEMORY,
+    #[token("MERGE", ignore(ascii_case))]
+    MERGE,
     #[token("METRICS", ignore(ascii_case))]
     METRICS,
     #[token("MICROSECONDS", ignore(ascii_case))]
@@ -559,6 +589,8 @@ pub enum TokenKind {
     MILLISECONDS,
     #[token("MINUTE", ignore(ascii_case))]
     MINUTE,
+    #[token("MODIFY", ignore(ascii_case))]
+    MODIFY,
     #[token("MONTH", ignore(ascii_case))]
     MONTH,
     #[token("NON_DISPLAY", ignore(ascii_case))]
@@ -595,6 +627,8 @@ pub enum TokenKind {
     ORDER,
     #[token("OUTER", ignore(ascii_case))]
     OUTER,
+    #[token("OVER", ignore(ascii_case))]
+    OVER,
     #[token("ON_ERROR", ignore(ascii_case))]
     ON_ERROR,
     #[token("OVERWRITE", ignore(ascii_case))]
@@ -631,14 +665,28 @@ pub enum TokenKind {
     REGEXP,
     #[token("RENAME", ignore(ascii_case))]
     RENAME,
+    #[token("REPLACE", ignore(ascii_case))]
+    REPLACE,
     #[token("ROW_TAG", ignore(ascii_case))]
     ROW_TAG,
+    #[token("ROW", ignore(ascii_case))]
+    ROW,
+    #[token("ROWS", ignore(ascii_case))]
+    ROWS,
     #[token("GRANT", ignore(ascii_case))]
     GRANT,
     #[token("ROLE", ignore(ascii_case))]
     ROLE,
+    #[token("ROLLBACK", ignore(ascii_case))]
+    ROLLBACK,
+    #[token("ROLLUP", ignore(ascii_case))]
+    ROLLUP,
     #[token("PRESIGN", ignore(ascii_case))]
     PRESIGN,
+    #[token("PARTITION", ignore(ascii_case))]
+    PARTITION,
+    #[token("PRECEDING", ignore(ascii_case))]
+    PRECEDING,
     #[token("PRIVILEGES", ignore(ascii_case))]
     PRIVILEGES,
     #[token("REGION", ignore(ascii_case))]
@@ -649,6 +697,8 @@ pub enum TokenKind {
     REVOKE,
     #[token("RECURSIVE", ignore(ascii_case))]
     RECURSIVE,
+    #[token("RANGE", ignore(ascii_case))]
+    RANGE,
     #[token("GRANTS", ignore(ascii_case))]
     GRANTS,
     #[token("RIGHT", ignore(ascii_case))]
@@ -673,6 +723,8 @@ pub enum TokenKind {
     SESSION_TOKEN,
     #[token("SET", ignore(ascii_case))]
     SET,
+    #[token("SETS", ignore(ascii_case))]
+    SETS,
     #[token("UNSET", ignore(ascii_case))]
     UNSET,
     #[token("SETTINGS", ignore(ascii_case))]
@@ -703,6 +755,8 @@ pub enum TokenKind {
     SPLIT_SIZE,
     #[token("STAGE", ignore(ascii_case))]
     STAGE,
+    #[token("START", ignore(ascii_case))]
+    START,
     #[token("SYNTAX", ignore(ascii_case))]
     SYNTAX,
     #[token("USAGE", ignore(ascii_case))]
@@ -719,6 +773,8 @@ pub enum TokenKind {
     SUPER,
     #[token("STATUS", ignore(ascii_case))]
     STATUS,
+    #[token("STREAM", ignore(ascii_case))]
+    STREAM,
     #[token("STRING", ignore(ascii_case))]
     STRING,
     #[token("SUBSTRING", ignore(ascii_case))]
@@ -755,6 +811,8 @@ pub enum TokenKind {
     TOKEN,
     #[token("TRAILING", ignore(ascii_case))]
     TRAILING,
+    #[token("TRANSACTION", ignore(ascii_case))]
+    TRANSACTION,
     #[token("TRANSIENT", ignore(ascii_case))]
     TRANSIENT,
     #[token("TRIM", ignore(ascii_case))]
@@ -785,6 +843,8 @@ pub enum TokenKind {
     UINT8,
     #[token("UNDROP", ignore(ascii_case))]
     UNDROP,
+    #[token("UNBOUNDED", ignore(ascii_case))]
+    UNBOUNDED,
     #[token("UNSIGNED", ignore(ascii_case))]
     UNSIGNED,
     #[token("URL", ignore(ascii_case))]
@@ -813,8 +873,12 @@ pub enum TokenKind {
     WHEN,
     #[token("WHERE", ignore(ascii_case))]
     WHERE,
+    #[token("WINDOW", ignore(ascii_case))]
+    WINDOW,
     #[token("WITH", ignore(ascii_case))]
     WITH,
+    #[token("WITHIN", ignore(ascii_case))]
+    WITHIN,
     #[token("XML", ignore(ascii_case))]
     XML,
     #[token("XOR", ignore(ascii_case))]
@@ -1092,7 +1156,7 @@ impl TokenKind {
             | TokenKind::INNER
             | TokenKind::IS
             | TokenKind::JOIN
-            // | TokenKind::LATERAL
+            | TokenKind::LATERAL
             | TokenKind::LEADING
             | TokenKind::LEFT
             | TokenKind::LIKE
@@ -1146,14 +1210,16 @@ impl TokenKind {
             | TokenKind::OF
             | TokenKind::ORDER
             // | TokenKind::OVERLAPS
+            | TokenKind::RANGE
             // | TokenKind::RETURNING
+            | TokenKind::ROWS
             | TokenKind::STAGE
             | TokenKind::SHARE
             | TokenKind::SHARES
             | TokenKind::TO
             | TokenKind::UNION
             | TokenKind::WHERE
-            // | TokenKind::WINDOW
+            | TokenKind::WINDOW
             | TokenKind::WITH
             | TokenKind::IGNORE_RESULT
             if !after_as => true,
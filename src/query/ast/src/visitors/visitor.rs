@@ -192,7 +192,11 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_literal(&mut self, _span: Span, _lit: &'ast Literal) {}
 
-    fn visit_count_all(&mut self, _span: Span) {}
+    fn visit_count_all(&mut self, _span: Span, window: &'ast Option<Window>) {
+        if let Some(window) = window {
+            self.visit_window(window);
+        }
+    }
 
     fn visit_tuple(&mut self, _span: Span, elements: &'ast [Expr]) {
         for element in elements {
@@ -207,10 +211,38 @@ pub trait Visitor<'ast>: Sized {
         _name: &'ast Identifier,
         args: &'ast [Expr],
         _params: &'ast [Literal],
+        order_by: &'ast [OrderByExpr],
+        window: &'ast Option<Window>,
     ) {
         for arg in args {
             walk_expr(self, arg);
         }
+        for order_by in order_by {
+            self.visit_order_by(order_by);
+        }
+        if let Some(window) = window {
+            self.visit_window(window);
+        }
+    }
+
+    fn visit_window(&mut self, window: &'ast Window) {
+        if let Window::WindowSpec(spec) = window {
+            for expr in spec.partition_by.iter() {
+                walk_expr(self, expr);
+            }
+            for order_by in spec.order_by.iter() {
+                walk_expr(self, &order_by.expr);
+            }
+            if let Some(frame) = &spec.window_frame {
+                for bound in [&frame.start_bound, &frame.end_bound] {
+                    if let WindowFrameBound::Preceding(Some(expr))
+                    | WindowFrameBound::Following(Some(expr)) = bound
+                    {
+                        walk_expr(self, expr);
+                    }
+                }
+            }
+        }
     }
 
     fn visit_case_when(
@@ -292,6 +324,10 @@ pub trait Visitor<'ast>: Sized {
         walk_expr(self, date);
     }
 
+    fn visit_lambda(&mut self, _span: Span, _params: &'ast [Identifier], expr: &'ast Expr) {
+        walk_expr(self, expr);
+    }
+
     fn visit_statement(&mut self, statement: &'ast Statement) {
         walk_statement(self, statement);
     }
@@ -338,6 +374,8 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_insert_source(&mut self, _insert_source: &'ast InsertSource) {}
 
+    fn visit_replace(&mut self, _replace: &'ast ReplaceStmt) {}
+
     fn visit_delete(
         &mut self,
         _table_reference: &'ast TableReference,
@@ -347,6 +385,14 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_update(&mut self, _update: &'ast UpdateStmt) {}
 
+    fn visit_merge_into(&mut self, _merge_into: &'ast MergeIntoStmt) {}
+
+    fn visit_begin(&mut self) {}
+
+    fn visit_commit(&mut self) {}
+
+    fn visit_abort(&mut self) {}
+
     fn visit_show_catalogs(&mut self, _stmt: &'ast ShowCatalogsStmt) {}
 
     fn visit_show_create_catalog(&mut self, _stmt: &'ast ShowCreateCatalogStmt) {}
@@ -405,6 +451,14 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_drop_view(&mut self, _stmt: &'ast DropViewStmt) {}
 
+    fn visit_create_stream(&mut self, _stmt: &'ast CreateStreamStmt) {}
+
+    fn visit_drop_stream(&mut self, _stmt: &'ast DropStreamStmt) {}
+
+    fn visit_create_aggregating_index(&mut self, _stmt: &'ast CreateAggregatingIndexStmt) {}
+
+    fn visit_drop_aggregating_index(&mut self, _stmt: &'ast DropAggregatingIndexStmt) {}
+
     fn visit_show_users(&mut self) {}
 
     fn visit_create_user(&mut self, _stmt: &'ast CreateUserStmt) {}
@@ -535,8 +589,20 @@ pub trait Visitor<'ast>: Sized {
             walk_expr(self, selection);
         }
 
-        for expr in group_by.iter() {
-            walk_expr(self, expr);
+        match group_by {
+            Some(GroupBy::Normal(exprs))
+            | Some(GroupBy::Rollup(exprs))
+            | Some(GroupBy::Cube(exprs)) => {
+                for expr in exprs.iter() {
+                    walk_expr(self, expr);
+                }
+            }
+            Some(GroupBy::GroupingSets(sets)) => {
+                for expr in sets.iter().flatten() {
+                    walk_expr(self, expr);
+                }
+            }
+            None => {}
         }
 
         if let Some(having) = having {
@@ -193,7 +193,11 @@ pub trait VisitorMut: Sized {
 
     fn visit_literal(&mut self, _span: Span, _lit: &mut Literal) {}
 
-    fn visit_count_all(&mut self, _span: Span) {}
+    fn visit_count_all(&mut self, _span: Span, window: &mut Option<Window>) {
+        if let Some(window) = window {
+            self.visit_window(window);
+        }
+    }
 
     fn visit_tuple(&mut self, _span: Span, elements: &mut [Expr]) {
         for elem in elements.iter_mut() {
@@ -208,10 +212,38 @@ pub trait VisitorMut: Sized {
         _name: &mut Identifier,
         args: &mut [Expr],
         _params: &mut [Literal],
+        order_by: &mut [OrderByExpr],
+        window: &mut Option<Window>,
     ) {
         for arg in args.iter_mut() {
             walk_expr_mut(self, arg);
         }
+        for order_by in order_by.iter_mut() {
+            self.visit_order_by(order_by);
+        }
+        if let Some(window) = window {
+            self.visit_window(window);
+        }
+    }
+
+    fn visit_window(&mut self, window: &mut Window) {
+        if let Window::WindowSpec(spec) = window {
+            for expr in spec.partition_by.iter_mut() {
+                walk_expr_mut(self, expr);
+            }
+            for order_by in spec.order_by.iter_mut() {
+                walk_expr_mut(self, &mut order_by.expr);
+            }
+            if let Some(frame) = &mut spec.window_frame {
+                for bound in [&mut frame.start_bound, &mut frame.end_bound] {
+                    if let WindowFrameBound::Preceding(Some(expr))
+                    | WindowFrameBound::Following(Some(expr)) = bound
+                    {
+                        walk_expr_mut(self, expr);
+                    }
+                }
+            }
+        }
     }
 
     fn visit_case_when(
@@ -296,6 +328,10 @@ pub trait VisitorMut: Sized {
         walk_expr_mut(self, date);
     }
 
+    fn visit_lambda(&mut self, _span: Span, _params: &mut Vec<Identifier>, expr: &mut Expr) {
+        walk_expr_mut(self, expr);
+    }
+
     fn visit_statement(&mut self, statement: &mut Statement) {
         walk_statement_mut(self, statement);
     }
@@ -344,6 +380,8 @@ pub trait VisitorMut: Sized {
 
     fn visit_insert_source(&mut self, _insert_source: &mut InsertSource) {}
 
+    fn visit_replace(&mut self, _replace: &mut ReplaceStmt) {}
+
     fn visit_delete(
         &mut self,
         _table_reference: &mut TableReference,
@@ -353,6 +391,14 @@ pub trait VisitorMut: Sized {
 
     fn visit_update(&mut self, _update: &mut UpdateStmt) {}
 
+    fn visit_merge_into(&mut self, _merge_into: &mut MergeIntoStmt) {}
+
+    fn visit_begin(&mut self) {}
+
+    fn visit_commit(&mut self) {}
+
+    fn visit_abort(&mut self) {}
+
     fn visit_show_catalogs(&mut self, _stmt: &mut ShowCatalogsStmt) {}
 
     fn visit_show_create_catalog(&mut self, _stmt: &mut ShowCreateCatalogStmt) {}
@@ -411,6 +457,14 @@ pub trait VisitorMut: Sized {
 
     fn visit_drop_view(&mut self, _stmt: &mut DropViewStmt) {}
 
+    fn visit_create_stream(&mut self, _stmt: &mut CreateStreamStmt) {}
+
+    fn visit_drop_stream(&mut self, _stmt: &mut DropStreamStmt) {}
+
+    fn visit_create_aggregating_index(&mut self, _stmt: &mut CreateAggregatingIndexStmt) {}
+
+    fn visit_drop_aggregating_index(&mut self, _stmt: &mut DropAggregatingIndexStmt) {}
+
     fn visit_show_users(&mut self) {}
 
     fn visit_create_user(&mut self, _stmt: &mut CreateUserStmt) {}
@@ -541,8 +595,20 @@ pub trait VisitorMut: Sized {
             walk_expr_mut(self, selection);
         }
 
-        for expr in group_by.iter_mut() {
-            walk_expr_mut(self, expr);
+        match group_by {
+            Some(GroupBy::Normal(exprs))
+            | Some(GroupBy::Rollup(exprs))
+            | Some(GroupBy::Cube(exprs)) => {
+                for expr in exprs.iter_mut() {
+                    walk_expr_mut(self, expr);
+                }
+            }
+            Some(GroupBy::GroupingSets(sets)) => {
+                for expr in sets.iter_mut().flatten() {
+                    walk_expr_mut(self, expr);
+                }
+            }
+            None => {}
         }
 
         if let Some(having) = having {
@@ -81,6 +81,7 @@ fn test_statement() {
         r#"create table if not exists a.b (c integer default 1 not null, b varchar) as select * from t;"#,
         r#"create table if not exists a.b (c tuple(m integer, n string), d tuple(integer, string));"#,
         r#"create table a.b like c.d;"#,
+        r#"create table a.b clone c.d at (snapshot => 'abc');"#,
         r#"create table t like t2 engine = memory;"#,
         r#"create table if not exists a.b (a int) 's3://testbucket/admin/data/' connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='http://127.0.0.1:9900');"#,
         r#"create table if not exists a.b (a int) 's3://testbucket/admin/data/'
@@ -104,6 +105,10 @@ fn test_statement() {
         r#"drop view v;"#,
         r#"create view v1(c1) as select number % 3 as a from numbers(1000);"#,
         r#"alter view v1(c2) as select number % 3 as a from numbers(1000);"#,
+        r#"create stream if not exists s on table db.t;"#,
+        r#"drop stream d.s;"#,
+        r#"create aggregating index if not exists idx as select a, sum(b) from t group by a;"#,
+        r#"drop aggregating index idx on db.t;"#,
         r#"rename table d.t to e.s;"#,
         r#"truncate table test;"#,
         r#"truncate table test_db.test;"#,
@@ -144,6 +149,12 @@ fn test_statement() {
         r#"select 1 from numbers(1) where ((1 = 1) or 1)"#,
         r#"select * from read_parquet('p1', 'p2', 'p3', prune_page => true, refresh_meta_cache => true);"#,
         r#"insert into t (c1, c2) values (1, 2), (3, 4);"#,
+        r#"replace into t (c1, c2) on conflict(c1) values (1, 2), (3, 4);"#,
+        r#"begin;"#,
+        r#"begin transaction;"#,
+        r#"start transaction;"#,
+        r#"commit;"#,
+        r#"rollback;"#,
         r#"insert into table t format json;"#,
         r#"insert into table t select * from t2;"#,
         r#"select parse_json('{"k1": [0, 1, 2]}').k1[0];"#,
@@ -163,6 +174,8 @@ fn test_statement() {
         r#"ALTER TABLE t RECLUSTER FINAL WHERE c1 > 0;"#,
         r#"ALTER TABLE t ADD COLUMN a float default 101 COMMENT 'hello';"#,
         r#"ALTER TABLE t DROP COLUMN b;"#,
+        r#"ALTER TABLE t RENAME COLUMN a TO b;"#,
+        r#"ALTER TABLE t MODIFY COLUMN b TYPE BIGINT;"#,
         r#"ALTER DATABASE IF EXISTS ctl.c RENAME TO a;"#,
         r#"ALTER DATABASE c RENAME TO a;"#,
         r#"ALTER DATABASE ctl.c RENAME TO a;"#,
@@ -353,6 +366,7 @@ fn test_statement() {
         r#"SHOW GRANTS ON DATABASE db;"#,
         r#"SHOW GRANTS OF SHARE t;"#,
         r#"UPDATE db1.tb1 set a = a + 1, b = 2 WHERE c > 3;"#,
+        r#"MERGE INTO t USING s ON t.a = s.a WHEN MATCHED AND s.b > 0 THEN UPDATE SET b = s.b WHEN MATCHED THEN DELETE WHEN NOT MATCHED THEN INSERT (a, b) VALUES (s.a, s.b);"#,
         r#"SET max_threads = 10;"#,
         r#"SET max_threads = 10*2;"#,
         r#"UNSET max_threads;"#,
@@ -443,6 +457,7 @@ fn test_query() {
         r#"with t2 as (select a from t) select t2.a from t2  where t2.a > 1"#,
         r#"with t2(tt) as (select a from t), t3 as (select * from t), t4 as (select a from t where a > 1) select t2.tt, t3.a, t4.a from t2, t3, t4 where t2.tt > 1"#,
         r#"with recursive t2(tt) as (select a from t1 union select tt from t2) select t2.tt from t2"#,
+        r#"with t2 as materialized (select a from t) select t2.a from t2  where t2.a > 1"#,
         r#"select c_count cc, count(*) as custdist, sum(c_acctbal) as totacctbal
             from customer, orders ODS,
                 (
@@ -466,6 +481,9 @@ fn test_query() {
         r#"select * from t1 union select * from t2 intersect select * from t3"#,
         r#"(select * from t1 union select * from t2) union select * from t3"#,
         r#"select * from t1 union (select * from t2 union select * from t3)"#,
+        r#"select sum(a) over w from t window w as (partition by b order by c range between unbounded preceding and current row)"#,
+        r#"select a, sum(b) from t group by grouping sets ((a), ())"#,
+        r#"select * from t, lateral flatten(t.payload, 'a') as f"#,
     ];
 
     for case in cases {
@@ -565,6 +583,14 @@ fn test_expr() {
         r#"1 is distinct from 2"#,
         r#"a is distinct from b"#,
         r#"1 is not distinct from null"#,
+        r#"ROW_NUMBER() OVER (PARTITION BY a ORDER BY b DESC ROWS BETWEEN 1 PRECEDING AND CURRENT ROW)"#,
+        r#"sum(a) OVER w"#,
+        r#"COUNT(*) OVER (ORDER BY a ROWS UNBOUNDED PRECEDING)"#,
+        r#"percentile_cont(0.5) WITHIN GROUP (ORDER BY a)"#,
+        r#"percentile_disc(0.25) WITHIN GROUP (ORDER BY a DESC)"#,
+        r#"array_agg(a ORDER BY b DESC NULLS FIRST)"#,
+        r#"array_transform(a, x -> x + 1)"#,
+        r#"array_reduce(a, (acc, x) -> acc + x)"#,
     ];
 
     for case in cases {
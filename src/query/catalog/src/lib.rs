@@ -19,8 +19,10 @@ pub mod catalog_kind;
 pub mod cluster_info;
 pub mod database;
 pub mod plan;
+pub mod runtime_filter_info;
 pub mod table;
 pub mod table_args;
 pub mod table_context;
 pub mod table_function;
 pub mod table_mutator;
+pub mod txn;
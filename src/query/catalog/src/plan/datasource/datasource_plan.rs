@@ -35,6 +35,10 @@ pub struct DataSourcePlan {
 
     pub tbl_args: Option<TableArgs>,
     pub push_downs: Option<PushDownInfo>,
+
+    /// The id of the table scan in the query, runtime filters pushed down
+    /// from hash joins are registered in the query context with this id.
+    pub scan_id: usize,
 }
 
 impl DataSourcePlan {
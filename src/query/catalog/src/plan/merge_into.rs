@@ -0,0 +1,64 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::FieldIndex;
+use common_expression::RemoteExpr;
+
+/// The source of `MERGE INTO`, which the blocks of the table are joined with.
+pub trait MergeIntoSource: Send + Sync {
+    /// The number of rows of the source.
+    fn num_rows(&self) -> usize;
+
+    /// Join a block of the table with the source, by the equi conditions and the other
+    /// conditions of the ON clause.
+    ///
+    /// Returns the indices of the matched rows in the block, and a block of the matched
+    /// pairs in the same order, with the columns of the table followed by the columns of
+    /// the source. The source rows are recorded as matched.
+    fn probe(&self, block: &DataBlock) -> Result<(Vec<u32>, DataBlock)>;
+
+    /// The source rows which are not matched by any row of the table, it can only be called
+    /// after all the blocks of the table have been probed.
+    fn unmatched_blocks(&self) -> Result<Vec<DataBlock>>;
+}
+
+/// The information needed by a table to apply `MERGE INTO`.
+///
+/// The expressions of the MATCHED clauses are evaluated on the matched pairs returned by
+/// [`MergeIntoSource::probe`], the expressions of the NOT MATCHED clauses are evaluated
+/// on the unmatched source rows.
+#[derive(Clone)]
+pub struct MergeIntoInfo {
+    pub source: Arc<dyn MergeIntoSource>,
+    pub matched: Vec<MergeMatchedOperation>,
+    pub unmatched: Vec<MergeUnmatchedOperation>,
+}
+
+#[derive(Debug, Clone)]
+pub struct MergeMatchedOperation {
+    pub condition: Option<RemoteExpr>,
+    /// `None` means the matched rows will be deleted.
+    pub update_list: Option<Vec<(FieldIndex, RemoteExpr)>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct MergeUnmatchedOperation {
+    pub condition: Option<RemoteExpr>,
+    /// One value for each field of the table.
+    pub values: Vec<RemoteExpr>,
+}
@@ -0,0 +1,96 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_expression::types::DataType;
+use common_expression::Scalar;
+use storages_common_index::filters::Xor8Filter;
+
+/// The maximum number of distinct build keys kept in the IN-list of a runtime filter.
+pub const MAX_RUNTIME_FILTER_INLIST_SIZE: usize = 1024;
+
+/// A filter on a column of a table scan, derived from the build side of a hash join
+/// after the hash table is built. Rows whose column value can not match any build key
+/// are pruned before they reach the join.
+#[derive(Clone)]
+pub struct RuntimeFilter {
+    /// The name of the column in the table schema.
+    pub column_name: String,
+    /// The non-nullable type of the build keys.
+    pub data_type: DataType,
+    /// The min and max value of the build keys, `None` if there is no non-null build key,
+    /// then all the rows of the scan can be pruned.
+    pub min_max: Option<(Scalar, Scalar)>,
+    /// The distinct build keys, `None` if there are more than `MAX_RUNTIME_FILTER_INLIST_SIZE`.
+    pub inlist: Option<Vec<Scalar>>,
+    /// The xor8 filters of the siphash digests of the build keys. A value may match if any of
+    /// the filters contains it, there are several filters if the runtime filter is merged from
+    /// the build sides of several nodes.
+    pub blooms: Vec<Arc<Xor8Filter>>,
+}
+
+impl RuntimeFilter {
+    /// Merge the runtime filter derived from another part of the build side.
+    pub fn merge(&mut self, other: RuntimeFilter) {
+        self.min_max = match (self.min_max.take(), other.min_max) {
+            (Some((min, max)), Some((other_min, other_max))) => {
+                Some((min.min(other_min), max.max(other_max)))
+            }
+            (min_max, None) | (None, min_max) => min_max,
+        };
+        self.inlist = match (self.inlist.take(), other.inlist) {
+            (Some(mut inlist), Some(other_inlist)) => {
+                inlist.extend(other_inlist);
+                inlist.sort();
+                inlist.dedup();
+                (inlist.len() <= MAX_RUNTIME_FILTER_INLIST_SIZE).then_some(inlist)
+            }
+            _ => None,
+        };
+        self.blooms.extend(other.blooms);
+    }
+}
+
+/// The runtime filters pushed down to a table scan.
+#[derive(Clone, Default)]
+pub struct RuntimeFilterInfo {
+    filters: Vec<RuntimeFilter>,
+}
+
+impl RuntimeFilterInfo {
+    pub fn add(&mut self, filter: RuntimeFilter) {
+        self.filters.push(filter);
+    }
+
+    pub fn extend(&mut self, other: RuntimeFilterInfo) {
+        self.filters.extend(other.filters);
+    }
+
+    pub fn filters(&self) -> &[RuntimeFilter] {
+        &self.filters
+    }
+
+    pub fn len(&self) -> usize {
+        self.filters.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.filters.is_empty()
+    }
+}
+
+/// The runtime filters of a query, keyed by the `scan_id` of the table scans.
+pub type RuntimeFilters = HashMap<usize, RuntimeFilterInfo>;
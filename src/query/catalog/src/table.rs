@@ -23,6 +23,7 @@ use common_exception::Result;
 use common_expression::BlockThresholds;
 use common_expression::ColumnId;
 use common_expression::DataBlock;
+use common_expression::FieldIndex;
 use common_expression::RemoteExpr;
 use common_expression::Scalar;
 use common_expression::TableSchema;
@@ -37,6 +38,8 @@ use common_storage::StorageMetrics;
 
 use crate::plan::DataSourceInfo;
 use crate::plan::DataSourcePlan;
+use crate::plan::MergeIntoInfo;
+use crate::plan::MergeIntoSource;
 use crate::plan::PartStatistics;
 use crate::plan::Partitions;
 use crate::plan::PushDownInfo;
@@ -108,6 +111,17 @@ pub trait Table: Sync + Send {
         false
     }
 
+    /// The table of the data of the aggregating index `index_id`, which replaces the table
+    /// in the queries answered by the index.
+    fn aggregating_index_table(&self, index_id: &str) -> Result<Arc<dyn Table>> {
+        Err(ErrorCode::Unimplemented(format!(
+            "table {}, of engine type {}, does not support aggregating index {}",
+            self.name(),
+            self.engine(),
+            index_id
+        )))
+    }
+
     async fn alter_table_cluster_keys(
         &self,
         ctx: Arc<dyn TableContext>,
@@ -260,6 +274,42 @@ pub trait Table: Sync + Send {
         )))
     }
 
+    async fn merge_into(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        merge_into_info: MergeIntoInfo,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let (_, _, _) = (ctx, merge_into_info, pipeline);
+
+        Err(ErrorCode::Unimplemented(format!(
+            "table {},  of engine type {}, does not support MERGE INTO",
+            self.name(),
+            self.get_table_info().engine(),
+        )))
+    }
+
+    /// Remove the rows whose `on_conflict_fields` are equal to the rows of `source`, and
+    /// insert all the rows of `source`. The columns of `source` are the fields of the table,
+    /// it is joined with the table by `on_conflict_fields`. `conflict_keys` are the values
+    /// of `on_conflict_fields` of the source rows, except the ones containing NULL.
+    async fn replace_into(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        source: Arc<dyn MergeIntoSource>,
+        on_conflict_fields: Vec<FieldIndex>,
+        conflict_keys: Vec<Vec<Scalar>>,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let (_, _, _, _, _) = (ctx, source, on_conflict_fields, conflict_keys, pipeline);
+
+        Err(ErrorCode::Unimplemented(format!(
+            "table {},  of engine type {}, does not support REPLACE INTO",
+            self.name(),
+            self.get_table_info().engine(),
+        )))
+    }
+
     fn get_block_compact_thresholds(&self) -> BlockThresholds {
         BlockThresholds {
             max_rows_per_block: DEFAULT_BLOCK_MAX_ROWS,
@@ -360,6 +410,17 @@ pub struct ColumnStatistics {
     pub max: Scalar,
     pub null_count: u64,
     pub number_of_distinct_values: u64,
+    /// collected by `ANALYZE TABLE`, if any
+    pub histogram: Option<ColumnHistogram>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ColumnHistogram {
+    /// (upper bound, number of values, number of distinct values) of each bucket,
+    /// the first bucket only holds the min value.
+    pub buckets: Vec<(Scalar, f64, f64)>,
+    /// most common values and their estimated number of rows.
+    pub most_common_values: Vec<(Scalar, f64)>,
 }
 
 pub enum CompactTarget {
@@ -29,6 +29,7 @@ use common_io::prelude::FormatSettings;
 use common_meta_app::principal::FileFormatOptions;
 use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserInfo;
+use common_meta_app::schema::UpdateTableMetaReq;
 use common_settings::Settings;
 use common_storage::DataOperator;
 use common_storage::StorageMetrics;
@@ -38,7 +39,9 @@ use crate::cluster_info::Cluster;
 use crate::plan::DataSourcePlan;
 use crate::plan::PartInfoPtr;
 use crate::plan::Partitions;
+use crate::runtime_filter_info::RuntimeFilterInfo;
 use crate::table::Table;
+use crate::txn::TxnManagerRef;
 
 #[derive(Debug)]
 pub struct ProcessInfo {
@@ -121,6 +124,17 @@ pub trait TableContext: Send + Sync {
     fn get_data_operator(&self) -> Result<DataOperator>;
     fn push_precommit_block(&self, block: DataBlock);
     fn consume_precommit_blocks(&self) -> Vec<DataBlock>;
+    // Get the explicit transaction of the session.
+    fn txn_mgr(&self) -> TxnManagerRef;
+
+    /// Push down runtime filters to the table scan with the `scan_id`.
+    fn set_runtime_filter(&self, filters: (usize, RuntimeFilterInfo));
+    fn get_runtime_filter_with_id(&self, id: usize) -> Option<RuntimeFilterInfo>;
+
+    /// Record the new offset of a stream read by the query, which is committed
+    /// atomically with the table the query writes to.
+    fn add_stream_offset(&self, catalog: &str, req: UpdateTableMetaReq);
+    fn get_stream_offsets(&self) -> Vec<(String, UpdateTableMetaReq)>;
 
     async fn get_file_format(&self, name: &str) -> Result<FileFormatOptions>;
 
@@ -0,0 +1,125 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::UpdateTableMetaReq;
+use parking_lot::Mutex;
+
+pub type TxnManagerRef = Arc<Mutex<TxnManager>>;
+
+/// (catalog, database, table)
+type TableKey = (String, String, String);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TxnState {
+    /// Every statement commits its changes immediately.
+    #[default]
+    AutoCommit,
+    /// In an explicit transaction started by `BEGIN`.
+    Active,
+}
+
+/// The explicit transaction of a session.
+///
+/// Inside a transaction, a table is read at the version when it is first accessed, and
+/// the new table metas of the mutations are buffered instead of being committed, so the
+/// following statements see the changes of the transaction but others do not. `COMMIT`
+/// updates the metas of all the mutated tables in one meta transaction, which fails if
+/// any of the tables has been changed by others since it was first accessed.
+#[derive(Debug, Default)]
+pub struct TxnManager {
+    state: TxnState,
+    /// The tables accessed in the transaction.
+    tables: HashMap<TableKey, TableInfo>,
+    /// The pending updates of the mutated tables, keyed by the table id.
+    mutated: BTreeMap<u64, (String, UpdateTableMetaReq)>,
+}
+
+impl TxnManager {
+    pub fn init() -> TxnManagerRef {
+        Arc::new(Mutex::new(TxnManager::default()))
+    }
+
+    pub fn state(&self) -> TxnState {
+        self.state
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.state == TxnState::Active
+    }
+
+    pub fn begin(&mut self) -> Result<()> {
+        if self.is_active() {
+            return Err(ErrorCode::InvalidTransaction(
+                "There is already a transaction in progress",
+            ));
+        }
+        self.state = TxnState::Active;
+        Ok(())
+    }
+
+    /// Clear the transaction and return to the auto commit mode.
+    pub fn clear(&mut self) {
+        self.state = TxnState::AutoCommit;
+        self.tables.clear();
+        self.mutated.clear();
+    }
+
+    pub fn get_table_info(&self, catalog: &str, database: &str, table: &str) -> Option<TableInfo> {
+        self.tables
+            .get(&(catalog.to_string(), database.to_string(), table.to_string()))
+            .cloned()
+    }
+
+    /// Remember the version of the table read in the transaction.
+    pub fn add_table_info(
+        &mut self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+        table_info: &TableInfo,
+    ) {
+        let key = (catalog.to_string(), database.to_string(), table.to_string());
+        self.tables.entry(key).or_insert_with(|| table_info.clone());
+    }
+
+    /// Buffer the new meta of a table, which will be committed with `COMMIT`.
+    ///
+    /// `req.seq` is the version of the table when it is first accessed in the transaction,
+    /// so the same table can be mutated several times.
+    pub fn update_table_meta(&mut self, catalog: &str, req: UpdateTableMetaReq) {
+        for table_info in self.tables.values_mut() {
+            if table_info.ident.table_id == req.table_id {
+                table_info.meta = req.new_table_meta.clone();
+            }
+        }
+        self.mutated
+            .insert(req.table_id, (catalog.to_string(), req));
+    }
+
+    /// The pending updates grouped by the catalog.
+    pub fn mutated_tables(&self) -> BTreeMap<String, Vec<UpdateTableMetaReq>> {
+        let mut reqs: BTreeMap<String, Vec<UpdateTableMetaReq>> = BTreeMap::new();
+        for (catalog, req) in self.mutated.values() {
+            reqs.entry(catalog.clone()).or_default().push(req.clone());
+        }
+        reqs
+    }
+}
@@ -1172,6 +1172,11 @@ pub struct QueryConfig {
     #[clap(long, default_value = "")]
     pub share_endpoint_auth_token_file: String,
 
+    /// The directory of the temporary files spilled by queries, `<temp dir>/databend/_query_spill`
+    /// if it's empty.
+    #[clap(long, default_value = "")]
+    pub spill_path: String,
+
     #[clap(skip)]
     quota: Option<TenantQuota>,
 
@@ -1289,6 +1294,7 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             },
             share_endpoint_address: self.share_endpoint_address,
             share_endpoint_auth_token_file: self.share_endpoint_auth_token_file,
+            spill_path: self.spill_path,
             tenant_quota: self.quota,
             internal_enable_sandbox_tenant: self.internal_enable_sandbox_tenant,
             internal_merge_on_read_mutation: self.internal_merge_on_read_mutation,
@@ -1348,6 +1354,7 @@ impl From<InnerQueryConfig> for QueryConfig {
             users: users_from_inner(inner.idm.users),
             share_endpoint_address: inner.share_endpoint_address,
             share_endpoint_auth_token_file: inner.share_endpoint_auth_token_file,
+            spill_path: inner.spill_path,
             quota: inner.tenant_quota,
             internal_enable_sandbox_tenant: inner.internal_enable_sandbox_tenant,
             internal_merge_on_read_mutation: false,
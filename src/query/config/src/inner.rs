@@ -163,6 +163,8 @@ pub struct QueryConfig {
     pub idm: IDMConfig,
     pub share_endpoint_address: String,
     pub share_endpoint_auth_token_file: String,
+    /// The directory of the temporary files spilled by queries.
+    pub spill_path: String,
     pub tenant_quota: Option<TenantQuota>,
     pub internal_enable_sandbox_tenant: bool,
     pub internal_merge_on_read_mutation: bool,
@@ -212,6 +214,7 @@ impl Default for QueryConfig {
             idm: IDMConfig::default(),
             share_endpoint_address: "".to_string(),
             share_endpoint_auth_token_file: "".to_string(),
+            spill_path: "".to_string(),
             tenant_quota: None,
             internal_enable_sandbox_tenant: false,
             internal_merge_on_read_mutation: false,
@@ -127,6 +127,7 @@ pub fn from_scalar(datavalue: &DataValue, datatype: &DataType) -> Scalar {
         DataType::Timestamp => Scalar::Timestamp(*datavalue.as_int64().unwrap()),
         DataType::Date => Scalar::Date(*datavalue.as_int64().unwrap() as i32),
         DataType::String => Scalar::String(datavalue.as_string().unwrap().to_vec()),
+        DataType::Bitmap => Scalar::Bitmap(datavalue.as_string().unwrap().to_vec()),
         DataType::Variant => match datavalue {
             DataValue::String(x) => Scalar::Variant(x.clone()),
             DataValue::Variant(x) => {
@@ -15,6 +15,7 @@
 use common_io::prelude::*;
 
 mod array;
+mod bitmap;
 mod boolean;
 mod date;
 mod decimal;
@@ -27,6 +28,7 @@ mod tuple;
 mod variant;
 
 pub use array::*;
+pub use bitmap::*;
 pub use boolean::*;
 use common_exception::Result;
 pub use date::*;
@@ -102,4 +104,5 @@ pub enum TypeDeserializerImpl {
     String(StringDeserializer),
     Struct(StructDeserializer),
     Variant(VariantDeserializer),
+    Bitmap(BitmapDeserializer),
 }
@@ -17,6 +17,9 @@ use std::collections::HashMap;
 use std::sync::Mutex;
 
 use common_arrow::arrow::bitmap;
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_arrow::arrow::buffer::Buffer;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::Span;
@@ -166,6 +169,14 @@ impl<'a> Evaluator<'a> {
                     self.run_cast(*span, expr.data_type(), dest_type, value)
                 }
             }
+            Expr::LambdaFunctionCall {
+                span,
+                name,
+                args,
+                lambda_expr,
+                return_type,
+                ..
+            } => self.run_lambda(*span, name, &args[0], lambda_expr, return_type),
         };
 
         #[cfg(debug_assertions)]
@@ -616,6 +627,214 @@ impl<'a> Evaluator<'a> {
         let evaluator = Evaluator::new(&block, self.func_ctx, self.fn_registry);
         Ok(Some(evaluator.run(&cast_expr)?))
     }
+
+    /// Evaluates a higher-order array function by flattening the array column, so that
+    /// the lambda is evaluated on all the elements at once instead of row by row.
+    fn run_lambda(
+        &self,
+        span: Span,
+        name: &str,
+        arg: &Expr,
+        lambda_expr: &Expr,
+        return_type: &DataType,
+    ) -> Result<Value<AnyType>> {
+        let (is_scalar, column) = match self.run(arg)? {
+            Value::Scalar(scalar) => (
+                true,
+                ColumnBuilder::repeat(&scalar.as_ref(), 1, arg.data_type()).build(),
+            ),
+            Value::Column(column) => (false, column),
+        };
+        let (column, validity) = match column {
+            Column::Nullable(box NullableColumn { column, validity }) => (column, Some(validity)),
+            column => (column, None),
+        };
+        let array = match column {
+            Column::Array(array) => *array,
+            _ => {
+                return Err(ErrorCode::Internal(format!(
+                    "function `{name}` expects an array argument, but got {}",
+                    arg.data_type()
+                ))
+                .set_span(span));
+            }
+        };
+        let elem_type = match arg.data_type().remove_nullable() {
+            DataType::Array(elem_type) => *elem_type,
+            _ => unreachable!(),
+        };
+
+        // Make the offsets start from zero, so that they can index the flattened values directly.
+        let start = array.offsets[0];
+        let end = array.offsets[array.len()];
+        let values = array.values.slice(start as usize..end as usize);
+        let offsets: Buffer<u64> = array.offsets.iter().map(|offset| offset - start).collect();
+        let lens = offsets
+            .windows(2)
+            .enumerate()
+            .map(|(row, w)| match &validity {
+                Some(validity) if !validity.get_bit(row) => 0,
+                _ => (w[1] - w[0]) as usize,
+            })
+            .collect::<Vec<_>>();
+
+        let column = match name {
+            "array_transform" => {
+                let values = self.run_lambda_expr(lambda_expr, vec![(elem_type, values)])?;
+                Column::Array(Box::new(ArrayColumn { values, offsets }))
+            }
+            "array_filter" => {
+                let predicate =
+                    self.run_lambda_predicate(lambda_expr, elem_type, values.clone())?;
+                let mut new_offsets = Vec::with_capacity(offsets.len());
+                new_offsets.push(0);
+                let mut len = 0;
+                for w in offsets.windows(2) {
+                    len += (w[0]..w[1])
+                        .filter(|i| predicate.get_bit(*i as usize))
+                        .count() as u64;
+                    new_offsets.push(len);
+                }
+                Column::Array(Box::new(ArrayColumn {
+                    values: values.filter(&predicate),
+                    offsets: new_offsets.into(),
+                }))
+            }
+            "array_any" | "array_all" => {
+                let predicate = self.run_lambda_predicate(lambda_expr, elem_type, values)?;
+                let is_any = name == "array_any";
+                let result: MutableBitmap = offsets
+                    .windows(2)
+                    .map(|w| {
+                        let mut range = w[0] as usize..w[1] as usize;
+                        if is_any {
+                            range.any(|i| predicate.get_bit(i))
+                        } else {
+                            range.all(|i| predicate.get_bit(i))
+                        }
+                    })
+                    .collect();
+                Column::Boolean(result.into())
+            }
+            "array_reduce" => {
+                self.run_lambda_reduce(lambda_expr, elem_type, values, &offsets, &lens)?
+            }
+            _ => {
+                return Err(
+                    ErrorCode::Internal(format!("unknown lambda function `{name}`")).set_span(span),
+                );
+            }
+        };
+
+        // `array_reduce` always returns a nullable value, and the NULL rows
+        // of the input have already been treated as empty arrays.
+        let column = match validity {
+            Some(validity) if name != "array_reduce" => {
+                Column::Nullable(Box::new(NullableColumn { column, validity }))
+            }
+            _ => column,
+        };
+        debug_assert_eq!(&column.data_type(), return_type);
+
+        if is_scalar {
+            Ok(Value::Scalar(column.index(0).unwrap().to_owned()))
+        } else {
+            Ok(Value::Column(column))
+        }
+    }
+
+    /// Evaluates `lambda_expr` with the given columns as its parameters.
+    fn run_lambda_expr(
+        &self,
+        lambda_expr: &Expr,
+        params: Vec<(DataType, Column)>,
+    ) -> Result<Column> {
+        let num_rows = params[0].1.len();
+        let entries = params
+            .into_iter()
+            .map(|(data_type, column)| BlockEntry {
+                data_type,
+                value: Value::Column(column),
+            })
+            .collect();
+        let block = DataBlock::new(entries, num_rows);
+        let evaluator = Evaluator::new(&block, self.func_ctx, self.fn_registry);
+        let result = evaluator.run(lambda_expr)?;
+        Ok(result.convert_to_full_column(lambda_expr.data_type(), num_rows))
+    }
+
+    /// Evaluates a predicate lambda on the elements, treating NULL as `false`.
+    fn run_lambda_predicate(
+        &self,
+        lambda_expr: &Expr,
+        elem_type: DataType,
+        values: Column,
+    ) -> Result<Bitmap> {
+        let num_rows = values.len();
+        match self.run_lambda_expr(lambda_expr, vec![(elem_type, values)])? {
+            Column::Boolean(predicate) => Ok(predicate),
+            Column::Nullable(box NullableColumn {
+                column: Column::Boolean(predicate),
+                validity,
+            }) => Ok(&predicate & &validity),
+            Column::Null { .. } => Ok(constant_bitmap(false, num_rows).into()),
+            _ => Err(ErrorCode::Internal(format!(
+                "lambda `{}` should return a boolean value",
+                lambda_expr.sql_display()
+            ))),
+        }
+    }
+
+    /// Folds the elements of each array from left to right.
+    ///
+    /// The rows are sorted by the length of the array in descending order, so in the
+    /// `k`-th step the rows that still have elements left form a prefix of the accumulator,
+    /// and the lambda can be evaluated on them together.
+    fn run_lambda_reduce(
+        &self,
+        lambda_expr: &Expr,
+        elem_type: DataType,
+        values: Column,
+        offsets: &[u64],
+        lens: &[usize],
+    ) -> Result<Column> {
+        let mut rows = (0..lens.len())
+            .filter(|row| lens[*row] > 0)
+            .collect::<Vec<_>>();
+        rows.sort_by(|a, b| lens[*b].cmp(&lens[*a]));
+
+        let first_indices = rows
+            .iter()
+            .map(|row| offsets[*row] as u32)
+            .collect::<Vec<_>>();
+        let mut acc = values.take(&first_indices);
+        let max_len = rows.first().map(|row| lens[*row]).unwrap_or(0);
+        for k in 1..max_len {
+            let num_active = rows.partition_point(|row| lens[*row] > k);
+            let indices = rows[..num_active]
+                .iter()
+                .map(|row| (offsets[*row] as usize + k) as u32)
+                .collect::<Vec<_>>();
+            let step = self.run_lambda_expr(lambda_expr, vec![
+                (elem_type.clone(), acc.slice(0..num_active)),
+                (elem_type.clone(), values.take(&indices)),
+            ])?;
+            acc = Column::concat(&[step, acc.slice(num_active..acc.len())]);
+        }
+
+        let mut positions = vec![None; lens.len()];
+        for (pos, row) in rows.iter().enumerate() {
+            positions[*row] = Some(pos);
+        }
+        let mut builder = ColumnBuilder::with_capacity(&elem_type.wrap_nullable(), lens.len());
+        for pos in positions {
+            match pos {
+                Some(pos) => builder.push(acc.index(pos).unwrap()),
+                None => builder.push_default(),
+            }
+        }
+        Ok(builder.build())
+    }
 }
 
 pub struct ConstantFolder<'a, Index: ColumnIndex> {
@@ -832,6 +1051,50 @@ impl<'a, Index: ColumnIndex> ConstantFolder<'a, Index> {
 
                 (func_expr, func_domain)
             }
+            Expr::LambdaFunctionCall {
+                span,
+                name,
+                args,
+                lambda_expr,
+                lambda_display,
+                return_type,
+            } => {
+                let args_expr = args
+                    .iter()
+                    .map(|arg| self.fold_once(arg).0)
+                    .collect::<Vec<_>>();
+                let all_args_is_scalar = args_expr.iter().all(|arg| arg.as_constant().is_some());
+
+                let func_expr = Expr::LambdaFunctionCall {
+                    span: *span,
+                    name: name.clone(),
+                    args: args_expr,
+                    lambda_expr: lambda_expr.clone(),
+                    lambda_display: lambda_display.clone(),
+                    return_type: return_type.clone(),
+                };
+
+                if all_args_is_scalar {
+                    let block = DataBlock::empty();
+                    let evaluator = Evaluator::new(&block, self.func_ctx, self.fn_registry);
+                    // Since we know the expression is constant, it'll be safe to change its column index type.
+                    let func_expr = func_expr.project_column_ref(|_| unreachable!());
+                    if let Ok(Value::Scalar(scalar)) = evaluator.run(&func_expr) {
+                        let domain = scalar.as_ref().domain(return_type);
+                        return (
+                            Expr::Constant {
+                                span: *span,
+                                scalar,
+                                data_type: return_type.clone(),
+                            },
+                            Some(domain),
+                        );
+                    }
+                }
+
+                // The lambda may fail on any element, so the domain is unknown.
+                (func_expr, None)
+            }
         };
 
         debug_assert_eq!(expr.data_type(), new_expr.data_type());
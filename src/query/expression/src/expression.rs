@@ -68,6 +68,14 @@ pub enum RawExpr<Index: ColumnIndex = usize> {
         params: Vec<usize>,
         args: Vec<RawExpr<Index>>,
     },
+    LambdaFunctionCall {
+        span: Span,
+        name: String,
+        args: Vec<RawExpr<Index>>,
+        lambda_expr: Box<RemoteExpr>,
+        lambda_display: String,
+        return_type: DataType,
+    },
 }
 
 /// A type-checked and ready to be evaluated expression, having all overloads chosen for function calls.
@@ -103,6 +111,17 @@ pub enum Expr<Index: ColumnIndex = usize> {
         args: Vec<Expr<Index>>,
         return_type: DataType,
     },
+    /// A higher-order function, like `array_transform`, that evaluates `lambda_expr`
+    /// on the elements of its first argument. The parameters of the lambda are
+    /// referred to by their positions in `lambda_expr`.
+    LambdaFunctionCall {
+        span: Span,
+        name: String,
+        args: Vec<Expr<Index>>,
+        lambda_expr: Box<Expr>,
+        lambda_display: String,
+        return_type: DataType,
+    },
 }
 
 /// Serializable expression used to share executable expression between nodes.
@@ -137,6 +156,14 @@ pub enum RemoteExpr<Index: ColumnIndex = usize> {
         args: Vec<RemoteExpr<Index>>,
         return_type: DataType,
     },
+    LambdaFunctionCall {
+        span: Span,
+        name: String,
+        args: Vec<RemoteExpr<Index>>,
+        lambda_expr: Box<RemoteExpr>,
+        lambda_display: String,
+        return_type: DataType,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, EnumAsInner)]
@@ -206,7 +233,9 @@ impl<Index: ColumnIndex> RawExpr<Index> {
                     buf.insert(id.clone(), data_type.clone());
                 }
                 RawExpr::Cast { expr, .. } => walk(expr, buf),
-                RawExpr::FunctionCall { args, .. } => args.iter().for_each(|expr| walk(expr, buf)),
+                RawExpr::FunctionCall { args, .. } | RawExpr::LambdaFunctionCall { args, .. } => {
+                    args.iter().for_each(|expr| walk(expr, buf))
+                }
                 RawExpr::Literal { .. } => (),
             }
         }
@@ -245,6 +274,23 @@ impl<Index: ColumnIndex> RawExpr<Index> {
                 s += ")";
                 s
             }
+            RawExpr::LambdaFunctionCall {
+                name,
+                args,
+                lambda_display,
+                ..
+            } => {
+                let mut s = String::new();
+                s += name;
+                s += "(";
+                for arg in args {
+                    s += &arg.sql_display();
+                    s += ", ";
+                }
+                s += lambda_display;
+                s += ")";
+                s
+            }
         }
     }
 }
@@ -256,6 +302,7 @@ impl<Index: ColumnIndex> Expr<Index> {
             Expr::ColumnRef { data_type, .. } => data_type,
             Expr::Cast { dest_type, .. } => dest_type,
             Expr::FunctionCall { return_type, .. } => return_type,
+            Expr::LambdaFunctionCall { return_type, .. } => return_type,
         }
     }
 
@@ -266,7 +313,9 @@ impl<Index: ColumnIndex> Expr<Index> {
                     buf.insert(id.clone(), data_type.clone());
                 }
                 Expr::Cast { expr, .. } => walk(expr, buf),
-                Expr::FunctionCall { args, .. } => args.iter().for_each(|expr| walk(expr, buf)),
+                Expr::FunctionCall { args, .. } | Expr::LambdaFunctionCall { args, .. } => {
+                    args.iter().for_each(|expr| walk(expr, buf))
+                }
                 Expr::Constant { .. } => (),
             }
         }
@@ -305,6 +354,23 @@ impl<Index: ColumnIndex> Expr<Index> {
                 s += ")";
                 s
             }
+            Expr::LambdaFunctionCall {
+                name,
+                args,
+                lambda_display,
+                ..
+            } => {
+                let mut s = String::new();
+                s += name;
+                s += "(";
+                for arg in args {
+                    s += &arg.sql_display();
+                    s += ", ";
+                }
+                s += lambda_display;
+                s += ")";
+                s
+            }
         }
     }
 
@@ -359,6 +425,21 @@ impl<Index: ColumnIndex> Expr<Index> {
                 args: args.iter().map(|expr| expr.project_column_ref(f)).collect(),
                 return_type: return_type.clone(),
             },
+            Expr::LambdaFunctionCall {
+                span,
+                name,
+                args,
+                lambda_expr,
+                lambda_display,
+                return_type,
+            } => Expr::LambdaFunctionCall {
+                span: *span,
+                name: name.clone(),
+                args: args.iter().map(|expr| expr.project_column_ref(f)).collect(),
+                lambda_expr: lambda_expr.clone(),
+                lambda_display: lambda_display.clone(),
+                return_type: return_type.clone(),
+            },
         }
     }
 
@@ -409,6 +490,21 @@ impl<Index: ColumnIndex> Expr<Index> {
                 args: args.iter().map(Expr::as_remote_expr).collect(),
                 return_type: return_type.clone(),
             },
+            Expr::LambdaFunctionCall {
+                span,
+                name,
+                args,
+                lambda_expr,
+                lambda_display,
+                return_type,
+            } => RemoteExpr::LambdaFunctionCall {
+                span: *span,
+                name: name.clone(),
+                args: args.iter().map(Expr::as_remote_expr).collect(),
+                lambda_expr: Box::new(lambda_expr.as_remote_expr()),
+                lambda_display: lambda_display.clone(),
+                return_type: return_type.clone(),
+            },
         }
     }
 
@@ -421,6 +517,9 @@ impl<Index: ColumnIndex> Expr<Index> {
                 !function.signature.property.non_deterministic
                     && args.iter().all(|arg| arg.is_deterministic())
             }
+            Expr::LambdaFunctionCall {
+                args, lambda_expr, ..
+            } => args.iter().all(|arg| arg.is_deterministic()) && lambda_expr.is_deterministic(),
         }
     }
 }
@@ -476,6 +575,21 @@ impl<Index: ColumnIndex> RemoteExpr<Index> {
                     return_type: return_type.clone(),
                 }
             }
+            RemoteExpr::LambdaFunctionCall {
+                span,
+                name,
+                args,
+                lambda_expr,
+                lambda_display,
+                return_type,
+            } => Expr::LambdaFunctionCall {
+                span: *span,
+                name: name.clone(),
+                args: args.iter().map(|arg| arg.as_expr(fn_registry)).collect(),
+                lambda_expr: Box::new(lambda_expr.as_expr(fn_registry)),
+                lambda_display: lambda_display.clone(),
+                return_type: return_type.clone(),
+            },
         }
     }
 }
@@ -24,6 +24,7 @@ use crate::types::string::StringColumnBuilder;
 use crate::types::AnyType;
 use crate::types::ArgType;
 use crate::types::ArrayType;
+use crate::types::BitmapType;
 use crate::types::BooleanType;
 use crate::types::DateType;
 use crate::types::EmptyArrayType;
@@ -184,6 +185,11 @@ impl Column {
                 let builder = StringColumnBuilder::with_capacity(capacity, data_capacity);
                 Self::concat_value_types::<VariantType>(builder, columns)
             }
+            Column::Bitmap(_) => {
+                let data_capacity = columns.iter().map(|c| c.memory_size() - c.len() * 8).sum();
+                let builder = StringColumnBuilder::with_capacity(capacity, data_capacity);
+                Self::concat_value_types::<BitmapType>(builder, columns)
+            }
         }
     }
 
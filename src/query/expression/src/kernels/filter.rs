@@ -27,6 +27,7 @@ use crate::types::string::StringColumn;
 use crate::types::string::StringColumnBuilder;
 use crate::types::AnyType;
 use crate::types::ArrayType;
+use crate::types::BitmapType;
 use crate::types::BooleanType;
 use crate::types::ValueType;
 use crate::types::VariantType;
@@ -172,6 +173,16 @@ impl Column {
                     filter,
                 )
             }
+            Column::Bitmap(column) => {
+                let bytes_per_row = column.data.len() / filter.len().max(1);
+                let data_capacity = (filter.len() - filter.unset_bits()) * bytes_per_row;
+
+                Self::filter_scalar_types::<BitmapType>(
+                    column,
+                    StringColumnBuilder::with_capacity(length, data_capacity),
+                    filter,
+                )
+            }
         }
     }
 
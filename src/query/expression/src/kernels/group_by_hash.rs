@@ -524,7 +524,7 @@ pub fn serialize_column_binary(column: &Column, row: usize, vec: &mut Vec<u8>) {
                 serialize_column_binary(inner_col, row, vec);
             }
         }
-        Column::Variant(v) => {
+        Column::Variant(v) | Column::Bitmap(v) => {
             BinaryWrite::write_binary(vec, unsafe { v.index_unchecked(row) }).unwrap()
         }
     }
@@ -23,6 +23,7 @@ use crate::types::number::NumberColumn;
 use crate::types::string::StringColumnBuilder;
 use crate::types::AnyType;
 use crate::types::ArrayType;
+use crate::types::BitmapType;
 use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
@@ -253,6 +254,12 @@ impl Column {
                 indices,
                 scatter_size,
             ),
+            Column::Bitmap(column) => Self::scatter_scalars::<BitmapType, _>(
+                column,
+                StringColumnBuilder::with_capacity(length, 0),
+                indices,
+                scatter_size,
+            ),
         }
     }
 
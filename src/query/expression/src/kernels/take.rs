@@ -22,6 +22,7 @@ use crate::types::number::NumberColumn;
 use crate::types::AnyType;
 use crate::types::ArgType;
 use crate::types::ArrayType;
+use crate::types::BitmapType;
 use crate::types::BooleanType;
 use crate::types::NumberType;
 use crate::types::StringType;
@@ -130,6 +131,7 @@ impl Column {
                 }
             }
             Column::Variant(column) => Self::take_arg_types::<VariantType, _>(column, indices),
+            Column::Bitmap(column) => Self::take_arg_types::<BitmapType, _>(column, indices),
         }
     }
 
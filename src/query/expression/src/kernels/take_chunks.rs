@@ -22,6 +22,7 @@ use crate::types::number::NumberColumn;
 use crate::types::AnyType;
 use crate::types::ArgType;
 use crate::types::ArrayType;
+use crate::types::BitmapType;
 use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
@@ -317,6 +318,10 @@ impl Column {
                 let builder = VariantType::create_builder(result_size, &[]);
                 Self::take_block_value_types::<VariantType>(columns, builder, indices)
             }
+            Column::Bitmap(_) => {
+                let builder = BitmapType::create_builder(result_size, &[]);
+                Self::take_block_value_types::<BitmapType>(columns, builder, indices)
+            }
         }
     }
 
@@ -176,7 +176,9 @@ impl Domain {
             }
             DataType::EmptyArray => Domain::Array(None),
             DataType::Array(ty) => Domain::Array(Some(Box::new(Domain::full(ty)))),
-            DataType::EmptyMap | DataType::Map(_) | DataType::Variant => Domain::Undefined,
+            DataType::EmptyMap | DataType::Map(_) | DataType::Variant | DataType::Bitmap => {
+                Domain::Undefined
+            }
             DataType::Generic(_) => unreachable!(),
         }
     }
@@ -36,10 +36,12 @@ use rand::distributions::DistString;
 use rand::rngs::SmallRng;
 use rand::Rng;
 use rand::SeedableRng;
+use roaring::RoaringTreemap;
 use serde::Deserialize;
 use serde::Serialize;
 
 use crate::types::array::ArrayColumn;
+use crate::types::bitmap::serialize_bitmap;
 use crate::types::date::DATE_MAX;
 use crate::types::date::DATE_MIN;
 use crate::types::decimal::DecimalColumn;
@@ -48,6 +50,7 @@ use crate::types::decimal::DecimalSize;
 use crate::types::nullable::NullableColumn;
 use crate::types::timestamp::TIMESTAMP_MAX;
 use crate::types::timestamp::TIMESTAMP_MIN;
+use crate::types::BitmapType;
 use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
@@ -64,6 +67,7 @@ use crate::FromData;
 use crate::Scalar;
 use crate::TypeDeserializerImpl;
 use crate::Value;
+use crate::ARROW_EXT_TYPE_BITMAP;
 use crate::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use crate::ARROW_EXT_TYPE_EMPTY_MAP;
 use crate::ARROW_EXT_TYPE_VARIANT;
@@ -106,6 +110,11 @@ pub struct TableField {
     data_type: TableDataType,
     #[serde(default = "uninit_column_id")]
     column_id: ColumnId,
+    // previous (column id, data type) of the column, the most recent first.
+    // a version is added each time the data type of the column is modified,
+    // blocks written before that still store the column by the previous column id.
+    #[serde(default)]
+    evolved_from: Vec<(ColumnId, TableDataType)>,
 }
 
 /// DataType with more information that is only available for table field, e.g, the
@@ -129,6 +138,7 @@ pub enum TableDataType {
         fields_type: Vec<TableDataType>,
     },
     Variant,
+    Bitmap,
 }
 
 impl DataSchema {
@@ -355,7 +365,9 @@ impl TableSchema {
 
     pub fn is_column_deleted(&self, column_id: ColumnId) -> bool {
         for field in &self.fields {
-            if field.contain_column_id(column_id) {
+            if field.contain_column_id(column_id)
+                || field.evolved_from.iter().any(|(id, _)| *id == column_id)
+            {
                 return false;
             }
         }
@@ -389,6 +401,51 @@ impl TableSchema {
         Ok(())
     }
 
+    pub fn rename_column(&mut self, column: &str, new_column: &str) -> Result<()> {
+        if self.index_of(new_column).is_ok() {
+            return Err(ErrorCode::AddColumnExistError(format!(
+                "rename column {} to {} already exist",
+                column, new_column,
+            )));
+        }
+        let i = self.index_of(column)?;
+        self.fields[i].name = new_column.to_string();
+
+        Ok(())
+    }
+
+    /// Change the data type of a column, the column is assigned a new column id,
+    /// the previous one is kept in `evolved_from` so that old blocks can still be read.
+    pub fn modify_column_type(&mut self, column: &str, data_type: TableDataType) -> Result<()> {
+        let i = self.index_of(column)?;
+        let field = &self.fields[i];
+        if field.data_type == data_type {
+            return Ok(());
+        }
+        if field.data_type.is_nested() || data_type.is_nested() {
+            return Err(ErrorCode::AlterTableError(format!(
+                "cannot modify column {} from {} to {}, nested data types are not supported",
+                column, field.data_type, data_type,
+            )));
+        }
+
+        let mut evolved_from = Vec::with_capacity(field.evolved_from.len() + 1);
+        evolved_from.push((field.column_id, field.data_type.clone()));
+        evolved_from.extend(field.evolved_from.iter().cloned());
+
+        let new_field = TableField {
+            name: field.name.clone(),
+            default_expr: field.default_expr.clone(),
+            data_type,
+            column_id: 0,
+            evolved_from,
+        }
+        .build_column_id(&mut self.next_column_id);
+        self.fields[i] = new_field;
+
+        Ok(())
+    }
+
     pub fn to_column_id_set(&self) -> HashSet<ColumnId> {
         HashSet::from_iter(self.to_column_ids().iter().cloned())
     }
@@ -778,6 +835,7 @@ impl TableField {
             default_expr: None,
             data_type,
             column_id: 0,
+            evolved_from: vec![],
         }
     }
 
@@ -787,6 +845,7 @@ impl TableField {
             default_expr: None,
             data_type,
             column_id,
+            evolved_from: vec![],
         }
     }
 
@@ -830,6 +889,7 @@ impl TableField {
             default_expr: self.default_expr.clone(),
             data_type: self.data_type.clone(),
             column_id,
+            evolved_from: self.evolved_from.clone(),
         }
     }
 
@@ -862,6 +922,16 @@ impl TableField {
         self.column_id
     }
 
+    pub fn evolved_from(&self) -> &[(ColumnId, TableDataType)] {
+        &self.evolved_from
+    }
+
+    #[must_use]
+    pub fn with_evolved_from(mut self, evolved_from: Vec<(ColumnId, TableDataType)>) -> Self {
+        self.evolved_from = evolved_from;
+        self
+    }
+
     #[must_use]
     pub fn with_default_expr(mut self, default_expr: Option<String>) -> Self {
         self.default_expr = default_expr;
@@ -910,6 +980,7 @@ impl From<&TableDataType> for DataType {
                 DataType::Tuple(fields_type.iter().map(Into::into).collect())
             }
             TableDataType::Variant => DataType::Variant,
+            TableDataType::Bitmap => DataType::Bitmap,
         }
     }
 }
@@ -934,6 +1005,13 @@ impl TableDataType {
         !self.is_nullable_or_null()
     }
 
+    pub fn is_nested(&self) -> bool {
+        matches!(
+            self.remove_nullable(),
+            TableDataType::Tuple { .. } | TableDataType::Array(_) | TableDataType::Map(_)
+        )
+    }
+
     pub fn remove_nullable(&self) -> Self {
         match self {
             TableDataType::Nullable(ty) => (**ty).clone(),
@@ -1163,6 +1241,23 @@ impl TableDataType {
                     value: Value::Column(VariantType::from_data(data)),
                 }
             }
+            TableDataType::Bitmap => {
+                let mut data = Vec::with_capacity(len);
+                for _ in 0..len {
+                    let bitmap_len = SmallRng::from_entropy().gen_range(0..=5);
+                    let mut bitmap = RoaringTreemap::new();
+                    for _ in 0..bitmap_len {
+                        bitmap.insert(SmallRng::from_entropy().gen_range(0..=u64::MAX));
+                    }
+                    let mut buf = vec![];
+                    serialize_bitmap(&bitmap, &mut buf).unwrap();
+                    data.push(buf);
+                }
+                BlockEntry {
+                    data_type: DataType::Bitmap,
+                    value: Value::Column(BitmapType::from_data(data)),
+                }
+            }
             _ => todo!(),
         }
     }
@@ -1235,6 +1330,7 @@ impl From<&ArrowField> for TableField {
             data_type: f.into(),
             default_expr: None,
             column_id: 0,
+            evolved_from: vec![],
         }
     }
 }
@@ -1292,6 +1388,7 @@ impl From<&ArrowField> for TableDataType {
             }
             ArrowDataType::Extension(custom_name, _, _) => match custom_name.as_str() {
                 ARROW_EXT_TYPE_VARIANT => TableDataType::Variant,
+                ARROW_EXT_TYPE_BITMAP => TableDataType::Bitmap,
                 ARROW_EXT_TYPE_EMPTY_ARRAY => TableDataType::EmptyArray,
                 ARROW_EXT_TYPE_EMPTY_MAP => TableDataType::EmptyMap,
                 _ => unimplemented!("data_type: {:?}", f.data_type()),
@@ -1384,6 +1481,11 @@ impl From<&DataType> for ArrowDataType {
                 Box::new(ArrowDataType::LargeBinary),
                 None,
             ),
+            DataType::Bitmap => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_BITMAP.to_string(),
+                Box::new(ArrowDataType::LargeBinary),
+                None,
+            ),
 
             _ => unreachable!(),
         }
@@ -1449,6 +1551,11 @@ impl From<&TableDataType> for ArrowDataType {
                 Box::new(ArrowDataType::LargeBinary),
                 None,
             ),
+            TableDataType::Bitmap => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_BITMAP.to_string(),
+                Box::new(ArrowDataType::LargeBinary),
+                None,
+            ),
         }
     }
 }
@@ -1481,6 +1588,7 @@ pub fn infer_schema_type(data_type: &DataType) -> Result<TableDataType> {
             Ok(TableDataType::Map(Box::new(infer_schema_type(inner_type)?)))
         }
         DataType::Variant => Ok(TableDataType::Variant),
+        DataType::Bitmap => Ok(TableDataType::Bitmap),
         DataType::Tuple(fields) => {
             let fields_type = fields
                 .iter()
@@ -77,6 +77,27 @@ pub fn check<Index: ColumnIndex>(
                 .try_collect()?;
             check_function(*span, name, params, &args_expr, fn_registry)
         }
+        RawExpr::LambdaFunctionCall {
+            span,
+            name,
+            args,
+            lambda_expr,
+            lambda_display,
+            return_type,
+        } => {
+            let args: Vec<_> = args
+                .iter()
+                .map(|arg| check(arg, fn_registry))
+                .try_collect()?;
+            Ok(Expr::LambdaFunctionCall {
+                span: *span,
+                name: name.clone(),
+                args,
+                lambda_expr: Box::new(lambda_expr.as_expr(fn_registry)),
+                lambda_display: lambda_display.clone(),
+                return_type: return_type.clone(),
+            })
+        }
     }
 }
 
@@ -569,6 +590,7 @@ pub const ALL_SIMPLE_CAST_FUNCTIONS: &[&str] = &[
     "to_timestamp",
     "to_date",
     "to_variant",
+    "to_bitmap",
     "to_boolean",
     "to_decimal",
 ];
@@ -14,6 +14,7 @@
 
 pub mod any;
 pub mod array;
+pub mod bitmap;
 pub mod boolean;
 pub mod date;
 pub mod decimal;
@@ -41,6 +42,7 @@ use serde::Serialize;
 
 pub use self::any::AnyType;
 pub use self::array::ArrayType;
+pub use self::bitmap::BitmapType;
 pub use self::boolean::BooleanType;
 pub use self::date::DateType;
 pub use self::decimal::DecimalDataType;
@@ -57,6 +59,7 @@ pub use self::string::StringType;
 pub use self::timestamp::TimestampType;
 pub use self::variant::VariantType;
 use crate::deserializations::ArrayDeserializer;
+use crate::deserializations::BitmapDeserializer;
 use crate::deserializations::DateDeserializer;
 use crate::deserializations::DecimalDeserializer;
 use crate::deserializations::NullableDeserializer;
@@ -89,6 +92,7 @@ pub enum DataType {
     Map(Box<DataType>),
     Tuple(Vec<DataType>),
     Variant,
+    Bitmap,
     Generic(usize),
 }
 
@@ -232,6 +236,7 @@ impl DataType {
                 NullableDeserializer::with_capacity(capacity, inner_ty.as_ref()).into()
             }
             DataType::Variant => VariantDeserializer::with_capacity(capacity).into(),
+            DataType::Bitmap => BitmapDeserializer::with_capacity(capacity).into(),
             DataType::Array(ty) => ArrayDeserializer::with_capacity(capacity, ty).into(),
             DataType::Map(_ty) => todo!(),
             DataType::Tuple(types) => TupleDeserializer::with_capacity(capacity, types).into(),
@@ -312,6 +317,7 @@ impl DataType {
                 Scalar::Tuple(tys.iter().map(|ty| ty.default_value()).collect())
             }
             DataType::Variant => Scalar::Variant(vec![]),
+            DataType::Bitmap => Scalar::Bitmap(vec![]),
 
             _ => unimplemented!(),
         }
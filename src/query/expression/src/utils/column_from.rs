@@ -56,7 +56,8 @@ macro_rules! for_common_scalar_values {
             { StringType },
             { DateType },
             { TimestampType },
-            { VariantType }
+            { VariantType },
+            { BitmapType }
         }
     };
 }
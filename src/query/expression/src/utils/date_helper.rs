@@ -27,6 +27,7 @@ use chrono::Utc;
 use chrono_tz::Tz;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_io::cursor_ext::unwrap_local_time;
 use num_traits::AsPrimitive;
 use once_cell::sync::Lazy;
 
@@ -197,10 +198,7 @@ impl TzLUT {
             Round::Hour => self.round_down(us, 3600),
             Round::Day => {
                 let dt = self.to_datetime_from_us(us);
-                let dt = self
-                    .tz
-                    .ymd(dt.year(), dt.month(), dt.day())
-                    .and_hms_micro(0, 0, 0, 0);
+                let dt = unwrap_local_time(&self.tz, &dt.naive_local().date().and_hms(0, 0, 0));
                 dt.timestamp() * MICROS_IN_A_SEC
             }
         }
@@ -402,7 +400,7 @@ impl ToNumberImpl {
     }
 
     pub fn eval_date<T: ToNumber<R>, R>(date: i32, tz: TzLUT) -> R {
-        let dt = date.to_date(tz.tz).and_hms(0, 0, 0);
+        let dt = unwrap_local_time(&tz.tz, &date.to_date(tz.tz).naive_local().and_hms(0, 0, 0));
         T::to_number(&dt)
     }
 }
@@ -493,7 +491,7 @@ impl DateRounder {
     }
 
     pub fn eval_date<T: ToNumber<i32>>(date: i32, tz: TzLUT) -> i32 {
-        let dt = date.to_date(tz.tz).and_hms(0, 0, 0);
+        let dt = unwrap_local_time(&tz.tz, &date.to_date(tz.tz).naive_local().and_hms(0, 0, 0));
         T::to_number(&dt)
     }
 }
@@ -549,10 +547,10 @@ impl ToNumber<i32> for ToStartOfYear {
 impl ToNumber<i32> for ToStartOfISOYear {
     fn to_number(dt: &DateTime<Tz>) -> i32 {
         let iso_year = dt.iso_week().year();
-        let iso_dt = dt
-            .timezone()
-            .isoywd(iso_year, 1, chrono::Weekday::Mon)
-            .and_hms(0, 0, 0);
+        let iso_dt = unwrap_local_time(
+            &dt.timezone(),
+            &NaiveDate::from_isoywd(iso_year, 1, chrono::Weekday::Mon).and_hms(0, 0, 0),
+        );
         datetime_to_date_inner_number(&iso_dt)
     }
 }
@@ -34,6 +34,7 @@ use crate::function::Function;
 use crate::function::FunctionSignature;
 use crate::property::Domain;
 use crate::property::FunctionProperty;
+use crate::types::bitmap::bitmap_to_string;
 use crate::types::boolean::BooleanDomain;
 use crate::types::date::date_to_string;
 use crate::types::decimal::DecimalColumn;
@@ -152,6 +153,7 @@ impl<'a> Debug for ScalarRef<'a> {
                 write!(f, ")")
             }
             ScalarRef::Variant(s) => write!(f, "0x{}", &hex::encode(s)),
+            ScalarRef::Bitmap(b) => write!(f, "0x{}", &hex::encode(b)),
         }
     }
 }
@@ -177,6 +179,7 @@ impl Debug for Column {
                 .field("len", len)
                 .finish(),
             Column::Variant(col) => write!(f, "{col:?}"),
+            Column::Bitmap(col) => write!(f, "{col:?}"),
         }
     }
 }
@@ -233,6 +236,7 @@ impl<'a> Display for ScalarRef<'a> {
                 let value = common_jsonb::to_string(s);
                 write!(f, "{value}")
             }
+            ScalarRef::Bitmap(b) => write!(f, "{:?}", bitmap_to_string(b)),
         }
     }
 }
@@ -412,6 +416,18 @@ impl<Index: ColumnIndex> Display for RawExpr<Index> {
                 }
                 write!(f, ")")
             }
+            RawExpr::LambdaFunctionCall {
+                name,
+                args,
+                lambda_display,
+                ..
+            } => {
+                write!(f, "{name}(")?;
+                for arg in args {
+                    write!(f, "{arg}, ")?;
+                }
+                write!(f, "{lambda_display})")
+            }
         }
     }
 }
@@ -465,6 +481,7 @@ impl Display for DataType {
                 write!(f, ")")
             }
             DataType::Variant => write!(f, "Variant"),
+            DataType::Bitmap => write!(f, "Bitmap"),
             DataType::Generic(index) => write!(f, "T{index}"),
         }
     }
@@ -502,6 +519,7 @@ impl Display for TableDataType {
                 write!(f, ")")
             }
             TableDataType::Variant => write!(f, "Variant"),
+            TableDataType::Bitmap => write!(f, "Bitmap"),
         }
     }
 }
@@ -587,6 +605,18 @@ impl<Index: ColumnIndex> Display for Expr<Index> {
                 }
                 write!(f, ")")
             }
+            Expr::LambdaFunctionCall {
+                name,
+                args,
+                lambda_display,
+                ..
+            } => {
+                write!(f, "{name}(")?;
+                for arg in args {
+                    write!(f, "{arg}, ")?;
+                }
+                write!(f, "{lambda_display})")
+            }
         }
     }
 }
@@ -93,6 +93,7 @@ pub enum Scalar {
     Map(Column),
     Tuple(Vec<Scalar>),
     Variant(Vec<u8>),
+    Bitmap(Vec<u8>),
 }
 
 #[derive(Clone, Default, Eq, EnumAsInner)]
@@ -111,6 +112,7 @@ pub enum ScalarRef<'a> {
     Map(Column),
     Tuple(Vec<ScalarRef<'a>>),
     Variant(&'a [u8]),
+    Bitmap(&'a [u8]),
 }
 
 #[derive(Clone, EnumAsInner)]
@@ -129,6 +131,7 @@ pub enum Column {
     Nullable(Box<NullableColumn<AnyType>>),
     Tuple { fields: Vec<Column>, len: usize },
     Variant(StringColumn),
+    Bitmap(StringColumn),
 }
 
 #[derive(Debug, Clone, EnumAsInner)]
@@ -156,6 +159,7 @@ pub enum ColumnBuilder {
         len: usize,
     },
     Variant(StringColumnBuilder),
+    Bitmap(StringColumnBuilder),
 }
 
 impl<'a, T: ValueType> ValueRef<'a, T> {
@@ -273,6 +277,7 @@ impl Scalar {
             Scalar::Map(col) => ScalarRef::Map(col.clone()),
             Scalar::Tuple(fields) => ScalarRef::Tuple(fields.iter().map(Scalar::as_ref).collect()),
             Scalar::Variant(s) => ScalarRef::Variant(s.as_slice()),
+            Scalar::Bitmap(b) => ScalarRef::Bitmap(b.as_slice()),
         }
     }
 }
@@ -295,6 +300,7 @@ impl<'a> ScalarRef<'a> {
                 Scalar::Tuple(fields.iter().map(ScalarRef::to_owned).collect())
             }
             ScalarRef::Variant(s) => Scalar::Variant(s.to_vec()),
+            ScalarRef::Bitmap(b) => Scalar::Bitmap(b.to_vec()),
         }
     }
 
@@ -348,7 +354,10 @@ impl<'a> ScalarRef<'a> {
                         .collect(),
                 )
             }
-            ScalarRef::EmptyMap | ScalarRef::Map(_) | ScalarRef::Variant(_) => Domain::Undefined,
+            ScalarRef::EmptyMap
+            | ScalarRef::Map(_)
+            | ScalarRef::Variant(_)
+            | ScalarRef::Bitmap(_) => Domain::Undefined,
         }
     }
 
@@ -375,6 +384,7 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::Map(col) => col.memory_size(),
             ScalarRef::Tuple(scalars) => scalars.iter().map(|s| s.memory_size()).sum(),
             ScalarRef::Variant(buf) => buf.len(),
+            ScalarRef::Bitmap(buf) => buf.len(),
         }
     }
 
@@ -415,6 +425,7 @@ impl PartialOrd for Scalar {
             (Scalar::Variant(v1), Scalar::Variant(v2)) => {
                 common_jsonb::compare(v1.as_slice(), v2.as_slice()).ok()
             }
+            (Scalar::Bitmap(b1), Scalar::Bitmap(b2)) => b1.partial_cmp(b2),
             _ => None,
         }
     }
@@ -447,6 +458,7 @@ impl PartialOrd for ScalarRef<'_> {
             (ScalarRef::Map(m1), ScalarRef::Map(m2)) => m1.partial_cmp(m2),
             (ScalarRef::Tuple(t1), ScalarRef::Tuple(t2)) => t1.partial_cmp(t2),
             (ScalarRef::Variant(v1), ScalarRef::Variant(v2)) => common_jsonb::compare(v1, v2).ok(),
+            (ScalarRef::Bitmap(b1), ScalarRef::Bitmap(b2)) => b1.partial_cmp(b2),
             _ => None,
         }
     }
@@ -494,6 +506,7 @@ impl Hash for ScalarRef<'_> {
                 v.hash(state);
             }
             ScalarRef::Variant(v) => v.hash(state),
+            ScalarRef::Bitmap(v) => v.hash(state),
         }
     }
 }
@@ -533,6 +546,7 @@ impl PartialOrd for Column {
             (Column::Variant(col1), Column::Variant(col2)) => col1
                 .iter()
                 .partial_cmp_by(col2.iter(), |v1, v2| common_jsonb::compare(v1, v2).ok()),
+            (Column::Bitmap(col1), Column::Bitmap(col2)) => col1.iter().partial_cmp(col2.iter()),
             _ => None,
         }
     }
@@ -547,6 +561,7 @@ impl PartialEq for Column {
 pub const ARROW_EXT_TYPE_EMPTY_ARRAY: &str = "EmptyArray";
 pub const ARROW_EXT_TYPE_EMPTY_MAP: &str = "EmptyMap";
 pub const ARROW_EXT_TYPE_VARIANT: &str = "Variant";
+pub const ARROW_EXT_TYPE_BITMAP: &str = "Bitmap";
 
 impl Column {
     pub fn len(&self) -> usize {
@@ -565,6 +580,7 @@ impl Column {
             Column::Nullable(col) => col.len(),
             Column::Tuple { len, .. } => *len,
             Column::Variant(col) => col.len(),
+            Column::Bitmap(col) => col.len(),
         }
     }
 
@@ -589,6 +605,7 @@ impl Column {
                     .collect::<Option<Vec<_>>>()?,
             )),
             Column::Variant(col) => Some(ScalarRef::Variant(col.index(index)?)),
+            Column::Bitmap(col) => Some(ScalarRef::Bitmap(col.index(index)?)),
         }
     }
 
@@ -615,6 +632,7 @@ impl Column {
                     .collect::<Vec<_>>(),
             ),
             Column::Variant(col) => ScalarRef::Variant(col.index_unchecked(index)),
+            Column::Bitmap(col) => ScalarRef::Bitmap(col.index_unchecked(index)),
         }
     }
 
@@ -666,6 +684,7 @@ impl Column {
                 len: range.end - range.start,
             },
             Column::Variant(col) => Column::Variant(col.slice(range)),
+            Column::Bitmap(col) => Column::Bitmap(col.slice(range)),
         }
     }
 
@@ -732,7 +751,7 @@ impl Column {
                 let domains = fields.iter().map(|col| col.domain()).collect::<Vec<_>>();
                 Domain::Tuple(domains)
             }
-            Column::Map(_) | Column::Variant(_) => Domain::Undefined,
+            Column::Map(_) | Column::Variant(_) | Column::Bitmap(_) => Domain::Undefined,
         }
     }
 
@@ -769,6 +788,7 @@ impl Column {
                 DataType::Tuple(inner)
             }
             Column::Variant(_) => DataType::Variant,
+            Column::Bitmap(_) => DataType::Bitmap,
         }
     }
 
@@ -971,7 +991,7 @@ impl Column {
                 )
                 .unwrap(),
             ),
-            Column::Variant(col) => {
+            Column::Variant(col) | Column::Bitmap(col) => {
                 let offsets: Buffer<i64> =
                     col.offsets.iter().map(|offset| *offset as i64).collect();
                 Box::new(
@@ -1201,7 +1221,9 @@ impl Column {
                     .values()
                     .clone(),
             ),
-            ArrowDataType::Extension(name, _, None) if name == ARROW_EXT_TYPE_VARIANT => {
+            ArrowDataType::Extension(name, _, None)
+                if name == ARROW_EXT_TYPE_VARIANT || name == ARROW_EXT_TYPE_BITMAP =>
+            {
                 let arrow_col = arrow_col
                     .as_any()
                     .downcast_ref::<common_arrow::arrow::array::BinaryArray<i64>>()
@@ -1212,10 +1234,15 @@ impl Column {
                     .iter()
                     .map(|x| *x as u64)
                     .collect::<Vec<_>>();
-                Column::Variant(StringColumn {
+                let col = StringColumn {
                     data: arrow_col.values().clone(),
                     offsets: offsets.into(),
-                })
+                };
+                if name == ARROW_EXT_TYPE_VARIANT {
+                    Column::Variant(col)
+                } else {
+                    Column::Bitmap(col)
+                }
             }
             ArrowDataType::List(f) => {
                 let array_list = arrow_cast::cast(
@@ -1373,7 +1400,7 @@ impl Column {
             Column::Map(col) => col.values.memory_size() + col.offsets.len() * 8,
             Column::Nullable(c) => c.column.memory_size() + c.validity.as_slice().0.len(),
             Column::Tuple { fields, .. } => fields.iter().map(|f| f.memory_size()).sum(),
-            Column::Variant(col) => col.data.len() + col.offsets.len() * 8,
+            Column::Variant(col) | Column::Bitmap(col) => col.data.len() + col.offsets.len() * 8,
         }
     }
 
@@ -1460,6 +1487,7 @@ impl ColumnBuilder {
                 len,
             },
             Column::Variant(col) => ColumnBuilder::Variant(StringColumnBuilder::from_column(col)),
+            Column::Bitmap(col) => ColumnBuilder::Bitmap(StringColumnBuilder::from_column(col)),
         }
     }
 
@@ -1521,6 +1549,7 @@ impl ColumnBuilder {
                 }
             }
             ScalarRef::Variant(s) => ColumnBuilder::Variant(StringColumnBuilder::repeat(s, n)),
+            ScalarRef::Bitmap(b) => ColumnBuilder::Bitmap(StringColumnBuilder::repeat(b, n)),
         }
     }
 
@@ -1540,6 +1569,7 @@ impl ColumnBuilder {
             ColumnBuilder::Nullable(builder) => builder.len(),
             ColumnBuilder::Tuple { len, .. } => *len,
             ColumnBuilder::Variant(builder) => builder.len(),
+            ColumnBuilder::Bitmap(builder) => builder.len(),
         }
     }
 
@@ -1590,6 +1620,9 @@ impl ColumnBuilder {
             DataType::Variant => {
                 ColumnBuilder::Variant(StringColumnBuilder::with_capacity(capacity, 0))
             }
+            DataType::Bitmap => {
+                ColumnBuilder::Bitmap(StringColumnBuilder::with_capacity(capacity, 0))
+            }
             DataType::Generic(_) => {
                 unreachable!("unable to initialize column builder for generic type")
             }
@@ -1631,7 +1664,8 @@ impl ColumnBuilder {
                 }
                 *len += 1;
             }
-            (ColumnBuilder::Variant(builder), ScalarRef::Variant(value)) => {
+            (ColumnBuilder::Variant(builder), ScalarRef::Variant(value))
+            | (ColumnBuilder::Bitmap(builder), ScalarRef::Bitmap(value)) => {
                 builder.put_slice(value);
                 builder.commit_row();
             }
@@ -1663,6 +1697,7 @@ impl ColumnBuilder {
                 builder.put_slice(JSONB_NULL);
                 builder.commit_row();
             }
+            ColumnBuilder::Bitmap(builder) => builder.commit_row(),
         }
     }
 
@@ -1689,7 +1724,8 @@ impl ColumnBuilder {
             (ColumnBuilder::String(builder), Column::String(other)) => {
                 builder.append_column(other);
             }
-            (ColumnBuilder::Variant(builder), Column::Variant(other)) => {
+            (ColumnBuilder::Variant(builder), Column::Variant(other))
+            | (ColumnBuilder::Bitmap(builder), Column::Bitmap(other)) => {
                 builder.append_column(other);
             }
             (ColumnBuilder::Timestamp(builder), Column::Timestamp(other)) => {
@@ -1743,6 +1779,7 @@ impl ColumnBuilder {
                 len,
             },
             ColumnBuilder::Variant(builder) => Column::Variant(builder.build()),
+            ColumnBuilder::Bitmap(builder) => Column::Bitmap(builder.build()),
         }
     }
 
@@ -1768,6 +1805,7 @@ impl ColumnBuilder {
                     .collect(),
             ),
             ColumnBuilder::Variant(builder) => Scalar::Variant(builder.build_scalar()),
+            ColumnBuilder::Bitmap(builder) => Scalar::Bitmap(builder.build_scalar()),
         }
     }
 }
@@ -17,7 +17,10 @@ use std::io::BufRead;
 use std::io::Cursor;
 
 use common_exception::Result;
+use common_expression::types::bitmap::parse_bitmap;
+use common_expression::types::bitmap::serialize_bitmap;
 use common_expression::ArrayDeserializer;
+use common_expression::BitmapDeserializer;
 use common_expression::StringDeserializer;
 use common_expression::StructDeserializer;
 use common_expression::VariantDeserializer;
@@ -110,6 +113,21 @@ impl FieldDecoderRowBased for FieldDecoderCSV {
         Ok(())
     }
 
+    fn read_bitmap<R: AsRef<[u8]>>(
+        &self,
+        column: &mut BitmapDeserializer,
+        reader: &mut Cursor<R>,
+        _raw: bool,
+    ) -> Result<()> {
+        let buf = reader.remaining_slice();
+        let bitmap = parse_bitmap(buf)?;
+        serialize_bitmap(&bitmap, &mut column.builder.data)?;
+        column.builder.commit_row();
+
+        reader.consume(buf.len());
+        Ok(())
+    }
+
     fn read_array<R: AsRef<[u8]>>(
         &self,
         column: &mut ArrayDeserializer,
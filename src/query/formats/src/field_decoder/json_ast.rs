@@ -24,6 +24,7 @@ use common_expression::types::number::Number;
 use common_expression::types::timestamp::check_timestamp;
 use common_expression::uniform_date;
 use common_expression::ArrayDeserializer;
+use common_expression::BitmapDeserializer;
 use common_expression::BooleanDeserializer;
 use common_expression::DateDeserializer;
 use common_expression::DecimalDeserializer;
@@ -90,6 +91,7 @@ impl FieldJsonAstDecoder {
             TypeDeserializerImpl::Array(c) => self.read_array(c, value),
             TypeDeserializerImpl::Struct(c) => self.read_struct(c, value),
             TypeDeserializerImpl::Variant(c) => self.read_variant(c, value),
+            TypeDeserializerImpl::Bitmap(c) => self.read_bitmap(c, value),
         }
     }
 
@@ -234,6 +236,11 @@ where {
         Ok(())
     }
 
+    fn read_bitmap(&self, column: &mut BitmapDeserializer, value: &Value) -> Result<()> {
+        column.de_json(value, &FormatSettings::default())?;
+        Ok(())
+    }
+
     fn read_array(&self, column: &mut ArrayDeserializer, value: &Value) -> Result<()> {
         match value {
             Value::Array(vals) => {
@@ -21,12 +21,15 @@ use bstr::ByteSlice;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::read_decimal_with_size;
+use common_expression::types::bitmap::parse_bitmap;
+use common_expression::types::bitmap::serialize_bitmap;
 use common_expression::types::date::check_date;
 use common_expression::types::decimal::Decimal;
 use common_expression::types::number::Number;
 use common_expression::types::timestamp::check_timestamp;
 use common_expression::uniform_date;
 use common_expression::ArrayDeserializer;
+use common_expression::BitmapDeserializer;
 use common_expression::BooleanDeserializer;
 use common_expression::DateDeserializer;
 use common_expression::DecimalDeserializer;
@@ -94,6 +97,7 @@ pub trait FieldDecoderRowBased: FieldDecoder {
             TypeDeserializerImpl::Array(c) => self.read_array(c, reader, raw),
             TypeDeserializerImpl::Struct(c) => self.read_struct(c, reader, raw),
             TypeDeserializerImpl::Variant(c) => self.read_variant(c, reader, raw),
+            TypeDeserializerImpl::Bitmap(c) => self.read_bitmap(c, reader, raw),
         }
     }
 
@@ -268,6 +272,20 @@ pub trait FieldDecoderRowBased: FieldDecoder {
         Ok(())
     }
 
+    fn read_bitmap<R: AsRef<[u8]>>(
+        &self,
+        column: &mut BitmapDeserializer,
+        reader: &mut Cursor<R>,
+        raw: bool,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, raw)?;
+        let bitmap = parse_bitmap(&buf)?;
+        serialize_bitmap(&bitmap, &mut column.builder.data)?;
+        column.builder.commit_row();
+        Ok(())
+    }
+
     fn read_array<R: AsRef<[u8]>>(
         &self,
         column: &mut ArrayDeserializer,
@@ -15,6 +15,7 @@
 use common_arrow::arrow::bitmap::Bitmap;
 use common_arrow::arrow::buffer::Buffer;
 use common_expression::types::array::ArrayColumn;
+use common_expression::types::bitmap::bitmap_to_string;
 use common_expression::types::date::date_to_string;
 use common_expression::types::decimal::DecimalColumn;
 use common_expression::types::nullable::NullableColumn;
@@ -65,6 +66,7 @@ pub trait FieldEncoderRowBased {
             Column::Map(box c) => self.write_map(c, row_index, out_buf, raw),
             Column::Tuple { fields, .. } => self.write_tuple(fields, row_index, out_buf, raw),
             Column::Variant(c) => self.write_variant(c, row_index, out_buf, raw),
+            Column::Bitmap(c) => self.write_bitmap(c, row_index, out_buf, raw),
         }
     }
 
@@ -176,6 +178,19 @@ pub trait FieldEncoderRowBased {
         self.write_string_inner(s.as_bytes(), out_buf, raw);
     }
 
+    /// Bitmaps are written as the comma separated list of their values, e.g. `1,3,5`.
+    fn write_bitmap(
+        &self,
+        column: &StringColumn,
+        row_index: usize,
+        out_buf: &mut Vec<u8>,
+        raw: bool,
+    ) {
+        let v = unsafe { column.index_unchecked(row_index) };
+        let s = bitmap_to_string(v);
+        self.write_string_inner(s.as_bytes(), out_buf, raw);
+    }
+
     fn write_array<T: ValueType>(
         &self,
         column: &ArrayColumn<T>,
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use common_expression::date_helper::DateConverter;
+use common_expression::types::bitmap::bitmap_to_string;
 use common_expression::types::number::NumberScalar;
 use common_expression::DataBlock;
 use common_expression::ScalarRef;
@@ -131,6 +132,7 @@ fn scalar_to_json(s: ScalarRef<'_>, format: &FormatSettings) -> JsonValue {
             let b = common_jsonb::from_slice(x).unwrap();
             b.into()
         }
+        ScalarRef::Bitmap(x) => JsonValue::String(bitmap_to_string(x)),
     }
 }
 
@@ -0,0 +1,256 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::cmp::Ordering;
+use std::fmt;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_io::prelude::*;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::aggregate_function::AggregateFunction;
+use super::aggregate_function::AggregateFunctionRef;
+use super::aggregate_function_factory::AggregateFunctionDescription;
+use super::StateAddr;
+
+#[derive(Serialize, Deserialize, Clone)]
+struct ArrayAggItem {
+    value: Scalar,
+    sort_keys: Vec<Scalar>,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct AggregateArrayAggState {
+    items: Vec<ArrayAggItem>,
+}
+
+/// Collects the values of a group into an array.
+///
+/// `array_agg(x ORDER BY y DESC, z)` is bound as `array_agg(false, false, true, false)(x, y, z)`:
+/// the arguments after the first one are the sort keys, and every sort key takes two
+/// parameters telling whether it is ascending and whether nulls come first.
+pub struct AggregateArrayAggFunction {
+    display_name: String,
+    value_type: DataType,
+    sort_descs: Vec<SortDesc>,
+}
+
+#[derive(Clone, Copy)]
+struct SortDesc {
+    asc: bool,
+    nulls_first: bool,
+}
+
+impl AggregateArrayAggFunction {
+    pub fn try_create(
+        display_name: &str,
+        params: Vec<Scalar>,
+        arguments: Vec<DataType>,
+    ) -> Result<AggregateFunctionRef> {
+        if params.len() % 2 != 0 || arguments.len() != params.len() / 2 + 1 {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "{} expect to have two parameters for every sort key, but got {} parameters and {} arguments",
+                display_name,
+                params.len(),
+                arguments.len()
+            )));
+        }
+        let flags = params
+            .iter()
+            .map(|param| match param {
+                Scalar::Boolean(flag) => Ok(*flag),
+                _ => param.as_ref().cast_to_u64().map(|v| v != 0).ok_or_else(|| {
+                    ErrorCode::BadArguments(format!(
+                        "{display_name} expect the sort options to be booleans, but got {param}"
+                    ))
+                }),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let sort_descs = flags
+            .chunks(2)
+            .map(|flags| SortDesc {
+                asc: flags[0],
+                nulls_first: flags[1],
+            })
+            .collect();
+
+        Ok(Arc::new(Self {
+            display_name: display_name.to_string(),
+            value_type: arguments[0].clone(),
+            sort_descs,
+        }))
+    }
+
+    pub fn desc() -> AggregateFunctionDescription {
+        AggregateFunctionDescription::creator(Box::new(Self::try_create))
+    }
+
+    fn compare(&self, lhs: &ArrayAggItem, rhs: &ArrayAggItem) -> Ordering {
+        for ((l, r), desc) in lhs
+            .sort_keys
+            .iter()
+            .zip(rhs.sort_keys.iter())
+            .zip(self.sort_descs.iter())
+        {
+            let ordering = match (l == &Scalar::Null, r == &Scalar::Null) {
+                (true, true) => Ordering::Equal,
+                (true, false) if desc.nulls_first => Ordering::Less,
+                (true, false) => Ordering::Greater,
+                (false, true) if desc.nulls_first => Ordering::Greater,
+                (false, true) => Ordering::Less,
+                (false, false) => {
+                    let ordering = l.partial_cmp(r).unwrap_or(Ordering::Equal);
+                    if desc.asc {
+                        ordering
+                    } else {
+                        ordering.reverse()
+                    }
+                }
+            };
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+        Ordering::Equal
+    }
+}
+
+impl AggregateFunction for AggregateArrayAggFunction {
+    fn name(&self) -> &str {
+        "AggregateArrayAggFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(DataType::Array(Box::new(self.value_type.clone())))
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(AggregateArrayAggState::default);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateArrayAggState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        input_rows: usize,
+    ) -> Result<()> {
+        match validity {
+            Some(bitmap) => {
+                for (row, is_valid) in bitmap.iter().enumerate() {
+                    if is_valid {
+                        self.accumulate_row(place, columns, row)?;
+                    }
+                }
+            }
+            None => {
+                for row in 0..input_rows {
+                    self.accumulate_row(place, columns, row)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        let state = place.get::<AggregateArrayAggState>();
+        let value = columns[0].index(row).unwrap();
+        if value == ScalarRef::Null {
+            return Ok(());
+        }
+        let value = value.to_owned();
+        let sort_keys = columns[1..]
+            .iter()
+            .map(|column| column.index(row).unwrap().to_owned())
+            .collect();
+        state.items.push(ArrayAggItem { value, sort_keys });
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        let state = place.get::<AggregateArrayAggState>();
+        serialize_into_buf(writer, state)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateArrayAggState>();
+        *state = deserialize_from_slice(reader)?;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateArrayAggState>();
+        let rhs = rhs.get::<AggregateArrayAggState>();
+        state.items.extend_from_slice(&rhs.items);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        let state = place.get::<AggregateArrayAggState>();
+        if !self.sort_descs.is_empty() {
+            state.items.sort_by(|lhs, rhs| self.compare(lhs, rhs));
+        }
+
+        let mut values = ColumnBuilder::with_capacity(&self.value_type, state.items.len());
+        for item in state.items.iter() {
+            values.push(item.value.as_ref());
+        }
+        builder.push(ScalarRef::Array(values.build()));
+        Ok(())
+    }
+
+    fn get_own_null_adaptor(
+        &self,
+        nested_function: AggregateFunctionRef,
+        _params: Vec<Scalar>,
+        _arguments: Vec<DataType>,
+    ) -> Result<Option<AggregateFunctionRef>> {
+        // The nullable columns are passed as they are, so that NULL values are skipped
+        // while NULL sort keys are kept and ordered by `NULLS FIRST` or `NULLS LAST`.
+        if self.sort_descs.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(nested_function))
+        }
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<AggregateArrayAggState>();
+        std::ptr::drop_in_place(state);
+    }
+}
+
+impl fmt::Display for AggregateArrayAggFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
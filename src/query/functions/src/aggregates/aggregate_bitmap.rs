@@ -0,0 +1,467 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::io::Read;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::bitmap::deserialize_bitmap;
+use common_expression::types::bitmap::serialize_bitmap;
+use common_expression::types::number::Number;
+use common_expression::types::BitmapType;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberType;
+use common_expression::types::UInt64Type;
+use common_expression::types::ValueType;
+use common_expression::with_integer_mapped_type;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::Scalar;
+use num_traits::ToPrimitive;
+use roaring::RoaringTreemap;
+
+use super::aggregate_function::AggregateFunction;
+use super::aggregate_function::AggregateFunctionRef;
+use super::aggregate_function_factory::AggregateFunctionDescription;
+use super::aggregate_function_factory::AggregateFunctionFeatures;
+use super::StateAddr;
+use crate::aggregates::assert_unary_arguments;
+
+#[derive(Clone, Copy)]
+enum BitmapOperation {
+    Or,
+    And,
+    Xor,
+}
+
+#[derive(Default)]
+struct AggregateBitmapState {
+    /// `None` until the first value is added, so that `AND` can start from the first bitmap
+    /// instead of the empty one.
+    rb: Option<RoaringTreemap>,
+}
+
+impl AggregateBitmapState {
+    #[inline(always)]
+    fn insert(&mut self, value: u64) {
+        self.rb
+            .get_or_insert_with(RoaringTreemap::new)
+            .insert(value);
+    }
+
+    fn add(&mut self, other: RoaringTreemap, op: BitmapOperation) {
+        match self.rb.as_mut() {
+            None => self.rb = Some(other),
+            Some(rb) => match op {
+                BitmapOperation::Or => *rb |= other,
+                BitmapOperation::And => *rb &= other,
+                BitmapOperation::Xor => *rb ^= other,
+            },
+        }
+    }
+
+    fn serialize(&self, writer: &mut Vec<u8>) -> Result<()> {
+        match &self.rb {
+            None => writer.push(0),
+            Some(rb) => {
+                writer.push(1);
+                rb.serialize_into(writer)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn deserialize(reader: &mut &[u8]) -> Result<Self> {
+        let mut flag = [0u8; 1];
+        reader.read_exact(&mut flag)?;
+        let rb = match flag[0] {
+            0 => None,
+            _ => Some(RoaringTreemap::deserialize_from(&mut *reader)?),
+        };
+        Ok(Self { rb })
+    }
+
+    fn merge_result(&self, builder: &mut ColumnBuilder, return_count: bool) -> Result<()> {
+        if return_count {
+            let builder = UInt64Type::try_downcast_builder(builder).unwrap();
+            builder.push(self.rb.as_ref().map(|rb| rb.len()).unwrap_or(0));
+        } else {
+            let builder = BitmapType::try_downcast_builder(builder).unwrap();
+            if let Some(rb) = &self.rb {
+                serialize_bitmap(rb, &mut builder.data)?;
+            }
+            builder.commit_row();
+        }
+        Ok(())
+    }
+}
+
+fn bitmap_return_type(return_count: bool) -> DataType {
+    if return_count {
+        DataType::Number(NumberDataType::UInt64)
+    } else {
+        DataType::Bitmap
+    }
+}
+
+/// Builds a bitmap from the integer values of a group, `group_bitmap` returns the number
+/// of distinct values instead of the bitmap itself.
+pub struct AggregateBitmapConstructFunction<T> {
+    display_name: String,
+    return_count: bool,
+    t: PhantomData<T>,
+}
+
+impl<T> AggregateBitmapConstructFunction<T>
+where T: Number
+{
+    #[inline(always)]
+    fn insert(&self, state: &mut AggregateBitmapState, value: &T) -> Result<()> {
+        match value.to_u64() {
+            Some(v) => {
+                state.insert(v);
+                Ok(())
+            }
+            None => Err(ErrorCode::BadArguments(format!(
+                "{} only supports non-negative integers, but got {:?}",
+                self.display_name, value
+            ))),
+        }
+    }
+}
+
+impl<T> AggregateFunction for AggregateBitmapConstructFunction<T>
+where T: Number
+{
+    fn name(&self) -> &str {
+        "AggregateBitmapConstructFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(bitmap_return_type(self.return_count))
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(AggregateBitmapState::default);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateBitmapState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<AggregateBitmapState>();
+        let column = NumberType::<T>::try_downcast_column(&columns[0]).unwrap();
+        match validity {
+            Some(bitmap) => {
+                for (value, is_valid) in column.iter().zip(bitmap.iter()) {
+                    if is_valid {
+                        self.insert(state, value)?;
+                    }
+                }
+            }
+            None => {
+                for value in column.iter() {
+                    self.insert(state, value)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        let state = place.get::<AggregateBitmapState>();
+        let column = NumberType::<T>::try_downcast_column(&columns[0]).unwrap();
+        self.insert(state, &column[row])
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        let state = place.get::<AggregateBitmapState>();
+        state.serialize(writer)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateBitmapState>();
+        *state = AggregateBitmapState::deserialize(reader)?;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateBitmapState>();
+        let rhs = rhs.get::<AggregateBitmapState>();
+        if let Some(rb) = &rhs.rb {
+            state.add(rb.clone(), BitmapOperation::Or);
+        }
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        let state = place.get::<AggregateBitmapState>();
+        state.merge_result(builder, self.return_count)
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<AggregateBitmapState>();
+        std::ptr::drop_in_place(state);
+    }
+}
+
+impl<T> fmt::Display for AggregateBitmapConstructFunction<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+/// Combines the bitmaps of a group with `OR`, `AND` or `XOR`, the `*_count` variants
+/// return the cardinality of the combined bitmap.
+pub struct AggregateBitmapFunction {
+    display_name: String,
+    op: BitmapOperation,
+    return_count: bool,
+}
+
+impl AggregateBitmapFunction {
+    #[inline(always)]
+    fn add(&self, state: &mut AggregateBitmapState, value: &[u8]) -> Result<()> {
+        let rb = deserialize_bitmap(value)?;
+        state.add(rb, self.op);
+        Ok(())
+    }
+}
+
+impl AggregateFunction for AggregateBitmapFunction {
+    fn name(&self) -> &str {
+        "AggregateBitmapFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(bitmap_return_type(self.return_count))
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(AggregateBitmapState::default);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateBitmapState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<AggregateBitmapState>();
+        let column = BitmapType::try_downcast_column(&columns[0]).unwrap();
+        match validity {
+            Some(bitmap) => {
+                for (value, is_valid) in column.iter().zip(bitmap.iter()) {
+                    if is_valid {
+                        self.add(state, value)?;
+                    }
+                }
+            }
+            None => {
+                for value in column.iter() {
+                    self.add(state, value)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        let state = place.get::<AggregateBitmapState>();
+        let column = BitmapType::try_downcast_column(&columns[0]).unwrap();
+        self.add(state, column.index(row).unwrap())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        let state = place.get::<AggregateBitmapState>();
+        state.serialize(writer)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateBitmapState>();
+        *state = AggregateBitmapState::deserialize(reader)?;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateBitmapState>();
+        let rhs = rhs.get::<AggregateBitmapState>();
+        if let Some(rb) = &rhs.rb {
+            state.add(rb.clone(), self.op);
+        }
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        let state = place.get::<AggregateBitmapState>();
+        state.merge_result(builder, self.return_count)
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<AggregateBitmapState>();
+        std::ptr::drop_in_place(state);
+    }
+}
+
+impl fmt::Display for AggregateBitmapFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+fn try_create_aggregate_bitmap_construct_function(
+    display_name: &str,
+    arguments: Vec<DataType>,
+    return_count: bool,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_arguments(display_name, arguments.len())?;
+    with_integer_mapped_type!(|NUM_TYPE| match &arguments[0] {
+        DataType::Number(NumberDataType::NUM_TYPE) => {
+            Ok(Arc::new(AggregateBitmapConstructFunction::<NUM_TYPE> {
+                display_name: display_name.to_string(),
+                return_count,
+                t: PhantomData,
+            }))
+        }
+        _ => Err(ErrorCode::BadDataValueType(format!(
+            "{} does not support type '{:?}'",
+            display_name, arguments[0]
+        ))),
+    })
+}
+
+fn try_create_aggregate_bitmap_function(
+    display_name: &str,
+    arguments: Vec<DataType>,
+    op: BitmapOperation,
+    return_count: bool,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_arguments(display_name, arguments.len())?;
+    if arguments[0] != DataType::Bitmap {
+        return Err(ErrorCode::BadDataValueType(format!(
+            "{} expect to have Bitmap argument, but got {:?}",
+            display_name, arguments[0]
+        )));
+    }
+    Ok(Arc::new(AggregateBitmapFunction {
+        display_name: display_name.to_string(),
+        op,
+        return_count,
+    }))
+}
+
+fn count_features() -> AggregateFunctionFeatures {
+    AggregateFunctionFeatures {
+        returns_default_when_only_null: true,
+        ..Default::default()
+    }
+}
+
+pub fn aggregate_bitmap_agg_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        |display_name: &str, _params: Vec<Scalar>, arguments: Vec<DataType>| {
+            try_create_aggregate_bitmap_construct_function(display_name, arguments, false)
+        },
+    ))
+}
+
+pub fn aggregate_group_bitmap_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator_with_features(
+        Box::new(
+            |display_name: &str, _params: Vec<Scalar>, arguments: Vec<DataType>| {
+                try_create_aggregate_bitmap_construct_function(display_name, arguments, true)
+            },
+        ),
+        count_features(),
+    )
+}
+
+macro_rules! bitmap_function_desc {
+    ($name: ident, $op: expr, $return_count: expr) => {
+        pub fn $name() -> AggregateFunctionDescription {
+            let creator = Box::new(
+                |display_name: &str, _params: Vec<Scalar>, arguments: Vec<DataType>| {
+                    try_create_aggregate_bitmap_function(
+                        display_name,
+                        arguments,
+                        $op,
+                        $return_count,
+                    )
+                },
+            );
+            if $return_count {
+                AggregateFunctionDescription::creator_with_features(creator, count_features())
+            } else {
+                AggregateFunctionDescription::creator(creator)
+            }
+        }
+    };
+}
+
+bitmap_function_desc!(
+    aggregate_bitmap_or_function_desc,
+    BitmapOperation::Or,
+    false
+);
+bitmap_function_desc!(
+    aggregate_bitmap_and_function_desc,
+    BitmapOperation::And,
+    false
+);
+bitmap_function_desc!(
+    aggregate_bitmap_xor_function_desc,
+    BitmapOperation::Xor,
+    false
+);
+bitmap_function_desc!(
+    aggregate_bitmap_or_count_function_desc,
+    BitmapOperation::Or,
+    true
+);
+bitmap_function_desc!(
+    aggregate_bitmap_and_count_function_desc,
+    BitmapOperation::And,
+    true
+);
+bitmap_function_desc!(
+    aggregate_bitmap_xor_count_function_desc,
+    BitmapOperation::Xor,
+    true
+);
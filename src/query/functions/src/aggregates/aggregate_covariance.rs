@@ -47,6 +47,8 @@ pub struct AggregateCovarianceState {
     pub co_moments: f64,
     pub left_mean: f64,
     pub right_mean: f64,
+    pub left_m2: f64,
+    pub right_m2: f64,
 }
 
 // Source: "Numerically Stable, Single-Pass, Parallel Statistics Algorithms"
@@ -70,6 +72,8 @@ impl AggregateCovarianceState {
         let new_right_mean = self.right_mean + right_delta / self.count as f64;
 
         self.co_moments += (s - new_left_mean) * (t - self.right_mean);
+        self.left_m2 += (s - new_left_mean) * left_delta;
+        self.right_m2 += (t - new_right_mean) * right_delta;
         self.left_mean = new_left_mean;
         self.right_mean = new_right_mean;
     }
@@ -90,6 +94,8 @@ impl AggregateCovarianceState {
         let right_delta = self.right_mean - other.right_mean;
 
         self.co_moments += other.co_moments + left_delta * right_delta * factor;
+        self.left_m2 += other.left_m2 + left_delta * left_delta * factor;
+        self.right_m2 += other.right_m2 + right_delta * right_delta * factor;
 
         if large_and_comparable(self.count, other.count) {
             self.left_mean = (self.left_sum() + other.left_sum()) / total as f64;
@@ -154,6 +160,8 @@ where
             left_mean: 0.0,
             right_mean: 0.0,
             co_moments: 0.0,
+            left_m2: 0.0,
+            right_m2: 0.0,
         });
     }
 
@@ -358,3 +366,187 @@ pub fn aggregate_covariance_population_desc() -> AggregateFunctionDescription {
         try_create_aggregate_covariance::<AggregateCovariancePopulationImpl>,
     ))
 }
+
+// Pearson correlation coefficient function implementation
+struct AggregateCorrImpl;
+
+impl AggregateCovariance for AggregateCorrImpl {
+    fn name() -> &'static str {
+        "AggregateCorrFunction"
+    }
+
+    fn apply(state: &AggregateCovarianceState) -> f64 {
+        state.co_moments / (state.left_m2 * state.right_m2).sqrt()
+    }
+}
+
+pub fn aggregate_corr_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_covariance::<AggregateCorrImpl>,
+    ))
+}
+
+// The regr_* functions take the dependent variable first, i.e. `regr_slope(y, x)`,
+// so the left side of the state is `y` and the right side is `x`.
+
+// Linear regression slope function implementation
+struct AggregateRegrSlopeImpl;
+
+impl AggregateCovariance for AggregateRegrSlopeImpl {
+    fn name() -> &'static str {
+        "AggregateRegrSlopeFunction"
+    }
+
+    fn apply(state: &AggregateCovarianceState) -> f64 {
+        if state.right_m2 == 0.0 {
+            f64::NAN
+        } else {
+            state.co_moments / state.right_m2
+        }
+    }
+}
+
+pub fn aggregate_regr_slope_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_covariance::<AggregateRegrSlopeImpl>,
+    ))
+}
+
+// Linear regression intercept function implementation
+struct AggregateRegrInterceptImpl;
+
+impl AggregateCovariance for AggregateRegrInterceptImpl {
+    fn name() -> &'static str {
+        "AggregateRegrInterceptFunction"
+    }
+
+    fn apply(state: &AggregateCovarianceState) -> f64 {
+        state.left_mean - state.right_mean * AggregateRegrSlopeImpl::apply(state)
+    }
+}
+
+pub fn aggregate_regr_intercept_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_covariance::<AggregateRegrInterceptImpl>,
+    ))
+}
+
+// Coefficient of determination function implementation
+struct AggregateRegrR2Impl;
+
+impl AggregateCovariance for AggregateRegrR2Impl {
+    fn name() -> &'static str {
+        "AggregateRegrR2Function"
+    }
+
+    fn apply(state: &AggregateCovarianceState) -> f64 {
+        if state.right_m2 == 0.0 {
+            f64::NAN
+        } else if state.left_m2 == 0.0 {
+            1.0
+        } else {
+            state.co_moments * state.co_moments / (state.left_m2 * state.right_m2)
+        }
+    }
+}
+
+pub fn aggregate_regr_r2_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_covariance::<AggregateRegrR2Impl>,
+    ))
+}
+
+// Average of the independent variable function implementation
+struct AggregateRegrAvgxImpl;
+
+impl AggregateCovariance for AggregateRegrAvgxImpl {
+    fn name() -> &'static str {
+        "AggregateRegrAvgxFunction"
+    }
+
+    fn apply(state: &AggregateCovarianceState) -> f64 {
+        state.right_mean
+    }
+}
+
+pub fn aggregate_regr_avgx_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_covariance::<AggregateRegrAvgxImpl>,
+    ))
+}
+
+// Average of the dependent variable function implementation
+struct AggregateRegrAvgyImpl;
+
+impl AggregateCovariance for AggregateRegrAvgyImpl {
+    fn name() -> &'static str {
+        "AggregateRegrAvgyFunction"
+    }
+
+    fn apply(state: &AggregateCovarianceState) -> f64 {
+        state.left_mean
+    }
+}
+
+pub fn aggregate_regr_avgy_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_covariance::<AggregateRegrAvgyImpl>,
+    ))
+}
+
+// Sum of squares of the independent variable function implementation
+struct AggregateRegrSxxImpl;
+
+impl AggregateCovariance for AggregateRegrSxxImpl {
+    fn name() -> &'static str {
+        "AggregateRegrSxxFunction"
+    }
+
+    fn apply(state: &AggregateCovarianceState) -> f64 {
+        state.right_m2
+    }
+}
+
+pub fn aggregate_regr_sxx_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_covariance::<AggregateRegrSxxImpl>,
+    ))
+}
+
+// Sum of squares of the dependent variable function implementation
+struct AggregateRegrSyyImpl;
+
+impl AggregateCovariance for AggregateRegrSyyImpl {
+    fn name() -> &'static str {
+        "AggregateRegrSyyFunction"
+    }
+
+    fn apply(state: &AggregateCovarianceState) -> f64 {
+        state.left_m2
+    }
+}
+
+pub fn aggregate_regr_syy_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_covariance::<AggregateRegrSyyImpl>,
+    ))
+}
+
+// Sum of products of the two variables function implementation
+struct AggregateRegrSxyImpl;
+
+impl AggregateCovariance for AggregateRegrSxyImpl {
+    fn name() -> &'static str {
+        "AggregateRegrSxyFunction"
+    }
+
+    fn apply(state: &AggregateCovarianceState) -> f64 {
+        state.co_moments
+    }
+}
+
+pub fn aggregate_regr_sxy_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_covariance::<AggregateRegrSxyImpl>,
+    ))
+}
@@ -0,0 +1,167 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_io::prelude::*;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::aggregate_function::AggregateFunction;
+use super::aggregate_function::AggregateFunctionRef;
+use super::aggregate_function_factory::AggregateFunctionDescription;
+use super::StateAddr;
+use crate::aggregates::assert_binary_arguments;
+
+#[derive(Serialize, Deserialize, Default)]
+struct AggregateMapAggState {
+    entries: Vec<(Scalar, Scalar)>,
+}
+
+/// Collects the key-value pairs of a group into a map, if a key appears
+/// more than once, the first value is kept.
+pub struct AggregateMapAggFunction {
+    display_name: String,
+    key_type: DataType,
+    value_type: DataType,
+}
+
+impl AggregateFunction for AggregateMapAggFunction {
+    fn name(&self) -> &str {
+        "AggregateMapAggFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(DataType::Map(Box::new(DataType::Tuple(vec![
+            self.key_type.clone(),
+            self.value_type.clone(),
+        ]))))
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(AggregateMapAggState::default);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateMapAggState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        input_rows: usize,
+    ) -> Result<()> {
+        match validity {
+            Some(bitmap) => {
+                for (row, is_valid) in bitmap.iter().enumerate() {
+                    if is_valid {
+                        self.accumulate_row(place, columns, row)?;
+                    }
+                }
+            }
+            None => {
+                for row in 0..input_rows {
+                    self.accumulate_row(place, columns, row)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        let state = place.get::<AggregateMapAggState>();
+        let key = columns[0].index(row).unwrap().to_owned();
+        let value = columns[1].index(row).unwrap().to_owned();
+        state.entries.push((key, value));
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        let state = place.get::<AggregateMapAggState>();
+        serialize_into_buf(writer, state)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateMapAggState>();
+        *state = deserialize_from_slice(reader)?;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateMapAggState>();
+        let rhs = rhs.get::<AggregateMapAggState>();
+        state.entries.extend_from_slice(&rhs.entries);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        let state = place.get::<AggregateMapAggState>();
+        let entry_type = DataType::Tuple(vec![self.key_type.clone(), self.value_type.clone()]);
+        let mut entries = ColumnBuilder::with_capacity(&entry_type, state.entries.len());
+        let mut keys = HashSet::with_capacity(state.entries.len());
+        for (key, value) in state.entries.iter() {
+            if keys.insert(key.as_ref()) {
+                entries.push(ScalarRef::Tuple(vec![key.as_ref(), value.as_ref()]));
+            }
+        }
+        builder.push(ScalarRef::Map(entries.build()));
+        Ok(())
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<AggregateMapAggState>();
+        std::ptr::drop_in_place(state);
+    }
+}
+
+impl fmt::Display for AggregateMapAggFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl AggregateMapAggFunction {
+    pub fn try_create(
+        display_name: &str,
+        _params: Vec<Scalar>,
+        arguments: Vec<DataType>,
+    ) -> Result<AggregateFunctionRef> {
+        assert_binary_arguments(display_name, arguments.len())?;
+        Ok(Arc::new(Self {
+            display_name: display_name.to_string(),
+            key_type: arguments[0].clone(),
+            value_type: arguments[1].clone(),
+        }))
+    }
+
+    pub fn desc() -> AggregateFunctionDescription {
+        AggregateFunctionDescription::creator(Box::new(Self::try_create))
+    }
+}
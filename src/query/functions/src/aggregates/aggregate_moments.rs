@@ -0,0 +1,402 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::number::Number;
+use common_expression::types::number::F64;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberType;
+use common_expression::types::ValueType;
+use common_expression::with_number_mapped_type;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::Scalar;
+use common_io::prelude::*;
+use num_traits::AsPrimitive;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::aggregator_common::assert_unary_arguments;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+/// Central moments up to the fourth order, `m2`, `m3` and `m4` are the sums of
+/// the 2nd, 3rd and 4th powers of the differences from the current mean.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct AggregateMomentsState {
+    pub count: u64,
+    pub mean: f64,
+    pub m2: f64,
+    pub m3: f64,
+    pub m4: f64,
+}
+
+// Source: "Formulas for Robust, One-Pass Parallel Computation of Covariances and
+// Arbitrary-Order Statistical Moments" (P. Pébay, Sandia Report SAND2008-6212)
+// Paper link: https://www.osti.gov/servlets/purl/1028931
+impl AggregateMomentsState {
+    // Welford's online update, extended to the 3rd and 4th moments as in formula (2.1) of the paper.
+    // The higher moments must be updated before the lower ones since they depend on the old values.
+    #[inline(always)]
+    fn add(&mut self, value: f64) {
+        let n1 = self.count as f64;
+        self.count += 1;
+        let n = self.count as f64;
+
+        let delta = value - self.mean;
+        let delta_n = delta / n;
+        let delta_n2 = delta_n * delta_n;
+        let term = delta * delta_n * n1;
+
+        self.mean += delta_n;
+        self.m4 += term * delta_n2 * (n * n - 3.0 * n + 3.0) + 6.0 * delta_n2 * self.m2
+            - 4.0 * delta_n * self.m3;
+        self.m3 += term * delta_n * (n - 2.0) - 3.0 * delta_n * self.m2;
+        self.m2 += term;
+    }
+
+    // The pairwise combination from formula (3.1) of the paper, which makes the state mergeable.
+    #[inline(always)]
+    fn merge(&mut self, other: &Self) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            *self = other.clone();
+            return;
+        }
+
+        let na = self.count as f64;
+        let nb = other.count as f64;
+        let n = na + nb;
+
+        let delta = other.mean - self.mean;
+        let delta2 = delta * delta;
+        let delta3 = delta2 * delta;
+        let delta4 = delta2 * delta2;
+
+        self.m4 += other.m4
+            + delta4 * na * nb * (na * na - na * nb + nb * nb) / (n * n * n)
+            + 6.0 * delta2 * (na * na * other.m2 + nb * nb * self.m2) / (n * n)
+            + 4.0 * delta * (na * other.m3 - nb * self.m3) / n;
+        self.m3 += other.m3
+            + delta3 * na * nb * (na - nb) / (n * n)
+            + 3.0 * delta * (na * other.m2 - nb * self.m2) / n;
+        self.m2 += other.m2 + delta2 * na * nb / n;
+        self.mean += delta * nb / n;
+        self.count += other.count;
+    }
+}
+
+#[derive(Clone)]
+pub struct AggregateMomentsFunction<T, R> {
+    display_name: String,
+    _t: PhantomData<T>,
+    _r: PhantomData<R>,
+}
+
+impl<T, R> AggregateFunction for AggregateMomentsFunction<T, R>
+where
+    T: Number + AsPrimitive<f64>,
+    R: AggregateMoments,
+{
+    fn name(&self) -> &str {
+        R::name()
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(DataType::Number(NumberDataType::Float64))
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateMomentsState {
+            count: 0,
+            mean: 0.0,
+            m2: 0.0,
+            m3: 0.0,
+            m4: 0.0,
+        });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateMomentsState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<AggregateMomentsState>();
+        let column = NumberType::<T>::try_downcast_column(&columns[0]).unwrap();
+        match validity {
+            Some(bitmap) => {
+                for (value, is_valid) in column.iter().zip(bitmap.iter()) {
+                    if is_valid {
+                        state.add(value.as_());
+                    }
+                }
+            }
+            None => {
+                for value in column.iter() {
+                    state.add(value.as_());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[Column],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let column = NumberType::<T>::try_downcast_column(&columns[0]).unwrap();
+
+        column.iter().zip(places.iter()).for_each(|(value, place)| {
+            let place = place.next(offset);
+            let state = place.get::<AggregateMomentsState>();
+            state.add(value.as_());
+        });
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        let column = NumberType::<T>::try_downcast_column(&columns[0]).unwrap();
+
+        let state = place.get::<AggregateMomentsState>();
+        state.add(column[row].as_());
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        let state = place.get::<AggregateMomentsState>();
+        serialize_into_buf(writer, state)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateMomentsState>();
+        *state = deserialize_from_slice(reader)?;
+
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateMomentsState>();
+        let rhs = rhs.get::<AggregateMomentsState>();
+        state.merge(rhs);
+        Ok(())
+    }
+
+    #[allow(unused_mut)]
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        let state = place.get::<AggregateMomentsState>();
+        let builder = NumberType::<F64>::try_downcast_builder(builder).unwrap();
+        builder.push(R::apply(state).into());
+        Ok(())
+    }
+}
+
+impl<T, R> fmt::Display for AggregateMomentsFunction<T, R> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl<T, R> AggregateMomentsFunction<T, R>
+where
+    T: Number + AsPrimitive<f64>,
+    R: AggregateMoments,
+{
+    pub fn try_create(
+        display_name: &str,
+        _arguments: Vec<DataType>,
+    ) -> Result<AggregateFunctionRef> {
+        Ok(Arc::new(Self {
+            display_name: display_name.to_string(),
+            _t: PhantomData,
+            _r: PhantomData,
+        }))
+    }
+}
+
+pub fn try_create_aggregate_moments<R: AggregateMoments>(
+    display_name: &str,
+    _params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_arguments(display_name, arguments.len())?;
+    with_number_mapped_type!(|NUM_TYPE| match &arguments[0] {
+        DataType::Number(NumberDataType::NUM_TYPE) => {
+            AggregateMomentsFunction::<NUM_TYPE, R>::try_create(display_name, arguments)
+        }
+        _ => Err(ErrorCode::BadDataValueType(format!(
+            "{} does not support type '{:?}'",
+            display_name, arguments[0]
+        ))),
+    })
+}
+
+pub trait AggregateMoments: Send + Sync + 'static {
+    fn name() -> &'static str;
+
+    fn apply(state: &AggregateMomentsState) -> f64;
+}
+
+// Population variance function implementation
+struct AggregateVarPopImpl;
+
+impl AggregateMoments for AggregateVarPopImpl {
+    fn name() -> &'static str {
+        "AggregateVarPopFunction"
+    }
+
+    fn apply(state: &AggregateMomentsState) -> f64 {
+        if state.count == 0 {
+            f64::INFINITY
+        } else if state.count == 1 {
+            0.0
+        } else {
+            state.m2 / state.count as f64
+        }
+    }
+}
+
+pub fn aggregate_var_pop_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_moments::<AggregateVarPopImpl>,
+    ))
+}
+
+// Sample variance function implementation
+struct AggregateVarSampImpl;
+
+impl AggregateMoments for AggregateVarSampImpl {
+    fn name() -> &'static str {
+        "AggregateVarSampFunction"
+    }
+
+    fn apply(state: &AggregateMomentsState) -> f64 {
+        if state.count < 2 {
+            f64::INFINITY
+        } else {
+            state.m2 / (state.count - 1) as f64
+        }
+    }
+}
+
+pub fn aggregate_var_samp_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_moments::<AggregateVarSampImpl>,
+    ))
+}
+
+// Population standard deviation function implementation
+struct AggregateStddevPopImpl;
+
+impl AggregateMoments for AggregateStddevPopImpl {
+    fn name() -> &'static str {
+        "AggregateStddevPopFunction"
+    }
+
+    fn apply(state: &AggregateMomentsState) -> f64 {
+        AggregateVarPopImpl::apply(state).sqrt()
+    }
+}
+
+pub fn aggregate_stddev_pop_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_moments::<AggregateStddevPopImpl>,
+    ))
+}
+
+// Sample standard deviation function implementation
+struct AggregateStddevSampImpl;
+
+impl AggregateMoments for AggregateStddevSampImpl {
+    fn name() -> &'static str {
+        "AggregateStddevSampFunction"
+    }
+
+    fn apply(state: &AggregateMomentsState) -> f64 {
+        AggregateVarSampImpl::apply(state).sqrt()
+    }
+}
+
+pub fn aggregate_stddev_samp_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_moments::<AggregateStddevSampImpl>,
+    ))
+}
+
+// Population skewness function implementation
+struct AggregateSkewnessImpl;
+
+impl AggregateMoments for AggregateSkewnessImpl {
+    fn name() -> &'static str {
+        "AggregateSkewnessFunction"
+    }
+
+    fn apply(state: &AggregateMomentsState) -> f64 {
+        if state.count == 0 || state.m2 == 0.0 {
+            f64::NAN
+        } else {
+            (state.count as f64).sqrt() * state.m3 / state.m2.powf(1.5)
+        }
+    }
+}
+
+pub fn aggregate_skewness_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_moments::<AggregateSkewnessImpl>,
+    ))
+}
+
+// Population excess kurtosis function implementation
+struct AggregateKurtosisImpl;
+
+impl AggregateMoments for AggregateKurtosisImpl {
+    fn name() -> &'static str {
+        "AggregateKurtosisFunction"
+    }
+
+    fn apply(state: &AggregateMomentsState) -> f64 {
+        if state.count == 0 || state.m2 == 0.0 {
+            f64::NAN
+        } else {
+            state.count as f64 * state.m4 / (state.m2 * state.m2) - 3.0
+        }
+    }
+}
+
+pub fn aggregate_kurtosis_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_moments::<AggregateKurtosisImpl>,
+    ))
+}
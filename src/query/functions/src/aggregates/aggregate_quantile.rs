@@ -0,0 +1,443 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::number::Number;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::number::F64;
+use common_expression::types::ArgType;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberType;
+use common_expression::types::ValueType;
+use common_expression::with_number_mapped_type;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::Scalar;
+use common_io::prelude::*;
+use num_traits::AsPrimitive;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::aggregator_common::assert_unary_arguments;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+/// Keeps every value of the group, so the quantiles are exact.
+#[derive(Serialize, Deserialize, Default)]
+struct AggregateQuantileState<T> {
+    pub values: Vec<T>,
+    pub sorted: bool,
+}
+
+impl<T> AggregateQuantileState<T>
+where T: Number + AsPrimitive<f64>
+{
+    #[inline(always)]
+    fn add(&mut self, value: T) {
+        self.values.push(value);
+        self.sorted = false;
+    }
+
+    #[inline(always)]
+    fn merge(&mut self, other: &Self) {
+        if other.values.is_empty() {
+            return;
+        }
+        self.values.extend_from_slice(&other.values);
+        self.sorted = false;
+    }
+
+    fn sort(&mut self) {
+        if !self.sorted {
+            self.values.sort_unstable();
+            self.sorted = true;
+        }
+    }
+
+    /// Linear interpolation between the two closest ranks, like `PERCENTILE_CONT`.
+    /// Returns `None` for an empty group.
+    fn quantile_cont(&mut self, level: f64) -> Option<f64> {
+        if self.values.is_empty() {
+            return None;
+        }
+        self.sort();
+
+        let pos = level * (self.values.len() - 1) as f64;
+        let lower = pos.floor() as usize;
+        let upper = pos.ceil() as usize;
+        let lower_value: f64 = self.values[lower].as_();
+        let upper_value: f64 = self.values[upper].as_();
+        Some(lower_value + (pos - lower as f64) * (upper_value - lower_value))
+    }
+
+    /// The first value whose cumulative distribution is not less than level,
+    /// like `PERCENTILE_DISC`. Returns `None` for an empty group.
+    fn quantile_disc(&mut self, level: f64) -> Option<T> {
+        if self.values.is_empty() {
+            return None;
+        }
+        self.sort();
+
+        let n = self.values.len();
+        let pos = (level * n as f64).ceil() as usize;
+        Some(self.values[pos.clamp(1, n) - 1])
+    }
+}
+
+pub struct AggregateQuantileFunction<T> {
+    display_name: String,
+    levels: Vec<f64>,
+    return_array: bool,
+    interpolate: bool,
+    t: PhantomData<T>,
+}
+
+impl<T> AggregateFunction for AggregateQuantileFunction<T>
+where T: Number + AsPrimitive<f64> + Serialize + DeserializeOwned
+{
+    fn name(&self) -> &str {
+        "AggregateQuantileFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        if self.interpolate {
+            Ok(quantile_return_type(self.return_array))
+        } else {
+            // `PERCENTILE_DISC` picks one of the input values.
+            Ok(NumberType::<T>::data_type().wrap_nullable())
+        }
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(AggregateQuantileState::<T>::default);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateQuantileState<T>>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<AggregateQuantileState<T>>();
+        let column = NumberType::<T>::try_downcast_column(&columns[0]).unwrap();
+        match validity {
+            Some(bitmap) => {
+                for (value, is_valid) in column.iter().zip(bitmap.iter()) {
+                    if is_valid {
+                        state.add(*value);
+                    }
+                }
+            }
+            None => {
+                for value in column.iter() {
+                    state.add(*value);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[Column],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let column = NumberType::<T>::try_downcast_column(&columns[0]).unwrap();
+
+        column.iter().zip(places.iter()).for_each(|(value, place)| {
+            let place = place.next(offset);
+            let state = place.get::<AggregateQuantileState<T>>();
+            state.add(*value);
+        });
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        let column = NumberType::<T>::try_downcast_column(&columns[0]).unwrap();
+
+        let state = place.get::<AggregateQuantileState<T>>();
+        state.add(column[row]);
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        let state = place.get::<AggregateQuantileState<T>>();
+        serialize_into_buf(writer, state)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateQuantileState<T>>();
+        *state = deserialize_from_slice(reader)?;
+
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateQuantileState<T>>();
+        let rhs = rhs.get::<AggregateQuantileState<T>>();
+        state.merge(rhs);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        let state = place.get::<AggregateQuantileState<T>>();
+        if !self.interpolate {
+            let builder = builder.as_nullable_mut().unwrap();
+            match state.quantile_disc(self.levels[0]) {
+                Some(value) => {
+                    NumberType::<T>::try_downcast_builder(&mut builder.builder)
+                        .unwrap()
+                        .push(value);
+                    builder.validity.push(true);
+                }
+                None => builder.push_null(),
+            }
+            return Ok(());
+        }
+
+        let values = self
+            .levels
+            .iter()
+            .map(|level| state.quantile_cont(*level))
+            .collect::<Option<Vec<_>>>();
+        push_quantile_result(builder, values, self.return_array);
+        Ok(())
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<AggregateQuantileState<T>>();
+        std::ptr::drop_in_place(state);
+    }
+}
+
+impl<T> fmt::Display for AggregateQuantileFunction<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl<T> AggregateQuantileFunction<T>
+where T: Number + AsPrimitive<f64> + Serialize + DeserializeOwned
+{
+    pub fn try_create(
+        display_name: &str,
+        levels: Vec<f64>,
+        return_array: bool,
+        interpolate: bool,
+    ) -> Result<AggregateFunctionRef> {
+        Ok(Arc::new(Self {
+            display_name: display_name.to_string(),
+            levels,
+            return_array,
+            interpolate,
+            t: PhantomData,
+        }))
+    }
+}
+
+/// Returns `Array(Float64)` for the functions accepting several levels, and `Float64` otherwise.
+/// The result is nullable, an empty group has no quantile.
+pub(crate) fn quantile_return_type(return_array: bool) -> DataType {
+    let data_type = DataType::Number(NumberDataType::Float64);
+    if return_array {
+        DataType::Array(Box::new(data_type)).wrap_nullable()
+    } else {
+        data_type.wrap_nullable()
+    }
+}
+
+/// Pushes the quantiles into the nullable builder, `None` means the group is empty.
+pub(crate) fn push_quantile_result(
+    builder: &mut ColumnBuilder,
+    values: Option<Vec<f64>>,
+    return_array: bool,
+) {
+    let builder = builder.as_nullable_mut().unwrap();
+    let values = match values {
+        Some(values) => values,
+        None => {
+            builder.push_null();
+            return;
+        }
+    };
+
+    if return_array {
+        let array = builder.builder.as_array_mut().unwrap();
+        let inner = array
+            .builder
+            .as_number_mut()
+            .unwrap()
+            .as_float64_mut()
+            .unwrap();
+        inner.extend(values.iter().map(|v| F64::from(*v)));
+        array.offsets.push(array.builder.len() as u64);
+    } else {
+        let inner = NumberType::<F64>::try_downcast_builder(&mut builder.builder).unwrap();
+        inner.push(values[0].into());
+    }
+    builder.validity.push(true);
+}
+
+/// Parse the quantile levels from the parameters, all of them must be in the range [0, 1].
+/// `median(x)` and `quantile(x)` without parameter default to level 0.5.
+pub(crate) fn get_levels(display_name: &str, params: &[Scalar]) -> Result<Vec<f64>> {
+    if params.is_empty() {
+        return Ok(vec![0.5]);
+    }
+    params
+        .iter()
+        .map(|param| {
+            let level: Option<f64> = match param {
+                Scalar::Number(number) => {
+                    with_number_mapped_type!(|NUM_TYPE| match number {
+                        NumberScalar::NUM_TYPE(v) => Some((*v).as_()),
+                    })
+                }
+                _ => None,
+            };
+            match level {
+                Some(level) if (0.0..=1.0).contains(&level) => Ok(level),
+                _ => Err(ErrorCode::BadArguments(format!(
+                    "{display_name} level must be a number between 0 and 1, but got {param}"
+                ))),
+            }
+        })
+        .collect()
+}
+
+fn try_create_quantile(
+    display_name: &str,
+    levels: Vec<f64>,
+    return_array: bool,
+    interpolate: bool,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_arguments(display_name, arguments.len())?;
+    with_number_mapped_type!(|NUM_TYPE| match &arguments[0] {
+        DataType::Number(NumberDataType::NUM_TYPE) => {
+            AggregateQuantileFunction::<NUM_TYPE>::try_create(
+                display_name,
+                levels,
+                return_array,
+                interpolate,
+            )
+        }
+        _ => Err(ErrorCode::BadDataValueType(format!(
+            "{} does not support type '{:?}'",
+            display_name, arguments[0]
+        ))),
+    })
+}
+
+fn assert_params(display_name: &str, actual: usize, expected: (usize, usize)) -> Result<()> {
+    if actual < expected.0 || actual > expected.1 {
+        return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+            "{} expect to have [{}, {}] parameters, but got {}",
+            display_name, expected.0, expected.1, actual
+        )));
+    }
+    Ok(())
+}
+
+pub fn try_create_aggregate_median_function(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_params(display_name, params.len(), (0, 0))?;
+    try_create_quantile(display_name, vec![0.5], false, true, arguments)
+}
+
+pub fn try_create_aggregate_quantile_function(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_params(display_name, params.len(), (0, 1))?;
+    let levels = get_levels(display_name, &params)?;
+    try_create_quantile(display_name, levels, false, true, arguments)
+}
+
+pub fn try_create_aggregate_quantiles_function(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_params(display_name, params.len(), (1, usize::MAX))?;
+    let levels = get_levels(display_name, &params)?;
+    try_create_quantile(display_name, levels, true, true, arguments)
+}
+
+pub fn try_create_aggregate_percentile_cont_function(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_params(display_name, params.len(), (1, 1))?;
+    let levels = get_levels(display_name, &params)?;
+    try_create_quantile(display_name, levels, false, true, arguments)
+}
+
+pub fn try_create_aggregate_percentile_disc_function(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_params(display_name, params.len(), (1, 1))?;
+    let levels = get_levels(display_name, &params)?;
+    try_create_quantile(display_name, levels, false, false, arguments)
+}
+
+pub fn aggregate_median_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_median_function))
+}
+
+pub fn aggregate_quantile_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_quantile_function))
+}
+
+pub fn aggregate_quantiles_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_quantiles_function))
+}
+
+pub fn aggregate_percentile_cont_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_percentile_cont_function))
+}
+
+pub fn aggregate_percentile_disc_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_percentile_disc_function))
+}
@@ -0,0 +1,354 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::f64::consts::PI;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::number::Number;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberType;
+use common_expression::types::ValueType;
+use common_expression::with_number_mapped_type;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::Scalar;
+use common_io::prelude::*;
+use num_traits::AsPrimitive;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::aggregate_quantile::get_levels;
+use crate::aggregates::aggregate_quantile::push_quantile_result;
+use crate::aggregates::aggregate_quantile::quantile_return_type;
+use crate::aggregates::aggregator_common::assert_unary_arguments;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+/// The compression parameter of the digest, it bounds the number of centroids to about
+/// `COMPRESSION * PI / 2`.
+const COMPRESSION: f64 = 100.0;
+/// Number of unmerged values buffered before they are compressed into centroids.
+const BUFFER_SIZE: usize = 500;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+/// A merging t-digest, see "Computing Extremely Accurate Quantiles Using t-Digests"
+/// by Ted Dunning and Otmar Ertl.
+///
+/// The state only keeps a bounded number of centroids, so it is cheap to serialize and
+/// two states can be merged, which makes it suitable for two-phase aggregation.
+#[derive(Serialize, Deserialize, Default)]
+struct AggregateQuantileTDigestState {
+    centroids: Vec<Centroid>,
+    #[serde(skip)]
+    unmerged: Vec<Centroid>,
+    total_weight: f64,
+    min: f64,
+    max: f64,
+}
+
+impl AggregateQuantileTDigestState {
+    #[inline(always)]
+    fn add(&mut self, value: f64) {
+        self.add_centroid(Centroid {
+            mean: value,
+            weight: 1.0,
+        });
+    }
+
+    fn add_centroid(&mut self, centroid: Centroid) {
+        if self.total_weight == 0.0 {
+            self.min = centroid.mean;
+            self.max = centroid.mean;
+        } else {
+            self.min = self.min.min(centroid.mean);
+            self.max = self.max.max(centroid.mean);
+        }
+        self.total_weight += centroid.weight;
+        self.unmerged.push(centroid);
+        if self.unmerged.len() >= BUFFER_SIZE {
+            self.compress();
+        }
+    }
+
+    fn merge(&mut self, other: &mut Self) {
+        if other.total_weight == 0.0 {
+            return;
+        }
+        other.compress();
+        for centroid in other.centroids.iter() {
+            self.add_centroid(*centroid);
+        }
+        // The extremes of the other digest may be hidden inside its centroids.
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+    }
+
+    /// Merge the buffered values into the centroids, the size of every centroid
+    /// is limited by the scale function `k(q) = COMPRESSION / (2 * PI) * asin(2q - 1)`.
+    fn compress(&mut self) {
+        if self.unmerged.is_empty() {
+            return;
+        }
+        let mut all = std::mem::take(&mut self.unmerged);
+        all.append(&mut self.centroids);
+        all.sort_unstable_by(|a, b| a.mean.total_cmp(&b.mean));
+
+        let total_weight: f64 = all.iter().map(|c| c.weight).sum();
+        let mut centroids = Vec::with_capacity(all.len().min(COMPRESSION as usize * 2));
+        let mut current = all[0];
+        let mut weight_so_far = 0.0;
+        let mut q_limit = Self::q_limit(0.0);
+        for next in all.into_iter().skip(1) {
+            let proposed_weight = current.weight + next.weight;
+            if (weight_so_far + proposed_weight) / total_weight <= q_limit {
+                current.mean += (next.mean - current.mean) * next.weight / proposed_weight;
+                current.weight = proposed_weight;
+            } else {
+                weight_so_far += current.weight;
+                q_limit = Self::q_limit(weight_so_far / total_weight);
+                centroids.push(current);
+                current = next;
+            }
+        }
+        centroids.push(current);
+        self.centroids = centroids;
+    }
+
+    /// The largest quantile the centroid starting at `q` may reach.
+    #[inline]
+    fn q_limit(q: f64) -> f64 {
+        let k = COMPRESSION / (2.0 * PI) * (2.0 * q - 1.0).asin();
+        ((((k + 1.0) * 2.0 * PI / COMPRESSION).min(PI / 2.0)).sin() + 1.0) / 2.0
+    }
+
+    /// Returns `None` for an empty group.
+    fn quantile(&mut self, level: f64) -> Option<f64> {
+        self.compress();
+        if self.centroids.is_empty() {
+            return None;
+        }
+        if self.centroids.len() == 1 {
+            return Some(self.centroids[0].mean);
+        }
+
+        // Every centroid is treated as a point at the middle of its weight, and the
+        // quantile is interpolated between the two closest points.
+        let target = level * self.total_weight;
+        let first = &self.centroids[0];
+        if target <= first.weight / 2.0 {
+            return Some(self.min + (first.mean - self.min) * target / (first.weight / 2.0));
+        }
+
+        let mut cumulative = first.weight / 2.0;
+        for pair in self.centroids.windows(2) {
+            let step = (pair[0].weight + pair[1].weight) / 2.0;
+            if target <= cumulative + step {
+                return Some(
+                    pair[0].mean + (pair[1].mean - pair[0].mean) * (target - cumulative) / step,
+                );
+            }
+            cumulative += step;
+        }
+
+        let last = &self.centroids[self.centroids.len() - 1];
+        let rest = last.weight / 2.0;
+        Some((last.mean + (self.max - last.mean) * (target - cumulative) / rest).min(self.max))
+    }
+}
+
+pub struct AggregateQuantileTDigestFunction<T> {
+    display_name: String,
+    levels: Vec<f64>,
+    return_array: bool,
+    t: PhantomData<T>,
+}
+
+impl<T> AggregateFunction for AggregateQuantileTDigestFunction<T>
+where T: Number + AsPrimitive<f64>
+{
+    fn name(&self) -> &str {
+        "AggregateQuantileTDigestFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(quantile_return_type(self.return_array))
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(AggregateQuantileTDigestState::default);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateQuantileTDigestState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<AggregateQuantileTDigestState>();
+        let column = NumberType::<T>::try_downcast_column(&columns[0]).unwrap();
+        match validity {
+            Some(bitmap) => {
+                for (value, is_valid) in column.iter().zip(bitmap.iter()) {
+                    if is_valid {
+                        state.add(value.as_());
+                    }
+                }
+            }
+            None => {
+                for value in column.iter() {
+                    state.add(value.as_());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[Column],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let column = NumberType::<T>::try_downcast_column(&columns[0]).unwrap();
+
+        column.iter().zip(places.iter()).for_each(|(value, place)| {
+            let place = place.next(offset);
+            let state = place.get::<AggregateQuantileTDigestState>();
+            state.add(value.as_());
+        });
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        let column = NumberType::<T>::try_downcast_column(&columns[0]).unwrap();
+
+        let state = place.get::<AggregateQuantileTDigestState>();
+        state.add(column[row].as_());
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        let state = place.get::<AggregateQuantileTDigestState>();
+        // The buffered values are not serialized.
+        state.compress();
+        serialize_into_buf(writer, state)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateQuantileTDigestState>();
+        *state = deserialize_from_slice(reader)?;
+
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateQuantileTDigestState>();
+        let rhs = rhs.get::<AggregateQuantileTDigestState>();
+        state.merge(rhs);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        let state = place.get::<AggregateQuantileTDigestState>();
+        let values = self
+            .levels
+            .iter()
+            .map(|level| state.quantile(*level))
+            .collect::<Option<Vec<_>>>();
+        push_quantile_result(builder, values, self.return_array);
+        Ok(())
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<AggregateQuantileTDigestState>();
+        std::ptr::drop_in_place(state);
+    }
+}
+
+impl<T> fmt::Display for AggregateQuantileTDigestFunction<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl<T> AggregateQuantileTDigestFunction<T>
+where T: Number + AsPrimitive<f64>
+{
+    pub fn try_create(
+        display_name: &str,
+        levels: Vec<f64>,
+        return_array: bool,
+    ) -> Result<AggregateFunctionRef> {
+        Ok(Arc::new(Self {
+            display_name: display_name.to_string(),
+            levels,
+            return_array,
+            t: PhantomData,
+        }))
+    }
+}
+
+/// `quantile_tdigest(level)(x)` returns a single value, and with several levels
+/// `quantile_tdigest(level1, level2, ...)(x)` returns an array.
+pub fn try_create_aggregate_quantile_tdigest_function(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_arguments(display_name, arguments.len())?;
+    let return_array = params.len() > 1;
+    let levels = get_levels(display_name, &params)?;
+    with_number_mapped_type!(|NUM_TYPE| match &arguments[0] {
+        DataType::Number(NumberDataType::NUM_TYPE) => {
+            AggregateQuantileTDigestFunction::<NUM_TYPE>::try_create(
+                display_name,
+                levels,
+                return_array,
+            )
+        }
+        _ => Err(ErrorCode::BadDataValueType(format!(
+            "{} does not support type '{:?}'",
+            display_name, arguments[0]
+        ))),
+    })
+}
+
+pub fn aggregate_quantile_tdigest_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_quantile_tdigest_function))
+}
@@ -250,7 +250,7 @@ where
 
 pub fn need_manual_drop_state(data_type: &DataType) -> bool {
     match data_type {
-        DataType::String | DataType::Variant => true,
+        DataType::String | DataType::Variant | DataType::Bitmap => true,
         DataType::Nullable(t) | DataType::Array(t) | DataType::Map(t) => need_manual_drop_state(t),
         DataType::Tuple(ts) => ts.iter().any(need_manual_drop_state),
         _ => false,
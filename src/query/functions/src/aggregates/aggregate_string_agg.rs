@@ -0,0 +1,223 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::StringType;
+use common_expression::types::ValueType;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::Scalar;
+use common_io::prelude::*;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::aggregate_function::AggregateFunction;
+use super::aggregate_function::AggregateFunctionRef;
+use super::aggregate_function_factory::AggregateFunctionDescription;
+use super::StateAddr;
+use crate::aggregates::assert_variadic_arguments;
+
+#[derive(Serialize, Deserialize, Default)]
+struct AggregateStringAggState {
+    value: Vec<u8>,
+    /// The delimiter before the first value, it is written between the values
+    /// of two states when they are merged.
+    first_delimiter: Vec<u8>,
+    has_value: bool,
+}
+
+impl AggregateStringAggState {
+    #[inline(always)]
+    fn add(&mut self, value: &[u8], delimiter: &[u8]) {
+        if self.has_value {
+            self.value.extend_from_slice(delimiter);
+        } else {
+            self.first_delimiter = delimiter.to_vec();
+            self.has_value = true;
+        }
+        self.value.extend_from_slice(value);
+    }
+
+    fn merge(&mut self, other: &Self) {
+        if other.has_value {
+            self.add(&other.value, &other.first_delimiter);
+        }
+    }
+}
+
+/// Concatenates the strings of a group, the delimiter before every value
+/// except the first one is taken from the second argument of the same row.
+///
+/// `string_agg(x)` uses an empty delimiter, while `group_concat(x)` uses `,`
+/// to be compatible with MySQL.
+pub struct AggregateStringAggFunction {
+    display_name: String,
+    default_delimiter: Vec<u8>,
+}
+
+impl AggregateFunction for AggregateStringAggFunction {
+    fn name(&self) -> &str {
+        "AggregateStringAggFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(DataType::String)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(AggregateStringAggState::default);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateStringAggState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        input_rows: usize,
+    ) -> Result<()> {
+        match validity {
+            Some(bitmap) => {
+                for (row, is_valid) in bitmap.iter().enumerate() {
+                    if is_valid {
+                        self.accumulate_row(place, columns, row)?;
+                    }
+                }
+            }
+            None => {
+                for row in 0..input_rows {
+                    self.accumulate_row(place, columns, row)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        let state = place.get::<AggregateStringAggState>();
+        let values = StringType::try_downcast_column(&columns[0]).unwrap();
+        let delimiters = columns
+            .get(1)
+            .map(|column| StringType::try_downcast_column(column).unwrap());
+        let delimiter = match &delimiters {
+            Some(delimiters) => delimiters.index(row).unwrap(),
+            None => self.default_delimiter.as_slice(),
+        };
+        state.add(values.index(row).unwrap(), delimiter);
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        let state = place.get::<AggregateStringAggState>();
+        serialize_into_buf(writer, state)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateStringAggState>();
+        *state = deserialize_from_slice(reader)?;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateStringAggState>();
+        let rhs = rhs.get::<AggregateStringAggState>();
+        state.merge(rhs);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        let state = place.get::<AggregateStringAggState>();
+        let builder = StringType::try_downcast_builder(builder).unwrap();
+        builder.put_slice(&state.value);
+        builder.commit_row();
+        Ok(())
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<AggregateStringAggState>();
+        std::ptr::drop_in_place(state);
+    }
+}
+
+impl fmt::Display for AggregateStringAggFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl AggregateStringAggFunction {
+    fn try_create(
+        display_name: &str,
+        default_delimiter: &str,
+        params: Vec<Scalar>,
+        arguments: Vec<DataType>,
+    ) -> Result<AggregateFunctionRef> {
+        if !params.is_empty() {
+            return Err(ErrorCode::BadArguments(format!(
+                "{} does not support parameters or ORDER BY",
+                display_name
+            )));
+        }
+        assert_variadic_arguments(display_name, arguments.len(), (1, 2))?;
+        if arguments.iter().any(|arg| arg != &DataType::String) {
+            return Err(ErrorCode::BadDataValueType(format!(
+                "{} expect to have String arguments, but got {:?}",
+                display_name, arguments
+            )));
+        }
+
+        Ok(Arc::new(Self {
+            display_name: display_name.to_string(),
+            default_delimiter: default_delimiter.as_bytes().to_vec(),
+        }))
+    }
+}
+
+pub fn try_create_aggregate_string_agg_function(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    AggregateStringAggFunction::try_create(display_name, "", params, arguments)
+}
+
+pub fn try_create_aggregate_group_concat_function(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    AggregateStringAggFunction::try_create(display_name, ",", params, arguments)
+}
+
+pub fn aggregate_string_agg_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_string_agg_function))
+}
+
+pub fn aggregate_group_concat_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_group_concat_function))
+}
@@ -16,18 +16,50 @@ use super::aggregate_approx_count_distinct::aggregate_approx_count_distinct_func
 use super::aggregate_arg_min_max::aggregate_arg_max_function_desc;
 use super::aggregate_arg_min_max::aggregate_arg_min_function_desc;
 use super::aggregate_avg::aggregate_avg_function_desc;
+use super::aggregate_bitmap::aggregate_bitmap_agg_function_desc;
+use super::aggregate_bitmap::aggregate_bitmap_and_count_function_desc;
+use super::aggregate_bitmap::aggregate_bitmap_and_function_desc;
+use super::aggregate_bitmap::aggregate_bitmap_or_count_function_desc;
+use super::aggregate_bitmap::aggregate_bitmap_or_function_desc;
+use super::aggregate_bitmap::aggregate_bitmap_xor_count_function_desc;
+use super::aggregate_bitmap::aggregate_bitmap_xor_function_desc;
+use super::aggregate_bitmap::aggregate_group_bitmap_function_desc;
 use super::aggregate_combinator_distinct::aggregate_combinator_distinct_desc;
 use super::aggregate_combinator_distinct::aggregate_combinator_uniq_desc;
+use super::aggregate_covariance::aggregate_corr_desc;
 use super::aggregate_covariance::aggregate_covariance_population_desc;
 use super::aggregate_covariance::aggregate_covariance_sample_desc;
+use super::aggregate_covariance::aggregate_regr_avgx_desc;
+use super::aggregate_covariance::aggregate_regr_avgy_desc;
+use super::aggregate_covariance::aggregate_regr_intercept_desc;
+use super::aggregate_covariance::aggregate_regr_r2_desc;
+use super::aggregate_covariance::aggregate_regr_slope_desc;
+use super::aggregate_covariance::aggregate_regr_sxx_desc;
+use super::aggregate_covariance::aggregate_regr_sxy_desc;
+use super::aggregate_covariance::aggregate_regr_syy_desc;
 use super::aggregate_min_max_any::aggregate_any_function_desc;
 use super::aggregate_min_max_any::aggregate_max_function_desc;
 use super::aggregate_min_max_any::aggregate_min_function_desc;
-use super::aggregate_stddev_pop::aggregate_stddev_pop_function_desc;
+use super::aggregate_moments::aggregate_kurtosis_function_desc;
+use super::aggregate_moments::aggregate_skewness_function_desc;
+use super::aggregate_moments::aggregate_stddev_pop_function_desc;
+use super::aggregate_moments::aggregate_stddev_samp_function_desc;
+use super::aggregate_moments::aggregate_var_pop_function_desc;
+use super::aggregate_moments::aggregate_var_samp_function_desc;
+use super::aggregate_quantile::aggregate_median_function_desc;
+use super::aggregate_quantile::aggregate_percentile_cont_function_desc;
+use super::aggregate_quantile::aggregate_percentile_disc_function_desc;
+use super::aggregate_quantile::aggregate_quantile_function_desc;
+use super::aggregate_quantile::aggregate_quantiles_function_desc;
+use super::aggregate_quantile_tdigest::aggregate_quantile_tdigest_function_desc;
+use super::aggregate_string_agg::aggregate_group_concat_function_desc;
+use super::aggregate_string_agg::aggregate_string_agg_function_desc;
 use super::aggregate_window_funnel::aggregate_window_funnel_function_desc;
+use super::AggregateArrayAggFunction;
 use super::AggregateCountFunction;
 use super::AggregateFunctionFactory;
 use super::AggregateIfCombinator;
+use super::AggregateMapAggFunction;
 use crate::aggregates::aggregate_retention::aggregate_retention_function_desc;
 use crate::aggregates::aggregate_sum::aggregate_sum_function_desc;
 
@@ -49,9 +81,26 @@ impl Aggregators {
 
         factory.register("covar_samp", aggregate_covariance_sample_desc());
         factory.register("covar_pop", aggregate_covariance_population_desc());
-        factory.register("stddev", aggregate_stddev_pop_function_desc());
+        factory.register("corr", aggregate_corr_desc());
+        factory.register("regr_slope", aggregate_regr_slope_desc());
+        factory.register("regr_intercept", aggregate_regr_intercept_desc());
+        factory.register("regr_r2", aggregate_regr_r2_desc());
+        factory.register("regr_avgx", aggregate_regr_avgx_desc());
+        factory.register("regr_avgy", aggregate_regr_avgy_desc());
+        factory.register("regr_sxx", aggregate_regr_sxx_desc());
+        factory.register("regr_syy", aggregate_regr_syy_desc());
+        factory.register("regr_sxy", aggregate_regr_sxy_desc());
+        // `stddev` and `variance` follow the SQL standard and return the sample value,
+        // `std` keeps the MySQL meaning of the population standard deviation.
+        factory.register("stddev", aggregate_stddev_samp_function_desc());
+        factory.register("stddev_samp", aggregate_stddev_samp_function_desc());
         factory.register("stddev_pop", aggregate_stddev_pop_function_desc());
         factory.register("std", aggregate_stddev_pop_function_desc());
+        factory.register("variance", aggregate_var_samp_function_desc());
+        factory.register("var_samp", aggregate_var_samp_function_desc());
+        factory.register("var_pop", aggregate_var_pop_function_desc());
+        factory.register("skewness", aggregate_skewness_function_desc());
+        factory.register("kurtosis", aggregate_kurtosis_function_desc());
         factory.register("window_funnel", aggregate_window_funnel_function_desc());
         factory.register(
             "approx_count_distinct",
@@ -59,6 +108,43 @@ impl Aggregators {
         );
 
         factory.register("retention", aggregate_retention_function_desc());
+
+        factory.register("median", aggregate_median_function_desc());
+        factory.register("quantile", aggregate_quantile_function_desc());
+        factory.register("quantiles", aggregate_quantiles_function_desc());
+        factory.register("percentile_cont", aggregate_percentile_cont_function_desc());
+        factory.register("percentile_disc", aggregate_percentile_disc_function_desc());
+        factory.register(
+            "quantile_tdigest",
+            aggregate_quantile_tdigest_function_desc(),
+        );
+
+        factory.register("array_agg", AggregateArrayAggFunction::desc());
+        factory.register("list", AggregateArrayAggFunction::desc());
+        factory.register("string_agg", aggregate_string_agg_function_desc());
+        factory.register("group_concat", aggregate_group_concat_function_desc());
+        factory.register("map_agg", AggregateMapAggFunction::desc());
+
+        factory.register("bitmap_agg", aggregate_bitmap_agg_function_desc());
+        factory.register("group_bitmap_state", aggregate_bitmap_agg_function_desc());
+        factory.register("group_bitmap", aggregate_group_bitmap_function_desc());
+        factory.register("bitmap_or_agg", aggregate_bitmap_or_function_desc());
+        factory.register("bitmap_union", aggregate_bitmap_or_function_desc());
+        factory.register("bitmap_and_agg", aggregate_bitmap_and_function_desc());
+        factory.register("bitmap_intersect", aggregate_bitmap_and_function_desc());
+        factory.register("bitmap_xor_agg", aggregate_bitmap_xor_function_desc());
+        factory.register(
+            "bitmap_union_count",
+            aggregate_bitmap_or_count_function_desc(),
+        );
+        factory.register(
+            "bitmap_intersect_count",
+            aggregate_bitmap_and_count_function_desc(),
+        );
+        factory.register(
+            "bitmap_xor_count",
+            aggregate_bitmap_xor_count_function_desc(),
+        );
     }
 
     pub fn register_combinator(factory: &mut AggregateFunctionFactory) {
@@ -126,3 +126,38 @@ pub fn eval_aggr(
     func.merge_result(eval.addr, &mut builder)?;
     Ok((builder.build(), data_type))
 }
+
+/// Evaluates the aggregate function for each group of the rows, `group_ids[i]` is the group
+/// of the `i`-th row. Returns the results of the groups, in the order of the group ids.
+pub fn eval_aggr_by_groups(
+    func: &AggregateFunctionRef,
+    columns: &[Column],
+    group_ids: &[usize],
+    num_groups: usize,
+) -> Result<Column> {
+    let arena = Bump::new();
+    let layout = func.state_layout();
+    let states: Vec<StateAddr> = (0..num_groups)
+        .map(|_| {
+            let addr: StateAddr = arena.alloc_layout(layout).into();
+            func.init_state(addr);
+            addr
+        })
+        .collect();
+    let places: Vec<StateAddr> = group_ids.iter().map(|id| states[*id]).collect();
+
+    let result = func
+        .accumulate_keys(&places, 0, columns, group_ids.len())
+        .and_then(|_| {
+            let mut builder = ColumnBuilder::with_capacity(&func.return_type()?, num_groups);
+            func.batch_merge_result(&states, &mut builder)?;
+            Ok(builder.build())
+        });
+
+    if func.need_manual_drop_state() {
+        for state in &states {
+            unsafe { func.drop_state(*state) }
+        }
+    }
+    result
+}
@@ -21,16 +21,22 @@ mod aggregate_function_state;
 mod adaptors;
 mod aggregate_approx_count_distinct;
 mod aggregate_arg_min_max;
+mod aggregate_array_agg;
 mod aggregate_avg;
+mod aggregate_bitmap;
 mod aggregate_combinator_distinct;
 mod aggregate_combinator_if;
 mod aggregate_covariance;
 mod aggregate_distinct_state;
+mod aggregate_map_agg;
 mod aggregate_min_max_any;
+mod aggregate_moments;
 mod aggregate_null_result;
+mod aggregate_quantile;
+mod aggregate_quantile_tdigest;
 mod aggregate_retention;
 mod aggregate_scalar_state;
-mod aggregate_stddev_pop;
+mod aggregate_string_agg;
 mod aggregate_sum;
 mod aggregate_window_funnel;
 mod aggregator;
@@ -38,6 +44,7 @@ mod aggregator_common;
 
 pub use adaptors::*;
 pub use aggregate_arg_min_max::AggregateArgMinMaxFunction;
+pub use aggregate_array_agg::AggregateArrayAggFunction;
 pub use aggregate_avg::AggregateAvgFunction;
 pub use aggregate_combinator_distinct::AggregateDistinctCombinator;
 pub use aggregate_combinator_if::AggregateIfCombinator;
@@ -49,6 +56,7 @@ pub use aggregate_function_factory::AggregateFunctionFactory;
 pub use aggregate_function_state::get_layout_offsets;
 pub use aggregate_function_state::StateAddr;
 pub use aggregate_function_state::StateAddrs;
+pub use aggregate_map_agg::AggregateMapAggFunction;
 pub use aggregate_min_max_any::AggregateMinMaxAnyFunction;
 pub use aggregate_null_result::AggregateNullResultFunction;
 pub use aggregate_retention::AggregateRetentionFunction;
@@ -19,10 +19,29 @@
 
 use aggregates::AggregateFunctionFactory;
 use scalars::BUILTIN_FUNCTIONS;
+use srfs::is_set_returning_function;
 
 pub mod aggregates;
 pub mod scalars;
+pub mod srfs;
+
+/// Higher-order functions that take an array and a lambda, like `array_transform(arr, x -> x + 1)`.
+/// They are evaluated by `common_expression::Evaluator` directly.
+pub const GENERAL_LAMBDA_FUNCTIONS: [&str; 5] = [
+    "array_transform",
+    "array_filter",
+    "array_reduce",
+    "array_any",
+    "array_all",
+];
 
 pub fn is_builtin_function(name: &str) -> bool {
-    BUILTIN_FUNCTIONS.contains(name) || AggregateFunctionFactory::instance().contains(name)
+    BUILTIN_FUNCTIONS.contains(name)
+        || AggregateFunctionFactory::instance().contains(name)
+        || is_set_returning_function(name)
+        || is_lambda_function(name)
+}
+
+pub fn is_lambda_function(name: &str) -> bool {
+    GENERAL_LAMBDA_FUNCTIONS.contains(&name)
 }
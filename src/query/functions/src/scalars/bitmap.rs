@@ -0,0 +1,149 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::types::bitmap::bitmap_to_string;
+use common_expression::types::bitmap::deserialize_bitmap;
+use common_expression::types::bitmap::parse_bitmap;
+use common_expression::types::bitmap::serialize_bitmap;
+use common_expression::types::string::StringColumnBuilder;
+use common_expression::types::BitmapType;
+use common_expression::types::BooleanType;
+use common_expression::types::NullableType;
+use common_expression::types::StringType;
+use common_expression::types::UInt64Type;
+use common_expression::vectorize_with_builder_1_arg;
+use common_expression::vectorize_with_builder_2_arg;
+use common_expression::EvalContext;
+use common_expression::FunctionDomain;
+use common_expression::FunctionProperty;
+use common_expression::FunctionRegistry;
+use roaring::RoaringTreemap;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, BitmapType, _, _>(
+        "to_bitmap",
+        FunctionProperty::default(),
+        |_| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<StringType, BitmapType>(
+            |s, output, ctx| match parse_bitmap(s) {
+                Ok(rb) => push_bitmap(&rb, output, ctx),
+                Err(err) => {
+                    ctx.set_error(output.len(), err.message());
+                    output.commit_row();
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<UInt64Type, BitmapType, _, _>(
+        "to_bitmap",
+        FunctionProperty::default(),
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<UInt64Type, BitmapType>(|v, output, ctx| {
+            let mut rb = RoaringTreemap::new();
+            rb.insert(v);
+            push_bitmap(&rb, output, ctx);
+        }),
+    );
+
+    registry.register_combine_nullable_1_arg::<StringType, BitmapType, _, _>(
+        "try_to_bitmap",
+        FunctionProperty::default(),
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<StringType, NullableType<BitmapType>>(|s, output, _| {
+            match parse_bitmap(s) {
+                Ok(rb) if serialize_bitmap(&rb, &mut output.builder.data).is_ok() => {
+                    output.validity.push(true);
+                    output.builder.commit_row();
+                }
+                _ => output.push_null(),
+            }
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<BitmapType, StringType, _, _>(
+        "to_string",
+        FunctionProperty::default(),
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<BitmapType, StringType>(|b, output, _| {
+            output.put_str(&bitmap_to_string(b));
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<BitmapType, UInt64Type, _, _>(
+        "bitmap_count",
+        FunctionProperty::default(),
+        |_| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<BitmapType, UInt64Type>(|b, output, ctx| {
+            match deserialize_bitmap(b) {
+                Ok(rb) => output.push(rb.len()),
+                Err(err) => {
+                    ctx.set_error(output.len(), err.message());
+                    output.push(0);
+                }
+            }
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<BitmapType, UInt64Type, BooleanType, _, _>(
+        "bitmap_contains",
+        FunctionProperty::default(),
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<BitmapType, UInt64Type, BooleanType>(|b, v, output, ctx| {
+            match deserialize_bitmap(b) {
+                Ok(rb) => output.push(rb.contains(v)),
+                Err(err) => {
+                    ctx.set_error(output.len(), err.message());
+                    output.push(false);
+                }
+            }
+        }),
+    );
+
+    register_bitmap_operation(registry, "bitmap_and", |lhs, rhs| *lhs &= rhs);
+    register_bitmap_operation(registry, "bitmap_or", |lhs, rhs| *lhs |= rhs);
+    register_bitmap_operation(registry, "bitmap_xor", |lhs, rhs| *lhs ^= rhs);
+}
+
+fn register_bitmap_operation(
+    registry: &mut FunctionRegistry,
+    name: &str,
+    op: fn(&mut RoaringTreemap, RoaringTreemap),
+) {
+    registry.register_passthrough_nullable_2_arg::<BitmapType, BitmapType, BitmapType, _, _>(
+        name,
+        FunctionProperty::default(),
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<BitmapType, BitmapType, BitmapType>(
+            move |lhs, rhs, output, ctx| match (deserialize_bitmap(lhs), deserialize_bitmap(rhs)) {
+                (Ok(mut lhs), Ok(rhs)) => {
+                    op(&mut lhs, rhs);
+                    push_bitmap(&lhs, output, ctx);
+                }
+                (Err(err), _) | (_, Err(err)) => {
+                    ctx.set_error(output.len(), err.message());
+                    output.commit_row();
+                }
+            },
+        ),
+    );
+}
+
+fn push_bitmap(rb: &RoaringTreemap, output: &mut StringColumnBuilder, ctx: &mut EvalContext) {
+    if let Err(err) = serialize_bitmap(rb, &mut output.data) {
+        ctx.set_error(output.len(), err.message());
+    }
+    output.commit_row();
+}
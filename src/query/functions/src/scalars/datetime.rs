@@ -16,6 +16,7 @@ use std::io::Write;
 
 use chrono::Datelike;
 use chrono::Utc;
+use chrono_tz::Tz;
 use common_arrow::arrow::temporal_conversions::EPOCH_DAYS_FROM_CE;
 use common_expression::error_to_null;
 use common_expression::types::date::check_date;
@@ -50,12 +51,14 @@ use common_expression::vectorize_1_arg;
 use common_expression::vectorize_2_arg;
 use common_expression::vectorize_with_builder_1_arg;
 use common_expression::vectorize_with_builder_2_arg;
+use common_expression::vectorize_with_builder_3_arg;
 use common_expression::EvalContext;
 use common_expression::FunctionDomain;
 use common_expression::FunctionProperty;
 use common_expression::FunctionRegistry;
 use common_expression::Value;
 use common_expression::ValueRef;
+use common_io::cursor_ext::unwrap_local_time;
 use num_traits::AsPrimitive;
 
 pub fn register(registry: &mut FunctionRegistry) {
@@ -96,6 +99,10 @@ pub fn register(registry: &mut FunctionRegistry) {
 
     // [date | timestamp] +/- number
     register_timestamp_add_sub(registry);
+
+    // convert_timezone, to_utc_timestamp, from_utc_timestamp
+    // to_*(timestamp, timezone) evaluated in an explicit timezone
+    register_timezone_functions(registry);
 }
 
 /// Check if timestamp is within range, and return the timestamp in micros.
@@ -1293,3 +1300,153 @@ fn register_rounder_functions(registry: &mut FunctionRegistry) {
         }),
     );
 }
+
+/// Resolve a timezone name passed as a function argument.
+fn parse_timezone(name: &[u8]) -> Result<TzLUT, String> {
+    std::str::from_utf8(name)
+        .ok()
+        .and_then(|name| TzFactory::instance().get_by_name(name).ok())
+        .ok_or_else(|| format!("invalid timezone: {}", String::from_utf8_lossy(name)))
+}
+
+/// Reinterpret the wall clock of `us` (as seen in the session timezone) as a local
+/// time in `from`, and return the timestamp showing the wall clock of `to` at that
+/// instant in the session timezone.
+fn convert_wall_clock(us: i64, from: Tz, to: Tz, session: Tz) -> Result<i64, String> {
+    let wall = us.to_timestamp(session).naive_local();
+    let wall = unwrap_local_time(&from, &wall)
+        .with_timezone(&to)
+        .naive_local();
+    check_timestamp(unwrap_local_time(&session, &wall).timestamp_micros())
+}
+
+fn register_timezone_functions(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_2_arg::<StringType, TimestampType, TimestampType, _, _>(
+        "convert_timezone",
+        FunctionProperty::default(),
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<StringType, TimestampType, TimestampType>(
+            |target, val, output, ctx| match parse_timezone(target)
+                .and_then(|target| convert_wall_clock(val, ctx.tz.tz, target.tz, ctx.tz.tz))
+            {
+                Ok(ts) => output.push(ts),
+                Err(e) => {
+                    ctx.set_error(output.len(), e);
+                    output.push(0);
+                }
+            },
+        ),
+    );
+    registry.register_passthrough_nullable_3_arg::<StringType, StringType, TimestampType, TimestampType, _, _>(
+        "convert_timezone",
+        FunctionProperty::default(),
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_3_arg::<StringType, StringType, TimestampType, TimestampType>(
+            |source, target, val, output, ctx| {
+                let ts = parse_timezone(source).and_then(|source| {
+                    let target = parse_timezone(target)?;
+                    convert_wall_clock(val, source.tz, target.tz, ctx.tz.tz)
+                });
+                match ts {
+                    Ok(ts) => output.push(ts),
+                    Err(e) => {
+                        ctx.set_error(output.len(), e);
+                        output.push(0);
+                    }
+                }
+            },
+        ),
+    );
+    registry.register_passthrough_nullable_2_arg::<TimestampType, StringType, TimestampType, _, _>(
+        "to_utc_timestamp",
+        FunctionProperty::default(),
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<TimestampType, StringType, TimestampType>(
+            |val, tz, output, ctx| match parse_timezone(tz)
+                .and_then(|tz| convert_wall_clock(val, tz.tz, Tz::UTC, ctx.tz.tz))
+            {
+                Ok(ts) => output.push(ts),
+                Err(e) => {
+                    ctx.set_error(output.len(), e);
+                    output.push(0);
+                }
+            },
+        ),
+    );
+    registry.register_passthrough_nullable_2_arg::<TimestampType, StringType, TimestampType, _, _>(
+        "from_utc_timestamp",
+        FunctionProperty::default(),
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<TimestampType, StringType, TimestampType>(
+            |val, tz, output, ctx| match parse_timezone(tz)
+                .and_then(|tz| convert_wall_clock(val, Tz::UTC, tz.tz, ctx.tz.tz))
+            {
+                Ok(ts) => output.push(ts),
+                Err(e) => {
+                    ctx.set_error(output.len(), e);
+                    output.push(0);
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<TimestampType, StringType, DateType, _, _>(
+        "to_date",
+        FunctionProperty::default(),
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<TimestampType, StringType, DateType>(
+            |val, tz, output, ctx| match parse_timezone(tz) {
+                Ok(tz) => {
+                    let date = val.to_timestamp(tz.tz).naive_local().date();
+                    output.push(date.num_days_from_ce() - EPOCH_DAYS_FROM_CE);
+                }
+                Err(e) => {
+                    ctx.set_error(output.len(), e);
+                    output.push(0);
+                }
+            },
+        ),
+    );
+    registry.register_passthrough_nullable_2_arg::<TimestampType, StringType, StringType, _, _>(
+        "to_string",
+        FunctionProperty::default(),
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<TimestampType, StringType, StringType>(
+            |val, tz, output, ctx| {
+                match parse_timezone(tz) {
+                    Ok(tz) => write!(output.data, "{}", timestamp_to_string(val, tz.tz)).unwrap(),
+                    Err(e) => ctx.set_error(output.len(), e),
+                }
+                output.commit_row();
+            },
+        ),
+    );
+    registry.register_passthrough_nullable_2_arg::<TimestampType, StringType, UInt8Type, _, _>(
+        "to_hour",
+        FunctionProperty::default(),
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<TimestampType, StringType, UInt8Type>(
+            |val, tz, output, ctx| match parse_timezone(tz) {
+                Ok(tz) => output.push(tz.to_hour(val)),
+                Err(e) => {
+                    ctx.set_error(output.len(), e);
+                    output.push(0);
+                }
+            },
+        ),
+    );
+    registry.register_passthrough_nullable_2_arg::<TimestampType, StringType, TimestampType, _, _>(
+        "to_start_of_day",
+        FunctionProperty::default(),
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<TimestampType, StringType, TimestampType>(
+            |val, tz, output, ctx| match parse_timezone(tz) {
+                Ok(tz) => output.push(tz.round_us(val, Round::Day)),
+                Err(e) => {
+                    ctx.set_error(output.len(), e);
+                    output.push(0);
+                }
+            },
+        ),
+    );
+}
@@ -351,7 +351,7 @@ impl DFHash for Scalar {
                     DFHash::hash(v, state);
                 }
             }),
-            Scalar::String(vals) | Scalar::Variant(vals) => {
+            Scalar::String(vals) | Scalar::Variant(vals) | Scalar::Bitmap(vals) => {
                 for v in vals {
                     DFHash::hash(v, state);
                 }
@@ -23,6 +23,7 @@ use ctor::ctor;
 mod arithmetic;
 mod arithmetic_modulo;
 mod array;
+mod bitmap;
 mod boolean;
 mod control;
 mod datetime;
@@ -56,6 +57,7 @@ fn builtin_functions() -> FunctionRegistry {
     variant::register(&mut registry);
     arithmetic::register(&mut registry);
     array::register(&mut registry);
+    bitmap::register(&mut registry);
     boolean::register(&mut registry);
     control::register(&mut registry);
     comparison::register(&mut registry);
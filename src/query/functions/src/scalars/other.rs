@@ -25,6 +25,8 @@ use common_expression::types::boolean::BooleanDomain;
 use common_expression::types::nullable::NullableColumn;
 use common_expression::types::number::Float64Type;
 use common_expression::types::number::Int64Type;
+use common_expression::types::number::NumberColumn;
+use common_expression::types::number::NumberScalar;
 use common_expression::types::number::UInt32Type;
 use common_expression::types::number::UInt8Type;
 use common_expression::types::number::F64;
@@ -35,12 +37,14 @@ use common_expression::types::DateType;
 use common_expression::types::GenericType;
 use common_expression::types::NullType;
 use common_expression::types::NullableType;
+use common_expression::types::NumberDataType;
 use common_expression::types::NumberType;
 use common_expression::types::SimpleDomain;
 use common_expression::types::StringType;
 use common_expression::types::TimestampType;
 use common_expression::types::ValueType;
 use common_expression::vectorize_with_builder_1_arg;
+use common_expression::Column;
 use common_expression::Domain;
 use common_expression::EvalContext;
 use common_expression::Function;
@@ -49,6 +53,7 @@ use common_expression::FunctionProperty;
 use common_expression::FunctionRegistry;
 use common_expression::FunctionSignature;
 use common_expression::Scalar;
+use common_expression::ScalarRef;
 use common_expression::Value;
 use common_expression::ValueRef;
 use ordered_float::OrderedFloat;
@@ -66,6 +71,7 @@ pub fn register(registry: &mut FunctionRegistry) {
     register_inet_aton(registry);
     register_inet_ntoa(registry);
     register_run_diff(registry);
+    register_grouping(registry);
 
     registry.register_passthrough_nullable_1_arg::<Float64Type, StringType, _, _>(
         "humanize_size",
@@ -343,3 +349,45 @@ fn register_run_diff(registry: &mut FunctionRegistry) {
         OrderedFloat(0.0)
     );
 }
+
+/// `grouping<i, j, ...>(_grouping_id)` returns the bits of `_grouping_id` at the
+/// positions of its parameters, the first parameter is the most significant bit.
+fn register_grouping(registry: &mut FunctionRegistry) {
+    registry.register_function_factory("grouping", |params, arg_type| {
+        if arg_type.len() != 1 || params.is_empty() {
+            return None;
+        }
+        let params = params.to_vec();
+        Some(Arc::new(Function {
+            signature: FunctionSignature {
+                name: "grouping".to_string(),
+                args_type: vec![DataType::Number(NumberDataType::UInt32)],
+                return_type: DataType::Number(NumberDataType::UInt32),
+                property: FunctionProperty::default(),
+            },
+            calc_domain: Box::new(|_| FunctionDomain::Full),
+            eval: Box::new(move |args, _| match &args[0] {
+                ValueRef::Scalar(ScalarRef::Number(NumberScalar::UInt32(grouping_id))) => {
+                    Value::Scalar(Scalar::Number(NumberScalar::UInt32(compute_grouping(
+                        &params,
+                        *grouping_id,
+                    ))))
+                }
+                ValueRef::Column(Column::Number(NumberColumn::UInt32(column))) => {
+                    let output = column
+                        .iter()
+                        .map(|grouping_id| compute_grouping(&params, *grouping_id))
+                        .collect::<Vec<_>>();
+                    Value::Column(Column::Number(NumberColumn::UInt32(output.into())))
+                }
+                _ => unreachable!(),
+            }),
+        }))
+    });
+}
+
+fn compute_grouping(params: &[usize], grouping_id: u32) -> u32 {
+    params.iter().fold(0, |grouping, position| {
+        (grouping << 1) | ((grouping_id >> position) & 1)
+    })
+}
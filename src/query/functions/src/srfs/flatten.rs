@@ -0,0 +1,144 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Cow;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::AnyType;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::ScalarRef;
+use common_expression::ValueRef;
+use common_jsonb::array_values;
+use common_jsonb::get_by_path;
+use common_jsonb::object_each;
+use common_jsonb::parse_json_path;
+
+/// FLATTEN(variant[, path]) returns a row for each element of the array or each field
+/// of the object at the path, with the columns:
+/// - `index`: the index of the element in the array, NULL for object.
+/// - `key`: the key of the field in the object, NULL for array.
+/// - `value`: the value of the element or the field.
+pub fn return_types(args: &[DataType]) -> Result<Vec<(String, DataType)>> {
+    if args.is_empty() || args.len() > 2 {
+        return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+            "flatten expects 1 or 2 arguments, but got {}",
+            args.len()
+        )));
+    }
+    if !matches!(
+        args[0].remove_nullable(),
+        DataType::Variant | DataType::Null
+    ) {
+        return Err(ErrorCode::SemanticError(format!(
+            "flatten expects a variant argument, but got {}",
+            args[0]
+        )));
+    }
+    if let Some(path) = args.get(1) {
+        if !matches!(path.remove_nullable(), DataType::String | DataType::Null) {
+            return Err(ErrorCode::SemanticError(format!(
+                "flatten expects a string path, but got {path}"
+            )));
+        }
+    }
+    Ok(vec![
+        (
+            "index".to_string(),
+            DataType::Nullable(Box::new(DataType::Number(NumberDataType::UInt64))),
+        ),
+        (
+            "key".to_string(),
+            DataType::Nullable(Box::new(DataType::String)),
+        ),
+        ("value".to_string(), DataType::Variant),
+    ])
+}
+
+pub fn eval(
+    args: &[ValueRef<AnyType>],
+    return_types: &[DataType],
+    num_rows: usize,
+) -> Result<(Vec<Column>, Vec<usize>)> {
+    let mut index_builder = ColumnBuilder::with_capacity(&return_types[0], num_rows);
+    let mut key_builder = ColumnBuilder::with_capacity(&return_types[1], num_rows);
+    let mut value_builder = ColumnBuilder::with_capacity(&return_types[2], num_rows);
+    let mut repeats = Vec::with_capacity(num_rows);
+
+    for row in 0..num_rows {
+        let val = match args[0].index(row) {
+            Some(ScalarRef::Variant(val)) if !val.is_empty() => val,
+            _ => {
+                repeats.push(0);
+                continue;
+            }
+        };
+        let val = match args.get(1).map(|path| path.index(row)) {
+            None => Cow::Borrowed(val),
+            Some(Some(ScalarRef::String(path))) if path.is_empty() => Cow::Borrowed(val),
+            Some(Some(ScalarRef::String(path))) => {
+                let json_paths = parse_json_path(path).map_err(|err| {
+                    ErrorCode::BadArguments(format!(
+                        "Invalid extraction path '{}': {}",
+                        &String::from_utf8_lossy(path),
+                        err
+                    ))
+                })?;
+                match get_by_path(val, json_paths) {
+                    Some(val) => Cow::Owned(val),
+                    None => {
+                        repeats.push(0);
+                        continue;
+                    }
+                }
+            }
+            Some(_) => {
+                repeats.push(0);
+                continue;
+            }
+        };
+
+        if let Some(items) = array_values(&val) {
+            for (i, item) in items.iter().enumerate() {
+                index_builder.push(ScalarRef::Number(NumberScalar::UInt64(i as u64)));
+                key_builder.push(ScalarRef::Null);
+                value_builder.push(ScalarRef::Variant(item));
+            }
+            repeats.push(items.len());
+        } else if let Some(items) = object_each(&val) {
+            for (key, item) in items.iter() {
+                index_builder.push(ScalarRef::Null);
+                key_builder.push(ScalarRef::String(key));
+                value_builder.push(ScalarRef::Variant(item));
+            }
+            repeats.push(items.len());
+        } else {
+            // A scalar value can not be flattened.
+            repeats.push(0);
+        }
+    }
+
+    Ok((
+        vec![
+            index_builder.build(),
+            key_builder.build(),
+            value_builder.build(),
+        ],
+        repeats,
+    ))
+}
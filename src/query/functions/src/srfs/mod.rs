@@ -0,0 +1,64 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Set-returning functions, which return a set of rows for each input row.
+
+mod flatten;
+mod unnest;
+
+use common_exception::Result;
+use common_expression::types::AnyType;
+use common_expression::types::DataType;
+use common_expression::Column;
+use common_expression::ValueRef;
+use once_cell::sync::Lazy;
+
+/// Check the types of the arguments, return the names and the types of the output columns.
+pub type SrfReturnTypes = fn(&[DataType]) -> Result<Vec<(String, DataType)>>;
+
+/// Evaluate the function with the arguments of `num_rows` rows and the types of the
+/// output columns, return the output columns and the number of output rows of each input row.
+pub type SrfEval =
+    fn(&[ValueRef<AnyType>], &[DataType], usize) -> Result<(Vec<Column>, Vec<usize>)>;
+
+pub struct SetReturningFunction {
+    pub name: &'static str,
+    pub return_types: SrfReturnTypes,
+    pub eval: SrfEval,
+}
+
+pub static BUILTIN_SET_RETURNING_FUNCTIONS: Lazy<Vec<SetReturningFunction>> = Lazy::new(|| {
+    vec![
+        SetReturningFunction {
+            name: "unnest",
+            return_types: unnest::return_types,
+            eval: unnest::eval,
+        },
+        SetReturningFunction {
+            name: "flatten",
+            return_types: flatten::return_types,
+            eval: flatten::eval,
+        },
+    ]
+});
+
+pub fn get_set_returning_function(name: &str) -> Option<&'static SetReturningFunction> {
+    BUILTIN_SET_RETURNING_FUNCTIONS
+        .iter()
+        .find(|srf| srf.name.eq_ignore_ascii_case(name))
+}
+
+pub fn is_set_returning_function(name: &str) -> bool {
+    get_set_returning_function(name).is_some()
+}
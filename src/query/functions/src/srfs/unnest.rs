@@ -0,0 +1,64 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::AnyType;
+use common_expression::types::DataType;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::ScalarRef;
+use common_expression::ValueRef;
+
+/// UNNEST(array) returns a row for each element of the array, NULL and empty array
+/// return no rows.
+pub fn return_types(args: &[DataType]) -> Result<Vec<(String, DataType)>> {
+    if args.len() != 1 {
+        return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+            "unnest expects 1 argument, but got {}",
+            args.len()
+        )));
+    }
+    let value_type = match args[0].remove_nullable() {
+        DataType::Array(box ty) => ty,
+        DataType::EmptyArray | DataType::Null => DataType::Null,
+        ty => {
+            return Err(ErrorCode::SemanticError(format!(
+                "unnest expects an array argument, but got {ty}"
+            )));
+        }
+    };
+    Ok(vec![("value".to_string(), value_type)])
+}
+
+pub fn eval(
+    args: &[ValueRef<AnyType>],
+    return_types: &[DataType],
+    num_rows: usize,
+) -> Result<(Vec<Column>, Vec<usize>)> {
+    let mut builder = ColumnBuilder::with_capacity(&return_types[0], num_rows);
+    let mut repeats = Vec::with_capacity(num_rows);
+    for row in 0..num_rows {
+        match args[0].index(row) {
+            Some(ScalarRef::Array(array)) => {
+                for item in array.iter() {
+                    builder.push(item);
+                }
+                repeats.push(array.len());
+            }
+            _ => repeats.push(0),
+        }
+    }
+    Ok((vec![builder.build()], repeats))
+}
@@ -45,10 +45,15 @@ fn test_agg() {
     test_agg_arg_max(file, eval_aggr);
     test_agg_covar_samp(file, eval_aggr);
     test_agg_covar_pop(file, eval_aggr);
+    test_agg_corr_regr(file, eval_aggr);
     test_agg_retention(file, eval_aggr);
-    test_agg_stddev_pop(file, eval_aggr);
+    test_agg_stddev(file, eval_aggr);
+    test_agg_moments(file, eval_aggr);
     test_agg_window_funnel(file, eval_aggr);
     test_agg_approx_count_distinct(file, eval_aggr);
+    test_agg_quantile(file, eval_aggr);
+    test_agg_collect(file, eval_aggr);
+    test_agg_bitmap(file, eval_aggr);
 }
 
 #[test]
@@ -69,10 +74,15 @@ fn test_agg_group_by() {
     test_agg_arg_max(file, simulate_two_groups_group_by);
     test_agg_covar_samp(file, simulate_two_groups_group_by);
     test_agg_covar_pop(file, simulate_two_groups_group_by);
+    test_agg_corr_regr(file, simulate_two_groups_group_by);
     test_agg_retention(file, simulate_two_groups_group_by);
-    test_agg_stddev_pop(file, simulate_two_groups_group_by);
+    test_agg_stddev(file, simulate_two_groups_group_by);
+    test_agg_moments(file, simulate_two_groups_group_by);
     test_agg_window_funnel(file, simulate_two_groups_group_by);
     test_agg_approx_count_distinct(file, simulate_two_groups_group_by);
+    test_agg_quantile(file, simulate_two_groups_group_by);
+    test_agg_collect(file, simulate_two_groups_group_by);
+    test_agg_bitmap(file, simulate_two_groups_group_by);
 }
 
 fn get_example() -> Vec<(&'static str, Column)> {
@@ -304,6 +314,25 @@ fn test_agg_covar_pop(file: &mut impl Write, simulator: impl AggregationSimulato
     );
 }
 
+fn test_agg_corr_regr(file: &mut impl Write, simulator: impl AggregationSimulator) {
+    run_agg_ast(file, "corr(a, b)", get_example().as_slice(), simulator);
+    run_agg_ast(file, "corr(b, c)", get_example().as_slice(), simulator);
+    run_agg_ast(
+        file,
+        "regr_slope(c, b)",
+        get_example().as_slice(),
+        simulator,
+    );
+    run_agg_ast(
+        file,
+        "regr_intercept(c, b)",
+        get_example().as_slice(),
+        simulator,
+    );
+    run_agg_ast(file, "regr_r2(c, b)", get_example().as_slice(), simulator);
+    run_agg_ast(file, "regr_sxy(c, b)", get_example().as_slice(), simulator);
+}
+
 fn test_agg_retention(file: &mut impl Write, simulator: impl AggregationSimulator) {
     run_agg_ast(
         file,
@@ -325,12 +354,25 @@ fn test_agg_retention(file: &mut impl Write, simulator: impl AggregationSimulato
     );
 }
 
-fn test_agg_stddev_pop(file: &mut impl Write, simulator: impl AggregationSimulator) {
+fn test_agg_stddev(file: &mut impl Write, simulator: impl AggregationSimulator) {
     run_agg_ast(file, "stddev_pop(a)", get_example().as_slice(), simulator);
     run_agg_ast(file, "stddev(x_null)", get_example().as_slice(), simulator);
     run_agg_ast(file, "std(1.0)", get_example().as_slice(), simulator);
 }
 
+fn test_agg_moments(file: &mut impl Write, simulator: impl AggregationSimulator) {
+    run_agg_ast(file, "var_pop(a)", get_example().as_slice(), simulator);
+    run_agg_ast(file, "var_samp(b)", get_example().as_slice(), simulator);
+    run_agg_ast(
+        file,
+        "variance(x_null)",
+        get_example().as_slice(),
+        simulator,
+    );
+    run_agg_ast(file, "skewness(c)", get_example().as_slice(), simulator);
+    run_agg_ast(file, "kurtosis(c)", get_example().as_slice(), simulator);
+}
+
 fn test_agg_window_funnel(file: &mut impl Write, simulator: impl AggregationSimulator) {
     run_agg_ast(
         file,
@@ -360,3 +402,53 @@ fn test_agg_approx_count_distinct(file: &mut impl Write, simulator: impl Aggrega
         simulator,
     );
 }
+
+fn test_agg_quantile(file: &mut impl Write, simulator: impl AggregationSimulator) {
+    run_agg_ast(file, "median(a)", get_example().as_slice(), simulator);
+    run_agg_ast(file, "median(x_null)", get_example().as_slice(), simulator);
+    run_agg_ast(
+        file,
+        "quantiles(0, 1)(b)",
+        get_example().as_slice(),
+        simulator,
+    );
+    run_agg_ast(
+        file,
+        "percentile_disc(1)(a)",
+        get_example().as_slice(),
+        simulator,
+    );
+    run_agg_ast(
+        file,
+        "quantile_tdigest(a)",
+        get_example().as_slice(),
+        simulator,
+    );
+}
+
+fn test_agg_collect(file: &mut impl Write, simulator: impl AggregationSimulator) {
+    run_agg_ast(file, "array_agg(a)", get_example().as_slice(), simulator);
+    run_agg_ast(
+        file,
+        "array_agg(x_null)",
+        get_example().as_slice(),
+        simulator,
+    );
+    run_agg_ast(
+        file,
+        "array_agg(0, 0)(a, b)",
+        get_example().as_slice(),
+        simulator,
+    );
+    run_agg_ast(file, "map_agg(c, a)", get_example().as_slice(), simulator);
+}
+
+fn test_agg_bitmap(file: &mut impl Write, simulator: impl AggregationSimulator) {
+    run_agg_ast(file, "group_bitmap(c)", get_example().as_slice(), simulator);
+    run_agg_ast(
+        file,
+        "group_bitmap(x_null)",
+        get_example().as_slice(),
+        simulator,
+    );
+}
@@ -13,6 +13,7 @@
 //  limitations under the License.
 
 mod sort;
+pub mod spill;
 pub mod transform;
 pub mod transform_accumulating;
 pub mod transform_block_compact;
@@ -21,7 +22,9 @@ pub mod transform_compact;
 pub mod transform_multi_sort_merge;
 pub mod transform_sort_merge;
 pub mod transform_sort_partial;
+pub mod transform_sort_spill;
 
+pub use spill::*;
 pub use transform::*;
 pub use transform_accumulating::*;
 pub use transform_block_compact::*;
@@ -29,3 +32,4 @@ pub use transform_compact::*;
 pub use transform_multi_sort_merge::*;
 pub use transform_sort_merge::*;
 pub use transform_sort_partial::*;
+pub use transform_sort_spill::*;
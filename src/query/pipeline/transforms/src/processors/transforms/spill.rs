@@ -0,0 +1,153 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs::File;
+use std::fs::OpenOptions;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::utils::arrow::deserialize_column;
+use common_expression::utils::arrow::serialize_column;
+use common_expression::DataBlock;
+
+/// Appends blocks to a temporary spill file.
+///
+/// A block is stored as its columns, each prefixed by the length of the serialized column, so
+/// that it can be read back alone with `read_spilled_block` by its (offset, length).
+pub struct SpillWriter {
+    path: PathBuf,
+    size: u64,
+}
+
+impl SpillWriter {
+    pub fn create(path: PathBuf) -> Self {
+        SpillWriter { path, size: 0 }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The bytes written to the file.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Append the block to the file, returns the (offset, length) of the block in the file.
+    pub fn append(&mut self, block: &DataBlock) -> Result<(u64, usize)> {
+        let mut data = vec![];
+        for entry in block.convert_to_full().columns() {
+            let column = serialize_column(entry.value.as_column().unwrap());
+            data.extend_from_slice(&(column.len() as u64).to_le_bytes());
+            data.extend_from_slice(&column);
+        }
+
+        if self.size == 0 {
+            if let Some(spill_dir) = self.path.parent() {
+                std::fs::create_dir_all(spill_dir)?;
+            }
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        file.write_all(&data)?;
+        file.flush()?;
+
+        let position = (self.size, data.len());
+        self.size += data.len() as u64;
+        Ok(position)
+    }
+}
+
+/// Read back a block written by `SpillWriter` at (offset, length) of the file.
+pub fn read_spilled_block(path: &Path, offset: u64, length: usize) -> Result<DataBlock> {
+    let mut file = File::open(path)?;
+    file.seek(SeekFrom::Start(offset))?;
+    let mut data = vec![0; length];
+    file.read_exact(&mut data)?;
+
+    let mut columns = vec![];
+    let mut cursor = 0;
+    while cursor < data.len() {
+        let mut len = [0; 8];
+        len.copy_from_slice(&data[cursor..cursor + 8]);
+        let len = u64::from_le_bytes(len) as usize;
+        cursor += 8;
+
+        let column = deserialize_column(&data[cursor..cursor + len]).ok_or_else(|| {
+            ErrorCode::Internal(format!(
+                "Cannot deserialize the spilled block in {:?}",
+                path
+            ))
+        })?;
+        columns.push(column);
+        cursor += len;
+    }
+    Ok(DataBlock::new_from_columns(columns))
+}
+
+/// A sequence of blocks spilled to a temporary file, the file is removed on drop.
+pub struct SpilledBlocks {
+    writer: SpillWriter,
+    blocks: Vec<(u64, usize)>,
+}
+
+impl SpilledBlocks {
+    pub fn create(path: PathBuf) -> Self {
+        SpilledBlocks {
+            writer: SpillWriter::create(path),
+            blocks: vec![],
+        }
+    }
+
+    pub fn append(&mut self, block: &DataBlock) -> Result<()> {
+        let position = self.writer.append(block)?;
+        self.blocks.push(position);
+        Ok(())
+    }
+
+    /// Read back the `index`-th block appended.
+    pub fn read(&self, index: usize) -> Result<DataBlock> {
+        let (offset, length) = self.blocks[index];
+        read_spilled_block(self.writer.path(), offset, length)
+    }
+
+    pub fn num_blocks(&self) -> usize {
+        self.blocks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.blocks.is_empty()
+    }
+
+    /// The bytes spilled to the file.
+    pub fn size(&self) -> u64 {
+        self.writer.size()
+    }
+}
+
+impl Drop for SpilledBlocks {
+    fn drop(&mut self) {
+        if self.writer.size() != 0 {
+            let _ = std::fs::remove_file(self.writer.path());
+        }
+    }
+}
@@ -0,0 +1,463 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::collections::VecDeque;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_arrow::arrow::compute::sort::row::RowConverter as ArrowRowConverter;
+use common_arrow::arrow::compute::sort::row::Rows as ArrowRows;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::DateType;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::with_number_mapped_type;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::SortColumnDescription;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::Event;
+use common_pipeline_core::processors::Processor;
+
+use super::sort::Cursor;
+use super::sort::RowConverter;
+use super::sort::Rows;
+use super::sort::SimpleRowConverter;
+use super::sort::SimpleRows;
+use super::Compactor;
+use super::SortMergeCompactor;
+use super::SpilledBlocks;
+
+static SPILLED_RUN_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// A sorted run spilled to a temporary file, stored as a sequence of sorted blocks.
+struct SpilledRun {
+    blocks: SpilledBlocks,
+    /// The index of the next block to restore.
+    next_block: usize,
+}
+
+impl SpilledRun {
+    fn create(spill_dir: &Path, blocks: Vec<DataBlock>) -> Result<Self> {
+        let id = SPILLED_RUN_ID.fetch_add(1, Ordering::Relaxed);
+        let path = spill_dir.join(format!("sort_run_{}_{}", std::process::id(), id));
+        let mut spilled = SpilledBlocks::create(path);
+        for block in blocks {
+            spilled.append(&block)?;
+        }
+
+        Ok(SpilledRun {
+            blocks: spilled,
+            next_block: 0,
+        })
+    }
+
+    /// Read back the next sorted block of the run, return `None` if the run is drained.
+    fn restore_next(&mut self) -> Result<Option<DataBlock>> {
+        if self.next_block >= self.blocks.num_blocks() {
+            return Ok(None);
+        }
+        let block = self.blocks.read(self.next_block)?;
+        self.next_block += 1;
+        Ok(Some(block))
+    }
+}
+
+enum SortSpillState {
+    Consume,
+    Spill,
+    Finalize,
+    Merge,
+    Finished,
+}
+
+/// External sort of the sorted blocks of a single input.
+///
+/// The blocks are buffered in memory and sorted like `SortMergeCompactor`, but once the buffered
+/// bytes exceed `spilling_bytes_threshold`, the buffer is sorted into a run and spilled to
+/// `spill_dir`. When the input is finished, all the runs are merged with a k-way merge, keeping only
+/// one block of each run in memory.
+pub struct TransformSortSpill<R, Converter> {
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+    state: SortSpillState,
+
+    block_size: usize,
+    sort_columns_descriptions: Vec<SortColumnDescription>,
+    spilling_bytes_threshold: usize,
+    spill_dir: PathBuf,
+    aborting: Arc<AtomicBool>,
+
+    compactor: SortMergeCompactor<R, Converter>,
+    row_converter: Converter,
+
+    buffered_blocks: Vec<DataBlock>,
+    buffered_bytes: usize,
+    spilled_runs: Vec<SpilledRun>,
+
+    /// The current block of each spilled run during merging.
+    merging_blocks: Vec<DataBlock>,
+    heap: BinaryHeap<Reverse<Cursor<R>>>,
+    output_blocks: VecDeque<DataBlock>,
+}
+
+impl<R, Converter> TransformSortSpill<R, Converter>
+where
+    R: Rows + Send + 'static,
+    Converter: RowConverter<R> + Send + 'static,
+{
+    pub fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        schema: DataSchemaRef,
+        block_size: usize,
+        sort_columns_descriptions: Vec<SortColumnDescription>,
+        spilling_bytes_threshold: usize,
+        spill_dir: PathBuf,
+    ) -> Result<Box<dyn Processor>> {
+        let compactor = SortMergeCompactor::try_create(
+            schema.clone(),
+            block_size,
+            None,
+            sort_columns_descriptions.clone(),
+        )?;
+        let row_converter = Converter::create(sort_columns_descriptions.clone(), schema)?;
+
+        Ok(Box::new(TransformSortSpill {
+            input,
+            output,
+            state: SortSpillState::Consume,
+            block_size,
+            sort_columns_descriptions,
+            spilling_bytes_threshold,
+            spill_dir,
+            aborting: Arc::new(AtomicBool::new(false)),
+            compactor,
+            row_converter,
+            buffered_blocks: vec![],
+            buffered_bytes: 0,
+            spilled_runs: vec![],
+            merging_blocks: vec![],
+            heap: BinaryHeap::new(),
+            output_blocks: VecDeque::new(),
+        }))
+    }
+
+    fn spill_buffered_blocks(&mut self) -> Result<()> {
+        let blocks = std::mem::take(&mut self.buffered_blocks);
+        self.buffered_bytes = 0;
+
+        let sorted_blocks = self.compactor.compact_final(&blocks)?;
+        drop(blocks);
+        if !sorted_blocks.is_empty() {
+            let run = SpilledRun::create(&self.spill_dir, sorted_blocks)?;
+            self.spilled_runs.push(run);
+        }
+        Ok(())
+    }
+
+    fn push_cursor(&mut self, run_index: usize, block: DataBlock) -> Result<()> {
+        let columns = self
+            .sort_columns_descriptions
+            .iter()
+            .map(|desc| block.get_by_offset(desc.offset).clone())
+            .collect::<Vec<_>>();
+        let rows = self.row_converter.convert(&columns, block.num_rows())?;
+        self.merging_blocks[run_index] = block;
+        self.heap.push(Reverse(Cursor::try_create(run_index, rows)));
+        Ok(())
+    }
+
+    fn start_merge(&mut self) -> Result<()> {
+        self.merging_blocks = vec![DataBlock::empty(); self.spilled_runs.len()];
+        for run_index in 0..self.spilled_runs.len() {
+            if let Some(block) = self.spilled_runs[run_index].restore_next()? {
+                self.push_cursor(run_index, block)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Merge the next `block_size` rows of the spilled runs into an output block.
+    fn merge_next_block(&mut self) -> Result<Option<DataBlock>> {
+        let mut parts = vec![];
+        let mut merge_slices: Vec<(usize, usize, usize)> = vec![];
+        let mut rows = 0;
+
+        while rows < self.block_size {
+            let mut cursor = match self.heap.pop() {
+                Some(Reverse(cursor)) => cursor,
+                None => break,
+            };
+
+            let run_index = cursor.input_index;
+            while rows < self.block_size && !cursor.is_finished() {
+                if let Some(next_cursor) = self.heap.peek() {
+                    if cursor.gt(&next_cursor.0) {
+                        break;
+                    }
+                }
+
+                let row_index = cursor.advance();
+                match merge_slices.last_mut() {
+                    Some((block_idx, start, len))
+                        if *block_idx == run_index && *start + *len == row_index =>
+                    {
+                        *len += 1
+                    }
+                    _ => merge_slices.push((run_index, row_index, 1)),
+                }
+                rows += 1;
+            }
+
+            if !cursor.is_finished() {
+                self.heap.push(Reverse(cursor));
+                continue;
+            }
+
+            // The current block of the run is drained, take the pending rows out before
+            // replacing it with the next block of the run.
+            if !merge_slices.is_empty() {
+                parts.push(DataBlock::take_by_slices_limit_from_blocks(
+                    &self.merging_blocks,
+                    &merge_slices,
+                    None,
+                ));
+                merge_slices.clear();
+            }
+
+            if let Some(block) = self.spilled_runs[run_index].restore_next()? {
+                self.push_cursor(run_index, block)?;
+            }
+        }
+
+        if !merge_slices.is_empty() {
+            parts.push(DataBlock::take_by_slices_limit_from_blocks(
+                &self.merging_blocks,
+                &merge_slices,
+                None,
+            ));
+        }
+
+        match parts.len() {
+            0 => Ok(None),
+            1 => Ok(parts.pop()),
+            _ => Ok(Some(DataBlock::concat(&parts)?)),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<R, Converter> Processor for TransformSortSpill<R, Converter>
+where
+    R: Rows + Send + 'static,
+    Converter: RowConverter<R> + Send + 'static,
+{
+    fn name(&self) -> String {
+        String::from("SortSpillTransform")
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            self.input.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            self.input.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(block) = self.output_blocks.pop_front() {
+            self.output.push_data(Ok(block));
+            return Ok(Event::NeedConsume);
+        }
+
+        match self.state {
+            SortSpillState::Consume => {
+                if self.input.has_data() {
+                    let block = self.input.pull_data().unwrap()?;
+                    self.buffered_bytes += block.memory_size();
+                    self.buffered_blocks.push(block);
+
+                    if self.buffered_bytes >= self.spilling_bytes_threshold {
+                        self.state = SortSpillState::Spill;
+                        return Ok(Event::Sync);
+                    }
+                }
+
+                if self.input.is_finished() {
+                    self.state = SortSpillState::Finalize;
+                    return Ok(Event::Sync);
+                }
+
+                self.input.set_need_data();
+                Ok(Event::NeedData)
+            }
+            SortSpillState::Finalize | SortSpillState::Merge => Ok(Event::Sync),
+            SortSpillState::Spill => Err(ErrorCode::Internal("It's a bug.")),
+            SortSpillState::Finished => {
+                self.output.finish();
+                Ok(Event::Finished)
+            }
+        }
+    }
+
+    fn interrupt(&self) {
+        self.aborting.store(true, Ordering::Release);
+        self.compactor.interrupt();
+    }
+
+    fn process(&mut self) -> Result<()> {
+        if self.aborting.load(Ordering::Relaxed) {
+            return Err(ErrorCode::AbortedQuery(
+                "Aborted query, because the server is shutting down or the query was killed.",
+            ));
+        }
+
+        match self.state {
+            SortSpillState::Spill => {
+                self.spill_buffered_blocks()?;
+                self.state = SortSpillState::Consume;
+            }
+            SortSpillState::Finalize => {
+                if self.spilled_runs.is_empty() {
+                    // All the data fits in memory, sort it like `SortMergeCompactor`.
+                    let blocks = std::mem::take(&mut self.buffered_blocks);
+                    self.output_blocks
+                        .extend(self.compactor.compact_final(&blocks)?);
+                    self.state = SortSpillState::Finished;
+                } else {
+                    self.spill_buffered_blocks()?;
+                    self.start_merge()?;
+                    self.state = SortSpillState::Merge;
+                }
+            }
+            SortSpillState::Merge => match self.merge_next_block()? {
+                Some(block) => self.output_blocks.push_back(block),
+                None => {
+                    self.spilled_runs.clear();
+                    self.merging_blocks.clear();
+                    self.state = SortSpillState::Finished;
+                }
+            },
+            _ => return Err(ErrorCode::Internal("State invalid. it's a bug.")),
+        }
+
+        Ok(())
+    }
+}
+
+type SimpleDateSortSpill = TransformSortSpill<SimpleRows<DateType>, SimpleRowConverter<DateType>>;
+type SimpleTimestampSortSpill =
+    TransformSortSpill<SimpleRows<TimestampType>, SimpleRowConverter<TimestampType>>;
+type SimpleStringSortSpill =
+    TransformSortSpill<SimpleRows<StringType>, SimpleRowConverter<StringType>>;
+type CommonSortSpill = TransformSortSpill<ArrowRows, ArrowRowConverter>;
+
+/// Create the external sort transform, which is used instead of `try_create_transform_sort_merge`
+/// when the sort has no limit and `spilling_bytes_threshold` is set.
+pub fn try_create_transform_sort_spill(
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+    output_schema: DataSchemaRef,
+    block_size: usize,
+    sort_columns_descriptions: Vec<SortColumnDescription>,
+    spilling_bytes_threshold: usize,
+    spill_dir: PathBuf,
+) -> Result<Box<dyn Processor>> {
+    if sort_columns_descriptions.len() == 1 {
+        let sort_type = output_schema
+            .field(sort_columns_descriptions[0].offset)
+            .data_type();
+        match sort_type {
+            DataType::Number(num_ty) => with_number_mapped_type!(|NUM_TYPE| match num_ty {
+                NumberDataType::NUM_TYPE => TransformSortSpill::<
+                    SimpleRows<NumberType<NUM_TYPE>>,
+                    SimpleRowConverter<NumberType<NUM_TYPE>>,
+                >::try_create(
+                    input,
+                    output,
+                    output_schema,
+                    block_size,
+                    sort_columns_descriptions,
+                    spilling_bytes_threshold,
+                    spill_dir,
+                ),
+            }),
+            DataType::Date => SimpleDateSortSpill::try_create(
+                input,
+                output,
+                output_schema,
+                block_size,
+                sort_columns_descriptions,
+                spilling_bytes_threshold,
+                spill_dir,
+            ),
+            DataType::Timestamp => SimpleTimestampSortSpill::try_create(
+                input,
+                output,
+                output_schema,
+                block_size,
+                sort_columns_descriptions,
+                spilling_bytes_threshold,
+                spill_dir,
+            ),
+            DataType::String => SimpleStringSortSpill::try_create(
+                input,
+                output,
+                output_schema,
+                block_size,
+                sort_columns_descriptions,
+                spilling_bytes_threshold,
+                spill_dir,
+            ),
+            _ => CommonSortSpill::try_create(
+                input,
+                output,
+                output_schema,
+                block_size,
+                sort_columns_descriptions,
+                spilling_bytes_threshold,
+                spill_dir,
+            ),
+        }
+    } else {
+        CommonSortSpill::try_create(
+            input,
+            output,
+            output_schema,
+            block_size,
+            sort_columns_descriptions,
+            spilling_bytes_threshold,
+            spill_dir,
+        )
+    }
+}
@@ -26,12 +26,15 @@ pub use rpc::ExchangeSorting;
 pub use rpc::ExecutePartialQueryPacket;
 pub use rpc::FlightAction;
 pub use rpc::FlightClient;
+pub use rpc::FlightScatter;
 pub use rpc::FragmentData;
 pub use rpc::FragmentPlanPacket;
+pub use rpc::HashFlightScatter;
 pub use rpc::InitNodesChannelPacket;
 pub use rpc::MergeExchange;
 pub use rpc::PrecommitBlock;
 pub use rpc::QueryFragmentsPlanPacket;
+pub use rpc::RuntimeFilterPacket;
 pub use rpc::ServerFlightExchange;
 pub use rpc::ShuffleDataExchange;
 pub use rpc_service::RpcService;
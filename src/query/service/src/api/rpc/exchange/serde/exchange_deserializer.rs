@@ -114,6 +114,7 @@ impl Transform for TransformExchangeDeserializer {
                     DataPacket::ClosingOutput => unreachable!(),
                     DataPacket::FetchProgressAndPrecommit => unreachable!(),
                     DataPacket::ProgressAndPrecommit { .. } => unreachable!(),
+                    DataPacket::RuntimeFilters(_) => unreachable!(),
                     DataPacket::FragmentData(v) => self.recv_data(v),
                 };
             }
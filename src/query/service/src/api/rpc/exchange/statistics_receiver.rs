@@ -66,6 +66,7 @@ impl StatisticsReceiver {
             let shutdown_notify = self.shutdown_notify.clone();
 
             self.exchange_handler.push(self.runtime.spawn(async move {
+                let mut sent_runtime_filters = 0;
                 let mut recv = Box::pin(flight_exchange.recv());
                 let mut notified = Box::pin(shutdown_notify.notified());
 
@@ -78,7 +79,14 @@ impl StatisticsReceiver {
                             notified = middle;
 
                             if !shutdown_flag.load(Ordering::Relaxed) {
-                                match Self::fetch(&ctx, &flight_exchange, recv).await {
+                                match Self::fetch(
+                                    &ctx,
+                                    &flight_exchange,
+                                    recv,
+                                    &mut sent_runtime_filters,
+                                )
+                                .await
+                                {
                                     Ok(true) => {
                                         flight_exchange.close_input().await;
                                         flight_exchange.close_output().await;
@@ -122,7 +130,9 @@ impl StatisticsReceiver {
                     }
                 }
 
-                if let Err(cause) = Self::fetch(&ctx, &flight_exchange, recv).await {
+                if let Err(cause) =
+                    Self::fetch(&ctx, &flight_exchange, recv, &mut sent_runtime_filters).await
+                {
                     ctx.get_current_session().force_kill_query(cause.clone());
                     flight_exchange.close_input().await;
                     flight_exchange.close_output().await;
@@ -140,7 +150,26 @@ impl StatisticsReceiver {
         ctx: &Arc<QueryContext>,
         flight_exchange: &FlightExchange,
         recv: impl Future<Output = Result<Option<DataPacket>>>,
+        sent_runtime_filters: &mut usize,
     ) -> Result<bool> {
+        // Send the runtime filters merged from all the nodes to the executor.
+        let runtime_filters = ctx.get_merged_runtime_filters(*sent_runtime_filters);
+        if !runtime_filters.is_empty() {
+            let num_runtime_filters = runtime_filters.len();
+            if let Err(error) = flight_exchange
+                .send(DataPacket::RuntimeFilters(runtime_filters))
+                .await
+            {
+                // The query is done(in remote).
+                return match error.code() == ErrorCode::ABORTED_QUERY {
+                    true => Ok(true),
+                    false => Err(error),
+                };
+            }
+
+            *sent_runtime_filters += num_runtime_filters;
+        }
+
         if let Err(error) = flight_exchange
             .send(DataPacket::FetchProgressAndPrecommit)
             .await
@@ -152,7 +181,14 @@ impl StatisticsReceiver {
             };
         }
 
-        Self::recv_data(ctx, recv.await)
+        // The runtime filters of the executor may arrive before the progress and precommit.
+        let mut recv_data = recv.await;
+        while let Ok(Some(DataPacket::RuntimeFilters(_))) = &recv_data {
+            Self::recv_data(ctx, recv_data)?;
+            recv_data = flight_exchange.recv().await;
+        }
+
+        Self::recv_data(ctx, recv_data)
     }
 
     fn recv_data(ctx: &Arc<QueryContext>, recv_data: Result<Option<DataPacket>>) -> Result<bool> {
@@ -164,6 +200,13 @@ impl StatisticsReceiver {
             Ok(Some(DataPacket::ClosingOutput)) => unreachable!(),
             Ok(Some(DataPacket::FragmentData(_))) => unreachable!(),
             Ok(Some(DataPacket::FetchProgressAndPrecommit)) => unreachable!(),
+            Ok(Some(DataPacket::RuntimeFilters(runtime_filters))) => {
+                for packet in runtime_filters {
+                    ctx.add_partial_runtime_filter(packet);
+                }
+
+                Ok(false)
+            }
             Ok(Some(DataPacket::ProgressAndPrecommit {
                 progress,
                 precommit,
@@ -19,6 +19,7 @@ use std::sync::Arc;
 use async_channel::Receiver;
 use async_channel::Sender;
 use common_base::base::tokio;
+use common_catalog::runtime_filter_info::RuntimeFilterInfo;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -147,8 +148,28 @@ impl StatisticsSender {
                         progress: Self::fetch_progress(ctx).await?,
                         precommit: Self::fetch_precommit(ctx).await?,
                     })
+                    .await?;
+
+                // Send the runtime filters derived on this node to the coordinator to be merged.
+                let runtime_filters = ctx.take_pending_runtime_filters();
+                if runtime_filters.is_empty() {
+                    return Ok(());
+                }
+
+                exchange_flight
+                    .send(DataPacket::RuntimeFilters(runtime_filters))
                     .await
             }
+            // The runtime filters merged by the coordinator.
+            DataPacket::RuntimeFilters(runtime_filters) => {
+                for packet in runtime_filters {
+                    let mut info = RuntimeFilterInfo::default();
+                    info.add(packet.filter);
+                    ctx.set_runtime_filter((packet.scan_id, info));
+                }
+
+                Ok(())
+            }
         }
     }
 
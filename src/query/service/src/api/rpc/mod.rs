@@ -34,6 +34,8 @@ pub use exchange::MergeExchange;
 pub use exchange::ShuffleDataExchange;
 pub use flight_client::ClientFlightExchange;
 pub use flight_client::ServerFlightExchange;
+pub use flight_scatter::FlightScatter;
+pub use flight_scatter_hash::HashFlightScatter;
 pub use packets::ConnectionInfo;
 pub use packets::DataPacket;
 pub use packets::ExecutePartialQueryPacket;
@@ -43,3 +45,4 @@ pub use packets::InitNodesChannelPacket;
 pub use packets::Packet;
 pub use packets::PrecommitBlock;
 pub use packets::QueryFragmentsPlanPacket;
+pub use packets::RuntimeFilterPacket;
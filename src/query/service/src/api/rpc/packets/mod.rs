@@ -16,6 +16,7 @@ mod packet;
 mod packet_data;
 mod packet_data_precommit;
 mod packet_data_progressinfo;
+mod packet_data_runtime_filter;
 mod packet_execute;
 mod packet_executor;
 mod packet_fragment;
@@ -26,6 +27,7 @@ pub use packet_data::DataPacket;
 pub use packet_data::FragmentData;
 pub use packet_data_precommit::PrecommitBlock;
 pub use packet_data_progressinfo::ProgressInfo;
+pub use packet_data_runtime_filter::RuntimeFilterPacket;
 pub use packet_execute::ExecutePartialQueryPacket;
 pub use packet_executor::QueryFragmentsPlanPacket;
 pub use packet_fragment::FragmentPlanPacket;
@@ -24,6 +24,7 @@ use common_exception::Result;
 use tracing::error;
 
 use crate::api::rpc::packets::ProgressInfo;
+use crate::api::rpc::packets::RuntimeFilterPacket;
 use crate::api::PrecommitBlock;
 
 pub struct FragmentData {
@@ -62,6 +63,9 @@ pub enum DataPacket {
     // we use the ClosingClient to notify the server side to close the connection for avoid errors.
     ClosingOutput,
     ClosingInput,
+    // Runtime filters of shuffled hash joins, which are sent from executors to the
+    // coordinator to be merged, and sent back to executors once they are complete.
+    RuntimeFilters(Vec<RuntimeFilterPacket>),
 }
 
 impl DataPacket {
@@ -131,10 +135,29 @@ impl From<DataPacket> for FlightData {
                 flight_descriptor: None,
                 app_metadata: vec![0x06],
             },
+            // The serialization error is sent to the peer instead of the runtime filters.
+            DataPacket::RuntimeFilters(filters) => match write_runtime_filters(filters) {
+                Ok(data_body) => FlightData {
+                    data_body,
+                    data_header: vec![],
+                    flight_descriptor: None,
+                    app_metadata: vec![0x07],
+                },
+                Err(cause) => FlightData::from(cause),
+            },
         }
     }
 }
 
+fn write_runtime_filters(filters: Vec<RuntimeFilterPacket>) -> Result<Vec<u8>> {
+    let mut data_body = vec![];
+    data_body.write_u64::<BigEndian>(filters.len() as u64)?;
+    for filter in filters {
+        filter.write(&mut data_body)?;
+    }
+    Ok(data_body)
+}
+
 impl From<FragmentData> for FlightData {
     fn from(mut data: FragmentData) -> Self {
         data.meta.push(0x01);
@@ -185,6 +208,17 @@ impl TryFrom<FlightData> for DataPacket {
             }
             0x05 => Ok(DataPacket::ClosingOutput),
             0x06 => Ok(DataPacket::ClosingInput),
+            0x07 => {
+                let mut bytes = flight_data.data_body.as_slice();
+                let filters_size = bytes.read_u64::<BigEndian>()?;
+
+                let mut filters = Vec::with_capacity(filters_size as usize);
+                for _index in 0..filters_size {
+                    filters.push(RuntimeFilterPacket::read(&mut bytes)?);
+                }
+
+                Ok(DataPacket::RuntimeFilters(filters))
+            }
             _ => Err(ErrorCode::BadBytes("Unknown flight data packet type.")),
         }
     }
@@ -0,0 +1,107 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Read;
+use std::io::Write;
+use std::sync::Arc;
+
+use byteorder::BigEndian;
+use byteorder::ReadBytesExt;
+use byteorder::WriteBytesExt;
+use common_catalog::runtime_filter_info::RuntimeFilter;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::ToErrorCode;
+use common_expression::types::DataType;
+use common_expression::Scalar;
+use storages_common_index::filters::Filter;
+use storages_common_index::filters::Xor8Filter;
+
+/// A runtime filter derived from a part of the build side of a shuffled hash join,
+/// which is exchanged between the nodes of the cluster to be merged into a complete one.
+#[derive(Clone)]
+pub struct RuntimeFilterPacket {
+    /// The `plan_id` of the hash join which derives the runtime filter.
+    pub join_id: u32,
+    /// The `scan_id` of the table scan which applies the runtime filter.
+    pub scan_id: usize,
+    pub filter: RuntimeFilter,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SerializedRuntimeFilter {
+    join_id: u32,
+    scan_id: usize,
+    column_name: String,
+    data_type: DataType,
+    min_max: Option<(Scalar, Scalar)>,
+    inlist: Option<Vec<Scalar>>,
+    blooms: Vec<Vec<u8>>,
+}
+
+impl RuntimeFilterPacket {
+    pub fn write<T: Write>(self, bytes: &mut T) -> Result<()> {
+        let blooms = self
+            .filter
+            .blooms
+            .iter()
+            .map(|bloom| bloom.to_bytes())
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        let serialized = bincode::serialize(&SerializedRuntimeFilter {
+            join_id: self.join_id,
+            scan_id: self.scan_id,
+            column_name: self.filter.column_name,
+            data_type: self.filter.data_type,
+            min_max: self.filter.min_max,
+            inlist: self.filter.inlist,
+            blooms,
+        })
+        .map_err_to_code(
+            ErrorCode::BadBytes,
+            || "runtime filter serialize error when exchange",
+        )?;
+
+        bytes.write_u64::<BigEndian>(serialized.len() as u64)?;
+        bytes.write_all(&serialized)?;
+        Ok(())
+    }
+
+    pub fn read<T: Read>(bytes: &mut T) -> Result<RuntimeFilterPacket> {
+        let len = bytes.read_u64::<BigEndian>()? as usize;
+        let mut serialized = vec![0; len];
+
+        bytes.read_exact(&mut serialized)?;
+        let filter: SerializedRuntimeFilter = bincode::deserialize(&serialized).map_err_to_code(
+            ErrorCode::BadBytes,
+            || "runtime filter deserialize error when exchange",
+        )?;
+
+        let blooms = filter
+            .blooms
+            .iter()
+            .map(|bytes| Ok(Arc::new(Xor8Filter::from_bytes(bytes)?.0)))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(RuntimeFilterPacket {
+            join_id: filter.join_id,
+            scan_id: filter.scan_id,
+            filter: RuntimeFilter {
+                column_name: filter.column_name,
+                data_type: filter.data_type,
+                min_max: filter.min_max,
+                inlist: filter.inlist,
+                blooms,
+            },
+        })
+    }
+}
@@ -65,6 +65,7 @@ impl AccessChecker for ManagementModeAccess {
                 // Table.
                 | Plan::DescribeTable(_)
                 | Plan::CreateTable(_)
+                | Plan::CloneTable(_)
                 | Plan::DropTable(_)
 
                 // User.
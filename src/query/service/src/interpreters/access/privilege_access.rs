@@ -100,6 +100,24 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::CloneTable(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        UserPrivilegeType::Create,
+                    )
+                    .await?;
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.source_database.clone(),
+                            plan.source_table.clone(),
+                        ),
+                        UserPrivilegeType::Select,
+                    )
+                    .await?;
+            }
             Plan::DropTable(plan) => {
                 session
                     .validate_privilege(
@@ -141,6 +159,30 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::RenameTableColumn(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        UserPrivilegeType::Alter,
+                    )
+                    .await?;
+            }
+            Plan::ModifyTableColumnType(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        UserPrivilegeType::Alter,
+                    )
+                    .await?;
+            }
             Plan::AlterTableClusterKey(plan) => {
                 session
                     .validate_privilege(
@@ -217,6 +259,20 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::Replace(plan) => {
+                for privilege in [UserPrivilegeType::Insert, UserPrivilegeType::Delete] {
+                    session
+                        .validate_privilege(
+                            &GrantObject::Table(
+                                plan.catalog.clone(),
+                                plan.database.clone(),
+                                plan.table.clone(),
+                            ),
+                            privilege,
+                        )
+                        .await?;
+                }
+            }
             Plan::Delete(plan) => {
                 session
                     .validate_privilege(
@@ -241,6 +297,38 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::MergeInto(plan) => {
+                let mut privileges = vec![];
+                if plan
+                    .matched_clauses
+                    .iter()
+                    .any(|clause| clause.update_list.is_some())
+                {
+                    privileges.push(UserPrivilegeType::Update);
+                }
+                if plan
+                    .matched_clauses
+                    .iter()
+                    .any(|clause| clause.update_list.is_none())
+                {
+                    privileges.push(UserPrivilegeType::Delete);
+                }
+                if !plan.unmatched_clauses.is_empty() {
+                    privileges.push(UserPrivilegeType::Insert);
+                }
+                for privilege in privileges {
+                    session
+                        .validate_privilege(
+                            &GrantObject::Table(
+                                plan.catalog.clone(),
+                                plan.database.clone(),
+                                plan.table.clone(),
+                            ),
+                            privilege,
+                        )
+                        .await?;
+                }
+            }
             Plan::CreateView(plan) => {
                 session
                     .validate_privilege(
@@ -265,6 +353,56 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::CreateStream(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        UserPrivilegeType::Create,
+                    )
+                    .await?;
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.table_database.clone(),
+                            plan.table_name.clone(),
+                        ),
+                        UserPrivilegeType::Select,
+                    )
+                    .await?;
+            }
+            Plan::DropStream(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        UserPrivilegeType::Drop,
+                    )
+                    .await?;
+            }
+            Plan::CreateAggregatingIndex(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        UserPrivilegeType::Alter,
+                    )
+                    .await?;
+            }
+            Plan::DropAggregatingIndex(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        UserPrivilegeType::Alter,
+                    )
+                    .await?;
+            }
             Plan::AlterUser(_) => {}
             Plan::CreateUser(_) => {}
             Plan::DropUser(_) => {}
@@ -290,6 +428,9 @@ impl AccessChecker for PrivilegeAccess {
             Plan::SetVariable(_) => {}
             Plan::UnSetVariable(_) => {}
             Plan::SetRole(_) => {}
+            Plan::Begin => {}
+            Plan::Commit => {}
+            Plan::Abort => {}
             Plan::Kill(_) => {
                 session
                     .validate_privilege(&GrantObject::Global, UserPrivilegeType::Super)
@@ -0,0 +1,100 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::CreateAggregatingIndexPlan;
+use storages_common_table_meta::table::AggregatingIndex;
+use storages_common_table_meta::table::OPT_KEY_AGGREGATING_INDEXES;
+use uuid::Uuid;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::storages::fuse::FuseTable;
+
+pub struct CreateAggregatingIndexInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateAggregatingIndexPlan,
+}
+
+impl CreateAggregatingIndexInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateAggregatingIndexPlan) -> Result<Self> {
+        Ok(CreateAggregatingIndexInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateAggregatingIndexInterpreter {
+    fn name(&self) -> &str {
+        "CreateAggregatingIndexInterpreter"
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog = self.ctx.get_catalog(&self.plan.catalog)?;
+        let table = catalog
+            .get_table(&self.plan.tenant, &self.plan.database, &self.plan.table)
+            .await?;
+        FuseTable::try_from_table(table.as_ref()).map_err(|_| {
+            ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} engine is {} that doesn't support aggregating index",
+                &self.plan.database,
+                &self.plan.table,
+                table.engine()
+            ))
+        })?;
+
+        let table_info = table.get_table_info();
+        let mut indexes = AggregatingIndex::from_table_options(table_info.options())?;
+        if indexes
+            .iter()
+            .any(|index| index.name == self.plan.index.name)
+        {
+            return if self.plan.if_not_exists {
+                Ok(PipelineBuildResult::create())
+            } else {
+                Err(ErrorCode::AggregatingIndexAlreadyExists(format!(
+                    "Aggregating index {} of table {}.{} already exists",
+                    self.plan.index.name, &self.plan.database, &self.plan.table
+                )))
+            };
+        }
+
+        // the blocks written before the index is created are aggregated while reading the
+        // index, until they are compacted.
+        let mut index = self.plan.index.clone();
+        index.id = Uuid::new_v4().simple().to_string();
+        indexes.push(index);
+
+        let mut new_table_meta = table_info.meta.clone();
+        new_table_meta.options.insert(
+            OPT_KEY_AGGREGATING_INDEXES.to_string(),
+            AggregatingIndex::to_table_option(&indexes)?,
+        );
+
+        let req = UpdateTableMetaReq {
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            new_table_meta,
+        };
+        catalog.update_table_meta(table_info, req).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,90 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::DropAggregatingIndexPlan;
+use storages_common_table_meta::table::AggregatingIndex;
+use storages_common_table_meta::table::OPT_KEY_AGGREGATING_INDEXES;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct DropAggregatingIndexInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropAggregatingIndexPlan,
+}
+
+impl DropAggregatingIndexInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropAggregatingIndexPlan) -> Result<Self> {
+        Ok(DropAggregatingIndexInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropAggregatingIndexInterpreter {
+    fn name(&self) -> &str {
+        "DropAggregatingIndexInterpreter"
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog = self.ctx.get_catalog(&self.plan.catalog)?;
+        let table = catalog
+            .get_table(&self.plan.tenant, &self.plan.database, &self.plan.table)
+            .await?;
+
+        let table_info = table.get_table_info();
+        let mut indexes = AggregatingIndex::from_table_options(table_info.options())?;
+        let position = match indexes
+            .iter()
+            .position(|index| index.name == self.plan.index_name)
+        {
+            Some(position) => position,
+            None if self.plan.if_exists => return Ok(PipelineBuildResult::create()),
+            None => {
+                return Err(ErrorCode::UnknownAggregatingIndex(format!(
+                    "Unknown aggregating index {} of table {}.{}",
+                    self.plan.index_name, &self.plan.database, &self.plan.table
+                )));
+            }
+        };
+        indexes.remove(position);
+
+        // the index files are left to the gc, with the blocks they belong to.
+        let mut new_table_meta = table_info.meta.clone();
+        if indexes.is_empty() {
+            new_table_meta.options.remove(OPT_KEY_AGGREGATING_INDEXES);
+        } else {
+            new_table_meta.options.insert(
+                OPT_KEY_AGGREGATING_INDEXES.to_string(),
+                AggregatingIndex::to_table_option(&indexes)?,
+            );
+        }
+
+        let req = UpdateTableMetaReq {
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            new_table_meta,
+        };
+        catalog.update_table_meta(table_info, req).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
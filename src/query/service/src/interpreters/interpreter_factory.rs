@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use common_ast::ast::ExplainKind;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataSchemaRef;
 use tracing::error;
@@ -40,6 +41,7 @@ use crate::interpreters::DropUserInterpreter;
 use crate::interpreters::SetRoleInterpreter;
 use crate::interpreters::UpdateInterpreter;
 use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
 use crate::sql::plans::Plan;
 
 /// InterpreterFactory is the entry of Interpreter.
@@ -55,9 +57,41 @@ impl InterpreterFactory {
             error!("Access.denied(v2): {:?}", e);
             e
         })?;
+        Self::check_txn(&ctx, plan)?;
         Self::get_inner(ctx, plan)
     }
 
+    /// Only queries and DML can be run in an explicit transaction, since the other
+    /// statements take effect immediately and can not be rolled back.
+    fn check_txn(ctx: &Arc<QueryContext>, plan: &Plan) -> Result<()> {
+        if !ctx.txn_mgr().lock().is_active() {
+            return Ok(());
+        }
+        match plan {
+            Plan::Query { .. }
+            | Plan::Explain { .. }
+            | Plan::ExplainAst { .. }
+            | Plan::ExplainSyntax { .. }
+            | Plan::ExplainAnalyze { .. }
+            | Plan::Insert(_)
+            | Plan::Replace(_)
+            | Plan::Delete(_)
+            | Plan::Update(_)
+            | Plan::MergeInto(_)
+            | Plan::ShowCreateTable(_)
+            | Plan::DescribeTable(_)
+            | Plan::ExistsTable(_)
+            | Plan::SetVariable(_)
+            | Plan::UnSetVariable(_)
+            | Plan::Begin
+            | Plan::Commit
+            | Plan::Abort => Ok(()),
+            _ => Err(ErrorCode::InvalidTransaction(format!(
+                "{plan} is not supported in a transaction"
+            ))),
+        }
+    }
+
     /// This is used for handlers to get the schema of the plan.
     /// Some plan may miss the schema and return empty plan such as `CallPlan`
     /// So we need to map the plan into to `Interpreter` and get the right schema.
@@ -156,6 +190,10 @@ impl InterpreterFactory {
                 ctx,
                 *create_table.clone(),
             )?)),
+            Plan::CloneTable(clone_table) => Ok(Arc::new(CloneTableInterpreter::try_create(
+                ctx,
+                *clone_table.clone(),
+            )?)),
             Plan::DropTable(drop_table) => Ok(Arc::new(DropTableInterpreter::try_create(
                 ctx,
                 *drop_table.clone(),
@@ -174,6 +212,15 @@ impl InterpreterFactory {
             Plan::DropTableColumn(drop_table_column) => Ok(Arc::new(
                 DropTableColumnInterpreter::try_create(ctx, *drop_table_column.clone())?,
             )),
+            Plan::RenameTableColumn(rename_table_column) => Ok(Arc::new(
+                RenameTableColumnInterpreter::try_create(ctx, *rename_table_column.clone())?,
+            )),
+            Plan::ModifyTableColumnType(modify_table_column_type) => {
+                Ok(Arc::new(ModifyTableColumnTypeInterpreter::try_create(
+                    ctx,
+                    *modify_table_column_type.clone(),
+                )?))
+            }
             Plan::AlterTableClusterKey(alter_table_cluster_key) => Ok(Arc::new(
                 AlterTableClusterKeyInterpreter::try_create(ctx, *alter_table_cluster_key.clone())?,
             )),
@@ -212,6 +259,24 @@ impl InterpreterFactory {
                 *drop_view.clone(),
             )?)),
 
+            // Streams
+            Plan::CreateStream(create_stream) => Ok(Arc::new(CreateStreamInterpreter::try_create(
+                ctx,
+                *create_stream.clone(),
+            )?)),
+            Plan::DropStream(drop_stream) => Ok(Arc::new(DropStreamInterpreter::try_create(
+                ctx,
+                *drop_stream.clone(),
+            )?)),
+
+            // Aggregating indexes
+            Plan::CreateAggregatingIndex(create_index) => Ok(Arc::new(
+                CreateAggregatingIndexInterpreter::try_create(ctx, *create_index.clone())?,
+            )),
+            Plan::DropAggregatingIndex(drop_index) => Ok(Arc::new(
+                DropAggregatingIndexInterpreter::try_create(ctx, *drop_index.clone())?,
+            )),
+
             // Users
             Plan::CreateUser(create_user) => Ok(Arc::new(CreateUserInterpreter::try_create(
                 ctx,
@@ -228,6 +293,16 @@ impl InterpreterFactory {
 
             Plan::Insert(insert) => InsertInterpreterV2::try_create(ctx, *insert.clone(), false),
 
+            Plan::Replace(replace) => Ok(Arc::new(ReplaceInterpreter::try_create(
+                ctx,
+                *replace.clone(),
+            )?)),
+
+            // Transactions
+            Plan::Begin => Ok(Arc::new(BeginInterpreter::try_create(ctx)?)),
+            Plan::Commit => Ok(Arc::new(CommitInterpreter::try_create(ctx)?)),
+            Plan::Abort => Ok(Arc::new(AbortInterpreter::try_create(ctx)?)),
+
             Plan::Delete(delete) => Ok(Arc::new(DeleteInterpreter::try_create(
                 ctx,
                 *delete.clone(),
@@ -238,6 +313,11 @@ impl InterpreterFactory {
                 *update.clone(),
             )?)),
 
+            Plan::MergeInto(merge_into) => Ok(Arc::new(MergeIntoInterpreter::try_create(
+                ctx,
+                *merge_into.clone(),
+            )?)),
+
             // Roles
             Plan::CreateRole(create_role) => Ok(Arc::new(CreateRoleInterpreter::try_create(
                 ctx,
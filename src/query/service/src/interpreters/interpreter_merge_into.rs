@@ -0,0 +1,257 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_catalog::plan::MergeIntoInfo;
+use common_catalog::plan::MergeMatchedOperation;
+use common_catalog::plan::MergeUnmatchedOperation;
+use common_exception::Result;
+use common_expression::DataSchema;
+use common_expression::DataSchemaRef;
+use common_expression::Expr;
+use common_expression::RemoteExpr;
+use common_pipeline_core::Pipeline;
+use common_sql::executor::cast_expr_to_non_null_boolean;
+use common_sql::field_default_value;
+use common_sql::plans::MergeIntoPlan;
+use common_sql::IndexType;
+use common_sql::ScalarExpr;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelineCompleteExecutor;
+use crate::pipelines::processors::MergeIntoHashTable;
+use crate::pipelines::PipelineBuildResult;
+use crate::schedulers::build_query_pipeline;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::executor::PhysicalPlanBuilder;
+
+/// interprets MergeIntoPlan
+pub struct MergeIntoInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: MergeIntoPlan,
+}
+
+impl MergeIntoInterpreter {
+    /// Create the MergeIntoInterpreter from MergeIntoPlan
+    pub fn try_create(ctx: Arc<QueryContext>, plan: MergeIntoPlan) -> Result<Self> {
+        Ok(MergeIntoInterpreter { ctx, plan })
+    }
+
+    /// Execute the source of MERGE INTO, and build its rows into the hash table which the
+    /// blocks of the table are joined with.
+    async fn build_source(
+        &self,
+        table_schema: DataSchemaRef,
+        source_join_keys: &[RemoteExpr],
+        target_join_keys: &[RemoteExpr],
+        join_filters: &[RemoteExpr],
+    ) -> Result<Arc<MergeIntoHashTable>> {
+        let mut builder = PhysicalPlanBuilder::new(self.plan.metadata.clone(), self.ctx.clone());
+        let physical_plan = builder.build(&self.plan.input).await?;
+        let mut build_res = build_query_pipeline(
+            &self.ctx,
+            &self.plan.bind_context.columns,
+            &physical_plan,
+            false,
+            false,
+        )
+        .await?;
+
+        let source = MergeIntoHashTable::try_create(
+            self.ctx.clone(),
+            self.plan.bind_context.output_schema(),
+            table_schema,
+            source_join_keys,
+            target_join_keys,
+            join_filters,
+        )?;
+        source.add_build_sink(&mut build_res.main_pipeline)?;
+
+        let settings = self.ctx.get_settings();
+        build_res.set_max_threads(settings.get_max_threads()? as usize);
+        let settings = ExecutorSettings::try_create(&settings, self.ctx.get_id())?;
+        let mut pipelines = build_res.sources_pipelines;
+        pipelines.push(build_res.main_pipeline);
+        let executor = PipelineCompleteExecutor::from_pipelines(pipelines, settings)?;
+
+        self.ctx.set_executor(Arc::downgrade(&executor.get_inner()));
+        executor.execute()?;
+        drop(executor);
+        Ok(source)
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for MergeIntoInterpreter {
+    /// Get the name of current interpreter
+    fn name(&self) -> &str {
+        "MergeIntoInterpreter"
+    }
+
+    /// Get the schema of MergeIntoPlan
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.schema()
+    }
+
+    #[tracing::instrument(level = "debug", name = "merge_into_interpreter_execute", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog_name = self.plan.catalog.as_str();
+        let db_name = self.plan.database.as_str();
+        let tbl_name = self.plan.table.as_str();
+        let tbl = self.ctx.get_table(catalog_name, db_name, tbl_name).await?;
+        let schema = tbl.schema();
+        let num_fields = schema.num_fields();
+
+        // The source columns follow the table columns in the matched pairs.
+        let target_columns = &self.plan.target_columns;
+        let source_columns = self
+            .plan
+            .bind_context
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(pos, column)| (column.index, pos))
+            .collect::<HashMap<IndexType, usize>>();
+        let source_columns = &source_columns;
+        let to_joined_expr = |scalar: &ScalarExpr| -> Result<Expr> {
+            Ok(scalar
+                .as_expr_with_col_index()?
+                .project_column_ref(|index| match target_columns.get(index) {
+                    Some(field_index) => *field_index,
+                    None => num_fields + source_columns[index],
+                }))
+        };
+        // The joined rows of the hash join have the row index in the block between the table
+        // columns and the source columns.
+        let to_join_filter = |scalar: &ScalarExpr| -> Result<Expr> {
+            Ok(scalar
+                .as_expr_with_col_index()?
+                .project_column_ref(|index| match target_columns.get(index) {
+                    Some(field_index) => *field_index,
+                    None => num_fields + 1 + source_columns[index],
+                }))
+        };
+        let to_source_expr = |scalar: &ScalarExpr| -> Result<Expr> {
+            Ok(scalar
+                .as_expr_with_col_index()?
+                .project_column_ref(|index| source_columns[index]))
+        };
+        let to_condition = |expr: Result<Expr>| -> Result<RemoteExpr> {
+            Ok(cast_expr_to_non_null_boolean(expr?)?.as_remote_expr())
+        };
+
+        let target_join_keys = self
+            .plan
+            .target_join_keys
+            .iter()
+            .map(|scalar| {
+                Ok(scalar
+                    .as_expr_with_col_index()?
+                    .project_column_ref(|index| target_columns[index])
+                    .as_remote_expr())
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let source_join_keys = self
+            .plan
+            .source_join_keys
+            .iter()
+            .map(|scalar| Ok(to_source_expr(scalar)?.as_remote_expr()))
+            .collect::<Result<Vec<_>>>()?;
+        let join_filters = self
+            .plan
+            .join_filters
+            .iter()
+            .map(|scalar| to_condition(to_join_filter(scalar)))
+            .collect::<Result<Vec<_>>>()?;
+        let source = self
+            .build_source(
+                Arc::new(DataSchema::from(schema.clone())),
+                &source_join_keys,
+                &target_join_keys,
+                &join_filters,
+            )
+            .await?;
+
+        let mut matched = Vec::with_capacity(self.plan.matched_clauses.len());
+        for clause in &self.plan.matched_clauses {
+            let condition = match &clause.condition {
+                Some(scalar) => Some(to_condition(to_joined_expr(scalar))?),
+                None => None,
+            };
+            let update_list = match &clause.update_list {
+                Some(update_list) => Some(
+                    update_list
+                        .iter()
+                        .map(|(index, scalar)| {
+                            Ok((*index, to_joined_expr(scalar)?.as_remote_expr()))
+                        })
+                        .collect::<Result<Vec<_>>>()?,
+                ),
+                None => None,
+            };
+            matched.push(MergeMatchedOperation {
+                condition,
+                update_list,
+            });
+        }
+
+        let mut unmatched = Vec::with_capacity(self.plan.unmatched_clauses.len());
+        for clause in &self.plan.unmatched_clauses {
+            let condition = match &clause.condition {
+                Some(scalar) => Some(to_condition(to_source_expr(scalar))?),
+                None => None,
+            };
+            let mut values = Vec::with_capacity(num_fields);
+            for (field, value) in schema.fields().iter().zip(clause.values.iter()) {
+                let expr = match value {
+                    Some(scalar) => to_source_expr(scalar)?,
+                    None => Expr::Constant {
+                        span: None,
+                        scalar: field_default_value(self.ctx.clone(), field)?,
+                        data_type: field.data_type().into(),
+                    },
+                };
+                values.push(expr.as_remote_expr());
+            }
+            unmatched.push(MergeUnmatchedOperation { condition, values });
+        }
+
+        let merge_into_info = MergeIntoInfo {
+            source,
+            matched,
+            unmatched,
+        };
+
+        let mut pipeline = Pipeline::create();
+        tbl.merge_into(self.ctx.clone(), merge_into_info, &mut pipeline)
+            .await?;
+        if !pipeline.is_empty() {
+            let settings = self.ctx.get_settings();
+            pipeline.set_max_threads(settings.get_max_threads()? as usize);
+            let query_id = self.ctx.get_id();
+            let executor_settings = ExecutorSettings::try_create(&settings, query_id)?;
+            let executor = PipelineCompleteExecutor::try_create(pipeline, executor_settings)?;
+
+            self.ctx.set_executor(Arc::downgrade(&executor.get_inner()));
+            executor.execute()?;
+            drop(executor);
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
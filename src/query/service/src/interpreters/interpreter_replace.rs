@@ -0,0 +1,242 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::MergeIntoSource;
+use common_catalog::table::Table;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_expression::DataSchemaRef;
+use common_expression::RemoteExpr;
+use common_pipeline_core::Pipeline;
+use common_pipeline_sources::AsyncSourcer;
+use common_sql::plans::InsertInputSource;
+use common_sql::plans::Plan;
+use common_sql::plans::Replace;
+use common_sql::NameResolutionContext;
+
+use super::interpreter_insert_v2::ValueSource;
+use crate::interpreters::Interpreter;
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelineCompleteExecutor;
+use crate::pipelines::processors::MergeIntoHashTable;
+use crate::pipelines::processors::TransformCastSchema;
+use crate::pipelines::processors::TransformResortAddOn;
+use crate::pipelines::PipelineBuildResult;
+use crate::schedulers::build_query_pipeline;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::executor::PhysicalPlanBuilder;
+
+/// interprets Replace
+pub struct ReplaceInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: Replace,
+}
+
+impl ReplaceInterpreter {
+    /// Create the ReplaceInterpreter from Replace
+    pub fn try_create(ctx: Arc<QueryContext>, plan: Replace) -> Result<Self> {
+        Ok(ReplaceInterpreter { ctx, plan })
+    }
+
+    /// Execute the source of REPLACE INTO into the hash table joined with the blocks of
+    /// the table on the conflict keys. The columns of the source are the columns of the
+    /// table, the missing columns are filled with the default values.
+    async fn build_source(&self, table: Arc<dyn Table>) -> Result<Arc<MergeIntoHashTable>> {
+        let insert_schema = self.plan.insert_schema();
+        let mut build_res = match &self.plan.source {
+            InsertInputSource::Values(data) => {
+                let mut build_res = PipelineBuildResult::create();
+                let settings = self.ctx.get_settings();
+                build_res.main_pipeline.add_source(
+                    |output| {
+                        let name_resolution_ctx =
+                            NameResolutionContext::try_from(settings.as_ref())?;
+                        let inner = ValueSource::new(
+                            data.to_string(),
+                            self.ctx.clone(),
+                            name_resolution_ctx,
+                            insert_schema.clone(),
+                        );
+                        AsyncSourcer::create(self.ctx.clone(), output, inner)
+                    },
+                    1,
+                )?;
+                build_res
+            }
+            InsertInputSource::SelectPlan(plan) => {
+                let (s_expr, metadata, bind_context) = match plan.as_ref() {
+                    Plan::Query {
+                        s_expr,
+                        metadata,
+                        bind_context,
+                        ..
+                    } => (s_expr, metadata, bind_context),
+                    _ => unreachable!(),
+                };
+                let select_schema = plan.schema();
+                if select_schema.fields().len() < insert_schema.fields().len() {
+                    return Err(ErrorCode::BadArguments(
+                        "Fields in select statement is less than expected",
+                    ));
+                }
+
+                let mut builder = PhysicalPlanBuilder::new(metadata.clone(), self.ctx.clone());
+                let physical_plan = builder.build(s_expr).await?;
+                let mut build_res = build_query_pipeline(
+                    &self.ctx,
+                    &bind_context.columns,
+                    &physical_plan,
+                    false,
+                    false,
+                )
+                .await?;
+                if select_schema != insert_schema {
+                    let func_ctx = self.ctx.get_function_context()?;
+                    build_res.main_pipeline.add_transform(|input, output| {
+                        TransformCastSchema::try_create(
+                            input,
+                            output,
+                            select_schema.clone(),
+                            insert_schema.clone(),
+                            func_ctx,
+                        )
+                    })?;
+                }
+                build_res
+            }
+            _ => {
+                return Err(ErrorCode::Unimplemented(
+                    "REPLACE INTO only supports VALUES and SELECT as the source",
+                ));
+            }
+        };
+
+        build_res.main_pipeline.add_transform(|input, output| {
+            TransformResortAddOn::try_create(
+                self.ctx.clone(),
+                input,
+                output,
+                insert_schema.clone(),
+                table.clone(),
+            )
+        })?;
+
+        let table_schema = Arc::new(DataSchema::from(table.schema()));
+        let keys = self
+            .plan
+            .on_conflict_fields
+            .iter()
+            .map(|idx| {
+                let field = table_schema.field(*idx);
+                RemoteExpr::ColumnRef {
+                    span: None,
+                    id: *idx,
+                    data_type: field.data_type().clone(),
+                    display_name: field.name().clone(),
+                }
+            })
+            .collect::<Vec<_>>();
+        let source = MergeIntoHashTable::try_create(
+            self.ctx.clone(),
+            table_schema.clone(),
+            table_schema,
+            &keys,
+            &keys,
+            &[],
+        )?;
+        source.add_build_sink(&mut build_res.main_pipeline)?;
+
+        let settings = self.ctx.get_settings();
+        build_res.set_max_threads(settings.get_max_threads()? as usize);
+        let settings = ExecutorSettings::try_create(&settings, self.ctx.get_id())?;
+        let mut pipelines = build_res.sources_pipelines;
+        pipelines.push(build_res.main_pipeline);
+        let executor = PipelineCompleteExecutor::from_pipelines(pipelines, settings)?;
+
+        self.ctx.set_executor(Arc::downgrade(&executor.get_inner()));
+        executor.execute()?;
+        drop(executor);
+        Ok(source)
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ReplaceInterpreter {
+    /// Get the name of current interpreter
+    fn name(&self) -> &str {
+        "ReplaceInterpreter"
+    }
+
+    /// Get the schema of Replace
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.schema()
+    }
+
+    #[tracing::instrument(level = "debug", name = "replace_interpreter_execute", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog_name = self.plan.catalog.as_str();
+        let db_name = self.plan.database.as_str();
+        let tbl_name = self.plan.table.as_str();
+        let tbl = self.ctx.get_table(catalog_name, db_name, tbl_name).await?;
+
+        let source = self.build_source(tbl.clone()).await?;
+        let conflict_keys = source.source_keys(&self.plan.on_conflict_fields);
+
+        let mut pipeline = Pipeline::create();
+        tbl.replace_into(
+            self.ctx.clone(),
+            Arc::new(ReplaceIntoSource(source)),
+            self.plan.on_conflict_fields.clone(),
+            conflict_keys,
+            &mut pipeline,
+        )
+        .await?;
+        if !pipeline.is_empty() {
+            let settings = self.ctx.get_settings();
+            pipeline.set_max_threads(settings.get_max_threads()? as usize);
+            let query_id = self.ctx.get_id();
+            let executor_settings = ExecutorSettings::try_create(&settings, query_id)?;
+            let executor = PipelineCompleteExecutor::try_create(pipeline, executor_settings)?;
+
+            self.ctx.set_executor(Arc::downgrade(&executor.get_inner()));
+            executor.execute()?;
+            drop(executor);
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
+
+/// The source of REPLACE INTO. The matched rows of the table are deleted and all the
+/// source rows are inserted, so every source row is passed to the NOT MATCHED clause.
+struct ReplaceIntoSource(Arc<MergeIntoHashTable>);
+
+impl MergeIntoSource for ReplaceIntoSource {
+    fn num_rows(&self) -> usize {
+        self.0.num_rows()
+    }
+
+    fn probe(&self, block: &DataBlock) -> Result<(Vec<u32>, DataBlock)> {
+        self.0.probe(block)
+    }
+
+    fn unmatched_blocks(&self) -> Result<Vec<DataBlock>> {
+        Ok(self.0.source_blocks())
+    }
+}
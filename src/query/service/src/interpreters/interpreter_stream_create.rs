@@ -0,0 +1,109 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TableNameIdent;
+use common_sql::plans::CreateStreamPlan;
+use common_storages_fuse::stream::CHANGE_ACTION_COLUMN;
+use common_storages_fuse::stream::OPT_KEY_OFFSET_SNAPSHOT_LOCATION;
+use common_storages_fuse::stream::OPT_KEY_TABLE_DATABASE;
+use common_storages_fuse::stream::OPT_KEY_TABLE_ID;
+use common_storages_fuse::stream::OPT_KEY_TABLE_NAME;
+use common_storages_fuse::stream::STREAM_ENGINE;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::storages::fuse::FuseTable;
+
+pub struct CreateStreamInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateStreamPlan,
+}
+
+impl CreateStreamInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateStreamPlan) -> Result<Self> {
+        Ok(CreateStreamInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateStreamInterpreter {
+    fn name(&self) -> &str {
+        "CreateStreamInterpreter"
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.plan.tenant.as_str();
+        let catalog = self.ctx.get_catalog(&self.plan.catalog)?;
+
+        let table = catalog
+            .get_table(tenant, &self.plan.table_database, &self.plan.table_name)
+            .await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref()).map_err(|_| {
+            ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} engine is {} that doesn't support stream",
+                &self.plan.table_database,
+                &self.plan.table_name,
+                table.engine()
+            ))
+        })?;
+
+        // the stream starts at the current snapshot of the table, with the columns of the
+        // table followed by `change$action`.
+        let mut schema = table.schema().as_ref().clone();
+        schema.add_columns(&[TableField::new(CHANGE_ACTION_COLUMN, TableDataType::String)])?;
+
+        let mut options = BTreeMap::new();
+        options.insert(
+            OPT_KEY_TABLE_DATABASE.to_string(),
+            self.plan.table_database.clone(),
+        );
+        options.insert(OPT_KEY_TABLE_NAME.to_string(), self.plan.table_name.clone());
+        options.insert(OPT_KEY_TABLE_ID.to_string(), table.get_id().to_string());
+        if let Some(location) = fuse_table.snapshot_loc().await? {
+            options.insert(OPT_KEY_OFFSET_SNAPSHOT_LOCATION.to_string(), location);
+        }
+
+        catalog
+            .create_table(CreateTableReq {
+                if_not_exists: self.plan.if_not_exists,
+                name_ident: TableNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: self.plan.database.clone(),
+                    table_name: self.plan.stream_name.clone(),
+                },
+                table_meta: TableMeta {
+                    schema: Arc::new(schema),
+                    engine: STREAM_ENGINE.to_string(),
+                    options,
+                    created_on: Utc::now(),
+                    ..Default::default()
+                },
+            })
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
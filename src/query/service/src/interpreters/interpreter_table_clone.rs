@@ -0,0 +1,124 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::TableNameIdent;
+use common_sql::plans::CloneTablePlan;
+use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::storages::fuse::FuseTable;
+
+pub struct CloneTableInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CloneTablePlan,
+}
+
+impl CloneTableInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CloneTablePlan) -> Result<Self> {
+        Ok(CloneTableInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CloneTableInterpreter {
+    fn name(&self) -> &str {
+        "CloneTableInterpreter"
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.plan.tenant.as_str();
+        let catalog = self.ctx.get_catalog(self.plan.catalog.as_str())?;
+
+        if catalog
+            .get_table(tenant, &self.plan.database, &self.plan.table)
+            .await
+            .is_ok()
+        {
+            if self.plan.if_not_exists {
+                return Ok(PipelineBuildResult::create());
+            }
+            return Err(ErrorCode::TableAlreadyExists(format!(
+                "Table '{}' already exists",
+                &self.plan.table
+            )));
+        }
+
+        let source = catalog
+            .get_table(tenant, &self.plan.source_database, &self.plan.source_table)
+            .await?;
+        if FuseTable::try_from_table(source.as_ref()).is_err() {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} engine is {} that doesn't support clone",
+                &self.plan.source_database,
+                &self.plan.source_table,
+                source.engine()
+            )));
+        }
+        let source = match &self.plan.point {
+            Some(point) => source.navigate_to(point).await?,
+            None => source,
+        };
+
+        // the clone keeps the storage and the options of the source table, but starts with
+        // its own (empty) history.
+        let db = catalog.get_database(tenant, &self.plan.database).await?;
+        let mut table_meta = source.get_table_info().meta.clone();
+        table_meta.schema = source.schema();
+        table_meta.options.remove(OPT_KEY_SNAPSHOT_LOCATION);
+        table_meta.options.remove(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+        table_meta.options.insert(
+            OPT_KEY_DATABASE_ID.to_owned(),
+            db.get_db_info().ident.db_id.to_string(),
+        );
+        table_meta.statistics = Default::default();
+        table_meta.created_on = Utc::now();
+        table_meta.updated_on = Utc::now();
+        table_meta.drop_on = None;
+
+        catalog
+            .create_table(CreateTableReq {
+                if_not_exists: false,
+                name_ident: TableNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: self.plan.database.clone(),
+                    table_name: self.plan.table.clone(),
+                },
+                table_meta,
+            })
+            .await?;
+
+        let table = catalog
+            .get_table(tenant, &self.plan.database, &self.plan.table)
+            .await?;
+        FuseTable::try_from_table(table.as_ref())?
+            .do_clone_from(
+                self.ctx.clone(),
+                FuseTable::try_from_table(source.as_ref())?,
+            )
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -25,6 +25,7 @@ use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
+use crate::storages::fuse::FuseTable;
 
 pub struct DropTableInterpreter {
     ctx: Arc<QueryContext>,
@@ -77,6 +78,11 @@ impl Interpreter for DropTableInterpreter {
                 let latest = tbl.as_ref().refresh(self.ctx.as_ref()).await?;
                 latest.truncate(self.ctx.clone(), purge).await?
             }
+
+            // a dropped clone no longer keeps the files of the tables it is cloned from.
+            if let Ok(fuse_table) = FuseTable::try_from_table(tbl.as_ref()) {
+                fuse_table.release_clone_references().await?;
+            }
         }
 
         Ok(PipelineBuildResult::create())
@@ -0,0 +1,139 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_meta_app::schema::DatabaseType;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::ModifyTableColumnTypePlan;
+use common_storages_view::view_table::VIEW_ENGINE;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct ModifyTableColumnTypeInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: ModifyTableColumnTypePlan,
+}
+
+impl ModifyTableColumnTypeInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: ModifyTableColumnTypePlan) -> Result<Self> {
+        Ok(ModifyTableColumnTypeInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ModifyTableColumnTypeInterpreter {
+    fn name(&self) -> &str {
+        "ModifyTableColumnTypeInterpreter"
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog_name = self.plan.catalog.as_str();
+        let db_name = self.plan.database.as_str();
+        let tbl_name = self.plan.table.as_str();
+        let tbl = self
+            .ctx
+            .get_catalog(catalog_name)?
+            .get_table(self.ctx.get_tenant().as_str(), db_name, tbl_name)
+            .await
+            .ok();
+
+        if let Some(table) = &tbl {
+            let table_info = table.get_table_info();
+            if table_info.engine() == VIEW_ENGINE {
+                return Err(ErrorCode::TableEngineNotSupported(format!(
+                    "{}.{} engine is VIEW that doesn't support alter",
+                    &self.plan.database, &self.plan.table
+                )));
+            }
+            if table_info.db_type != DatabaseType::NormalDB {
+                return Err(ErrorCode::TableEngineNotSupported(format!(
+                    "{}.{} doesn't support alter",
+                    &self.plan.database, &self.plan.table
+                )));
+            }
+            // blocks written before the modification are casted on read by the fuse block reader.
+            if table_info.engine() != "FUSE" {
+                return Err(ErrorCode::TableEngineNotSupported(format!(
+                    "{}.{} engine is {} that doesn't support modify column type",
+                    &self.plan.database,
+                    &self.plan.table,
+                    table_info.engine()
+                )));
+            }
+
+            let schema = table.schema();
+            let field = schema.field_with_name(&self.plan.column)?;
+            // only widening casts are allowed, so that old blocks can always be read.
+            let src_type = DataType::from(field.data_type());
+            let dest_type = DataType::from(&self.plan.data_type);
+            if !is_widening_cast(&src_type, &dest_type) {
+                return Err(ErrorCode::AlterTableError(format!(
+                    "cannot modify column {} from {} to {}",
+                    &self.plan.column, src_type, dest_type
+                )));
+            }
+
+            let catalog = self.ctx.get_catalog(catalog_name)?;
+            let mut new_table_meta = table.get_table_info().meta.clone();
+            new_table_meta.modify_column_type(&self.plan.column, self.plan.data_type.clone())?;
+
+            let table_id = table_info.ident.table_id;
+            let table_version = table_info.ident.seq;
+
+            let req = UpdateTableMetaReq {
+                table_id,
+                seq: MatchSeq::Exact(table_version),
+                new_table_meta,
+            };
+
+            catalog.update_table_meta(table_info, req).await?;
+        };
+
+        Ok(PipelineBuildResult::create())
+    }
+}
+
+/// Check if every value of `src` can be casted to `dest` without loss or failure.
+fn is_widening_cast(src: &DataType, dest: &DataType) -> bool {
+    match (src, dest) {
+        _ if src == dest => true,
+        (DataType::Nullable(src), DataType::Nullable(dest)) => is_widening_cast(src, dest),
+        (_, DataType::Nullable(dest)) => is_widening_cast(src, dest),
+        (DataType::Number(src), DataType::Number(dest)) => is_widening_number_cast(*src, *dest),
+        (DataType::Decimal(src), DataType::Decimal(dest)) => {
+            dest.leading_digits() >= src.leading_digits() && dest.scale() >= src.scale()
+        }
+        _ => false,
+    }
+}
+
+fn is_widening_number_cast(src: NumberDataType, dest: NumberDataType) -> bool {
+    match (src.is_float(), dest.is_float()) {
+        (true, true) => src.bit_width() < dest.bit_width(),
+        // Unsigned integers can be widened to signed integers, but not the reverse.
+        (false, false) => {
+            (!src.is_signed() || dest.is_signed()) && src.bit_width() < dest.bit_width()
+        }
+        _ => false,
+    }
+}
@@ -21,6 +21,7 @@ use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
+use crate::storages::fuse::FuseTable;
 
 pub struct UndropTableInterpreter {
     ctx: Arc<QueryContext>,
@@ -44,6 +45,16 @@ impl Interpreter for UndropTableInterpreter {
         let catalog = self.ctx.get_catalog(catalog_name)?;
         catalog.undrop_table(self.plan.clone().into()).await?;
 
+        // an undropped clone references the files of the tables it is cloned from again.
+        let table = catalog
+            .get_table(&self.plan.tenant, &self.plan.database, &self.plan.table)
+            .await?;
+        if let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) {
+            fuse_table
+                .restore_clone_references(self.ctx.clone())
+                .await?;
+        }
+
         Ok(PipelineBuildResult::create())
     }
 }
@@ -0,0 +1,63 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::servers::http::v1::HttpQueryManager;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+
+/// Starts an explicit transaction of the session.
+pub struct BeginInterpreter {
+    ctx: Arc<QueryContext>,
+}
+
+impl BeginInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>) -> Result<Self> {
+        Ok(BeginInterpreter { ctx })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for BeginInterpreter {
+    fn name(&self) -> &str {
+        "BeginInterpreter"
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        // The session of an http query is dropped after the query unless it is kept on
+        // the server side, which would silently rollback the transaction.
+        let session = self.ctx.get_current_session();
+        if matches!(session.get_type(), SessionType::HTTPQuery)
+            && HttpQueryManager::instance()
+                .get_session(&session.get_id())
+                .await
+                .is_none()
+        {
+            return Err(ErrorCode::InvalidTransaction(
+                "Transaction in http query requires a session kept on the server side, please set `keep_server_session_secs` and reuse the `session_id`",
+            ));
+        }
+
+        self.ctx.txn_mgr().lock().begin()?;
+        Ok(PipelineBuildResult::create())
+    }
+}
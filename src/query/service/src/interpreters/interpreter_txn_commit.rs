@@ -0,0 +1,85 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Commits the explicit transaction of the session.
+///
+/// The new metas of all the tables mutated in the transaction are updated in one meta
+/// transaction, which fails if any of them has been changed by others since it was first
+/// accessed in the transaction.
+pub struct CommitInterpreter {
+    ctx: Arc<QueryContext>,
+}
+
+impl CommitInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>) -> Result<Self> {
+        Ok(CommitInterpreter { ctx })
+    }
+
+    async fn commit(&self) -> Result<()> {
+        let mutated = self.ctx.txn_mgr().lock().mutated_tables();
+        if mutated.len() > 1 {
+            return Err(ErrorCode::InvalidTransaction(
+                "Transaction across catalogs is not supported",
+            ));
+        }
+
+        for (catalog, update_table_metas) in mutated {
+            let catalog = self.ctx.get_catalog(&catalog)?;
+            catalog
+                .update_multi_table_meta(UpdateMultiTableMetaReq { update_table_metas })
+                .await
+                .map_err(|e| match e.code() {
+                    ErrorCode::TABLE_VERSION_MISMATCHED => ErrorCode::TransactionConflict(format!(
+                        "Transaction is aborted by a concurrent commit: {}",
+                        e.message()
+                    )),
+                    _ => e,
+                })?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CommitInterpreter {
+    fn name(&self) -> &str {
+        "CommitInterpreter"
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let txn_mgr = self.ctx.txn_mgr();
+        if !txn_mgr.lock().is_active() {
+            // no transaction in progress, nothing to commit
+            return Ok(PipelineBuildResult::create());
+        }
+
+        // The transaction is finished whether the commit succeeds or not.
+        let res = self.commit().await;
+        txn_mgr.lock().clear();
+        res?;
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -27,18 +27,24 @@ use common_expression::SortColumnDescription;
 use common_functions::aggregates::AggregateFunctionFactory;
 use common_functions::aggregates::AggregateFunctionRef;
 use common_functions::scalars::BUILTIN_FUNCTIONS;
+use common_functions::srfs::get_set_returning_function;
+use common_pipeline_core::pipe::Pipe;
 use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_sinks::EmptySink;
 use common_pipeline_sinks::Sinker;
 use common_pipeline_sinks::UnionReceiveSink;
+use common_pipeline_sources::BlocksSource;
 use common_pipeline_transforms::processors::transforms::try_add_multi_sort_merge;
 use common_pipeline_transforms::processors::transforms::try_create_transform_sort_merge;
+use common_pipeline_transforms::processors::transforms::try_create_transform_sort_spill;
 use common_profile::ProfSpanSetRef;
 use common_sql::evaluator::BlockOperator;
 use common_sql::evaluator::CompoundBlockOperator;
+use common_sql::executor::AggregateExpand;
 use common_sql::executor::AggregateFinal;
 use common_sql::executor::AggregateFunctionDesc;
 use common_sql::executor::AggregatePartial;
+use common_sql::executor::CteScan;
 use common_sql::executor::DistributedInsertSelect;
 use common_sql::executor::EvalScalar;
 use common_sql::executor::ExchangeSink;
@@ -46,27 +52,46 @@ use common_sql::executor::ExchangeSource;
 use common_sql::executor::Filter;
 use common_sql::executor::HashJoin;
 use common_sql::executor::Limit;
+use common_sql::executor::MaterializedCte;
 use common_sql::executor::PhysicalPlan;
 use common_sql::executor::Project;
+use common_sql::executor::ProjectSet;
+use common_sql::executor::RecursiveCte;
+use common_sql::executor::RecursiveCteScan;
 use common_sql::executor::Sort;
 use common_sql::executor::TableScan;
 use common_sql::executor::UnionAll;
+use common_sql::executor::Window;
+use common_sql::executor::WindowFunction;
 use common_sql::plans::JoinType;
 use common_sql::ColumnBinding;
 use common_sql::IndexType;
+use parking_lot::Mutex;
 
 use super::processors::ProfileWrapper;
 use crate::api::ExchangeSorting;
 use crate::pipelines::processors::transforms::efficiently_memory_final_aggregator;
 use crate::pipelines::processors::transforms::AggregateExchangeSorting;
+use crate::pipelines::processors::transforms::CteScanSource;
 use crate::pipelines::processors::transforms::FinalSingleStateAggregator;
 use crate::pipelines::processors::transforms::HashJoinDesc;
+use crate::pipelines::processors::transforms::JoinSpillState;
+use crate::pipelines::processors::transforms::MaterializedCteState;
+use crate::pipelines::processors::transforms::MaterializedCteStates;
+use crate::pipelines::processors::transforms::RecursiveCteSource;
+use crate::pipelines::processors::transforms::RecursiveCteState;
+use crate::pipelines::processors::transforms::RecursiveCteStates;
 use crate::pipelines::processors::transforms::RightSemiAntiJoinCompactor;
+use crate::pipelines::processors::transforms::TransformExpandGroupingSets;
 use crate::pipelines::processors::transforms::TransformLeftJoin;
 use crate::pipelines::processors::transforms::TransformMarkJoin;
 use crate::pipelines::processors::transforms::TransformMergeBlock;
 use crate::pipelines::processors::transforms::TransformRightJoin;
 use crate::pipelines::processors::transforms::TransformRightSemiAntiJoin;
+use crate::pipelines::processors::transforms::TransformSRF;
+use crate::pipelines::processors::transforms::TransformWindow;
+use crate::pipelines::processors::transforms::TransformWindowScatter;
+use crate::pipelines::processors::transforms::WindowFunctionImpl;
 use crate::pipelines::processors::AggregatorParams;
 use crate::pipelines::processors::AggregatorTransformParams;
 use crate::pipelines::processors::JoinHashTable;
@@ -94,6 +119,11 @@ pub struct PipelineBuilder {
     enable_profiling: bool,
     prof_span_set: ProfSpanSetRef,
     exchange_sorting: Option<Arc<dyn ExchangeSorting>>,
+
+    /// Working tables of the recursive CTEs being evaluated.
+    pub recursive_cte_states: RecursiveCteStates,
+    /// Materialized CTEs which can be read by the plan being built.
+    pub materialized_cte_states: MaterializedCteStates,
 }
 
 impl PipelineBuilder {
@@ -109,6 +139,8 @@ impl PipelineBuilder {
             main_pipeline: Pipeline::create(),
             prof_span_set,
             exchange_sorting: None,
+            recursive_cte_states: RecursiveCteStates::new(),
+            materialized_cte_states: MaterializedCteStates::new(),
         }
     }
 
@@ -137,14 +169,21 @@ impl PipelineBuilder {
             PhysicalPlan::Filter(filter) => self.build_filter(filter),
             PhysicalPlan::Project(project) => self.build_project(project),
             PhysicalPlan::EvalScalar(eval_scalar) => self.build_eval_scalar(eval_scalar),
+            PhysicalPlan::AggregateExpand(aggregate) => self.build_aggregate_expand(aggregate),
             PhysicalPlan::AggregatePartial(aggregate) => self.build_aggregate_partial(aggregate),
             PhysicalPlan::AggregateFinal(aggregate) => self.build_aggregate_final(aggregate),
             PhysicalPlan::Sort(sort) => self.build_sort(sort),
             PhysicalPlan::Limit(limit) => self.build_limit(limit),
+            PhysicalPlan::Window(window) => self.build_window(window),
+            PhysicalPlan::ProjectSet(project_set) => self.build_project_set(project_set),
             PhysicalPlan::HashJoin(join) => self.build_join(join),
             PhysicalPlan::ExchangeSink(sink) => self.build_exchange_sink(sink),
             PhysicalPlan::ExchangeSource(source) => self.build_exchange_source(source),
             PhysicalPlan::UnionAll(union_all) => self.build_union_all(union_all),
+            PhysicalPlan::RecursiveCte(cte) => self.build_recursive_cte(cte),
+            PhysicalPlan::RecursiveCteScan(scan) => self.build_recursive_cte_scan(scan),
+            PhysicalPlan::MaterializedCte(cte) => self.build_materialized_cte(cte),
+            PhysicalPlan::CteScan(scan) => self.build_cte_scan(scan),
             PhysicalPlan::DistributedInsertSelect(insert_select) => {
                 self.build_distributed_insert_select(insert_select)
             }
@@ -161,12 +200,26 @@ impl PipelineBuilder {
     }
 
     fn build_join_state(&mut self, join: &HashJoin) -> Result<Arc<JoinHashTable>> {
+        let spilling_bytes_threshold = self
+            .ctx
+            .get_settings()
+            .get_join_spilling_bytes_threshold()? as usize;
+        let spill_state = match spilling_bytes_threshold {
+            0 => None,
+            _ => Some(JoinSpillState::try_create(
+                self.ctx.clone(),
+                join,
+                spilling_bytes_threshold,
+            )?),
+        };
+
         JoinHashTable::create_join_state(
             self.ctx.clone(),
             &join.build_keys,
             join.build.output_schema()?,
             join.probe.output_schema()?,
             HashJoinDesc::create(join)?,
+            spill_state,
         )
     }
 
@@ -177,11 +230,13 @@ impl PipelineBuilder {
         join_state: Arc<JoinHashTable>,
     ) -> Result<()> {
         let build_side_context = QueryContext::create_from(self.ctx.clone());
-        let build_side_builder = PipelineBuilder::create(
+        let mut build_side_builder = PipelineBuilder::create(
             build_side_context,
             self.enable_profiling,
             self.prof_span_set.clone(),
         );
+        build_side_builder.recursive_cte_states = self.recursive_cte_states.clone();
+        build_side_builder.materialized_cte_states = self.materialized_cte_states.clone();
         let mut build_res = build_side_builder.finalize(build)?;
 
         assert!(build_res.main_pipeline.is_pulling_pipeline()?);
@@ -362,6 +417,53 @@ impl PipelineBuilder {
         Ok(())
     }
 
+    fn build_aggregate_expand(&mut self, expand: &AggregateExpand) -> Result<()> {
+        self.build_pipeline(&expand.input)?;
+        let input_schema = expand.input.output_schema()?;
+        let group_bys = expand
+            .group_bys
+            .iter()
+            .map(|index| input_schema.index_of(&index.to_string()))
+            .collect::<Result<Vec<_>>>()?;
+        let dup_group_items = expand
+            .dup_group_items
+            .iter()
+            .map(|(index, _)| input_schema.index_of(&index.to_string()))
+            .collect::<Result<Vec<_>>>()?;
+        let grouping_ids = expand
+            .grouping_sets
+            .iter()
+            .map(|set| {
+                expand
+                    .group_bys
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, index)| !set.contains(index))
+                    .fold(0u32, |id, (i, _)| id | (1 << i))
+            })
+            .collect::<Vec<_>>();
+
+        self.main_pipeline.add_transform(|input, output| {
+            let transform = TransformExpandGroupingSets::create(
+                input,
+                output,
+                group_bys.clone(),
+                dup_group_items.clone(),
+                grouping_ids.clone(),
+            );
+
+            if self.enable_profiling {
+                Ok(ProcessorPtr::create(ProfileWrapper::create(
+                    transform,
+                    expand.plan_id,
+                    self.prof_span_set.clone(),
+                )))
+            } else {
+                Ok(ProcessorPtr::create(transform))
+            }
+        })
+    }
+
     fn build_aggregate_partial(&mut self, aggregate: &AggregatePartial) -> Result<()> {
         self.build_pipeline(&aggregate.input)?;
         let params = Self::build_aggregator_params(
@@ -501,6 +603,11 @@ impl PipelineBuilder {
 
         let max_threads = self.ctx.get_settings().get_max_threads()? as usize;
         let block_size = self.ctx.get_settings().get_max_block_size()? as usize;
+        let spilling_bytes_threshold = self
+            .ctx
+            .get_settings()
+            .get_sort_spilling_bytes_threshold()? as usize;
+        let spill_dir = self.ctx.get_spill_dir();
 
         // TODO(Winter): the query will hang in MultiSortMergeProcessor when max_threads == 1 and output_len != 1
         if self.main_pipeline.output_len() == 1 || max_threads == 1 {
@@ -525,14 +632,27 @@ impl PipelineBuilder {
 
         // Merge
         self.main_pipeline.add_transform(|input, output| {
-            let transform = try_create_transform_sort_merge(
-                input,
-                output,
-                input_schema.clone(),
-                block_size,
-                sort.limit,
-                sort_desc.clone(),
-            )?;
+            // The sort with limit only keeps a few rows of each block, so it's never spilled.
+            let transform = if spilling_bytes_threshold != 0 && sort.limit.is_none() {
+                try_create_transform_sort_spill(
+                    input,
+                    output,
+                    input_schema.clone(),
+                    block_size,
+                    sort_desc.clone(),
+                    spilling_bytes_threshold,
+                    spill_dir.clone(),
+                )?
+            } else {
+                try_create_transform_sort_merge(
+                    input,
+                    output,
+                    input_schema.clone(),
+                    block_size,
+                    sort.limit,
+                    sort_desc.clone(),
+                )?
+            };
 
             if self.enable_profiling {
                 Ok(ProcessorPtr::create(ProfileWrapper::create(
@@ -574,6 +694,161 @@ impl PipelineBuilder {
         })
     }
 
+    fn build_window(&mut self, window: &Window) -> Result<()> {
+        self.build_pipeline(&window.input)?;
+
+        let input_schema = window.input.output_schema()?;
+
+        let partition_by = window
+            .partition_by
+            .iter()
+            .map(|index| input_schema.index_of(&index.to_string()))
+            .collect::<Result<Vec<_>>>()?;
+
+        let order_by = window
+            .order_by
+            .iter()
+            .map(|desc| input_schema.index_of(&desc.order_by.to_string()))
+            .collect::<Result<Vec<_>>>()?;
+
+        // The input is sorted by the partition keys and the order keys in a single stream.
+        // Each partition is evaluated by a single processor, so the rows are scattered to the
+        // processors by the hash of the partition keys, which keeps them sorted.
+        let max_threads = self.ctx.get_settings().get_max_threads()? as usize;
+        self.main_pipeline.resize(1)?;
+        if !partition_by.is_empty() && max_threads > 1 {
+            self.main_pipeline
+                .add_pipe(Pipe::create(1, max_threads, vec![
+                    TransformWindowScatter::create_item(max_threads, partition_by.clone()),
+                ]));
+        }
+
+        self.main_pipeline.add_transform(|input, output| {
+            let func = match &window.func {
+                WindowFunction::Aggregate(agg) => {
+                    let params = agg
+                        .sig
+                        .params
+                        .iter()
+                        .map(|p| p.clone().into_scalar())
+                        .collect();
+                    let agg_func = AggregateFunctionFactory::instance().get(
+                        agg.sig.name.as_str(),
+                        params,
+                        agg.sig.args.clone(),
+                    )?;
+                    WindowFunctionImpl::Aggregate {
+                        agg: agg_func,
+                        args: agg.args.clone(),
+                    }
+                }
+                WindowFunction::RowNumber => WindowFunctionImpl::RowNumber,
+                WindowFunction::Rank => WindowFunctionImpl::Rank,
+                WindowFunction::DenseRank => WindowFunctionImpl::DenseRank,
+                WindowFunction::PercentRank => WindowFunctionImpl::PercentRank,
+                WindowFunction::CumeDist => WindowFunctionImpl::CumeDist,
+                WindowFunction::LagLead(lag_lead) => WindowFunctionImpl::LagLead {
+                    is_lag: lag_lead.is_lag,
+                    offset: lag_lead.offset as usize,
+                    arg: lag_lead.arg,
+                    default: lag_lead.default,
+                    return_type: lag_lead.return_type.clone(),
+                },
+                WindowFunction::NthValue(nth_value) => WindowFunctionImpl::NthValue {
+                    n: nth_value.n.map(|n| n as usize),
+                    arg: nth_value.arg,
+                    return_type: nth_value.return_type.clone(),
+                },
+            };
+
+            let transform = TransformWindow::try_create(
+                input,
+                output,
+                func,
+                partition_by.clone(),
+                order_by.clone(),
+                window.window_frame.clone(),
+            )?;
+
+            if self.enable_profiling {
+                Ok(ProcessorPtr::create(ProfileWrapper::create(
+                    transform,
+                    window.plan_id,
+                    self.prof_span_set.clone(),
+                )))
+            } else {
+                Ok(ProcessorPtr::create(transform))
+            }
+        })?;
+
+        // Merge the outputs back into a single sorted stream, the following windows
+        // may rely on the sorted input.
+        let default_nulls_first = !self.ctx.get_settings().get_sql_dialect()?.is_null_biggest();
+        let sort_desc = partition_by
+            .iter()
+            .map(|offset| SortColumnDescription {
+                offset: *offset,
+                asc: true,
+                nulls_first: default_nulls_first,
+            })
+            .chain(
+                window
+                    .order_by
+                    .iter()
+                    .zip(order_by.iter())
+                    .map(|(desc, offset)| SortColumnDescription {
+                        offset: *offset,
+                        asc: desc.asc,
+                        nulls_first: desc.nulls_first,
+                    }),
+            )
+            .collect::<Vec<_>>();
+        let block_size = self.ctx.get_settings().get_max_block_size()? as usize;
+        try_add_multi_sort_merge(
+            &mut self.main_pipeline,
+            window.output_schema()?,
+            block_size,
+            None,
+            sort_desc,
+        )
+    }
+
+    fn build_project_set(&mut self, project_set: &ProjectSet) -> Result<()> {
+        self.build_pipeline(&project_set.input)?;
+
+        let srf = get_set_returning_function(&project_set.func_name).ok_or_else(|| {
+            ErrorCode::UnknownFunction(format!(
+                "Unknown set-returning function {}",
+                project_set.func_name
+            ))
+        })?;
+        let return_types = project_set
+            .columns
+            .iter()
+            .map(|(_, data_type)| data_type.clone())
+            .collect::<Vec<_>>();
+
+        self.main_pipeline.add_transform(|input, output| {
+            let transform = TransformSRF::create(
+                input,
+                output,
+                srf,
+                project_set.arguments.clone(),
+                return_types.clone(),
+            );
+
+            if self.enable_profiling {
+                Ok(ProcessorPtr::create(ProfileWrapper::create(
+                    transform,
+                    project_set.plan_id,
+                    self.prof_span_set.clone(),
+                )))
+            } else {
+                Ok(ProcessorPtr::create(transform))
+            }
+        })
+    }
+
     fn build_join_probe(&mut self, join: &HashJoin, state: Arc<JoinHashTable>) -> Result<()> {
         self.build_pipeline(&join.probe)?;
 
@@ -711,8 +986,10 @@ impl PipelineBuilder {
         union_plan: &UnionAll,
     ) -> Result<Receiver<DataBlock>> {
         let union_ctx = QueryContext::create_from(self.ctx.clone());
-        let pipeline_builder =
+        let mut pipeline_builder =
             PipelineBuilder::create(union_ctx, self.enable_profiling, self.prof_span_set.clone());
+        pipeline_builder.recursive_cte_states = self.recursive_cte_states.clone();
+        pipeline_builder.materialized_cte_states = self.materialized_cte_states.clone();
         let mut build_res = pipeline_builder.finalize(input)?;
 
         assert!(build_res.main_pipeline.is_pulling_pipeline()?);
@@ -739,6 +1016,79 @@ impl PipelineBuilder {
         Ok(rx)
     }
 
+    fn build_recursive_cte(&mut self, cte: &RecursiveCte) -> Result<()> {
+        let mut states = self.recursive_cte_states.clone();
+        states.insert(cte.cte_name.clone(), Arc::new(RecursiveCteState::default()));
+
+        self.main_pipeline.add_source(
+            |output| {
+                RecursiveCteSource::create(
+                    self.ctx.clone(),
+                    output,
+                    cte.clone(),
+                    states.clone(),
+                    self.materialized_cte_states.clone(),
+                )
+            },
+            1,
+        )
+    }
+
+    fn build_recursive_cte_scan(&mut self, scan: &RecursiveCteScan) -> Result<()> {
+        let state = self
+            .recursive_cte_states
+            .get(&scan.cte_name)
+            .ok_or_else(|| {
+                ErrorCode::Internal(format!(
+                    "Recursive CTE {} is referenced out of its recursive term",
+                    scan.cte_name
+                ))
+            })?;
+        let blocks = Arc::new(Mutex::new(state.working_table().into()));
+
+        self.main_pipeline.add_source(
+            |output| BlocksSource::create(self.ctx.clone(), output, blocks.clone()),
+            1,
+        )
+    }
+
+    fn build_materialized_cte(&mut self, cte: &MaterializedCte) -> Result<()> {
+        let state = MaterializedCteState::create(
+            self.ctx.clone(),
+            cte.clone(),
+            self.recursive_cte_states.clone(),
+            self.materialized_cte_states.clone(),
+        );
+        self.materialized_cte_states
+            .insert(cte.cte_idx, Arc::new(state));
+        self.build_pipeline(&cte.right)
+    }
+
+    fn build_cte_scan(&mut self, scan: &CteScan) -> Result<()> {
+        let state = self
+            .materialized_cte_states
+            .get(&scan.cte_idx)
+            .cloned()
+            .ok_or_else(|| {
+                ErrorCode::Internal(format!(
+                    "Materialized CTE {} is referenced out of its scope",
+                    scan.cte_name
+                ))
+            })?;
+
+        self.main_pipeline.add_source(
+            |output| {
+                CteScanSource::create(
+                    self.ctx.clone(),
+                    output,
+                    state.clone(),
+                    scan.cte_columns.clone(),
+                )
+            },
+            1,
+        )
+    }
+
     pub fn build_union_all(&mut self, union_all: &UnionAll) -> Result<()> {
         self.build_pipeline(&union_all.left)?;
         let union_all_receiver = self.expand_union_all(&union_all.right, union_all)?;
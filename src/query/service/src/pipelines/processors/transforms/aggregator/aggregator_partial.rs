@@ -26,6 +26,8 @@ use common_hashtable::HashtableEntryRefLike;
 use common_hashtable::HashtableLike;
 
 use super::estimated_key_size;
+use super::remove_spilled_file;
+use super::OverflowInfo;
 use crate::pipelines::processors::transforms::group_by::Area;
 use crate::pipelines::processors::transforms::group_by::ArenaHolder;
 use crate::pipelines::processors::transforms::group_by::HashMethodBounds;
@@ -45,6 +47,8 @@ pub struct PartialAggregator<const HAS_AGG: bool, Method: HashMethodBounds> {
     pub input_rows: usize,
     pub pass_state_to_final: bool,
     pub two_level_mode: bool,
+    /// The buckets spilled to disk in two-level mode, restored at generate time.
+    pub spilled_files: Vec<OverflowInfo>,
 }
 
 impl<const HAS_AGG: bool, Method: HashMethodBounds> PartialAggregator<HAS_AGG, Method> {
@@ -65,6 +69,7 @@ impl<const HAS_AGG: bool, Method: HashMethodBounds> PartialAggregator<HAS_AGG, M
             input_rows: 0,
             pass_state_to_final,
             two_level_mode: false,
+            spilled_files: vec![],
         })
     }
 
@@ -295,6 +300,10 @@ impl<const HAS_AGG: bool, Method: HashMethodBounds> PartialAggregator<HAS_AGG, M
             self.hash_table.clear();
             self.states_dropped = true;
         }
+
+        for spilled_file in self.spilled_files.drain(..) {
+            remove_spilled_file(&spilled_file);
+        }
     }
 }
 
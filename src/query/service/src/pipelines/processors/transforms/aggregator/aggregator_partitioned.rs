@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::path::Path;
 use std::time::Instant;
 
 use common_exception::ErrorCode;
@@ -29,9 +30,13 @@ use common_hashtable::HashtableLike;
 use tracing::info;
 
 use super::estimated_key_size;
+use super::remove_spilled_file;
+use super::restore_bucket;
+use super::spill_buckets;
 use crate::pipelines::processors::transforms::aggregator::aggregate_info::AggregateInfo;
 use crate::pipelines::processors::transforms::aggregator::AggregateHashStateInfo;
 use crate::pipelines::processors::transforms::aggregator::PartialAggregator;
+use crate::pipelines::processors::transforms::group_by::Area;
 use crate::pipelines::processors::transforms::group_by::HashMethodBounds;
 use crate::pipelines::processors::transforms::group_by::KeysColumnBuilder;
 use crate::pipelines::processors::transforms::group_by::PartitionedHashMethod;
@@ -67,6 +72,17 @@ where Self: Aggregator + Send
             Self::NAME
         )))
     }
+
+    fn get_partitioned_state_bytes(_agg: &Self::PartitionedAggregator) -> usize {
+        0
+    }
+
+    fn spill_partitioned(_agg: &mut Self::PartitionedAggregator, _spill_dir: &Path) -> Result<()> {
+        Err(ErrorCode::Unimplemented(format!(
+            "Spilling aggregator is unimplemented for {}",
+            Self::NAME
+        )))
+    }
 }
 
 impl<Method: HashMethodBounds, const HAS_AGG: bool> PartitionedAggregatorLike
@@ -122,6 +138,7 @@ impl<Method: HashMethodBounds, const HAS_AGG: bool> PartitionedAggregatorLike
                 input_rows: self.input_rows,
                 pass_state_to_final: self.pass_state_to_final,
                 two_level_mode: true,
+                spilled_files: vec![],
             },
         })
     }
@@ -129,38 +146,22 @@ impl<Method: HashMethodBounds, const HAS_AGG: bool> PartitionedAggregatorLike
     fn convert_partitioned_block(agg: &mut Self::PartitionedAggregator) -> Result<Vec<DataBlock>> {
         let mut data_blocks = Vec::with_capacity(256);
 
-        fn clear_table<T: HashtableLike<Value = usize>>(table: &mut T, params: &AggregatorParams) {
-            let aggregate_functions = &params.aggregate_functions;
-            let offsets_aggregate_states = &params.offsets_aggregate_states;
-
-            let functions = aggregate_functions
-                .iter()
-                .filter(|p| p.need_manual_drop_state())
-                .collect::<Vec<_>>();
-
-            let states = offsets_aggregate_states
-                .iter()
-                .enumerate()
-                .filter(|(idx, _)| aggregate_functions[*idx].need_manual_drop_state())
-                .map(|(_, s)| *s)
-                .collect::<Vec<_>>();
-
-            if !states.is_empty() {
-                for group_entity in table.iter() {
-                    let place = Into::<StateAddr>::into(*group_entity.get());
-
-                    for (function, state_offset) in functions.iter().zip(states.iter()) {
-                        unsafe { function.drop_state(place.next(*state_offset)) }
-                    }
+        for (bucket, inner_table) in agg.hash_table.iter_tables_mut().enumerate() {
+            // Restore the spilled states of this bucket, they are merged with the
+            // in-memory states by the final aggregator.
+            for spilled_file in agg.spilled_files.iter_mut() {
+                if let Some(block) = restore_bucket(spilled_file, bucket)? {
+                    let meta = AggregateInfo::create(bucket as isize);
+                    data_blocks.push(block.add_meta(Some(meta))?);
                 }
             }
 
-            table.clear();
-        }
-
-        for (bucket, inner_table) in agg.hash_table.iter_tables_mut().enumerate() {
             if inner_table.len() == 0 {
-                continue;
+                if data_blocks.is_empty() {
+                    continue;
+                }
+
+                return Ok(data_blocks);
             }
 
             if agg.pass_state_to_final {
@@ -173,85 +174,163 @@ impl<Method: HashMethodBounds, const HAS_AGG: bool> PartitionedAggregatorLike
                     agg.area_holder.clone(),
                 );
 
-                let block = DataBlock::new_with_meta(vec![], rows, Some(meta));
-                return Ok(vec![block]);
+                data_blocks.push(DataBlock::new_with_meta(vec![], rows, Some(meta)));
+                return Ok(data_blocks);
             }
 
-            let capacity = inner_table.len();
-            let iterator = inner_table.iter();
+            let block = serialize_table(&agg.method.method, &agg.params, inner_table, bucket)?;
+            data_blocks.push(block);
 
-            let aggregator_params = agg.params.as_ref();
-            let funcs = &aggregator_params.aggregate_functions;
-            let aggr_len = funcs.len();
-            let offsets_aggregate_states = &aggregator_params.offsets_aggregate_states;
+            clear_table(inner_table, &agg.params);
 
-            // Builders.
-            let mut state_builders: Vec<StringColumnBuilder> = (0..aggr_len)
-                .map(|_| StringColumnBuilder::with_capacity(capacity, capacity * 4))
-                .collect();
+            // streaming return Partitioned blocks by bucket
+            return Ok(data_blocks);
+        }
 
-            let value_size = estimated_key_size(inner_table);
-            let mut group_key_builder = agg.method.keys_column_builder(capacity, value_size);
+        if !agg.pass_state_to_final {
+            drop(agg.area.take());
+            drop(agg.area_holder.take());
+        }
 
-            for group_entity in iterator {
-                let place = Into::<StateAddr>::into(*group_entity.get());
+        for spilled_file in agg.spilled_files.drain(..) {
+            remove_spilled_file(&spilled_file);
+        }
 
-                for (idx, func) in funcs.iter().enumerate() {
-                    let arg_place = place.next(offsets_aggregate_states[idx]);
-                    func.serialize(arg_place, &mut state_builders[idx].data)?;
-                    state_builders[idx].commit_row();
-                }
+        Ok(data_blocks)
+    }
 
-                group_key_builder.append_value(group_entity.key());
-            }
+    fn get_partitioned_state_bytes(agg: &Self::PartitionedAggregator) -> usize {
+        let arena_bytes = agg.area.as_ref().map_or(0, |area| area.allocated_bytes());
+        agg.hash_table.bytes_len() + arena_bytes
+    }
 
-            let mut columns = Vec::with_capacity(state_builders.len() + 1);
-            for builder in state_builders.into_iter() {
-                let col = builder.build();
-                columns.push(BlockEntry {
-                    value: Value::Column(Column::String(col)),
-                    data_type: DataType::String,
-                });
+    fn spill_partitioned(agg: &mut Self::PartitionedAggregator, spill_dir: &Path) -> Result<()> {
+        let mut blocks = vec![];
+        for (bucket, inner_table) in agg.hash_table.iter_tables_mut().enumerate() {
+            if inner_table.len() == 0 {
+                continue;
             }
 
-            let col = group_key_builder.finish();
-            let num_rows = col.len();
-            let group_key_type = col.data_type();
+            let block = serialize_table(&agg.method.method, &agg.params, inner_table, bucket)?;
+            blocks.push((bucket, block));
+            clear_table(inner_table, &agg.params);
+            // a cleared table keeps its capacity, which counts in the state bytes, so it is
+            // replaced by a new one to not spill again for every following block.
+            *inner_table = agg.method.method.create_hash_table()?;
+        }
 
-            columns.push(BlockEntry {
-                value: Value::Column(col),
-                data_type: group_key_type,
-            });
+        if blocks.is_empty() {
+            return Ok(());
+        }
 
-            data_blocks.push(DataBlock::new_with_meta(
-                columns,
-                num_rows,
-                Some(AggregateInfo::create(bucket as isize)),
-            ));
+        agg.spilled_files.push(spill_buckets(spill_dir, blocks)?);
+        // All the states are serialized, so the arena can be released.
+        agg.area = Some(Area::create());
+        Ok(())
+    }
+}
 
-            clear_table(inner_table, &agg.params);
+fn clear_table<T: HashtableLike<Value = usize>>(table: &mut T, params: &AggregatorParams) {
+    let aggregate_functions = &params.aggregate_functions;
+    let offsets_aggregate_states = &params.offsets_aggregate_states;
 
-            // streaming return Partitioned blocks by bucket
-            return Ok(data_blocks);
+    let functions = aggregate_functions
+        .iter()
+        .filter(|p| p.need_manual_drop_state())
+        .collect::<Vec<_>>();
+
+    let states = offsets_aggregate_states
+        .iter()
+        .enumerate()
+        .filter(|(idx, _)| aggregate_functions[*idx].need_manual_drop_state())
+        .map(|(_, s)| *s)
+        .collect::<Vec<_>>();
+
+    if !states.is_empty() {
+        for group_entity in table.iter() {
+            let place = Into::<StateAddr>::into(*group_entity.get());
+
+            for (function, state_offset) in functions.iter().zip(states.iter()) {
+                unsafe { function.drop_state(place.next(*state_offset)) }
+            }
         }
+    }
 
-        if !agg.pass_state_to_final {
-            drop(agg.area.take());
-            drop(agg.area_holder.take());
+    table.clear();
+}
+
+/// Serialize the aggregation states and group keys of a bucket into a block.
+fn serialize_table<'a, Method: HashMethodBounds>(
+    method: &'a Method,
+    params: &AggregatorParams,
+    table: &'a Method::HashTable<usize>,
+    bucket: usize,
+) -> Result<DataBlock> {
+    let capacity = table.len();
+    let funcs = &params.aggregate_functions;
+    let aggr_len = funcs.len();
+    let offsets_aggregate_states = &params.offsets_aggregate_states;
+
+    // Builders.
+    let mut state_builders: Vec<StringColumnBuilder> = (0..aggr_len)
+        .map(|_| StringColumnBuilder::with_capacity(capacity, capacity * 4))
+        .collect();
+
+    let value_size = estimated_key_size(table);
+    let mut group_key_builder = method.keys_column_builder(capacity, value_size);
+
+    for group_entity in table.iter() {
+        let place = Into::<StateAddr>::into(*group_entity.get());
+
+        for (idx, func) in funcs.iter().enumerate() {
+            let arg_place = place.next(offsets_aggregate_states[idx]);
+            func.serialize(arg_place, &mut state_builders[idx].data)?;
+            state_builders[idx].commit_row();
         }
 
-        Ok(data_blocks)
+        group_key_builder.append_value(group_entity.key());
+    }
+
+    let mut columns = Vec::with_capacity(state_builders.len() + 1);
+    for builder in state_builders.into_iter() {
+        let col = builder.build();
+        columns.push(BlockEntry {
+            value: Value::Column(Column::String(col)),
+            data_type: DataType::String,
+        });
     }
+
+    let col = group_key_builder.finish();
+    let num_rows = col.len();
+    let group_key_type = col.data_type();
+
+    columns.push(BlockEntry {
+        value: Value::Column(col),
+        data_type: group_key_type,
+    });
+
+    Ok(DataBlock::new_with_meta(
+        columns,
+        num_rows,
+        Some(AggregateInfo::create(bucket as isize)),
+    ))
 }
 
-// Example: PartitionedAggregator<PartialAggregator<HAS_AGG, Method>> ->
-//      PartitionedAggregator {
-//          inner: PartialAggregator<HAS_AGG, PartitionedMethod<Method>>
-//      }
 pub struct PartitionedAggregator<T: PartitionedAggregatorLike> {
     inner: T::PartitionedAggregator,
 }
 
+impl<T: PartitionedAggregatorLike> PartitionedAggregator<T> {
+    pub fn get_state_bytes(&self) -> usize {
+        T::get_partitioned_state_bytes(&self.inner)
+    }
+
+    /// Spill the states of all the buckets into a temporary file under `spill_dir`.
+    pub fn spill(&mut self, spill_dir: &Path) -> Result<()> {
+        T::spill_partitioned(&mut self.inner, spill_dir)
+    }
+}
+
 impl<T: PartitionedAggregatorLike> Aggregator for PartitionedAggregator<T> {
     const NAME: &'static str = "PartitionedAggregator";
 
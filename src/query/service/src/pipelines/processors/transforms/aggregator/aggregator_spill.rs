@@ -0,0 +1,66 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_pipeline_transforms::processors::transforms::read_spilled_block;
+use common_pipeline_transforms::processors::transforms::SpillWriter;
+use tracing::info;
+
+use crate::pipelines::processors::transforms::aggregator::OverflowInfo;
+
+/// Spill the serialized partial aggregation states of some buckets into a temporary file
+/// under `spill_dir`, one block per bucket.
+pub fn spill_buckets(spill_dir: &Path, blocks: Vec<(usize, DataBlock)>) -> Result<OverflowInfo> {
+    let mut writer = SpillWriter::create(spill_dir.join(uuid::Uuid::new_v4().to_string()));
+    let mut bucket_info = HashMap::with_capacity(blocks.len());
+    for (bucket, block) in blocks {
+        let (offset, length) = writer.append(&block)?;
+        bucket_info.insert(bucket, (offset as usize, length));
+    }
+
+    info!(
+        "Spilled {} bytes of aggregation states to {:?}",
+        writer.size(),
+        writer.path()
+    );
+    Ok(OverflowInfo {
+        temporary_path: writer.path().to_string_lossy().to_string(),
+        bucket_info,
+    })
+}
+
+/// Read back the spilled block of the bucket, each bucket can only be restored once.
+pub fn restore_bucket(info: &mut OverflowInfo, bucket: usize) -> Result<Option<DataBlock>> {
+    let (offset, length) = match info.bucket_info.remove(&bucket) {
+        None => return Ok(None),
+        Some(position) => position,
+    };
+
+    let path = Path::new(&info.temporary_path);
+    Ok(Some(read_spilled_block(path, offset as u64, length)?))
+}
+
+/// Remove the temporary file of the spilled buckets.
+pub fn remove_spilled_file(info: &OverflowInfo) {
+    if let Err(cause) = std::fs::remove_file(&info.temporary_path) {
+        info!(
+            "Cannot remove spilled file {}: {:?}",
+            info.temporary_path, cause
+        );
+    }
+}
@@ -20,6 +20,7 @@ mod aggregator_params;
 mod aggregator_partial;
 mod aggregator_partitioned;
 mod aggregator_single_key;
+mod aggregator_spill;
 mod utils;
 
 pub use aggregate_exchange_sorting::AggregateExchangeSorting;
@@ -34,4 +35,5 @@ pub use aggregator_partitioned::PartitionedAggregator;
 pub use aggregator_partitioned::PartitionedAggregatorLike;
 pub use aggregator_single_key::FinalSingleStateAggregator;
 pub use aggregator_single_key::PartialSingleStateAggregator;
+pub use aggregator_spill::*;
 pub use utils::*;
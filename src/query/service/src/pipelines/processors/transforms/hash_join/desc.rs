@@ -20,6 +20,7 @@ use common_expression::Expr;
 use common_expression::RemoteExpr;
 use common_functions::scalars::BUILTIN_FUNCTIONS;
 use common_sql::executor::HashJoin;
+use common_sql::executor::RuntimeFilterTarget;
 use parking_lot::RwLock;
 
 use crate::pipelines::processors::transforms::hash_join::row::RowPtr;
@@ -68,6 +69,9 @@ pub struct HashJoinDesc {
     /// Whether the Join are derived from correlated subquery.
     pub(crate) from_correlated_subquery: bool,
     pub(crate) join_state: JoinState,
+    /// The `plan_id` of the join.
+    pub(crate) join_id: u32,
+    pub(crate) runtime_filter_targets: Vec<RuntimeFilterTarget>,
 }
 
 impl HashJoinDesc {
@@ -96,6 +100,36 @@ impl HashJoinDesc {
             },
             from_correlated_subquery: join.from_correlated_subquery,
             join_state: JoinState::create()?,
+            join_id: join.plan_id,
+            runtime_filter_targets: join.runtime_filter_targets.clone(),
+        })
+    }
+
+    /// Create the description of an inner join which is not planned by the optimizer, e.g.
+    /// the join of the source and the target of MERGE INTO.
+    pub fn create_inner_join(
+        build_keys: &[RemoteExpr],
+        probe_keys: &[RemoteExpr],
+        non_equi_conditions: &[RemoteExpr],
+    ) -> Result<HashJoinDesc> {
+        let as_exprs = |keys: &[RemoteExpr]| {
+            keys.iter()
+                .map(|k| k.as_expr(&BUILTIN_FUNCTIONS))
+                .collect::<Vec<_>>()
+        };
+
+        Ok(HashJoinDesc {
+            join_type: JoinType::Inner,
+            build_keys: as_exprs(build_keys),
+            probe_keys: as_exprs(probe_keys),
+            other_predicate: Self::join_predicate(non_equi_conditions)?,
+            marker_join_desc: MarkJoinDesc {
+                has_null: RwLock::new(false),
+            },
+            from_correlated_subquery: false,
+            join_state: JoinState::create()?,
+            join_id: 0,
+            runtime_filter_targets: vec![],
         })
     }
 
@@ -45,7 +45,7 @@ pub trait HashJoinState: Send + Sync {
     async fn wait_finish(&self) -> Result<()>;
 
     /// Get mark join results
-    fn mark_join_blocks(&self) -> Result<Vec<DataBlock>>;
+    fn mark_join_blocks(&self, blocks: &[DataBlock]) -> Result<Vec<DataBlock>>;
 
     /// Get right join results
     fn right_join_blocks(&self, blocks: &[DataBlock]) -> Result<Vec<DataBlock>>;
@@ -55,4 +55,23 @@ pub trait HashJoinState: Send + Sync {
 
     /// Get left join results
     fn left_join_blocks(&self, blocks: &[DataBlock]) -> Result<Vec<DataBlock>>;
+
+    /// Is the build side spilled to disk, then the probe side should be spilled as well.
+    fn is_spilled(&self) -> bool;
+
+    /// Scatter the probe block into the spilled partitions
+    fn spill_probe(&self, input: &DataBlock) -> Result<()>;
+
+    /// Attach a probe processor to the state
+    fn probe_attach(&self);
+
+    /// Detach a probe processor from the state, the spilled partitions can be joined after
+    /// all the probe processors have been detached.
+    fn probe_detach(&self);
+
+    /// Wait until all the probe processors are detached
+    async fn wait_probe_finish(&self) -> Result<()>;
+
+    /// Join the next spilled partition, return `None` if all the partitions are joined.
+    fn join_spilled_partition(&self) -> Result<Option<Vec<DataBlock>>>;
 }
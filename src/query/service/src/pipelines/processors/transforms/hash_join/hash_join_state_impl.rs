@@ -25,6 +25,7 @@ use common_hashtable::HashtableLike;
 
 use super::ProbeState;
 use crate::pipelines::processors::transforms::hash_join::desc::MarkerKind;
+use crate::pipelines::processors::transforms::hash_join::join_spill::JoinSpillState;
 use crate::pipelines::processors::transforms::hash_join::row::RowPtr;
 use crate::pipelines::processors::HashJoinState;
 use crate::pipelines::processors::HashTable;
@@ -35,6 +36,10 @@ use crate::sql::planner::plans::JoinType;
 #[async_trait::async_trait]
 impl HashJoinState for JoinHashTable {
     fn build(&self, input: DataBlock) -> Result<()> {
+        if let Some(spill_state) = &self.spill_state {
+            return self.build_with_spilling(spill_state, input);
+        }
+
         let data_block_size_limit = self.ctx.get_settings().get_max_block_size()? * 16;
         let mut buffer = self.row_space.buffer.write().unwrap();
         buffer.push(input);
@@ -81,6 +86,7 @@ impl HashJoinState for JoinHashTable {
         *count -= 1;
         if *count == 0 {
             self.finish()?;
+            self.push_runtime_filters()?;
             let mut is_finished = self.is_finished.lock().unwrap();
             *is_finished = true;
             self.finished_notify.notify_waiters();
@@ -149,6 +155,16 @@ impl HashJoinState for JoinHashTable {
             }};
         }
 
+        if let Some(spill_state) = &self.spill_state {
+            if spill_state.is_spilled() {
+                let mut buffer = self.row_space.buffer.write().unwrap();
+                for block in buffer.drain(..) {
+                    spill_state.spill_build_block(&block)?;
+                }
+                return Ok(());
+            }
+        }
+
         {
             let buffer = self.row_space.buffer.write().unwrap();
             if !buffer.is_empty() {
@@ -232,7 +248,11 @@ impl HashJoinState for JoinHashTable {
         Ok(())
     }
 
-    fn mark_join_blocks(&self) -> Result<Vec<DataBlock>> {
+    fn mark_join_blocks(&self, blocks: &[DataBlock]) -> Result<Vec<DataBlock>> {
+        if self.is_spilled() {
+            return Ok(blocks.to_vec());
+        }
+
         let row_ptrs = self.row_ptrs.read();
         let has_null = self.hash_join_desc.marker_join_desc.has_null.read();
 
@@ -243,6 +263,11 @@ impl HashJoinState for JoinHashTable {
     }
 
     fn right_join_blocks(&self, blocks: &[DataBlock]) -> Result<Vec<DataBlock>> {
+        // The spilled partitions are joined completely before.
+        if self.is_spilled() {
+            return Ok(blocks.to_vec());
+        }
+
         let mut row_state = self.row_state_for_right_join()?;
         let unmatched_build_indexes = self.find_unmatched_build_indexes(&row_state)?;
 
@@ -314,6 +339,10 @@ impl HashJoinState for JoinHashTable {
     }
 
     fn right_semi_join_blocks(&self, blocks: &[DataBlock]) -> Result<Vec<DataBlock>> {
+        if self.is_spilled() {
+            return Ok(blocks.to_vec());
+        }
+
         let mut row_state = self.row_state_for_right_join()?;
         let unmatched_build_indexes = self.find_unmatched_build_indexes(&row_state)?;
         let unmatched_build_block = self.row_space.gather(&unmatched_build_indexes)?;
@@ -407,6 +436,9 @@ impl HashJoinState for JoinHashTable {
     fn left_join_blocks(&self, blocks: &[DataBlock]) -> Result<Vec<DataBlock>> {
         // Get rest blocks
         let mut input_blocks = blocks.to_vec();
+        if self.is_spilled() {
+            return Ok(input_blocks);
+        }
         let rest_block = self.rest_block()?;
         if rest_block.is_empty() {
             return Ok(input_blocks);
@@ -414,9 +446,67 @@ impl HashJoinState for JoinHashTable {
         input_blocks.push(rest_block);
         Ok(input_blocks)
     }
+
+    fn is_spilled(&self) -> bool {
+        matches!(&self.spill_state, Some(spill_state) if spill_state.is_spilled())
+    }
+
+    fn spill_probe(&self, input: &DataBlock) -> Result<()> {
+        match &self.spill_state {
+            Some(spill_state) => spill_state.spill_probe_block(input),
+            None => Err(ErrorCode::Internal(
+                "Cannot spill the probe side of a join without spill state",
+            )),
+        }
+    }
+
+    fn probe_attach(&self) {
+        if let Some(spill_state) = &self.spill_state {
+            spill_state.probe_attach();
+        }
+    }
+
+    fn probe_detach(&self) {
+        if let Some(spill_state) = &self.spill_state {
+            spill_state.probe_detach();
+        }
+    }
+
+    async fn wait_probe_finish(&self) -> Result<()> {
+        match &self.spill_state {
+            Some(spill_state) => spill_state.wait_probe_finish().await,
+            None => Ok(()),
+        }
+    }
+
+    fn join_spilled_partition(&self) -> Result<Option<Vec<DataBlock>>> {
+        match &self.spill_state {
+            Some(spill_state) if spill_state.is_spilled() => spill_state.join_next_partition(),
+            _ => Ok(None),
+        }
+    }
 }
 
 impl JoinHashTable {
+    /// Buffer the raw build blocks, and scatter all of them into the spilled partitions once
+    /// the buffered bytes exceed the spilling threshold.
+    fn build_with_spilling(&self, spill_state: &JoinSpillState, input: DataBlock) -> Result<()> {
+        if spill_state.is_spilled() {
+            return spill_state.spill_build_block(&input);
+        }
+
+        let bytes = input.memory_size();
+        let mut buffer = self.row_space.buffer.write().unwrap();
+        buffer.push(input);
+        if spill_state.add_build_bytes(bytes) && !spill_state.is_spilled() {
+            spill_state.set_spilled();
+            for block in buffer.drain(..) {
+                spill_state.spill_build_block(&block)?;
+            }
+        }
+        Ok(())
+    }
+
     pub(crate) fn filter_rows_for_right_join(
         &self,
         bm: &mut MutableBitmap,
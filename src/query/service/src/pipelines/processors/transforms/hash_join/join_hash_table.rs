@@ -43,6 +43,7 @@ use primitive_types::U512;
 
 use super::ProbeState;
 use crate::pipelines::processors::transforms::hash_join::desc::HashJoinDesc;
+use crate::pipelines::processors::transforms::hash_join::join_spill::JoinSpillState;
 use crate::pipelines::processors::transforms::hash_join::row::RowPtr;
 use crate::pipelines::processors::transforms::hash_join::row::RowSpace;
 use crate::pipelines::processors::transforms::hash_join::util::build_schema_wrap_nullable;
@@ -90,6 +91,8 @@ pub struct JoinHashTable {
     pub(crate) probe_schema: DataSchemaRef,
     pub(crate) interrupt: Arc<AtomicBool>,
     pub(crate) finished_notify: Arc<Notify>,
+    /// The state of grace hash join, `None` if spilling is disabled.
+    pub(crate) spill_state: Option<JoinSpillState>,
 }
 
 impl JoinHashTable {
@@ -99,6 +102,7 @@ impl JoinHashTable {
         build_schema: DataSchemaRef,
         probe_schema: DataSchemaRef,
         hash_join_desc: HashJoinDesc,
+        spill_state: Option<JoinSpillState>,
     ) -> Result<Arc<JoinHashTable>> {
         let hash_key_types = build_keys
             .iter()
@@ -115,6 +119,7 @@ impl JoinHashTable {
                 build_schema,
                 probe_schema,
                 hash_join_desc,
+                spill_state,
             )?),
             HashMethodKind::SingleString(_) => Arc::new(JoinHashTable::try_create(
                 ctx,
@@ -125,6 +130,7 @@ impl JoinHashTable {
                 build_schema,
                 probe_schema,
                 hash_join_desc,
+                spill_state,
             )?),
             HashMethodKind::KeysU8(hash_method) => Arc::new(JoinHashTable::try_create(
                 ctx,
@@ -135,6 +141,7 @@ impl JoinHashTable {
                 build_schema,
                 probe_schema,
                 hash_join_desc,
+                spill_state,
             )?),
             HashMethodKind::KeysU16(hash_method) => Arc::new(JoinHashTable::try_create(
                 ctx,
@@ -145,6 +152,7 @@ impl JoinHashTable {
                 build_schema,
                 probe_schema,
                 hash_join_desc,
+                spill_state,
             )?),
             HashMethodKind::KeysU32(hash_method) => Arc::new(JoinHashTable::try_create(
                 ctx,
@@ -155,6 +163,7 @@ impl JoinHashTable {
                 build_schema,
                 probe_schema,
                 hash_join_desc,
+                spill_state,
             )?),
             HashMethodKind::KeysU64(hash_method) => Arc::new(JoinHashTable::try_create(
                 ctx,
@@ -165,6 +174,7 @@ impl JoinHashTable {
                 build_schema,
                 probe_schema,
                 hash_join_desc,
+                spill_state,
             )?),
             HashMethodKind::KeysU128(hash_method) => Arc::new(JoinHashTable::try_create(
                 ctx,
@@ -175,6 +185,7 @@ impl JoinHashTable {
                 build_schema,
                 probe_schema,
                 hash_join_desc,
+                spill_state,
             )?),
             HashMethodKind::KeysU256(hash_method) => Arc::new(JoinHashTable::try_create(
                 ctx,
@@ -185,6 +196,7 @@ impl JoinHashTable {
                 build_schema,
                 probe_schema,
                 hash_join_desc,
+                spill_state,
             )?),
             HashMethodKind::KeysU512(hash_method) => Arc::new(JoinHashTable::try_create(
                 ctx,
@@ -195,6 +207,7 @@ impl JoinHashTable {
                 build_schema,
                 probe_schema,
                 hash_join_desc,
+                spill_state,
             )?),
         })
     }
@@ -205,6 +218,7 @@ impl JoinHashTable {
         mut build_data_schema: DataSchemaRef,
         mut probe_data_schema: DataSchemaRef,
         hash_join_desc: HashJoinDesc,
+        spill_state: Option<JoinSpillState>,
     ) -> Result<Self> {
         if hash_join_desc.join_type == JoinType::Left
             || hash_join_desc.join_type == JoinType::Single
@@ -229,6 +243,7 @@ impl JoinHashTable {
             probe_schema: probe_data_schema,
             finished_notify: Arc::new(Notify::new()),
             interrupt: Arc::new(AtomicBool::new(false)),
+            spill_state,
         })
     }
 
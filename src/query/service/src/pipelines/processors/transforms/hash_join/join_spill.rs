@@ -0,0 +1,303 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use common_base::base::tokio::sync::Notify;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_functions::scalars::BUILTIN_FUNCTIONS;
+use common_pipeline_transforms::processors::transforms::SpilledBlocks;
+use common_sql::executor::HashJoin;
+use tracing::info;
+
+use crate::api::FlightScatter;
+use crate::api::HashFlightScatter;
+use crate::pipelines::processors::transforms::hash_join::HashJoinDesc;
+use crate::pipelines::processors::transforms::hash_join::HashJoinState;
+use crate::pipelines::processors::transforms::hash_join::JoinHashTable;
+use crate::pipelines::processors::transforms::hash_join::ProbeState;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::plans::JoinType;
+
+/// The number of partitions the build side and the probe side are scattered into once spilled.
+pub const JOIN_SPILL_PARTITIONS: usize = 32;
+
+/// The state of grace hash join.
+///
+/// Once the build side exceeds `spilling_bytes_threshold`, both sides of the join are scattered
+/// into `JOIN_SPILL_PARTITIONS` partitions by the hash of the join keys and written to disk. After
+/// all the probe processors finished scattering, the partitions are joined one by one, each with
+/// its own in-memory hash table.
+pub struct JoinSpillState {
+    ctx: Arc<QueryContext>,
+    join: HashJoin,
+    spilling_bytes_threshold: usize,
+
+    build_scatter: Box<dyn FlightScatter>,
+    probe_scatter: Box<dyn FlightScatter>,
+    build_partitions: Vec<Mutex<SpilledBlocks>>,
+    probe_partitions: Vec<Mutex<SpilledBlocks>>,
+
+    is_spilled: AtomicBool,
+    build_bytes: AtomicUsize,
+    /// Whether the first build key has null, it's required by right mark join.
+    build_has_null: AtomicBool,
+    /// Whether the first probe column has null, it's required by left mark join.
+    probe_has_null: AtomicBool,
+
+    probe_ref_count: Mutex<usize>,
+    probe_finished: Mutex<bool>,
+    probe_finished_notify: Notify,
+    next_partition: AtomicUsize,
+}
+
+impl JoinSpillState {
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        join: &HashJoin,
+        spilling_bytes_threshold: usize,
+    ) -> Result<Self> {
+        let func_ctx = ctx.get_function_context()?;
+        let build_scatter = HashFlightScatter::try_create(
+            func_ctx,
+            join.build_keys.clone(),
+            JOIN_SPILL_PARTITIONS,
+        )?;
+        let probe_scatter = HashFlightScatter::try_create(
+            func_ctx,
+            join.probe_keys.clone(),
+            JOIN_SPILL_PARTITIONS,
+        )?;
+
+        let spill_dir = ctx.get_spill_dir();
+        let create_partitions = || {
+            (0..JOIN_SPILL_PARTITIONS)
+                .map(|_| {
+                    let file = uuid::Uuid::new_v4().to_string();
+                    Mutex::new(SpilledBlocks::create(spill_dir.join(file)))
+                })
+                .collect::<Vec<_>>()
+        };
+
+        Ok(JoinSpillState {
+            ctx,
+            join: join.clone(),
+            spilling_bytes_threshold,
+            build_scatter,
+            probe_scatter,
+            build_partitions: create_partitions(),
+            probe_partitions: create_partitions(),
+            is_spilled: AtomicBool::new(false),
+            build_bytes: AtomicUsize::new(0),
+            build_has_null: AtomicBool::new(false),
+            probe_has_null: AtomicBool::new(false),
+            probe_ref_count: Mutex::new(0),
+            probe_finished: Mutex::new(false),
+            probe_finished_notify: Notify::new(),
+            next_partition: AtomicUsize::new(0),
+        })
+    }
+
+    pub fn is_spilled(&self) -> bool {
+        self.is_spilled.load(Ordering::Acquire)
+    }
+
+    pub fn set_spilled(&self) {
+        self.is_spilled.store(true, Ordering::Release);
+    }
+
+    /// Account the bytes of the build side kept in memory, returns whether the build side
+    /// should be spilled.
+    pub fn add_build_bytes(&self, bytes: usize) -> bool {
+        let total = self.build_bytes.fetch_add(bytes, Ordering::Relaxed) + bytes;
+        total >= self.spilling_bytes_threshold
+    }
+
+    pub fn spill_build_block(&self, block: &DataBlock) -> Result<()> {
+        if block.is_empty() {
+            return Ok(());
+        }
+
+        let block = block.convert_to_full();
+        if self.join.join_type == JoinType::RightMark
+            && !self.build_has_null.load(Ordering::Relaxed)
+        {
+            let func_ctx = self.ctx.get_function_context()?;
+            let evaluator = Evaluator::new(&block, func_ctx, &BUILTIN_FUNCTIONS);
+            let expr = self.join.build_keys[0].as_expr(&BUILTIN_FUNCTIONS);
+            let column = evaluator
+                .run(&expr)?
+                .convert_to_full_column(expr.data_type(), block.num_rows());
+            if matches!(column.validity().1, Some(x) if x.unset_bits() > 0) {
+                self.build_has_null.store(true, Ordering::Relaxed);
+            }
+        }
+
+        Self::scatter(&*self.build_scatter, &self.build_partitions, &block)
+    }
+
+    pub fn spill_probe_block(&self, block: &DataBlock) -> Result<()> {
+        if block.is_empty() {
+            return Ok(());
+        }
+
+        if self.join.join_type == JoinType::LeftMark {
+            let probe_column = block.get_by_offset(0).value.as_column().unwrap();
+            if matches!(probe_column.validity().1, Some(x) if x.unset_bits() > 0) {
+                self.probe_has_null.store(true, Ordering::Relaxed);
+            }
+        }
+
+        Self::scatter(&*self.probe_scatter, &self.probe_partitions, block)
+    }
+
+    fn scatter(
+        scatter: &dyn FlightScatter,
+        partitions: &[Mutex<SpilledBlocks>],
+        block: &DataBlock,
+    ) -> Result<()> {
+        for (partition, block) in scatter.execute(block)?.into_iter().enumerate() {
+            if !block.is_empty() {
+                partitions[partition].lock().unwrap().append(&block)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn restore(partition: &SpilledBlocks) -> Result<Vec<DataBlock>> {
+        (0..partition.num_blocks())
+            .map(|index| partition.read(index))
+            .collect()
+    }
+
+    pub fn probe_attach(&self) {
+        let mut count = self.probe_ref_count.lock().unwrap();
+        *count += 1;
+    }
+
+    pub fn probe_detach(&self) {
+        let mut count = self.probe_ref_count.lock().unwrap();
+        *count -= 1;
+        if *count == 0 {
+            *self.probe_finished.lock().unwrap() = true;
+            self.probe_finished_notify.notify_waiters();
+        }
+    }
+
+    /// Wait until all the probe processors finished scattering the probe side.
+    pub async fn wait_probe_finish(&self) -> Result<()> {
+        let notified = self.probe_finished_notify.notified();
+        if !*self.probe_finished.lock().unwrap() {
+            notified.await;
+        }
+        Ok(())
+    }
+
+    /// Take the next spilled partition and join it, returns `None` if all the partitions are joined.
+    pub fn join_next_partition(&self) -> Result<Option<Vec<DataBlock>>> {
+        loop {
+            let partition = self.next_partition.fetch_add(1, Ordering::Relaxed);
+            if partition >= JOIN_SPILL_PARTITIONS {
+                return Ok(None);
+            }
+
+            let build_partition = self.build_partitions[partition].lock().unwrap();
+            let probe_partition = self.probe_partitions[partition].lock().unwrap();
+            if build_partition.is_empty() && probe_partition.is_empty() {
+                continue;
+            }
+
+            let build_blocks = Self::restore(&build_partition)?;
+            let probe_blocks = Self::restore(&probe_partition)?;
+            drop(build_partition);
+            drop(probe_partition);
+            info!(
+                "Join spilled partition {} with {} build blocks and {} probe blocks",
+                partition,
+                build_blocks.len(),
+                probe_blocks.len()
+            );
+            return self.join_partition(build_blocks, probe_blocks).map(Some);
+        }
+    }
+
+    fn join_partition(
+        &self,
+        build_blocks: Vec<DataBlock>,
+        probe_blocks: Vec<DataBlock>,
+    ) -> Result<Vec<DataBlock>> {
+        let join = &self.join;
+        let table = JoinHashTable::create_join_state(
+            self.ctx.clone(),
+            &join.build_keys,
+            join.build.output_schema()?,
+            join.probe.output_schema()?,
+            HashJoinDesc::create(join)?,
+            None,
+        )?;
+
+        for block in build_blocks {
+            table.build(block)?;
+        }
+        table.finish()?;
+
+        let has_null = &table.hash_join_desc.marker_join_desc.has_null;
+        if join.join_type == JoinType::RightMark && self.build_has_null.load(Ordering::Relaxed) {
+            *has_null.write() = true;
+        }
+
+        let block_size = self.ctx.get_settings().get_max_block_size()? as usize;
+        let mut probe_state = ProbeState::with_capacity(block_size);
+        let mut blocks = vec![];
+        for block in probe_blocks {
+            probe_state.clear();
+            blocks.extend(table.probe(&block, &mut probe_state)?);
+        }
+
+        // A correlated mark join with non-equi conditions doesn't check `has_null` of the probe side.
+        if join.join_type == JoinType::LeftMark
+            && self.probe_has_null.load(Ordering::Relaxed)
+            && !(join.from_correlated_subquery && !join.non_equi_conditions.is_empty())
+        {
+            *has_null.write() = true;
+        }
+
+        // Finish the partition like the transforms following the probe in the pipeline.
+        if matches!(
+            join.join_type,
+            JoinType::Left | JoinType::Full | JoinType::Single
+        ) && join.non_equi_conditions.is_empty()
+        {
+            blocks = table.left_join_blocks(&blocks)?;
+        }
+        if join.join_type == JoinType::LeftMark {
+            blocks = table.mark_join_blocks(&blocks)?;
+        }
+        if matches!(join.join_type, JoinType::Right | JoinType::Full) {
+            blocks = table.right_join_blocks(&blocks)?;
+        }
+        if matches!(join.join_type, JoinType::RightSemi | JoinType::RightAnti) {
+            blocks = table.right_semi_join_blocks(&blocks)?;
+        }
+
+        Ok(blocks.into_iter().filter(|b| !b.is_empty()).collect())
+    }
+}
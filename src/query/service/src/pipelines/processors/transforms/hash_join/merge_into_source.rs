@@ -0,0 +1,274 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_catalog::plan::MergeIntoSource;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::UInt32Type;
+use common_expression::types::UInt64Type;
+use common_expression::types::ValueType;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::DataField;
+use common_expression::DataSchemaRef;
+use common_expression::DataSchemaRefExt;
+use common_expression::FieldIndex;
+use common_expression::FromData;
+use common_expression::RemoteExpr;
+use common_expression::Scalar;
+use common_expression::Value;
+use common_pipeline_core::Pipeline;
+use common_pipeline_sinks::Sinker;
+use parking_lot::Mutex;
+
+use crate::pipelines::processors::processor::ProcessorPtr;
+use crate::pipelines::processors::transforms::hash_join::desc::JOIN_MAX_BLOCK_SIZE;
+use crate::pipelines::processors::transforms::hash_join::HashJoinDesc;
+use crate::pipelines::processors::transforms::hash_join::HashJoinState;
+use crate::pipelines::processors::transforms::hash_join::JoinHashTable;
+use crate::pipelines::processors::transforms::hash_join::ProbeState;
+use crate::pipelines::processors::transforms::transform::Transform;
+use crate::pipelines::processors::transforms::transform::Transformer;
+use crate::pipelines::processors::SinkBuildHashTable;
+use crate::sessions::QueryContext;
+
+/// The source of MERGE INTO, built into the hash table of an inner join whose probe side
+/// is the blocks of the table.
+///
+/// A row number column is appended to both sides: the index of the row in the probed block,
+/// which tells the rows of the block that are matched, and the index of the row in the
+/// source, which records the source rows that are matched.
+pub struct MergeIntoHashTable {
+    join_state: Arc<JoinHashTable>,
+    num_fields: usize,
+    num_source_columns: usize,
+    num_rows: AtomicUsize,
+    /// The source rows that have been matched, it is sized once the hash table is built.
+    matched: Mutex<MutableBitmap>,
+}
+
+impl MergeIntoHashTable {
+    /// `source_join_keys` are evaluated on the source rows, `target_join_keys` on the
+    /// blocks of the table. `join_filters` are evaluated on the joined rows, with the columns
+    /// of the table, the row index in the block, then the columns of the source.
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        source_schema: DataSchemaRef,
+        table_schema: DataSchemaRef,
+        source_join_keys: &[RemoteExpr],
+        target_join_keys: &[RemoteExpr],
+        join_filters: &[RemoteExpr],
+    ) -> Result<Arc<MergeIntoHashTable>> {
+        let with_row_number = |schema: &DataSchemaRef, data_type: NumberDataType| {
+            let mut fields = schema.fields().clone();
+            fields.push(DataField::new("_row_number", DataType::Number(data_type)));
+            DataSchemaRefExt::create(fields)
+        };
+        let join_state = JoinHashTable::create_join_state(
+            ctx,
+            source_join_keys,
+            with_row_number(&source_schema, NumberDataType::UInt64),
+            with_row_number(&table_schema, NumberDataType::UInt32),
+            HashJoinDesc::create_inner_join(source_join_keys, target_join_keys, join_filters)?,
+            None,
+        )?;
+
+        Ok(Arc::new(MergeIntoHashTable {
+            join_state,
+            num_fields: table_schema.num_fields(),
+            num_source_columns: source_schema.num_fields(),
+            num_rows: AtomicUsize::new(0),
+            matched: Mutex::new(MutableBitmap::new()),
+        }))
+    }
+
+    /// Add the sinks building the hash table to the pipeline producing the source rows.
+    pub fn add_build_sink(self: &Arc<Self>, pipeline: &mut Pipeline) -> Result<()> {
+        pipeline.add_transform(|input, output| {
+            Ok(ProcessorPtr::create(Transformer::create(
+                input,
+                output,
+                TransformAddRowNumber {
+                    source: self.clone(),
+                },
+            )))
+        })?;
+        pipeline.add_sink(|input| {
+            Ok(ProcessorPtr::create(Sinker::<SinkBuildHashTable>::create(
+                input,
+                SinkBuildHashTable::try_create(self.join_state.clone())?,
+            )))
+        })
+    }
+
+    /// The values of the given columns of the source rows, the rows having NULL in any
+    /// of the columns are skipped. It is valid once the hash table is built.
+    pub fn source_keys(&self, fields: &[FieldIndex]) -> Vec<Vec<Scalar>> {
+        let mut keys = Vec::with_capacity(self.num_rows());
+        for block in self.join_state.row_space.datablocks() {
+            let columns = fields
+                .iter()
+                .map(|idx| block.get_by_offset(*idx).value.as_ref())
+                .collect::<Vec<_>>();
+            for row in 0..block.num_rows() {
+                let key = columns
+                    .iter()
+                    .map(|column| column.index(row).unwrap().to_owned())
+                    .collect::<Vec<_>>();
+                if key.iter().all(|scalar| !scalar.is_null()) {
+                    keys.push(key);
+                }
+            }
+        }
+        keys
+    }
+
+    /// All the source rows, matched or not. It is valid once the hash table is built.
+    pub fn source_blocks(&self) -> Vec<DataBlock> {
+        self.join_state
+            .row_space
+            .datablocks()
+            .into_iter()
+            .map(|block| {
+                let num_rows = block.num_rows();
+                DataBlock::new(
+                    block.columns()[..self.num_source_columns].to_vec(),
+                    num_rows,
+                )
+            })
+            .collect()
+    }
+}
+
+impl MergeIntoSource for MergeIntoHashTable {
+    fn num_rows(&self) -> usize {
+        self.num_rows.load(Ordering::Relaxed)
+    }
+
+    fn probe(&self, block: &DataBlock) -> Result<(Vec<u32>, DataBlock)> {
+        let mut input = block.clone();
+        input.add_column(BlockEntry {
+            data_type: DataType::Number(NumberDataType::UInt32),
+            value: Value::Column(UInt32Type::from_data(
+                (0..block.num_rows() as u32).collect::<Vec<_>>(),
+            )),
+        });
+        let mut probe_state = ProbeState::with_capacity(JOIN_MAX_BLOCK_SIZE);
+        let probed = self
+            .join_state
+            .probe(&input, &mut probe_state)?
+            .into_iter()
+            .filter(|block| !block.is_empty())
+            .collect::<Vec<_>>();
+        if probed.is_empty() {
+            return Ok((vec![], DataBlock::empty()));
+        }
+
+        // The joined rows are the columns of the table, the row index in the block, the
+        // columns of the source, and the row number of the source.
+        let joined = DataBlock::concat(&probed)?;
+        let num_rows = joined.num_rows();
+        let row_number = |offset: usize, data_type: NumberDataType| {
+            joined
+                .get_by_offset(offset)
+                .value
+                .convert_to_full_column(&DataType::Number(data_type), num_rows)
+        };
+        let target_rows = row_number(self.num_fields, NumberDataType::UInt32);
+        let target_rows = UInt32Type::try_downcast_column(&target_rows).unwrap();
+        let source_rows = row_number(
+            self.num_fields + 1 + self.num_source_columns,
+            NumberDataType::UInt64,
+        );
+        let source_rows = UInt64Type::try_downcast_column(&source_rows).unwrap();
+
+        {
+            let mut matched = self.matched.lock();
+            if matched.is_empty() {
+                matched.extend_constant(self.num_rows(), false);
+            }
+            for row in source_rows.iter() {
+                matched.set(*row as usize, true);
+            }
+        }
+
+        let columns = joined.columns();
+        let pairs = columns[..self.num_fields]
+            .iter()
+            .chain(
+                columns[self.num_fields + 1..self.num_fields + 1 + self.num_source_columns].iter(),
+            )
+            .cloned()
+            .collect::<Vec<_>>();
+        Ok((target_rows.to_vec(), DataBlock::new(pairs, num_rows)))
+    }
+
+    fn unmatched_blocks(&self) -> Result<Vec<DataBlock>> {
+        let matched = self.matched.lock();
+        let is_matched = |row: u64| !matched.is_empty() && matched.get(row as usize);
+
+        let mut blocks = vec![];
+        for block in self.join_state.row_space.datablocks() {
+            let num_rows = block.num_rows();
+            let source_rows = block
+                .get_by_offset(self.num_source_columns)
+                .value
+                .convert_to_full_column(&DataType::Number(NumberDataType::UInt64), num_rows);
+            let source_rows = UInt64Type::try_downcast_column(&source_rows).unwrap();
+            let rows = source_rows
+                .iter()
+                .enumerate()
+                .filter(|(_, row)| !is_matched(**row))
+                .map(|(idx, _)| idx as u32)
+                .collect::<Vec<_>>();
+            if rows.is_empty() {
+                continue;
+            }
+
+            let unmatched = block.take(&rows)?;
+            blocks.push(DataBlock::new(
+                unmatched.columns()[..self.num_source_columns].to_vec(),
+                rows.len(),
+            ));
+        }
+        Ok(blocks)
+    }
+}
+
+/// Appends the row number of the source to the blocks building the hash table.
+struct TransformAddRowNumber {
+    source: Arc<MergeIntoHashTable>,
+}
+
+impl Transform for TransformAddRowNumber {
+    const NAME: &'static str = "TransformAddRowNumber";
+
+    fn transform(&mut self, mut data: DataBlock) -> Result<DataBlock> {
+        let num_rows = data.num_rows();
+        let start = self.source.num_rows.fetch_add(num_rows, Ordering::Relaxed) as u64;
+        data.add_column(BlockEntry {
+            data_type: DataType::Number(NumberDataType::UInt64),
+            value: Value::Column(UInt64Type::from_data(
+                (start..start + num_rows as u64).collect::<Vec<_>>(),
+            )),
+        });
+        Ok(data)
+    }
+}
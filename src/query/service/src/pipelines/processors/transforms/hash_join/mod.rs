@@ -17,10 +17,13 @@ mod desc;
 mod hash_join_state;
 mod hash_join_state_impl;
 mod join_hash_table;
+mod join_spill;
+mod merge_into_source;
 mod probe_join;
 mod probe_state;
 mod result_blocks;
 pub(crate) mod row;
+mod runtime_filter;
 mod util;
 
 pub use desc::HashJoinDesc;
@@ -29,5 +32,7 @@ pub use join_hash_table::FixedKeyHashTable;
 pub use join_hash_table::HashTable;
 pub use join_hash_table::JoinHashTable;
 pub use join_hash_table::SerializerHashTable;
+pub use join_spill::JoinSpillState;
+pub use merge_into_source::MergeIntoHashTable;
 pub use probe_state::ProbeState;
 pub use result_blocks::*;
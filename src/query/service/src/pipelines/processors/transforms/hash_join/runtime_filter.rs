@@ -0,0 +1,136 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_catalog::runtime_filter_info::RuntimeFilter;
+use common_catalog::runtime_filter_info::RuntimeFilterInfo;
+use common_catalog::runtime_filter_info::MAX_RUNTIME_FILTER_INLIST_SIZE;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::UInt64Type;
+use common_expression::types::ValueType;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::FunctionContext;
+use storages_common_index::filters::FilterBuilder;
+use storages_common_index::filters::Xor8Builder;
+use storages_common_index::BloomIndex;
+
+use crate::api::RuntimeFilterPacket;
+use crate::pipelines::processors::HashJoinState;
+use crate::pipelines::processors::JoinHashTable;
+use crate::sessions::TableContext;
+
+impl JoinHashTable {
+    /// Derive the runtime filters from the build keys after the hash table is built,
+    /// and push them down to the probe side table scans.
+    pub(crate) fn push_runtime_filters(&self) -> Result<()> {
+        let targets = &self.hash_join_desc.runtime_filter_targets;
+        // The build side is not in memory if it is spilled.
+        if targets.is_empty() || self.is_spilled() {
+            return Ok(());
+        }
+
+        let func_ctx = self.ctx.get_function_context()?;
+        let chunks = self.row_space.chunks.read().unwrap();
+        for target in targets {
+            let columns = chunks
+                .iter()
+                .map(|chunk| chunk.cols[target.key_index].0.clone())
+                .collect::<Vec<_>>();
+            let data_type = self.hash_join_desc.build_keys[target.key_index].data_type();
+            let filter =
+                build_runtime_filter(func_ctx, target.column_name.clone(), &columns, data_type)?;
+
+            if target.is_global {
+                self.ctx.add_local_runtime_filter(RuntimeFilterPacket {
+                    join_id: self.hash_join_desc.join_id,
+                    scan_id: target.scan_id,
+                    filter,
+                });
+            } else {
+                let mut info = RuntimeFilterInfo::default();
+                info.add(filter);
+                self.ctx.set_runtime_filter((target.scan_id, info));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Build the runtime filter of a build key, the null keys are ignored since they never match.
+fn build_runtime_filter(
+    func_ctx: FunctionContext,
+    column_name: String,
+    columns: &[Column],
+    data_type: &DataType,
+) -> Result<RuntimeFilter> {
+    let data_type = data_type.remove_nullable();
+    let column = match columns.is_empty() {
+        true => ColumnBuilder::with_capacity(&data_type, 0).build(),
+        false => match Column::concat(columns) {
+            Column::Nullable(column) => column.column.filter(&column.validity),
+            Column::Null { .. } => ColumnBuilder::with_capacity(&data_type, 0).build(),
+            column => column,
+        },
+    };
+
+    let mut min_max = None;
+    let mut inlist = Some(HashSet::new());
+    for value in column.iter() {
+        min_max = match min_max {
+            None => Some((value.clone(), value.clone())),
+            Some((min, max)) => Some((min.min(value.clone()), max.max(value.clone()))),
+        };
+        if let Some(values) = &mut inlist {
+            values.insert(value);
+            if values.len() > MAX_RUNTIME_FILTER_INLIST_SIZE {
+                inlist = None;
+            }
+        }
+    }
+    let inlist = inlist.map(|values| {
+        let mut values = values
+            .into_iter()
+            .map(|value| value.to_owned())
+            .collect::<Vec<_>>();
+        values.sort();
+        values
+    });
+
+    let mut blooms = vec![];
+    if column.len() > 0 {
+        let digests = BloomIndex::calculate_column_digest(
+            func_ctx,
+            &column,
+            &data_type,
+            &DataType::Number(NumberDataType::UInt64),
+        )?;
+        let digests = UInt64Type::try_downcast_column(&digests).unwrap();
+        let mut builder = Xor8Builder::create();
+        builder.add_digests(digests.iter());
+        blooms.push(Arc::new(builder.build()?));
+    }
+
+    Ok(RuntimeFilter {
+        column_name,
+        data_type,
+        min_max: min_max.map(|(min, max)| (min.to_owned(), max.to_owned())),
+        inlist,
+        blooms,
+    })
+}
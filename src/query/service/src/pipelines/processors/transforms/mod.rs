@@ -27,10 +27,16 @@ mod transform_mark_join;
 mod profile_wrapper;
 mod transform_add_const_columns;
 mod transform_convert_grouping;
+mod transform_expand_grouping_sets;
+mod transform_materialized_cte;
 mod transform_merge_block;
+mod transform_recursive_cte;
 mod transform_resort_addon;
 mod transform_right_join;
 mod transform_right_semi_anti_join;
+mod transform_srf;
+mod transform_window;
+mod transform_window_scatter;
 
 pub use aggregator::AggregateExchangeSorting;
 pub use aggregator::AggregatorParams;
@@ -46,6 +52,8 @@ pub use hash_join::HashJoinDesc;
 pub use hash_join::HashJoinState;
 pub use hash_join::HashTable;
 pub use hash_join::JoinHashTable;
+pub use hash_join::JoinSpillState;
+pub use hash_join::MergeIntoHashTable;
 pub use hash_join::SerializerHashTable;
 pub use profile_wrapper::ProfileWrapper;
 pub use transform_add_const_columns::TransformAddConstColumns;
@@ -62,6 +70,7 @@ pub use transform_create_sets::TransformCreateSets;
 pub use transform_dummy::create_dummy_item;
 pub use transform_dummy::create_dummy_items;
 pub use transform_dummy::TransformDummy;
+pub use transform_expand_grouping_sets::TransformExpandGroupingSets;
 pub use transform_hash_join::SinkBuildHashTable;
 pub use transform_hash_join::TransformHashJoinProbe;
 pub use transform_left_join::LeftJoinCompactor;
@@ -69,7 +78,13 @@ pub use transform_left_join::TransformLeftJoin;
 pub use transform_limit::TransformLimit;
 pub use transform_mark_join::MarkJoinCompactor;
 pub use transform_mark_join::TransformMarkJoin;
+pub use transform_materialized_cte::CteScanSource;
+pub use transform_materialized_cte::MaterializedCteState;
+pub use transform_materialized_cte::MaterializedCteStates;
 pub use transform_merge_block::TransformMergeBlock;
+pub use transform_recursive_cte::RecursiveCteSource;
+pub use transform_recursive_cte::RecursiveCteState;
+pub use transform_recursive_cte::RecursiveCteStates;
 pub use transform_resort_addon::TransformResortAddOn;
 pub use transform_right_join::RightJoinCompactor;
 pub use transform_right_join::TransformRightJoin;
@@ -77,3 +92,7 @@ pub use transform_right_semi_anti_join::RightSemiAntiJoinCompactor;
 pub use transform_right_semi_anti_join::TransformRightSemiAntiJoin;
 pub use transform_sort_merge::SortMergeCompactor;
 pub use transform_sort_partial::TransformSortPartial;
+pub use transform_srf::TransformSRF;
+pub use transform_window::TransformWindow;
+pub use transform_window::WindowFunctionImpl;
+pub use transform_window_scatter::TransformWindowScatter;
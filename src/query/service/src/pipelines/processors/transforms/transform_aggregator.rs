@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use common_catalog::table_context::TableContext;
@@ -99,12 +100,16 @@ impl<TAggregator: Aggregator + PartitionedAggregatorLike + 'static>
     ) -> Result<Box<dyn Processor>> {
         let settings = ctx.get_settings();
         let two_level_threshold = settings.get_group_by_two_level_threshold()? as usize;
+        let spilling_bytes_threshold = settings.get_aggregate_spilling_bytes_threshold()? as usize;
+        let spill_dir = ctx.get_spill_dir();
 
         let transformer = AggregatorTransform::<TAggregator>::ConsumeData(ConsumeState {
             inner,
             input_port: transform_params.transform_input_port,
             output_port: transform_params.transform_output_port,
             two_level_threshold,
+            spilling_bytes_threshold,
+            spill_dir,
             input_data_block: None,
         });
 
@@ -146,6 +151,8 @@ impl<TAggregator: Aggregator + PartitionedAggregatorLike + 'static>
                     inner: s.inner.convert_partitioned()?,
                     input_port: s.input_port,
                     output_port: s.output_port,
+                    spilling_bytes_threshold: s.spilling_bytes_threshold,
+                    spill_dir: s.spill_dir,
                     input_data_block: None,
                 },
             )),
@@ -194,11 +201,15 @@ impl<TAggregator: Aggregator + PartitionedAggregatorLike + 'static>
         if let AggregatorTransform::ConsumeData(state) = self {
             if TAggregator::SUPPORT_PARTITION {
                 let cardinality = state.inner.get_state_cardinality();
+                let bytes = state.inner.get_state_bytes();
 
                 static TWOL_LEVEL_BYTES_THRESHOLD: usize = 5_000_000;
 
+                // Only the two-level states can be spilled.
                 if cardinality >= state.two_level_threshold
-                    || state.inner.get_state_bytes() >= TWOL_LEVEL_BYTES_THRESHOLD
+                    || bytes >= TWOL_LEVEL_BYTES_THRESHOLD
+                    || (state.spilling_bytes_threshold != 0
+                        && bytes >= state.spilling_bytes_threshold)
                 {
                     let mut temp_state = AggregatorTransform::Finished;
                     std::mem::swap(self, &mut temp_state);
@@ -331,6 +342,8 @@ impl<TAggregator: Aggregator + PartitionedAggregatorLike + 'static>
 struct ConsumeState<TAggregator: Aggregator> {
     inner: TAggregator,
     two_level_threshold: usize,
+    spilling_bytes_threshold: usize,
+    spill_dir: PathBuf,
 
     input_port: Arc<InputPort>,
     output_port: Arc<OutputPort>,
@@ -349,6 +362,9 @@ impl<TAggregator: Aggregator> ConsumeState<TAggregator> {
 
 struct PartitionedConsumeState<TAggregator: Aggregator + PartitionedAggregatorLike> {
     inner: PartitionedAggregator<TAggregator>,
+    /// Spill the states to disk if they take more memory than the threshold, 0 means never spill.
+    spilling_bytes_threshold: usize,
+    spill_dir: PathBuf,
 
     input_port: Arc<InputPort>,
     output_port: Arc<OutputPort>,
@@ -359,6 +375,12 @@ impl<TAggregator: Aggregator + PartitionedAggregatorLike> PartitionedConsumeStat
     pub fn consume(&mut self) -> Result<()> {
         if let Some(input_data) = self.input_data_block.take() {
             self.inner.consume(input_data)?;
+
+            if self.spilling_bytes_threshold != 0
+                && self.inner.get_state_bytes() >= self.spilling_bytes_threshold
+            {
+                self.inner.spill(&self.spill_dir)?;
+            }
         }
 
         Ok(())
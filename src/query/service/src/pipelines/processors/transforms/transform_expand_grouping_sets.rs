@@ -0,0 +1,119 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_exception::Result;
+use common_expression::types::nullable::NullableColumn;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberScalar;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::DataBlock;
+use common_expression::Scalar;
+use common_expression::Value;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::Processor;
+use common_pipeline_transforms::processors::transforms::transform::Transform;
+use common_pipeline_transforms::processors::transforms::transform::Transformer;
+
+/// Expands each input row once per grouping set of `GROUP BY GROUPING SETS`.
+///
+/// The group by columns are turned into nullable in place, and set to NULL if
+/// they are not in the grouping set. The original values are appended as the
+/// duplicated columns, followed by the grouping id.
+pub struct TransformExpandGroupingSets {
+    /// Offsets of the group by columns in the input block.
+    group_bys: Vec<usize>,
+    /// Offsets of the columns to be duplicated.
+    dup_group_items: Vec<usize>,
+    /// Bit `i` of a grouping id is set if the `i`-th group by column
+    /// is not in the grouping set.
+    grouping_ids: Vec<u32>,
+}
+
+impl TransformExpandGroupingSets {
+    pub fn create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        group_bys: Vec<usize>,
+        dup_group_items: Vec<usize>,
+        grouping_ids: Vec<u32>,
+    ) -> Box<dyn Processor> {
+        Transformer::create(input, output, TransformExpandGroupingSets {
+            group_bys,
+            dup_group_items,
+            grouping_ids,
+        })
+    }
+}
+
+impl Transform for TransformExpandGroupingSets {
+    const NAME: &'static str = "TransformExpandGroupingSets";
+
+    fn transform(&mut self, data: DataBlock) -> Result<DataBlock> {
+        let data = data.convert_to_full();
+        let num_rows = data.num_rows();
+
+        let dup_columns = self
+            .dup_group_items
+            .iter()
+            .map(|offset| data.get_by_offset(*offset).clone())
+            .collect::<Vec<_>>();
+
+        let mut blocks = Vec::with_capacity(self.grouping_ids.len());
+        for id in self.grouping_ids.iter() {
+            let mut columns = data.columns().to_vec();
+            for (i, offset) in self.group_bys.iter().enumerate() {
+                let entry = &mut columns[*offset];
+                let data_type = entry.data_type.wrap_nullable();
+                let column = entry.value.as_column().unwrap();
+                let column = if id & (1 << i) != 0 {
+                    ColumnBuilder::repeat(&Scalar::Null.as_ref(), num_rows, &data_type).build()
+                } else if entry.data_type.is_nullable_or_null() {
+                    column.clone()
+                } else {
+                    Column::Nullable(Box::new(NullableColumn {
+                        column: column.clone(),
+                        validity: MutableBitmap::from_len_set(num_rows).into(),
+                    }))
+                };
+                *entry = BlockEntry {
+                    data_type,
+                    value: Value::Column(column),
+                };
+            }
+            columns.extend(dup_columns.iter().cloned());
+
+            let grouping_id = ColumnBuilder::repeat(
+                &Scalar::Number(NumberScalar::UInt32(*id)).as_ref(),
+                num_rows,
+                &DataType::Number(NumberDataType::UInt32),
+            )
+            .build();
+            columns.push(BlockEntry {
+                data_type: DataType::Number(NumberDataType::UInt32),
+                value: Value::Column(grouping_id),
+            });
+
+            blocks.push(DataBlock::new(columns, num_rows));
+        }
+
+        DataBlock::concat(&blocks)
+    }
+}
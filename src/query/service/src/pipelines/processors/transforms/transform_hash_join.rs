@@ -60,6 +60,11 @@ impl Sink for SinkBuildHashTable {
 enum HashJoinStep {
     Build,
     Probe,
+    /// Wait for the other probe processors to finish spilling the probe side.
+    WaitProbe,
+    /// Join the spilled partitions.
+    JoinSpilled,
+    Finished,
 }
 
 pub struct TransformHashJoinProbe {
@@ -71,6 +76,7 @@ pub struct TransformHashJoinProbe {
     step: HashJoinStep,
     join_state: Arc<dyn HashJoinState>,
     probe_state: ProbeState,
+    probe_detached: bool,
 }
 
 impl TransformHashJoinProbe {
@@ -82,6 +88,7 @@ impl TransformHashJoinProbe {
         _output_schema: DataSchemaRef,
     ) -> Result<Box<dyn Processor>> {
         let default_block_size = ctx.get_settings().get_max_block_size()?;
+        join_state.probe_attach();
         Ok(Box::new(TransformHashJoinProbe {
             input_data: None,
             output_data_blocks: VecDeque::new(),
@@ -90,6 +97,7 @@ impl TransformHashJoinProbe {
             step: HashJoinStep::Build,
             join_state,
             probe_state: ProbeState::with_capacity(default_block_size as usize),
+            probe_detached: false,
         }))
     }
 
@@ -99,6 +107,13 @@ impl TransformHashJoinProbe {
             .extend(self.join_state.probe(block, &mut self.probe_state)?);
         Ok(())
     }
+
+    fn probe_detach(&mut self) {
+        if !self.probe_detached {
+            self.probe_detached = true;
+            self.join_state.probe_detach();
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -113,10 +128,11 @@ impl Processor for TransformHashJoinProbe {
 
     fn event(&mut self) -> Result<Event> {
         match self.step {
-            HashJoinStep::Build => Ok(Event::Async),
+            HashJoinStep::Build | HashJoinStep::WaitProbe => Ok(Event::Async),
             HashJoinStep::Probe => {
                 if self.output_port.is_finished() {
                     self.input_port.finish();
+                    self.probe_detach();
                     return Ok(Event::Finished);
                 }
 
@@ -142,6 +158,11 @@ impl Processor for TransformHashJoinProbe {
                 }
 
                 if self.input_port.is_finished() {
+                    self.probe_detach();
+                    if self.join_state.is_spilled() {
+                        self.step = HashJoinStep::WaitProbe;
+                        return Ok(Event::Async);
+                    }
                     self.output_port.finish();
                     return Ok(Event::Finished);
                 }
@@ -149,6 +170,27 @@ impl Processor for TransformHashJoinProbe {
                 self.input_port.set_need_data();
                 Ok(Event::NeedData)
             }
+            HashJoinStep::JoinSpilled => {
+                if self.output_port.is_finished() {
+                    return Ok(Event::Finished);
+                }
+
+                if !self.output_port.can_push() {
+                    return Ok(Event::NeedConsume);
+                }
+
+                if !self.output_data_blocks.is_empty() {
+                    let data = self.output_data_blocks.pop_front().unwrap();
+                    self.output_port.push_data(Ok(data));
+                    return Ok(Event::NeedConsume);
+                }
+
+                Ok(Event::Sync)
+            }
+            HashJoinStep::Finished => {
+                self.output_port.finish();
+                Ok(Event::Finished)
+            }
         }
     }
 
@@ -158,11 +200,22 @@ impl Processor for TransformHashJoinProbe {
 
     fn process(&mut self) -> Result<()> {
         match self.step {
-            HashJoinStep::Build => Ok(()),
+            HashJoinStep::Build | HashJoinStep::WaitProbe | HashJoinStep::Finished => Ok(()),
             HashJoinStep::Probe => {
                 if let Some(data) = self.input_data.take() {
                     let data = data.convert_to_full();
-                    self.probe(&data)?;
+                    if self.join_state.is_spilled() {
+                        self.join_state.spill_probe(&data)?;
+                    } else {
+                        self.probe(&data)?;
+                    }
+                }
+                Ok(())
+            }
+            HashJoinStep::JoinSpilled => {
+                match self.join_state.join_spilled_partition()? {
+                    Some(blocks) => self.output_data_blocks.extend(blocks),
+                    None => self.step = HashJoinStep::Finished,
                 }
                 Ok(())
             }
@@ -170,9 +223,16 @@ impl Processor for TransformHashJoinProbe {
     }
 
     async fn async_process(&mut self) -> Result<()> {
-        if let HashJoinStep::Build = &self.step {
-            self.join_state.wait_finish().await?;
-            self.step = HashJoinStep::Probe;
+        match &self.step {
+            HashJoinStep::Build => {
+                self.join_state.wait_finish().await?;
+                self.step = HashJoinStep::Probe;
+            }
+            HashJoinStep::WaitProbe => {
+                self.join_state.wait_probe_finish().await?;
+                self.step = HashJoinStep::JoinSpilled;
+            }
+            _ => {}
         }
 
         Ok(())
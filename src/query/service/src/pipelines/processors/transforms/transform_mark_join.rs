@@ -41,8 +41,8 @@ impl Compactor for MarkJoinCompactor {
     }
 
     // `compact_final` is called when all the blocks are pushed
-    fn compact_final(&self, _blocks: &[DataBlock]) -> Result<Vec<DataBlock>> {
-        self.hash_join_state.mark_join_blocks()
+    fn compact_final(&self, blocks: &[DataBlock]) -> Result<Vec<DataBlock>> {
+        self.hash_join_state.mark_join_blocks(blocks)
     }
 }
 
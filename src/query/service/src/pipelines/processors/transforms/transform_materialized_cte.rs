@@ -0,0 +1,203 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sources::SyncSource;
+use common_pipeline_sources::SyncSourcer;
+use common_pipeline_transforms::processors::transforms::SpilledBlocks;
+use common_profile::ProfSpanSetRef;
+use common_sql::executor::MaterializedCte;
+use common_sql::IndexType;
+use parking_lot::Mutex;
+use tracing::info;
+
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelinePullingExecutor;
+use crate::pipelines::processors::transforms::RecursiveCteStates;
+use crate::pipelines::PipelineBuilder;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// The buffered result of a materialized CTE, the blocks exceeding
+/// `cte_spilling_bytes_threshold` are spilled to disk.
+pub struct MaterializedCteResult {
+    schema: DataSchemaRef,
+    blocks: Vec<DataBlock>,
+    spilled: SpilledBlocks,
+}
+
+impl MaterializedCteResult {
+    pub fn schema(&self) -> DataSchemaRef {
+        self.schema.clone()
+    }
+
+    pub fn num_blocks(&self) -> usize {
+        self.blocks.len() + self.spilled.num_blocks()
+    }
+
+    pub fn block(&self, index: usize) -> Result<DataBlock> {
+        match self.blocks.get(index) {
+            Some(block) => Ok(block.clone()),
+            None => self.spilled.read(index - self.blocks.len()),
+        }
+    }
+}
+
+/// A materialized CTE shared by its references. The CTE is executed by the
+/// first reference reading it, and the result is kept until the query ends.
+pub struct MaterializedCteState {
+    ctx: Arc<QueryContext>,
+    plan: MaterializedCte,
+    recursive_cte_states: RecursiveCteStates,
+    materialized_cte_states: MaterializedCteStates,
+    result: Mutex<Option<Arc<MaterializedCteResult>>>,
+}
+
+impl MaterializedCteState {
+    pub fn create(
+        ctx: Arc<QueryContext>,
+        plan: MaterializedCte,
+        recursive_cte_states: RecursiveCteStates,
+        materialized_cte_states: MaterializedCteStates,
+    ) -> Self {
+        MaterializedCteState {
+            ctx,
+            plan,
+            recursive_cte_states,
+            materialized_cte_states,
+            result: Mutex::new(None),
+        }
+    }
+
+    pub fn materialize(&self) -> Result<Arc<MaterializedCteResult>> {
+        let mut result = self.result.lock();
+        if let Some(result) = result.as_ref() {
+            return Ok(result.clone());
+        }
+
+        let materialized = Arc::new(self.execute()?);
+        *result = Some(materialized.clone());
+        Ok(materialized)
+    }
+
+    fn execute(&self) -> Result<MaterializedCteResult> {
+        let ctx = QueryContext::create_from(self.ctx.clone());
+        let mut builder = PipelineBuilder::create(ctx.clone(), false, ProfSpanSetRef::default());
+        builder.recursive_cte_states = self.recursive_cte_states.clone();
+        builder.materialized_cte_states = self.materialized_cte_states.clone();
+        let mut build_res = builder.finalize(&self.plan.left)?;
+
+        let settings = ctx.get_settings();
+        let spilling_bytes_threshold = settings.get_cte_spilling_bytes_threshold()? as usize;
+        build_res.set_max_threads(settings.get_max_threads()? as usize);
+        let executor_settings = ExecutorSettings::try_create(&settings, ctx.get_id())?;
+
+        let spill_file = ctx.get_spill_dir().join(uuid::Uuid::new_v4().to_string());
+        let mut result = MaterializedCteResult {
+            schema: self.plan.left.output_schema()?,
+            blocks: vec![],
+            spilled: SpilledBlocks::create(spill_file),
+        };
+
+        let mut executor = PipelinePullingExecutor::from_pipelines(build_res, executor_settings)?;
+        executor.start();
+        let mut memory_bytes = 0;
+        while let Some(block) = executor.pull_data()? {
+            if block.is_empty() {
+                continue;
+            }
+            let block = block.convert_to_full();
+            if spilling_bytes_threshold != 0 && memory_bytes >= spilling_bytes_threshold {
+                result.spilled.append(&block)?;
+            } else {
+                memory_bytes += block.memory_size();
+                result.blocks.push(block);
+            }
+        }
+
+        if !result.spilled.is_empty() {
+            info!(
+                "Materialized CTE {} spilled {} blocks, {} bytes to disk",
+                self.plan.cte_name,
+                result.spilled.num_blocks(),
+                result.spilled.size()
+            );
+        }
+        Ok(result)
+    }
+}
+
+pub type MaterializedCteStates = HashMap<IndexType, Arc<MaterializedCteState>>;
+
+/// Reads the buffered result of a materialized CTE.
+pub struct CteScanSource {
+    state: Arc<MaterializedCteState>,
+    cte_columns: Vec<String>,
+
+    result: Option<(Arc<MaterializedCteResult>, Vec<usize>)>,
+    next_block: usize,
+}
+
+impl CteScanSource {
+    pub fn create(
+        ctx: Arc<QueryContext>,
+        output: Arc<OutputPort>,
+        state: Arc<MaterializedCteState>,
+        cte_columns: Vec<String>,
+    ) -> Result<ProcessorPtr> {
+        SyncSourcer::create(ctx, output, CteScanSource {
+            state,
+            cte_columns,
+            result: None,
+            next_block: 0,
+        })
+    }
+}
+
+impl SyncSource for CteScanSource {
+    const NAME: &'static str = "CteScanSource";
+
+    fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.result.is_none() {
+            let result = self.state.materialize()?;
+            let schema = result.schema();
+            let offsets = self
+                .cte_columns
+                .iter()
+                .map(|name| schema.index_of(name))
+                .collect::<Result<Vec<_>>>()?;
+            self.result = Some((result, offsets));
+        }
+
+        let (result, offsets) = self.result.as_ref().unwrap();
+        if self.next_block >= result.num_blocks() {
+            return Ok(None);
+        }
+        let block = result.block(self.next_block)?;
+        self.next_block += 1;
+
+        let columns = offsets
+            .iter()
+            .map(|offset| block.get_by_offset(*offset).clone())
+            .collect();
+        Ok(Some(DataBlock::new(columns, block.num_rows())))
+    }
+}
@@ -0,0 +1,210 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::Scalar;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sources::SyncSource;
+use common_pipeline_sources::SyncSourcer;
+use common_profile::ProfSpanSetRef;
+use common_sql::executor::PhysicalPlan;
+use common_sql::executor::RecursiveCte;
+use parking_lot::Mutex;
+
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelinePullingExecutor;
+use crate::pipelines::processors::transforms::MaterializedCteStates;
+use crate::pipelines::PipelineBuilder;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// The working table of a recursive CTE, which holds the rows produced
+/// by the previous iteration.
+#[derive(Default)]
+pub struct RecursiveCteState {
+    working_table: Mutex<Vec<DataBlock>>,
+}
+
+impl RecursiveCteState {
+    pub fn working_table(&self) -> Vec<DataBlock> {
+        self.working_table.lock().clone()
+    }
+
+    fn set_working_table(&self, blocks: Vec<DataBlock>) {
+        *self.working_table.lock() = blocks;
+    }
+}
+
+pub type RecursiveCteStates = HashMap<String, Arc<RecursiveCteState>>;
+
+/// Evaluates a recursive CTE by executing the recursive term repeatedly
+/// until it produces no new rows.
+pub struct RecursiveCteSource {
+    ctx: Arc<QueryContext>,
+    plan: RecursiveCte,
+    state: Arc<RecursiveCteState>,
+    states: RecursiveCteStates,
+    materialized_cte_states: MaterializedCteStates,
+    max_iterations: u64,
+
+    results: Option<VecDeque<DataBlock>>,
+}
+
+impl RecursiveCteSource {
+    pub fn create(
+        ctx: Arc<QueryContext>,
+        output: Arc<OutputPort>,
+        plan: RecursiveCte,
+        states: RecursiveCteStates,
+        materialized_cte_states: MaterializedCteStates,
+    ) -> Result<ProcessorPtr> {
+        let state = states
+            .get(&plan.cte_name)
+            .cloned()
+            .ok_or_else(|| ErrorCode::Internal("Missing working table of recursive CTE"))?;
+        let max_iterations = ctx.get_settings().get_max_recursive_cte_iterations()?;
+        SyncSourcer::create(ctx.clone(), output, RecursiveCteSource {
+            ctx,
+            plan,
+            state,
+            states,
+            materialized_cte_states,
+            max_iterations,
+            results: None,
+        })
+    }
+
+    fn execute_plan(&self, plan: &PhysicalPlan) -> Result<Vec<DataBlock>> {
+        let ctx = QueryContext::create_from(self.ctx.clone());
+        let mut builder = PipelineBuilder::create(ctx.clone(), false, ProfSpanSetRef::default());
+        builder.recursive_cte_states = self.states.clone();
+        builder.materialized_cte_states = self.materialized_cte_states.clone();
+        let mut build_res = builder.finalize(plan)?;
+
+        let settings = ctx.get_settings();
+        build_res.set_max_threads(settings.get_max_threads()? as usize);
+        let settings = ExecutorSettings::try_create(&settings, ctx.get_id())?;
+
+        let mut executor = PipelinePullingExecutor::from_pipelines(build_res, settings)?;
+        executor.start();
+        let mut blocks = vec![];
+        while let Some(block) = executor.pull_data()? {
+            blocks.push(block);
+        }
+        Ok(blocks)
+    }
+
+    /// Reorder the columns of the blocks as the output of the CTE, and discard
+    /// the rows that have been produced before if `UNION` is used.
+    fn normalize(
+        &self,
+        plan: &PhysicalPlan,
+        blocks: Vec<DataBlock>,
+        right: bool,
+        seen: &mut HashSet<Vec<Scalar>>,
+    ) -> Result<Vec<DataBlock>> {
+        let schema = plan.output_schema()?;
+        let offsets = self
+            .plan
+            .pairs
+            .iter()
+            .map(|(left, right_name)| {
+                let name = if right { right_name } else { left };
+                schema.index_of(name)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut result = Vec::with_capacity(blocks.len());
+        for block in blocks {
+            let block = block.convert_to_full();
+            let num_rows = block.num_rows();
+            let columns = offsets
+                .iter()
+                .map(|offset| block.get_by_offset(*offset).clone())
+                .collect();
+            let mut block = DataBlock::new(columns, num_rows);
+
+            if self.plan.distinct {
+                let columns = block
+                    .columns()
+                    .iter()
+                    .map(|entry| entry.value.as_column().unwrap().clone())
+                    .collect::<Vec<_>>();
+                let mut bitmap = MutableBitmap::with_capacity(num_rows);
+                for row in 0..num_rows {
+                    let key = columns
+                        .iter()
+                        .map(|column| column.index(row).unwrap().to_owned())
+                        .collect::<Vec<_>>();
+                    bitmap.push(seen.insert(key));
+                }
+                block = block.filter_with_bitmap(&bitmap.into())?;
+            }
+
+            if !block.is_empty() {
+                result.push(block);
+            }
+        }
+        Ok(result)
+    }
+
+    fn execute(&self) -> Result<Vec<DataBlock>> {
+        let mut seen = HashSet::new();
+        let anchor = self.execute_plan(&self.plan.anchor)?;
+        let mut working_table = self.normalize(&self.plan.anchor, anchor, false, &mut seen)?;
+
+        let mut results = vec![];
+        let mut iterations = 0;
+        while !working_table.is_empty() {
+            if iterations >= self.max_iterations {
+                return Err(ErrorCode::Overflow(format!(
+                    "Recursive CTE {} exceeds the maximum iterations {}, please check the terminate condition or increase max_recursive_cte_iterations",
+                    self.plan.cte_name, self.max_iterations
+                )));
+            }
+            iterations += 1;
+
+            results.extend(working_table.iter().cloned());
+            self.state.set_working_table(working_table);
+            let blocks = self.execute_plan(&self.plan.recursive)?;
+            working_table = self.normalize(&self.plan.recursive, blocks, true, &mut seen)?;
+        }
+        self.state.set_working_table(vec![]);
+
+        Ok(results)
+    }
+}
+
+impl SyncSource for RecursiveCteSource {
+    const NAME: &'static str = "RecursiveCteSource";
+
+    fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.results.is_none() {
+            self.results = Some(self.execute()?.into());
+        }
+        Ok(self
+            .results
+            .as_mut()
+            .and_then(|results| results.pop_front()))
+    }
+}
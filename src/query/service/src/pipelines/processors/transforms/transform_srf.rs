@@ -0,0 +1,82 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::Value;
+use common_functions::srfs::SetReturningFunction;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::Processor;
+use common_pipeline_transforms::processors::transforms::transform::Transform;
+use common_pipeline_transforms::processors::transforms::transform::Transformer;
+
+/// Evaluates a set-returning function for each input row.
+///
+/// Each input row is repeated once per row returned by the function, and
+/// the output columns of the function are appended.
+pub struct TransformSRF {
+    srf: &'static SetReturningFunction,
+    /// Offsets of the arguments in the input block.
+    arguments: Vec<usize>,
+    return_types: Vec<DataType>,
+}
+
+impl TransformSRF {
+    pub fn create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        srf: &'static SetReturningFunction,
+        arguments: Vec<usize>,
+        return_types: Vec<DataType>,
+    ) -> Box<dyn Processor> {
+        Transformer::create(input, output, TransformSRF {
+            srf,
+            arguments,
+            return_types,
+        })
+    }
+}
+
+impl Transform for TransformSRF {
+    const NAME: &'static str = "TransformSRF";
+
+    fn transform(&mut self, data: DataBlock) -> Result<DataBlock> {
+        let num_rows = data.num_rows();
+        let args = self
+            .arguments
+            .iter()
+            .map(|offset| data.get_by_offset(*offset).value.as_ref())
+            .collect::<Vec<_>>();
+        let (columns, repeats) = (self.srf.eval)(&args, &self.return_types, num_rows)?;
+
+        let indices = repeats
+            .iter()
+            .enumerate()
+            .flat_map(|(row, repeat)| std::iter::repeat(row as u32).take(*repeat))
+            .collect::<Vec<_>>();
+        let mut block = data.take(&indices)?;
+        for (column, data_type) in columns.into_iter().zip(self.return_types.iter()) {
+            block.add_column(BlockEntry {
+                data_type: data_type.clone(),
+                value: Value::Column(column),
+            });
+        }
+        Ok(block)
+    }
+}
@@ -0,0 +1,418 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use bumpalo::Bump;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberScalar;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::DataBlock;
+use common_expression::ScalarRef;
+use common_expression::Value;
+use common_functions::aggregates::AggregateFunctionRef;
+use common_functions::aggregates::StateAddr;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::Processor;
+use common_pipeline_transforms::processors::transforms::transform_accumulating::AccumulatingTransform;
+use common_pipeline_transforms::processors::transforms::transform_accumulating::AccumulatingTransformer;
+use common_sql::plans::WindowFuncFrame;
+use common_sql::plans::WindowFuncFrameBound;
+use common_sql::plans::WindowFuncFrameUnits;
+
+/// The window function evaluated by [`TransformWindow`].
+pub enum WindowFunctionImpl {
+    Aggregate {
+        agg: AggregateFunctionRef,
+        /// Offsets of the arguments in the input block.
+        args: Vec<usize>,
+    },
+    RowNumber,
+    Rank,
+    DenseRank,
+    PercentRank,
+    CumeDist,
+    LagLead {
+        is_lag: bool,
+        offset: usize,
+        /// Offset of the argument in the input block.
+        arg: usize,
+        /// Offset of the default value in the input block.
+        default: Option<usize>,
+        return_type: DataType,
+    },
+    NthValue {
+        /// The n-th row of the frame counted from 1, `None` is the last row.
+        n: Option<usize>,
+        /// Offset of the argument in the input block.
+        arg: usize,
+        return_type: DataType,
+    },
+}
+
+impl WindowFunctionImpl {
+    fn return_type(&self) -> Result<DataType> {
+        Ok(match self {
+            WindowFunctionImpl::Aggregate { agg, .. } => agg.return_type()?,
+            WindowFunctionImpl::RowNumber
+            | WindowFunctionImpl::Rank
+            | WindowFunctionImpl::DenseRank => DataType::Number(NumberDataType::UInt64),
+            WindowFunctionImpl::PercentRank | WindowFunctionImpl::CumeDist => {
+                DataType::Number(NumberDataType::Float64)
+            }
+            WindowFunctionImpl::LagLead { return_type, .. }
+            | WindowFunctionImpl::NthValue { return_type, .. } => return_type.clone(),
+        })
+    }
+}
+
+/// Evaluates one window function and appends the result as the last column.
+///
+/// The input must be sorted by partition keys and then by order keys, and must
+/// arrive through a single stream. Rows are buffered until the input is finished.
+pub struct TransformWindow {
+    func: WindowFunctionImpl,
+    partition_by: Vec<usize>,
+    order_by: Vec<usize>,
+    frame: WindowFuncFrame,
+
+    blocks: Vec<DataBlock>,
+}
+
+impl TransformWindow {
+    pub fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        func: WindowFunctionImpl,
+        partition_by: Vec<usize>,
+        order_by: Vec<usize>,
+        frame: WindowFuncFrame,
+    ) -> Result<Box<dyn Processor>> {
+        Ok(AccumulatingTransformer::create(
+            input,
+            output,
+            TransformWindow {
+                func,
+                partition_by,
+                order_by,
+                frame,
+                blocks: vec![],
+            },
+        ))
+    }
+
+    fn rows_equal(columns: &[Column], lhs: usize, rhs: usize) -> bool {
+        columns
+            .iter()
+            .all(|column| column.index(lhs) == column.index(rhs))
+    }
+
+    /// Split `[0, num_rows)` into consecutive ranges of rows that are equal on `columns`.
+    fn split_ranges(columns: &[Column], start: usize, end: usize) -> Vec<(usize, usize)> {
+        let mut ranges = vec![];
+        let mut range_start = start;
+        for row in start + 1..end {
+            if !Self::rows_equal(columns, row - 1, row) {
+                ranges.push((range_start, row));
+                range_start = row;
+            }
+        }
+        if range_start < end {
+            ranges.push((range_start, end));
+        }
+        ranges
+    }
+
+    fn collect_columns(block: &DataBlock, offsets: &[usize]) -> Vec<Column> {
+        offsets
+            .iter()
+            .map(|offset| {
+                block
+                    .get_by_offset(*offset)
+                    .value
+                    .as_column()
+                    .unwrap()
+                    .clone()
+            })
+            .collect()
+    }
+
+    fn evaluate_ranking(
+        &self,
+        order_columns: &[Column],
+        start: usize,
+        end: usize,
+        builder: &mut ColumnBuilder,
+    ) {
+        let num_rows = (end - start) as u64;
+        let peers = Self::split_ranges(order_columns, start, end);
+        for (dense_rank, (peer_start, peer_end)) in peers.into_iter().enumerate() {
+            let rank = (peer_start - start) as u64 + 1;
+            for row in peer_start..peer_end {
+                let value = match self.func {
+                    WindowFunctionImpl::RowNumber => NumberScalar::UInt64((row - start) as u64 + 1),
+                    WindowFunctionImpl::Rank => NumberScalar::UInt64(rank),
+                    WindowFunctionImpl::DenseRank => NumberScalar::UInt64(dense_rank as u64 + 1),
+                    WindowFunctionImpl::PercentRank => {
+                        let value = if num_rows <= 1 {
+                            0.0
+                        } else {
+                            (rank - 1) as f64 / (num_rows - 1) as f64
+                        };
+                        NumberScalar::Float64(value.into())
+                    }
+                    WindowFunctionImpl::CumeDist => {
+                        let value = (peer_end - start) as f64 / num_rows as f64;
+                        NumberScalar::Float64(value.into())
+                    }
+                    _ => unreachable!(),
+                };
+                builder.push(ScalarRef::Number(value));
+            }
+        }
+    }
+
+    /// Returns the frame `[start, end)` of each row in the partition `[start, end)`.
+    fn frame_bounds(
+        &self,
+        order_columns: &[Column],
+        start: usize,
+        end: usize,
+    ) -> Vec<(usize, usize)> {
+        let mut bounds = Vec::with_capacity(end - start);
+        match self.frame.units {
+            WindowFuncFrameUnits::Rows => {
+                for row in start..end {
+                    let frame_start = match &self.frame.start_bound {
+                        WindowFuncFrameBound::Preceding(None) => start,
+                        WindowFuncFrameBound::Preceding(Some(n)) => {
+                            row.saturating_sub(*n as usize).max(start)
+                        }
+                        WindowFuncFrameBound::CurrentRow => row,
+                        WindowFuncFrameBound::Following(Some(n)) => (row + *n as usize).min(end),
+                        WindowFuncFrameBound::Following(None) => end,
+                    };
+                    let frame_end = match &self.frame.end_bound {
+                        WindowFuncFrameBound::Preceding(None) => start,
+                        WindowFuncFrameBound::Preceding(Some(n)) => {
+                            (row + 1).saturating_sub(*n as usize).max(start)
+                        }
+                        WindowFuncFrameBound::CurrentRow => row + 1,
+                        WindowFuncFrameBound::Following(Some(n)) => {
+                            (row + 1 + *n as usize).min(end)
+                        }
+                        WindowFuncFrameBound::Following(None) => end,
+                    };
+                    bounds.push((frame_start, frame_end.max(frame_start)));
+                }
+            }
+            WindowFuncFrameUnits::Range => {
+                // Only UNBOUNDED and CURRENT ROW bounds are allowed in RANGE mode,
+                // so the frame is always aligned to peer groups.
+                for (peer_start, peer_end) in Self::split_ranges(order_columns, start, end) {
+                    let frame_start = match &self.frame.start_bound {
+                        WindowFuncFrameBound::CurrentRow => peer_start,
+                        WindowFuncFrameBound::Following(_) => end,
+                        WindowFuncFrameBound::Preceding(_) => start,
+                    };
+                    let frame_end = match &self.frame.end_bound {
+                        WindowFuncFrameBound::CurrentRow => peer_end,
+                        WindowFuncFrameBound::Preceding(_) => start,
+                        WindowFuncFrameBound::Following(_) => end,
+                    };
+                    for _ in peer_start..peer_end {
+                        bounds.push((frame_start, frame_end.max(frame_start)));
+                    }
+                }
+            }
+        }
+        bounds
+    }
+
+    fn evaluate_aggregate(
+        &self,
+        agg: &AggregateFunctionRef,
+        arg_columns: &[Column],
+        order_columns: &[Column],
+        start: usize,
+        end: usize,
+        builder: &mut ColumnBuilder,
+    ) -> Result<()> {
+        let arena = Bump::new();
+        let bounds = self.frame_bounds(order_columns, start, end);
+
+        // The frames of a partition only grow when the frame starts at
+        // UNBOUNDED PRECEDING, so a single state can be accumulated incrementally.
+        let incremental = bounds.iter().all(|(frame_start, _)| *frame_start == start)
+            && bounds.windows(2).all(|w| w[0].1 <= w[1].1);
+
+        if incremental {
+            let place: StateAddr = arena.alloc_layout(agg.state_layout()).into();
+            agg.init_state(place);
+            let mut accumulated = start;
+            for (_, frame_end) in bounds {
+                while accumulated < frame_end {
+                    agg.accumulate_row(place, arg_columns, accumulated)?;
+                    accumulated += 1;
+                }
+                agg.merge_result(place, builder)?;
+            }
+            if agg.need_manual_drop_state() {
+                unsafe { agg.drop_state(place) }
+            }
+        } else {
+            for (frame_start, frame_end) in bounds {
+                let place: StateAddr = arena.alloc_layout(agg.state_layout()).into();
+                agg.init_state(place);
+                for row in frame_start..frame_end {
+                    agg.accumulate_row(place, arg_columns, row)?;
+                }
+                agg.merge_result(place, builder)?;
+                if agg.need_manual_drop_state() {
+                    unsafe { agg.drop_state(place) }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// `LAG` and `LEAD` return the argument of the row at `offset` before or after the
+    /// current row in the partition, the frame is ignored.
+    fn evaluate_lag_lead(
+        is_lag: bool,
+        offset: usize,
+        arg: &Column,
+        default: Option<&Column>,
+        start: usize,
+        end: usize,
+        builder: &mut ColumnBuilder,
+    ) {
+        for row in start..end {
+            let target = if is_lag {
+                row.checked_sub(offset).filter(|target| *target >= start)
+            } else {
+                row.checked_add(offset).filter(|target| *target < end)
+            };
+            match (target, default) {
+                (Some(target), _) => builder.push(arg.index(target).unwrap()),
+                (None, Some(default)) => builder.push(default.index(row).unwrap()),
+                (None, None) => builder.push(ScalarRef::Null),
+            }
+        }
+    }
+
+    /// `FIRST_VALUE` and `LAST_VALUE` return the argument of the first or last row of the
+    /// frame, or NULL if the frame is empty.
+    fn evaluate_nth_value(
+        &self,
+        n: Option<usize>,
+        arg: &Column,
+        order_columns: &[Column],
+        start: usize,
+        end: usize,
+        builder: &mut ColumnBuilder,
+    ) {
+        for (frame_start, frame_end) in self.frame_bounds(order_columns, start, end) {
+            let row = match n {
+                Some(n) => Some(frame_start + n - 1).filter(|row| *row < frame_end),
+                None => (frame_end > frame_start).then(|| frame_end - 1),
+            };
+            match row {
+                Some(row) => builder.push(arg.index(row).unwrap()),
+                None => builder.push(ScalarRef::Null),
+            }
+        }
+    }
+}
+
+impl AccumulatingTransform for TransformWindow {
+    const NAME: &'static str = "TransformWindow";
+
+    fn transform(&mut self, block: DataBlock) -> Result<Option<DataBlock>> {
+        if !block.is_empty() {
+            self.blocks.push(block.convert_to_full());
+        }
+        Ok(None)
+    }
+
+    fn on_finish(&mut self, output: bool) -> Result<Option<DataBlock>> {
+        if !output || self.blocks.is_empty() {
+            return Ok(None);
+        }
+
+        let blocks = std::mem::take(&mut self.blocks);
+        let mut block = DataBlock::concat(&blocks)?;
+        let num_rows = block.num_rows();
+
+        let partition_columns = Self::collect_columns(&block, &self.partition_by);
+        let order_columns = Self::collect_columns(&block, &self.order_by);
+
+        let data_type = self.func.return_type()?;
+        let mut builder = ColumnBuilder::with_capacity(&data_type, num_rows);
+
+        for (start, end) in Self::split_ranges(&partition_columns, 0, num_rows) {
+            match &self.func {
+                WindowFunctionImpl::Aggregate { agg, args } => {
+                    let arg_columns = Self::collect_columns(&block, args);
+                    self.evaluate_aggregate(
+                        agg,
+                        &arg_columns,
+                        &order_columns,
+                        start,
+                        end,
+                        &mut builder,
+                    )?;
+                }
+                WindowFunctionImpl::LagLead {
+                    is_lag,
+                    offset,
+                    arg,
+                    default,
+                    ..
+                } => {
+                    let arg = Self::collect_columns(&block, &[*arg]).remove(0);
+                    let default =
+                        default.map(|default| Self::collect_columns(&block, &[default]).remove(0));
+                    Self::evaluate_lag_lead(
+                        *is_lag,
+                        *offset,
+                        &arg,
+                        default.as_ref(),
+                        start,
+                        end,
+                        &mut builder,
+                    );
+                }
+                WindowFunctionImpl::NthValue { n, arg, .. } => {
+                    let arg = Self::collect_columns(&block, &[*arg]).remove(0);
+                    self.evaluate_nth_value(*n, &arg, &order_columns, start, end, &mut builder);
+                }
+                _ => self.evaluate_ranking(&order_columns, start, end, &mut builder),
+            }
+        }
+
+        block.add_column(BlockEntry {
+            data_type,
+            value: Value::Column(builder.build()),
+        });
+
+        Ok(Some(block))
+    }
+}
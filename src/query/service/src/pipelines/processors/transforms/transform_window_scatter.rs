@@ -0,0 +1,150 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_pipeline_core::pipe::PipeItem;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::Event;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_core::processors::Processor;
+
+/// Scatters the rows to the outputs by the hash of the partition keys of a window,
+/// so that all the rows of a partition are evaluated by the same [`TransformWindow`].
+///
+/// The rows sent to each output keep their input order, so the sorted input stays
+/// sorted in each output.
+///
+/// [`TransformWindow`]: super::TransformWindow
+pub struct TransformWindowScatter {
+    input: Arc<InputPort>,
+    outputs: Vec<Arc<OutputPort>>,
+    partition_by: Vec<usize>,
+
+    input_data: Option<DataBlock>,
+    output_data: Vec<Option<DataBlock>>,
+}
+
+impl TransformWindowScatter {
+    pub fn create_item(outputs: usize, partition_by: Vec<usize>) -> PipeItem {
+        let input = InputPort::create();
+        let outputs = (0..outputs)
+            .map(|_| OutputPort::create())
+            .collect::<Vec<_>>();
+        let processor = TransformWindowScatter {
+            input: input.clone(),
+            outputs: outputs.clone(),
+            partition_by,
+            input_data: None,
+            output_data: vec![None; outputs.len()],
+        };
+        PipeItem::create(
+            ProcessorPtr::create(Box::new(processor)),
+            vec![input],
+            outputs,
+        )
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformWindowScatter {
+    fn name(&self) -> String {
+        "TransformWindowScatter".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.outputs.iter().all(|output| output.is_finished()) {
+            self.input.finish();
+            return Ok(Event::Finished);
+        }
+
+        let mut all_pushed = true;
+        for (output, data) in self.outputs.iter().zip(self.output_data.iter_mut()) {
+            if data.is_none() {
+                continue;
+            }
+            if output.is_finished() {
+                *data = None;
+            } else if output.can_push() {
+                output.push_data(Ok(data.take().unwrap()));
+            } else {
+                all_pushed = false;
+            }
+        }
+        if !all_pushed {
+            self.input.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.input_data.is_some() {
+            return Ok(Event::Sync);
+        }
+
+        if self.input.has_data() {
+            self.input_data = Some(self.input.pull_data().unwrap()?);
+            return Ok(Event::Sync);
+        }
+
+        if self.input.is_finished() {
+            for output in self.outputs.iter() {
+                output.finish();
+            }
+            return Ok(Event::Finished);
+        }
+
+        self.input.set_need_data();
+        Ok(Event::NeedData)
+    }
+
+    fn process(&mut self) -> Result<()> {
+        if let Some(block) = self.input_data.take() {
+            let columns = self
+                .partition_by
+                .iter()
+                .map(|offset| block.get_by_offset(*offset).value.as_ref())
+                .collect::<Vec<_>>();
+            let indices = (0..block.num_rows())
+                .map(|row| {
+                    let mut hasher = DefaultHasher::new();
+                    for column in columns.iter() {
+                        column.index(row).unwrap().hash(&mut hasher);
+                    }
+                    hasher.finish() % self.outputs.len() as u64
+                })
+                .collect::<Vec<_>>();
+
+            for (data, scattered) in self
+                .output_data
+                .iter_mut()
+                .zip(block.scatter(&indices, self.outputs.len())?)
+            {
+                if !scattered.is_empty() {
+                    *data = Some(scattered);
+                }
+            }
+        }
+        Ok(())
+    }
+}
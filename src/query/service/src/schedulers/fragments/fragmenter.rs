@@ -150,6 +150,7 @@ impl PhysicalPlanReplacer for Fragmenter {
             join_type: plan.join_type.clone(),
             marker_index: plan.marker_index,
             from_correlated_subquery: plan.from_correlated_subquery,
+            runtime_filter_targets: plan.runtime_filter_targets.clone(),
             stat_info: plan.stat_info.clone(),
         }))
     }
@@ -194,10 +194,8 @@ impl MySQLFederated {
                     format!("{}-{}", self.mysql_version, self.databend_version.clone()).as_str(),
                 ),
             ),
-            // Txn.
-            ("(?i)^(ROLLBACK(.*))", None),
-            ("(?i)^(COMMIT(.*))", None),
-            ("(?i)^(START(.*))", None),
+            // Replication. Txn, START TRANSACTION/BEGIN/COMMIT/ROLLBACK are handled by the interpreters.
+            ("(?i)^(START (SLAVE|REPLICA|GROUP_REPLICATION)(.*))", None),
             // Set.
             ("(?i)^(SET NAMES(.*))", None),
             ("(?i)^(SET character_set_results(.*))", None),
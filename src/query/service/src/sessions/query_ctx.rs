@@ -16,6 +16,7 @@ use std::collections::HashMap;
 use std::collections::VecDeque;
 use std::future::Future;
 use std::net::SocketAddr;
+use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::AtomicUsize;
@@ -33,8 +34,10 @@ use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::PartInfoPtr;
 use common_catalog::plan::Partitions;
 use common_catalog::plan::StageTableInfo;
+use common_catalog::runtime_filter_info::RuntimeFilterInfo;
 use common_catalog::table_args::TableArgs;
 use common_catalog::table_context::StageAttachment;
+use common_catalog::txn::TxnManagerRef;
 use common_config::DATABEND_COMMIT_VERSION;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -47,6 +50,7 @@ use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::StageFileFormatType;
 use common_meta_app::principal::UserInfo;
 use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::UpdateTableMetaReq;
 use common_settings::Settings;
 use common_storage::DataOperator;
 use common_storage::StorageMetrics;
@@ -58,6 +62,7 @@ use parking_lot::RwLock;
 use tracing::debug;
 
 use crate::api::DataExchangeManager;
+use crate::api::RuntimeFilterPacket;
 use crate::auth::AuthMgr;
 use crate::catalogs::Catalog;
 use crate::clusters::Cluster;
@@ -173,6 +178,11 @@ impl QueryContext {
         self.shared.session.session_ctx.get_client_host()
     }
 
+    /// The directory of the temporary files spilled by the query.
+    pub fn get_spill_dir(&self) -> PathBuf {
+        self.shared.get_spill_dir()
+    }
+
     pub fn get_affect(self: &Arc<Self>) -> Option<QueryAffect> {
         self.shared.get_affect()
     }
@@ -197,6 +207,22 @@ impl QueryContext {
         self.shared.created_time
     }
 
+    pub fn add_local_runtime_filter(&self, packet: RuntimeFilterPacket) {
+        self.shared.add_local_runtime_filter(packet)
+    }
+
+    pub fn add_partial_runtime_filter(&self, packet: RuntimeFilterPacket) {
+        self.shared.add_partial_runtime_filter(packet)
+    }
+
+    pub fn take_pending_runtime_filters(&self) -> Vec<RuntimeFilterPacket> {
+        self.shared.take_pending_runtime_filters()
+    }
+
+    pub fn get_merged_runtime_filters(&self, skip: usize) -> Vec<RuntimeFilterPacket> {
+        self.shared.get_merged_runtime_filters(skip)
+    }
+
     pub fn get_on_error_map(&self) -> Option<HashMap<String, ErrorCode>> {
         self.shared.get_on_error_map()
     }
@@ -419,6 +445,26 @@ impl TableContext for QueryContext {
         self.shared.consume_precommit_blocks()
     }
 
+    fn txn_mgr(&self) -> TxnManagerRef {
+        self.shared.session.txn_mgr()
+    }
+
+    fn set_runtime_filter(&self, filters: (usize, RuntimeFilterInfo)) {
+        self.shared.set_runtime_filter(filters)
+    }
+
+    fn get_runtime_filter_with_id(&self, id: usize) -> Option<RuntimeFilterInfo> {
+        self.shared.get_runtime_filter_with_id(id)
+    }
+
+    fn add_stream_offset(&self, catalog: &str, req: UpdateTableMetaReq) {
+        self.shared.add_stream_offset(catalog, req)
+    }
+
+    fn get_stream_offsets(&self) -> Vec<(String, UpdateTableMetaReq)> {
+        self.shared.get_stream_offsets()
+    }
+
     async fn get_file_format(&self, name: &str) -> Result<FileFormatOptions> {
         let opt = match StageFileFormatType::from_str(name) {
             Ok(typ) => FileFormatOptions::default_by_type(typ),
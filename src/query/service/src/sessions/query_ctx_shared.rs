@@ -13,7 +13,9 @@
 // limitations under the License.
 
 use std::collections::hash_map::Entry;
+use std::collections::BTreeMap;
 use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
@@ -22,6 +24,8 @@ use std::time::SystemTime;
 
 use common_base::base::Progress;
 use common_base::runtime::Runtime;
+use common_catalog::runtime_filter_info::RuntimeFilterInfo;
+use common_catalog::runtime_filter_info::RuntimeFilters;
 use common_catalog::table_context::StageAttachment;
 use common_config::InnerConfig;
 use common_exception::ErrorCode;
@@ -29,13 +33,16 @@ use common_exception::Result;
 use common_expression::DataBlock;
 use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserInfo;
+use common_meta_app::schema::UpdateTableMetaReq;
 use common_settings::Settings;
 use common_storage::DataOperator;
 use common_storage::StorageMetrics;
 use parking_lot::Mutex;
 use parking_lot::RwLock;
+use tracing::warn;
 use uuid::Uuid;
 
+use crate::api::RuntimeFilterPacket;
 use crate::auth::AuthMgr;
 use crate::catalogs::CatalogManager;
 use crate::clusters::Cluster;
@@ -82,8 +89,25 @@ pub struct QueryContextShared {
     pub(in crate::sessions) on_error_map: Arc<RwLock<Option<HashMap<String, ErrorCode>>>>,
     /// partitions_sha for each table in the query. Not empty only when enabling query result cache.
     pub(in crate::sessions) partitions_shas: Arc<RwLock<Vec<String>>>,
+    /// Runtime filters pushed down from hash joins, keyed by the `scan_id` of table scans.
+    pub(in crate::sessions) runtime_filters: Arc<RwLock<RuntimeFilters>>,
+    /// Runtime filters of shuffled hash joins being merged, with the number of merged parts.
+    pub(in crate::sessions) partial_runtime_filters:
+        Arc<Mutex<HashMap<RuntimeFilterKey, (usize, RuntimeFilterPacket)>>>,
+    /// Runtime filters derived on this node, which are not sent to the coordinator yet.
+    pub(in crate::sessions) pending_runtime_filters: Arc<Mutex<Vec<RuntimeFilterPacket>>>,
+    /// Runtime filters merged from all the nodes, which are sent to executors by the coordinator.
+    pub(in crate::sessions) merged_runtime_filters: Arc<RwLock<Vec<RuntimeFilterPacket>>>,
+    /// New offsets of the streams read by the query, keyed by the table id of streams.
+    pub(in crate::sessions) stream_offsets:
+        Arc<RwLock<BTreeMap<u64, (String, UpdateTableMetaReq)>>>,
+    /// The root directory of the temporary files spilled by queries.
+    pub(in crate::sessions) spill_root: PathBuf,
 }
 
+/// (join_id, scan_id, column_name)
+type RuntimeFilterKey = (u32, usize, String);
+
 impl QueryContextShared {
     pub fn try_create(
         config: &InnerConfig,
@@ -113,6 +137,15 @@ impl QueryContextShared {
             created_time: SystemTime::now(),
             on_error_map: Arc::new(RwLock::new(None)),
             partitions_shas: Arc::new(RwLock::new(vec![])),
+            runtime_filters: Arc::new(RwLock::new(HashMap::new())),
+            partial_runtime_filters: Arc::new(Mutex::new(HashMap::new())),
+            pending_runtime_filters: Arc::new(Mutex::new(vec![])),
+            merged_runtime_filters: Arc::new(RwLock::new(vec![])),
+            stream_offsets: Arc::new(RwLock::new(BTreeMap::new())),
+            spill_root: match config.query.spill_path.is_empty() {
+                true => std::env::temp_dir().join("databend").join("_query_spill"),
+                false => PathBuf::from(&config.query.spill_path),
+            },
         }))
     }
 
@@ -141,6 +174,12 @@ impl QueryContextShared {
         // TODO: Wait for the query to be processed (write out the last error)
     }
 
+    /// The directory of the temporary files spilled by the query, it's removed when the query
+    /// finishes.
+    pub fn get_spill_dir(&self) -> PathBuf {
+        self.spill_root.join(self.init_query_id.read().as_str())
+    }
+
     pub fn get_cluster(&self) -> Arc<Cluster> {
         self.cluster_cache.clone()
     }
@@ -236,8 +275,36 @@ impl QueryContextShared {
     ) -> Result<Arc<dyn Table>> {
         let tenant = self.get_tenant();
         let table_meta_key = (catalog.to_string(), database.to_string(), table.to_string());
+        let catalog_name = catalog;
         let catalog = self.catalog_manager.get_catalog(catalog)?;
-        let cache_table = catalog.get_table(tenant.as_str(), database, table).await?;
+
+        // In a transaction, the table is read at the version when it is first accessed in
+        // the transaction, with the changes made by the transaction.
+        let txn_mgr = self.session.txn_mgr();
+        let txn_table_info = {
+            let txn_mgr = txn_mgr.lock();
+            match txn_mgr.is_active() {
+                true => Some(txn_mgr.get_table_info(catalog_name, database, table)),
+                false => None,
+            }
+        };
+        let cache_table = match txn_table_info {
+            Some(Some(table_info)) => catalog.get_table_by_info(&table_info)?,
+            Some(None) => {
+                let table_name = table;
+                let table = catalog.get_table(tenant.as_str(), database, table).await?;
+                if table.engine() == "FUSE" {
+                    txn_mgr.lock().add_table_info(
+                        catalog_name,
+                        database,
+                        table_name,
+                        table.get_table_info(),
+                    );
+                }
+                table
+            }
+            None => catalog.get_table(tenant.as_str(), database, table).await?,
+        };
 
         let mut tables_refs = self.tables_refs.lock();
 
@@ -322,6 +389,78 @@ impl QueryContextShared {
         swapped_precommit_blocks
     }
 
+    pub fn set_runtime_filter(&self, filters: (usize, RuntimeFilterInfo)) {
+        let (scan_id, filters) = filters;
+        let mut runtime_filters = self.runtime_filters.write();
+        runtime_filters.entry(scan_id).or_default().extend(filters);
+    }
+
+    pub fn get_runtime_filter_with_id(&self, id: usize) -> Option<RuntimeFilterInfo> {
+        self.runtime_filters.read().get(&id).cloned()
+    }
+
+    pub fn add_stream_offset(&self, catalog: &str, req: UpdateTableMetaReq) {
+        let mut stream_offsets = self.stream_offsets.write();
+        stream_offsets.insert(req.table_id, (catalog.to_string(), req));
+    }
+
+    pub fn get_stream_offsets(&self) -> Vec<(String, UpdateTableMetaReq)> {
+        self.stream_offsets.read().values().cloned().collect()
+    }
+
+    /// Merge a part of a runtime filter of a shuffled hash join, the runtime filter is
+    /// pushed down to the table scan once the parts of all the nodes are merged.
+    pub fn add_partial_runtime_filter(&self, packet: RuntimeFilterPacket) {
+        let num_nodes = self.cluster_cache.nodes.len();
+        let key = (
+            packet.join_id,
+            packet.scan_id,
+            packet.filter.column_name.clone(),
+        );
+
+        let merged = {
+            let mut partial_runtime_filters = self.partial_runtime_filters.lock();
+            let (num_parts, merged) = match partial_runtime_filters.entry(key) {
+                Entry::Occupied(mut v) => {
+                    let (num_parts, merged) = v.get_mut();
+                    *num_parts += 1;
+                    merged.filter.merge(packet.filter);
+                    (*num_parts, merged.clone())
+                }
+                Entry::Vacant(v) => v.insert((1, packet)).clone(),
+            };
+
+            match num_parts >= num_nodes {
+                true => Some(merged),
+                false => None,
+            }
+        };
+
+        if let Some(merged) = merged {
+            let mut info = RuntimeFilterInfo::default();
+            info.add(merged.filter.clone());
+            self.set_runtime_filter((merged.scan_id, info));
+            self.merged_runtime_filters.write().push(merged);
+        }
+    }
+
+    /// Add a part of a runtime filter derived on this node, it will be sent to the coordinator
+    /// if this node is an executor of a cluster query.
+    pub fn add_local_runtime_filter(&self, packet: RuntimeFilterPacket) {
+        self.pending_runtime_filters.lock().push(packet.clone());
+        self.add_partial_runtime_filter(packet);
+    }
+
+    pub fn take_pending_runtime_filters(&self) -> Vec<RuntimeFilterPacket> {
+        std::mem::take(&mut *self.pending_runtime_filters.lock())
+    }
+
+    /// Get the merged runtime filters, skipping the first `skip` ones which have been sent.
+    pub fn get_merged_runtime_filters(&self, skip: usize) -> Vec<RuntimeFilterPacket> {
+        let merged_runtime_filters = self.merged_runtime_filters.read();
+        merged_runtime_filters.iter().skip(skip).cloned().collect()
+    }
+
     pub fn get_stage_attachment(&self) -> Option<StageAttachment> {
         self.stage_attachment.read().clone()
     }
@@ -340,7 +479,14 @@ impl Drop for QueryContextShared {
     fn drop(&mut self) {
         self.session
             .session_ctx
-            .update_query_ids_results(self.init_query_id.read().clone(), None)
+            .update_query_ids_results(self.init_query_id.read().clone(), None);
+
+        let spill_dir = self.get_spill_dir();
+        if spill_dir.exists() {
+            if let Err(cause) = std::fs::remove_dir_all(&spill_dir) {
+                warn!("Cannot remove the spill dir {:?}: {:?}", spill_dir, cause);
+            }
+        }
     }
 }
 
@@ -16,6 +16,7 @@ use std::net::SocketAddr;
 use std::sync::Arc;
 
 use chrono_tz::Tz;
+use common_catalog::txn::TxnManagerRef;
 use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -177,6 +178,10 @@ impl Session {
         self.session_ctx.get_current_catalog()
     }
 
+    pub fn txn_mgr(self: &Arc<Self>) -> TxnManagerRef {
+        self.session_ctx.txn_mgr()
+    }
+
     pub fn get_current_tenant(self: &Arc<Self>) -> String {
         self.session_ctx.get_current_tenant()
     }
@@ -18,6 +18,8 @@ use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Weak;
 
+use common_catalog::txn::TxnManager;
+use common_catalog::txn::TxnManagerRef;
 use common_config::GlobalConfig;
 use common_exception::Result;
 use common_meta_app::principal::RoleInfo;
@@ -54,6 +56,8 @@ pub struct SessionContext {
     // We store `query_id -> query_result_cache_key` to session context, so that we can fetch
     // query result through previous query_id easily.
     query_ids_results: RwLock<Vec<(String, Option<String>)>>,
+    // The explicit transaction started by `BEGIN`, it is shared by the queries of the session.
+    txn_mgr: TxnManagerRef,
 }
 
 impl SessionContext {
@@ -71,6 +75,7 @@ impl SessionContext {
             io_shutdown_tx: Default::default(),
             query_context_shared: Default::default(),
             query_ids_results: Default::default(),
+            txn_mgr: TxnManager::init(),
         }))
     }
 
@@ -96,6 +101,10 @@ impl SessionContext {
         self.settings.apply_changed_settings(changed_settings)
     }
 
+    pub fn txn_mgr(&self) -> TxnManagerRef {
+        self.txn_mgr.clone()
+    }
+
     // Get current catalog name.
     pub fn get_current_catalog(&self) -> String {
         let lock = self.current_catalog.read();
@@ -433,6 +433,46 @@ async fn test_http_session() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_http_txn() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let ep = create_endpoint().await?;
+    let json = serde_json::json!({"sql": "create table t_txn(a int) engine=fuse", "session": {"keep_server_session_secs": 10}});
+    let (status, result) = post_json_to_endpoint(&ep, &json).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result);
+    let session_id = &result.session_id.unwrap();
+
+    let sqls = vec![
+        ("begin", 0),
+        ("insert into t_txn values (1)", 0),
+        ("select * from t_txn", 1),
+        ("rollback", 0),
+        ("select * from t_txn", 0),
+        ("start transaction", 0),
+        ("insert into t_txn values (2)", 0),
+        ("commit", 0),
+        ("select * from t_txn", 1),
+    ];
+
+    for (sql, data_len) in sqls {
+        let json = serde_json::json!({"sql": sql, "session_id": session_id, "pagination": {"wait_time_secs": 3}});
+        let (status, result) = post_json_to_endpoint(&ep, &json).await?;
+        assert_eq!(status, StatusCode::OK, "{} {:?}", sql, result);
+        assert!(result.error.is_none(), "{} {:?}", sql, result);
+        assert_eq!(result.data.len(), data_len, "{} {:?}", sql, result);
+        assert_eq!(
+            result.state,
+            ExecuteStateKind::Succeeded,
+            "{} {:?}",
+            sql,
+            result
+        );
+    }
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_result_timeout() -> Result<()> {
     let config = ConfigBuilder::create()
@@ -26,10 +26,12 @@ use common_catalog::database::Database;
 use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::PartInfoPtr;
 use common_catalog::plan::Partitions;
+use common_catalog::runtime_filter_info::RuntimeFilterInfo;
 use common_catalog::table::Table;
 use common_catalog::table_context::ProcessInfo;
 use common_catalog::table_context::StageAttachment;
 use common_catalog::table_context::TableContext;
+use common_catalog::txn::TxnManagerRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataBlock;
@@ -478,6 +480,26 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn txn_mgr(&self) -> TxnManagerRef {
+        self.ctx.txn_mgr()
+    }
+
+    fn set_runtime_filter(&self, filters: (usize, RuntimeFilterInfo)) {
+        self.ctx.set_runtime_filter(filters)
+    }
+
+    fn get_runtime_filter_with_id(&self, id: usize) -> Option<RuntimeFilterInfo> {
+        self.ctx.get_runtime_filter_with_id(id)
+    }
+
+    fn add_stream_offset(&self, catalog: &str, req: UpdateTableMetaReq) {
+        self.ctx.add_stream_offset(catalog, req)
+    }
+
+    fn get_stream_offsets(&self) -> Vec<(String, UpdateTableMetaReq)> {
+        self.ctx.get_stream_offsets()
+    }
+
     async fn get_file_format(&self, _name: &str) -> Result<FileFormatOptions> {
         todo!()
     }
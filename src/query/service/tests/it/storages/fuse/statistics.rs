@@ -44,6 +44,7 @@ use common_storages_fuse::statistics::STATS_REPLACEMENT_CHAR;
 use common_storages_fuse::statistics::STATS_STRING_PREFIX_LEN;
 use common_storages_fuse::FuseStorageFormat;
 use databend_query::storages::fuse::io::TableMetaLocationGenerator;
+use databend_query::storages::fuse::statistics::build_column_histogram;
 use databend_query::storages::fuse::statistics::gen_columns_statistics;
 use databend_query::storages::fuse::statistics::reducers;
 use databend_query::storages::fuse::statistics::ClusterStatsGenerator;
@@ -52,8 +53,10 @@ use opendal::Operator;
 use rand::Rng;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ClusterStatistics;
+use storages_common_table_meta::meta::ColumnHistogram;
 use storages_common_table_meta::meta::ColumnStatistics;
 use storages_common_table_meta::meta::Compression;
+use storages_common_table_meta::meta::HistogramBucket;
 use storages_common_table_meta::meta::Statistics;
 
 use crate::storages::fuse::block_writer::BlockWriter;
@@ -556,3 +559,36 @@ fn test_reduce_block_meta() -> common_exception::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_build_column_histogram() -> common_exception::Result<()> {
+    let int = |v: i32| Scalar::Number(NumberScalar::Int32(v));
+
+    // 0..10 and another 20 rows of 7
+    let mut values: Vec<Scalar> = (0..10).map(int).collect();
+    values.extend(std::iter::repeat(int(7)).take(20));
+
+    // each sampled value stands for 2 rows
+    let histogram = build_column_histogram(values, 2.0, None, 3, 10);
+    let bucket = |v: i32, num_values: f64, num_distinct: f64| HistogramBucket {
+        upper_bound: int(v),
+        num_values,
+        num_distinct,
+    };
+    assert_eq!(
+        Some(ColumnHistogram {
+            buckets: vec![
+                bucket(0, 2.0, 1.0),
+                bucket(7, 54.0, 7.0),
+                bucket(9, 4.0, 2.0)
+            ],
+            most_common_values: vec![(int(7), 42.0)],
+        }),
+        histogram
+    );
+
+    // no values sampled
+    assert_eq!(None, build_column_histogram(vec![], 1.0, None, 3, 10));
+
+    Ok(())
+}
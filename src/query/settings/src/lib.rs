@@ -287,6 +287,46 @@ impl Settings {
                 desc: "The threshold of keys to open two-level aggregation, default value: 20000.",
                 possible_values: None,
             },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(0),
+                user_setting: UserSetting::create(
+                    "aggregate_spilling_bytes_threshold",
+                    UserSettingValue::UInt64(0),
+                ),
+                level: ScopeLevel::Session,
+                desc: "The memory threshold in bytes of each aggregator to spill the states to disk, 0 means never spill, default value: 0.",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(0),
+                user_setting: UserSetting::create(
+                    "sort_spilling_bytes_threshold",
+                    UserSettingValue::UInt64(0),
+                ),
+                level: ScopeLevel::Session,
+                desc: "The memory threshold in bytes of each sorter to spill sorted runs to disk, 0 means never spill, default value: 0.",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(0),
+                user_setting: UserSetting::create(
+                    "join_spilling_bytes_threshold",
+                    UserSettingValue::UInt64(0),
+                ),
+                level: ScopeLevel::Session,
+                desc: "The memory threshold in bytes of the build side of each hash join to spill both sides to disk, 0 means never spill, default value: 0.",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(0),
+                user_setting: UserSetting::create(
+                    "cte_spilling_bytes_threshold",
+                    UserSettingValue::UInt64(0),
+                ),
+                level: ScopeLevel::Session,
+                desc: "The memory threshold in bytes of the buffered result of each materialized CTE to spill the rest to disk, 0 means never spill, default value: 0.",
+                possible_values: None,
+            },
             SettingValue {
                 default_value: UserSettingValue::UInt64(3),
                 user_setting: UserSetting::create("max_inlist_to_or", UserSettingValue::UInt64(3)),
@@ -369,6 +409,16 @@ impl Settings {
                 desc: "The maximum query execution time. it means no limit if the value is zero. default value: 0.",
                 possible_values: None,
             },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(1000),
+                user_setting: UserSetting::create(
+                    "max_recursive_cte_iterations",
+                    UserSettingValue::UInt64(1000),
+                ),
+                level: ScopeLevel::Session,
+                desc: "The maximum iterations of a recursive CTE before the query fails, default value: 1000.",
+                possible_values: None,
+            },
             SettingValue {
                 default_value: UserSettingValue::String("binary".to_owned()),
                 user_setting: UserSetting::create(
@@ -418,6 +468,16 @@ impl Settings {
                 desc: "If enable distributed eval index, default value: 1",
                 possible_values: None,
             },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(1),
+                user_setting: UserSetting::create(
+                    "enable_aggregating_index",
+                    UserSettingValue::UInt64(1),
+                ),
+                level: ScopeLevel::Session,
+                desc: "Whether to rewrite aggregations to read the aggregating indexes of the tables, default value: 1.",
+                possible_values: None,
+            },
             SettingValue {
                 default_value: UserSettingValue::UInt64(1),
                 user_setting: UserSetting::create(
@@ -485,6 +545,33 @@ impl Settings {
                 desc: "Enable generating bushy join plan in optimizer",
                 possible_values: None,
             },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(0),
+                user_setting: UserSetting::create("enable_dphyp", UserSettingValue::UInt64(0)),
+                level: ScopeLevel::Session,
+                desc: "Enable reordering joins with the DPhyp join enumerator in optimizer, default value: 0.",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(10),
+                user_setting: UserSetting::create(
+                    "join_reorder_dp_threshold",
+                    UserSettingValue::UInt64(10),
+                ),
+                level: ScopeLevel::Session,
+                desc: "The maximum number of relations in a join graph to be reordered by dynamic programming, larger join graphs are reordered greedily, default value: 10.",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(0),
+                user_setting: UserSetting::create(
+                    "enable_runtime_filter",
+                    UserSettingValue::UInt64(0),
+                ),
+                level: ScopeLevel::Session,
+                desc: "Enable pushing runtime filters derived from the build side of hash joins into the probe side table scans, default value: 0.",
+                possible_values: None,
+            },
             SettingValue {
                 default_value: UserSettingValue::UInt64(0),
                 user_setting: UserSetting::create(
@@ -691,6 +778,23 @@ impl Settings {
         self.try_get_u64(KEY)
     }
 
+    pub fn get_enable_dphyp(&self) -> Result<bool> {
+        static KEY: &str = "enable_dphyp";
+        let v = self.try_get_u64(KEY)?;
+        Ok(v != 0)
+    }
+
+    pub fn get_join_reorder_dp_threshold(&self) -> Result<u64> {
+        static KEY: &str = "join_reorder_dp_threshold";
+        self.try_get_u64(KEY)
+    }
+
+    pub fn get_enable_runtime_filter(&self) -> Result<bool> {
+        static KEY: &str = "enable_runtime_filter";
+        let v = self.try_get_u64(KEY)?;
+        Ok(v != 0)
+    }
+
     pub fn get_timezone(&self) -> Result<String> {
         let key = "timezone";
         self.check_and_get_setting_value(key)
@@ -709,6 +813,26 @@ impl Settings {
         self.try_set_u64(key, val, false)
     }
 
+    pub fn get_aggregate_spilling_bytes_threshold(&self) -> Result<u64> {
+        let key = "aggregate_spilling_bytes_threshold";
+        self.try_get_u64(key)
+    }
+
+    pub fn get_sort_spilling_bytes_threshold(&self) -> Result<u64> {
+        let key = "sort_spilling_bytes_threshold";
+        self.try_get_u64(key)
+    }
+
+    pub fn get_join_spilling_bytes_threshold(&self) -> Result<u64> {
+        let key = "join_spilling_bytes_threshold";
+        self.try_get_u64(key)
+    }
+
+    pub fn get_cte_spilling_bytes_threshold(&self) -> Result<u64> {
+        let key = "cte_spilling_bytes_threshold";
+        self.try_get_u64(key)
+    }
+
     pub fn get_max_inlist_to_or(&self) -> Result<u64> {
         let key = "max_inlist_to_or";
         self.try_get_u64(key)
@@ -774,6 +898,12 @@ impl Settings {
         Ok(v != 0)
     }
 
+    pub fn get_enable_aggregating_index(&self) -> Result<bool> {
+        static KEY: &str = "enable_aggregating_index";
+        let v = self.try_get_u64(KEY)?;
+        Ok(v != 0)
+    }
+
     pub fn get_max_result_rows(&self) -> Result<u64> {
         static KEY: &str = "max_result_rows";
         let v = self.try_get_u64(KEY)?;
@@ -883,6 +1013,11 @@ impl Settings {
         self.try_get_u64(key).map(|v| v != 0)
     }
 
+    pub fn get_max_recursive_cte_iterations(&self) -> Result<u64> {
+        let key = "max_recursive_cte_iterations";
+        self.try_get_u64(key)
+    }
+
     pub fn has_setting(&self, key: &str) -> bool {
         self.settings.get(key).is_some()
     }
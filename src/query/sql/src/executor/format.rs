@@ -19,19 +19,26 @@ use common_functions::scalars::BUILTIN_FUNCTIONS;
 use common_profile::ProfSpanSetRef;
 use itertools::Itertools;
 
+use super::AggregateExpand;
 use super::AggregateFinal;
 use super::AggregateFunctionDesc;
 use super::AggregatePartial;
+use super::CteScan;
 use super::EvalScalar;
 use super::Exchange;
 use super::Filter;
 use super::HashJoin;
 use super::Limit;
+use super::MaterializedCte;
 use super::PhysicalPlan;
 use super::Project;
+use super::ProjectSet;
+use super::RecursiveCte;
+use super::RecursiveCteScan;
 use super::Sort;
 use super::TableScan;
 use super::UnionAll;
+use super::Window;
 use crate::executor::explain::PlanStatsInfo;
 use crate::executor::DistributedInsertSelect;
 use crate::executor::ExchangeSink;
@@ -42,6 +49,7 @@ use crate::planner::DUMMY_TABLE_INDEX;
 use crate::BaseTableColumn;
 use crate::ColumnEntry;
 use crate::DerivedColumn;
+use crate::IndexType;
 
 impl PhysicalPlan {
     pub fn format(
@@ -63,6 +71,9 @@ fn to_format_tree(
         PhysicalPlan::Filter(plan) => filter_to_format_tree(plan, metadata, prof_span_set),
         PhysicalPlan::Project(plan) => project_to_format_tree(plan, metadata, prof_span_set),
         PhysicalPlan::EvalScalar(plan) => eval_scalar_to_format_tree(plan, metadata, prof_span_set),
+        PhysicalPlan::AggregateExpand(plan) => {
+            aggregate_expand_to_format_tree(plan, metadata, prof_span_set)
+        }
         PhysicalPlan::AggregatePartial(plan) => {
             aggregate_partial_to_format_tree(plan, metadata, prof_span_set)
         }
@@ -71,9 +82,19 @@ fn to_format_tree(
         }
         PhysicalPlan::Sort(plan) => sort_to_format_tree(plan, metadata, prof_span_set),
         PhysicalPlan::Limit(plan) => limit_to_format_tree(plan, metadata, prof_span_set),
+        PhysicalPlan::Window(plan) => window_to_format_tree(plan, metadata, prof_span_set),
+        PhysicalPlan::ProjectSet(plan) => project_set_to_format_tree(plan, metadata, prof_span_set),
         PhysicalPlan::HashJoin(plan) => hash_join_to_format_tree(plan, metadata, prof_span_set),
         PhysicalPlan::Exchange(plan) => exchange_to_format_tree(plan, metadata, prof_span_set),
         PhysicalPlan::UnionAll(plan) => union_all_to_format_tree(plan, metadata, prof_span_set),
+        PhysicalPlan::RecursiveCte(plan) => {
+            recursive_cte_to_format_tree(plan, metadata, prof_span_set)
+        }
+        PhysicalPlan::RecursiveCteScan(plan) => recursive_cte_scan_to_format_tree(plan),
+        PhysicalPlan::MaterializedCte(plan) => {
+            materialized_cte_to_format_tree(plan, metadata, prof_span_set)
+        }
+        PhysicalPlan::CteScan(plan) => cte_scan_to_format_tree(plan),
         PhysicalPlan::ExchangeSource(plan) => exchange_source_to_format_tree(plan),
         PhysicalPlan::ExchangeSink(plan) => {
             exchange_sink_to_format_tree(plan, metadata, prof_span_set)
@@ -275,6 +296,54 @@ pub fn pretty_display_agg_desc(desc: &AggregateFunctionDesc, metadata: &Metadata
     )
 }
 
+fn aggregate_expand_to_format_tree(
+    plan: &AggregateExpand,
+    metadata: &MetadataRef,
+    prof_span_set: &ProfSpanSetRef,
+) -> Result<FormatTreeNode<String>> {
+    let sets = plan
+        .grouping_sets
+        .iter()
+        .map(|set| {
+            set.iter()
+                .map(|column| {
+                    let column = metadata.read().column(*column).clone();
+                    match column {
+                        ColumnEntry::BaseTableColumn(BaseTableColumn { column_name, .. }) => {
+                            column_name
+                        }
+                        ColumnEntry::DerivedColumn(DerivedColumn { alias, .. }) => alias,
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(", ")
+        })
+        .map(|s| format!("({})", s))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut children = vec![FormatTreeNode::new(format!("grouping sets: [{sets}]"))];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
+        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
+        children.push(FormatTreeNode::new(format!(
+            "total process time: {process_time}ms"
+        )));
+    }
+
+    children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
+
+    Ok(FormatTreeNode::with_children(
+        "AggregateExpand".to_string(),
+        children,
+    ))
+}
+
 fn aggregate_partial_to_format_tree(
     plan: &AggregatePartial,
     metadata: &MetadataRef,
@@ -381,6 +450,110 @@ fn aggregate_final_to_format_tree(
     ))
 }
 
+fn window_to_format_tree(
+    plan: &Window,
+    metadata: &MetadataRef,
+    prof_span_set: &ProfSpanSetRef,
+) -> Result<FormatTreeNode<String>> {
+    let column_name = |index: IndexType| match metadata.read().column(index).clone() {
+        ColumnEntry::BaseTableColumn(BaseTableColumn { column_name, .. }) => column_name,
+        ColumnEntry::DerivedColumn(DerivedColumn { alias, .. }) => alias,
+    };
+
+    let partition_by = plan
+        .partition_by
+        .iter()
+        .map(|index| column_name(*index))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let order_by = plan
+        .order_by
+        .iter()
+        .map(|sort_key| {
+            format!(
+                "{} {} {}",
+                column_name(sort_key.order_by),
+                if sort_key.asc { "ASC" } else { "DESC" },
+                if sort_key.nulls_first {
+                    "NULLS FIRST"
+                } else {
+                    "NULLS LAST"
+                }
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut children = vec![
+        FormatTreeNode::new(format!("window function: [{}]", column_name(plan.index))),
+        FormatTreeNode::new(format!("partition by: [{partition_by}]")),
+        FormatTreeNode::new(format!("order by: [{order_by}]")),
+        FormatTreeNode::new(format!("frame: [{}]", plan.window_frame)),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
+        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
+        children.push(FormatTreeNode::new(format!(
+            "total process time: {process_time}ms"
+        )));
+    }
+
+    children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
+
+    Ok(FormatTreeNode::with_children(
+        "Window".to_string(),
+        children,
+    ))
+}
+
+fn project_set_to_format_tree(
+    plan: &ProjectSet,
+    metadata: &MetadataRef,
+    prof_span_set: &ProfSpanSetRef,
+) -> Result<FormatTreeNode<String>> {
+    let column_name = |index: IndexType| match metadata.read().column(index).clone() {
+        ColumnEntry::BaseTableColumn(BaseTableColumn { column_name, .. }) => column_name,
+        ColumnEntry::DerivedColumn(DerivedColumn { alias, .. }) => alias,
+    };
+
+    let columns = plan
+        .columns
+        .iter()
+        .map(|(index, _)| column_name(*index))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut children = vec![
+        FormatTreeNode::new(format!("set-returning function: [{}]", plan.func_name)),
+        FormatTreeNode::new(format!("output columns: [{columns}]")),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
+        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
+        children.push(FormatTreeNode::new(format!(
+            "total process time: {process_time}ms"
+        )));
+    }
+
+    children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
+
+    Ok(FormatTreeNode::with_children(
+        "ProjectSet".to_string(),
+        children,
+    ))
+}
+
 fn sort_to_format_tree(
     plan: &Sort,
     metadata: &MetadataRef,
@@ -497,6 +670,22 @@ fn hash_join_to_format_tree(
         FormatTreeNode::new(format!("filters: [{filters}]")),
     ];
 
+    if !plan.runtime_filter_targets.is_empty() {
+        let runtime_filters = plan
+            .runtime_filter_targets
+            .iter()
+            .map(|target| {
+                plan.probe_keys[target.key_index]
+                    .as_expr(&BUILTIN_FUNCTIONS)
+                    .sql_display()
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        children.push(FormatTreeNode::new(format!(
+            "runtime filters: [{runtime_filters}]"
+        )));
+    }
+
     if let Some(info) = &plan.stat_info {
         let items = plan_stats_info_to_format_tree(info);
         children.extend(items);
@@ -571,6 +760,90 @@ fn union_all_to_format_tree(
     ))
 }
 
+fn recursive_cte_to_format_tree(
+    plan: &RecursiveCte,
+    metadata: &MetadataRef,
+    prof_span_set: &ProfSpanSetRef,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![
+        FormatTreeNode::new(format!("cte name: {}", plan.cte_name)),
+        FormatTreeNode::new(format!("distinct: {}", plan.distinct)),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
+        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
+        children.push(FormatTreeNode::new(format!(
+            "total process time: {process_time}ms"
+        )));
+    }
+
+    children.extend(vec![
+        to_format_tree(&plan.anchor, metadata, prof_span_set)?,
+        to_format_tree(&plan.recursive, metadata, prof_span_set)?,
+    ]);
+
+    Ok(FormatTreeNode::with_children(
+        "RecursiveCte".to_string(),
+        children,
+    ))
+}
+
+fn recursive_cte_scan_to_format_tree(plan: &RecursiveCteScan) -> Result<FormatTreeNode<String>> {
+    Ok(FormatTreeNode::with_children(
+        "RecursiveCteScan".to_string(),
+        vec![FormatTreeNode::new(format!("cte name: {}", plan.cte_name))],
+    ))
+}
+
+fn materialized_cte_to_format_tree(
+    plan: &MaterializedCte,
+    metadata: &MetadataRef,
+    prof_span_set: &ProfSpanSetRef,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![FormatTreeNode::new(format!("cte name: {}", plan.cte_name))];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
+        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
+        children.push(FormatTreeNode::new(format!(
+            "total process time: {process_time}ms"
+        )));
+    }
+
+    children.extend(vec![
+        to_format_tree(&plan.left, metadata, prof_span_set)?,
+        to_format_tree(&plan.right, metadata, prof_span_set)?,
+    ]);
+
+    Ok(FormatTreeNode::with_children(
+        "MaterializedCte".to_string(),
+        children,
+    ))
+}
+
+fn cte_scan_to_format_tree(plan: &CteScan) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![FormatTreeNode::new(format!("cte name: {}", plan.cte_name))];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    Ok(FormatTreeNode::with_children(
+        "CteScan".to_string(),
+        children,
+    ))
+}
+
 fn part_stats_info_to_format_tree(info: &PartStatistics) -> Vec<FormatTreeNode<String>> {
     let mut items = vec![
         FormatTreeNode::new(format!("read rows: {}", info.read_rows)),
@@ -13,10 +13,13 @@
 // limitations under the License.
 
 use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
 
 use common_catalog::plan::DataSourcePlan;
 use common_exception::Result;
 use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
 use common_expression::DataBlock;
 use common_expression::DataField;
 use common_expression::DataSchemaRef;
@@ -29,6 +32,7 @@ use common_meta_app::schema::TableInfo;
 use crate::executor::explain::PlanStatsInfo;
 use crate::optimizer::ColumnSet;
 use crate::plans::JoinType;
+use crate::plans::WindowFuncFrame;
 use crate::ColumnBinding;
 use crate::IndexType;
 
@@ -133,6 +137,45 @@ impl EvalScalar {
     }
 }
 
+/// Expands each input row once for each grouping set, the group items which are not
+/// in the grouping set are replaced with NULL.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct AggregateExpand {
+    /// A unique id of operator in a `PhysicalPlan` tree.
+    /// Only used for display.
+    pub plan_id: u32,
+
+    pub input: Box<PhysicalPlan>,
+    pub group_bys: Vec<IndexType>,
+    pub grouping_id_index: IndexType,
+    pub grouping_sets: Vec<Vec<IndexType>>,
+    /// Pairs of the group item and its duplicated column, which keeps the original values.
+    pub dup_group_items: Vec<(IndexType, IndexType)>,
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl AggregateExpand {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let input_schema = self.input.output_schema()?;
+        let mut fields = input_schema.fields().clone();
+        for group_by in self.group_bys.iter() {
+            let offset = input_schema.index_of(&group_by.to_string())?;
+            let field = &fields[offset];
+            fields[offset] = DataField::new(field.name(), field.data_type().wrap_nullable());
+        }
+        for (group_by, dup) in self.dup_group_items.iter() {
+            let field = input_schema.field_with_name(&group_by.to_string())?;
+            fields.push(DataField::new(&dup.to_string(), field.data_type().clone()));
+        }
+        fields.push(DataField::new(
+            &self.grouping_id_index.to_string(),
+            DataType::Number(NumberDataType::UInt32),
+        ));
+        Ok(DataSchemaRefExt::create(fields))
+    }
+}
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct AggregatePartial {
     /// A unique id of operator in a `PhysicalPlan` tree.
@@ -231,6 +274,133 @@ impl Sort {
     }
 }
 
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct Window {
+    /// A unique id of operator in a `PhysicalPlan` tree.
+    /// Only used for display.
+    pub plan_id: u32,
+
+    /// Output column of the window function
+    pub index: IndexType,
+    pub input: Box<PhysicalPlan>,
+    pub func: WindowFunction,
+    pub partition_by: Vec<IndexType>,
+    pub order_by: Vec<SortDesc>,
+    pub window_frame: WindowFuncFrame,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl Window {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let input_schema = self.input.output_schema()?;
+        let mut fields = input_schema.fields().clone();
+        fields.push(DataField::new(
+            &self.index.to_string(),
+            self.func.data_type(),
+        ));
+        Ok(DataSchemaRefExt::create(fields))
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub enum WindowFunction {
+    Aggregate(AggregateFunctionDesc),
+    RowNumber,
+    Rank,
+    DenseRank,
+    PercentRank,
+    CumeDist,
+    LagLead(LagLeadDesc),
+    NthValue(NthValueDesc),
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct LagLeadDesc {
+    pub is_lag: bool,
+    pub offset: u64,
+    /// Offset of the argument in the input block.
+    pub arg: usize,
+    /// Offset of the default value in the input block.
+    pub default: Option<usize>,
+    pub return_type: DataType,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct NthValueDesc {
+    /// The n-th row of the frame counted from 1, `None` is the last row.
+    pub n: Option<u64>,
+    /// Offset of the argument in the input block.
+    pub arg: usize,
+    pub return_type: DataType,
+}
+
+impl WindowFunction {
+    pub fn data_type(&self) -> DataType {
+        match self {
+            WindowFunction::Aggregate(agg) => agg.sig.return_type.clone(),
+            WindowFunction::RowNumber | WindowFunction::Rank | WindowFunction::DenseRank => {
+                DataType::Number(NumberDataType::UInt64)
+            }
+            WindowFunction::PercentRank | WindowFunction::CumeDist => {
+                DataType::Number(NumberDataType::Float64)
+            }
+            WindowFunction::LagLead(lag_lead) => lag_lead.return_type.clone(),
+            WindowFunction::NthValue(nth_value) => nth_value.return_type.clone(),
+        }
+    }
+}
+
+impl Display for WindowFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WindowFunction::Aggregate(agg) => write!(f, "{}", agg.sig.name),
+            WindowFunction::RowNumber => write!(f, "row_number"),
+            WindowFunction::Rank => write!(f, "rank"),
+            WindowFunction::DenseRank => write!(f, "dense_rank"),
+            WindowFunction::PercentRank => write!(f, "percent_rank"),
+            WindowFunction::CumeDist => write!(f, "cume_dist"),
+            WindowFunction::LagLead(lag_lead) if lag_lead.is_lag => write!(f, "lag"),
+            WindowFunction::LagLead(_) => write!(f, "lead"),
+            WindowFunction::NthValue(nth_value) => match nth_value.n {
+                Some(1) => write!(f, "first_value"),
+                Some(_) => write!(f, "nth_value"),
+                None => write!(f, "last_value"),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ProjectSet {
+    /// A unique id of operator in a `PhysicalPlan` tree.
+    /// Only used for display.
+    pub plan_id: u32,
+
+    pub input: Box<PhysicalPlan>,
+    /// Name of the set-returning function
+    pub func_name: String,
+    /// Offsets of the arguments in the input
+    pub arguments: Vec<usize>,
+    /// Output columns of the function
+    pub columns: Vec<(IndexType, DataType)>,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl ProjectSet {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let input_schema = self.input.output_schema()?;
+        let mut fields = input_schema.fields().clone();
+        for (index, data_type) in self.columns.iter() {
+            fields.push(DataField::new(&index.to_string(), data_type.clone()));
+        }
+        Ok(DataSchemaRefExt::create(fields))
+    }
+}
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct Limit {
     /// A unique id of operator in a `PhysicalPlan` tree.
@@ -265,11 +435,29 @@ pub struct HashJoin {
     pub join_type: JoinType,
     pub marker_index: Option<IndexType>,
     pub from_correlated_subquery: bool,
+    /// The probe side table scans that the runtime filters derived from the build keys
+    /// can be pushed down to.
+    pub runtime_filter_targets: Vec<RuntimeFilterTarget>,
 
     /// Only used for explain
     pub stat_info: Option<PlanStatsInfo>,
 }
 
+/// A table scan on the probe side of a hash join, the runtime filter derived from
+/// a build key of the join is pushed down to it once the hash table is built.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct RuntimeFilterTarget {
+    /// The index of the join key.
+    pub key_index: usize,
+    /// The `scan_id` of the table scan.
+    pub scan_id: usize,
+    /// The name of the table column referred by the probe key.
+    pub column_name: String,
+    /// Whether the probe side is shuffled across the cluster. The build side is shuffled
+    /// as well, so the runtime filter must be merged from all the nodes before pushed down.
+    pub is_global: bool,
+}
+
 impl HashJoin {
     pub fn output_schema(&self) -> Result<DataSchemaRef> {
         let mut fields = self.probe.output_schema()?.fields().clone();
@@ -433,6 +621,95 @@ impl UnionAll {
     }
 }
 
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct RecursiveCte {
+    /// A unique id of operator in a `PhysicalPlan` tree.
+    /// Only used for display.
+    pub plan_id: u32,
+
+    pub cte_name: String,
+    /// The non-recursive term.
+    pub anchor: Box<PhysicalPlan>,
+    /// The recursive term, executed repeatedly until it produces no new rows.
+    pub recursive: Box<PhysicalPlan>,
+    pub pairs: Vec<(String, String)>,
+    pub schema: DataSchemaRef,
+    pub distinct: bool,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl RecursiveCte {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.schema.clone())
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct RecursiveCteScan {
+    /// A unique id of operator in a `PhysicalPlan` tree.
+    /// Only used for display.
+    pub plan_id: u32,
+
+    pub cte_name: String,
+    pub schema: DataSchemaRef,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl RecursiveCteScan {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.schema.clone())
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct MaterializedCte {
+    /// A unique id of operator in a `PhysicalPlan` tree.
+    /// Only used for display.
+    pub plan_id: u32,
+
+    pub cte_idx: IndexType,
+    pub cte_name: String,
+    /// The definition of the CTE, executed only once.
+    pub left: Box<PhysicalPlan>,
+    /// The query reading the CTE.
+    pub right: Box<PhysicalPlan>,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl MaterializedCte {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        self.right.output_schema()
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct CteScan {
+    /// A unique id of operator in a `PhysicalPlan` tree.
+    /// Only used for display.
+    pub plan_id: u32,
+
+    pub cte_idx: IndexType,
+    pub cte_name: String,
+    /// Names of the columns in the result of the CTE, read as the fields of `schema`.
+    pub cte_columns: Vec<String>,
+    pub schema: DataSchemaRef,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl CteScan {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.schema.clone())
+    }
+}
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct DistributedInsertSelect {
     pub input: Box<PhysicalPlan>,
@@ -459,13 +736,20 @@ pub enum PhysicalPlan {
     Filter(Filter),
     Project(Project),
     EvalScalar(EvalScalar),
+    AggregateExpand(AggregateExpand),
     AggregatePartial(AggregatePartial),
     AggregateFinal(AggregateFinal),
     Sort(Sort),
     Limit(Limit),
+    Window(Window),
+    ProjectSet(ProjectSet),
     HashJoin(HashJoin),
     Exchange(Exchange),
     UnionAll(UnionAll),
+    RecursiveCte(RecursiveCte),
+    RecursiveCteScan(RecursiveCteScan),
+    MaterializedCte(MaterializedCte),
+    CteScan(CteScan),
 
     /// For insert into ... select ... in cluster
     DistributedInsertSelect(Box<DistributedInsertSelect>),
@@ -490,15 +774,22 @@ impl PhysicalPlan {
             PhysicalPlan::Filter(plan) => plan.output_schema(),
             PhysicalPlan::Project(plan) => plan.output_schema(),
             PhysicalPlan::EvalScalar(plan) => plan.output_schema(),
+            PhysicalPlan::AggregateExpand(plan) => plan.output_schema(),
             PhysicalPlan::AggregatePartial(plan) => plan.output_schema(),
             PhysicalPlan::AggregateFinal(plan) => plan.output_schema(),
             PhysicalPlan::Sort(plan) => plan.output_schema(),
             PhysicalPlan::Limit(plan) => plan.output_schema(),
+            PhysicalPlan::Window(plan) => plan.output_schema(),
+            PhysicalPlan::ProjectSet(plan) => plan.output_schema(),
             PhysicalPlan::HashJoin(plan) => plan.output_schema(),
             PhysicalPlan::Exchange(plan) => plan.output_schema(),
             PhysicalPlan::ExchangeSource(plan) => plan.output_schema(),
             PhysicalPlan::ExchangeSink(plan) => plan.output_schema(),
             PhysicalPlan::UnionAll(plan) => plan.output_schema(),
+            PhysicalPlan::RecursiveCte(plan) => plan.output_schema(),
+            PhysicalPlan::RecursiveCteScan(plan) => plan.output_schema(),
+            PhysicalPlan::MaterializedCte(plan) => plan.output_schema(),
+            PhysicalPlan::CteScan(plan) => plan.output_schema(),
             PhysicalPlan::DistributedInsertSelect(plan) => plan.output_schema(),
         }
     }
@@ -509,13 +800,20 @@ impl PhysicalPlan {
             PhysicalPlan::Filter(_) => "Filter".to_string(),
             PhysicalPlan::Project(_) => "Project".to_string(),
             PhysicalPlan::EvalScalar(_) => "EvalScalar".to_string(),
+            PhysicalPlan::AggregateExpand(_) => "AggregateExpand".to_string(),
             PhysicalPlan::AggregatePartial(_) => "AggregatePartial".to_string(),
             PhysicalPlan::AggregateFinal(_) => "AggregateFinal".to_string(),
             PhysicalPlan::Sort(_) => "Sort".to_string(),
             PhysicalPlan::Limit(_) => "Limit".to_string(),
+            PhysicalPlan::Window(_) => "Window".to_string(),
+            PhysicalPlan::ProjectSet(_) => "ProjectSet".to_string(),
             PhysicalPlan::HashJoin(_) => "HashJoin".to_string(),
             PhysicalPlan::Exchange(_) => "Exchange".to_string(),
             PhysicalPlan::UnionAll(_) => "UnionAll".to_string(),
+            PhysicalPlan::RecursiveCte(_) => "RecursiveCte".to_string(),
+            PhysicalPlan::RecursiveCteScan(_) => "RecursiveCteScan".to_string(),
+            PhysicalPlan::MaterializedCte(_) => "MaterializedCte".to_string(),
+            PhysicalPlan::CteScan(_) => "CteScan".to_string(),
             PhysicalPlan::DistributedInsertSelect(_) => "DistributedInsertSelect".to_string(),
             PhysicalPlan::ExchangeSource(_) => "Exchange Source".to_string(),
             PhysicalPlan::ExchangeSink(_) => "Exchange Sink".to_string(),
@@ -528,10 +826,13 @@ impl PhysicalPlan {
             PhysicalPlan::Filter(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::Project(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::EvalScalar(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::AggregateExpand(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::AggregatePartial(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::AggregateFinal(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::Sort(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::Limit(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::Window(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::ProjectSet(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::HashJoin(plan) => Box::new(
                 std::iter::once(plan.probe.as_ref()).chain(std::iter::once(plan.build.as_ref())),
             ),
@@ -541,6 +842,15 @@ impl PhysicalPlan {
             PhysicalPlan::UnionAll(plan) => Box::new(
                 std::iter::once(plan.left.as_ref()).chain(std::iter::once(plan.right.as_ref())),
             ),
+            PhysicalPlan::RecursiveCte(plan) => Box::new(
+                std::iter::once(plan.anchor.as_ref())
+                    .chain(std::iter::once(plan.recursive.as_ref())),
+            ),
+            PhysicalPlan::RecursiveCteScan(_) => Box::new(std::iter::empty()),
+            PhysicalPlan::MaterializedCte(plan) => Box::new(
+                std::iter::once(plan.left.as_ref()).chain(std::iter::once(plan.right.as_ref())),
+            ),
+            PhysicalPlan::CteScan(_) => Box::new(std::iter::empty()),
             PhysicalPlan::DistributedInsertSelect(plan) => {
                 Box::new(std::iter::once(plan.input.as_ref()))
             }
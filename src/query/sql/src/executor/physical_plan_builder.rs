@@ -28,6 +28,7 @@ use common_expression::type_check::check_function;
 use common_expression::types::DataType;
 use common_expression::ConstantFolder;
 use common_expression::DataBlock;
+use common_expression::DataField;
 use common_expression::DataSchemaRefExt;
 use common_expression::Expr;
 use common_expression::RemoteExpr;
@@ -36,16 +37,27 @@ use common_functions::scalars::BUILTIN_FUNCTIONS;
 use itertools::Itertools;
 
 use super::cast_expr_to_non_null_boolean;
+use super::AggregateExpand;
 use super::AggregateFinal;
 use super::AggregateFunctionDesc;
 use super::AggregateFunctionSignature;
 use super::AggregatePartial;
+use super::CteScan as PhysicalCteScan;
 use super::Exchange as PhysicalExchange;
 use super::Filter;
 use super::HashJoin;
+use super::LagLeadDesc;
 use super::Limit;
+use super::MaterializedCte as PhysicalMaterializedCte;
+use super::NthValueDesc;
+use super::ProjectSet;
+use super::RecursiveCte as PhysicalRecursiveCte;
+use super::RecursiveCteScan as PhysicalRecursiveCteScan;
+use super::RuntimeFilterTarget;
 use super::Sort;
 use super::TableScan;
+use super::Window;
+use super::WindowFunction;
 use crate::executor::explain::PlanStatsInfo;
 use crate::executor::table_read_plan::ToReadDataSourcePlan;
 use crate::executor::EvalScalar;
@@ -59,9 +71,12 @@ use crate::optimizer::SExpr;
 use crate::plans::AggregateMode;
 use crate::plans::AndExpr;
 use crate::plans::Exchange;
+use crate::plans::Join;
+use crate::plans::JoinType;
 use crate::plans::RelOperator;
 use crate::plans::ScalarExpr;
 use crate::plans::Scan;
+use crate::plans::WindowFuncType;
 use crate::BaseTableColumn;
 use crate::ColumnEntry;
 use crate::DerivedColumn;
@@ -185,13 +200,14 @@ impl PhysicalPlanBuilder {
 
                 let push_downs = self.push_downs(scan, &table_schema, has_inner_column)?;
 
-                let source = table
+                let mut source = table
                     .read_plan_with_catalog(
                         self.ctx.clone(),
                         table_entry.catalog().to_string(),
                         Some(push_downs),
                     )
                     .await?;
+                source.scan_id = scan.table_index;
 
                 Ok(PhysicalPlan::TableScan(TableScan {
                     plan_id: self.next_plan_id(),
@@ -235,6 +251,7 @@ impl PhysicalPlanBuilder {
                         .cloned()
                         .collect::<Vec<_>>(),
                 );
+                let runtime_filter_targets = self.runtime_filter_targets(join, &probe_side)?;
                 Ok(PhysicalPlan::HashJoin(HashJoin {
                     plan_id: self.next_plan_id(),
                     build: Box::new(build_side),
@@ -296,6 +313,7 @@ impl PhysicalPlanBuilder {
                         .collect::<Result<_>>()?,
                     marker_index: join.marker_index,
                     from_correlated_subquery: join.from_correlated_subquery,
+                    runtime_filter_targets,
 
                     stat_info: Some(stat_info),
                 }))
@@ -361,7 +379,39 @@ impl PhysicalPlanBuilder {
                 }))
             }
             RelOperator::Aggregate(agg) => {
-                let input = self.build(s_expr.child(0)?).await?;
+                let mut input = self.build(s_expr.child(0)?).await?;
+                if let (AggregateMode::Partial, Some(grouping_sets)) =
+                    (&agg.mode, &agg.grouping_sets)
+                {
+                    // Expand each row once per grouping set before the partial aggregation,
+                    // the exchange (if any) must stay on top of the expanded rows.
+                    let expand = |builder: &mut Self, input: Box<PhysicalPlan>| {
+                        PhysicalPlan::AggregateExpand(AggregateExpand {
+                            plan_id: builder.next_plan_id(),
+                            input,
+                            group_bys: agg
+                                .group_items
+                                .iter()
+                                .map(|item| item.index)
+                                .filter(|index| *index != grouping_sets.grouping_id_index)
+                                .collect(),
+                            grouping_id_index: grouping_sets.grouping_id_index,
+                            grouping_sets: grouping_sets.sets.clone(),
+                            dup_group_items: grouping_sets.dup_group_items.clone(),
+                            stat_info: Some(stat_info.clone()),
+                        })
+                    };
+                    input = match input {
+                        PhysicalPlan::Exchange(PhysicalExchange { input, kind, keys }) => {
+                            PhysicalPlan::Exchange(PhysicalExchange {
+                                input: Box::new(expand(self, input)),
+                                kind,
+                                keys,
+                            })
+                        }
+                        _ => expand(self, Box::new(input)),
+                    };
+                }
                 let input_schema = input.output_schema()?;
                 let group_items = agg.group_items.iter().map(|v| v.index).collect::<Vec<_>>();
 
@@ -569,6 +619,115 @@ impl PhysicalPlanBuilder {
 
                 stat_info: Some(stat_info),
             })),
+            RelOperator::Window(w) => {
+                let input = self.build(s_expr.child(0)?).await?;
+                let input_schema = input.output_schema()?;
+
+                // Window function arguments are replaced with `BoundColumnRef` by binder.
+                let offset_of = |arg: &ScalarExpr| match arg {
+                    ScalarExpr::BoundColumnRef(col) => {
+                        input_schema.index_of(&col.column.index.to_string())
+                    }
+                    _ => Err(ErrorCode::Internal(
+                        "Window function argument must be a BoundColumnRef".to_string(),
+                    )),
+                };
+                let func = match &w.function {
+                    WindowFuncType::Aggregate(agg) => {
+                        let arg_indices = agg
+                            .args
+                            .iter()
+                            .map(|arg| {
+                                if let ScalarExpr::BoundColumnRef(col) = arg {
+                                    Ok(col.column.index)
+                                } else {
+                                    Err(ErrorCode::Internal(
+                                        "Window aggregate function argument must be a BoundColumnRef"
+                                            .to_string(),
+                                    ))
+                                }
+                            })
+                            .collect::<Result<Vec<_>>>()?;
+                        WindowFunction::Aggregate(AggregateFunctionDesc {
+                            sig: AggregateFunctionSignature {
+                                name: agg.func_name.clone(),
+                                args: agg.args.iter().map(|s| s.data_type()).collect(),
+                                params: agg.params.clone(),
+                                return_type: *agg.return_type.clone(),
+                            },
+                            output_column: w.index,
+                            args: arg_indices
+                                .iter()
+                                .map(|index| input_schema.index_of(&index.to_string()))
+                                .collect::<Result<_>>()?,
+                            arg_indices,
+                        })
+                    }
+                    WindowFuncType::RowNumber => WindowFunction::RowNumber,
+                    WindowFuncType::Rank => WindowFunction::Rank,
+                    WindowFuncType::DenseRank => WindowFunction::DenseRank,
+                    WindowFuncType::PercentRank => WindowFunction::PercentRank,
+                    WindowFuncType::CumeDist => WindowFunction::CumeDist,
+                    WindowFuncType::LagLead(lag_lead) => WindowFunction::LagLead(LagLeadDesc {
+                        is_lag: lag_lead.is_lag,
+                        offset: lag_lead.offset,
+                        arg: offset_of(&lag_lead.arg)?,
+                        default: lag_lead
+                            .default
+                            .as_ref()
+                            .map(|default| offset_of(default))
+                            .transpose()?,
+                        return_type: *lag_lead.return_type.clone(),
+                    }),
+                    WindowFuncType::NthValue(nth_value) => WindowFunction::NthValue(NthValueDesc {
+                        n: nth_value.n,
+                        arg: offset_of(&nth_value.arg)?,
+                        return_type: *nth_value.return_type.clone(),
+                    }),
+                };
+
+                let default_nulls_first =
+                    !self.ctx.get_settings().get_sql_dialect()?.is_null_biggest();
+
+                Ok(PhysicalPlan::Window(Window {
+                    plan_id: self.next_plan_id(),
+                    index: w.index,
+                    input: Box::new(input),
+                    func,
+                    partition_by: w.partition_by.iter().map(|v| v.index).collect(),
+                    order_by: w
+                        .order_by
+                        .iter()
+                        .map(|v| SortDesc {
+                            asc: v.asc.unwrap_or(true),
+                            nulls_first: v.nulls_first.unwrap_or(default_nulls_first),
+                            order_by: v.order_by_item.index,
+                        })
+                        .collect(),
+                    window_frame: w.frame.clone(),
+
+                    stat_info: Some(stat_info),
+                }))
+            }
+            RelOperator::ProjectSet(project_set) => {
+                let input = self.build(s_expr.child(0)?).await?;
+                let input_schema = input.output_schema()?;
+                let arguments = project_set
+                    .arguments
+                    .iter()
+                    .map(|item| input_schema.index_of(&item.index.to_string()))
+                    .collect::<Result<Vec<_>>>()?;
+
+                Ok(PhysicalPlan::ProjectSet(ProjectSet {
+                    plan_id: self.next_plan_id(),
+                    input: Box::new(input),
+                    func_name: project_set.func_name.clone(),
+                    arguments,
+                    columns: project_set.columns.clone(),
+
+                    stat_info: Some(stat_info),
+                }))
+            }
             RelOperator::Limit(limit) => Ok(PhysicalPlan::Limit(Limit {
                 plan_id: self.next_plan_id(),
                 input: Box::new(self.build(s_expr.child(0)?).await?),
@@ -631,6 +790,104 @@ impl PhysicalPlanBuilder {
                     stat_info: Some(stat_info),
                 }))
             }
+            RelOperator::RecursiveCte(op) => {
+                let anchor = self.build(s_expr.child(0)?).await?;
+                let anchor_schema = anchor.output_schema()?;
+                let pairs = op
+                    .pairs
+                    .iter()
+                    .map(|(l, r)| (l.to_string(), r.to_string()))
+                    .collect::<Vec<_>>();
+                let fields = pairs
+                    .iter()
+                    .zip(op.output_columns.iter())
+                    .map(|((left, _), index)| {
+                        let field = anchor_schema.field_with_name(left)?;
+                        Ok(DataField::new(
+                            &index.to_string(),
+                            field.data_type().clone(),
+                        ))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(PhysicalPlan::RecursiveCte(PhysicalRecursiveCte {
+                    plan_id: self.next_plan_id(),
+                    cte_name: op.cte_name.clone(),
+                    anchor: Box::new(anchor),
+                    recursive: Box::new(self.build(s_expr.child(1)?).await?),
+                    pairs,
+                    schema: DataSchemaRefExt::create(fields),
+                    distinct: op.distinct,
+
+                    stat_info: Some(stat_info),
+                }))
+            }
+            RelOperator::RecursiveCteScan(scan) => {
+                let metadata = self.metadata.read().clone();
+                let fields = scan
+                    .columns
+                    .iter()
+                    .map(|index| {
+                        let data_type = match metadata.column(*index) {
+                            ColumnEntry::DerivedColumn(DerivedColumn { data_type, .. }) => {
+                                data_type.clone()
+                            }
+                            _ => {
+                                return Err(ErrorCode::Internal(
+                                    "Recursive CTE column must be a derived column",
+                                ));
+                            }
+                        };
+                        Ok(DataField::new(&index.to_string(), data_type))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(PhysicalPlan::RecursiveCteScan(PhysicalRecursiveCteScan {
+                    plan_id: self.next_plan_id(),
+                    cte_name: scan.cte_name.clone(),
+                    schema: DataSchemaRefExt::create(fields),
+
+                    stat_info: Some(stat_info),
+                }))
+            }
+            RelOperator::MaterializedCte(cte) => {
+                Ok(PhysicalPlan::MaterializedCte(PhysicalMaterializedCte {
+                    plan_id: self.next_plan_id(),
+                    cte_idx: cte.cte_idx,
+                    cte_name: cte.cte_name.clone(),
+                    left: Box::new(self.build(s_expr.child(0)?).await?),
+                    right: Box::new(self.build(s_expr.child(1)?).await?),
+
+                    stat_info: Some(stat_info),
+                }))
+            }
+            RelOperator::CteScan(scan) => {
+                let metadata = self.metadata.read().clone();
+                let fields = scan
+                    .columns
+                    .iter()
+                    .map(|index| {
+                        let data_type = match metadata.column(*index) {
+                            ColumnEntry::DerivedColumn(DerivedColumn { data_type, .. }) => {
+                                data_type.clone()
+                            }
+                            _ => {
+                                return Err(ErrorCode::Internal(
+                                    "CTE column must be a derived column",
+                                ));
+                            }
+                        };
+                        Ok(DataField::new(&index.to_string(), data_type))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(PhysicalPlan::CteScan(PhysicalCteScan {
+                    plan_id: self.next_plan_id(),
+                    cte_idx: scan.cte_idx,
+                    cte_name: scan.cte_name.clone(),
+                    cte_columns: scan.cte_columns.iter().map(|c| c.to_string()).collect(),
+                    schema: DataSchemaRefExt::create(fields),
+
+                    stat_info: Some(stat_info),
+                }))
+            }
             _ => Err(ErrorCode::Internal(format!(
                 "Unsupported physical plan: {:?}",
                 s_expr.plan()
@@ -638,6 +895,69 @@ impl PhysicalPlanBuilder {
         }
     }
 
+    /// Find the probe side table scans that the runtime filters derived from the build keys
+    /// can be pushed down to. Only the probe keys referring to a base table column are
+    /// considered, and the runtime filters are only derived for the join types whose probe
+    /// rows without a match are discarded.
+    fn runtime_filter_targets(
+        &self,
+        join: &Join,
+        probe_side: &PhysicalPlan,
+    ) -> Result<Vec<RuntimeFilterTarget>> {
+        if !self.ctx.get_settings().get_enable_runtime_filter()?
+            || !matches!(
+                join.join_type,
+                JoinType::Inner
+                    | JoinType::Right
+                    | JoinType::RightSemi
+                    | JoinType::RightAnti
+                    | JoinType::LeftSemi
+            )
+        {
+            return Ok(vec![]);
+        }
+
+        let metadata = self.metadata.read();
+        let mut targets = vec![];
+        for (key_index, (left, right)) in join
+            .left_conditions
+            .iter()
+            .zip(join.right_conditions.iter())
+            .enumerate()
+        {
+            let column = match left {
+                ScalarExpr::BoundColumnRef(column_ref) => &column_ref.column,
+                _ => continue,
+            };
+            let data_type = column.data_type.remove_nullable();
+            if data_type != right.data_type().remove_nullable()
+                || !is_runtime_filter_supported_type(&data_type)
+            {
+                continue;
+            }
+
+            if let ColumnEntry::BaseTableColumn(BaseTableColumn {
+                table_index,
+                column_name,
+                path_indices: None,
+                ..
+            }) = metadata.column(column.index)
+            {
+                if let Some(is_global) =
+                    find_runtime_filter_scan(probe_side, *table_index, column.index)
+                {
+                    targets.push(RuntimeFilterTarget {
+                        key_index,
+                        scan_id: *table_index,
+                        column_name: column_name.clone(),
+                        is_global,
+                    });
+                }
+            }
+        }
+        Ok(targets)
+    }
+
     fn push_downs(
         &self,
         scan: &Scan,
@@ -788,3 +1108,44 @@ impl PhysicalPlanBuilder {
         })
     }
 }
+
+fn is_runtime_filter_supported_type(data_type: &DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Number(_) | DataType::String | DataType::Date | DataType::Timestamp
+    )
+}
+
+/// Find the table scan which outputs the column through the operators that a filter
+/// on the column can be pushed through. Returns whether a shuffle exchange is crossed.
+fn find_runtime_filter_scan(
+    plan: &PhysicalPlan,
+    table_index: IndexType,
+    column_index: IndexType,
+) -> Option<bool> {
+    match plan {
+        PhysicalPlan::TableScan(scan) => (scan.table_index == table_index
+            && scan
+                .name_mapping
+                .values()
+                .any(|index| *index == column_index))
+        .then_some(false),
+        PhysicalPlan::Filter(plan) => {
+            find_runtime_filter_scan(&plan.input, table_index, column_index)
+        }
+        PhysicalPlan::Project(plan) => {
+            find_runtime_filter_scan(&plan.input, table_index, column_index)
+        }
+        PhysicalPlan::EvalScalar(plan) => {
+            find_runtime_filter_scan(&plan.input, table_index, column_index)
+        }
+        PhysicalPlan::HashJoin(plan) => {
+            find_runtime_filter_scan(&plan.probe, table_index, column_index)
+                .or_else(|| find_runtime_filter_scan(&plan.build, table_index, column_index))
+        }
+        PhysicalPlan::Exchange(plan) if plan.kind == FragmentKind::Normal => {
+            find_runtime_filter_scan(&plan.input, table_index, column_index).map(|_| true)
+        }
+        _ => None,
+    }
+}
@@ -19,8 +19,10 @@ use common_functions::scalars::BUILTIN_FUNCTIONS;
 use itertools::Itertools;
 
 use super::DistributedInsertSelect;
+use crate::executor::AggregateExpand;
 use crate::executor::AggregateFinal;
 use crate::executor::AggregatePartial;
+use crate::executor::CteScan;
 use crate::executor::EvalScalar;
 use crate::executor::Exchange;
 use crate::executor::ExchangeSink;
@@ -28,11 +30,16 @@ use crate::executor::ExchangeSource;
 use crate::executor::Filter;
 use crate::executor::HashJoin;
 use crate::executor::Limit;
+use crate::executor::MaterializedCte;
 use crate::executor::PhysicalPlan;
 use crate::executor::Project;
+use crate::executor::ProjectSet;
+use crate::executor::RecursiveCte;
+use crate::executor::RecursiveCteScan;
 use crate::executor::Sort;
 use crate::executor::TableScan;
 use crate::executor::UnionAll;
+use crate::executor::Window;
 use crate::plans::JoinType;
 
 impl PhysicalPlan {
@@ -55,15 +62,22 @@ impl<'a> Display for PhysicalPlanIndentFormatDisplay<'a> {
             PhysicalPlan::Filter(filter) => write!(f, "{}", filter)?,
             PhysicalPlan::Project(project) => write!(f, "{}", project)?,
             PhysicalPlan::EvalScalar(eval_scalar) => write!(f, "{}", eval_scalar)?,
+            PhysicalPlan::AggregateExpand(expand) => write!(f, "{}", expand)?,
             PhysicalPlan::AggregatePartial(aggregate) => write!(f, "{}", aggregate)?,
             PhysicalPlan::AggregateFinal(aggregate) => write!(f, "{}", aggregate)?,
             PhysicalPlan::Sort(sort) => write!(f, "{}", sort)?,
             PhysicalPlan::Limit(limit) => write!(f, "{}", limit)?,
+            PhysicalPlan::Window(window) => write!(f, "{}", window)?,
+            PhysicalPlan::ProjectSet(project_set) => write!(f, "{}", project_set)?,
             PhysicalPlan::HashJoin(join) => write!(f, "{}", join)?,
             PhysicalPlan::Exchange(exchange) => write!(f, "{}", exchange)?,
             PhysicalPlan::ExchangeSource(source) => write!(f, "{}", source)?,
             PhysicalPlan::ExchangeSink(sink) => write!(f, "{}", sink)?,
             PhysicalPlan::UnionAll(union_all) => write!(f, "{}", union_all)?,
+            PhysicalPlan::RecursiveCte(cte) => write!(f, "{}", cte)?,
+            PhysicalPlan::RecursiveCteScan(scan) => write!(f, "{}", scan)?,
+            PhysicalPlan::MaterializedCte(cte) => write!(f, "{}", cte)?,
+            PhysicalPlan::CteScan(scan) => write!(f, "{}", scan)?,
             PhysicalPlan::DistributedInsertSelect(insert_select) => write!(f, "{}", insert_select)?,
         }
 
@@ -130,6 +144,46 @@ impl Display for Sort {
     }
 }
 
+impl Display for Window {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let partition_by = self
+            .partition_by
+            .iter()
+            .map(|index| index.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let order_by = self
+            .order_by
+            .iter()
+            .map(|item| {
+                format!(
+                    "{} {}",
+                    item.order_by,
+                    if item.asc { "ASC" } else { "DESC" }
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(
+            f,
+            "Window: [{}], Partition By: [{}], Order By: [{}], Frame: [{}]",
+            self.func, partition_by, order_by, self.window_frame
+        )
+    }
+}
+
+impl Display for ProjectSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let columns = self
+            .columns
+            .iter()
+            .map(|(index, _)| index.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(f, "ProjectSet: {} => [{}]", self.func_name, columns)
+    }
+}
+
 impl Display for EvalScalar {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let scalars = self
@@ -142,6 +196,18 @@ impl Display for EvalScalar {
     }
 }
 
+impl Display for AggregateExpand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let grouping_sets = self
+            .grouping_sets
+            .iter()
+            .map(|set| format!("({})", set.iter().map(|v| v.to_string()).join(", ")))
+            .join(", ");
+
+        write!(f, "Aggregate(Expand): grouping sets: [{}]", grouping_sets)
+    }
+}
+
 impl Display for AggregateFinal {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let group_items = self
@@ -291,6 +357,31 @@ impl Display for UnionAll {
     }
 }
 
+impl Display for RecursiveCte {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let kind = if self.distinct { "Union" } else { "UnionAll" };
+        write!(f, "RecursiveCte: {} [{}]", self.cte_name, kind)
+    }
+}
+
+impl Display for RecursiveCteScan {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RecursiveCteScan: {}", self.cte_name)
+    }
+}
+
+impl Display for MaterializedCte {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MaterializedCte: {}", self.cte_name)
+    }
+}
+
+impl Display for CteScan {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CteScan: {}", self.cte_name)
+    }
+}
+
 impl Display for DistributedInsertSelect {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "DistributedInsertSelect")
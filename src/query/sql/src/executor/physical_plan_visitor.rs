@@ -14,8 +14,10 @@
 
 use common_exception::Result;
 
+use super::AggregateExpand;
 use super::AggregateFinal;
 use super::AggregatePartial;
+use super::CteScan;
 use super::DistributedInsertSelect;
 use super::EvalScalar;
 use super::Exchange;
@@ -24,10 +26,15 @@ use super::ExchangeSource;
 use super::Filter;
 use super::HashJoin;
 use super::Limit;
+use super::MaterializedCte;
 use super::PhysicalPlan;
 use super::Project;
+use super::ProjectSet;
+use super::RecursiveCte;
+use super::RecursiveCteScan;
 use super::Sort;
 use super::TableScan;
+use super::Window;
 use crate::executor::UnionAll;
 
 pub trait PhysicalPlanReplacer {
@@ -37,15 +44,22 @@ pub trait PhysicalPlanReplacer {
             PhysicalPlan::Filter(plan) => self.replace_filter(plan),
             PhysicalPlan::Project(plan) => self.replace_project(plan),
             PhysicalPlan::EvalScalar(plan) => self.replace_eval_scalar(plan),
+            PhysicalPlan::AggregateExpand(plan) => self.replace_aggregate_expand(plan),
             PhysicalPlan::AggregatePartial(plan) => self.replace_aggregate_partial(plan),
             PhysicalPlan::AggregateFinal(plan) => self.replace_aggregate_final(plan),
             PhysicalPlan::Sort(plan) => self.replace_sort(plan),
             PhysicalPlan::Limit(plan) => self.replace_limit(plan),
+            PhysicalPlan::Window(plan) => self.replace_window(plan),
+            PhysicalPlan::ProjectSet(plan) => self.replace_project_set(plan),
             PhysicalPlan::HashJoin(plan) => self.replace_hash_join(plan),
             PhysicalPlan::Exchange(plan) => self.replace_exchange(plan),
             PhysicalPlan::ExchangeSource(plan) => self.replace_exchange_source(plan),
             PhysicalPlan::ExchangeSink(plan) => self.replace_exchange_sink(plan),
             PhysicalPlan::UnionAll(plan) => self.replace_union(plan),
+            PhysicalPlan::RecursiveCte(plan) => self.replace_recursive_cte(plan),
+            PhysicalPlan::RecursiveCteScan(plan) => self.replace_recursive_cte_scan(plan),
+            PhysicalPlan::MaterializedCte(plan) => self.replace_materialized_cte(plan),
+            PhysicalPlan::CteScan(plan) => self.replace_cte_scan(plan),
             PhysicalPlan::DistributedInsertSelect(plan) => self.replace_insert_select(plan),
         }
     }
@@ -88,6 +102,20 @@ pub trait PhysicalPlanReplacer {
         }))
     }
 
+    fn replace_aggregate_expand(&mut self, plan: &AggregateExpand) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+
+        Ok(PhysicalPlan::AggregateExpand(AggregateExpand {
+            plan_id: plan.plan_id,
+            input: Box::new(input),
+            group_bys: plan.group_bys.clone(),
+            grouping_id_index: plan.grouping_id_index,
+            grouping_sets: plan.grouping_sets.clone(),
+            dup_group_items: plan.dup_group_items.clone(),
+            stat_info: plan.stat_info.clone(),
+        }))
+    }
+
     fn replace_aggregate_partial(&mut self, plan: &AggregatePartial) -> Result<PhysicalPlan> {
         let input = self.replace(&plan.input)?;
 
@@ -128,6 +156,7 @@ pub trait PhysicalPlanReplacer {
             join_type: plan.join_type.clone(),
             marker_index: plan.marker_index,
             from_correlated_subquery: plan.from_correlated_subquery,
+            runtime_filter_targets: plan.runtime_filter_targets.clone(),
             stat_info: plan.stat_info.clone(),
         }))
     }
@@ -144,6 +173,34 @@ pub trait PhysicalPlanReplacer {
         }))
     }
 
+    fn replace_window(&mut self, plan: &Window) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+
+        Ok(PhysicalPlan::Window(Window {
+            plan_id: plan.plan_id,
+            index: plan.index,
+            input: Box::new(input),
+            func: plan.func.clone(),
+            partition_by: plan.partition_by.clone(),
+            order_by: plan.order_by.clone(),
+            window_frame: plan.window_frame.clone(),
+            stat_info: plan.stat_info.clone(),
+        }))
+    }
+
+    fn replace_project_set(&mut self, plan: &ProjectSet) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+
+        Ok(PhysicalPlan::ProjectSet(ProjectSet {
+            plan_id: plan.plan_id,
+            input: Box::new(input),
+            func_name: plan.func_name.clone(),
+            arguments: plan.arguments.clone(),
+            columns: plan.columns.clone(),
+            stat_info: plan.stat_info.clone(),
+        }))
+    }
+
     fn replace_limit(&mut self, plan: &Limit) -> Result<PhysicalPlan> {
         let input = self.replace(&plan.input)?;
 
@@ -197,6 +254,42 @@ pub trait PhysicalPlanReplacer {
         }))
     }
 
+    fn replace_recursive_cte(&mut self, plan: &RecursiveCte) -> Result<PhysicalPlan> {
+        let anchor = self.replace(&plan.anchor)?;
+        let recursive = self.replace(&plan.recursive)?;
+        Ok(PhysicalPlan::RecursiveCte(RecursiveCte {
+            plan_id: plan.plan_id,
+            cte_name: plan.cte_name.clone(),
+            anchor: Box::new(anchor),
+            recursive: Box::new(recursive),
+            pairs: plan.pairs.clone(),
+            schema: plan.schema.clone(),
+            distinct: plan.distinct,
+            stat_info: plan.stat_info.clone(),
+        }))
+    }
+
+    fn replace_recursive_cte_scan(&mut self, plan: &RecursiveCteScan) -> Result<PhysicalPlan> {
+        Ok(PhysicalPlan::RecursiveCteScan(plan.clone()))
+    }
+
+    fn replace_materialized_cte(&mut self, plan: &MaterializedCte) -> Result<PhysicalPlan> {
+        let left = self.replace(&plan.left)?;
+        let right = self.replace(&plan.right)?;
+        Ok(PhysicalPlan::MaterializedCte(MaterializedCte {
+            plan_id: plan.plan_id,
+            cte_idx: plan.cte_idx,
+            cte_name: plan.cte_name.clone(),
+            left: Box::new(left),
+            right: Box::new(right),
+            stat_info: plan.stat_info.clone(),
+        }))
+    }
+
+    fn replace_cte_scan(&mut self, plan: &CteScan) -> Result<PhysicalPlan> {
+        Ok(PhysicalPlan::CteScan(plan.clone()))
+    }
+
     fn replace_insert_select(&mut self, plan: &DistributedInsertSelect) -> Result<PhysicalPlan> {
         let input = self.replace(&plan.input)?;
 
@@ -234,6 +327,9 @@ impl PhysicalPlan {
                 PhysicalPlan::EvalScalar(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
+                PhysicalPlan::AggregateExpand(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
                 PhysicalPlan::AggregatePartial(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
@@ -246,6 +342,12 @@ impl PhysicalPlan {
                 PhysicalPlan::Limit(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
+                PhysicalPlan::Window(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::ProjectSet(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
                 PhysicalPlan::HashJoin(plan) => {
                     Self::traverse(&plan.build, pre_visit, visit, post_visit);
                     Self::traverse(&plan.probe, pre_visit, visit, post_visit);
@@ -261,6 +363,16 @@ impl PhysicalPlan {
                     Self::traverse(&plan.left, pre_visit, visit, post_visit);
                     Self::traverse(&plan.right, pre_visit, visit, post_visit);
                 }
+                PhysicalPlan::RecursiveCte(plan) => {
+                    Self::traverse(&plan.anchor, pre_visit, visit, post_visit);
+                    Self::traverse(&plan.recursive, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::RecursiveCteScan(_) => {}
+                PhysicalPlan::MaterializedCte(plan) => {
+                    Self::traverse(&plan.left, pre_visit, visit, post_visit);
+                    Self::traverse(&plan.right, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::CteScan(_) => {}
                 PhysicalPlan::DistributedInsertSelect(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
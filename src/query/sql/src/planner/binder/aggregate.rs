@@ -17,11 +17,14 @@ use std::collections::HashMap;
 use std::collections::HashSet;
 
 use common_ast::ast::Expr;
+use common_ast::ast::GroupBy;
 use common_ast::ast::Literal;
 use common_ast::ast::SelectTarget;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use itertools::Itertools;
 
 use super::prune_by_children;
 use crate::binder::scalar::ScalarBinder;
@@ -39,10 +42,14 @@ use crate::plans::CastExpr;
 use crate::plans::ComparisonExpr;
 use crate::plans::EvalScalar;
 use crate::plans::FunctionCall;
+use crate::plans::GroupingSets;
+use crate::plans::LambdaFunc;
 use crate::plans::NotExpr;
 use crate::plans::OrExpr;
 use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
+use crate::plans::WindowFunc;
+use crate::plans::WindowOrderBy;
 use crate::BindContext;
 use crate::MetadataRef;
 
@@ -73,6 +80,21 @@ pub struct AggregateInfo {
     /// TODO(leiysky): so far we are using `Debug` string of `Scalar` as identifier,
     /// maybe a more reasonable way is needed
     pub group_items_map: HashMap<String, usize>,
+
+    /// Grouping sets of `GROUP BY GROUPING SETS`, `ROLLUP` or `CUBE`.
+    pub grouping_sets: Option<GroupingSetsInfo>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct GroupingSetsInfo {
+    /// The virtual column `_grouping_id`, the k-th bit of which is set if the k-th
+    /// group item is not in the grouping set of the row.
+    pub grouping_id_column: ColumnBinding,
+    /// Each grouping set is a list of positions in `group_items`.
+    pub sets: Vec<Vec<usize>>,
+    /// The duplicated columns of `group_items`. The group items are set to NULL if they
+    /// are not in the grouping set, so the aggregate functions read the duplicated ones.
+    pub dup_group_items: Vec<ColumnBinding>,
 }
 
 pub(super) struct AggregateRewriter<'a> {
@@ -116,6 +138,14 @@ impl<'a> AggregateRewriter<'a> {
                 return_type: scalar.return_type.clone(),
             }
             .into()),
+            ScalarExpr::FunctionCall(func)
+                if func.func_name == "grouping"
+                    && self.bind_context.aggregate_info.grouping_sets.is_none() =>
+            {
+                Err(ErrorCode::SemanticError(
+                    "grouping can only be called in GROUP BY GROUPING SETS, ROLLUP or CUBE clause",
+                ))
+            }
             ScalarExpr::FunctionCall(func) => {
                 let new_args = func
                     .arguments
@@ -137,11 +167,62 @@ impl<'a> AggregateRewriter<'a> {
                 target_type: cast.target_type.clone(),
             }
             .into()),
+            ScalarExpr::LambdaFunction(lambda) => {
+                let new_args = lambda
+                    .args
+                    .iter()
+                    .map(|arg| self.visit(arg))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(LambdaFunc {
+                    func_name: lambda.func_name.clone(),
+                    args: new_args,
+                    lambda_expr: lambda.lambda_expr.clone(),
+                    lambda_display: lambda.lambda_display.clone(),
+                    return_type: lambda.return_type.clone(),
+                }
+                .into())
+            }
 
             // TODO(leiysky): should we recursively process subquery here?
             ScalarExpr::SubqueryExpr(_) => Ok(scalar.clone()),
 
             ScalarExpr::AggregateFunction(agg_func) => self.replace_aggregate_function(agg_func),
+
+            ScalarExpr::WindowFunction(window) => {
+                // The aggregate function of a window is evaluated over the window rather than
+                // the group, so we only rewrite the aggregates nested in the window function.
+                let args = window
+                    .func
+                    .arguments()
+                    .into_iter()
+                    .map(|arg| self.visit(arg))
+                    .collect::<Result<Vec<_>>>()?;
+                let func = window.func.with_arguments(args);
+                let partition_by = window
+                    .partition_by
+                    .iter()
+                    .map(|part| self.visit(part))
+                    .collect::<Result<Vec<_>>>()?;
+                let order_by = window
+                    .order_by
+                    .iter()
+                    .map(|order| {
+                        Ok(WindowOrderBy {
+                            expr: self.visit(&order.expr)?,
+                            asc: order.asc,
+                            nulls_first: order.nulls_first,
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(WindowFunc {
+                    display_name: window.display_name.clone(),
+                    func,
+                    partition_by,
+                    order_by,
+                    frame: window.frame.clone(),
+                }
+                .into())
+            }
         }
     }
 
@@ -154,7 +235,17 @@ impl<'a> AggregateRewriter<'a> {
         for (i, arg) in aggregate.args.iter().enumerate() {
             let name = format!("{}_arg_{}", &aggregate.func_name, i);
             if let ScalarExpr::BoundColumnRef(column_ref) = arg {
-                replaced_args.push(column_ref.clone().into());
+                let dup_column = agg_info.grouping_sets.as_ref().and_then(|grouping_sets| {
+                    agg_info
+                        .group_items
+                        .iter()
+                        .position(|item| item.index == column_ref.column.index)
+                        .map(|i| grouping_sets.dup_group_items[i].clone())
+                });
+                match dup_column {
+                    Some(column) => replaced_args.push(BoundColumnRef { column }.into()),
+                    None => replaced_args.push(column_ref.clone().into()),
+                }
                 agg_info.aggregate_arguments.push(ScalarItem {
                     index: column_ref.column.index,
                     scalar: arg.clone(),
@@ -242,11 +333,13 @@ impl Binder {
     ///     `SELECT a as b, COUNT(a) FROM t GROUP BY b`.
     ///   - Scalar expressions that can be evaluated in current scope(doesn't contain aliases), e.g.
     ///     column `a` and expression `a+1` in `SELECT a as b, COUNT(a) FROM t GROUP BY a, a+1`.
+    ///
+    /// `ROLLUP` and `CUBE` are expanded into the equivalent `GROUPING SETS`.
     pub async fn analyze_group_items<'a>(
         &mut self,
         bind_context: &mut BindContext,
         select_list: &SelectList<'a>,
-        group_by: &[Expr],
+        group_by: &GroupBy,
     ) -> Result<()> {
         let mut available_aliases = vec![];
 
@@ -269,8 +362,34 @@ impl Binder {
             }
         }
 
-        self.resolve_group_items(bind_context, select_list, group_by, &available_aliases)
-            .await
+        match group_by {
+            GroupBy::Normal(exprs) => {
+                self.resolve_group_items(bind_context, select_list, exprs, &available_aliases)
+                    .await
+            }
+            GroupBy::GroupingSets(sets) => {
+                self.resolve_grouping_sets(bind_context, select_list, sets, &available_aliases)
+                    .await
+            }
+            GroupBy::Rollup(exprs) => {
+                // ROLLUP (a, b) => GROUPING SETS ((a, b), (a), ())
+                let sets = (0..=exprs.len())
+                    .rev()
+                    .map(|i| exprs[0..i].to_vec())
+                    .collect::<Vec<_>>();
+                self.resolve_grouping_sets(bind_context, select_list, &sets, &available_aliases)
+                    .await
+            }
+            GroupBy::Cube(exprs) => {
+                // CUBE (a, b) => GROUPING SETS ((a, b), (a), (b), ())
+                let sets = (0..=exprs.len())
+                    .rev()
+                    .flat_map(|n| exprs.iter().cloned().combinations(n))
+                    .collect::<Vec<_>>();
+                self.resolve_grouping_sets(bind_context, select_list, &sets, &available_aliases)
+                    .await
+            }
+        }
     }
 
     pub(super) async fn bind_aggregate(
@@ -300,12 +419,62 @@ impl Binder {
             new_expr = SExpr::create_unary(eval_scalar.into(), new_expr);
         }
 
+        let agg_info = &bind_context.aggregate_info;
+        let (group_items, grouping_sets) = match &agg_info.grouping_sets {
+            Some(info) => {
+                // The group items become nullable after the rows are expanded, and the
+                // grouping id is also a group key to tell the grouping sets apart.
+                let mut group_items = agg_info
+                    .group_items
+                    .iter()
+                    .map(|item| ScalarItem {
+                        scalar: BoundColumnRef {
+                            column: ColumnBinding {
+                                database_name: None,
+                                table_name: None,
+                                column_name: "group_item".to_string(),
+                                index: item.index,
+                                data_type: Box::new(item.scalar.data_type().wrap_nullable()),
+                                visibility: Visibility::Visible,
+                            },
+                        }
+                        .into(),
+                        index: item.index,
+                    })
+                    .collect::<Vec<_>>();
+                group_items.push(ScalarItem {
+                    scalar: BoundColumnRef {
+                        column: info.grouping_id_column.clone(),
+                    }
+                    .into(),
+                    index: info.grouping_id_column.index,
+                });
+                let grouping_sets = GroupingSets {
+                    grouping_id_index: info.grouping_id_column.index,
+                    sets: info
+                        .sets
+                        .iter()
+                        .map(|set| set.iter().map(|i| agg_info.group_items[*i].index).collect())
+                        .collect(),
+                    dup_group_items: agg_info
+                        .group_items
+                        .iter()
+                        .zip(info.dup_group_items.iter())
+                        .map(|(item, dup)| (item.index, dup.index))
+                        .collect(),
+                };
+                (group_items, Some(grouping_sets))
+            }
+            None => (agg_info.group_items.clone(), None),
+        };
+
         let aggregate_plan = Aggregate {
             mode: AggregateMode::Initial,
-            group_items: bind_context.aggregate_info.group_items.clone(),
-            aggregate_functions: bind_context.aggregate_info.aggregate_functions.clone(),
+            group_items,
+            aggregate_functions: agg_info.aggregate_functions.clone(),
             from_distinct: false,
             limit: None,
+            grouping_sets,
         };
         new_expr = SExpr::create_unary(aggregate_plan.into(), new_expr);
 
@@ -319,78 +488,9 @@ impl Binder {
         group_by: &[Expr],
         available_aliases: &[(ColumnBinding, ScalarExpr)],
     ) -> Result<()> {
-        // Resolve group items with `FROM` context. Since the alias item can not be resolved
-        // from the context, we can detect the failure and fallback to resolving with `available_aliases`.
         for expr in group_by.iter() {
-            // If expr is a number literal, then this is a index group item.
-            if let Expr::Literal {
-                lit: Literal::Integer(index),
-                ..
-            } = expr
-            {
-                let (scalar, alias) = Self::resolve_index_item(expr, *index, select_list)?;
-                let key = format!("{:?}", &scalar);
-                if let Entry::Vacant(entry) = bind_context.aggregate_info.group_items_map.entry(key)
-                {
-                    // Add group item if it's not duplicated
-                    let column_binding = if let ScalarExpr::BoundColumnRef(ref column_ref) = scalar
-                    {
-                        column_ref.column.clone()
-                    } else {
-                        self.create_column_binding(None, None, alias, scalar.data_type())
-                    };
-                    bind_context.aggregate_info.group_items.push(ScalarItem {
-                        scalar,
-                        index: column_binding.index,
-                    });
-                    entry.insert(bind_context.aggregate_info.group_items.len() - 1);
-                }
-                continue;
-            }
-
-            // Resolve scalar item and alias item
-            let mut scalar_binder = ScalarBinder::new(
-                bind_context,
-                self.ctx.clone(),
-                &self.name_resolution_ctx,
-                self.metadata.clone(),
-                &[],
-            );
-            let (scalar_expr, data_type) = scalar_binder
-                .bind(expr)
-                .await
-                .or_else(|e| Self::resolve_alias_item(bind_context, expr, available_aliases, e))?;
-
-            if bind_context
-                .aggregate_info
-                .group_items_map
-                .get(&format!("{:?}", &scalar_expr))
-                .is_some()
-            {
-                // The group key is duplicated
-                continue;
-            }
-
-            let group_item_name = format!("{:#}", expr);
-            let index = if let ScalarExpr::BoundColumnRef(BoundColumnRef {
-                column: ColumnBinding { index, .. },
-            }) = &scalar_expr
-            {
-                *index
-            } else {
-                self.metadata
-                    .write()
-                    .add_derived_column(group_item_name.clone(), data_type.clone())
-            };
-
-            bind_context.aggregate_info.group_items.push(ScalarItem {
-                scalar: scalar_expr.clone(),
-                index,
-            });
-            bind_context.aggregate_info.group_items_map.insert(
-                format!("{:?}", &scalar_expr),
-                bind_context.aggregate_info.group_items.len() - 1,
-            );
+            self.resolve_group_item(bind_context, select_list, expr, available_aliases)
+                .await?;
         }
 
         // Remove dependent group items, group by a, f(a, b), f(a), b ---> group by a,b
@@ -421,6 +521,146 @@ impl Binder {
         Ok(())
     }
 
+    async fn resolve_grouping_sets(
+        &mut self,
+        bind_context: &mut BindContext,
+        select_list: &SelectList<'_>,
+        sets: &[Vec<Expr>],
+        available_aliases: &[(ColumnBinding, ScalarExpr)],
+    ) -> Result<()> {
+        // The dependent group items are kept, since they may be in different grouping sets.
+        let mut grouping_sets = Vec::with_capacity(sets.len());
+        for set in sets.iter() {
+            let mut grouping_set = Vec::with_capacity(set.len());
+            for expr in set.iter() {
+                let position = self
+                    .resolve_group_item(bind_context, select_list, expr, available_aliases)
+                    .await?;
+                if !grouping_set.contains(&position) {
+                    grouping_set.push(position);
+                }
+            }
+            grouping_set.sort();
+            grouping_sets.push(grouping_set);
+        }
+
+        let group_items = &bind_context.aggregate_info.group_items;
+        if group_items.len() > 32 {
+            return Err(ErrorCode::SemanticError(format!(
+                "Too many group items in grouping sets: {}, the maximum is 32",
+                group_items.len()
+            )));
+        }
+
+        let grouping_id_column = self.create_column_binding(
+            None,
+            None,
+            "_grouping_id".to_string(),
+            DataType::Number(NumberDataType::UInt32),
+        );
+        let dup_group_items = group_items
+            .iter()
+            .map(|item| {
+                let name = format!("_dup_group_item_{}", item.index);
+                self.create_column_binding(None, None, name, item.scalar.data_type())
+            })
+            .collect();
+
+        bind_context.aggregate_info.grouping_sets = Some(GroupingSetsInfo {
+            grouping_id_column,
+            sets: grouping_sets,
+            dup_group_items,
+        });
+        Ok(())
+    }
+
+    /// Resolve a group item and return its position in `group_items`.
+    async fn resolve_group_item(
+        &mut self,
+        bind_context: &mut BindContext,
+        select_list: &SelectList<'_>,
+        expr: &Expr,
+        available_aliases: &[(ColumnBinding, ScalarExpr)],
+    ) -> Result<usize> {
+        // If expr is a number literal, then this is a index group item.
+        if let Expr::Literal {
+            lit: Literal::Integer(index),
+            ..
+        } = expr
+        {
+            let (scalar, alias) = Self::resolve_index_item(expr, *index, select_list)?;
+            self.check_no_window_function(&scalar, "GROUP BY clause", expr.span())?;
+            let key = format!("{:?}", &scalar);
+            return match bind_context.aggregate_info.group_items_map.entry(key) {
+                Entry::Occupied(entry) => Ok(*entry.get()),
+                Entry::Vacant(entry) => {
+                    // Add group item if it's not duplicated
+                    let column_binding = if let ScalarExpr::BoundColumnRef(ref column_ref) = scalar
+                    {
+                        column_ref.column.clone()
+                    } else {
+                        self.create_column_binding(None, None, alias, scalar.data_type())
+                    };
+                    bind_context.aggregate_info.group_items.push(ScalarItem {
+                        scalar,
+                        index: column_binding.index,
+                    });
+                    let position = bind_context.aggregate_info.group_items.len() - 1;
+                    entry.insert(position);
+                    Ok(position)
+                }
+            };
+        }
+
+        // Resolve scalar item and alias item with `FROM` context. Since the alias item can not
+        // be resolved from the context, we can detect the failure and fallback to resolving
+        // with `available_aliases`.
+        let mut scalar_binder = ScalarBinder::new(
+            bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+        let (scalar_expr, data_type) = scalar_binder
+            .bind(expr)
+            .await
+            .or_else(|e| Self::resolve_alias_item(bind_context, expr, available_aliases, e))?;
+        self.check_no_window_function(&scalar_expr, "GROUP BY clause", expr.span())?;
+
+        if let Some(position) = bind_context
+            .aggregate_info
+            .group_items_map
+            .get(&format!("{:?}", &scalar_expr))
+        {
+            // The group key is duplicated
+            return Ok(*position);
+        }
+
+        let group_item_name = format!("{:#}", expr);
+        let index = if let ScalarExpr::BoundColumnRef(BoundColumnRef {
+            column: ColumnBinding { index, .. },
+        }) = &scalar_expr
+        {
+            *index
+        } else {
+            self.metadata
+                .write()
+                .add_derived_column(group_item_name.clone(), data_type.clone())
+        };
+
+        bind_context.aggregate_info.group_items.push(ScalarItem {
+            scalar: scalar_expr.clone(),
+            index,
+        });
+        let position = bind_context.aggregate_info.group_items.len() - 1;
+        bind_context
+            .aggregate_info
+            .group_items_map
+            .insert(format!("{:?}", &scalar_expr), position);
+        Ok(position)
+    }
+
     fn resolve_index_item(
         expr: &Expr,
         index: u64,
@@ -479,6 +719,14 @@ impl Binder {
             )
         } else {
             let (column_binding, scalar) = available_aliases[result[0]].clone();
+            if bind_context
+                .aggregate_info
+                .group_items_map
+                .contains_key(&format!("{:?}", &scalar))
+            {
+                // The alias has been resolved by another grouping set
+                return Ok((scalar.clone(), scalar.data_type()));
+            }
             // We will add the alias to BindContext, so we can reference it
             // in `HAVING` and `ORDER BY` clause.
             bind_context.columns.push(column_binding.clone());
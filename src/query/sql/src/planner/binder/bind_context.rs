@@ -25,6 +25,7 @@ use common_expression::DataSchemaRefExt;
 use dashmap::DashMap;
 
 use super::AggregateInfo;
+use super::WindowInfo;
 use crate::normalize_identifier;
 use crate::optimizer::SExpr;
 use crate::plans::ScalarExpr;
@@ -88,6 +89,8 @@ pub struct BindContext {
 
     pub aggregate_info: AggregateInfo,
 
+    pub windows: WindowInfo,
+
     /// True if there is aggregation in current context, which means
     /// non-grouping columns cannot be referenced outside aggregation
     /// functions, otherwise a grouping error will be raised.
@@ -103,6 +106,10 @@ pub struct CteInfo {
     pub columns_alias: Vec<String>,
     pub s_expr: SExpr,
     pub bind_context: BindContext,
+    /// Set if the CTE is read through `CteScan`, which may be materialized.
+    pub cte_idx: Option<IndexType>,
+    /// Specified with `AS MATERIALIZED`.
+    pub materialized: bool,
 }
 
 impl BindContext {
@@ -111,6 +118,7 @@ impl BindContext {
             parent: None,
             columns: Vec::new(),
             aggregate_info: AggregateInfo::default(),
+            windows: WindowInfo::default(),
             in_grouping: false,
             ctes_map: Box::new(DashMap::new()),
             is_view: false,
@@ -122,6 +130,7 @@ impl BindContext {
             parent: Some(parent.clone()),
             columns: vec![],
             aggregate_info: Default::default(),
+            windows: Default::default(),
             in_grouping: false,
             ctes_map: parent.ctes_map.clone(),
             is_view: false,
@@ -184,6 +184,16 @@ impl<'a> Binder {
             Statement::AlterView(stmt) => self.bind_alter_view(stmt).await?,
             Statement::DropView(stmt) => self.bind_drop_view(stmt).await?,
 
+            // Streams
+            Statement::CreateStream(stmt) => self.bind_create_stream(stmt).await?,
+            Statement::DropStream(stmt) => self.bind_drop_stream(stmt).await?,
+
+            // Aggregating indexes
+            Statement::CreateAggregatingIndex(stmt) => {
+                self.bind_create_aggregating_index(stmt).await?
+            }
+            Statement::DropAggregatingIndex(stmt) => self.bind_drop_aggregating_index(stmt).await?,
+
             // Users
             Statement::CreateUser(stmt) => self.bind_create_user(stmt).await?,
             Statement::DropUser { if_exists, user } => Plan::DropUser(Box::new(DropUserPlan {
@@ -228,6 +238,7 @@ impl<'a> Binder {
                 self.bind_remove_stage(location, pattern).await?
             }
             Statement::Insert(stmt) => self.bind_insert(bind_context, stmt).await?,
+            Statement::Replace(stmt) => self.bind_replace(bind_context, stmt).await?,
             Statement::Delete {
                 table_reference,
                 selection,
@@ -236,6 +247,12 @@ impl<'a> Binder {
                     .await?
             }
             Statement::Update(stmt) => self.bind_update(bind_context, stmt).await?,
+            Statement::MergeInto(stmt) => self.bind_merge_into(bind_context, stmt).await?,
+
+            // Transactions
+            Statement::Begin => Plan::Begin,
+            Statement::Commit => Plan::Commit,
+            Statement::Abort => Plan::Abort,
 
             // Permissions
             Statement::Grant(stmt) => self.bind_grant(stmt).await?,
@@ -0,0 +1,113 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_ast::ast::CreateAggregatingIndexStmt;
+use common_ast::ast::DropAggregatingIndexStmt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::binder::Binder;
+use crate::optimizer::ScanAggregation;
+use crate::planner::semantic::normalize_identifier;
+use crate::plans::CreateAggregatingIndexPlan;
+use crate::plans::DropAggregatingIndexPlan;
+use crate::plans::Plan;
+use crate::plans::RelOperator;
+use crate::BindContext;
+
+impl Binder {
+    pub(in crate::planner::binder) async fn bind_create_aggregating_index(
+        &mut self,
+        stmt: &CreateAggregatingIndexStmt,
+    ) -> Result<Plan> {
+        let CreateAggregatingIndexStmt {
+            if_not_exists,
+            index_name,
+            query,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let index_name = normalize_identifier(index_name, &self.name_resolution_ctx).name;
+
+        let bind_context = BindContext::new();
+        let (s_expr, _) = self.bind_query(&bind_context, query).await?;
+        // the projection of the query doesn't matter, the index keeps the keys and the results
+        // of the aggregate functions.
+        let s_expr = match s_expr.plan() {
+            RelOperator::EvalScalar(_) => s_expr.child(0)?,
+            _ => &s_expr,
+        };
+
+        let metadata = self.metadata.read();
+        let matched = ScanAggregation::try_match(s_expr, &metadata)
+            .filter(|matched| !metadata.table(matched.scan.table_index).is_source_of_view())
+            .ok_or_else(|| {
+                ErrorCode::SemanticError(
+                    "The query of an aggregating index must be `SELECT <columns>, <aggregate functions> FROM <table> GROUP BY <columns>`, the aggregate functions can only be SUM, COUNT, MIN or MAX of a column"
+                        .to_string(),
+                )
+                .set_span(query.span)
+            })?;
+        let table_entry = metadata.table(matched.scan.table_index);
+        let index = matched.to_aggregating_index(
+            table_entry.table().as_ref(),
+            index_name,
+            query.to_string(),
+        )?;
+
+        let plan = CreateAggregatingIndexPlan {
+            if_not_exists: *if_not_exists,
+            tenant,
+            catalog: table_entry.catalog().to_string(),
+            database: table_entry.database().to_string(),
+            table: table_entry.name().to_string(),
+            index,
+        };
+        Ok(Plan::CreateAggregatingIndex(Box::new(plan)))
+    }
+
+    pub(in crate::planner::binder) async fn bind_drop_aggregating_index(
+        &mut self,
+        stmt: &DropAggregatingIndexStmt,
+    ) -> Result<Plan> {
+        let DropAggregatingIndexStmt {
+            if_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let index_name = normalize_identifier(index_name, &self.name_resolution_ctx).name;
+        let catalog = catalog
+            .as_ref()
+            .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_catalog());
+        let database = database
+            .as_ref()
+            .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_database());
+        let table = normalize_identifier(table, &self.name_resolution_ctx).name;
+
+        let plan = DropAggregatingIndexPlan {
+            if_exists: *if_exists,
+            tenant,
+            catalog,
+            database,
+            table,
+            index_name,
+        };
+        Ok(Plan::DropAggregatingIndex(Box::new(plan)))
+    }
+}
@@ -13,10 +13,12 @@
 // limitations under the License.
 
 mod account;
+mod aggregating_index;
 mod catalog;
 mod database;
 mod role;
 mod share;
 mod stage;
+mod stream;
 mod table;
 mod view;
@@ -84,16 +84,19 @@ use crate::plans::AddTableColumnPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AnalyzeTablePlan;
 use crate::plans::CastExpr;
+use crate::plans::CloneTablePlan;
 use crate::plans::CreateTablePlanV2;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
 use crate::plans::DropTablePlan;
 use crate::plans::ExistsTablePlan;
+use crate::plans::ModifyTableColumnTypePlan;
 use crate::plans::OptimizeTableAction;
 use crate::plans::OptimizeTablePlan;
 use crate::plans::Plan;
 use crate::plans::ReclusterTablePlan;
+use crate::plans::RenameTableColumnPlan;
 use crate::plans::RenameTableEntity;
 use crate::plans::RenameTablePlan;
 use crate::plans::RevertTablePlan;
@@ -343,6 +346,61 @@ impl Binder {
             .unwrap_or_else(|| self.ctx.get_current_database());
         let table = normalize_identifier(table, &self.name_resolution_ctx).name;
 
+        if let Some(CreateTableSource::Clone {
+            catalog: source_catalog,
+            database: source_database,
+            table: source_table,
+            travel_point,
+        }) = source
+        {
+            // the cloned table shares the storage and the options of the source table.
+            if as_query.is_some()
+                || engine.is_some()
+                || !cluster_by.is_empty()
+                || !table_options.is_empty()
+                || uri_location.is_some()
+                || *transient
+            {
+                return Err(ErrorCode::SemanticError(
+                    "CREATE TABLE ... CLONE doesn't support AS SELECT, ENGINE, CLUSTER BY, table options, location or TRANSIENT".to_string(),
+                ));
+            }
+
+            let source_catalog = source_catalog
+                .as_ref()
+                .map(|catalog| normalize_identifier(catalog, &self.name_resolution_ctx).name)
+                .unwrap_or_else(|| self.ctx.get_current_catalog());
+            if source_catalog != catalog {
+                return Err(ErrorCode::SemanticError(format!(
+                    "cannot clone table across catalogs, source catalog: {}, target catalog: {}",
+                    source_catalog, catalog
+                )));
+            }
+            let source_database = source_database
+                .as_ref()
+                .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+                .unwrap_or_else(|| self.ctx.get_current_database());
+            let source_table = normalize_identifier(source_table, &self.name_resolution_ctx).name;
+            let point = match travel_point {
+                Some(point) => Some(
+                    self.resolve_data_travel_point(&BindContext::new(), point)
+                        .await?,
+                ),
+                None => None,
+            };
+
+            return Ok(Plan::CloneTable(Box::new(CloneTablePlan {
+                if_not_exists: *if_not_exists,
+                tenant: self.ctx.get_tenant(),
+                catalog,
+                database,
+                table,
+                source_database,
+                source_table,
+                point,
+            })));
+        }
+
         // Take FUSE engine AS default engine
         let engine = engine.unwrap_or(Engine::Fuse);
         let mut options: BTreeMap<String, String> = BTreeMap::new();
@@ -670,6 +728,25 @@ impl Binder {
                     column: column.to_string(),
                 })))
             }
+            AlterTableAction::RenameColumn {
+                old_column,
+                new_column,
+            } => Ok(Plan::RenameTableColumn(Box::new(RenameTableColumnPlan {
+                catalog,
+                database,
+                table,
+                old_column: normalize_identifier(old_column, &self.name_resolution_ctx).name,
+                new_column: normalize_identifier(new_column, &self.name_resolution_ctx).name,
+            }))),
+            AlterTableAction::ModifyColumnType { column, data_type } => Ok(
+                Plan::ModifyTableColumnType(Box::new(ModifyTableColumnTypePlan {
+                    catalog,
+                    database,
+                    table,
+                    column: normalize_identifier(column, &self.name_resolution_ctx).name,
+                    data_type: TypeChecker::resolve_type_name(data_type)?,
+                })),
+            ),
             AlterTableAction::AlterTableClusterKey { cluster_by } => {
                 let schema = self
                     .ctx
@@ -1025,6 +1102,9 @@ impl Binder {
                     Ok((table.schema(), vec![], table.field_comments().clone()))
                 }
             }
+            CreateTableSource::Clone { .. } => Err(ErrorCode::SemanticError(
+                "CREATE TABLE ... CLONE doesn't define a schema".to_string(),
+            )),
         }
     }
 
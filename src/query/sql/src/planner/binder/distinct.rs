@@ -76,6 +76,7 @@ impl Binder {
             aggregate_functions: vec![],
             from_distinct: true,
             limit: None,
+            grouping_sets: None,
         };
 
         Ok(SExpr::create_unary(distinct_plan.into(), new_expr))
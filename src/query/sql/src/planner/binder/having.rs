@@ -49,6 +49,7 @@ impl Binder {
             &aliases,
         );
         let (scalar, _) = scalar_binder.bind(having).await?;
+        self.check_no_window_function(&scalar, "HAVING clause", having.span())?;
         let mut rewriter = AggregateRewriter::new(bind_context, self.metadata.clone());
         Ok((rewriter.visit(&scalar)?, having.span()))
     }
@@ -14,10 +14,12 @@
 
 use std::sync::Arc;
 
+use common_ast::ast::Identifier;
 use common_ast::ast::InsertSource;
 use common_ast::ast::InsertStmt;
 use common_ast::ast::Statement;
 use common_exception::Result;
+use common_expression::TableSchemaRef;
 use common_expression::TableSchemaRefExt;
 use common_meta_app::principal::FileFormatOptions;
 
@@ -59,25 +61,48 @@ impl Binder {
             .get_table(&catalog_name, &database_name, &table_name)
             .await?;
         let table_id = table.get_id();
+        let schema = self.insert_schema(table.schema(), columns)?;
+        let input_source = self.bind_insert_source(bind_context, source).await?;
 
-        let schema = if columns.is_empty() {
-            table.schema()
-        } else {
-            let schema = table.schema();
-            let fields = columns
-                .iter()
-                .map(|ident| {
-                    schema
-                        .field_with_name(
-                            &normalize_identifier(ident, &self.name_resolution_ctx).name,
-                        )
-                        .map(|v| v.clone())
-                })
-                .collect::<Result<Vec<_>>>()?;
-            TableSchemaRefExt::create(fields)
+        let plan = Insert {
+            catalog: catalog_name.to_string(),
+            database: database_name.to_string(),
+            table: table_name,
+            table_id,
+            schema,
+            overwrite: *overwrite,
+            source: input_source,
         };
 
-        let input_source: Result<InsertInputSource> = match source.clone() {
+        Ok(Plan::Insert(Box::new(plan)))
+    }
+
+    /// The schema of the inserted columns, all the fields of the table if `columns` is empty.
+    pub(in crate::planner::binder) fn insert_schema(
+        &self,
+        schema: TableSchemaRef,
+        columns: &[Identifier],
+    ) -> Result<TableSchemaRef> {
+        if columns.is_empty() {
+            return Ok(schema);
+        }
+        let fields = columns
+            .iter()
+            .map(|ident| {
+                schema
+                    .field_with_name(&normalize_identifier(ident, &self.name_resolution_ctx).name)
+                    .map(|v| v.clone())
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(TableSchemaRefExt::create(fields))
+    }
+
+    pub(in crate::planner::binder) async fn bind_insert_source(
+        &mut self,
+        bind_context: &BindContext,
+        source: &InsertSource,
+    ) -> Result<InsertInputSource> {
+        match source.clone() {
             InsertSource::Streaming {
                 format,
                 rest_str,
@@ -115,18 +140,6 @@ impl Binder {
                 let optimized_plan = optimize(self.ctx.clone(), opt_ctx, select_plan)?;
                 Ok(InsertInputSource::SelectPlan(Box::new(optimized_plan)))
             }
-        };
-
-        let plan = Insert {
-            catalog: catalog_name.to_string(),
-            database: database_name.to_string(),
-            table: table_name,
-            table_id,
-            schema,
-            overwrite: *overwrite,
-            source: input_source?,
-        };
-
-        Ok(Plan::Insert(Box::new(plan)))
+        }
     }
 }
@@ -20,11 +20,13 @@ use common_ast::ast::split_equivalent_predicate_expr;
 use common_ast::ast::Expr;
 use common_ast::ast::JoinCondition;
 use common_ast::ast::JoinOperator;
+use common_ast::ast::TableReference;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::type_check::common_super_type;
 use common_functions::scalars::BUILTIN_FUNCTIONS;
+use common_functions::srfs::is_set_returning_function;
 
 use crate::binder::JoinPredicate;
 use crate::binder::Visibility;
@@ -58,6 +60,12 @@ impl Binder {
         bind_context: &BindContext,
         join: &common_ast::ast::Join,
     ) -> Result<(SExpr, BindContext)> {
+        if let TableReference::TableFunction { name, lateral, .. } = &join.right {
+            if *lateral || is_set_returning_function(&name.name) {
+                return self.bind_lateral_join(bind_context, join).await;
+            }
+        }
+
         let (left_child, left_context) =
             self.bind_table_reference(bind_context, &join.left).await?;
         let (right_child, right_context) =
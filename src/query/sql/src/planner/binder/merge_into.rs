@@ -0,0 +1,356 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_ast::ast::Expr;
+use common_ast::ast::MatchOperation;
+use common_ast::ast::MergeIntoStmt;
+use common_ast::ast::MergeOption;
+use common_ast::ast::TableReference;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::type_check::common_super_type;
+use common_expression::types::DataType;
+use common_expression::FieldIndex;
+use common_functions::scalars::BUILTIN_FUNCTIONS;
+
+use crate::binder::split_conjunctions;
+use crate::binder::split_equivalent_predicate;
+use crate::binder::wrap_cast_if_needed;
+use crate::binder::Binder;
+use crate::binder::ScalarBinder;
+use crate::normalize_identifier;
+use crate::optimizer::find_subquery_in_expr;
+use crate::optimizer::optimize_query;
+use crate::optimizer::OptimizerConfig;
+use crate::optimizer::OptimizerContext;
+use crate::plans::MergeIntoPlan;
+use crate::plans::MergeMatchedClause;
+use crate::plans::MergeUnmatchedClause;
+use crate::plans::Plan;
+use crate::plans::ScalarExpr;
+use crate::BindContext;
+use crate::IndexType;
+use crate::Visibility;
+
+impl Binder {
+    pub(in crate::planner::binder) async fn bind_merge_into(
+        &mut self,
+        bind_context: &BindContext,
+        stmt: &MergeIntoStmt,
+    ) -> Result<Plan> {
+        let MergeIntoStmt {
+            catalog,
+            database,
+            table,
+            target_alias,
+            source,
+            join_expr,
+            merge_options,
+        } = stmt;
+
+        let catalog_name = catalog.as_ref().map_or_else(
+            || self.ctx.get_current_catalog(),
+            |ident| normalize_identifier(ident, &self.name_resolution_ctx).name,
+        );
+        let database_name = database.as_ref().map_or_else(
+            || self.ctx.get_current_database(),
+            |ident| normalize_identifier(ident, &self.name_resolution_ctx).name,
+        );
+        let table_name = normalize_identifier(table, &self.name_resolution_ctx).name;
+        let table = self
+            .ctx
+            .get_table(&catalog_name, &database_name, &table_name)
+            .await?;
+        let schema = table.schema();
+
+        let target_reference = TableReference::Table {
+            span: None,
+            catalog: catalog.clone(),
+            database: database.clone(),
+            table: stmt.table.clone(),
+            alias: target_alias.clone(),
+            travel_point: None,
+        };
+        let (_, target_context) = self
+            .bind_table_reference(bind_context, &target_reference)
+            .await?;
+        let (source_expr, source_context) = self.bind_table_reference(bind_context, source).await?;
+
+        // The visible columns of the target are the fields of the table in order.
+        let target_columns = target_context
+            .columns
+            .iter()
+            .filter(|column| column.visibility == Visibility::Visible)
+            .enumerate()
+            .map(|(field_index, column)| (column.index, field_index))
+            .collect::<HashMap<IndexType, FieldIndex>>();
+        if target_columns.len() != schema.num_fields() {
+            return Err(ErrorCode::Internal(format!(
+                "The target of MERGE INTO has {} columns, but the table has {} fields",
+                target_columns.len(),
+                schema.num_fields()
+            )));
+        }
+
+        let mut source_context = source_context;
+        source_context
+            .columns
+            .retain(|column| column.visibility == Visibility::Visible);
+        let source_columns = source_context
+            .columns
+            .iter()
+            .map(|column| column.index)
+            .collect::<HashSet<IndexType>>();
+
+        let mut join_context = bind_context.replace();
+        for column in target_context.columns.iter() {
+            join_context.add_column_binding(column.clone());
+        }
+        for column in source_context.columns.iter() {
+            join_context.add_column_binding(column.clone());
+        }
+
+        // Split the ON clause into the equi conditions between the target and the source,
+        // and the remaining conditions.
+        let mut target_join_keys = vec![];
+        let mut source_join_keys = vec![];
+        let mut join_filters = vec![];
+        let on_condition = self
+            .bind_merge_into_expr(&join_context, join_expr, &target_columns, &source_columns)
+            .await?;
+        for predicate in split_conjunctions(&on_condition) {
+            if let Some((left, right)) = split_equivalent_predicate(&predicate) {
+                let left_used_columns = left.used_columns();
+                let right_used_columns = right.used_columns();
+                let is_target = |columns: &HashSet<IndexType>| {
+                    !columns.is_empty() && columns.iter().all(|c| target_columns.contains_key(c))
+                };
+                let is_source = |columns: &HashSet<IndexType>| {
+                    !columns.is_empty() && columns.is_subset(&source_columns)
+                };
+                let keys = if is_target(&left_used_columns) && is_source(&right_used_columns) {
+                    Some((left, right))
+                } else if is_source(&left_used_columns) && is_target(&right_used_columns) {
+                    Some((right, left))
+                } else {
+                    None
+                };
+                if let Some((target_key, source_key)) = keys {
+                    let target_type = target_key.data_type();
+                    let source_type = source_key.data_type();
+                    let common_type = common_super_type(
+                        target_type.clone(),
+                        source_type.clone(),
+                        &BUILTIN_FUNCTIONS.default_cast_rules,
+                    )
+                    .ok_or_else(|| {
+                        ErrorCode::SemanticError(format!(
+                            "Target type {target_type} and source type {source_type} cannot be matched"
+                        ))
+                    })?;
+                    target_join_keys.push(wrap_cast_if_needed(&target_key, &common_type));
+                    source_join_keys.push(wrap_cast_if_needed(&source_key, &common_type));
+                    continue;
+                }
+            }
+            join_filters.push(predicate);
+        }
+        if target_join_keys.is_empty() {
+            return Err(ErrorCode::SemanticError(
+                "MERGE INTO requires at least one equality condition between the target and the source in the ON clause",
+            ));
+        }
+
+        let mut matched_clauses = vec![];
+        let mut unmatched_clauses = vec![];
+        for option in merge_options {
+            match option {
+                MergeOption::Match(clause) => {
+                    let condition = match &clause.selection {
+                        Some(selection) => Some(
+                            self.bind_merge_into_expr(
+                                &join_context,
+                                selection,
+                                &target_columns,
+                                &source_columns,
+                            )
+                            .await?,
+                        ),
+                        None => None,
+                    };
+                    let update_list = match &clause.operation {
+                        MatchOperation::Update { update_list } => {
+                            let mut update_columns = HashMap::with_capacity(update_list.len());
+                            for update_expr in update_list {
+                                let col_name = normalize_identifier(
+                                    &update_expr.name,
+                                    &self.name_resolution_ctx,
+                                )
+                                .name;
+                                let index = schema.index_of(&col_name)?;
+                                if update_columns.contains_key(&index) {
+                                    return Err(ErrorCode::BadArguments(format!(
+                                        "Multiple assignments in the single statement to column `{}`",
+                                        col_name
+                                    )));
+                                }
+                                let scalar = self
+                                    .bind_merge_into_expr(
+                                        &join_context,
+                                        &update_expr.expr,
+                                        &target_columns,
+                                        &source_columns,
+                                    )
+                                    .await?;
+                                let field_type = DataType::from(schema.field(index).data_type());
+                                update_columns
+                                    .insert(index, wrap_cast_if_needed(&scalar, &field_type));
+                            }
+                            Some(update_columns)
+                        }
+                        MatchOperation::Delete => None,
+                    };
+                    matched_clauses.push(MergeMatchedClause {
+                        condition,
+                        update_list,
+                    });
+                }
+                MergeOption::Unmatch(clause) => {
+                    // The target columns are not available in the NOT MATCHED clause.
+                    let no_target_columns = HashMap::new();
+                    let condition = match &clause.selection {
+                        Some(selection) => Some(
+                            self.bind_merge_into_expr(
+                                &source_context,
+                                selection,
+                                &no_target_columns,
+                                &source_columns,
+                            )
+                            .await?,
+                        ),
+                        None => None,
+                    };
+
+                    let field_indices = if clause.columns.is_empty() {
+                        (0..schema.num_fields()).collect::<Vec<_>>()
+                    } else {
+                        clause
+                            .columns
+                            .iter()
+                            .map(|column| {
+                                schema.index_of(
+                                    &normalize_identifier(column, &self.name_resolution_ctx).name,
+                                )
+                            })
+                            .collect::<Result<Vec<_>>>()?
+                    };
+                    if field_indices.len() != clause.values.len() {
+                        return Err(ErrorCode::TableSchemaMismatch(format!(
+                            "Table columns count is not match, expect {}, input: {}",
+                            field_indices.len(),
+                            clause.values.len()
+                        )));
+                    }
+
+                    let mut values = vec![None; schema.num_fields()];
+                    for (field_index, value) in field_indices.into_iter().zip(clause.values.iter())
+                    {
+                        if values[field_index].is_some() {
+                            return Err(ErrorCode::BadArguments(format!(
+                                "Column `{}` is specified more than once",
+                                schema.field(field_index).name()
+                            )));
+                        }
+                        let scalar = self
+                            .bind_merge_into_expr(
+                                &source_context,
+                                value,
+                                &no_target_columns,
+                                &source_columns,
+                            )
+                            .await?;
+                        let field_type = DataType::from(schema.field(field_index).data_type());
+                        values[field_index] = Some(wrap_cast_if_needed(&scalar, &field_type));
+                    }
+                    unmatched_clauses.push(MergeUnmatchedClause { condition, values });
+                }
+            }
+        }
+
+        let opt_ctx = Arc::new(OptimizerContext::new(OptimizerConfig {
+            enable_distributed_optimization: false,
+        }));
+        let input = optimize_query(
+            self.ctx.clone(),
+            opt_ctx,
+            self.metadata.clone(),
+            Box::new(source_context.clone()),
+            source_expr,
+        )?;
+
+        let plan = MergeIntoPlan {
+            catalog: catalog_name,
+            database: database_name,
+            table: table_name,
+            input: Box::new(input),
+            bind_context: Box::new(source_context),
+            metadata: self.metadata.clone(),
+            target_columns,
+            target_join_keys,
+            source_join_keys,
+            join_filters,
+            matched_clauses,
+            unmatched_clauses,
+        };
+        Ok(Plan::MergeInto(Box::new(plan)))
+    }
+
+    /// Bind an expression of MERGE INTO, which can only reference the columns of the target
+    /// and the source.
+    async fn bind_merge_into_expr(
+        &self,
+        bind_context: &BindContext,
+        expr: &Expr,
+        target_columns: &HashMap<IndexType, FieldIndex>,
+        source_columns: &HashSet<IndexType>,
+    ) -> Result<ScalarExpr> {
+        let mut scalar_binder = ScalarBinder::new(
+            bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+        let (scalar, _) = scalar_binder.bind(expr).await?;
+        if find_subquery_in_expr(&scalar) {
+            return Err(ErrorCode::Unimplemented(
+                "MERGE INTO does not support subquery temporarily",
+            ));
+        }
+        if scalar
+            .used_columns()
+            .iter()
+            .any(|index| !target_columns.contains_key(index) && !source_columns.contains(index))
+        {
+            return Err(ErrorCode::SemanticError(format!(
+                "Expression `{expr}` references columns that are not supported in MERGE INTO"
+            )));
+        }
+        Ok(scalar)
+    }
+}
@@ -118,13 +118,25 @@ impl Binder {
             })
             .collect::<Result<Vec<_>>>()?;
 
+        let mut columns = columns.to_vec();
+        if bind_context.aggregate_info.grouping_sets.is_some() {
+            // The group items are nullable after grouping, so the types of the
+            // output columns need to be derived again.
+            for column in columns.iter_mut() {
+                if let Some(item) = scalars.iter().find(|item| item.index == column.index) {
+                    let data_type = item.scalar.as_expr_with_col_index()?.data_type().clone();
+                    column.data_type = Box::new(data_type);
+                }
+            }
+        }
+
         scalars.sort_by_key(|s| s.index);
         let eval_scalar = EvalScalar { items: scalars };
 
         let new_expr = SExpr::create_unary(eval_scalar.into(), child);
 
         // Set output columns
-        bind_context.columns = columns.to_vec();
+        bind_context.columns = columns;
 
         Ok(new_expr)
     }
@@ -0,0 +1,248 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_recursion::async_recursion;
+use common_ast::ast::Expr;
+use common_ast::ast::Identifier;
+use common_ast::ast::JoinCondition;
+use common_ast::ast::JoinOperator;
+use common_ast::ast::TableAlias;
+use common_ast::ast::TableReference;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::Span;
+use common_functions::srfs::get_set_returning_function;
+
+use crate::binder::split_conjunctions;
+use crate::binder::ColumnBinding;
+use crate::binder::Visibility;
+use crate::normalize_identifier;
+use crate::optimizer::SExpr;
+use crate::planner::binder::scalar::ScalarBinder;
+use crate::plans::DummyTableScan;
+use crate::plans::EvalScalar;
+use crate::plans::Filter;
+use crate::plans::ProjectSet;
+use crate::plans::ScalarExpr;
+use crate::plans::ScalarItem;
+use crate::BindContext;
+use crate::Binder;
+
+impl Binder {
+    /// Bind a set-returning function in the FROM clause that is not joined with
+    /// anything, e.g. `SELECT * FROM UNNEST([1, 2, 3])`.
+    /// The function is evaluated on top of a single-row `DummyTableScan`.
+    #[allow(clippy::too_many_arguments)]
+    pub(super) async fn bind_set_returning_function(
+        &mut self,
+        bind_context: &BindContext,
+        span: Span,
+        name: &Identifier,
+        params: &[Expr],
+        named_params: &[(String, Expr)],
+        alias: &Option<TableAlias>,
+    ) -> Result<(SExpr, BindContext)> {
+        let child = SExpr::create_leaf(DummyTableScan.into());
+        self.bind_project_set(
+            bind_context.replace(),
+            child,
+            span,
+            name,
+            params,
+            named_params,
+            alias,
+        )
+        .await
+    }
+
+    /// Bind a lateral join whose right side is a set-returning function,
+    /// e.g. `SELECT * FROM t, LATERAL FLATTEN(t.payload)`.
+    ///
+    /// The function is evaluated once per row of the left side, so the join is
+    /// planned as a `ProjectSet` over the left child instead of a `Join`.
+    #[async_recursion]
+    pub(super) async fn bind_lateral_join(
+        &mut self,
+        bind_context: &BindContext,
+        join: &common_ast::ast::Join,
+    ) -> Result<(SExpr, BindContext)> {
+        let (span, name, params, named_params, alias) = match &join.right {
+            TableReference::TableFunction {
+                span,
+                name,
+                params,
+                named_params,
+                alias,
+                ..
+            } => (span, name, params, named_params, alias),
+            _ => unreachable!(),
+        };
+
+        match (&join.op, &join.condition) {
+            (JoinOperator::CrossJoin, JoinCondition::None)
+            | (JoinOperator::Inner, JoinCondition::None)
+            | (JoinOperator::Inner, JoinCondition::On(_)) => (),
+            (JoinOperator::CrossJoin, _) => {
+                return Err(ErrorCode::SemanticError(
+                    "cross join should not contain join conditions".to_string(),
+                ));
+            }
+            _ => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "{} only supports cross join or inner join with ON condition",
+                    &name.name
+                ))
+                .set_span(*span));
+            }
+        }
+
+        let (left_child, left_context) =
+            self.bind_table_reference(bind_context, &join.left).await?;
+
+        let (s_expr, bind_context) = self
+            .bind_project_set(
+                left_context,
+                left_child,
+                *span,
+                name,
+                params,
+                named_params,
+                alias,
+            )
+            .await?;
+
+        if let JoinCondition::On(condition) = &join.condition {
+            let mut scalar_binder = ScalarBinder::new(
+                &bind_context,
+                self.ctx.clone(),
+                &self.name_resolution_ctx,
+                self.metadata.clone(),
+                &[],
+            );
+            let (scalar, _) = scalar_binder.bind(condition).await?;
+            let filter = Filter {
+                predicates: split_conjunctions(&scalar),
+                is_having: false,
+            };
+            return Ok((SExpr::create_unary(filter.into(), s_expr), bind_context));
+        }
+
+        Ok((s_expr, bind_context))
+    }
+
+    /// Build a `ProjectSet` evaluating the set-returning function over `child`.
+    /// The arguments are resolved against `input_context`, and the output
+    /// columns of the function are appended to it.
+    #[allow(clippy::too_many_arguments)]
+    async fn bind_project_set(
+        &mut self,
+        input_context: BindContext,
+        child: SExpr,
+        span: Span,
+        name: &Identifier,
+        params: &[Expr],
+        named_params: &[(String, Expr)],
+        alias: &Option<TableAlias>,
+    ) -> Result<(SExpr, BindContext)> {
+        let func_name = normalize_identifier(name, &self.name_resolution_ctx).name;
+        let srf = get_set_returning_function(&func_name).ok_or_else(|| {
+            ErrorCode::SemanticError(format!("{func_name} is not a set-returning function"))
+                .set_span(span)
+        })?;
+        if !named_params.is_empty() {
+            return Err(ErrorCode::SemanticError(format!(
+                "set-returning function {func_name} does not accept named parameters"
+            ))
+            .set_span(span));
+        }
+
+        let mut scalar_binder = ScalarBinder::new(
+            &input_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+        let mut arguments = Vec::with_capacity(params.len());
+        let mut arg_types = Vec::with_capacity(params.len());
+        for param in params {
+            let (scalar, data_type) = scalar_binder.bind(param).await?;
+            arguments.push(scalar);
+            arg_types.push(data_type);
+        }
+        let return_types = (srf.return_types)(&arg_types).map_err(|e| e.set_span(span))?;
+
+        // Arguments are evaluated into columns by an `EvalScalar` below the `ProjectSet`.
+        let mut eval_items = vec![];
+        let arguments = arguments
+            .into_iter()
+            .enumerate()
+            .map(|(i, scalar)| {
+                let index = if let ScalarExpr::BoundColumnRef(column_ref) = &scalar {
+                    column_ref.column.index
+                } else {
+                    let index = self
+                        .metadata
+                        .write()
+                        .add_derived_column(format!("{func_name}_arg_{i}"), scalar.data_type());
+                    eval_items.push(ScalarItem {
+                        scalar: scalar.clone(),
+                        index,
+                    });
+                    index
+                };
+                ScalarItem { scalar, index }
+            })
+            .collect::<Vec<_>>();
+        let child = if eval_items.is_empty() {
+            child
+        } else {
+            SExpr::create_unary(EvalScalar { items: eval_items }.into(), child)
+        };
+
+        let mut srf_context = BindContext::new();
+        let mut columns = Vec::with_capacity(return_types.len());
+        for (column_name, data_type) in return_types {
+            let index = self
+                .metadata
+                .write()
+                .add_derived_column(column_name.clone(), data_type.clone());
+            srf_context.add_column_binding(ColumnBinding {
+                database_name: None,
+                table_name: None,
+                column_name,
+                index,
+                data_type: Box::new(data_type.clone()),
+                visibility: Visibility::Visible,
+            });
+            columns.push((index, data_type));
+        }
+        if let Some(alias) = alias {
+            srf_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
+        }
+
+        let project_set = ProjectSet {
+            func_name,
+            arguments,
+            columns,
+        };
+        let s_expr = SExpr::create_unary(project_set.into(), child);
+
+        let mut bind_context = input_context;
+        for column in srf_context.columns {
+            bind_context.add_column_binding(column);
+        }
+        Ok((s_expr, bind_context))
+    }
+}
@@ -0,0 +1,87 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::ReplaceStmt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::binder::Binder;
+use crate::normalize_identifier;
+use crate::plans::Plan;
+use crate::plans::Replace;
+use crate::BindContext;
+
+impl Binder {
+    pub(in crate::planner::binder) async fn bind_replace(
+        &mut self,
+        bind_context: &BindContext,
+        stmt: &ReplaceStmt,
+    ) -> Result<Plan> {
+        let ReplaceStmt {
+            catalog,
+            database,
+            table,
+            on_conflict_columns,
+            columns,
+            source,
+        } = stmt;
+        let catalog_name = catalog.as_ref().map_or_else(
+            || self.ctx.get_current_catalog(),
+            |ident| normalize_identifier(ident, &self.name_resolution_ctx).name,
+        );
+        let database_name = database.as_ref().map_or_else(
+            || self.ctx.get_current_database(),
+            |ident| normalize_identifier(ident, &self.name_resolution_ctx).name,
+        );
+        let table_name = normalize_identifier(table, &self.name_resolution_ctx).name;
+        let table = self
+            .ctx
+            .get_table(&catalog_name, &database_name, &table_name)
+            .await?;
+        let table_id = table.get_id();
+        let table_schema = table.schema();
+        let schema = self.insert_schema(table_schema.clone(), columns)?;
+
+        let mut on_conflict_fields = Vec::with_capacity(on_conflict_columns.len());
+        for ident in on_conflict_columns {
+            let name = normalize_identifier(ident, &self.name_resolution_ctx).name;
+            let index = table_schema.index_of(&name)?;
+            if on_conflict_fields.contains(&index) {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Column `{name}` is specified more than once in ON CONFLICT"
+                )));
+            }
+            if !schema.has_field(&name) {
+                return Err(ErrorCode::BadArguments(format!(
+                    "The conflict column `{name}` must be specified in the inserted columns"
+                )));
+            }
+            on_conflict_fields.push(index);
+        }
+
+        let input_source = self.bind_insert_source(bind_context, source).await?;
+
+        let plan = Replace {
+            catalog: catalog_name,
+            database: database_name,
+            table: table_name,
+            table_id,
+            on_conflict_fields,
+            schema,
+            source: input_source,
+        };
+
+        Ok(Plan::Replace(Box::new(plan)))
+    }
+}
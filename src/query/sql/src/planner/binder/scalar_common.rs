@@ -26,6 +26,7 @@ use crate::plans::CastExpr;
 use crate::plans::ComparisonExpr;
 use crate::plans::ComparisonOp;
 use crate::plans::FunctionCall;
+use crate::plans::LambdaFunc;
 use crate::plans::NotExpr;
 use crate::plans::OrExpr;
 use crate::plans::ScalarExpr;
@@ -42,7 +43,6 @@ impl<'a, F> Finder<'a, F>
 where F: Fn(&ScalarExpr) -> bool
 {
     /// Create a new finder with the `test_fn`
-    #[allow(dead_code)]
     fn new(find_fn: &'a F) -> Self {
         Self {
             find_fn,
@@ -67,6 +67,13 @@ where F: Fn(&ScalarExpr) -> bool
     }
 }
 
+/// Find all the window functions in a scalar expression.
+pub fn find_window_functions(scalar: &ScalarExpr) -> Result<Vec<ScalarExpr>> {
+    let find_fn = |scalar: &ScalarExpr| matches!(scalar, ScalarExpr::WindowFunction(_));
+    let finder = scalar.accept(Finder::new(&find_fn))?;
+    Ok(finder.scalars)
+}
+
 pub fn split_conjunctions(scalar: &ScalarExpr) -> Vec<ScalarExpr> {
     match scalar {
         ScalarExpr::AndExpr(AndExpr { left, right, .. }) => {
@@ -171,6 +178,7 @@ pub fn contain_subquery(scalar: &ScalarExpr) -> bool {
             arguments.iter().any(contain_subquery)
         }
         ScalarExpr::CastExpr(CastExpr { argument, .. }) => contain_subquery(argument),
+        ScalarExpr::LambdaFunction(LambdaFunc { args, .. }) => args.iter().any(contain_subquery),
         _ => false,
     }
 }
@@ -198,12 +206,17 @@ pub fn prune_by_children(scalar: &ScalarExpr, columns: &HashSet<ScalarExpr>) ->
             .args
             .iter()
             .all(|arg| prune_by_children(arg, columns)),
+        ScalarExpr::WindowFunction(_) => false,
         ScalarExpr::FunctionCall(scalar) => scalar
             .arguments
             .iter()
             .all(|arg| prune_by_children(arg, columns)),
         ScalarExpr::CastExpr(expr) => prune_by_children(expr.argument.as_ref(), columns),
         ScalarExpr::SubqueryExpr(_) => false,
+        ScalarExpr::LambdaFunction(scalar) => scalar
+            .args
+            .iter()
+            .all(|arg| prune_by_children(arg, columns)),
     }
 }
 
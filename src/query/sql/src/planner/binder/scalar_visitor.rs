@@ -19,9 +19,11 @@ use crate::plans::AndExpr;
 use crate::plans::CastExpr;
 use crate::plans::ComparisonExpr;
 use crate::plans::FunctionCall;
+use crate::plans::LambdaFunc;
 use crate::plans::NotExpr;
 use crate::plans::OrExpr;
 use crate::plans::ScalarExpr;
+use crate::plans::WindowFunc;
 
 /// Controls how the visitor recursion should proceed.
 pub enum Recursion<V: ScalarVisitor> {
@@ -60,6 +62,22 @@ pub trait ScalarVisitor: Sized {
                                         stack.push(RecursionProcessing::Call(arg));
                                     }
                                 }
+                                ScalarExpr::WindowFunction(WindowFunc {
+                                    func,
+                                    partition_by,
+                                    order_by,
+                                    ..
+                                }) => {
+                                    for arg in func.arguments() {
+                                        stack.push(RecursionProcessing::Call(arg));
+                                    }
+                                    for arg in partition_by {
+                                        stack.push(RecursionProcessing::Call(arg));
+                                    }
+                                    for order in order_by {
+                                        stack.push(RecursionProcessing::Call(&order.expr));
+                                    }
+                                }
                                 ScalarExpr::ComparisonExpr(ComparisonExpr {
                                     left, right, ..
                                 }) => {
@@ -87,6 +105,11 @@ pub trait ScalarVisitor: Sized {
                                     stack.push(RecursionProcessing::Call(argument))
                                 }
                                 ScalarExpr::SubqueryExpr(_) => {}
+                                ScalarExpr::LambdaFunction(LambdaFunc { args, .. }) => {
+                                    for arg in args.iter() {
+                                        stack.push(RecursionProcessing::Call(arg));
+                                    }
+                                }
                             }
 
                             visitor
@@ -25,9 +25,16 @@ use common_ast::ast::SelectStmt;
 use common_ast::ast::SelectTarget;
 use common_ast::ast::SetExpr;
 use common_ast::ast::SetOperator;
+use common_ast::ast::SubqueryModifier;
 use common_ast::ast::TableReference;
+use common_ast::ast::CTE;
+use common_ast::walk_expr;
+use common_ast::walk_query;
+use common_ast::walk_table_reference;
+use common_ast::Visitor;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_exception::Span;
 use common_expression::type_check::common_super_type;
 use common_expression::types::DataType;
 use common_functions::scalars::BUILTIN_FUNCTIONS;
@@ -36,6 +43,8 @@ use crate::binder::join::JoinConditions;
 use crate::binder::scalar_common::split_conjunctions;
 use crate::binder::CteInfo;
 use crate::binder::Visibility;
+use crate::normalize_identifier;
+use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
 use crate::planner::binder::scalar::ScalarBinder;
 use crate::planner::binder::BindContext;
@@ -45,11 +54,16 @@ use crate::plans::CastExpr;
 use crate::plans::EvalScalar;
 use crate::plans::Filter;
 use crate::plans::JoinType;
+use crate::plans::MaterializedCte;
+use crate::plans::RecursiveCte;
+use crate::plans::RecursiveCteScan;
+use crate::plans::RelOperator;
 use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
 use crate::plans::UnionAll;
 use crate::ColumnBinding;
 use crate::IndexType;
+use crate::NameResolutionContext;
 
 // A normalized IR for `SELECT` clause.
 #[derive(Debug, Default)]
@@ -96,16 +110,24 @@ impl Binder {
             s_expr = self.bind_where(&from_context, expr, s_expr).await?;
         }
 
+        // Expand named windows in select list, e.g. `SELECT sum(a) OVER w FROM t WINDOW w AS (...)`
+        let expanded_select_list = self.expand_window_definitions(stmt)?;
+        let select_targets = expanded_select_list
+            .as_deref()
+            .unwrap_or(stmt.select_list.as_slice());
+
         // Generate a analyzed select list with from context
         let mut select_list = self
-            .normalize_select_list(&from_context, &stmt.select_list)
+            .normalize_select_list(&from_context, select_targets)
             .await?;
 
         let (mut scalar_items, projections) = self.analyze_projection(&select_list)?;
 
         // This will potentially add some alias group items to `from_context` if find some.
-        self.analyze_group_items(&mut from_context, &select_list, &stmt.group_by)
-            .await?;
+        if let Some(group_by) = &stmt.group_by {
+            self.analyze_group_items(&mut from_context, &select_list, group_by)
+                .await?;
+        }
 
         self.analyze_aggregate_select(&mut from_context, &mut select_list)?;
 
@@ -128,6 +150,8 @@ impl Binder {
             )
             .await?;
 
+        self.analyze_window_select(&mut from_context, &scalar_items)?;
+
         if !from_context.aggregate_info.aggregate_functions.is_empty()
             || !from_context.aggregate_info.group_items.is_empty()
         {
@@ -140,6 +164,13 @@ impl Binder {
                 .await?;
         }
 
+        if !from_context.windows.window_functions.is_empty() {
+            s_expr = self.bind_window(&from_context, s_expr)?;
+            for item in scalar_items.values_mut() {
+                item.scalar = self.replace_window_functions(&from_context, &item.scalar)?;
+            }
+        }
+
         if stmt.distinct {
             s_expr = self.bind_distinct(&from_context, &projections, &mut scalar_items, s_expr)?;
         }
@@ -195,7 +226,12 @@ impl Binder {
         bind_context: &BindContext,
         query: &Query,
     ) -> Result<(SExpr, BindContext)> {
+        let mut materialized_ctes = vec![];
         if let Some(with) = &query.with {
+            let mut counter = CteReferenceCounter::new(self.name_resolution_ctx.clone());
+            if !with.recursive {
+                counter.visit_query(query);
+            }
             for cte in with.ctes.iter() {
                 let table_name = cte.alias.name.name.clone();
                 if bind_context.ctes_map.contains_key(&table_name) {
@@ -203,11 +239,51 @@ impl Binder {
                         "duplicate cte {table_name}"
                     )));
                 }
-                let (s_expr, cte_bind_context) = self.bind_query(bind_context, &cte.query).await?;
+                if with.recursive && cte.materialized {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "AS MATERIALIZED is not supported in recursive CTE {table_name}"
+                    )));
+                }
+                let (s_expr, cte_bind_context) = if with.recursive {
+                    self.bind_recursive_cte(bind_context, &table_name, cte)
+                        .await?
+                } else {
+                    self.bind_query(bind_context, &cte.query).await?
+                };
+
+                // A CTE referenced several times is read through `CteScan`, the optimizer will
+                // decide whether to materialize it or not. Correlated CTEs are always inlined.
+                let mut cte_idx = None;
+                if !with.recursive
+                    && (cte.materialized
+                        || (counter.references(&table_name) > 1 && !counter.has_subquery))
+                    && RelExpr::with_s_expr(&s_expr)
+                        .derive_relational_prop()?
+                        .outer_columns
+                        .is_empty()
+                {
+                    let idx = self
+                        .metadata
+                        .write()
+                        .add_materialized_cte(table_name.clone());
+                    cte_idx = Some(idx);
+                    materialized_ctes.push((
+                        MaterializedCte {
+                            cte_idx: idx,
+                            cte_name: table_name.clone(),
+                            cte_columns: cte_bind_context.columns.iter().map(|c| c.index).collect(),
+                            materialized: cte.materialized,
+                        },
+                        s_expr.clone(),
+                    ));
+                }
+
                 let cte_info = CteInfo {
                     columns_alias: cte.alias.columns.iter().map(|c| c.name.clone()).collect(),
                     s_expr,
                     bind_context: cte_bind_context.clone(),
+                    cte_idx,
+                    materialized: cte.materialized,
                 };
                 bind_context.ctes_map.insert(table_name, cte_info);
             }
@@ -250,9 +326,130 @@ impl Binder {
                 .await?;
         }
 
+        // The CTEs are executed before the query reading them, a CTE may also be read by
+        // the CTEs defined after it.
+        for (cte, cte_expr) in materialized_ctes.into_iter().rev() {
+            s_expr = SExpr::create_binary(cte.into(), cte_expr, s_expr);
+        }
+
         Ok((s_expr, bind_context))
     }
 
+    /// Bind a common table expression of `WITH RECURSIVE`.
+    ///
+    /// A self-referencing CTE must be in the form of
+    /// `<non-recursive term> UNION [ALL] <recursive term>`, where only the recursive
+    /// term references the CTE itself. Other CTEs are bound as normal ones.
+    #[async_recursion]
+    async fn bind_recursive_cte(
+        &mut self,
+        bind_context: &BindContext,
+        cte_name: &str,
+        cte: &CTE,
+    ) -> Result<(SExpr, BindContext)> {
+        let set_operation = match &cte.query.body {
+            SetExpr::SetOperation(op) if op.op == SetOperator::Union => op,
+            _ => return self.bind_query(bind_context, &cte.query).await,
+        };
+
+        let (anchor_expr, anchor_context) = self
+            .bind_set_expr(bind_context, &set_operation.left, &[])
+            .await?;
+
+        // The working table holds the rows produced by the previous iteration,
+        // its columns are also the output columns of the CTE.
+        let mut working_context = BindContext::new();
+        let mut output_columns = Vec::with_capacity(anchor_context.columns.len());
+        for column in anchor_context.columns.iter() {
+            let index = self
+                .metadata
+                .write()
+                .add_derived_column(column.column_name.clone(), *column.data_type.clone());
+            output_columns.push(index);
+            working_context.add_column_binding(ColumnBinding {
+                database_name: None,
+                table_name: None,
+                column_name: column.column_name.clone(),
+                index,
+                data_type: column.data_type.clone(),
+                visibility: Visibility::Visible,
+            });
+        }
+
+        let columns_alias: Vec<String> = cte.alias.columns.iter().map(|c| c.name.clone()).collect();
+        bind_context.ctes_map.insert(cte_name.to_string(), CteInfo {
+            columns_alias,
+            s_expr: SExpr::create_leaf(
+                RecursiveCteScan {
+                    cte_name: cte_name.to_string(),
+                    columns: output_columns.clone(),
+                }
+                .into(),
+            ),
+            bind_context: working_context.clone(),
+            cte_idx: None,
+            materialized: false,
+        });
+        let recursive = self
+            .bind_set_expr(bind_context, &set_operation.right, &[])
+            .await;
+        bind_context.ctes_map.remove(cte_name);
+        let (recursive_expr, recursive_context) = recursive?;
+
+        if !Self::has_recursive_cte_scan(&recursive_expr, cte_name) {
+            return self.bind_query(bind_context, &cte.query).await;
+        }
+
+        if !cte.query.order_by.is_empty()
+            || !cte.query.limit.is_empty()
+            || cte.query.offset.is_some()
+        {
+            return Err(ErrorCode::SemanticError(format!(
+                "ORDER BY, LIMIT and OFFSET are not supported in recursive CTE {cte_name}"
+            )));
+        }
+        if anchor_context.columns.len() != recursive_context.columns.len() {
+            return Err(ErrorCode::SemanticError(
+                "SetOperation must have the same number of columns",
+            ));
+        }
+
+        // The recursive term is casted to the types of the non-recursive term.
+        let coercion_types = anchor_context
+            .columns
+            .iter()
+            .map(|column| *column.data_type.clone())
+            .collect();
+        let (_, pairs, anchor_expr, recursive_expr) = self.coercion_union_type(
+            anchor_context,
+            recursive_context,
+            anchor_expr,
+            recursive_expr,
+            coercion_types,
+        )?;
+
+        let recursive_cte = RecursiveCte {
+            cte_name: cte_name.to_string(),
+            output_columns,
+            pairs,
+            distinct: !set_operation.all,
+        };
+        Ok((
+            SExpr::create_binary(recursive_cte.into(), anchor_expr, recursive_expr),
+            working_context,
+        ))
+    }
+
+    fn has_recursive_cte_scan(s_expr: &SExpr, cte_name: &str) -> bool {
+        if let RelOperator::RecursiveCteScan(scan) = s_expr.plan() {
+            return scan.cte_name == cte_name;
+        }
+        s_expr
+            .children()
+            .iter()
+            .any(|child| Self::has_recursive_cte_scan(child, cte_name))
+    }
+
     pub(super) async fn bind_where(
         &mut self,
         bind_context: &BindContext,
@@ -267,6 +464,7 @@ impl Binder {
             &[],
         );
         let (scalar, _) = scalar_binder.bind(expr).await?;
+        self.check_no_window_function(&scalar, "WHERE clause", expr.span())?;
         let filter_plan = Filter {
             predicates: split_conjunctions(&scalar),
             is_having: false,
@@ -548,3 +746,70 @@ impl Binder {
         Ok((new_bind_context, pairs, left_expr, right_expr))
     }
 }
+
+/// Counts the references to the tables by name in a query, which tells how many times a CTE
+/// is referenced.
+struct CteReferenceCounter {
+    name_resolution_ctx: NameResolutionContext,
+    references: HashMap<String, usize>,
+    /// Columns of a CTE referenced in a subquery may be correlated, which can't be decorrelated
+    /// if the CTE is not inlined.
+    has_subquery: bool,
+}
+
+impl CteReferenceCounter {
+    fn new(name_resolution_ctx: NameResolutionContext) -> Self {
+        Self {
+            name_resolution_ctx,
+            references: HashMap::new(),
+            has_subquery: false,
+        }
+    }
+
+    fn references(&self, table_name: &str) -> usize {
+        self.references.get(table_name).cloned().unwrap_or(0)
+    }
+}
+
+impl<'ast> Visitor<'ast> for CteReferenceCounter {
+    fn visit_table_reference(&mut self, table_ref: &'ast TableReference) {
+        if let TableReference::Table {
+            catalog: None,
+            database: None,
+            table,
+            ..
+        } = table_ref
+        {
+            let table_name = normalize_identifier(table, &self.name_resolution_ctx).name;
+            *self.references.entry(table_name).or_default() += 1;
+        }
+        walk_table_reference(self, table_ref);
+    }
+
+    fn visit_in_subquery(
+        &mut self,
+        _span: Span,
+        expr: &'ast Expr,
+        subquery: &'ast Query,
+        _not: bool,
+    ) {
+        self.has_subquery = true;
+        walk_expr(self, expr);
+        walk_query(self, subquery);
+    }
+
+    fn visit_exists(&mut self, _span: Span, _not: bool, subquery: &'ast Query) {
+        self.has_subquery = true;
+        walk_query(self, subquery);
+    }
+
+    fn visit_subquery(
+        &mut self,
+        _span: Span,
+        _modifier: &'ast Option<SubqueryModifier>,
+        subquery: &'ast Query,
+    ) {
+        self.has_subquery = true;
+        walk_query(self, subquery);
+    }
+}
@@ -36,6 +36,7 @@ use crate::plans::CastExpr;
 use crate::plans::ComparisonExpr;
 use crate::plans::EvalScalar;
 use crate::plans::FunctionCall;
+use crate::plans::LambdaFunc;
 use crate::plans::NotExpr;
 use crate::plans::OrExpr;
 use crate::plans::ScalarExpr;
@@ -350,7 +351,7 @@ impl Binder {
     }
 
     #[allow(clippy::only_used_in_recursion)]
-    fn rewrite_scalar_with_replacement<F>(
+    pub(super) fn rewrite_scalar_with_replacement<F>(
         &self,
         original_scalar: &ScalarExpr,
         replacement_fn: &F,
@@ -473,6 +474,17 @@ impl Binder {
                         target_type: target_type.clone(),
                     }))
                 }
+                ScalarExpr::LambdaFunction(func) => {
+                    let args = func
+                        .args
+                        .iter()
+                        .map(|arg| self.rewrite_scalar_with_replacement(arg, replacement_fn))
+                        .collect::<Result<Vec<_>>>()?;
+                    Ok(ScalarExpr::LambdaFunction(LambdaFunc {
+                        args,
+                        ..func.clone()
+                    }))
+                }
                 _ => Ok(original_scalar.clone()),
             },
         }
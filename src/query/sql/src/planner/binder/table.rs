@@ -44,6 +44,7 @@ use common_expression::types::DataType;
 use common_expression::ColumnId;
 use common_expression::ConstantFolder;
 use common_functions::scalars::BUILTIN_FUNCTIONS;
+use common_functions::srfs::is_set_returning_function;
 use common_meta_app::principal::StageFileFormatType;
 use common_meta_app::principal::UserStageInfo;
 use common_storage::StageFilesInfo;
@@ -58,9 +59,11 @@ use crate::binder::Binder;
 use crate::binder::ColumnBinding;
 use crate::binder::CteInfo;
 use crate::binder::Visibility;
+use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
 use crate::planner::semantic::normalize_identifier;
 use crate::planner::semantic::TypeChecker;
+use crate::plans::CteScan;
 use crate::plans::Scan;
 use crate::plans::Statistics;
 use crate::BaseTableColumn;
@@ -225,12 +228,33 @@ impl Binder {
                 }
             }
             TableReference::TableFunction {
-                span: _,
+                span,
+                lateral,
                 name,
                 params,
                 named_params,
                 alias,
             } => {
+                if is_set_returning_function(&name.name) {
+                    return self
+                        .bind_set_returning_function(
+                            bind_context,
+                            *span,
+                            name,
+                            params,
+                            named_params,
+                            alias,
+                        )
+                        .await;
+                }
+                if *lateral {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "LATERAL is only supported for set-returning functions, but got {}",
+                        &name.name
+                    ))
+                    .set_span(*span));
+                }
+
                 let mut scalar_binder = ScalarBinder::new(
                     bind_context,
                     self.ctx.clone(),
@@ -397,7 +421,37 @@ impl Binder {
         for (index, column_name) in cols_alias.iter().enumerate() {
             new_bind_context.columns[index].column_name = column_name.clone();
         }
-        Ok((cte_info.s_expr.clone(), new_bind_context))
+
+        let cte_idx = match cte_info.cte_idx {
+            Some(cte_idx) => cte_idx,
+            None => return Ok((cte_info.s_expr.clone(), new_bind_context)),
+        };
+        // Each reference to the CTE has its own columns, so that the CTE can be self-joined.
+        let mut cte_columns = Vec::with_capacity(new_bind_context.columns.len());
+        let mut columns = Vec::with_capacity(new_bind_context.columns.len());
+        for column in new_bind_context.columns.iter_mut() {
+            let index = self
+                .metadata
+                .write()
+                .add_derived_column(column.column_name.clone(), *column.data_type.clone());
+            cte_columns.push(column.index);
+            columns.push(index);
+            column.index = index;
+        }
+        let cardinality = RelExpr::with_s_expr(&cte_info.s_expr)
+            .derive_relational_prop()?
+            .cardinality;
+        let s_expr = SExpr::create_leaf(
+            CteScan {
+                cte_idx,
+                cte_name: table_name.to_string(),
+                cte_columns,
+                columns,
+                cardinality,
+            }
+            .into(),
+        );
+        Ok((s_expr, new_bind_context))
     }
 
     async fn bind_base_table(
@@ -0,0 +1,470 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use common_ast::ast::Identifier;
+use common_ast::ast::SelectStmt;
+use common_ast::ast::SelectTarget;
+use common_ast::ast::Window as AstWindow;
+use common_ast::ast::WindowDefinition;
+use common_ast::ast::WindowSpec;
+use common_ast::VisitorMut;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::Span;
+
+use crate::binder::find_window_functions;
+use crate::binder::ColumnBinding;
+use crate::binder::Visibility;
+use crate::normalize_identifier;
+use crate::optimizer::SExpr;
+use crate::planner::semantic::GroupingChecker;
+use crate::plans::BoundColumnRef;
+use crate::plans::EvalScalar;
+use crate::plans::ScalarExpr;
+use crate::plans::ScalarItem;
+use crate::plans::Sort;
+use crate::plans::SortItem;
+use crate::plans::Window;
+use crate::plans::WindowFunc;
+use crate::plans::WindowFuncFrame;
+use crate::plans::WindowFuncType;
+use crate::plans::WindowOrderByInfo;
+use crate::BindContext;
+use crate::Binder;
+use crate::IndexType;
+use crate::MetadataRef;
+use crate::NameResolutionContext;
+
+#[derive(Default, Clone, PartialEq, Eq, Debug)]
+pub struct WindowInfo {
+    /// Window functions in current context
+    pub window_functions: Vec<WindowFunctionInfo>,
+
+    /// Mapping: (window function display name) -> (index of window function in `window_functions`)
+    /// This is used to find a window function in current context.
+    pub window_functions_map: HashMap<String, usize>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct WindowFunctionInfo {
+    /// Index of the output column of window function
+    pub index: IndexType,
+    /// Window function whose arguments are replaced with `BoundColumnRef`
+    pub func: WindowFuncType,
+    pub arguments: Vec<ScalarItem>,
+    pub partition_by_items: Vec<ScalarItem>,
+    pub order_by_items: Vec<WindowOrderByInfo>,
+    pub frame: WindowFuncFrame,
+}
+
+impl WindowInfo {
+    /// Check if the column is the output of a window function.
+    pub fn is_window_output(&self, index: IndexType) -> bool {
+        self.window_functions.iter().any(|w| w.index == index)
+    }
+}
+
+/// Collect the window functions in a scalar expression, and register the
+/// window functions and their arguments into `WindowInfo`.
+struct WindowRewriter<'a> {
+    bind_context: &'a mut BindContext,
+    metadata: MetadataRef,
+}
+
+impl<'a> WindowRewriter<'a> {
+    fn new(bind_context: &'a mut BindContext, metadata: MetadataRef) -> Self {
+        Self {
+            bind_context,
+            metadata,
+        }
+    }
+
+    fn visit(&mut self, scalar: &ScalarExpr) -> Result<()> {
+        for window in find_window_functions(scalar)? {
+            if let ScalarExpr::WindowFunction(window) = window {
+                if !self
+                    .bind_context
+                    .windows
+                    .window_functions_map
+                    .contains_key(&window.display_name)
+                {
+                    self.register_window_function(&window);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Create a derived column for the expression if it's not a column reference.
+    fn create_item(&mut self, name: String, scalar: &ScalarExpr) -> ScalarItem {
+        let index = if let ScalarExpr::BoundColumnRef(column_ref) = scalar {
+            column_ref.column.index
+        } else {
+            self.metadata
+                .write()
+                .add_derived_column(name, scalar.data_type())
+        };
+        ScalarItem {
+            scalar: scalar.clone(),
+            index,
+        }
+    }
+
+    fn register_window_function(&mut self, window: &WindowFunc) {
+        let func_name = window.func.func_name();
+        let mut arguments = vec![];
+        let mut replaced_args = vec![];
+        for (i, arg) in window.func.arguments().into_iter().enumerate() {
+            let name = format!("{func_name}_arg_{i}");
+            let item = self.create_item(name.clone(), arg);
+            replaced_args.push(
+                BoundColumnRef {
+                    column: ColumnBinding {
+                        database_name: None,
+                        table_name: None,
+                        column_name: name,
+                        index: item.index,
+                        data_type: Box::new(arg.data_type()),
+                        visibility: Visibility::Visible,
+                    },
+                }
+                .into(),
+            );
+            arguments.push(item);
+        }
+        let func = window.func.with_arguments(replaced_args);
+
+        let partition_by_items = window
+            .partition_by
+            .iter()
+            .enumerate()
+            .map(|(i, part)| self.create_item(format!("partition_by_{i}"), part))
+            .collect();
+
+        let order_by_items = window
+            .order_by
+            .iter()
+            .enumerate()
+            .map(|(i, order)| WindowOrderByInfo {
+                order_by_item: self.create_item(format!("order_by_{i}"), &order.expr),
+                asc: order.asc,
+                nulls_first: order.nulls_first,
+            })
+            .collect();
+
+        let index = self
+            .metadata
+            .write()
+            .add_derived_column(window.display_name.clone(), window.func.return_type());
+
+        let windows = &mut self.bind_context.windows;
+        windows.window_functions.push(WindowFunctionInfo {
+            index,
+            func,
+            arguments,
+            partition_by_items,
+            order_by_items,
+            frame: window.frame.clone(),
+        });
+        windows.window_functions_map.insert(
+            window.display_name.clone(),
+            windows.window_functions.len() - 1,
+        );
+    }
+}
+
+/// Replace the named window reference, e.g. `OVER w`, with the definition
+/// in `WINDOW` clause.
+struct WindowDefinitionExpander<'a> {
+    // Expanded window definitions, which don't reference other windows.
+    definitions: HashMap<String, WindowSpec>,
+    name_resolution_ctx: &'a NameResolutionContext,
+    error: Option<ErrorCode>,
+}
+
+impl<'a> WindowDefinitionExpander<'a> {
+    fn expand_spec(&self, spec: &WindowSpec) -> Result<WindowSpec> {
+        match &spec.existing_window_name {
+            Some(name) => {
+                let base = self.lookup(name)?;
+                if !base.order_by.is_empty() && !spec.order_by.is_empty() {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "cannot override ORDER BY clause of window '{}'",
+                        name.name
+                    ))
+                    .set_span(name.span));
+                }
+                if !spec.partition_by.is_empty() {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "cannot override PARTITION BY clause of window '{}'",
+                        name.name
+                    ))
+                    .set_span(name.span));
+                }
+                if base.window_frame.is_some() {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "cannot copy window '{}' because it has a frame clause",
+                        name.name
+                    ))
+                    .set_span(name.span));
+                }
+                Ok(WindowSpec {
+                    existing_window_name: None,
+                    partition_by: base.partition_by,
+                    order_by: if spec.order_by.is_empty() {
+                        base.order_by
+                    } else {
+                        spec.order_by.clone()
+                    },
+                    window_frame: spec.window_frame.clone(),
+                })
+            }
+            None => Ok(spec.clone()),
+        }
+    }
+
+    fn lookup(&self, name: &Identifier) -> Result<WindowSpec> {
+        let window_name = normalize_identifier(name, self.name_resolution_ctx).name;
+        match self.definitions.get(&window_name) {
+            Some(spec) => Ok(spec.clone()),
+            None => Err(
+                ErrorCode::SemanticError(format!("window '{}' is not defined", name.name))
+                    .set_span(name.span),
+            ),
+        }
+    }
+}
+
+impl<'a> VisitorMut for WindowDefinitionExpander<'a> {
+    fn visit_window(&mut self, window: &mut AstWindow) {
+        if self.error.is_some() {
+            return;
+        }
+        let spec = match window {
+            AstWindow::WindowReference(reference) => self.lookup(&reference.window_name),
+            AstWindow::WindowSpec(spec) => self.expand_spec(spec),
+        };
+        match spec {
+            Ok(spec) => *window = AstWindow::WindowSpec(spec),
+            Err(err) => self.error = Some(err),
+        }
+    }
+}
+
+impl Binder {
+    /// Expand the named windows defined in `WINDOW` clause for select list.
+    /// Returns `None` if there isn't any `WINDOW` clause.
+    pub(super) fn expand_window_definitions(
+        &self,
+        stmt: &SelectStmt,
+    ) -> Result<Option<Vec<SelectTarget>>> {
+        let window_list = match &stmt.window_list {
+            Some(window_list) => window_list,
+            None => return Ok(None),
+        };
+
+        let mut expander = WindowDefinitionExpander {
+            definitions: HashMap::with_capacity(window_list.len()),
+            name_resolution_ctx: &self.name_resolution_ctx,
+            error: None,
+        };
+        // A window definition can only reference the windows defined before it.
+        for WindowDefinition { name, spec } in window_list.iter() {
+            let window_name = normalize_identifier(name, &self.name_resolution_ctx).name;
+            if expander.definitions.contains_key(&window_name) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "window '{}' is already defined",
+                    name.name
+                ))
+                .set_span(name.span));
+            }
+            let spec = expander.expand_spec(spec)?;
+            expander.definitions.insert(window_name, spec);
+        }
+
+        let mut select_list = stmt.select_list.clone();
+        for target in select_list.iter_mut() {
+            if let SelectTarget::AliasedExpr { expr, .. } = target {
+                expander.visit_expr(expr);
+            }
+        }
+        if let Some(err) = expander.error {
+            return Err(err);
+        }
+
+        Ok(Some(select_list))
+    }
+
+    /// Analyze window functions in select clause and order by clause,
+    /// the window functions will be registered in `WindowInfo`.
+    pub(super) fn analyze_window_select(
+        &mut self,
+        bind_context: &mut BindContext,
+        scalar_items: &HashMap<IndexType, ScalarItem>,
+    ) -> Result<()> {
+        let mut items = scalar_items.values().collect::<Vec<_>>();
+        // Keep the order of window functions stable.
+        items.sort_by_key(|item| item.index);
+        for item in items {
+            let mut rewriter = WindowRewriter::new(bind_context, self.metadata.clone());
+            rewriter.visit(&item.scalar)?;
+        }
+        Ok(())
+    }
+
+    /// Build window plans for window functions:
+    ///   EvalScalar(arguments, partition by and order by items) -> Sort -> Window -> Sort -> Window...
+    pub(super) fn bind_window(
+        &mut self,
+        bind_context: &BindContext,
+        child: SExpr,
+    ) -> Result<SExpr> {
+        let windows = &bind_context.windows;
+
+        // Evaluate the arguments, partition by and order by items of all window functions.
+        let mut scalar_items = vec![];
+        let mut evaluated = HashSet::new();
+        for window in windows.window_functions.iter() {
+            let items = window
+                .arguments
+                .iter()
+                .chain(window.partition_by_items.iter())
+                .chain(window.order_by_items.iter().map(|o| &o.order_by_item));
+            for item in items {
+                let scalar = if bind_context.in_grouping {
+                    let mut grouping_checker = GroupingChecker::new(bind_context);
+                    grouping_checker.resolve(&item.scalar, None)?
+                } else {
+                    item.scalar.clone()
+                };
+                if let ScalarExpr::BoundColumnRef(column_ref) = &scalar {
+                    if column_ref.column.index == item.index {
+                        continue;
+                    }
+                }
+                if evaluated.insert(item.index) {
+                    scalar_items.push(ScalarItem {
+                        scalar,
+                        index: item.index,
+                    });
+                }
+            }
+        }
+
+        let mut new_expr = child;
+        if !scalar_items.is_empty() {
+            let eval_scalar = EvalScalar {
+                items: scalar_items,
+            };
+            new_expr = SExpr::create_unary(eval_scalar.into(), new_expr);
+        }
+
+        // null is the largest value in databend, smallest in hive
+        let default_nulls_first = !self.ctx.get_settings().get_sql_dialect()?.is_null_biggest();
+
+        let mut last_sort_items: Option<Vec<SortItem>> = None;
+        for window in windows.window_functions.iter() {
+            let sort_items = window
+                .partition_by_items
+                .iter()
+                .map(|item| SortItem {
+                    index: item.index,
+                    asc: true,
+                    nulls_first: default_nulls_first,
+                })
+                .chain(window.order_by_items.iter().map(|order| SortItem {
+                    index: order.order_by_item.index,
+                    asc: order.asc.unwrap_or(true),
+                    nulls_first: order.nulls_first.unwrap_or(default_nulls_first),
+                }))
+                .collect::<Vec<_>>();
+
+            // The input is already sorted if the previous window has the same sort keys.
+            if !sort_items.is_empty() && last_sort_items.as_ref() != Some(&sort_items) {
+                let sort_plan = Sort {
+                    items: sort_items.clone(),
+                    limit: None,
+                };
+                new_expr = SExpr::create_unary(sort_plan.into(), new_expr);
+            }
+            last_sort_items = Some(sort_items);
+
+            let window_plan = Window {
+                index: window.index,
+                function: window.func.clone(),
+                arguments: window.arguments.clone(),
+                partition_by: window.partition_by_items.clone(),
+                order_by: window.order_by_items.clone(),
+                frame: window.frame.clone(),
+            };
+            new_expr = SExpr::create_unary(window_plan.into(), new_expr);
+        }
+
+        Ok(new_expr)
+    }
+
+    /// Replace the window functions in scalar expression with the output
+    /// column of corresponding `Window` plan.
+    pub(super) fn replace_window_functions(
+        &self,
+        bind_context: &BindContext,
+        scalar: &ScalarExpr,
+    ) -> Result<ScalarExpr> {
+        self.rewrite_scalar_with_replacement(scalar, &|nest_scalar| {
+            if let ScalarExpr::WindowFunction(window) = nest_scalar {
+                let windows = &bind_context.windows;
+                return match windows.window_functions_map.get(&window.display_name) {
+                    Some(i) => {
+                        let window_info = &windows.window_functions[*i];
+                        Ok(Some(
+                            BoundColumnRef {
+                                column: ColumnBinding {
+                                    database_name: None,
+                                    table_name: None,
+                                    column_name: window.display_name.clone(),
+                                    index: window_info.index,
+                                    data_type: Box::new(window_info.func.return_type()),
+                                    visibility: Visibility::Visible,
+                                },
+                            }
+                            .into(),
+                        ))
+                    }
+                    None => Err(ErrorCode::Internal("Invalid window function")),
+                };
+            }
+            Ok(None)
+        })
+    }
+
+    /// Window functions are evaluated after `WHERE`, `GROUP BY` and `HAVING`, so they
+    /// are not allowed to appear in these clauses.
+    pub(super) fn check_no_window_function(
+        &self,
+        scalar: &ScalarExpr,
+        clause: &str,
+        span: Span,
+    ) -> Result<()> {
+        if !find_window_functions(scalar)?.is_empty() {
+            return Err(ErrorCode::SemanticError(format!(
+                "window functions are not allowed in {clause}"
+            ))
+            .set_span(span));
+        }
+        Ok(())
+    }
+}
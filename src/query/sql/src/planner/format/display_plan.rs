@@ -53,12 +53,19 @@ impl Plan {
             // Tables
             Plan::ShowCreateTable(show_create_table) => Ok(format!("{:?}", show_create_table)),
             Plan::CreateTable(create_table) => Ok(format!("{:?}", create_table)),
+            Plan::CloneTable(clone_table) => Ok(format!("{:?}", clone_table)),
             Plan::DropTable(drop_table) => Ok(format!("{:?}", drop_table)),
             Plan::UndropTable(undrop_table) => Ok(format!("{:?}", undrop_table)),
             Plan::DescribeTable(describe_table) => Ok(format!("{:?}", describe_table)),
             Plan::RenameTable(rename_table) => Ok(format!("{:?}", rename_table)),
             Plan::AddTableColumn(add_table_column) => Ok(format!("{:?}", add_table_column)),
             Plan::DropTableColumn(drop_table_column) => Ok(format!("{:?}", drop_table_column)),
+            Plan::RenameTableColumn(rename_table_column) => {
+                Ok(format!("{:?}", rename_table_column))
+            }
+            Plan::ModifyTableColumnType(modify_table_column_type) => {
+                Ok(format!("{:?}", modify_table_column_type))
+            }
             Plan::AlterTableClusterKey(alter_table_cluster_key) => {
                 Ok(format!("{:?}", alter_table_cluster_key))
             }
@@ -76,10 +83,25 @@ impl Plan {
             Plan::AlterView(alter_view) => Ok(format!("{:?}", alter_view)),
             Plan::DropView(drop_view) => Ok(format!("{:?}", drop_view)),
 
+            // Streams
+            Plan::CreateStream(create_stream) => Ok(format!("{:?}", create_stream)),
+            Plan::DropStream(drop_stream) => Ok(format!("{:?}", drop_stream)),
+
+            // Aggregating indexes
+            Plan::CreateAggregatingIndex(create_index) => Ok(format!("{:?}", create_index)),
+            Plan::DropAggregatingIndex(drop_index) => Ok(format!("{:?}", drop_index)),
+
             // Insert
             Plan::Insert(insert) => Ok(format!("{:?}", insert)),
+            Plan::Replace(replace) => Ok(format!("{:?}", replace)),
             Plan::Delete(delete) => Ok(format!("{:?}", delete)),
             Plan::Update(update) => Ok(format!("{:?}", update)),
+            Plan::MergeInto(merge_into) => Ok(format!("{:?}", merge_into)),
+
+            // Transactions
+            Plan::Begin => Ok("Begin".to_string()),
+            Plan::Commit => Ok("Commit".to_string()),
+            Plan::Abort => Ok("Abort".to_string()),
 
             // Stages
             Plan::ListStage(s) => Ok(format!("{:?}", s)),
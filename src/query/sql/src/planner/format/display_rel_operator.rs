@@ -78,6 +78,12 @@ impl Display for FormatContext {
                 RelOperator::UnionAll(_) => write!(f, "Union"),
                 RelOperator::Pattern(_) => write!(f, "Pattern"),
                 RelOperator::DummyTableScan(_) => write!(f, "DummyTableScan"),
+                RelOperator::Window(_) => write!(f, "Window"),
+                RelOperator::RecursiveCte(_) => write!(f, "RecursiveCte"),
+                RelOperator::RecursiveCteScan(_) => write!(f, "RecursiveCteScan"),
+                RelOperator::MaterializedCte(_) => write!(f, "MaterializedCte"),
+                RelOperator::CteScan(_) => write!(f, "CteScan"),
+                RelOperator::ProjectSet(_) => write!(f, "ProjectSet"),
             },
             Self::Text(text) => write!(f, "{}", text),
         }
@@ -118,6 +124,7 @@ pub fn format_scalar(_metadata: &MetadataRef, scalar: &ScalarExpr) -> String {
             format_scalar(_metadata, &comp.right)
         ),
         ScalarExpr::AggregateFunction(agg) => agg.display_name.clone(),
+        ScalarExpr::WindowFunction(window) => window.display_name.clone(),
         ScalarExpr::FunctionCall(func) => {
             format!(
                 "{}({})",
@@ -137,6 +144,19 @@ pub fn format_scalar(_metadata: &MetadataRef, scalar: &ScalarExpr) -> String {
             )
         }
         ScalarExpr::SubqueryExpr(_) => "SUBQUERY".to_string(),
+        ScalarExpr::LambdaFunction(lambda) => {
+            format!(
+                "{}({}, {})",
+                &lambda.func_name,
+                lambda
+                    .args
+                    .iter()
+                    .map(|arg| { format_scalar(_metadata, arg) })
+                    .collect::<Vec<String>>()
+                    .join(", "),
+                &lambda.lambda_display
+            )
+        }
     }
 }
 
@@ -28,6 +28,7 @@ use crate::optimizer::cost::CostModel;
 use crate::optimizer::cost::DefaultCostModel;
 use crate::optimizer::format::display_memo;
 use crate::optimizer::memo::Memo;
+use crate::optimizer::rule::RuleID;
 use crate::optimizer::rule::RuleSet;
 use crate::optimizer::rule::TransformResult;
 use crate::optimizer::SExpr;
@@ -47,10 +48,15 @@ pub struct CascadesOptimizer {
 }
 
 impl CascadesOptimizer {
-    pub fn create(ctx: Arc<dyn TableContext>) -> Result<Self> {
+    pub fn create(ctx: Arc<dyn TableContext>, dphyp_optimized: bool) -> Result<Self> {
         let explore_rules = if ctx.get_settings().get_enable_cbo()? {
-            let enable_bushy_join = ctx.get_settings().get_enable_bushy_join()? != 0;
-            get_explore_rule_set(enable_bushy_join)
+            if dphyp_optimized {
+                // Join order has been decided by DPhyp, only choose the build side of joins.
+                RuleSet::create_with_ids(vec![RuleID::CommuteJoin]).unwrap()
+            } else {
+                let enable_bushy_join = ctx.get_settings().get_enable_bushy_join()? != 0;
+                get_explore_rule_set(enable_bushy_join)
+            }
         } else {
             RuleSet::create_with_ids(vec![]).unwrap()
         };
@@ -39,14 +39,20 @@ impl CostModel for DefaultCostModel {
 fn compute_cost_impl(memo: &Memo, m_expr: &MExpr) -> Result<Cost> {
     match &m_expr.plan {
         RelOperator::Scan(plan) => compute_cost_scan(memo, m_expr, plan),
-        RelOperator::DummyTableScan(_) => Ok(Cost(0.0)),
+        RelOperator::DummyTableScan(_) | RelOperator::RecursiveCteScan(_) => Ok(Cost(0.0)),
+        RelOperator::CteScan(_) => compute_cost_cte_scan(memo, m_expr),
+        RelOperator::MaterializedCte(_) => compute_cost_materialized_cte(memo, m_expr),
         RelOperator::Join(plan) => compute_cost_join(memo, m_expr, plan),
-        RelOperator::UnionAll(_) => compute_cost_union_all(memo, m_expr),
+        RelOperator::UnionAll(_) | RelOperator::RecursiveCte(_) => {
+            compute_cost_union_all(memo, m_expr)
+        }
 
         RelOperator::EvalScalar(_)
         | RelOperator::Filter(_)
         | RelOperator::Aggregate(_)
         | RelOperator::Sort(_)
+        | RelOperator::Window(_)
+        | RelOperator::ProjectSet(_)
         | RelOperator::Limit(_) => compute_cost_unary_common_operator(memo, m_expr),
 
         _ => Err(ErrorCode::Internal("Cannot compute cost from logical plan")),
@@ -62,6 +68,19 @@ fn compute_cost_scan(memo: &Memo, m_expr: &MExpr, _plan: &Scan) -> Result<Cost>
     Ok(Cost(cost))
 }
 
+fn compute_cost_cte_scan(memo: &Memo, m_expr: &MExpr) -> Result<Cost> {
+    let group = memo.group(m_expr.group_index)?;
+    let cost = group.relational_prop.cardinality * COST_FACTOR_COMPUTE_PER_ROW;
+    Ok(Cost(cost))
+}
+
+/// The result of the CTE is buffered once, it's cheap compared with the computation of the CTE.
+fn compute_cost_materialized_cte(memo: &Memo, m_expr: &MExpr) -> Result<Cost> {
+    let group = m_expr.child_group(memo, 0)?;
+    let cost = group.relational_prop.cardinality * COST_FACTOR_COMPUTE_PER_ROW;
+    Ok(Cost(cost))
+}
+
 fn compute_cost_join(memo: &Memo, m_expr: &MExpr, plan: &Join) -> Result<Cost> {
     let build_group = m_expr.child_group(memo, 1)?;
     let probe_group = m_expr.child_group(memo, 0)?;
@@ -46,6 +46,12 @@ pub fn display_rel_op(rel_op: &RelOperator) -> String {
         RelOperator::Exchange(_) => "Exchange".to_string(),
         RelOperator::Pattern(_) => "Pattern".to_string(),
         RelOperator::DummyTableScan(_) => "DummyTableScan".to_string(),
+        RelOperator::Window(_) => "Window".to_string(),
+        RelOperator::RecursiveCte(_) => "RecursiveCte".to_string(),
+        RelOperator::RecursiveCteScan(_) => "RecursiveCteScan".to_string(),
+        RelOperator::MaterializedCte(_) => "MaterializedCte".to_string(),
+        RelOperator::CteScan(_) => "CteScan".to_string(),
+        RelOperator::ProjectSet(_) => "ProjectSet".to_string(),
     }
 }
 
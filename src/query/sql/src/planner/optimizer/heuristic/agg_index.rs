@@ -0,0 +1,432 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_catalog::table::Table;
+use common_exception::Result;
+use common_expression::infer_schema_type;
+use common_expression::types::DataType;
+use common_expression::ColumnId;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_functions::aggregates::AggregateFunctionFactory;
+use storages_common_table_meta::table::AggregatingIndex;
+use storages_common_table_meta::table::AggregatingIndexFunc;
+
+use crate::binder::Visibility;
+use crate::optimizer::SExpr;
+use crate::plans::Aggregate;
+use crate::plans::AggregateFunction;
+use crate::plans::AggregateMode;
+use crate::plans::BoundColumnRef;
+use crate::plans::EvalScalar;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::plans::ScalarItem;
+use crate::plans::Scan;
+use crate::plans::Statistics;
+use crate::BaseTableColumn;
+use crate::ColumnBinding;
+use crate::ColumnEntry;
+use crate::Metadata;
+use crate::MetadataRef;
+
+/// The aggregate functions an aggregating index can pre-compute, the results of them on each
+/// block are merged with the paired function.
+const INDEXABLE_AGGREGATE_FUNCTIONS: [(&str, &str); 4] = [
+    ("sum", "sum"),
+    ("count", "sum"),
+    ("min", "min"),
+    ("max", "max"),
+];
+
+/// An aggregation directly on the scan of a table, `SELECT <keys>, <aggregates> FROM t GROUP BY
+/// <keys>`, which is what an aggregating index pre-computes.
+pub struct ScanAggregation {
+    pub aggregate: Aggregate,
+    pub scan: Scan,
+    /// the columns of the table the rows are grouped by, in the order of the group items.
+    pub group_by: Vec<BaseTableColumn>,
+    /// the aggregate functions with the column of their argument, `None` for `count(*)`.
+    pub aggregates: Vec<(AggregateFunction, Option<BaseTableColumn>)>,
+}
+
+impl ScanAggregation {
+    /// Match an `Aggregate` on a `Scan`, with an optional `EvalScalar` in between that only
+    /// passes the columns through. The group items must be columns of the table, and the
+    /// aggregate functions must be indexable ones on a column.
+    pub fn try_match(s_expr: &SExpr, metadata: &Metadata) -> Option<Self> {
+        let aggregate = match s_expr.plan() {
+            RelOperator::Aggregate(aggregate) => aggregate,
+            _ => return None,
+        };
+        if aggregate.mode != AggregateMode::Initial
+            || aggregate.from_distinct
+            || aggregate.limit.is_some()
+            || aggregate.grouping_sets.is_some()
+            || aggregate.group_items.is_empty()
+        {
+            return None;
+        }
+
+        let mut input = s_expr.child(0).ok()?;
+        if let RelOperator::EvalScalar(eval_scalar) = input.plan() {
+            let passes_through = eval_scalar.items.iter().all(|item| match &item.scalar {
+                ScalarExpr::BoundColumnRef(column_ref) => column_ref.column.index == item.index,
+                _ => false,
+            });
+            if !passes_through {
+                return None;
+            }
+            input = input.child(0).ok()?;
+        }
+        let scan = match input.plan() {
+            RelOperator::Scan(scan) => scan,
+            _ => return None,
+        };
+        if scan.push_down_predicates.is_some()
+            || scan.limit.is_some()
+            || scan.order_by.is_some()
+            || scan.prewhere.is_some()
+        {
+            return None;
+        }
+
+        let column_of = |scalar: &ScalarExpr| match scalar {
+            ScalarExpr::BoundColumnRef(column_ref) => {
+                match metadata.column(column_ref.column.index) {
+                    ColumnEntry::BaseTableColumn(column)
+                        if column.table_index == scan.table_index
+                            && column.path_indices.is_none()
+                            && !matches!(
+                                column.data_type.remove_nullable(),
+                                TableDataType::Tuple { .. }
+                            ) =>
+                    {
+                        Some(column.clone())
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+
+        let group_by = aggregate
+            .group_items
+            .iter()
+            .map(|item| column_of(&item.scalar).filter(|column| column.column_index == item.index))
+            .collect::<Option<Vec<_>>>()?;
+        let aggregates = aggregate
+            .aggregate_functions
+            .iter()
+            .map(|item| match &item.scalar {
+                ScalarExpr::AggregateFunction(func)
+                    if merge_function(&func.func_name).is_some()
+                        && !func.distinct
+                        && func.params.is_empty() =>
+                {
+                    let arg = match func.args.as_slice() {
+                        [] if func.func_name.eq_ignore_ascii_case("count") => None,
+                        [arg] => Some(column_of(arg)?),
+                        _ => return None,
+                    };
+                    Some((func.clone(), arg))
+                }
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(ScanAggregation {
+            aggregate: aggregate.clone(),
+            scan: scan.clone(),
+            group_by,
+            aggregates,
+        })
+    }
+
+    /// Build the definition of an aggregating index that pre-computes the aggregation, the id
+    /// of the index is left empty.
+    pub fn to_aggregating_index(
+        &self,
+        table: &dyn Table,
+        name: String,
+        query: String,
+    ) -> Result<AggregatingIndex> {
+        let schema = table.schema();
+        let field_of = |column: &BaseTableColumn| -> Result<TableField> {
+            Ok(schema.field_with_name(&column.column_name)?.clone())
+        };
+
+        let group_by = self
+            .group_by
+            .iter()
+            .map(field_of)
+            .collect::<Result<Vec<_>>>()?;
+        let aggregates = self
+            .aggregates
+            .iter()
+            .map(|(func, arg)| {
+                Ok(AggregatingIndexFunc {
+                    func_name: func.func_name.to_lowercase(),
+                    arg: arg.as_ref().map(field_of).transpose()?,
+                    data_type: infer_schema_type(&func.return_type)?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(AggregatingIndex {
+            id: String::new(),
+            name,
+            query,
+            group_by,
+            aggregates,
+        })
+    }
+}
+
+fn merge_function(func_name: &str) -> Option<&'static str> {
+    INDEXABLE_AGGREGATE_FUNCTIONS
+        .iter()
+        .find(|(name, _)| func_name.eq_ignore_ascii_case(name))
+        .map(|(_, merge)| *merge)
+}
+
+/// Rewrites the aggregations on fuse tables to read the pre-computed results of an aggregating
+/// index instead of the rows of the table, when the keys of the aggregation are a subset of
+/// the keys of the index and every aggregate function is pre-computed by the index.
+///
+/// The index table returns the index data of each block, or computes it from the rows of the
+/// block if the block isn't indexed yet, and the results are merged by the rewritten
+/// aggregation, e.g. the counts of the blocks are summed up.
+pub struct AggIndexRewriter {
+    metadata: MetadataRef,
+}
+
+impl AggIndexRewriter {
+    pub fn new(metadata: MetadataRef) -> Self {
+        Self { metadata }
+    }
+
+    pub fn rewrite(&self, s_expr: &SExpr) -> Result<SExpr> {
+        let matched = ScanAggregation::try_match(s_expr, &self.metadata.read());
+        if let Some(matched) = matched {
+            if let Some(result) = self.rewrite_aggregation(&matched)? {
+                return Ok(result);
+            }
+        }
+
+        let children = s_expr
+            .children()
+            .iter()
+            .map(|child| self.rewrite(child))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(s_expr.replace_children(children))
+    }
+
+    fn rewrite_aggregation(&self, matched: &ScanAggregation) -> Result<Option<SExpr>> {
+        let (catalog, database, table) = {
+            let metadata = self.metadata.read();
+            let table_entry = metadata.table(matched.scan.table_index);
+            (
+                table_entry.catalog().to_string(),
+                table_entry.database().to_string(),
+                table_entry.table(),
+            )
+        };
+        if table.engine().to_lowercase() != "fuse" {
+            return Ok(None);
+        }
+
+        let schema = table.schema();
+        let column_id_of = |column: &BaseTableColumn| -> Result<ColumnId> {
+            schema.column_id_of(&column.column_name)
+        };
+        let keys = matched
+            .group_by
+            .iter()
+            .map(column_id_of)
+            .collect::<Result<Vec<_>>>()?;
+        let args = matched
+            .aggregates
+            .iter()
+            .map(|(_, arg)| arg.as_ref().map(column_id_of).transpose())
+            .collect::<Result<Vec<_>>>()?;
+
+        for index in AggregatingIndex::from_table_options(table.options())? {
+            if !index.is_valid_for(&schema) {
+                continue;
+            }
+            if let Some((key_offsets, agg_offsets)) =
+                Self::match_index(matched, &index, &keys, &args)?
+            {
+                let index_table = table.aggregating_index_table(&index.id)?;
+                return Ok(Some(self.read_index(
+                    matched,
+                    catalog,
+                    database,
+                    index_table,
+                    &key_offsets,
+                    &agg_offsets,
+                )));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Find the offsets of the key columns and the aggregate columns of the index data that
+    /// the aggregation reads, with the function to merge each aggregate column.
+    #[allow(clippy::type_complexity)]
+    fn match_index(
+        matched: &ScanAggregation,
+        index: &AggregatingIndex,
+        keys: &[ColumnId],
+        args: &[Option<ColumnId>],
+    ) -> Result<Option<(Vec<usize>, Vec<(usize, &'static str)>)>> {
+        let mut key_offsets = Vec::with_capacity(keys.len());
+        for key in keys {
+            match index
+                .group_by
+                .iter()
+                .position(|field| field.column_id() == *key)
+            {
+                Some(offset) => key_offsets.push(offset),
+                None => return Ok(None),
+            }
+        }
+
+        let factory = AggregateFunctionFactory::instance();
+        let mut agg_offsets = Vec::with_capacity(args.len());
+        for ((func, _), arg) in matched.aggregates.iter().zip(args) {
+            let offset = index.aggregates.iter().position(|indexed| {
+                func.func_name.eq_ignore_ascii_case(&indexed.func_name)
+                    && indexed.arg.as_ref().map(|field| field.column_id()) == *arg
+            });
+            let offset = match offset {
+                Some(offset) => offset,
+                None => return Ok(None),
+            };
+
+            // the merged results must be of the same type as the original ones.
+            let merge = match merge_function(&func.func_name) {
+                Some(merge) => merge,
+                None => return Ok(None),
+            };
+            let data_type = DataType::from(&index.aggregates[offset].data_type);
+            if factory.get(merge, vec![], vec![data_type])?.return_type()? != *func.return_type {
+                return Ok(None);
+            }
+            agg_offsets.push((index.group_by.len() + offset, merge));
+        }
+        Ok(Some((key_offsets, agg_offsets)))
+    }
+
+    /// Replace the scan of the table with the scan of the index table, the key columns of the
+    /// index take the place of the group items, and the aggregate functions merge the
+    /// pre-computed results.
+    fn read_index(
+        &self,
+        matched: &ScanAggregation,
+        catalog: String,
+        database: String,
+        index_table: Arc<dyn Table>,
+        key_offsets: &[usize],
+        agg_offsets: &[(usize, &'static str)],
+    ) -> SExpr {
+        let table_name = index_table.name().to_string();
+        let table_index =
+            self.metadata
+                .write()
+                .add_table(catalog, database.clone(), index_table, None, false);
+        let columns = self
+            .metadata
+            .read()
+            .columns_by_table_index(table_index)
+            .into_iter()
+            .filter_map(|column| match column {
+                ColumnEntry::BaseTableColumn(column) => Some(column),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let column_ref = |column: &BaseTableColumn| -> ScalarExpr {
+            BoundColumnRef {
+                column: ColumnBinding {
+                    database_name: Some(database.clone()),
+                    table_name: Some(table_name.clone()),
+                    column_name: column.column_name.clone(),
+                    index: column.column_index,
+                    data_type: Box::new(DataType::from(&column.data_type)),
+                    visibility: Visibility::Visible,
+                },
+            }
+            .into()
+        };
+
+        let scan = Scan {
+            table_index,
+            columns: columns.iter().map(|column| column.column_index).collect(),
+            push_down_predicates: None,
+            limit: None,
+            order_by: None,
+            prewhere: None,
+            statistics: Statistics {
+                statistics: None,
+                col_stats: HashMap::new(),
+                is_accurate: false,
+            },
+        };
+
+        let eval_scalar = EvalScalar {
+            items: matched
+                .aggregate
+                .group_items
+                .iter()
+                .zip(key_offsets)
+                .map(|(item, offset)| ScalarItem {
+                    scalar: column_ref(&columns[*offset]),
+                    index: item.index,
+                })
+                .collect(),
+        };
+
+        let aggregate_functions = matched
+            .aggregate
+            .aggregate_functions
+            .iter()
+            .zip(matched.aggregates.iter())
+            .zip(agg_offsets)
+            .map(|((item, (func, _)), (offset, merge))| ScalarItem {
+                scalar: AggregateFunction {
+                    display_name: func.display_name.clone(),
+                    func_name: merge.to_string(),
+                    distinct: false,
+                    params: vec![],
+                    args: vec![column_ref(&columns[*offset])],
+                    return_type: func.return_type.clone(),
+                }
+                .into(),
+                index: item.index,
+            })
+            .collect();
+        let aggregate = Aggregate {
+            aggregate_functions,
+            ..matched.aggregate.clone()
+        };
+
+        SExpr::create_unary(
+            aggregate.into(),
+            SExpr::create_unary(eval_scalar.into(), SExpr::create_leaf(scan.into())),
+        )
+    }
+}
@@ -43,6 +43,7 @@ use crate::plans::Filter;
 use crate::plans::FunctionCall;
 use crate::plans::Join;
 use crate::plans::JoinType;
+use crate::plans::LambdaFunc;
 use crate::plans::NotExpr;
 use crate::plans::OrExpr;
 use crate::plans::PatternPlan;
@@ -385,7 +386,11 @@ impl SubqueryRewriter {
             // Such as: select t2.a from (select a + 1 as a from t) as t2 where (select sum(a) from t as t1 where t1.a < t2.a) = 1;
             let table_index = metadata
                 .table_index_by_column_indexes(correlated_columns)
-                .unwrap();
+                .ok_or_else(|| {
+                    ErrorCode::Unimplemented(
+                        "Correlated subquery referencing derived columns of the outer query is not supported",
+                    )
+                })?;
             for correlated_column in correlated_columns.iter() {
                 let column_entry = metadata.column(*correlated_column).clone();
                 let (name, data_type) = match &column_entry {
@@ -547,6 +552,11 @@ impl SubqueryRewriter {
                 ))
             }
             RelOperator::Aggregate(aggregate) => {
+                if aggregate.grouping_sets.is_some() {
+                    return Err(ErrorCode::SemanticError(
+                        "GROUPING SETS in correlated subquery is not supported",
+                    ));
+                }
                 if aggregate
                     .used_columns()?
                     .iter()
@@ -619,6 +629,7 @@ impl SubqueryRewriter {
                         aggregate_functions: agg_items,
                         from_distinct: aggregate.from_distinct,
                         limit: aggregate.limit,
+                        grouping_sets: None,
                     }
                     .into(),
                     flatten_plan,
@@ -673,6 +684,33 @@ impl SubqueryRewriter {
                 ))
             }
 
+            RelOperator::Window(_) => Err(ErrorCode::SemanticError(
+                "Window function in correlated subquery is not supported",
+            )),
+
+            RelOperator::ProjectSet(_) => Err(ErrorCode::SemanticError(
+                "Set-returning function in correlated subquery is not supported",
+            )),
+
+            RelOperator::MaterializedCte(_) => {
+                // The definition of a materialized CTE is never correlated.
+                let right_flatten_plan = self.flatten(
+                    plan.child(1)?,
+                    correlated_columns,
+                    flatten_info,
+                    need_cross_join,
+                )?;
+                Ok(SExpr::create_binary(
+                    plan.plan().clone(),
+                    plan.child(0)?.clone(),
+                    right_flatten_plan,
+                ))
+            }
+
+            RelOperator::RecursiveCte(_) | RelOperator::RecursiveCteScan(_) => Err(
+                ErrorCode::SemanticError("Recursive CTE in correlated subquery is not supported"),
+            ),
+
             _ => Err(ErrorCode::Internal(
                 "Invalid plan type for flattening subquery",
             )),
@@ -773,6 +811,19 @@ impl SubqueryRewriter {
                     target_type: cast_expr.target_type.clone(),
                 }))
             }
+            ScalarExpr::LambdaFunction(lambda) => {
+                let mut args = Vec::with_capacity(lambda.args.len());
+                for arg in &lambda.args {
+                    args.push(self.flatten_scalar(arg, correlated_columns)?);
+                }
+                Ok(ScalarExpr::LambdaFunction(LambdaFunc {
+                    func_name: lambda.func_name.clone(),
+                    args,
+                    lambda_expr: lambda.lambda_expr.clone(),
+                    lambda_display: lambda.lambda_display.clone(),
+                    return_type: lambda.return_type.clone(),
+                }))
+            }
             _ => Err(ErrorCode::Internal(
                 "Invalid scalar for flattening subquery",
             )),
@@ -19,7 +19,9 @@ use common_exception::Result;
 use once_cell::sync::Lazy;
 
 use super::prune_unused_columns::UnusedColumnPruner;
+use crate::optimizer::heuristic::agg_index::AggIndexRewriter;
 use crate::optimizer::heuristic::decorrelate::decorrelate_subquery;
+use crate::optimizer::heuristic::materialized_cte::CteInliner;
 use crate::optimizer::heuristic::prewhere_optimization::PrewhereOptimizer;
 use crate::optimizer::heuristic::RuleList;
 use crate::optimizer::rule::TransformResult;
@@ -58,7 +60,7 @@ pub static DEFAULT_REWRITE_RULES: Lazy<Vec<RuleID>> = Lazy::new(|| {
 pub struct HeuristicOptimizer {
     rules: RuleList,
 
-    _ctx: Arc<dyn TableContext>,
+    ctx: Arc<dyn TableContext>,
     bind_context: Box<BindContext>,
     metadata: MetadataRef,
 }
@@ -73,7 +75,7 @@ impl HeuristicOptimizer {
         HeuristicOptimizer {
             rules,
 
-            _ctx: ctx,
+            ctx,
             bind_context,
             metadata,
         }
@@ -85,11 +87,21 @@ impl HeuristicOptimizer {
             s_expr = decorrelate_subquery(self.metadata.clone(), s_expr)?;
         }
 
+        let cte_inliner = CteInliner::new(self.metadata.clone());
+        s_expr = cte_inliner.optimize(&s_expr)?;
+
         // always pruner the unused columns before and after optimization
         let pruner = UnusedColumnPruner::new(self.metadata.clone());
         let require_columns: ColumnSet =
             self.bind_context.columns.iter().map(|c| c.index).collect();
-        pruner.remove_unused_columns(&s_expr, require_columns)
+        s_expr = pruner.remove_unused_columns(&s_expr, require_columns)?;
+
+        // the aggregations are matched before the filters are pushed down into the scans.
+        if self.ctx.get_settings().get_enable_aggregating_index()? {
+            let rewriter = AggIndexRewriter::new(self.metadata.clone());
+            s_expr = rewriter.rewrite(&s_expr)?;
+        }
+        Ok(s_expr)
     }
 
     fn post_optimize(&mut self, s_expr: SExpr) -> Result<SExpr> {
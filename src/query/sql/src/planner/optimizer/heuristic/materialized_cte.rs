@@ -0,0 +1,168 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::types::DataType;
+
+use crate::binder::Visibility;
+use crate::optimizer::RelExpr;
+use crate::optimizer::SExpr;
+use crate::plans::BoundColumnRef;
+use crate::plans::CteScan;
+use crate::plans::EvalScalar;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::plans::ScalarItem;
+use crate::BaseTableColumn;
+use crate::ColumnBinding;
+use crate::ColumnEntry;
+use crate::DerivedColumn;
+use crate::IndexType;
+use crate::MetadataRef;
+
+static COST_FACTOR_COMPUTE_PER_ROW: f64 = 1.0;
+static COST_FACTOR_BUFFER_PER_ROW: f64 = 1.0;
+
+/// Decides whether a CTE is materialized or inlined into the places referencing it.
+///
+/// A materialized CTE is computed only once, but its result has to be buffered and then
+/// read by every reference. So unless it's specified with `AS MATERIALIZED`, a CTE is
+/// inlined if it's referenced only once or recomputing it is cheaper than buffering it.
+pub struct CteInliner {
+    metadata: MetadataRef,
+}
+
+impl CteInliner {
+    pub fn new(metadata: MetadataRef) -> Self {
+        Self { metadata }
+    }
+
+    pub fn optimize(&self, s_expr: &SExpr) -> Result<SExpr> {
+        // The inner CTEs are decided first, since inlining them may add references
+        // to the outer ones.
+        let children = s_expr
+            .children()
+            .iter()
+            .map(|child| self.optimize(child))
+            .collect::<Result<Vec<_>>>()?;
+        let s_expr = s_expr.replace_children(children);
+
+        if let RelOperator::MaterializedCte(cte) = s_expr.plan() {
+            let cte_expr = s_expr.child(0)?;
+            let input = s_expr.child(1)?;
+            let references = Self::count_references(input, cte.cte_idx);
+            if references == 0 {
+                return Ok(input.clone());
+            }
+            if !cte.materialized
+                && (references == 1 || !Self::worth_materializing(cte_expr, references)?)
+            {
+                return self.inline(input, cte.cte_idx, cte_expr);
+            }
+        }
+        Ok(s_expr)
+    }
+
+    fn count_references(s_expr: &SExpr, cte_idx: IndexType) -> usize {
+        match s_expr.plan() {
+            RelOperator::CteScan(scan) if scan.cte_idx == cte_idx => 1,
+            _ => s_expr
+                .children()
+                .iter()
+                .map(|child| Self::count_references(child, cte_idx))
+                .sum(),
+        }
+    }
+
+    fn worth_materializing(cte_expr: &SExpr, references: usize) -> Result<bool> {
+        let cost = Self::compute_cost(cte_expr)?;
+        let cardinality = RelExpr::with_s_expr(cte_expr)
+            .derive_relational_prop()?
+            .cardinality;
+        // The result is written once and read by each reference.
+        let buffer_cost = cardinality * (references + 1) as f64 * COST_FACTOR_BUFFER_PER_ROW;
+        Ok(cost + buffer_cost < cost * references as f64)
+    }
+
+    /// Estimate the cost of computing a plan by the number of rows processed by each operator.
+    fn compute_cost(s_expr: &SExpr) -> Result<f64> {
+        let mut cost = 0.0;
+        if s_expr.children().is_empty() {
+            cost += RelExpr::with_s_expr(s_expr)
+                .derive_relational_prop()?
+                .cardinality;
+        }
+        for child in s_expr.children() {
+            cost += RelExpr::with_s_expr(child)
+                .derive_relational_prop()?
+                .cardinality;
+            cost += Self::compute_cost(child)?;
+        }
+        Ok(cost * COST_FACTOR_COMPUTE_PER_ROW)
+    }
+
+    /// Replace the `CteScan`s of the CTE with its definition.
+    fn inline(&self, s_expr: &SExpr, cte_idx: IndexType, cte_expr: &SExpr) -> Result<SExpr> {
+        match s_expr.plan() {
+            RelOperator::CteScan(scan) if scan.cte_idx == cte_idx => {
+                let items = self.rename_columns(scan);
+                Ok(SExpr::create_unary(
+                    EvalScalar { items }.into(),
+                    cte_expr.clone(),
+                ))
+            }
+            _ => {
+                let children = s_expr
+                    .children()
+                    .iter()
+                    .map(|child| self.inline(child, cte_idx, cte_expr))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(s_expr.replace_children(children))
+            }
+        }
+    }
+
+    fn rename_columns(&self, scan: &CteScan) -> Vec<ScalarItem> {
+        let metadata = self.metadata.read();
+        scan.cte_columns
+            .iter()
+            .zip(scan.columns.iter())
+            .map(|(cte_column, column)| {
+                let (column_name, data_type) = match metadata.column(*cte_column) {
+                    ColumnEntry::BaseTableColumn(BaseTableColumn {
+                        column_name,
+                        data_type,
+                        ..
+                    }) => (column_name.clone(), DataType::from(data_type)),
+                    ColumnEntry::DerivedColumn(DerivedColumn {
+                        alias, data_type, ..
+                    }) => (alias.clone(), data_type.clone()),
+                };
+                ScalarItem {
+                    scalar: ScalarExpr::BoundColumnRef(BoundColumnRef {
+                        column: ColumnBinding {
+                            database_name: None,
+                            table_name: None,
+                            column_name,
+                            index: *cte_column,
+                            data_type: Box::new(data_type),
+                            visibility: Visibility::Visible,
+                        },
+                    }),
+                    index: *column,
+                }
+            })
+            .collect()
+    }
+}
@@ -12,14 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod agg_index;
 mod decorrelate;
 #[allow(clippy::module_inception)]
 mod heuristic;
+mod materialized_cte;
 mod prewhere_optimization;
 mod prune_unused_columns;
 mod rule_list;
 mod subquery_rewriter;
 
+pub use agg_index::ScanAggregation;
 pub use heuristic::HeuristicOptimizer;
 pub use heuristic::DEFAULT_REWRITE_RULES;
 pub use rule_list::RuleList;
@@ -76,6 +76,11 @@ impl PrewhereOptimizer {
             ScalarExpr::CastExpr(cast) => {
                 Self::collect_columns_impl(cast.argument.as_ref(), columns);
             }
+            ScalarExpr::LambdaFunction(lambda) => {
+                for arg in lambda.args.iter() {
+                    Self::collect_columns_impl(arg, columns);
+                }
+            }
             // 1. ConstantExpr is not collected.
             // 2. SubqueryExpr and AggregateFunction will not appear in Filter-LogicalGet
             _ => {}
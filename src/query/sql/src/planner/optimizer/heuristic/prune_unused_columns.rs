@@ -150,6 +150,7 @@ impl UnusedColumnPruner {
                         from_distinct: p.from_distinct,
                         mode: p.mode,
                         limit: p.limit,
+                        grouping_sets: p.grouping_sets.clone(),
                     }),
                     Self::keep_required_columns(expr.child(0)?, required)?,
                 ))
@@ -163,6 +164,36 @@ impl UnusedColumnPruner {
                     Self::keep_required_columns(expr.child(0)?, required)?,
                 ))
             }
+            RelOperator::Window(p) => {
+                if !required.contains(&p.index) {
+                    // The window function is not used, prune it.
+                    return Self::keep_required_columns(expr.child(0)?, required);
+                }
+                p.arguments.iter().for_each(|item| {
+                    required.insert(item.index);
+                });
+                p.partition_by.iter().for_each(|item| {
+                    required.insert(item.index);
+                });
+                p.order_by.iter().for_each(|item| {
+                    required.insert(item.order_by_item.index);
+                });
+                Ok(SExpr::create_unary(
+                    RelOperator::Window(p.clone()),
+                    Self::keep_required_columns(expr.child(0)?, required)?,
+                ))
+            }
+            RelOperator::ProjectSet(p) => {
+                // The function can't be pruned even if its output is not used,
+                // because it changes the number of rows.
+                p.arguments.iter().for_each(|item| {
+                    required.insert(item.index);
+                });
+                Ok(SExpr::create_unary(
+                    RelOperator::ProjectSet(p.clone()),
+                    Self::keep_required_columns(expr.child(0)?, required)?,
+                ))
+            }
             RelOperator::Limit(p) => Ok(SExpr::create_unary(
                 RelOperator::Limit(p.clone()),
                 Self::keep_required_columns(expr.child(0)?, required)?,
@@ -184,7 +215,32 @@ impl UnusedColumnPruner {
                 ))
             }
 
-            RelOperator::DummyTableScan(_) => Ok(expr.clone()),
+            RelOperator::RecursiveCte(p) => {
+                // The working table is shared by both terms, so all the
+                // columns of the CTE must be kept.
+                let left_used = p.pairs.iter().map(|v| v.0).collect();
+                let right_used = p.pairs.iter().map(|v| v.1).collect();
+                Ok(SExpr::create_binary(
+                    RelOperator::RecursiveCte(p.clone()),
+                    Self::keep_required_columns(expr.child(0)?, left_used)?,
+                    Self::keep_required_columns(expr.child(1)?, right_used)?,
+                ))
+            }
+
+            RelOperator::MaterializedCte(p) => {
+                // The result of the CTE is shared by all the scans, so all the
+                // columns of the CTE must be kept.
+                let left_used = p.cte_columns.iter().cloned().collect();
+                Ok(SExpr::create_binary(
+                    RelOperator::MaterializedCte(p.clone()),
+                    Self::keep_required_columns(expr.child(0)?, left_used)?,
+                    Self::keep_required_columns(expr.child(1)?, required)?,
+                ))
+            }
+
+            RelOperator::DummyTableScan(_)
+            | RelOperator::RecursiveCteScan(_)
+            | RelOperator::CteScan(_) => Ok(expr.clone()),
 
             _ => Err(ErrorCode::Internal(
                 "Attempting to prune columns of a physical plan is not allowed",
@@ -41,6 +41,7 @@ use crate::plans::Filter;
 use crate::plans::FunctionCall;
 use crate::plans::Join;
 use crate::plans::JoinType;
+use crate::plans::LambdaFunc;
 use crate::plans::Limit;
 use crate::plans::NotExpr;
 use crate::plans::OrExpr;
@@ -119,18 +120,27 @@ impl SubqueryRewriter {
                 Ok(SExpr::create_unary(plan.into(), input))
             }
 
-            RelOperator::Join(_) | RelOperator::UnionAll(_) => Ok(SExpr::create_binary(
+            RelOperator::Join(_)
+            | RelOperator::UnionAll(_)
+            | RelOperator::RecursiveCte(_)
+            | RelOperator::MaterializedCte(_) => Ok(SExpr::create_binary(
                 s_expr.plan().clone(),
                 self.rewrite(s_expr.child(0)?)?,
                 self.rewrite(s_expr.child(1)?)?,
             )),
 
-            RelOperator::Limit(_) | RelOperator::Sort(_) => Ok(SExpr::create_unary(
+            RelOperator::Limit(_)
+            | RelOperator::Sort(_)
+            | RelOperator::Window(_)
+            | RelOperator::ProjectSet(_) => Ok(SExpr::create_unary(
                 s_expr.plan().clone(),
                 self.rewrite(s_expr.child(0)?)?,
             )),
 
-            RelOperator::DummyTableScan(_) | RelOperator::Scan(_) => Ok(s_expr.clone()),
+            RelOperator::DummyTableScan(_)
+            | RelOperator::Scan(_)
+            | RelOperator::RecursiveCteScan(_)
+            | RelOperator::CteScan(_) => Ok(s_expr.clone()),
 
             _ => Err(ErrorCode::Internal("Invalid plan type")),
         }
@@ -207,7 +217,9 @@ impl SubqueryRewriter {
                 ))
             }
 
-            ScalarExpr::AggregateFunction(_) => Ok((scalar.clone(), s_expr.clone())),
+            ScalarExpr::AggregateFunction(_) | ScalarExpr::WindowFunction(_) => {
+                Ok((scalar.clone(), s_expr.clone()))
+            }
 
             ScalarExpr::FunctionCall(func) => {
                 let mut args = vec![];
@@ -243,6 +255,27 @@ impl SubqueryRewriter {
                 ))
             }
 
+            ScalarExpr::LambdaFunction(lambda) => {
+                let mut args = vec![];
+                let mut s_expr = s_expr.clone();
+                for arg in lambda.args.iter() {
+                    let res = self.try_rewrite_subquery(arg, &s_expr, false)?;
+                    s_expr = res.1;
+                    args.push(res.0);
+                }
+
+                let expr: ScalarExpr = LambdaFunc {
+                    func_name: lambda.func_name.clone(),
+                    args,
+                    lambda_expr: lambda.lambda_expr.clone(),
+                    lambda_display: lambda.lambda_display.clone(),
+                    return_type: lambda.return_type.clone(),
+                }
+                .into();
+
+                Ok((expr, s_expr))
+            }
+
             ScalarExpr::SubqueryExpr(subquery) => {
                 // Rewrite subquery recursively
                 let mut subquery = subquery.clone();
@@ -419,6 +452,7 @@ impl SubqueryRewriter {
                     from_distinct: false,
                     mode: AggregateMode::Initial,
                     limit: None,
+                    grouping_sets: None,
                 };
 
                 let compare = ComparisonExpr {
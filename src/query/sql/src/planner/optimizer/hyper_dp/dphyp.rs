@@ -0,0 +1,624 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+
+use crate::binder::JoinPredicate;
+use crate::optimizer::hyper_dp::query_graph::QueryGraph;
+use crate::optimizer::hyper_dp::util::is_disjoint;
+use crate::optimizer::hyper_dp::util::is_subset;
+use crate::optimizer::hyper_dp::util::non_empty_subsets;
+use crate::optimizer::hyper_dp::util::union;
+use crate::optimizer::rule::get_join_predicates;
+use crate::optimizer::ColumnSet;
+use crate::optimizer::RelExpr;
+use crate::optimizer::SExpr;
+use crate::plans::ComparisonExpr;
+use crate::plans::ComparisonOp;
+use crate::plans::Filter;
+use crate::plans::Join;
+use crate::plans::JoinType;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::IndexType;
+
+/// Plan of a set of relations in the dp table.
+#[derive(Clone, Debug)]
+struct JoinNode {
+    s_expr: SExpr,
+    cardinality: f64,
+    /// The sum of the cardinalities of all the joins in the plan, a.k.a `C_out`.
+    cost: f64,
+}
+
+/// A join enumerator based on the paper "Dynamic Programming Strikes Back",
+/// which enumerates connected subgraph/complement pairs of the join hypergraph
+/// to find the optimal bushy join tree.
+///
+/// Inner joins and cross joins are reordered freely. Left (right) outer, semi and
+/// anti joins are reordered with them under the conflict rules of the paper "On the
+/// Correct and Complete Enumeration of the Core Search Space": their null-supplying
+/// (or filtering) side is kept intact, and the relations their predicates depend on
+/// must be joined into the preserved side first. Full outer joins, mark joins, single
+/// joins and joins of correlated subqueries are reordering barriers: they are kept in
+/// place and the joins of their inputs are reordered separately. Join graphs with more
+/// relations than `join_reorder_dp_threshold` or not connected by predicates are
+/// reordered greedily.
+pub struct DPhyp {
+    dp_threshold: usize,
+    optimized: bool,
+}
+
+impl DPhyp {
+    pub fn new(ctx: Arc<dyn TableContext>) -> Result<Self> {
+        Ok(Self {
+            dp_threshold: ctx.get_settings().get_join_reorder_dp_threshold()? as usize,
+            optimized: false,
+        })
+    }
+
+    /// Returns the optimized plan and whether any join has been reordered.
+    pub fn optimize(mut self, s_expr: &SExpr) -> Result<(SExpr, bool)> {
+        let result = self.optimize_expr(s_expr)?;
+        Ok((result, self.optimized))
+    }
+
+    fn optimize_expr(&mut self, s_expr: &SExpr) -> Result<SExpr> {
+        if is_reorderable(s_expr) {
+            let mut relations = vec![];
+            let mut predicates = vec![];
+            let mut non_inner_joins = vec![];
+            collect_relations(
+                s_expr,
+                &mut relations,
+                &mut predicates,
+                &mut non_inner_joins,
+            )?;
+            if relations.len() > 2 {
+                let relations = relations
+                    .iter()
+                    .map(|relation| self.optimize_expr(relation))
+                    .collect::<Result<Vec<_>>>()?;
+                let mut join_graph = JoinGraph::new(relations, predicates, non_inner_joins)?;
+                let result = if join_graph.relations.len() <= self.dp_threshold {
+                    match join_graph.solve_dp()? {
+                        Some(node) => Some(node),
+                        None => join_graph.solve_greedy()?,
+                    }
+                } else {
+                    join_graph.solve_greedy()?
+                };
+                // Keep the joins in place if no valid order is found greedily.
+                if let Some(result) = result {
+                    self.optimized = true;
+                    return Ok(result.s_expr);
+                }
+            }
+        }
+
+        let children = s_expr
+            .children()
+            .iter()
+            .map(|child| self.optimize_expr(child))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(s_expr.replace_children(children))
+    }
+}
+
+/// Check if the `s_expr` is the root of a sub-plan of reorderable joins.
+fn is_reorderable(s_expr: &SExpr) -> bool {
+    match s_expr.plan() {
+        RelOperator::Join(join) => {
+            matches!(
+                join.join_type,
+                JoinType::Inner
+                    | JoinType::Cross
+                    | JoinType::Left
+                    | JoinType::Right
+                    | JoinType::LeftSemi
+                    | JoinType::RightSemi
+                    | JoinType::LeftAnti
+                    | JoinType::RightAnti
+            ) && join.marker_index.is_none()
+                && !join.from_correlated_subquery
+        }
+        // Predicates of the filter on top of joins can be used as join predicates.
+        RelOperator::Filter(filter) => {
+            !filter.is_having && s_expr.children().iter().all(is_reorderable)
+        }
+        _ => false,
+    }
+}
+
+/// A left outer, semi or anti join of the reorderable joins, right joins are
+/// converted by swapping the inputs.
+struct NonInnerJoin {
+    join_type: JoinType,
+    predicates: Vec<ScalarExpr>,
+    /// The relations of the preserved side.
+    left: Vec<IndexType>,
+    /// The relations of the null-supplying (or filtering) side, which must be
+    /// joined together before the join.
+    right: Vec<IndexType>,
+    /// The relations of the preserved side which must be joined before the join,
+    /// i.e. the left part of its total eligibility set. It's computed by [`JoinGraph::new`].
+    tes_left: Vec<IndexType>,
+}
+
+/// Collect the base relations and the predicates of the reorderable joins, returns
+/// the relations of `s_expr`.
+///
+/// The predicates of inner joins and filters are collected with the relations of the
+/// plan they are evaluated on, to find the non-inner joins below them.
+fn collect_relations(
+    s_expr: &SExpr,
+    relations: &mut Vec<SExpr>,
+    predicates: &mut Vec<(ScalarExpr, Vec<IndexType>)>,
+    non_inner_joins: &mut Vec<NonInnerJoin>,
+) -> Result<Vec<IndexType>> {
+    if !is_reorderable(s_expr) {
+        relations.push(s_expr.clone());
+        return Ok(vec![relations.len() - 1]);
+    }
+    match s_expr.plan() {
+        RelOperator::Join(join) => {
+            let left = collect_relations(s_expr.child(0)?, relations, predicates, non_inner_joins)?;
+            let right =
+                collect_relations(s_expr.child(1)?, relations, predicates, non_inner_joins)?;
+            let scope = union(&left, &right);
+            let join_predicates = get_join_predicates(join)?;
+            match join.join_type {
+                JoinType::Inner | JoinType::Cross => predicates.extend(
+                    join_predicates
+                        .into_iter()
+                        .map(|predicate| (predicate, scope.clone())),
+                ),
+                JoinType::Left | JoinType::LeftSemi | JoinType::LeftAnti => {
+                    non_inner_joins.push(NonInnerJoin {
+                        join_type: join.join_type.clone(),
+                        predicates: join_predicates,
+                        left,
+                        right,
+                        tes_left: vec![],
+                    })
+                }
+                _ => non_inner_joins.push(NonInnerJoin {
+                    join_type: join.join_type.opposite(),
+                    predicates: join_predicates,
+                    left: right,
+                    right: left,
+                    tes_left: vec![],
+                }),
+            }
+            Ok(scope)
+        }
+        RelOperator::Filter(filter) => {
+            let scope =
+                collect_relations(s_expr.child(0)?, relations, predicates, non_inner_joins)?;
+            predicates.extend(
+                filter
+                    .predicates
+                    .iter()
+                    .map(|predicate| (predicate.clone(), scope.clone())),
+            );
+            Ok(scope)
+        }
+        _ => unreachable!(),
+    }
+}
+
+/// Extend `relations` with all the relations of the non-inner joins in `scope` whose
+/// null-supplying side is referenced, such joins must be done before evaluating a
+/// predicate referencing `relations`.
+fn add_conflicts(
+    mut relations: Vec<IndexType>,
+    scope: &[IndexType],
+    non_inner_joins: &[NonInnerJoin],
+) -> Vec<IndexType> {
+    loop {
+        let mut changed = false;
+        for join in non_inner_joins.iter() {
+            let join_relations = union(&join.left, &join.right);
+            if is_subset(&join_relations, scope)
+                && !is_disjoint(&join.right, &relations)
+                && !is_subset(&join_relations, &relations)
+            {
+                relations = union(&relations, &join_relations);
+                changed = true;
+            }
+        }
+        if !changed {
+            return relations;
+        }
+    }
+}
+
+struct JoinGraph {
+    relations: Vec<JoinNode>,
+    /// Predicates of inner joins and filters, and the relations which must be joined
+    /// before evaluating them.
+    predicates: Vec<(ScalarExpr, Vec<IndexType>)>,
+    non_inner_joins: Vec<NonInnerJoin>,
+    query_graph: QueryGraph,
+    dp_table: HashMap<Vec<IndexType>, JoinNode>,
+}
+
+impl JoinGraph {
+    fn new(
+        relations: Vec<SExpr>,
+        predicates: Vec<(ScalarExpr, Vec<IndexType>)>,
+        mut non_inner_joins: Vec<NonInnerJoin>,
+    ) -> Result<Self> {
+        let mut output_columns = Vec::with_capacity(relations.len());
+        let mut join_nodes = Vec::with_capacity(relations.len());
+        for s_expr in relations.into_iter() {
+            let prop = RelExpr::with_s_expr(&s_expr).derive_relational_prop()?;
+            output_columns.push(prop.output_columns.clone());
+            join_nodes.push(JoinNode {
+                s_expr,
+                cardinality: prop.cardinality,
+                cost: 0.0,
+            });
+        }
+
+        let relations_of = |columns: &ColumnSet| -> Vec<IndexType> {
+            output_columns
+                .iter()
+                .enumerate()
+                .filter(|(_, output)| !output.is_disjoint(columns))
+                .map(|(idx, _)| idx)
+                .collect()
+        };
+
+        let mut query_graph = QueryGraph::new();
+        for idx in 0..non_inner_joins.len() {
+            let join = &non_inner_joins[idx];
+            let mut used_columns = ColumnSet::new();
+            for predicate in join.predicates.iter() {
+                used_columns.extend(predicate.used_columns());
+            }
+            let referenced = relations_of(&used_columns)
+                .into_iter()
+                .filter(|relation| join.left.binary_search(relation).is_ok())
+                .collect::<Vec<_>>();
+            let tes_left = if referenced.is_empty() {
+                join.left.clone()
+            } else {
+                add_conflicts(referenced, &join.left, &non_inner_joins)
+            };
+            query_graph.add_edge(tes_left.clone(), join.right.clone());
+            non_inner_joins[idx].tes_left = tes_left;
+        }
+
+        let mut join_predicates = Vec::with_capacity(predicates.len());
+        for (predicate, scope) in predicates.into_iter() {
+            if let ScalarExpr::ComparisonExpr(ComparisonExpr {
+                op: ComparisonOp::Equal,
+                left,
+                right,
+                ..
+            }) = &predicate
+            {
+                let left_relations = relations_of(&left.used_columns());
+                let right_relations = relations_of(&right.used_columns());
+                if !left_relations.is_empty()
+                    && !right_relations.is_empty()
+                    && is_disjoint(&left_relations, &right_relations)
+                {
+                    query_graph.add_edge(left_relations, right_relations);
+                }
+            }
+            let mut relations = relations_of(&predicate.used_columns());
+            if relations.is_empty() {
+                // Constant predicates are evaluated by the join they come from.
+                relations = scope.clone();
+            }
+            let relations = add_conflicts(relations, &scope, &non_inner_joins);
+            join_predicates.push((predicate, relations));
+        }
+
+        let dp_table = join_nodes
+            .iter()
+            .enumerate()
+            .map(|(idx, node)| (vec![idx], node.clone()))
+            .collect();
+
+        Ok(Self {
+            relations: join_nodes,
+            predicates: join_predicates,
+            non_inner_joins,
+            query_graph,
+            dp_table,
+        })
+    }
+
+    /// Enumerate the connected subgraph/complement pairs, returns `None` if
+    /// the relations are not connected by the join predicates.
+    fn solve_dp(&mut self) -> Result<Option<JoinNode>> {
+        for idx in (0..self.relations.len()).rev() {
+            let nodes = vec![idx];
+            self.emit_csg(&nodes)?;
+            let forbidden = (0..=idx).collect::<Vec<_>>();
+            self.enumerate_csg_rec(&nodes, &forbidden)?;
+        }
+        let all_relations = (0..self.relations.len()).collect::<Vec<_>>();
+        Ok(self.dp_table.get(&all_relations).cloned())
+    }
+
+    /// Extend the connected subgraph `nodes` with its neighbors.
+    fn enumerate_csg_rec(&mut self, nodes: &[IndexType], forbidden: &[IndexType]) -> Result<()> {
+        let neighbors = self.query_graph.neighbors(nodes, forbidden);
+        if neighbors.is_empty() {
+            return Ok(());
+        }
+        let subsets = non_empty_subsets(&neighbors);
+        for subset in subsets.iter() {
+            let merged = union(nodes, subset);
+            if self.dp_table.contains_key(&merged) {
+                self.emit_csg(&merged)?;
+            }
+        }
+        let forbidden = union(forbidden, &neighbors);
+        for subset in subsets.iter() {
+            self.enumerate_csg_rec(&union(nodes, subset), &forbidden)?;
+        }
+        Ok(())
+    }
+
+    /// Find the complements of the connected subgraph `nodes`.
+    fn emit_csg(&mut self, nodes: &[IndexType]) -> Result<()> {
+        let forbidden = union(nodes, &(0..nodes[0]).collect::<Vec<_>>());
+        let neighbors = self.query_graph.neighbors(nodes, &forbidden);
+        for (idx, neighbor) in neighbors.iter().enumerate().rev() {
+            let complement = vec![*neighbor];
+            if self.query_graph.is_connected(nodes, &complement) {
+                self.emit_csg_cmp(nodes, &complement)?;
+            }
+            let forbidden = union(&forbidden, &neighbors[..=idx]);
+            self.enumerate_cmp_rec(nodes, &complement, &forbidden)?;
+        }
+        Ok(())
+    }
+
+    /// Extend the complement `right` of the connected subgraph `left` with its neighbors.
+    fn enumerate_cmp_rec(
+        &mut self,
+        left: &[IndexType],
+        right: &[IndexType],
+        forbidden: &[IndexType],
+    ) -> Result<()> {
+        let neighbors = self.query_graph.neighbors(right, forbidden);
+        if neighbors.is_empty() {
+            return Ok(());
+        }
+        let subsets = non_empty_subsets(&neighbors);
+        for subset in subsets.iter() {
+            let merged = union(right, subset);
+            if self.dp_table.contains_key(&merged) && self.query_graph.is_connected(left, &merged) {
+                self.emit_csg_cmp(left, &merged)?;
+            }
+        }
+        let forbidden = union(forbidden, &neighbors);
+        for subset in subsets.iter() {
+            self.enumerate_cmp_rec(left, &union(right, subset), &forbidden)?;
+        }
+        Ok(())
+    }
+
+    /// Join the plans of `left` and `right`, and keep it if it's the cheapest
+    /// plan of their union.
+    fn emit_csg_cmp(&mut self, left: &[IndexType], right: &[IndexType]) -> Result<()> {
+        let (left_node, right_node) = match (self.dp_table.get(left), self.dp_table.get(right)) {
+            (Some(left_node), Some(right_node)) => (left_node, right_node),
+            _ => return Ok(()),
+        };
+        let node = match self.create_join_node(left, left_node, right, right_node)? {
+            Some(node) => node,
+            None => return Ok(()),
+        };
+        let relations = union(left, right);
+        match self.dp_table.get(&relations) {
+            Some(best) if best.cost <= node.cost => {}
+            _ => {
+                self.dp_table.insert(relations, node);
+            }
+        }
+        Ok(())
+    }
+
+    /// Greedy operator ordering, which joins the pair of plans with the
+    /// smallest result repeatedly, cross joins are only used when no pair
+    /// of plans is connected by the join predicates. Returns `None` if no valid
+    /// pair of plans can be joined.
+    fn solve_greedy(&mut self) -> Result<Option<JoinNode>> {
+        let mut nodes: Vec<(Vec<IndexType>, JoinNode)> = self
+            .relations
+            .iter()
+            .enumerate()
+            .map(|(idx, node)| (vec![idx], node.clone()))
+            .collect();
+        while nodes.len() > 1 {
+            let mut best: Option<(usize, usize, JoinNode)> = None;
+            for connected_only in [true, false] {
+                for i in 0..nodes.len() {
+                    for j in i + 1..nodes.len() {
+                        let (left, left_node) = &nodes[i];
+                        let (right, right_node) = &nodes[j];
+                        if connected_only && !self.query_graph.is_connected(left, right) {
+                            continue;
+                        }
+                        let node =
+                            match self.create_join_node(left, left_node, right, right_node)? {
+                                Some(node) => node,
+                                None => continue,
+                            };
+                        if best
+                            .as_ref()
+                            .map_or(true, |(_, _, best)| node.cardinality < best.cardinality)
+                        {
+                            best = Some((i, j, node));
+                        }
+                    }
+                }
+                if best.is_some() {
+                    break;
+                }
+            }
+            let (i, j, node) = match best {
+                Some(best) => best,
+                None => return Ok(None),
+            };
+            // `j` is always greater than `i`.
+            let (right, _) = nodes.remove(j);
+            let (left, _) = nodes.remove(i);
+            nodes.push((union(&left, &right), node));
+        }
+        Ok(nodes.pop().map(|(_, node)| node))
+    }
+
+    /// Find the non-inner join done by joining `left` and `right`, returns `Err(())` if
+    /// the join breaks a non-inner join: it joins a part of the null-supplying side with
+    /// other relations, or the relations the non-inner join depends on are not joined yet.
+    ///
+    /// The returned flag tells whether the preserved side is `right`.
+    fn find_non_inner_join(
+        &self,
+        left: &[IndexType],
+        right: &[IndexType],
+    ) -> std::result::Result<Option<(&NonInnerJoin, bool)>, ()> {
+        let relations = union(left, right);
+        let mut found = None;
+        for join in self.non_inner_joins.iter() {
+            if is_disjoint(&join.right, &relations) || is_subset(&relations, &join.right) {
+                continue;
+            }
+            // A plan containing the null-supplying side and other relations has done the join.
+            let is_done =
+                |side: &[IndexType]| side.len() > join.right.len() && is_subset(&join.right, side);
+            if is_done(left) || is_done(right) {
+                continue;
+            }
+            if found.is_some() {
+                return Err(());
+            }
+            if right == join.right.as_slice() && is_subset(&join.tes_left, left) {
+                found = Some((join, false));
+            } else if left == join.right.as_slice() && is_subset(&join.tes_left, right) {
+                found = Some((join, true));
+            } else {
+                return Err(());
+            }
+        }
+        Ok(found)
+    }
+
+    /// Join the plans of `left` and `right`, returns `None` if they can't be joined
+    /// without breaking a non-inner join.
+    fn create_join_node(
+        &self,
+        left: &[IndexType],
+        left_node: &JoinNode,
+        right: &[IndexType],
+        right_node: &JoinNode,
+    ) -> Result<Option<JoinNode>> {
+        let non_inner_join = match self.find_non_inner_join(left, right) {
+            Ok(non_inner_join) => non_inner_join,
+            Err(_) => return Ok(None),
+        };
+        let (left, left_node, right, right_node) = match non_inner_join {
+            // Keep the preserved side of the non-inner join on the left.
+            Some((_, true)) => (right, right_node, left, left_node),
+            Some((_, false)) => (left, left_node, right, right_node),
+            // The right child is the build side, use the smaller one to build the hash table.
+            None if left_node.cardinality < right_node.cardinality => {
+                (right, right_node, left, left_node)
+            }
+            None => (left, left_node, right, right_node),
+        };
+        let relations = union(left, right);
+        let left_prop = RelExpr::with_s_expr(&left_node.s_expr).derive_relational_prop()?;
+        let right_prop = RelExpr::with_s_expr(&right_node.s_expr).derive_relational_prop()?;
+
+        let mut join = Join::default();
+        let mut add_condition =
+            |predicate: &ScalarExpr| match JoinPredicate::new(predicate, &left_prop, &right_prop) {
+                JoinPredicate::Both {
+                    left: left_condition,
+                    right: right_condition,
+                } => {
+                    join.left_conditions.push(left_condition.clone());
+                    join.right_conditions.push(right_condition.clone());
+                }
+                _ => join.non_equi_conditions.push(predicate.clone()),
+            };
+        let mut filters = vec![];
+        for (predicate, predicate_relations) in self.predicates.iter() {
+            // The predicate is evaluated by the lowest join which contains all the relations of it.
+            if !is_subset(predicate_relations, &relations)
+                || (left.len() > 1 && is_subset(predicate_relations, left))
+                || (right.len() > 1 && is_subset(predicate_relations, right))
+            {
+                continue;
+            }
+            if non_inner_join.is_some() {
+                // Predicates of inner joins and filters are not the conditions of a non-inner
+                // join, they are evaluated by a filter on top of it.
+                filters.push(predicate.clone());
+            } else {
+                add_condition(predicate);
+            }
+        }
+        match non_inner_join {
+            Some((non_inner_join, _)) => {
+                non_inner_join
+                    .predicates
+                    .iter()
+                    .for_each(&mut add_condition);
+                join.join_type = non_inner_join.join_type.clone();
+            }
+            None if !join.left_conditions.is_empty() && !join.right_conditions.is_empty() => {
+                join.join_type = JoinType::Inner;
+            }
+            None => {}
+        }
+
+        let mut s_expr = SExpr::create_binary(
+            join.into(),
+            left_node.s_expr.clone(),
+            right_node.s_expr.clone(),
+        );
+        if !filters.is_empty() {
+            s_expr = SExpr::create_unary(
+                Filter {
+                    predicates: filters,
+                    is_having: false,
+                }
+                .into(),
+                s_expr,
+            );
+        }
+        let cardinality = RelExpr::with_s_expr(&s_expr)
+            .derive_relational_prop()?
+            .cardinality;
+        Ok(Some(JoinNode {
+            s_expr,
+            cardinality,
+            cost: cardinality + left_node.cost + right_node.cost,
+        }))
+    }
+}
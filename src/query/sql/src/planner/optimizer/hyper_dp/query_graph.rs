@@ -0,0 +1,65 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::optimizer::hyper_dp::util::is_disjoint;
+use crate::optimizer::hyper_dp::util::is_subset;
+use crate::IndexType;
+
+/// Hypergraph of the join predicates, every hyperedge connects two
+/// disjoint sets of relations, e.g. `t1.a + t2.a = t3.a` connects
+/// `{t1, t2}` and `{t3}`.
+#[derive(Default, Debug)]
+pub struct QueryGraph {
+    edges: Vec<(Vec<IndexType>, Vec<IndexType>)>,
+}
+
+impl QueryGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_edge(&mut self, left: Vec<IndexType>, right: Vec<IndexType>) {
+        let exists = self
+            .edges
+            .iter()
+            .any(|(l, r)| (l == &left && r == &right) || (l == &right && r == &left));
+        if !exists {
+            self.edges.push((left, right));
+        }
+    }
+
+    /// Get the neighbors of `nodes` which are not in `forbidden`, a hyperedge
+    /// is represented by the minimal relation of the other side.
+    pub fn neighbors(&self, nodes: &[IndexType], forbidden: &[IndexType]) -> Vec<IndexType> {
+        let mut neighbors = vec![];
+        for (left, right) in self.edges.iter() {
+            for (from, to) in [(left, right), (right, left)] {
+                if is_subset(from, nodes) && is_disjoint(to, nodes) && is_disjoint(to, forbidden) {
+                    neighbors.push(to[0]);
+                }
+            }
+        }
+        neighbors.sort_unstable();
+        neighbors.dedup();
+        neighbors
+    }
+
+    /// Check if there is a hyperedge between `left` and `right`.
+    pub fn is_connected(&self, left: &[IndexType], right: &[IndexType]) -> bool {
+        self.edges.iter().any(|(l, r)| {
+            (is_subset(l, left) && is_subset(r, right))
+                || (is_subset(l, right) && is_subset(r, left))
+        })
+    }
+}
@@ -0,0 +1,49 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::IndexType;
+
+// Relation sets used by join reordering are sorted vectors of relation indexes.
+
+pub fn union(left: &[IndexType], right: &[IndexType]) -> Vec<IndexType> {
+    let mut result = Vec::with_capacity(left.len() + right.len());
+    result.extend_from_slice(left);
+    result.extend_from_slice(right);
+    result.sort_unstable();
+    result.dedup();
+    result
+}
+
+pub fn is_subset(sub: &[IndexType], set: &[IndexType]) -> bool {
+    sub.iter().all(|idx| set.binary_search(idx).is_ok())
+}
+
+pub fn is_disjoint(left: &[IndexType], right: &[IndexType]) -> bool {
+    left.iter().all(|idx| right.binary_search(idx).is_err())
+}
+
+/// Get all the non-empty subsets of `set`, ordered by size.
+pub fn non_empty_subsets(set: &[IndexType]) -> Vec<Vec<IndexType>> {
+    let mut subsets = (1..1usize << set.len())
+        .map(|mask| {
+            set.iter()
+                .enumerate()
+                .filter(|(i, _)| mask & (1 << i) != 0)
+                .map(|(_, idx)| *idx)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    subsets.sort_by_key(|subset| subset.len());
+    subsets
+}
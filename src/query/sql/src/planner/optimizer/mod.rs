@@ -18,6 +18,7 @@ mod distributed;
 mod format;
 mod group;
 mod heuristic;
+mod hyper_dp;
 mod m_expr;
 mod memo;
 #[allow(clippy::module_inception)]
@@ -29,11 +30,14 @@ mod s_expr;
 mod util;
 
 pub use heuristic::HeuristicOptimizer;
+pub use heuristic::ScanAggregation;
 pub use heuristic::SubqueryRewriter;
 pub use heuristic::DEFAULT_REWRITE_RULES;
+pub use hyper_dp::DPhyp;
 pub use m_expr::MExpr;
 pub use memo::Memo;
 pub use optimizer::optimize;
+pub use optimizer::optimize_query;
 pub use optimizer::OptimizerConfig;
 pub use optimizer::OptimizerContext;
 pub use pattern_extractor::PatternExtractor;
@@ -42,4 +46,5 @@ pub use rule::try_push_down_filter_join;
 pub use rule::RuleFactory;
 pub use rule::RuleID;
 pub use rule::RuleSet;
+pub(crate) use s_expr::find_subquery_in_expr;
 pub use s_expr::SExpr;
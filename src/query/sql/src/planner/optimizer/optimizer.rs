@@ -26,6 +26,7 @@ use super::Memo;
 use crate::optimizer::cascades::CascadesOptimizer;
 use crate::optimizer::distributed::optimize_distributed_query;
 use crate::optimizer::heuristic::RuleList;
+use crate::optimizer::hyper_dp::DPhyp;
 use crate::optimizer::util::contains_local_table_scan;
 use crate::optimizer::HeuristicOptimizer;
 use crate::optimizer::SExpr;
@@ -148,12 +149,16 @@ pub fn optimize_query(
 ) -> Result<SExpr> {
     let rules = RuleList::create(DEFAULT_REWRITE_RULES.clone(), Some(metadata.clone()))?;
 
-    let contains_local_table_scan = contains_local_table_scan(&s_expr, &metadata);
-
-    let mut heuristic = HeuristicOptimizer::new(ctx.clone(), bind_context, metadata, rules);
+    let mut heuristic = HeuristicOptimizer::new(ctx.clone(), bind_context, metadata.clone(), rules);
     let mut result = heuristic.optimize(s_expr)?;
 
-    let mut cascades = CascadesOptimizer::create(ctx.clone())?;
+    // Checked after the heuristic optimization, since the CTEs may have been inlined.
+    let contains_local_table_scan = contains_local_table_scan(&result, &metadata);
+
+    let (dphyp_result, dphyp_optimized) = optimize_join_order(ctx.clone(), &result)?;
+    result = dphyp_result;
+
+    let mut cascades = CascadesOptimizer::create(ctx.clone(), dphyp_optimized)?;
     result = cascades.optimize(result)?;
 
     // So far, we don't have ability to execute distributed query
@@ -179,7 +184,19 @@ fn get_optimized_memo(
     let mut heuristic = HeuristicOptimizer::new(ctx.clone(), bind_context, metadata, rules);
     let result = heuristic.optimize(s_expr)?;
 
-    let mut cascades = CascadesOptimizer::create(ctx)?;
+    let (result, dphyp_optimized) = optimize_join_order(ctx.clone(), &result)?;
+
+    let mut cascades = CascadesOptimizer::create(ctx, dphyp_optimized)?;
     cascades.optimize(result)?;
     Ok((cascades.memo, cascades.best_cost_map))
 }
+
+/// Reorder joins with DPhyp if it's enabled, returns the plan and whether it's reordered.
+fn optimize_join_order(ctx: Arc<dyn TableContext>, s_expr: &SExpr) -> Result<(SExpr, bool)> {
+    let settings = ctx.get_settings();
+    if settings.get_enable_cbo()? && settings.get_enable_dphyp()? {
+        DPhyp::new(ctx)?.optimize(s_expr)
+    } else {
+        Ok((s_expr.clone(), false))
+    }
+}
@@ -32,18 +32,38 @@ pub const DEFAULT_HISTOGRAM_BUCKETS: usize = 100;
 /// it is difficult to give the exact frequency of the skew data
 /// when the skew data and other data fall into the same bucket
 ///
-/// We choose this approach because so far the histogram is originally
-/// constructed from NDV(number of distinct values) and the total number
-/// of rows instead of maintaining a real histogram for each column,
-/// which brings the assumption that the data is uniformly distributed.
+/// If the table has been analyzed, the histogram is built by `ANALYZE TABLE`
+/// from sampled values and comes with the most common values of the column.
+/// Otherwise it's constructed from NDV(number of distinct values) and the total
+/// number of rows, which brings the assumption that the data is uniformly distributed.
 #[derive(Debug, Clone)]
 pub struct Histogram {
     pub buckets: Vec<HistogramBucket>,
+    /// Most common values and their number of rows, only available for collected histograms.
+    pub most_common_values: Vec<(Datum, f64)>,
+    /// Whether the histogram is collected by `ANALYZE TABLE` rather than derived from NDV.
+    pub collected: bool,
 }
 
 impl Histogram {
     pub fn new(buckets: Vec<HistogramBucket>) -> Self {
-        Self { buckets }
+        Self {
+            buckets,
+            most_common_values: vec![],
+            collected: false,
+        }
+    }
+
+    /// Create a histogram collected by `ANALYZE TABLE`.
+    pub fn new_collected(
+        buckets: Vec<HistogramBucket>,
+        most_common_values: Vec<(Datum, f64)>,
+    ) -> Self {
+        Self {
+            buckets,
+            most_common_values,
+            collected: true,
+        }
     }
 
     /// Get number of buckets
@@ -94,7 +114,7 @@ pub fn histogram_from_ndv(
                 ndv, num_rows
             ))
         } else {
-            Ok(Histogram { buckets: vec![] })
+            Ok(Histogram::new(vec![]))
         };
     }
 
@@ -149,7 +169,7 @@ pub fn histogram_from_ndv(
         buckets.push(bucket);
     }
 
-    Ok(Histogram { buckets })
+    Ok(Histogram::new(buckets))
 }
 
 #[derive(Debug, Clone)]
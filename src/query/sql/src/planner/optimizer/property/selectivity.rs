@@ -109,6 +109,13 @@ impl<'a> SelectivityEstimator<'a> {
                 return DEFAULT_SELECTIVITY;
             };
 
+            if col_hist.collected {
+                // The histogram is collected from the real data distribution,
+                // so we don't need to assume the column is uniformly distributed.
+                return evaluate_by_collected_histogram(col_hist, &comp_expr.op, &const_datum)
+                    .unwrap_or(DEFAULT_SELECTIVITY);
+            }
+
             match &comp_expr.op {
                 ComparisonOp::Equal => {
                     // For equal predicate, we just use cardinality of a single
@@ -244,3 +251,81 @@ fn compare_equal(datum: &Option<Datum>, col_hist: &Histogram, column_stat: &Colu
 
     1.0 / col_hist.num_distinct_values()
 }
+
+fn evaluate_by_collected_histogram(
+    col_hist: &Histogram,
+    op: &ComparisonOp,
+    datum: &Datum,
+) -> Option<f64> {
+    let num_values = col_hist.num_values();
+    if num_values == 0.0 {
+        return None;
+    }
+    let selectivity = match op {
+        ComparisonOp::Equal => num_equal(col_hist, datum)? / num_values,
+        ComparisonOp::NotEqual => 1.0 - num_equal(col_hist, datum)? / num_values,
+        ComparisonOp::LT => num_less(col_hist, datum, false)? / num_values,
+        ComparisonOp::LTE => num_less(col_hist, datum, true)? / num_values,
+        ComparisonOp::GT => 1.0 - num_less(col_hist, datum, true)? / num_values,
+        ComparisonOp::GTE => 1.0 - num_less(col_hist, datum, false)? / num_values,
+    };
+    Some(selectivity.clamp(0.0, 1.0))
+}
+
+/// Estimate the number of values equal to `datum`, the most common values are exact,
+/// other values share the rows of the bucket they fall into.
+fn num_equal(col_hist: &Histogram, datum: &Datum) -> Option<f64> {
+    for (value, count) in col_hist.most_common_values.iter() {
+        if value.compare(datum).ok()? == Ordering::Equal {
+            return Some(*count);
+        }
+    }
+    for (idx, bucket) in col_hist.buckets_iter().enumerate() {
+        match bucket.upper_bound().compare(datum).ok()? {
+            Ordering::Less => continue,
+            // Less than the min value.
+            Ordering::Greater if idx == 0 => return Some(0.0),
+            _ => return Some(bucket.num_values() / bucket.num_distinct().max(1.0)),
+        }
+    }
+    // Greater than the max value.
+    Some(0.0)
+}
+
+/// Estimate the number of values less than (or equal to, if `inclusive`) `datum`,
+/// values are assumed to be uniformly distributed inside the bucket containing `datum`.
+fn num_less(col_hist: &Histogram, datum: &Datum, inclusive: bool) -> Option<f64> {
+    let mut num_less = 0.0;
+    let mut lower_bound: Option<&Datum> = None;
+    for bucket in col_hist.buckets_iter() {
+        match bucket.upper_bound().compare(datum).ok()? {
+            Ordering::Less => num_less += bucket.num_values(),
+            Ordering::Equal => {
+                num_less += bucket.num_values();
+                if !inclusive {
+                    num_less -= num_equal(col_hist, datum)?.min(bucket.num_values());
+                }
+                return Some(num_less);
+            }
+            Ordering::Greater => {
+                // Values of the bucket are in (lower_bound, upper_bound].
+                if let Some(lower_bound) = lower_bound {
+                    let fraction = match (
+                        lower_bound.to_double(),
+                        bucket.upper_bound().to_double(),
+                        datum.to_double(),
+                    ) {
+                        (Ok(lower), Ok(upper), Ok(value)) if upper > lower => {
+                            (value - lower) / (upper - lower)
+                        }
+                        _ => 0.5,
+                    };
+                    num_less += bucket.num_values() * fraction;
+                }
+                return Some(num_less);
+            }
+        }
+        lower_bound = Some(bucket.upper_bound());
+    }
+    Some(num_less)
+}
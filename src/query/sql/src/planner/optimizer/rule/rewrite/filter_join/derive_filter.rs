@@ -149,6 +149,13 @@ fn replace_column(scalar: &mut ScalarExpr, col_to_scalar: &HashMap<&IndexType, &
         ScalarExpr::CastExpr(expr) => {
             replace_column(&mut expr.argument, col_to_scalar);
         }
-        ScalarExpr::ConstantExpr(_) | ScalarExpr::SubqueryExpr(_) => {}
+        ScalarExpr::LambdaFunction(expr) => {
+            for arg in expr.args.iter_mut() {
+                replace_column(arg, col_to_scalar)
+            }
+        }
+        ScalarExpr::ConstantExpr(_)
+        | ScalarExpr::WindowFunction(_)
+        | ScalarExpr::SubqueryExpr(_) => {}
     }
 }
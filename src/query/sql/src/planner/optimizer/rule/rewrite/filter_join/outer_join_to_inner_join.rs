@@ -29,6 +29,7 @@ use crate::plans::Filter;
 use crate::plans::FunctionCall;
 use crate::plans::Join;
 use crate::plans::JoinType;
+use crate::plans::LambdaFunc;
 use crate::plans::NotExpr;
 use crate::plans::OrExpr;
 use crate::ColumnBinding;
@@ -383,6 +384,27 @@ fn remove_column_nullable(
                 target_type: expr.target_type.clone(),
             })
         }
-        ScalarExpr::ConstantExpr(_) | ScalarExpr::SubqueryExpr(_) => scalar_expr.clone(),
+        ScalarExpr::LambdaFunction(expr) => {
+            let mut args = Vec::with_capacity(expr.args.len());
+            for arg in expr.args.iter() {
+                args.push(remove_column_nullable(
+                    arg,
+                    left_prop,
+                    right_prop,
+                    join_type,
+                    metadata.clone(),
+                )?);
+            }
+            ScalarExpr::LambdaFunction(LambdaFunc {
+                func_name: expr.func_name.clone(),
+                args,
+                lambda_expr: expr.lambda_expr.clone(),
+                lambda_display: expr.lambda_display.clone(),
+                return_type: expr.return_type.clone(),
+            })
+        }
+        ScalarExpr::ConstantExpr(_)
+        | ScalarExpr::WindowFunction(_)
+        | ScalarExpr::SubqueryExpr(_) => scalar_expr.clone(),
     })
 }
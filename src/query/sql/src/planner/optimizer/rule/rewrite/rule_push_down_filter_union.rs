@@ -27,6 +27,7 @@ use crate::plans::CastExpr;
 use crate::plans::ComparisonExpr;
 use crate::plans::Filter;
 use crate::plans::FunctionCall;
+use crate::plans::LambdaFunc;
 use crate::plans::NotExpr;
 use crate::plans::OrExpr;
 use crate::plans::PatternPlan;
@@ -199,6 +200,20 @@ fn replace_column_binding(
             from_type: expr.from_type,
             target_type: expr.target_type,
         })),
+        ScalarExpr::LambdaFunction(expr) => Ok(ScalarExpr::LambdaFunction(LambdaFunc {
+            func_name: expr.func_name,
+            args: expr
+                .args
+                .into_iter()
+                .map(|arg| replace_column_binding(index_pairs, arg))
+                .collect::<Result<Vec<_>>>()?,
+            lambda_expr: expr.lambda_expr,
+            lambda_display: expr.lambda_display,
+            return_type: expr.return_type,
+        })),
+        ScalarExpr::WindowFunction(_) => Err(ErrorCode::Unimplemented(
+            "replace_column_binding: don't support window function",
+        )),
         ScalarExpr::SubqueryExpr(_) => Err(ErrorCode::Unimplemented(
             "replace_column_binding: don't support subquery",
         )),
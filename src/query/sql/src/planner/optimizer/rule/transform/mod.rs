@@ -28,3 +28,4 @@ pub use rule_left_associate_join::RuleLeftAssociateJoin;
 pub use rule_left_exchange_join::RuleLeftExchangeJoin;
 pub use rule_right_associate_join::RuleRightAssociateJoin;
 pub use rule_right_exchange_join::RuleRightExchangeJoin;
+pub use util::get_join_predicates;
@@ -185,6 +185,10 @@ fn find_subquery(rel_op: &RelOperator) -> bool {
         | RelOperator::UnionAll(_)
         | RelOperator::Sort(_)
         | RelOperator::DummyTableScan(_)
+        | RelOperator::RecursiveCte(_)
+        | RelOperator::RecursiveCteScan(_)
+        | RelOperator::MaterializedCte(_)
+        | RelOperator::CteScan(_)
         | RelOperator::Pattern(_) => false,
         RelOperator::Join(op) => {
             op.left_conditions.iter().any(find_subquery_in_expr)
@@ -205,10 +209,28 @@ fn find_subquery(rel_op: &RelOperator) -> bool {
                     .iter()
                     .any(|expr| find_subquery_in_expr(&expr.scalar))
         }
+        RelOperator::Window(op) => {
+            op.arguments
+                .iter()
+                .any(|expr| find_subquery_in_expr(&expr.scalar))
+                || op
+                    .partition_by
+                    .iter()
+                    .any(|expr| find_subquery_in_expr(&expr.scalar))
+                || op
+                    .order_by
+                    .iter()
+                    .any(|o| find_subquery_in_expr(&o.order_by_item.scalar))
+        }
+        RelOperator::ProjectSet(op) => op
+            .arguments
+            .iter()
+            .any(|expr| find_subquery_in_expr(&expr.scalar)),
     }
 }
 
-fn find_subquery_in_expr(expr: &ScalarExpr) -> bool {
+/// Check if the expression contains a subquery, at any depth.
+pub(crate) fn find_subquery_in_expr(expr: &ScalarExpr) -> bool {
     match expr {
         ScalarExpr::BoundColumnRef(_) | ScalarExpr::ConstantExpr(_) => false,
         ScalarExpr::AndExpr(expr) => {
@@ -222,8 +244,14 @@ fn find_subquery_in_expr(expr: &ScalarExpr) -> bool {
             find_subquery_in_expr(&expr.left) || find_subquery_in_expr(&expr.right)
         }
         ScalarExpr::AggregateFunction(expr) => expr.args.iter().any(find_subquery_in_expr),
+        ScalarExpr::WindowFunction(expr) => {
+            expr.func.arguments().into_iter().any(find_subquery_in_expr)
+                || expr.partition_by.iter().any(find_subquery_in_expr)
+                || expr.order_by.iter().any(|o| find_subquery_in_expr(&o.expr))
+        }
         ScalarExpr::FunctionCall(expr) => expr.arguments.iter().any(find_subquery_in_expr),
         ScalarExpr::CastExpr(expr) => find_subquery_in_expr(&expr.argument),
         ScalarExpr::SubqueryExpr(_) => true,
+        ScalarExpr::LambdaFunction(expr) => expr.args.iter().any(find_subquery_in_expr),
     }
 }
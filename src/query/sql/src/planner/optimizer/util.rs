@@ -22,9 +22,12 @@ pub fn contains_local_table_scan(s_expr: &SExpr, metadata: &MetadataRef) -> bool
         .children()
         .iter()
         .any(|s_expr| contains_local_table_scan(s_expr, metadata))
-        || if let RelOperator::Scan(get) = s_expr.plan() {
-            metadata.read().table(get.table_index).table().is_local()
-        } else {
-            false
+        || match s_expr.plan() {
+            RelOperator::Scan(get) => metadata.read().table(get.table_index).table().is_local(),
+            // The working table of a recursive CTE only lives in the local node.
+            RelOperator::RecursiveCte(_) => true,
+            // The buffered result of a materialized CTE only lives in the local node.
+            RelOperator::MaterializedCte(_) => true,
+            _ => false,
         }
 }
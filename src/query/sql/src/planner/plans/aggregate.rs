@@ -27,6 +27,7 @@ use crate::optimizer::Statistics;
 use crate::plans::Operator;
 use crate::plans::RelOp;
 use crate::plans::ScalarItem;
+use crate::IndexType;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Copy)]
 pub enum AggregateMode {
@@ -38,6 +39,20 @@ pub enum AggregateMode {
     Initial,
 }
 
+/// Grouping sets of an aggregation, the input rows are expanded once for each
+/// grouping set before they are aggregated.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GroupingSets {
+    /// Index of the virtual column `_grouping_id`, which identifies the grouping set of a row.
+    pub grouping_id_index: IndexType,
+    /// Each grouping set is a list of group item columns, the other group items
+    /// are NULL in the rows of the grouping set.
+    pub sets: Vec<Vec<IndexType>>,
+    /// Pairs of the group item column and its duplicated column, which keeps the
+    /// original values for the arguments of aggregate functions.
+    pub dup_group_items: Vec<(IndexType, IndexType)>,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Aggregate {
     pub mode: AggregateMode,
@@ -48,6 +63,8 @@ pub struct Aggregate {
     // True if the plan is generated from distinct, else the plan is a normal aggregate;
     pub from_distinct: bool,
     pub limit: Option<usize>,
+    // Grouping sets of `GROUP BY GROUPING SETS`, `ROLLUP` or `CUBE`;
+    pub grouping_sets: Option<GroupingSets>,
 }
 
 impl Aggregate {
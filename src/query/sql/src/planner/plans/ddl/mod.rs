@@ -12,19 +12,23 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 mod account;
+mod aggregating_index;
 mod catalog;
 mod database;
 mod file_format;
 mod stage;
+mod stream;
 mod table;
 mod udf;
 mod view;
 
 pub use account::*;
+pub use aggregating_index::*;
 pub use catalog::*;
 pub use database::*;
 pub use file_format::*;
 pub use stage::*;
+pub use stream::*;
 pub use table::*;
 pub use udf::*;
 pub use view::*;
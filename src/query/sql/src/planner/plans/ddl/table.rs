@@ -23,6 +23,7 @@ use common_expression::DataField;
 use common_expression::DataSchema;
 use common_expression::DataSchemaRef;
 use common_expression::DataSchemaRefExt;
+use common_expression::TableDataType;
 use common_expression::TableSchemaRef;
 use common_meta_app::schema::TableNameIdent;
 use common_meta_app::schema::UndropTableReq;
@@ -57,6 +58,25 @@ impl CreateTablePlanV2 {
     }
 }
 
+/// Create a table as a zero-copy clone of a fuse table, optionally at a point of its history.
+#[derive(Clone, Debug)]
+pub struct CloneTablePlan {
+    pub if_not_exists: bool,
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub source_database: String,
+    pub source_table: String,
+    pub point: Option<NavigationPoint>,
+}
+
+impl CloneTablePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
+
 /// Desc.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DescribeTablePlan {
@@ -183,6 +203,38 @@ impl DropTableColumnPlan {
     }
 }
 
+// Table rename column
+#[derive(Clone, Debug, PartialEq)]
+pub struct RenameTableColumnPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub old_column: String,
+    pub new_column: String,
+}
+
+impl RenameTableColumnPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+// Table modify column type
+#[derive(Clone, Debug, PartialEq)]
+pub struct ModifyTableColumnTypePlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub column: String,
+    pub data_type: TableDataType,
+}
+
+impl ModifyTableColumnTypePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
 /// Show.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ShowCreateTablePlan {
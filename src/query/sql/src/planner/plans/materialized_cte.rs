@@ -0,0 +1,170 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use itertools::Itertools;
+
+use crate::optimizer::ColumnSet;
+use crate::optimizer::Distribution;
+use crate::optimizer::PhysicalProperty;
+use crate::optimizer::RelExpr;
+use crate::optimizer::RelationalProperty;
+use crate::optimizer::RequiredProperty;
+use crate::optimizer::Statistics;
+use crate::plans::Operator;
+use crate::plans::RelOp;
+use crate::IndexType;
+
+/// A common table expression which is executed only once, the result is buffered and shared
+/// by all the references to it.
+///
+/// The left child is the definition of the CTE, the right child is the query which reads
+/// the CTE through [`CteScan`]s with the same `cte_idx`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MaterializedCte {
+    pub cte_idx: IndexType,
+    pub cte_name: String,
+    /// Output columns of the definition of the CTE.
+    pub cte_columns: Vec<IndexType>,
+    /// Specified with `AS MATERIALIZED`, then the CTE will never be inlined.
+    pub materialized: bool,
+}
+
+impl MaterializedCte {
+    pub fn used_columns(&self) -> Result<ColumnSet> {
+        Ok(self.cte_columns.iter().cloned().collect())
+    }
+}
+
+impl Operator for MaterializedCte {
+    fn rel_op(&self) -> RelOp {
+        RelOp::MaterializedCte
+    }
+
+    fn derive_relational_prop(&self, rel_expr: &RelExpr) -> Result<RelationalProperty> {
+        let left_prop = rel_expr.derive_relational_prop_child(0)?;
+        let right_prop = rel_expr.derive_relational_prop_child(1)?;
+
+        let outer_columns = left_prop
+            .outer_columns
+            .union(&right_prop.outer_columns)
+            .cloned()
+            .collect();
+
+        let mut used_columns = self.used_columns()?;
+        used_columns.extend(left_prop.used_columns);
+        used_columns.extend(right_prop.used_columns);
+
+        Ok(RelationalProperty {
+            output_columns: right_prop.output_columns,
+            outer_columns,
+            used_columns,
+            cardinality: right_prop.cardinality,
+            statistics: right_prop.statistics,
+        })
+    }
+
+    fn derive_physical_prop(&self, _rel_expr: &RelExpr) -> Result<PhysicalProperty> {
+        Ok(PhysicalProperty {
+            distribution: Distribution::Serial,
+        })
+    }
+
+    fn compute_required_prop_child(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _rel_expr: &RelExpr,
+        _child_index: usize,
+        required: &RequiredProperty,
+    ) -> Result<RequiredProperty> {
+        let mut required = required.clone();
+        required.distribution = Distribution::Serial;
+        Ok(required)
+    }
+}
+
+/// Reads the buffered result of a [`MaterializedCte`].
+#[derive(Clone, Debug)]
+pub struct CteScan {
+    pub cte_idx: IndexType,
+    pub cte_name: String,
+    /// Output columns of the definition of the CTE.
+    pub cte_columns: Vec<IndexType>,
+    /// Output columns of the scan, each of them is read from the CTE column at the same position.
+    pub columns: Vec<IndexType>,
+    /// Estimated cardinality of the CTE, it will be ignored in comparison and hashing.
+    pub cardinality: f64,
+}
+
+impl CteScan {
+    pub fn used_columns(&self) -> Result<ColumnSet> {
+        Ok(ColumnSet::new())
+    }
+}
+
+impl PartialEq for CteScan {
+    fn eq(&self, other: &Self) -> bool {
+        self.cte_idx == other.cte_idx && self.columns == other.columns
+    }
+}
+
+impl Eq for CteScan {}
+
+impl std::hash::Hash for CteScan {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.cte_idx.hash(state);
+        for column in self.columns.iter().sorted() {
+            column.hash(state);
+        }
+    }
+}
+
+impl Operator for CteScan {
+    fn rel_op(&self) -> RelOp {
+        RelOp::CteScan
+    }
+
+    fn derive_relational_prop(&self, _rel_expr: &RelExpr) -> Result<RelationalProperty> {
+        Ok(RelationalProperty {
+            output_columns: self.columns.iter().cloned().collect(),
+            outer_columns: ColumnSet::new(),
+            used_columns: ColumnSet::new(),
+            cardinality: self.cardinality,
+            statistics: Statistics {
+                precise_cardinality: None,
+                column_stats: Default::default(),
+                is_accurate: false,
+            },
+        })
+    }
+
+    fn derive_physical_prop(&self, _rel_expr: &RelExpr) -> Result<PhysicalProperty> {
+        Ok(PhysicalProperty {
+            distribution: Distribution::Serial,
+        })
+    }
+
+    fn compute_required_prop_child(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _rel_expr: &RelExpr,
+        _child_index: usize,
+        required: &RequiredProperty,
+    ) -> Result<RequiredProperty> {
+        Ok(required.clone())
+    }
+}
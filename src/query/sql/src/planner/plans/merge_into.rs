@@ -0,0 +1,67 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_expression::DataSchema;
+use common_expression::DataSchemaRef;
+use common_expression::FieldIndex;
+
+use crate::optimizer::SExpr;
+use crate::plans::ScalarExpr;
+use crate::BindContext;
+use crate::IndexType;
+use crate::MetadataRef;
+
+#[derive(Clone, Debug)]
+pub struct MergeIntoPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    /// The optimized plan of the source rows.
+    pub input: Box<SExpr>,
+    /// The bind context of the source, its columns are the output columns of `input`.
+    pub bind_context: Box<BindContext>,
+    pub metadata: MetadataRef,
+    /// Maps the column index of the target table to its field index in the table schema.
+    pub target_columns: HashMap<IndexType, FieldIndex>,
+    /// The equi conditions of the ON clause, split into the target side and the source side.
+    pub target_join_keys: Vec<ScalarExpr>,
+    pub source_join_keys: Vec<ScalarExpr>,
+    /// The remaining conditions of the ON clause.
+    pub join_filters: Vec<ScalarExpr>,
+    pub matched_clauses: Vec<MergeMatchedClause>,
+    pub unmatched_clauses: Vec<MergeUnmatchedClause>,
+}
+
+#[derive(Clone, Debug)]
+pub struct MergeMatchedClause {
+    pub condition: Option<ScalarExpr>,
+    /// `None` means the matched rows will be deleted.
+    pub update_list: Option<HashMap<FieldIndex, ScalarExpr>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct MergeUnmatchedClause {
+    pub condition: Option<ScalarExpr>,
+    /// One value for each field of the table, `None` if the field takes its default value.
+    pub values: Vec<Option<ScalarExpr>>,
+}
+
+impl MergeIntoPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
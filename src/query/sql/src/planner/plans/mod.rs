@@ -26,11 +26,16 @@ mod join;
 mod kill;
 mod limit;
 mod list;
+mod materialized_cte;
+mod merge_into;
 mod operator;
 mod pattern;
 mod plan;
 mod presign;
+mod project_set;
 mod recluster_table;
+mod recursive_cte;
+mod replace;
 mod revert_table;
 mod scalar;
 mod scan;
@@ -39,6 +44,7 @@ pub mod share;
 mod sort;
 mod union_all;
 mod update;
+mod window;
 
 pub use aggregate::*;
 pub use call::CallPlan;
@@ -55,13 +61,18 @@ pub use join::*;
 pub use kill::KillPlan;
 pub use limit::*;
 pub use list::ListPlan;
+pub use materialized_cte::*;
+pub use merge_into::*;
 pub use operator::*;
 pub use pattern::PatternPlan;
 pub use plan::Plan::*;
 pub use plan::RewriteKind::*;
 pub use plan::*;
 pub use presign::*;
+pub use project_set::*;
 pub use recluster_table::ReclusterTablePlan;
+pub use recursive_cte::*;
+pub use replace::Replace;
 pub use revert_table::RevertTablePlan;
 pub use scalar::*;
 pub use scan::*;
@@ -70,3 +81,4 @@ pub use share::*;
 pub use sort::*;
 pub use union_all::UnionAll;
 pub use update::UpdatePlan;
+pub use window::*;
@@ -24,10 +24,16 @@ use super::eval_scalar::EvalScalar;
 use super::filter::Filter;
 use super::join::Join;
 use super::limit::Limit;
+use super::materialized_cte::CteScan;
+use super::materialized_cte::MaterializedCte;
 use super::pattern::PatternPlan;
+use super::project_set::ProjectSet;
+use super::recursive_cte::RecursiveCte;
+use super::recursive_cte::RecursiveCteScan;
 use super::scan::Scan;
 use super::sort::Sort;
 use super::union_all::UnionAll;
+use super::window::Window;
 use crate::optimizer::PhysicalProperty;
 use crate::optimizer::RelExpr;
 use crate::optimizer::RelationalProperty;
@@ -67,6 +73,12 @@ pub enum RelOp {
     Exchange,
     UnionAll,
     DummyTableScan,
+    Window,
+    RecursiveCte,
+    RecursiveCteScan,
+    MaterializedCte,
+    CteScan,
+    ProjectSet,
 
     // Pattern
     Pattern,
@@ -85,6 +97,12 @@ pub enum RelOperator {
     Exchange(Exchange),
     UnionAll(UnionAll),
     DummyTableScan(DummyTableScan),
+    Window(Window),
+    RecursiveCte(RecursiveCte),
+    RecursiveCteScan(RecursiveCteScan),
+    MaterializedCte(MaterializedCte),
+    CteScan(CteScan),
+    ProjectSet(ProjectSet),
 
     Pattern(PatternPlan),
 }
@@ -103,6 +121,12 @@ impl Operator for RelOperator {
             RelOperator::Exchange(rel_op) => rel_op.rel_op(),
             RelOperator::UnionAll(rel_op) => rel_op.rel_op(),
             RelOperator::DummyTableScan(rel_op) => rel_op.rel_op(),
+            RelOperator::Window(rel_op) => rel_op.rel_op(),
+            RelOperator::RecursiveCte(rel_op) => rel_op.rel_op(),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.rel_op(),
+            RelOperator::MaterializedCte(rel_op) => rel_op.rel_op(),
+            RelOperator::CteScan(rel_op) => rel_op.rel_op(),
+            RelOperator::ProjectSet(rel_op) => rel_op.rel_op(),
         }
     }
 
@@ -119,6 +143,12 @@ impl Operator for RelOperator {
             RelOperator::Exchange(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::UnionAll(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::DummyTableScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::Window(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::RecursiveCte(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::MaterializedCte(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::CteScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::ProjectSet(rel_op) => rel_op.derive_relational_prop(rel_expr),
         }
     }
 
@@ -135,6 +165,12 @@ impl Operator for RelOperator {
             RelOperator::Exchange(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::UnionAll(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::DummyTableScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::Window(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::RecursiveCte(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::MaterializedCte(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::CteScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::ProjectSet(rel_op) => rel_op.derive_physical_prop(rel_expr),
         }
     }
 
@@ -179,6 +215,24 @@ impl Operator for RelOperator {
             RelOperator::DummyTableScan(rel_op) => {
                 rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
             }
+            RelOperator::Window(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
+            RelOperator::RecursiveCte(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
+            RelOperator::RecursiveCteScan(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
+            RelOperator::MaterializedCte(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
+            RelOperator::CteScan(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
+            RelOperator::ProjectSet(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
         }
     }
 }
@@ -386,3 +440,115 @@ impl TryFrom<RelOperator> for DummyTableScan {
         }
     }
 }
+
+impl From<Window> for RelOperator {
+    fn from(v: Window) -> Self {
+        Self::Window(v)
+    }
+}
+
+impl TryFrom<RelOperator> for Window {
+    type Error = ErrorCode;
+    fn try_from(value: RelOperator) -> Result<Self> {
+        if let RelOperator::Window(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal("Cannot downcast RelOperator to Window"))
+        }
+    }
+}
+
+impl From<RecursiveCte> for RelOperator {
+    fn from(v: RecursiveCte) -> Self {
+        Self::RecursiveCte(v)
+    }
+}
+
+impl TryFrom<RelOperator> for RecursiveCte {
+    type Error = ErrorCode;
+    fn try_from(value: RelOperator) -> Result<Self> {
+        if let RelOperator::RecursiveCte(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast RelOperator to RecursiveCte",
+            ))
+        }
+    }
+}
+
+impl From<RecursiveCteScan> for RelOperator {
+    fn from(v: RecursiveCteScan) -> Self {
+        Self::RecursiveCteScan(v)
+    }
+}
+
+impl TryFrom<RelOperator> for RecursiveCteScan {
+    type Error = ErrorCode;
+    fn try_from(value: RelOperator) -> Result<Self> {
+        if let RelOperator::RecursiveCteScan(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast RelOperator to RecursiveCteScan",
+            ))
+        }
+    }
+}
+
+impl From<ProjectSet> for RelOperator {
+    fn from(v: ProjectSet) -> Self {
+        Self::ProjectSet(v)
+    }
+}
+
+impl TryFrom<RelOperator> for ProjectSet {
+    type Error = ErrorCode;
+    fn try_from(value: RelOperator) -> Result<Self> {
+        if let RelOperator::ProjectSet(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast RelOperator to ProjectSet",
+            ))
+        }
+    }
+}
+
+impl From<MaterializedCte> for RelOperator {
+    fn from(v: MaterializedCte) -> Self {
+        Self::MaterializedCte(v)
+    }
+}
+
+impl TryFrom<RelOperator> for MaterializedCte {
+    type Error = ErrorCode;
+    fn try_from(value: RelOperator) -> Result<Self> {
+        if let RelOperator::MaterializedCte(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast RelOperator to MaterializedCte",
+            ))
+        }
+    }
+}
+
+impl From<CteScan> for RelOperator {
+    fn from(v: CteScan) -> Self {
+        Self::CteScan(v)
+    }
+}
+
+impl TryFrom<RelOperator> for CteScan {
+    type Error = ErrorCode;
+    fn try_from(value: RelOperator) -> Result<Self> {
+        if let RelOperator::CteScan(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast RelOperator to CteScan",
+            ))
+        }
+    }
+}
@@ -27,6 +27,7 @@ use crate::plans::copy_v2::CopyPlanV2;
 use crate::plans::insert::Insert;
 use crate::plans::presign::PresignPlan;
 use crate::plans::recluster_table::ReclusterTablePlan;
+use crate::plans::replace::Replace;
 use crate::plans::share::AlterShareTenantsPlan;
 use crate::plans::share::CreateSharePlan;
 use crate::plans::share::DescSharePlan;
@@ -43,22 +44,27 @@ use crate::plans::AlterUserPlan;
 use crate::plans::AlterViewPlan;
 use crate::plans::AnalyzeTablePlan;
 use crate::plans::CallPlan;
+use crate::plans::CloneTablePlan;
+use crate::plans::CreateAggregatingIndexPlan;
 use crate::plans::CreateCatalogPlan;
 use crate::plans::CreateDatabasePlan;
 use crate::plans::CreateFileFormatPlan;
 use crate::plans::CreateRolePlan;
 use crate::plans::CreateStagePlan;
+use crate::plans::CreateStreamPlan;
 use crate::plans::CreateTablePlanV2;
 use crate::plans::CreateUDFPlan;
 use crate::plans::CreateUserPlan;
 use crate::plans::CreateViewPlan;
 use crate::plans::DeletePlan;
 use crate::plans::DescribeTablePlan;
+use crate::plans::DropAggregatingIndexPlan;
 use crate::plans::DropCatalogPlan;
 use crate::plans::DropDatabasePlan;
 use crate::plans::DropFileFormatPlan;
 use crate::plans::DropRolePlan;
 use crate::plans::DropStagePlan;
+use crate::plans::DropStreamPlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
 use crate::plans::DropTablePlan;
@@ -70,9 +76,12 @@ use crate::plans::GrantPrivilegePlan;
 use crate::plans::GrantRolePlan;
 use crate::plans::KillPlan;
 use crate::plans::ListPlan;
+use crate::plans::MergeIntoPlan;
+use crate::plans::ModifyTableColumnTypePlan;
 use crate::plans::OptimizeTablePlan;
 use crate::plans::RemoveStagePlan;
 use crate::plans::RenameDatabasePlan;
+use crate::plans::RenameTableColumnPlan;
 use crate::plans::RenameTablePlan;
 use crate::plans::RevertTablePlan;
 use crate::plans::RevokePrivilegePlan;
@@ -144,11 +153,14 @@ pub enum Plan {
     ShowCreateTable(Box<ShowCreateTablePlan>),
     DescribeTable(Box<DescribeTablePlan>),
     CreateTable(Box<CreateTablePlanV2>),
+    CloneTable(Box<CloneTablePlan>),
     DropTable(Box<DropTablePlan>),
     UndropTable(Box<UndropTablePlan>),
     RenameTable(Box<RenameTablePlan>),
     AddTableColumn(Box<AddTableColumnPlan>),
     DropTableColumn(Box<DropTableColumnPlan>),
+    RenameTableColumn(Box<RenameTableColumnPlan>),
+    ModifyTableColumnType(Box<ModifyTableColumnTypePlan>),
     AlterTableClusterKey(Box<AlterTableClusterKeyPlan>),
     DropTableClusterKey(Box<DropTableClusterKeyPlan>),
     ReclusterTable(Box<ReclusterTablePlan>),
@@ -160,14 +172,29 @@ pub enum Plan {
 
     // Insert
     Insert(Box<Insert>),
+    Replace(Box<Replace>),
     Delete(Box<DeletePlan>),
     Update(Box<UpdatePlan>),
+    MergeInto(Box<MergeIntoPlan>),
+
+    // Transactions
+    Begin,
+    Commit,
+    Abort,
 
     // Views
     CreateView(Box<CreateViewPlan>),
     AlterView(Box<AlterViewPlan>),
     DropView(Box<DropViewPlan>),
 
+    // Streams
+    CreateStream(Box<CreateStreamPlan>),
+    DropStream(Box<DropStreamPlan>),
+
+    // Aggregating indexes
+    CreateAggregatingIndex(Box<CreateAggregatingIndexPlan>),
+    DropAggregatingIndex(Box<DropAggregatingIndexPlan>),
+
     // Account
     AlterUser(Box<AlterUserPlan>),
     CreateUser(Box<CreateUserPlan>),
@@ -259,11 +286,14 @@ impl Display for Plan {
             Plan::ShowCreateTable(_) => write!(f, "ShowCreateTable"),
             Plan::DescribeTable(_) => write!(f, "DescribeTable"),
             Plan::CreateTable(_) => write!(f, "CreateTable"),
+            Plan::CloneTable(_) => write!(f, "CloneTable"),
             Plan::DropTable(_) => write!(f, "DropTable"),
             Plan::UndropTable(_) => write!(f, "UndropTable"),
             Plan::RenameTable(_) => write!(f, "RenameTable"),
             Plan::AddTableColumn(_) => write!(f, "AddTableColumn"),
             Plan::DropTableColumn(_) => write!(f, "DropTableColumn"),
+            Plan::RenameTableColumn(_) => write!(f, "RenameTableColumn"),
+            Plan::ModifyTableColumnType(_) => write!(f, "ModifyTableColumnType"),
             Plan::AlterTableClusterKey(_) => write!(f, "AlterTableClusterKey"),
             Plan::DropTableClusterKey(_) => write!(f, "DropTableClusterKey"),
             Plan::ReclusterTable(_) => write!(f, "ReclusterTable"),
@@ -274,6 +304,10 @@ impl Display for Plan {
             Plan::CreateView(_) => write!(f, "CreateView"),
             Plan::AlterView(_) => write!(f, "AlterView"),
             Plan::DropView(_) => write!(f, "DropView"),
+            Plan::CreateStream(_) => write!(f, "CreateStream"),
+            Plan::DropStream(_) => write!(f, "DropStream"),
+            Plan::CreateAggregatingIndex(_) => write!(f, "CreateAggregatingIndex"),
+            Plan::DropAggregatingIndex(_) => write!(f, "DropAggregatingIndex"),
             Plan::AlterUser(_) => write!(f, "AlterUser"),
             Plan::CreateUser(_) => write!(f, "CreateUser"),
             Plan::DropUser(_) => write!(f, "DropUser"),
@@ -296,8 +330,13 @@ impl Display for Plan {
             Plan::AlterUDF(_) => write!(f, "AlterUDF"),
             Plan::DropUDF(_) => write!(f, "DropUDF"),
             Plan::Insert(_) => write!(f, "Insert"),
+            Plan::Replace(_) => write!(f, "Replace"),
             Plan::Delete(_) => write!(f, "Delete"),
             Plan::Update(_) => write!(f, "Update"),
+            Plan::MergeInto(_) => write!(f, "MergeInto"),
+            Plan::Begin => write!(f, "Begin"),
+            Plan::Commit => write!(f, "Commit"),
+            Plan::Abort => write!(f, "Abort"),
             Plan::Call(_) => write!(f, "Call"),
             Plan::Presign(_) => write!(f, "Presign"),
             Plan::SetVariable(_) => write!(f, "SetVariable"),
@@ -350,11 +389,14 @@ impl Plan {
             Plan::ShowCreateTable(plan) => plan.schema(),
             Plan::DescribeTable(plan) => plan.schema(),
             Plan::CreateTable(plan) => plan.schema(),
+            Plan::CloneTable(plan) => plan.schema(),
             Plan::DropTable(plan) => plan.schema(),
             Plan::UndropTable(plan) => plan.schema(),
             Plan::RenameTable(plan) => plan.schema(),
             Plan::AddTableColumn(plan) => plan.schema(),
             Plan::DropTableColumn(plan) => plan.schema(),
+            Plan::RenameTableColumn(plan) => plan.schema(),
+            Plan::ModifyTableColumnType(plan) => plan.schema(),
             Plan::AlterTableClusterKey(plan) => plan.schema(),
             Plan::DropTableClusterKey(plan) => plan.schema(),
             Plan::ReclusterTable(plan) => plan.schema(),
@@ -365,6 +407,10 @@ impl Plan {
             Plan::CreateView(plan) => plan.schema(),
             Plan::AlterView(plan) => plan.schema(),
             Plan::DropView(plan) => plan.schema(),
+            Plan::CreateStream(plan) => plan.schema(),
+            Plan::DropStream(plan) => plan.schema(),
+            Plan::CreateAggregatingIndex(plan) => plan.schema(),
+            Plan::DropAggregatingIndex(plan) => plan.schema(),
             Plan::AlterUser(plan) => plan.schema(),
             Plan::CreateUser(plan) => plan.schema(),
             Plan::DropUser(plan) => plan.schema(),
@@ -387,8 +433,11 @@ impl Plan {
             Plan::AlterUDF(_) => Arc::new(DataSchema::empty()),
             Plan::DropUDF(_) => Arc::new(DataSchema::empty()),
             Plan::Insert(plan) => plan.schema(),
+            Plan::Replace(plan) => plan.schema(),
             Plan::Delete(_) => Arc::new(DataSchema::empty()),
             Plan::Update(_) => Arc::new(DataSchema::empty()),
+            Plan::MergeInto(plan) => plan.schema(),
+            Plan::Begin | Plan::Commit | Plan::Abort => Arc::new(DataSchema::empty()),
             Plan::Call(_) => Arc::new(DataSchema::empty()),
             Plan::Presign(plan) => plan.schema(),
             Plan::SetVariable(plan) => plan.schema(),
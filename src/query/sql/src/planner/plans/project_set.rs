@@ -0,0 +1,105 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::DataType;
+
+use crate::optimizer::ColumnSet;
+use crate::optimizer::PhysicalProperty;
+use crate::optimizer::RelExpr;
+use crate::optimizer::RelationalProperty;
+use crate::optimizer::RequiredProperty;
+use crate::optimizer::Statistics;
+use crate::plans::Operator;
+use crate::plans::RelOp;
+use crate::plans::ScalarItem;
+use crate::IndexType;
+
+/// Evaluate a set-returning function for each row of the input, and join
+/// the row with each row returned by the function.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ProjectSet {
+    pub func_name: String,
+    // Arguments of the function, they are evaluated by the child `EvalScalar`
+    pub arguments: Vec<ScalarItem>,
+    // Output columns of the function
+    pub columns: Vec<(IndexType, DataType)>,
+}
+
+impl ProjectSet {
+    pub fn used_columns(&self) -> Result<ColumnSet> {
+        let mut used_columns = ColumnSet::new();
+        for arg in self.arguments.iter() {
+            used_columns.insert(arg.index);
+            used_columns.extend(arg.scalar.used_columns())
+        }
+        used_columns.extend(self.columns.iter().map(|(index, _)| *index));
+        Ok(used_columns)
+    }
+}
+
+impl Operator for ProjectSet {
+    fn rel_op(&self) -> RelOp {
+        RelOp::ProjectSet
+    }
+
+    fn derive_physical_prop(&self, rel_expr: &RelExpr) -> Result<PhysicalProperty> {
+        rel_expr.derive_physical_prop_child(0)
+    }
+
+    fn compute_required_prop_child(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _rel_expr: &RelExpr,
+        _child_index: usize,
+        required: &RequiredProperty,
+    ) -> Result<RequiredProperty> {
+        Ok(required.clone())
+    }
+
+    fn derive_relational_prop(&self, rel_expr: &RelExpr) -> Result<RelationalProperty> {
+        let input_prop = rel_expr.derive_relational_prop_child(0)?;
+
+        // Derive output columns
+        let mut output_columns = input_prop.output_columns;
+        output_columns.extend(self.columns.iter().map(|(index, _)| *index));
+
+        // Derive outer columns
+        let outer_columns = input_prop
+            .outer_columns
+            .difference(&output_columns)
+            .cloned()
+            .collect();
+
+        // Derive used columns
+        let mut used_columns = self.used_columns()?;
+        used_columns.extend(input_prop.used_columns);
+
+        // The number of rows returned by the function is unknown.
+        Ok(RelationalProperty {
+            output_columns,
+            outer_columns,
+            used_columns,
+            cardinality: input_prop.cardinality,
+            statistics: Statistics {
+                precise_cardinality: None,
+                column_stats: input_prop.statistics.column_stats,
+                is_accurate: false,
+            },
+        })
+    }
+}
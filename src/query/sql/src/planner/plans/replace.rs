@@ -0,0 +1,59 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_expression::DataSchema;
+use common_expression::DataSchemaRef;
+use common_expression::FieldIndex;
+use common_expression::TableSchemaRef;
+use common_meta_types::MetaId;
+
+use crate::plans::InsertInputSource;
+
+#[derive(Clone)]
+pub struct Replace {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub table_id: MetaId,
+    /// The fields of the table which identify the conflicting rows.
+    pub on_conflict_fields: Vec<FieldIndex>,
+    /// The schema of the inserted columns.
+    pub schema: TableSchemaRef,
+    pub source: InsertInputSource,
+}
+
+impl Replace {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+
+    pub fn insert_schema(&self) -> DataSchemaRef {
+        Arc::new(self.schema.clone().into())
+    }
+}
+
+impl std::fmt::Debug for Replace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Replace")
+            .field("catalog", &self.catalog)
+            .field("database", &self.database)
+            .field("table", &self.table)
+            .field("table_id", &self.table_id)
+            .field("on_conflict_fields", &self.on_conflict_fields)
+            .field("schema", &self.schema)
+            .finish()
+    }
+}
@@ -18,11 +18,14 @@ use common_ast::ast::BinaryOperator;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
 use common_expression::Literal;
+use common_expression::RemoteExpr;
 
 use crate::binder::ColumnBinding;
 use crate::optimizer::ColumnSet;
 use crate::optimizer::SExpr;
+use crate::plans::WindowFuncFrame;
 use crate::IndexType;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -34,11 +37,13 @@ pub enum ScalarExpr {
     NotExpr(NotExpr),
     ComparisonExpr(ComparisonExpr),
     AggregateFunction(AggregateFunction),
+    WindowFunction(WindowFunc),
     FunctionCall(FunctionCall),
     // TODO(leiysky): maybe we don't need this variant any more
     // after making functions static typed?
     CastExpr(CastExpr),
     SubqueryExpr(SubqueryExpr),
+    LambdaFunction(LambdaFunc),
 }
 
 impl ScalarExpr {
@@ -51,9 +56,11 @@ impl ScalarExpr {
             ScalarExpr::NotExpr(scalar) => (*scalar.return_type).clone(),
             ScalarExpr::ComparisonExpr(scalar) => (*scalar.return_type).clone(),
             ScalarExpr::AggregateFunction(scalar) => (*scalar.return_type).clone(),
+            ScalarExpr::WindowFunction(scalar) => scalar.func.return_type(),
             ScalarExpr::FunctionCall(scalar) => (*scalar.return_type).clone(),
             ScalarExpr::CastExpr(scalar) => (*scalar.target_type).clone(),
             ScalarExpr::SubqueryExpr(scalar) => scalar.data_type(),
+            ScalarExpr::LambdaFunction(scalar) => (*scalar.return_type).clone(),
         }
     }
 
@@ -84,6 +91,19 @@ impl ScalarExpr {
                 }
                 result
             }
+            ScalarExpr::WindowFunction(scalar) => {
+                let mut result = ColumnSet::new();
+                for scalar in scalar.func.arguments() {
+                    result = result.union(&scalar.used_columns()).cloned().collect();
+                }
+                for scalar in &scalar.partition_by {
+                    result = result.union(&scalar.used_columns()).cloned().collect();
+                }
+                for order in &scalar.order_by {
+                    result = result.union(&order.expr.used_columns()).cloned().collect();
+                }
+                result
+            }
             ScalarExpr::FunctionCall(scalar) => {
                 let mut result = ColumnSet::new();
                 for scalar in &scalar.arguments {
@@ -93,6 +113,13 @@ impl ScalarExpr {
             }
             ScalarExpr::CastExpr(scalar) => scalar.argument.used_columns(),
             ScalarExpr::SubqueryExpr(scalar) => scalar.outer_columns.clone(),
+            ScalarExpr::LambdaFunction(scalar) => {
+                let mut result = ColumnSet::new();
+                for scalar in &scalar.args {
+                    result = result.union(&scalar.used_columns()).cloned().collect();
+                }
+                result
+            }
         }
     }
 }
@@ -224,6 +251,25 @@ impl TryFrom<ScalarExpr> for AggregateFunction {
     }
 }
 
+impl From<WindowFunc> for ScalarExpr {
+    fn from(v: WindowFunc) -> Self {
+        Self::WindowFunction(v)
+    }
+}
+
+impl TryFrom<ScalarExpr> for WindowFunc {
+    type Error = ErrorCode;
+    fn try_from(value: ScalarExpr) -> Result<Self> {
+        if let ScalarExpr::WindowFunction(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast Scalar to WindowFunction",
+            ))
+        }
+    }
+}
+
 impl From<FunctionCall> for ScalarExpr {
     fn from(v: FunctionCall) -> Self {
         Self::FunctionCall(v)
@@ -279,6 +325,25 @@ impl TryFrom<ScalarExpr> for SubqueryExpr {
     }
 }
 
+impl From<LambdaFunc> for ScalarExpr {
+    fn from(v: LambdaFunc) -> Self {
+        Self::LambdaFunction(v)
+    }
+}
+
+impl TryFrom<ScalarExpr> for LambdaFunc {
+    type Error = ErrorCode;
+    fn try_from(value: ScalarExpr) -> Result<Self> {
+        if let ScalarExpr::LambdaFunction(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast Scalar to LambdaFunction",
+            ))
+        }
+    }
+}
+
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub struct BoundColumnRef {
     pub column: ColumnBinding,
@@ -379,6 +444,138 @@ pub struct AggregateFunction {
     pub return_type: Box<DataType>,
 }
 
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct WindowFunc {
+    pub display_name: String,
+
+    pub func: WindowFuncType,
+    pub partition_by: Vec<ScalarExpr>,
+    pub order_by: Vec<WindowOrderBy>,
+    pub frame: WindowFuncFrame,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct WindowOrderBy {
+    pub expr: ScalarExpr,
+    // Optional `ASC` or `DESC`
+    pub asc: Option<bool>,
+    // Optional `NULLS FIRST` or `NULLS LAST`
+    pub nulls_first: Option<bool>,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum WindowFuncType {
+    Aggregate(AggregateFunction),
+    RowNumber,
+    Rank,
+    DenseRank,
+    PercentRank,
+    CumeDist,
+    LagLead(LagLeadFunction),
+    NthValue(NthValueFunction),
+}
+
+/// `LAG(arg, offset, default)` or `LEAD(arg, offset, default)`.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct LagLeadFunction {
+    pub is_lag: bool,
+    pub arg: Box<ScalarExpr>,
+    pub offset: u64,
+    // The value if the offset row is out of the partition, it's NULL if not given
+    pub default: Option<Box<ScalarExpr>>,
+    pub return_type: Box<DataType>,
+}
+
+/// `FIRST_VALUE(arg)` or `LAST_VALUE(arg)`, the value of the n-th row of the frame.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct NthValueFunction {
+    // The n-th row counted from 1, `None` is the last row of the frame
+    pub n: Option<u64>,
+    pub arg: Box<ScalarExpr>,
+    pub return_type: Box<DataType>,
+}
+
+impl WindowFuncType {
+    pub fn from_name(name: &str) -> Result<WindowFuncType> {
+        match name.to_lowercase().as_str() {
+            "row_number" => Ok(WindowFuncType::RowNumber),
+            "rank" => Ok(WindowFuncType::Rank),
+            "dense_rank" => Ok(WindowFuncType::DenseRank),
+            "percent_rank" => Ok(WindowFuncType::PercentRank),
+            "cume_dist" => Ok(WindowFuncType::CumeDist),
+            _ => Err(ErrorCode::UnknownFunction(format!(
+                "Unknown window function: {name}"
+            ))),
+        }
+    }
+
+    pub fn func_name(&self) -> String {
+        match self {
+            WindowFuncType::Aggregate(agg) => agg.func_name.to_string(),
+            WindowFuncType::RowNumber => "row_number".to_string(),
+            WindowFuncType::Rank => "rank".to_string(),
+            WindowFuncType::DenseRank => "dense_rank".to_string(),
+            WindowFuncType::PercentRank => "percent_rank".to_string(),
+            WindowFuncType::CumeDist => "cume_dist".to_string(),
+            WindowFuncType::LagLead(lag_lead) if lag_lead.is_lag => "lag".to_string(),
+            WindowFuncType::LagLead(_) => "lead".to_string(),
+            WindowFuncType::NthValue(nth_value) => match nth_value.n {
+                Some(1) => "first_value".to_string(),
+                Some(_) => "nth_value".to_string(),
+                None => "last_value".to_string(),
+            },
+        }
+    }
+
+    /// The arguments of the window function, which are evaluated before the window.
+    pub fn arguments(&self) -> Vec<&ScalarExpr> {
+        match self {
+            WindowFuncType::Aggregate(agg) => agg.args.iter().collect(),
+            WindowFuncType::LagLead(lag_lead) => std::iter::once(lag_lead.arg.as_ref())
+                .chain(lag_lead.default.as_deref())
+                .collect(),
+            WindowFuncType::NthValue(nth_value) => vec![nth_value.arg.as_ref()],
+            _ => vec![],
+        }
+    }
+
+    /// Replace the arguments of the window function, `args` are in the same order as
+    /// [`WindowFuncType::arguments`].
+    pub fn with_arguments(&self, args: Vec<ScalarExpr>) -> WindowFuncType {
+        let mut args = args.into_iter();
+        match self {
+            WindowFuncType::Aggregate(agg) => WindowFuncType::Aggregate(AggregateFunction {
+                args: args.collect(),
+                ..agg.clone()
+            }),
+            WindowFuncType::LagLead(lag_lead) => WindowFuncType::LagLead(LagLeadFunction {
+                arg: Box::new(args.next().unwrap()),
+                default: args.next().map(Box::new),
+                ..lag_lead.clone()
+            }),
+            WindowFuncType::NthValue(nth_value) => WindowFuncType::NthValue(NthValueFunction {
+                arg: Box::new(args.next().unwrap()),
+                ..nth_value.clone()
+            }),
+            func => func.clone(),
+        }
+    }
+
+    pub fn return_type(&self) -> DataType {
+        match self {
+            WindowFuncType::Aggregate(agg) => *agg.return_type.clone(),
+            WindowFuncType::RowNumber | WindowFuncType::Rank | WindowFuncType::DenseRank => {
+                DataType::Number(NumberDataType::UInt64)
+            }
+            WindowFuncType::PercentRank | WindowFuncType::CumeDist => {
+                DataType::Number(NumberDataType::Float64)
+            }
+            WindowFuncType::LagLead(lag_lead) => *lag_lead.return_type.clone(),
+            WindowFuncType::NthValue(nth_value) => *nth_value.return_type.clone(),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct FunctionCall {
     pub params: Vec<usize>,
@@ -445,3 +642,34 @@ impl Hash for SubqueryExpr {
         unreachable!()
     }
 }
+
+/// A higher-order function whose last argument is a lambda, like `array_transform(a, x -> x + 1)`.
+///
+/// The lambda body is type-checked on its own and refers to its parameters by position,
+/// so it does not take part in column binding.
+#[derive(Clone, Debug)]
+pub struct LambdaFunc {
+    pub func_name: String,
+    pub args: Vec<ScalarExpr>,
+    pub lambda_expr: Box<RemoteExpr>,
+    /// The lambda as written in SQL, like `x -> x + 1`.
+    pub lambda_display: String,
+    pub return_type: Box<DataType>,
+}
+
+impl PartialEq for LambdaFunc {
+    fn eq(&self, other: &Self) -> bool {
+        self.func_name == other.func_name
+            && self.args == other.args
+            && self.lambda_expr == other.lambda_expr
+    }
+}
+
+impl Eq for LambdaFunc {}
+
+impl Hash for LambdaFunc {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.func_name.hash(state);
+        self.args.hash(state);
+    }
+}
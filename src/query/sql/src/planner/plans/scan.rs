@@ -15,6 +15,7 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use common_catalog::table::ColumnHistogram;
 use common_catalog::table::ColumnStatistics;
 use common_catalog::table::TableStatistics;
 use common_catalog::table_context::TableContext;
@@ -27,6 +28,8 @@ use crate::optimizer::ColumnStat;
 use crate::optimizer::ColumnStatSet;
 use crate::optimizer::Datum;
 use crate::optimizer::Distribution;
+use crate::optimizer::Histogram;
+use crate::optimizer::HistogramBucket;
 use crate::optimizer::PhysicalProperty;
 use crate::optimizer::RelExpr;
 use crate::optimizer::RelationalProperty;
@@ -116,6 +119,26 @@ impl std::hash::Hash for Scan {
     }
 }
 
+fn collected_histogram(histogram: &ColumnHistogram) -> Option<Histogram> {
+    if histogram.buckets.is_empty() {
+        return None;
+    }
+    let buckets = histogram
+        .buckets
+        .iter()
+        .map(|(upper_bound, num_values, num_distinct)| {
+            Datum::from_data_value(upper_bound)
+                .map(|datum| HistogramBucket::new(datum, *num_values, *num_distinct))
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let most_common_values = histogram
+        .most_common_values
+        .iter()
+        .map(|(value, count)| Datum::from_data_value(value).map(|datum| (datum, *count)))
+        .collect::<Option<Vec<_>>>()?;
+    Some(Histogram::new_collected(buckets, most_common_values))
+}
+
 impl Operator for Scan {
     fn rel_op(&self) -> RelOp {
         RelOp::Scan
@@ -153,13 +176,20 @@ impl Operator for Scan {
                 let min_datum = Datum::from_data_value(&min);
                 let max_datum = Datum::from_data_value(&max);
                 if let (Some(min), Some(max)) = (min_datum, max_datum) {
-                    let histogram = histogram_from_ndv(
-                        col_stat.number_of_distinct_values,
-                        num_rows,
-                        Some((min.clone(), max.clone())),
-                        DEFAULT_HISTOGRAM_BUCKETS,
-                    )
-                    .ok();
+                    // Prefer the histogram collected by `ANALYZE TABLE`.
+                    let histogram = col_stat
+                        .histogram
+                        .as_ref()
+                        .and_then(collected_histogram)
+                        .or_else(|| {
+                            histogram_from_ndv(
+                                col_stat.number_of_distinct_values,
+                                num_rows,
+                                Some((min.clone(), max.clone())),
+                                DEFAULT_HISTOGRAM_BUCKETS,
+                            )
+                            .ok()
+                        });
                     let column_stat = ColumnStat {
                         min,
                         max,
@@ -0,0 +1,163 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::optimizer::ColumnSet;
+use crate::optimizer::Distribution;
+use crate::optimizer::PhysicalProperty;
+use crate::optimizer::RelExpr;
+use crate::optimizer::RelationalProperty;
+use crate::optimizer::RequiredProperty;
+use crate::optimizer::Statistics;
+use crate::plans::Operator;
+use crate::plans::RelOp;
+use crate::plans::ScalarItem;
+use crate::plans::WindowFuncType;
+use crate::IndexType;
+
+/// Evaluate a window function over the (sorted) input,
+/// appending its result as a new column.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Window {
+    // Index of the window function output column
+    pub index: IndexType,
+    pub function: WindowFuncType,
+    // Arguments of the window function, they are evaluated by the child `EvalScalar`
+    pub arguments: Vec<ScalarItem>,
+    pub partition_by: Vec<ScalarItem>,
+    pub order_by: Vec<WindowOrderByInfo>,
+    pub frame: WindowFuncFrame,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct WindowOrderByInfo {
+    pub order_by_item: ScalarItem,
+    pub asc: Option<bool>,
+    pub nulls_first: Option<bool>,
+}
+
+impl Window {
+    pub fn used_columns(&self) -> Result<ColumnSet> {
+        let mut used_columns = ColumnSet::new();
+        used_columns.insert(self.index);
+        for arg in self.arguments.iter() {
+            used_columns.insert(arg.index);
+            used_columns.extend(arg.scalar.used_columns())
+        }
+        for part in self.partition_by.iter() {
+            used_columns.insert(part.index);
+            used_columns.extend(part.scalar.used_columns())
+        }
+        for sort in self.order_by.iter() {
+            used_columns.insert(sort.order_by_item.index);
+            used_columns.extend(sort.order_by_item.scalar.used_columns())
+        }
+        Ok(used_columns)
+    }
+}
+
+impl Operator for Window {
+    fn rel_op(&self) -> RelOp {
+        RelOp::Window
+    }
+
+    fn derive_physical_prop(&self, rel_expr: &RelExpr) -> Result<PhysicalProperty> {
+        rel_expr.derive_physical_prop_child(0)
+    }
+
+    fn compute_required_prop_child(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _rel_expr: &RelExpr,
+        _child_index: usize,
+        required: &RequiredProperty,
+    ) -> Result<RequiredProperty> {
+        // Rows of a partition must be processed by a single processor.
+        let mut required = required.clone();
+        required.distribution = Distribution::Serial;
+        Ok(required)
+    }
+
+    fn derive_relational_prop(&self, rel_expr: &RelExpr) -> Result<RelationalProperty> {
+        let input_prop = rel_expr.derive_relational_prop_child(0)?;
+
+        // Derive output columns
+        let mut output_columns = input_prop.output_columns;
+        output_columns.insert(self.index);
+
+        // Derive outer columns
+        let outer_columns = input_prop
+            .outer_columns
+            .difference(&output_columns)
+            .cloned()
+            .collect();
+
+        // Derive used columns
+        let mut used_columns = self.used_columns()?;
+        used_columns.extend(input_prop.used_columns);
+
+        Ok(RelationalProperty {
+            output_columns,
+            outer_columns,
+            used_columns,
+            cardinality: input_prop.cardinality,
+            statistics: Statistics {
+                precise_cardinality: input_prop.statistics.precise_cardinality,
+                column_stats: input_prop.statistics.column_stats,
+                is_accurate: input_prop.statistics.is_accurate,
+            },
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct WindowFuncFrame {
+    pub units: WindowFuncFrameUnits,
+    pub start_bound: WindowFuncFrameBound,
+    pub end_bound: WindowFuncFrameBound,
+}
+
+impl Display for WindowFuncFrame {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:?}: {:?} ~ {:?}",
+            self.units, self.start_bound, self.end_bound
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum WindowFuncFrameUnits {
+    Rows,
+    Range,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum WindowFuncFrameBound {
+    /// `CURRENT ROW`
+    CurrentRow,
+    /// `<N> PRECEDING` or `UNBOUNDED PRECEDING`
+    Preceding(Option<u64>),
+    /// `<N> FOLLOWING` or `UNBOUNDED FOLLOWING`.
+    Following(Option<u64>),
+}
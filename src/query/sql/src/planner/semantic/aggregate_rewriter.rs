@@ -32,8 +32,12 @@ impl VisitorMut for AggregateRewriter {
                 distinct,
                 name,
                 args,
+                order_by,
+                window,
                 ..
             } if !*distinct
+                && order_by.is_empty()
+                && window.is_none()
                 && args.len() == 1
                 && name.name.to_ascii_lowercase().to_lowercase() == "sum" =>
             {
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use common_ast::ast::Expr;
+use common_ast::ast::GroupBy;
 use common_ast::ast::Identifier;
 use common_ast::ast::Query;
 use common_ast::ast::SelectStmt;
@@ -34,7 +35,7 @@ impl VisitorMut for DistinctToGroupBy {
             ..
         } = stmt;
 
-        if group_by.is_empty() && select_list.len() == 1 && from.len() == 1 {
+        if group_by.is_none() && select_list.len() == 1 && from.len() == 1 {
             if let common_ast::ast::SelectTarget::AliasedExpr {
                 expr:
                     box Expr::FunctionCall {
@@ -42,6 +43,8 @@ impl VisitorMut for DistinctToGroupBy {
                         distinct,
                         name,
                         args,
+                        order_by,
+                        window: None,
                         ..
                     },
                 alias,
@@ -50,6 +53,7 @@ impl VisitorMut for DistinctToGroupBy {
                 if ((name.name.to_ascii_lowercase() == "count" && *distinct)
                     || name.name.to_ascii_lowercase() == "count_distinct")
                     && args.iter().all(|arg| !matches!(arg, Expr::Literal { .. }))
+                    && order_by.is_empty()
                 {
                     let subquery = Query {
                         span: None,
@@ -60,8 +64,9 @@ impl VisitorMut for DistinctToGroupBy {
                             select_list: vec![],
                             from: from.clone(),
                             selection: selection.clone(),
-                            group_by: args.clone(),
+                            group_by: Some(GroupBy::Normal(args.clone())),
                             having: None,
+                            window_list: None,
                         })),
                         order_by: vec![],
                         limit: vec![],
@@ -83,6 +88,8 @@ impl VisitorMut for DistinctToGroupBy {
                                 },
                                 args: vec![],
                                 params: vec![],
+                                order_by: vec![],
+                                window: None,
                             }),
                             alias: alias.clone(),
                         }],
@@ -92,8 +99,9 @@ impl VisitorMut for DistinctToGroupBy {
                             alias: None,
                         }],
                         selection: None,
-                        group_by: vec![],
+                        group_by: None,
                         having: having.clone(),
+                        window_list: None,
                     };
 
                     *stmt = new_stmt;
@@ -23,6 +23,7 @@ use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
 use crate::plans::ComparisonExpr;
 use crate::plans::FunctionCall;
+use crate::plans::LambdaFunc;
 use crate::plans::NotExpr;
 use crate::plans::OrExpr;
 use crate::plans::ScalarExpr;
@@ -48,12 +49,17 @@ impl<'a> GroupingChecker<'a> {
             .get(&format!("{:?}", scalar))
         {
             let column = &self.bind_context.aggregate_info.group_items[*index];
+            let mut data_type = column.scalar.data_type();
+            if self.bind_context.aggregate_info.grouping_sets.is_some() {
+                // The group item is NULL if it's not in the grouping set of the row
+                data_type = data_type.wrap_nullable();
+            }
             let column_binding = ColumnBinding {
                 database_name: None,
                 table_name: None,
                 column_name: "group_item".to_string(),
                 index: column.index,
-                data_type: Box::new(column.scalar.data_type()),
+                data_type: Box::new(data_type),
                 visibility: Visibility::Visible,
             };
             return Ok(BoundColumnRef {
@@ -63,6 +69,15 @@ impl<'a> GroupingChecker<'a> {
         }
 
         match scalar {
+            ScalarExpr::BoundColumnRef(column)
+                if self
+                    .bind_context
+                    .windows
+                    .is_window_output(column.column.index) =>
+            {
+                // The window functions are evaluated after aggregation
+                Ok(scalar.clone())
+            }
             ScalarExpr::BoundColumnRef(column) => {
                 // If this is a group item, then it should have been replaced with `group_items_map`
                 Err(ErrorCode::SemanticError(format!(
@@ -95,6 +110,9 @@ impl<'a> GroupingChecker<'a> {
                 return_type: scalar.return_type.clone(),
             }
             .into()),
+            ScalarExpr::FunctionCall(func) if func.func_name == "grouping" => {
+                self.resolve_grouping(func, span)
+            }
             ScalarExpr::FunctionCall(func) => {
                 let args = func
                     .arguments
@@ -116,6 +134,21 @@ impl<'a> GroupingChecker<'a> {
                 target_type: cast.target_type.clone(),
             }
             .into()),
+            ScalarExpr::LambdaFunction(lambda) => {
+                let args = lambda
+                    .args
+                    .iter()
+                    .map(|arg| self.resolve(arg, span))
+                    .collect::<Result<Vec<ScalarExpr>>>()?;
+                Ok(LambdaFunc {
+                    func_name: lambda.func_name.clone(),
+                    args,
+                    lambda_expr: lambda.lambda_expr.clone(),
+                    lambda_display: lambda.lambda_display.clone(),
+                    return_type: lambda.return_type.clone(),
+                }
+                .into())
+            }
             ScalarExpr::SubqueryExpr(_) => {
                 // TODO(leiysky): check subquery in the future
                 Ok(scalar.clone())
@@ -144,6 +177,72 @@ impl<'a> GroupingChecker<'a> {
                 }
                 Err(ErrorCode::Internal("Invalid aggregate function"))
             }
+
+            ScalarExpr::WindowFunction(win) => {
+                if let Some(column) = self
+                    .bind_context
+                    .windows
+                    .window_functions_map
+                    .get(&win.display_name)
+                {
+                    let window_info = &self.bind_context.windows.window_functions[*column];
+                    let column_binding = ColumnBinding {
+                        database_name: None,
+                        table_name: None,
+                        column_name: win.display_name.clone(),
+                        index: window_info.index,
+                        data_type: Box::new(window_info.func.return_type()),
+                        visibility: Visibility::Visible,
+                    };
+                    return Ok(BoundColumnRef {
+                        column: column_binding,
+                    }
+                    .into());
+                }
+                Err(ErrorCode::Internal("Invalid window function"))
+            }
+        }
+    }
+
+    /// Replace `GROUPING(a, b)` with `grouping<i, j>(_grouping_id)`, where `i` and `j`
+    /// are the positions of `a` and `b` in group items.
+    fn resolve_grouping(&mut self, func: &FunctionCall, span: Span) -> Result<ScalarExpr> {
+        let agg_info = &self.bind_context.aggregate_info;
+        let grouping_sets = agg_info.grouping_sets.as_ref().ok_or_else(|| {
+            ErrorCode::SemanticError(
+                "grouping can only be called in GROUP BY GROUPING SETS, ROLLUP or CUBE clause",
+            )
+            .set_span(span)
+        })?;
+
+        let params = func
+            .arguments
+            .iter()
+            .map(|arg| {
+                agg_info
+                    .group_items_map
+                    .get(&format!("{:?}", arg))
+                    .cloned()
+                    .ok_or_else(|| {
+                        ErrorCode::SemanticError(
+                            "arguments of grouping must be expressions in GROUP BY clause",
+                        )
+                        .set_span(span)
+                    })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(FunctionCall {
+            params,
+            arguments: vec![
+                BoundColumnRef {
+                    column: grouping_sets.grouping_id_column.clone(),
+                }
+                .into(),
+            ],
+            func_name: func.func_name.clone(),
+            return_type: func.return_type.clone(),
         }
+        .into())
     }
 }
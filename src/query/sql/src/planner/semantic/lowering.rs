@@ -86,6 +86,12 @@ impl ScalarExpr {
                 data_type: (*agg.return_type).clone(),
                 display_name: agg.display_name.clone(),
             },
+            ScalarExpr::WindowFunction(win) => RawExpr::ColumnRef {
+                span: None,
+                id: win.display_name.clone(),
+                data_type: win.func.return_type(),
+                display_name: win.display_name.clone(),
+            },
             ScalarExpr::FunctionCall(func) => RawExpr::FunctionCall {
                 span: None,
                 name: func.func_name.clone(),
@@ -102,6 +108,18 @@ impl ScalarExpr {
                 expr: Box::new(cast.argument.as_raw_expr_with_col_name()),
                 dest_type: (*cast.target_type).clone(),
             },
+            ScalarExpr::LambdaFunction(func) => RawExpr::LambdaFunctionCall {
+                span: None,
+                name: func.func_name.clone(),
+                args: func
+                    .args
+                    .iter()
+                    .map(ScalarExpr::as_raw_expr_with_col_name)
+                    .collect(),
+                lambda_expr: func.lambda_expr.clone(),
+                lambda_display: func.lambda_display.clone(),
+                return_type: (*func.return_type).clone(),
+            },
             ScalarExpr::SubqueryExpr(subquery) => RawExpr::ColumnRef {
                 span: None,
                 id: DUMMY_NAME.to_string(),
@@ -177,6 +195,12 @@ impl ScalarExpr {
                 data_type: (*agg.return_type).clone(),
                 display_name: agg.display_name.clone(),
             },
+            ScalarExpr::WindowFunction(win) => RawExpr::ColumnRef {
+                span: None,
+                id: DUMMY_INDEX,
+                data_type: win.func.return_type(),
+                display_name: win.display_name.clone(),
+            },
             ScalarExpr::FunctionCall(func) => RawExpr::FunctionCall {
                 span: None,
                 name: func.func_name.clone(),
@@ -193,6 +217,18 @@ impl ScalarExpr {
                 expr: Box::new(cast.argument.as_raw_expr_with_col_index()),
                 dest_type: (*cast.target_type).clone(),
             },
+            ScalarExpr::LambdaFunction(func) => RawExpr::LambdaFunctionCall {
+                span: None,
+                name: func.func_name.clone(),
+                args: func
+                    .args
+                    .iter()
+                    .map(ScalarExpr::as_raw_expr_with_col_index)
+                    .collect(),
+                lambda_expr: func.lambda_expr.clone(),
+                lambda_display: func.lambda_display.clone(),
+                return_type: (*func.return_type).clone(),
+            },
             ScalarExpr::SubqueryExpr(subquery) => RawExpr::ColumnRef {
                 span: None,
                 id: DUMMY_INDEX,
@@ -23,11 +23,16 @@ use common_ast::ast::Identifier;
 use common_ast::ast::IntervalKind as ASTIntervalKind;
 use common_ast::ast::Literal;
 use common_ast::ast::MapAccessor;
+use common_ast::ast::OrderByExpr;
 use common_ast::ast::Query;
 use common_ast::ast::SubqueryModifier;
 use common_ast::ast::TrimWhere;
 use common_ast::ast::TypeName;
 use common_ast::ast::UnaryOperator;
+use common_ast::ast::Window;
+use common_ast::ast::WindowFrame;
+use common_ast::ast::WindowFrameBound;
+use common_ast::ast::WindowFrameUnits;
 use common_ast::parser::parse_expr;
 use common_ast::parser::tokenize_sql;
 use common_ast::Backtrace;
@@ -52,7 +57,9 @@ use common_expression::TableDataType;
 use common_functions::aggregates::AggregateCountFunction;
 use common_functions::aggregates::AggregateFunctionFactory;
 use common_functions::is_builtin_function;
+use common_functions::is_lambda_function;
 use common_functions::scalars::BUILTIN_FUNCTIONS;
+use common_functions::srfs::is_set_returning_function;
 use common_users::UserApiProvider;
 
 use super::name_resolution::NameResolutionContext;
@@ -70,16 +77,26 @@ use crate::plans::ComparisonExpr;
 use crate::plans::ComparisonOp;
 use crate::plans::ConstantExpr;
 use crate::plans::FunctionCall;
+use crate::plans::LagLeadFunction;
+use crate::plans::LambdaFunc;
 use crate::plans::NotExpr;
+use crate::plans::NthValueFunction;
 use crate::plans::OrExpr;
 use crate::plans::ScalarExpr;
 use crate::plans::SubqueryExpr;
 use crate::plans::SubqueryType;
+use crate::plans::WindowFunc;
+use crate::plans::WindowFuncFrame;
+use crate::plans::WindowFuncFrameBound;
+use crate::plans::WindowFuncFrameUnits;
+use crate::plans::WindowFuncType;
+use crate::plans::WindowOrderBy;
 use crate::BaseTableColumn;
 use crate::BindContext;
 use crate::ColumnBinding;
 use crate::ColumnEntry;
 use crate::MetadataRef;
+use crate::Visibility;
 
 /// A helper for type checking.
 ///
@@ -101,6 +118,10 @@ pub struct TypeChecker<'a> {
     // true if current expr is inside an aggregate function.
     // This is used to check if there is nested aggregate function.
     in_aggregate_function: bool,
+
+    // true if current expr is inside a window function.
+    // This is used to check if there is nested window function.
+    in_window_function: bool,
 }
 
 impl<'a> TypeChecker<'a> {
@@ -118,6 +139,7 @@ impl<'a> TypeChecker<'a> {
             metadata,
             aliases,
             in_aggregate_function: false,
+            in_window_function: false,
         }
     }
 
@@ -306,6 +328,8 @@ impl<'a> TypeChecker<'a> {
                                 },
                                 args: args.iter().copied().cloned().collect(),
                                 params: vec![],
+                                order_by: vec![],
+                                window: None,
                             },
                             None,
                         )
@@ -579,6 +603,8 @@ impl<'a> TypeChecker<'a> {
                                 },
                                 args: vec![*operand.clone(), c.clone()],
                                 params: vec![],
+                                order_by: vec![],
+                                window: None,
                             };
                             arguments.push(equal_expr)
                         }
@@ -644,11 +670,36 @@ impl<'a> TypeChecker<'a> {
                 name,
                 args,
                 params,
-                ..
+                order_by,
+                window,
             } => {
                 let func_name = name.name.to_lowercase();
                 let func_name = func_name.as_str();
-                if !is_builtin_function(func_name)
+                if window.is_none()
+                    && (WindowFuncType::from_name(func_name).is_ok()
+                        || Self::value_window_functions().contains(&func_name))
+                {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "window function {func_name} requires an OVER clause"
+                    ))
+                    .set_span(*span));
+                }
+                if is_set_returning_function(func_name) {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "set-returning function {func_name} can only be used in the FROM clause"
+                    ))
+                    .set_span(*span));
+                }
+                if !order_by.is_empty()
+                    && !Self::order_sensitive_aggregate_functions().contains(&func_name)
+                {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "{func_name} does not support ORDER BY"
+                    ))
+                    .set_span(*span));
+                }
+                if window.is_none()
+                    && !is_builtin_function(func_name)
                     && !Self::all_rewritable_scalar_function().contains(&func_name)
                 {
                     return self.resolve_udf(*span, func_name, args).await;
@@ -656,7 +707,16 @@ impl<'a> TypeChecker<'a> {
 
                 let args: Vec<&Expr> = args.iter().collect();
 
-                if AggregateFunctionFactory::instance().contains(func_name) {
+                if let Some(window) = window {
+                    // Window function
+                    self.resolve_window_function(
+                        *span, expr, func_name, *distinct, params, order_by, &args, window,
+                    )
+                    .await?
+                } else if is_lambda_function(func_name) {
+                    self.resolve_lambda_function(*span, func_name, &args)
+                        .await?
+                } else if AggregateFunctionFactory::instance().contains(func_name) {
                     if self.in_aggregate_function {
                         // Reset the state
                         self.in_aggregate_function = false;
@@ -666,67 +726,12 @@ impl<'a> TypeChecker<'a> {
                         .set_span(expr.span()));
                     }
 
-                    // Check aggregate function
-                    let params = params
-                        .iter()
-                        .map(|literal| {
-                            self.resolve_literal(literal, None)
-                                .map(|box (value, _)| value)
-                        })
-                        .collect::<Result<Vec<_>>>()?;
-
-                    let scalar_params = params
-                        .iter()
-                        .map(|param| check_literal(param).0)
-                        .collect::<Vec<_>>();
-
-                    self.in_aggregate_function = true;
-                    let mut arguments = vec![];
-                    let mut arg_types = vec![];
-                    for arg in args.iter() {
-                        let box (argument, arg_type) = self.resolve(arg, None).await?;
-                        arguments.push(argument);
-                        arg_types.push(arg_type);
-                    }
-                    self.in_aggregate_function = false;
-
-                    // Rewrite `xxx(distinct)` to `xxx_distinct(...)`
-                    let (func_name, distinct) =
-                        if func_name.eq_ignore_ascii_case("count") && *distinct {
-                            ("count_distinct", false)
-                        } else {
-                            (func_name, *distinct)
-                        };
-
-                    let func_name = if distinct {
-                        format!("{}_distinct", func_name)
-                    } else {
-                        func_name.to_string()
-                    };
-
-                    let agg_func = AggregateFunctionFactory::instance()
-                        .get(&func_name, scalar_params, arg_types)
-                        .map_err(|e| e.set_span(*span))?;
-
-                    let args = if optimize_remove_count_args(&func_name, distinct, args.as_slice())
-                    {
-                        vec![]
-                    } else {
-                        arguments
-                    };
-
-                    Box::new((
-                        AggregateFunction {
-                            display_name: format!("{:#}", expr),
-                            func_name,
-                            distinct: false,
-                            params,
-                            args,
-                            return_type: Box::new(agg_func.return_type()?),
-                        }
-                        .into(),
-                        agg_func.return_type()?,
-                    ))
+                    let (agg, data_type) = self
+                        .resolve_aggregate_function(
+                            *span, func_name, expr, *distinct, params, order_by, &args, false,
+                        )
+                        .await?;
+                    Box::new((agg.into(), data_type))
                 } else {
                     // Scalar function
                     let params = params
@@ -745,21 +750,27 @@ impl<'a> TypeChecker<'a> {
                 }
             }
 
-            Expr::CountAll { .. } => {
-                let agg_func = AggregateCountFunction::try_create("", vec![], vec![])?;
+            Expr::CountAll { span, window } => {
+                if let Some(window) = window {
+                    // Window function
+                    self.resolve_window_function(*span, expr, "count", false, &[], &[], &[], window)
+                        .await?
+                } else {
+                    let agg_func = AggregateCountFunction::try_create("", vec![], vec![])?;
 
-                Box::new((
-                    AggregateFunction {
-                        display_name: format!("{:#}", expr),
-                        func_name: "count".to_string(),
-                        distinct: false,
-                        params: vec![],
-                        args: vec![],
-                        return_type: Box::new(agg_func.return_type()?),
-                    }
-                    .into(),
-                    agg_func.return_type()?,
-                ))
+                    Box::new((
+                        AggregateFunction {
+                            display_name: format!("{:#}", expr),
+                            func_name: "count".to_string(),
+                            distinct: false,
+                            params: vec![],
+                            args: vec![],
+                            return_type: Box::new(agg_func.return_type()?),
+                        }
+                        .into(),
+                        agg_func.return_type()?,
+                    ))
+                }
             }
 
             Expr::Exists { subquery, not, .. } => {
@@ -932,6 +943,14 @@ impl<'a> TypeChecker<'a> {
             }
 
             Expr::Tuple { span, exprs, .. } => self.resolve_tuple(*span, exprs).await?,
+
+            Expr::Lambda { span, .. } => {
+                return Err(ErrorCode::SemanticError(
+                    "lambda expression can only be used as the argument of a higher-order function"
+                        .to_string(),
+                )
+                .set_span(*span));
+            }
         };
 
         Ok(Box::new(self.post_resolve(&scalar, &data_type)?))
@@ -950,6 +969,398 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
+    /// Resolve aggregate function call.
+    /// If `in_window` is true, the aggregate function is evaluated over a window, so
+    /// its arguments are allowed to contain (group) aggregate functions.
+    #[allow(clippy::too_many_arguments)]
+    #[async_recursion::async_recursion]
+    async fn resolve_aggregate_function(
+        &mut self,
+        span: Span,
+        func_name: &str,
+        expr: &Expr,
+        distinct: bool,
+        params: &[Literal],
+        order_by: &[OrderByExpr],
+        args: &[&Expr],
+        in_window: bool,
+    ) -> Result<(AggregateFunction, DataType)> {
+        // Check aggregate function
+        let mut params = params
+            .iter()
+            .map(|literal| {
+                self.resolve_literal(literal, None)
+                    .map(|box (value, _)| value)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // `ARRAY_AGG(x ORDER BY y DESC)` is lowered to `ARRAY_AGG(FALSE, FALSE)(x, y)`,
+        // the sort keys are appended to the arguments, and every sort key adds whether it
+        // is ascending and whether nulls come first to the parameters.
+        let mut args = args.to_vec();
+        if !order_by.is_empty() {
+            if distinct {
+                return Err(ErrorCode::SemanticError(format!(
+                    "{func_name} does not support both DISTINCT and ORDER BY"
+                ))
+                .set_span(span));
+            }
+            if !params.is_empty() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "{func_name} does not support both parameters and ORDER BY"
+                ))
+                .set_span(span));
+            }
+            let default_nulls_first = !self.ctx.get_settings().get_sql_dialect()?.is_null_biggest();
+            for order_by in order_by.iter() {
+                params.push(common_expression::Literal::Boolean(
+                    order_by.asc.unwrap_or(true),
+                ));
+                params.push(common_expression::Literal::Boolean(
+                    order_by.nulls_first.unwrap_or(default_nulls_first),
+                ));
+                args.push(&order_by.expr);
+            }
+        }
+
+        let scalar_params = params
+            .iter()
+            .map(|param| check_literal(param).0)
+            .collect::<Vec<_>>();
+
+        self.in_aggregate_function = !in_window;
+        let mut arguments = vec![];
+        let mut arg_types = vec![];
+        for arg in args.iter() {
+            let box (argument, arg_type) = self.resolve(arg, None).await?;
+            arguments.push(argument);
+            arg_types.push(arg_type);
+        }
+        self.in_aggregate_function = false;
+
+        // Rewrite `xxx(distinct)` to `xxx_distinct(...)`
+        let (func_name, distinct) = if func_name.eq_ignore_ascii_case("count") && distinct {
+            ("count_distinct", false)
+        } else {
+            (func_name, distinct)
+        };
+
+        let func_name = if distinct {
+            format!("{}_distinct", func_name)
+        } else {
+            func_name.to_string()
+        };
+
+        let agg_func = AggregateFunctionFactory::instance()
+            .get(&func_name, scalar_params, arg_types)
+            .map_err(|e| e.set_span(span))?;
+
+        let args = if optimize_remove_count_args(&func_name, distinct, &args) {
+            vec![]
+        } else {
+            arguments
+        };
+
+        Ok((
+            AggregateFunction {
+                display_name: format!("{:#}", expr),
+                func_name,
+                distinct: false,
+                params,
+                args,
+                return_type: Box::new(agg_func.return_type()?),
+            },
+            agg_func.return_type()?,
+        ))
+    }
+
+    /// Resolve window function call, e.g. `ROW_NUMBER() OVER (PARTITION BY a ORDER BY b)`.
+    #[allow(clippy::too_many_arguments)]
+    #[async_recursion::async_recursion]
+    async fn resolve_window_function(
+        &mut self,
+        span: Span,
+        expr: &Expr,
+        func_name: &str,
+        distinct: bool,
+        params: &[Literal],
+        order_by: &[OrderByExpr],
+        args: &[&Expr],
+        window: &Window,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        if self.in_aggregate_function {
+            // Reset the state
+            self.in_aggregate_function = false;
+            return Err(ErrorCode::SemanticError(
+                "window function calls cannot be nested inside aggregate function".to_string(),
+            )
+            .set_span(span));
+        }
+        if self.in_window_function {
+            // Reset the state
+            self.in_window_function = false;
+            return Err(ErrorCode::SemanticError(
+                "window function calls cannot be nested".to_string(),
+            )
+            .set_span(span));
+        }
+
+        // Named windows should have been expanded by binder.
+        let spec = match window {
+            Window::WindowSpec(spec) => spec,
+            Window::WindowReference(reference) => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "window '{}' is not defined",
+                    reference.window_name.name
+                ))
+                .set_span(span));
+            }
+        };
+
+        self.in_window_function = true;
+        let func = if AggregateFunctionFactory::instance().contains(func_name) {
+            let (agg, _) = self
+                .resolve_aggregate_function(
+                    span, func_name, expr, distinct, params, order_by, args, true,
+                )
+                .await?;
+            WindowFuncType::Aggregate(agg)
+        } else if Self::value_window_functions().contains(&func_name) {
+            if !params.is_empty() || distinct {
+                return Err(ErrorCode::SemanticError(format!(
+                    "window function {func_name} doesn't accept parameters or DISTINCT"
+                ))
+                .set_span(span));
+            }
+            self.resolve_value_window_function(span, func_name, args)
+                .await?
+        } else {
+            if !args.is_empty() || !params.is_empty() || distinct {
+                return Err(ErrorCode::SemanticError(format!(
+                    "window function {func_name} doesn't accept any argument"
+                ))
+                .set_span(span));
+            }
+            WindowFuncType::from_name(func_name).map_err(|e| e.set_span(span))?
+        };
+
+        let mut partition_by = Vec::with_capacity(spec.partition_by.len());
+        for p in spec.partition_by.iter() {
+            let box (argument, _) = self.resolve(p, None).await?;
+            partition_by.push(argument);
+        }
+
+        let mut order_by = Vec::with_capacity(spec.order_by.len());
+        for o in spec.order_by.iter() {
+            let box (argument, _) = self.resolve(&o.expr, None).await?;
+            order_by.push(WindowOrderBy {
+                expr: argument,
+                asc: o.asc,
+                nulls_first: o.nulls_first,
+            });
+        }
+        self.in_window_function = false;
+
+        let frame = self.resolve_window_frame(span, !order_by.is_empty(), &spec.window_frame)?;
+        let data_type = func.return_type();
+
+        Ok(Box::new((
+            WindowFunc {
+                display_name: format!("{:#}", expr),
+                func,
+                partition_by,
+                order_by,
+                frame,
+            }
+            .into(),
+            data_type,
+        )))
+    }
+
+    pub fn value_window_functions() -> &'static [&'static str] {
+        &["lag", "lead", "first_value", "last_value"]
+    }
+
+    /// Aggregate functions depending on the order of the input, which accept sort keys
+    /// like `ARRAY_AGG(x ORDER BY y)`.
+    pub fn order_sensitive_aggregate_functions() -> &'static [&'static str] {
+        &["array_agg", "list"]
+    }
+
+    /// Resolve the window functions returning a value of another row, i.e.
+    /// `LAG(arg [, offset [, default]])`, `LEAD(...)`, `FIRST_VALUE(arg)` and `LAST_VALUE(arg)`.
+    /// They return NULL if the row is out of the partition or frame, so the return type
+    /// is nullable.
+    #[async_recursion::async_recursion]
+    async fn resolve_value_window_function(
+        &mut self,
+        span: Span,
+        func_name: &str,
+        args: &[&Expr],
+    ) -> Result<WindowFuncType> {
+        match func_name {
+            "lag" | "lead" => {
+                if args.is_empty() || args.len() > 3 {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "window function {func_name} requires 1 to 3 arguments, but got {}",
+                        args.len()
+                    ))
+                    .set_span(span));
+                }
+                let box (arg, arg_type) = self.resolve(args[0], None).await?;
+                let offset = match args.get(1) {
+                    None => 1,
+                    Some(Expr::Literal {
+                        lit: Literal::Integer(n),
+                        ..
+                    }) => *n,
+                    Some(expr) => {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "offset of {func_name} must be a non-negative integer literal, but got {expr}"
+                        ))
+                        .set_span(expr.span()));
+                    }
+                };
+                let (default, return_type) = match args.get(2) {
+                    None => (None, arg_type.wrap_nullable()),
+                    Some(expr) => {
+                        let box (default, default_type) = self.resolve(expr, None).await?;
+                        let return_type = common_super_type(
+                            arg_type.clone(),
+                            default_type.clone(),
+                            &BUILTIN_FUNCTIONS.default_cast_rules,
+                        )
+                        .ok_or_else(|| {
+                            ErrorCode::SemanticError(format!(
+                                "default value of {func_name} has type {default_type}, which can't be matched with the argument type {arg_type}"
+                            ))
+                            .set_span(expr.span())
+                        })?
+                        .wrap_nullable();
+                        (Some(default), return_type)
+                    }
+                };
+
+                Ok(WindowFuncType::LagLead(LagLeadFunction {
+                    is_lag: func_name == "lag",
+                    arg: Box::new(wrap_cast_if_needed(&arg, &return_type)),
+                    offset,
+                    default: default
+                        .map(|default| Box::new(wrap_cast_if_needed(&default, &return_type))),
+                    return_type: Box::new(return_type),
+                }))
+            }
+            _ => {
+                if args.len() != 1 {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "window function {func_name} requires 1 argument, but got {}",
+                        args.len()
+                    ))
+                    .set_span(span));
+                }
+                let box (arg, arg_type) = self.resolve(args[0], None).await?;
+                Ok(WindowFuncType::NthValue(NthValueFunction {
+                    n: if func_name == "first_value" {
+                        Some(1)
+                    } else {
+                        None
+                    },
+                    arg: Box::new(arg),
+                    return_type: Box::new(arg_type.wrap_nullable()),
+                }))
+            }
+        }
+    }
+
+    fn resolve_window_frame(
+        &self,
+        span: Span,
+        has_order_by: bool,
+        frame: &Option<WindowFrame>,
+    ) -> Result<WindowFuncFrame> {
+        let frame = match frame {
+            Some(frame) => frame,
+            None => {
+                // Without `ORDER BY`, all rows of the partition are peers of the current row,
+                // so the default frame covers the whole partition.
+                return Ok(if has_order_by {
+                    WindowFuncFrame {
+                        units: WindowFuncFrameUnits::Range,
+                        start_bound: WindowFuncFrameBound::Preceding(None),
+                        end_bound: WindowFuncFrameBound::CurrentRow,
+                    }
+                } else {
+                    WindowFuncFrame {
+                        units: WindowFuncFrameUnits::Rows,
+                        start_bound: WindowFuncFrameBound::Preceding(None),
+                        end_bound: WindowFuncFrameBound::Following(None),
+                    }
+                });
+            }
+        };
+
+        let units = match frame.units {
+            WindowFrameUnits::Rows => WindowFuncFrameUnits::Rows,
+            WindowFrameUnits::Range => WindowFuncFrameUnits::Range,
+        };
+        let start_bound = self.resolve_window_frame_bound(span, &units, &frame.start_bound)?;
+        let end_bound = self.resolve_window_frame_bound(span, &units, &frame.end_bound)?;
+
+        if matches!(start_bound, WindowFuncFrameBound::Following(None)) {
+            return Err(ErrorCode::SemanticError(
+                "frame start cannot be UNBOUNDED FOLLOWING".to_string(),
+            )
+            .set_span(span));
+        }
+        if matches!(end_bound, WindowFuncFrameBound::Preceding(None)) {
+            return Err(ErrorCode::SemanticError(
+                "frame end cannot be UNBOUNDED PRECEDING".to_string(),
+            )
+            .set_span(span));
+        }
+
+        Ok(WindowFuncFrame {
+            units,
+            start_bound,
+            end_bound,
+        })
+    }
+
+    fn resolve_window_frame_bound(
+        &self,
+        span: Span,
+        units: &WindowFuncFrameUnits,
+        bound: &WindowFrameBound,
+    ) -> Result<WindowFuncFrameBound> {
+        let offset = |expr: &Option<Box<Expr>>| -> Result<Option<u64>> {
+            match expr {
+                None => Ok(None),
+                Some(box Expr::Literal {
+                    lit: Literal::Integer(n),
+                    ..
+                }) => {
+                    if *units == WindowFuncFrameUnits::Range {
+                        return Err(ErrorCode::Unimplemented(
+                            "RANGE frame with offset PRECEDING/FOLLOWING is not supported yet",
+                        )
+                        .set_span(span));
+                    }
+                    Ok(Some(*n))
+                }
+                Some(expr) => Err(ErrorCode::SemanticError(format!(
+                    "frame offset must be a non-negative integer literal, but got {expr}"
+                ))
+                .set_span(expr.span())),
+            }
+        };
+
+        Ok(match bound {
+            WindowFrameBound::CurrentRow => WindowFuncFrameBound::CurrentRow,
+            WindowFrameBound::Preceding(expr) => WindowFuncFrameBound::Preceding(offset(expr)?),
+            WindowFrameBound::Following(expr) => WindowFuncFrameBound::Following(offset(expr)?),
+        })
+    }
+
     /// Resolve function call.
     #[async_recursion::async_recursion]
     pub async fn resolve_function(
@@ -1446,6 +1857,7 @@ impl<'a> TypeChecker<'a> {
             "is_null",
             "coalesce",
             "last_query_id",
+            "grouping",
         ]
     }
 
@@ -1590,6 +2002,8 @@ impl<'a> TypeChecker<'a> {
                             },
                             args: vec![arg_x.clone()],
                             params: vec![],
+                            order_by: vec![],
+                            window: None,
                         },
                         None,
                     )
@@ -1627,6 +2041,8 @@ impl<'a> TypeChecker<'a> {
                         },
                         args: vec![(*arg).clone()],
                         params: vec![],
+                        order_by: vec![],
+                        window: None,
                     };
 
                     new_args.push(is_not_null_expr);
@@ -1697,10 +2113,45 @@ impl<'a> TypeChecker<'a> {
                     .await,
                 )
             }
+            ("grouping", args) => Some(self.resolve_grouping(span, args).await),
             _ => None,
         }
     }
 
+    /// Resolve `GROUPING(expr, ...)`. The arguments will be replaced with the grouping id
+    /// by `GroupingChecker` once the group items are resolved.
+    #[async_recursion::async_recursion]
+    async fn resolve_grouping(
+        &mut self,
+        span: Span,
+        args: &[&Expr],
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        if args.is_empty() || args.len() > 32 {
+            return Err(ErrorCode::SemanticError(
+                "grouping requires 1 to 32 arguments".to_string(),
+            )
+            .set_span(span));
+        }
+
+        let mut arguments = Vec::with_capacity(args.len());
+        for arg in args {
+            let box (arg, _) = self.resolve(arg, None).await?;
+            arguments.push(arg);
+        }
+
+        let data_type = DataType::Number(NumberDataType::UInt32);
+        Ok(Box::new((
+            FunctionCall {
+                params: vec![],
+                arguments,
+                func_name: "grouping".to_string(),
+                return_type: Box::new(data_type.clone()),
+            }
+            .into(),
+            data_type,
+        )))
+    }
+
     #[async_recursion::async_recursion]
     async fn resolve_trim_function(
         &mut self,
@@ -1832,6 +2283,121 @@ impl<'a> TypeChecker<'a> {
             .await
     }
 
+    /// Resolve a higher-order function like `array_transform(arr, x -> x + 1)`.
+    ///
+    /// The lambda body is resolved in a standalone context that only contains the
+    /// parameters, whose column indexes are their positions in the parameter list.
+    #[async_recursion::async_recursion]
+    async fn resolve_lambda_function(
+        &mut self,
+        span: Span,
+        func_name: &str,
+        args: &[&Expr],
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        let (arg, lambda, params, body) = match args {
+            [arg, lambda @ Expr::Lambda { params, expr, .. }] => (*arg, *lambda, params, expr),
+            _ => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "function {func_name} expects an array and a lambda, like `{func_name}(arr, x -> ...)`"
+                ))
+                .set_span(span));
+            }
+        };
+        let num_params = if func_name == "array_reduce" { 2 } else { 1 };
+        if params.len() != num_params {
+            return Err(ErrorCode::SemanticError(format!(
+                "lambda of function {func_name} expects {num_params} parameter(s), but got {}",
+                params.len()
+            ))
+            .set_span(lambda.span()));
+        }
+
+        let box (arg, arg_type) = self.resolve(arg, None).await?;
+        let elem_type = match arg_type.remove_nullable() {
+            DataType::Array(box elem_type) => elem_type,
+            _ => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "function {func_name} expects an array with known element type, but got {arg_type}"
+                ))
+                .set_span(span));
+            }
+        };
+
+        let mut lambda_context = BindContext::new();
+        for (index, param) in params.iter().enumerate() {
+            lambda_context.add_column_binding(ColumnBinding {
+                database_name: None,
+                table_name: None,
+                column_name: normalize_identifier(param, self.name_resolution_ctx).name,
+                index,
+                data_type: Box::new(elem_type.clone()),
+                visibility: Visibility::Visible,
+            });
+        }
+        let mut lambda_checker = TypeChecker::new(
+            &lambda_context,
+            self.ctx.clone(),
+            self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+        let box (mut body, body_type) = lambda_checker.resolve(body, None).await?;
+
+        let return_type = match func_name {
+            "array_transform" => DataType::Array(Box::new(body_type.clone())),
+            "array_reduce" => {
+                body = wrap_cast_if_needed(&body, &elem_type);
+                elem_type.wrap_nullable()
+            }
+            _ => {
+                if !matches!(
+                    body_type.remove_nullable(),
+                    DataType::Boolean | DataType::Null
+                ) {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "lambda of function {func_name} must return a boolean value, but got {body_type}"
+                    ))
+                    .set_span(lambda.span()));
+                }
+                if func_name == "array_filter" {
+                    DataType::Array(Box::new(elem_type))
+                } else {
+                    DataType::Boolean
+                }
+            }
+        };
+        let return_type = if arg_type.is_nullable() {
+            return_type.wrap_nullable()
+        } else {
+            return_type
+        };
+
+        let lambda_expr = body.as_expr_with_col_index()?;
+        if lambda_expr
+            .column_refs()
+            .keys()
+            .any(|index| *index >= num_params)
+        {
+            return Err(ErrorCode::SemanticError(
+                "lambda body can only refer to its parameters, aggregate functions and subqueries are not allowed"
+                    .to_string(),
+            )
+            .set_span(lambda.span()));
+        }
+
+        Ok(Box::new((
+            LambdaFunc {
+                func_name: func_name.to_string(),
+                args: vec![arg],
+                lambda_expr: Box::new(lambda_expr.as_remote_expr()),
+                lambda_display: lambda.to_string(),
+                return_type: Box::new(return_type.clone()),
+            }
+            .into(),
+            return_type,
+        )))
+    }
+
     #[async_recursion::async_recursion]
     async fn resolve_tuple(
         &mut self,
@@ -2274,6 +2840,8 @@ impl<'a> TypeChecker<'a> {
                     name,
                     args,
                     params,
+                    order_by,
+                    window,
                 } => Ok(Expr::FunctionCall {
                     span: *span,
                     distinct: *distinct,
@@ -2283,6 +2851,18 @@ impl<'a> TypeChecker<'a> {
                         .map(|arg| self.clone_expr_with_replacement(arg, replacement_fn))
                         .collect::<Result<Vec<Expr>>>()?,
                     params: params.clone(),
+                    order_by: order_by
+                        .iter()
+                        .map(|order_by| {
+                            Ok(OrderByExpr {
+                                expr: self
+                                    .clone_expr_with_replacement(&order_by.expr, replacement_fn)?,
+                                asc: order_by.asc,
+                                nulls_first: order_by.nulls_first,
+                            })
+                        })
+                        .collect::<Result<Vec<OrderByExpr>>>()?,
+                    window: window.clone(),
                 }),
                 Expr::Case {
                     span,
@@ -2428,6 +3008,7 @@ impl<'a> TypeChecker<'a> {
                 TableDataType::Nullable(Box::new(Self::resolve_type_name(inner_type)?))
             }
             TypeName::Variant => TableDataType::Variant,
+            TypeName::Bitmap => TableDataType::Bitmap,
             name => {
                 return Err(ErrorCode::Internal(format!(
                     "Invalid type name \'{:?}\'",
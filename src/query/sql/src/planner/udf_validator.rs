@@ -17,6 +17,8 @@ use std::collections::HashSet;
 use common_ast::ast::Expr;
 use common_ast::ast::Identifier;
 use common_ast::ast::Literal;
+use common_ast::ast::OrderByExpr;
+use common_ast::ast::Window;
 use common_ast::walk_expr;
 use common_ast::Visitor;
 use common_exception::ErrorCode;
@@ -86,6 +88,8 @@ impl<'ast> Visitor<'ast> for UDFValidator {
         name: &'ast Identifier,
         args: &'ast [Expr],
         _params: &'ast [Literal],
+        order_by: &'ast [OrderByExpr],
+        _window: &'ast Option<Window>,
     ) {
         let name = name.to_string();
         if !is_builtin_function(&name) && self.name.eq_ignore_ascii_case(&name) {
@@ -96,5 +100,8 @@ impl<'ast> Visitor<'ast> for UDFValidator {
         for arg in args {
             walk_expr(self, arg);
         }
+        for order_by in order_by {
+            walk_expr(self, &order_by.expr);
+        }
     }
 }
@@ -0,0 +1,98 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::Literal;
+use common_sql::optimizer::ColumnStat;
+use common_sql::optimizer::Datum;
+use common_sql::optimizer::Histogram;
+use common_sql::optimizer::HistogramBucket;
+use common_sql::optimizer::SelectivityEstimator;
+use common_sql::optimizer::Statistics;
+use common_sql::plans::BoundColumnRef;
+use common_sql::plans::ComparisonExpr;
+use common_sql::plans::ComparisonOp;
+use common_sql::plans::ConstantExpr;
+use common_sql::plans::ScalarExpr;
+use common_sql::ColumnBinding;
+use common_sql::Visibility;
+
+fn comparison(op: ComparisonOp, value: u64) -> ScalarExpr {
+    let data_type = Box::new(DataType::Number(NumberDataType::UInt64));
+    ScalarExpr::ComparisonExpr(ComparisonExpr {
+        op,
+        left: Box::new(ScalarExpr::BoundColumnRef(BoundColumnRef {
+            column: ColumnBinding {
+                database_name: None,
+                table_name: None,
+                column_name: "a".to_string(),
+                index: 0,
+                data_type: data_type.clone(),
+                visibility: Visibility::Visible,
+            },
+        })),
+        right: Box::new(ScalarExpr::ConstantExpr(ConstantExpr {
+            value: Literal::UInt64(value),
+            data_type,
+        })),
+        return_type: Box::new(DataType::Boolean),
+    })
+}
+
+#[test]
+fn test_selectivity_with_collected_histogram() {
+    // 21 values in [0, 20], 5 of them are 15.
+    let histogram = Histogram::new_collected(
+        vec![
+            HistogramBucket::new(Datum::UInt(0), 1.0, 1.0),
+            HistogramBucket::new(Datum::UInt(10), 10.0, 10.0),
+            HistogramBucket::new(Datum::UInt(20), 10.0, 6.0),
+        ],
+        vec![(Datum::UInt(15), 5.0)],
+    );
+    let mut column_stats = HashMap::new();
+    column_stats.insert(0, ColumnStat {
+        min: Datum::UInt(0),
+        max: Datum::UInt(20),
+        ndv: 17.0,
+        null_count: 0,
+        histogram: Some(histogram),
+    });
+    let statistics = Statistics {
+        precise_cardinality: Some(21),
+        column_stats,
+        is_accurate: false,
+    };
+    let estimator = SelectivityEstimator::new(&statistics);
+
+    // Most common value.
+    let s = estimator.compute_selectivity(&comparison(ComparisonOp::Equal, 15));
+    assert_eq!(s, 5.0 / 21.0);
+    // Other values share the rows of their bucket.
+    let s = estimator.compute_selectivity(&comparison(ComparisonOp::Equal, 5));
+    assert_eq!(s, 1.0 / 21.0);
+    // Out of range.
+    let s = estimator.compute_selectivity(&comparison(ComparisonOp::Equal, 25));
+    assert_eq!(s, 0.0);
+    // Interpolate inside the bucket (10, 20].
+    let s = estimator.compute_selectivity(&comparison(ComparisonOp::LT, 15));
+    assert_eq!(s, 16.0 / 21.0);
+    let s = estimator.compute_selectivity(&comparison(ComparisonOp::LTE, 10));
+    assert_eq!(s, 11.0 / 21.0);
+    let s = estimator.compute_selectivity(&comparison(ComparisonOp::GT, 20));
+    assert_eq!(s, 0.0);
+}
@@ -20,6 +20,7 @@ mod current;
 mod v0;
 mod v1;
 mod v2;
+mod v3;
 mod versions;
 
 pub use compression::Compression;
@@ -15,6 +15,7 @@
 use std::collections::HashMap;
 
 use common_expression::ColumnId;
+use common_expression::Scalar;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -31,14 +32,38 @@ pub struct TableSnapshotStatistics {
     pub snapshot_id: SnapshotId,
 
     pub column_distinct_values: HashMap<ColumnId, u64>,
+
+    /// histograms of the sampled columns, absent in statistics written before they were collected
+    #[serde(default)]
+    pub column_histograms: HashMap<ColumnId, ColumnHistogram>,
+}
+
+/// Equi-depth histogram of a column, built by `ANALYZE TABLE` from sampled blocks.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ColumnHistogram {
+    /// Buckets ordered by upper bound, the first bucket only holds the min value.
+    pub buckets: Vec<HistogramBucket>,
+    /// Most common values and their estimated number of rows, in descending order of frequency.
+    pub most_common_values: Vec<(Scalar, f64)>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct HistogramBucket {
+    pub upper_bound: Scalar,
+    pub num_values: f64,
+    pub num_distinct: f64,
 }
 
 impl TableSnapshotStatistics {
-    pub fn new(column_distinct_values: HashMap<ColumnId, u64>) -> Self {
+    pub fn new(
+        column_distinct_values: HashMap<ColumnId, u64>,
+        column_histograms: HashMap<ColumnId, ColumnHistogram>,
+    ) -> Self {
         Self {
             format_version: TableSnapshotStatistics::VERSION,
             snapshot_id: SnapshotId::new_v4(),
             column_distinct_values,
+            column_histograms,
         }
     }
 
@@ -49,4 +74,8 @@ impl TableSnapshotStatistics {
     pub fn get_column_distinct_values(&self) -> &HashMap<ColumnId, u64> {
         &self.column_distinct_values
     }
+
+    pub fn get_column_histograms(&self) -> &HashMap<ColumnId, ColumnHistogram> {
+        &self.column_histograms
+    }
 }
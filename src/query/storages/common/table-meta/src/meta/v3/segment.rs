@@ -0,0 +1,206 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_expression::ColumnId;
+use common_expression::TableField;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::meta::statistics::ClusterStatistics;
+use crate::meta::statistics::ColumnStatistics;
+use crate::meta::statistics::FormatVersion;
+use crate::meta::v2::ColumnMeta;
+use crate::meta::Compression;
+use crate::meta::Location;
+use crate::meta::Statistics;
+use crate::meta::Versioned;
+
+/// A segment comprises one or more blocks
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct SegmentInfo {
+    /// format version
+    format_version: FormatVersion,
+    /// blocks belong to this segment
+    pub blocks: Vec<Arc<BlockMeta>>,
+    /// summary statistics
+    pub summary: Statistics,
+}
+
+/// Meta information of a block
+/// Part of and kept inside the [SegmentInfo]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct BlockMeta {
+    /// number of rows physically stored in the data block,
+    /// including the rows marked as deleted by the deletion vector
+    pub row_count: u64,
+    pub block_size: u64,
+    pub file_size: u64,
+    pub col_stats: HashMap<ColumnId, ColumnStatistics>,
+    pub col_metas: HashMap<ColumnId, ColumnMeta>,
+    pub cluster_stats: Option<ClusterStatistics>,
+    /// location of data block
+    pub location: Location,
+    /// location of bloom filter index
+    pub bloom_filter_index_location: Option<Location>,
+
+    #[serde(default)]
+    pub bloom_filter_index_size: u64,
+    pub compression: Compression,
+
+    /// rows of the data block that have been deleted, but not yet compacted away
+    #[serde(default)]
+    pub deletion_vector: Option<DeletionVector>,
+
+    /// locations of the data of the aggregating indexes, keyed by the id of the index
+    #[serde(default)]
+    pub agg_index_locations: BTreeMap<String, Location>,
+}
+
+/// A bitmap of the deleted rows of a block, stored alongside the data block.
+///
+/// Bit `i` of the bitmap is set if the `i`-th row of the block has been deleted.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DeletionVector {
+    /// location of the bitmap file
+    pub location: Location,
+    /// size of the bitmap file in bytes
+    pub size: u64,
+    /// number of the rows marked as deleted
+    pub deleted_rows: u64,
+}
+
+impl BlockMeta {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        row_count: u64,
+        block_size: u64,
+        file_size: u64,
+        col_stats: HashMap<ColumnId, ColumnStatistics>,
+        col_metas: HashMap<ColumnId, ColumnMeta>,
+        cluster_stats: Option<ClusterStatistics>,
+        location: Location,
+        bloom_filter_index_location: Option<Location>,
+        bloom_filter_index_size: u64,
+        compression: Compression,
+    ) -> Self {
+        Self {
+            row_count,
+            block_size,
+            file_size,
+            col_stats,
+            col_metas,
+            cluster_stats,
+            location,
+            bloom_filter_index_location,
+            bloom_filter_index_size,
+            compression,
+            deletion_vector: None,
+            agg_index_locations: BTreeMap::new(),
+        }
+    }
+
+    pub fn with_agg_index_locations(mut self, locations: BTreeMap<String, Location>) -> Self {
+        self.agg_index_locations = locations;
+        self
+    }
+
+    pub fn compression(&self) -> Compression {
+        self.compression
+    }
+
+    /// Number of the rows that are visible, i.e. not marked as deleted.
+    pub fn live_row_count(&self) -> u64 {
+        match &self.deletion_vector {
+            None => self.row_count,
+            Some(v) => self.row_count - v.deleted_rows,
+        }
+    }
+
+    /// Location of the data of the aggregating index, which is computed from all the rows of
+    /// the block, thus can't be used once some of the rows are deleted.
+    pub fn agg_index_location(&self, index_id: &str) -> Option<&Location> {
+        match &self.deletion_vector {
+            None => self.agg_index_locations.get(index_id),
+            Some(_) => None,
+        }
+    }
+}
+
+impl SegmentInfo {
+    pub fn new(blocks: Vec<Arc<BlockMeta>>, summary: Statistics) -> Self {
+        Self {
+            format_version: SegmentInfo::VERSION,
+            blocks,
+            summary,
+        }
+    }
+
+    pub fn format_version(&self) -> u64 {
+        self.format_version
+    }
+
+    // Total block bytes of this segment.
+    pub fn total_bytes(&self) -> u64 {
+        self.blocks.iter().map(|v| v.block_size).sum()
+    }
+}
+
+use super::super::v0;
+use super::super::v1;
+use super::super::v2;
+
+impl SegmentInfo {
+    pub fn from_v0(s: v0::SegmentInfo, fields: &[TableField]) -> Self {
+        Self::from_v2(v2::SegmentInfo::from_v0(s, fields))
+    }
+
+    pub fn from_v1(s: v1::SegmentInfo, fields: &[TableField]) -> Self {
+        Self::from_v2(v2::SegmentInfo::from_v1(s, fields))
+    }
+
+    pub fn from_v2(s: v2::SegmentInfo) -> Self {
+        Self {
+            format_version: SegmentInfo::VERSION,
+            blocks: s
+                .blocks
+                .into_iter()
+                .map(|b| Arc::new(BlockMeta::from_v2(b.as_ref())))
+                .collect::<_>(),
+            summary: s.summary,
+        }
+    }
+}
+
+impl BlockMeta {
+    pub fn from_v2(s: &v2::BlockMeta) -> Self {
+        Self {
+            row_count: s.row_count,
+            block_size: s.block_size,
+            file_size: s.file_size,
+            col_stats: s.col_stats.clone(),
+            col_metas: s.col_metas.clone(),
+            cluster_stats: s.cluster_stats.clone(),
+            location: s.location.clone(),
+            bloom_filter_index_location: s.bloom_filter_index_location.clone(),
+            bloom_filter_index_size: s.bloom_filter_index_size,
+            compression: s.compression,
+            deletion_vector: None,
+            agg_index_locations: BTreeMap::new(),
+        }
+    }
+}
@@ -18,6 +18,7 @@ use common_exception::ErrorCode;
 use common_expression::DataBlock;
 
 use super::v2;
+use super::v3;
 use crate::meta::v0;
 use crate::meta::v1;
 
@@ -43,11 +44,13 @@ where Self: Sized
 impl Versioned<0> for v0::SegmentInfo {}
 impl Versioned<1> for v1::SegmentInfo {}
 impl Versioned<2> for v2::SegmentInfo {}
+impl Versioned<3> for v3::SegmentInfo {}
 
 pub enum SegmentInfoVersion {
     V0(PhantomData<v0::SegmentInfo>),
     V1(PhantomData<v1::SegmentInfo>),
     V2(PhantomData<v2::SegmentInfo>),
+    V3(PhantomData<v3::SegmentInfo>),
 }
 
 impl Versioned<0> for v0::TableSnapshot {}
@@ -78,6 +81,8 @@ impl Versioned<0> for v1::TableSnapshotStatistics {}
 
 impl Versioned<2> for DataBlock {}
 
+impl Versioned<0> for v3::DeletionVector {}
+
 pub enum TableSnapshotStatisticsVersion {
     V0(PhantomData<v1::TableSnapshotStatistics>),
 }
@@ -116,8 +121,9 @@ mod converters {
                 )),
                 1 => Ok(SegmentInfoVersion::V1(testify_version::<_, 1>(PhantomData))),
                 2 => Ok(SegmentInfoVersion::V2(testify_version::<_, 2>(PhantomData))),
+                3 => Ok(SegmentInfoVersion::V3(testify_version::<_, 3>(PhantomData))),
                 _ => Err(ErrorCode::Internal(format!(
-                    "unknown segment version {value}, versions supported: 0, 1"
+                    "unknown segment version {value}, versions supported: 0, 1, 2, 3"
                 ))),
             }
         }
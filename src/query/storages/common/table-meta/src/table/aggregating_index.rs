@@ -0,0 +1,119 @@
+//  Copyright 2023 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::table::OPT_KEY_AGGREGATING_INDEXES;
+
+/// An aggregate function pre-computed by an aggregating index.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AggregatingIndexFunc {
+    pub func_name: String,
+    /// the argument of the function, `None` for `count(*)`.
+    pub arg: Option<TableField>,
+    /// the type of the result of the function.
+    pub data_type: TableDataType,
+}
+
+/// Definition of an aggregating index of a fuse table.
+///
+/// An aggregating index stores the result of `SELECT <group_by>, <aggregates> FROM t GROUP BY
+/// <group_by>` of each block of the table, next to the block. The definitions are kept in the
+/// table option `aggregating_indexes`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AggregatingIndex {
+    /// unique id of the index, the index files are stored under it, so an index re-created
+    /// with the same name never reads the files of the dropped one.
+    pub id: String,
+    pub name: String,
+    /// the query the index is created with, only for display.
+    pub query: String,
+    pub group_by: Vec<TableField>,
+    pub aggregates: Vec<AggregatingIndexFunc>,
+}
+
+impl AggregatingIndex {
+    /// version of the format of the index data files
+    pub const VERSION: u64 = 0;
+
+    pub fn from_table_options(options: &BTreeMap<String, String>) -> Result<Vec<Self>> {
+        match options.get(OPT_KEY_AGGREGATING_INDEXES) {
+            Some(value) => serde_json::from_str(value).map_err(|e| {
+                ErrorCode::Internal(format!("Invalid aggregating indexes {}: {}", value, e))
+            }),
+            None => Ok(vec![]),
+        }
+    }
+
+    pub fn to_table_option(indexes: &[Self]) -> Result<String> {
+        serde_json::to_string(indexes).map_err(|e| {
+            ErrorCode::Internal(format!("Cannot serialize aggregating indexes: {}", e))
+        })
+    }
+
+    /// Schema of the index data of a block: the group by columns, followed by the result of
+    /// each aggregate function, named `agg$<n>`.
+    pub fn schema(&self) -> TableSchemaRef {
+        let keys = self
+            .group_by
+            .iter()
+            .map(|field| TableField::new(field.name(), field.data_type().clone()));
+        let results = self
+            .aggregates
+            .iter()
+            .enumerate()
+            .map(|(i, func)| TableField::new(&format!("agg${}", i), func.data_type.clone()));
+        let fields = keys.chain(results).collect();
+        Arc::new(TableSchema::new(fields))
+    }
+
+    /// The columns of the table the index is computed from, with their column ids.
+    pub fn source_schema(&self) -> TableSchemaRef {
+        let mut fields: Vec<TableField> = self
+            .group_by
+            .iter()
+            .chain(self.aggregates.iter().filter_map(|func| func.arg.as_ref()))
+            .cloned()
+            .collect();
+        fields.sort_by_key(|field| field.column_id());
+        fields.dedup_by_key(|field| field.column_id());
+        let next_column_id = fields.last().map_or(1, |field| field.column_id() + 1);
+        Arc::new(TableSchema::new_from_column_ids(
+            fields,
+            BTreeMap::new(),
+            next_column_id,
+        ))
+    }
+
+    /// An index can't be used anymore once a column of it is dropped, or its data type is
+    /// modified.
+    pub fn is_valid_for(&self, table_schema: &TableSchema) -> bool {
+        self.source_schema().fields().iter().all(|field| {
+            table_schema.fields().iter().any(|table_field| {
+                table_field.column_id() == field.column_id()
+                    && table_field.data_type() == field.data_type()
+            })
+        })
+    }
+}
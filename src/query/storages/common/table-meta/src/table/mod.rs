@@ -12,10 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod aggregating_index;
 mod table_compression;
 mod table_keys;
 mod table_prefix;
 
+pub use aggregating_index::AggregatingIndex;
+pub use aggregating_index::AggregatingIndexFunc;
 pub use table_compression::TableCompression;
 pub use table_keys::*;
 pub use table_prefix::*;
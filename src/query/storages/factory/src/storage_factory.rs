@@ -25,6 +25,8 @@ use common_storages_random::RandomTable;
 use common_storages_view::view_table::ViewTable;
 use dashmap::DashMap;
 
+use crate::fuse::aggregating_index::AggIndexTable;
+use crate::fuse::stream::StreamTable;
 use crate::fuse::FuseTable;
 use crate::Table;
 
@@ -96,6 +98,18 @@ impl StorageFactory {
             descriptor: Arc::new(ViewTable::description),
         });
 
+        // Register STREAM table engine
+        creators.insert("STREAM".to_string(), Storage {
+            creator: Arc::new(StreamTable::try_create),
+            descriptor: Arc::new(StreamTable::description),
+        });
+
+        // Register AGGREGATING_INDEX table engine
+        creators.insert("AGGREGATING_INDEX".to_string(), Storage {
+            creator: Arc::new(AggIndexTable::try_create),
+            descriptor: Arc::new(AggIndexTable::description),
+        });
+
         // Register RANDOM table engine
         creators.insert("RANDOM".to_string(), Storage {
             creator: Arc::new(RandomTable::try_create),
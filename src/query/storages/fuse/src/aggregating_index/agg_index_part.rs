@@ -0,0 +1,67 @@
+//  Copyright 2023 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::any::Any;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::Arc;
+
+use common_catalog::plan::PartInfo;
+use common_catalog::plan::PartInfoPtr;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use storages_common_table_meta::meta::BlockMeta;
+
+/// A block of the table, read through its aggregating index data if there is one, otherwise
+/// the index data is computed from the rows of the block.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+pub struct AggIndexPartInfo {
+    pub block_meta: BlockMeta,
+}
+
+#[typetag::serde(name = "agg_index")]
+impl PartInfo for AggIndexPartInfo {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, info: &Box<dyn PartInfo>) -> bool {
+        match info.as_any().downcast_ref::<AggIndexPartInfo>() {
+            None => false,
+            Some(other) => self == other,
+        }
+    }
+
+    fn hash(&self) -> u64 {
+        let mut s = DefaultHasher::new();
+        self.block_meta.location.0.hash(&mut s);
+        s.finish()
+    }
+}
+
+impl AggIndexPartInfo {
+    pub fn create(block_meta: BlockMeta) -> PartInfoPtr {
+        Arc::new(Box::new(AggIndexPartInfo { block_meta }))
+    }
+
+    pub fn from_part(info: &PartInfoPtr) -> Result<&AggIndexPartInfo> {
+        match info.as_any().downcast_ref::<AggIndexPartInfo>() {
+            Some(part_ref) => Ok(part_ref),
+            None => Err(ErrorCode::Internal(
+                "Cannot downcast from PartInfo to AggIndexPartInfo.",
+            )),
+        }
+    }
+}
@@ -0,0 +1,147 @@
+//  Copyright 2023 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::io::Cursor;
+use std::sync::Arc;
+
+use common_arrow::arrow::io::parquet::read::{self as pread};
+use common_arrow::parquet::read::read_metadata;
+use common_catalog::plan::Projection;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_expression::TableSchemaRef;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sources::AsyncSource;
+use common_pipeline_sources::AsyncSourcer;
+use opendal::Operator;
+use storages_common_table_meta::table::AggregatingIndex;
+
+use super::build_agg_index_block;
+use super::AggIndexPartInfo;
+use crate::io::filter_deleted_rows;
+use crate::io::read_deletion_vector;
+use crate::io::BlockReader;
+use crate::io::ReadSettings;
+use crate::FuseStorageFormat;
+use crate::FuseTable;
+
+/// Reads the data of an aggregating index, the index data of a block without it is computed
+/// from the rows of the block which are not deleted.
+pub struct AggIndexSource {
+    ctx: Arc<dyn TableContext>,
+    index: AggregatingIndex,
+    index_schema: TableSchemaRef,
+    source_schema: TableSchemaRef,
+    operator: Operator,
+    storage_format: FuseStorageFormat,
+    block_reader: Arc<BlockReader>,
+    /// offsets of the columns of the index to output, all of them if `None`.
+    projection: Option<Vec<usize>>,
+}
+
+impl AggIndexSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        index: AggregatingIndex,
+        fuse_table: &FuseTable,
+        projection: Option<Vec<usize>>,
+    ) -> Result<ProcessorPtr> {
+        let source_schema = index.source_schema();
+        let projection = Projection::Columns((0..source_schema.fields().len()).collect());
+        let block_reader = BlockReader::create(
+            fuse_table.get_operator(),
+            source_schema.clone(),
+            projection,
+            ctx.clone(),
+        )?;
+        AsyncSourcer::create(ctx.clone(), output, AggIndexSource {
+            ctx,
+            index_schema: index.schema(),
+            index,
+            source_schema,
+            operator: fuse_table.get_operator(),
+            storage_format: fuse_table.storage_format,
+            block_reader,
+            projection,
+        })
+    }
+
+    async fn read_index_data(&self, location: &str) -> Result<DataBlock> {
+        let data = self.operator.object(location).read().await?;
+        let mut reader = Cursor::new(data);
+        let meta = read_metadata(&mut reader)?;
+        let schema = DataSchema::from(self.index_schema.clone());
+
+        let chunks = pread::FileReader::new(
+            reader,
+            meta.row_groups,
+            self.index_schema.to_arrow(),
+            None,
+            None,
+            None,
+        );
+        let blocks = chunks
+            .map(|chunk| DataBlock::from_arrow_chunk(&chunk?, &schema))
+            .collect::<Result<Vec<_>>>()?;
+        if blocks.is_empty() {
+            return Ok(DataBlock::empty_with_schema(Arc::new(schema)));
+        }
+        DataBlock::concat(&blocks)
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for AggIndexSource {
+    const NAME: &'static str = "AggIndexSource";
+
+    #[async_trait::unboxed_simple]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        let part = match self.ctx.get_partition() {
+            Some(part) => part,
+            None => return Ok(None),
+        };
+
+        let block_meta = &AggIndexPartInfo::from_part(&part)?.block_meta;
+        let block = match block_meta.agg_index_location(&self.index.id) {
+            Some(location) => self.read_index_data(&location.0).await?,
+            None => {
+                let settings = ReadSettings::from_ctx(&self.ctx)?;
+                let block = self
+                    .block_reader
+                    .read_by_meta(&settings, block_meta, &self.storage_format)
+                    .await?;
+                let deleted =
+                    read_deletion_vector(&self.operator, block_meta.deletion_vector.as_ref())
+                        .await?;
+                let block = filter_deleted_rows(block, deleted.as_ref())?;
+                build_agg_index_block(&self.index, &self.source_schema, &block)?
+            }
+        };
+        let block = match &self.projection {
+            Some(projection) => DataBlock::new(
+                projection
+                    .iter()
+                    .map(|offset| block.get_by_offset(*offset).clone())
+                    .collect(),
+                block.num_rows(),
+            ),
+            None => block,
+        };
+        Ok(Some(block))
+    }
+}
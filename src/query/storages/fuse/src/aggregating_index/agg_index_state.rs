@@ -0,0 +1,167 @@
+//  Copyright 2023 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::ScalarRef;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_functions::aggregates::eval_aggr_by_groups;
+use common_functions::aggregates::AggregateFunctionFactory;
+use common_io::constants::DEFAULT_BLOCK_INDEX_BUFFER_SIZE;
+use opendal::Operator;
+use storages_common_blocks::blocks_to_parquet;
+use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::table::AggregatingIndex;
+use storages_common_table_meta::table::TableCompression;
+use uuid::Uuid;
+
+use crate::io;
+use crate::io::TableMetaLocationGenerator;
+
+/// Compute the data of the aggregating index from the rows of a block, `schema` is the schema of
+/// the block, the columns of the index are looked up by their column ids.
+pub fn build_agg_index_block(
+    index: &AggregatingIndex,
+    schema: &TableSchema,
+    block: &DataBlock,
+) -> Result<DataBlock> {
+    let num_rows = block.num_rows();
+    let column_of = |field: &TableField| -> Result<Column> {
+        let offset = schema
+            .fields()
+            .iter()
+            .position(|f| f.column_id() == field.column_id())
+            .ok_or_else(|| {
+                ErrorCode::Internal(format!(
+                    "Column {} of aggregating index {} not found in block",
+                    field.name(),
+                    index.name
+                ))
+            })?;
+        let entry = block.get_by_offset(offset);
+        Ok(entry
+            .value
+            .convert_to_full_column(&entry.data_type, num_rows))
+    };
+
+    let keys = index
+        .group_by
+        .iter()
+        .map(column_of)
+        .collect::<Result<Vec<_>>>()?;
+
+    // assign an id to each group, in the order of their first rows.
+    let mut groups: HashMap<Vec<ScalarRef>, usize> = HashMap::new();
+    let mut group_ids = Vec::with_capacity(num_rows);
+    let mut first_rows: Vec<u32> = vec![];
+    for row in 0..num_rows {
+        let key = keys
+            .iter()
+            .map(|column| unsafe { column.index_unchecked(row) })
+            .collect::<Vec<_>>();
+        let next_id = groups.len();
+        let id = *groups.entry(key).or_insert_with(|| {
+            first_rows.push(row as u32);
+            next_id
+        });
+        group_ids.push(id);
+    }
+
+    let mut columns = keys
+        .iter()
+        .map(|key| key.take(&first_rows))
+        .collect::<Vec<_>>();
+
+    let factory = AggregateFunctionFactory::instance();
+    for func in &index.aggregates {
+        let args = func.arg.iter().map(column_of).collect::<Result<Vec<_>>>()?;
+        let arg_types = args.iter().map(|arg| arg.data_type()).collect();
+        let aggr = factory.get(&func.func_name, vec![], arg_types)?;
+        columns.push(eval_aggr_by_groups(
+            &aggr,
+            &args,
+            &group_ids,
+            first_rows.len(),
+        )?);
+    }
+
+    Ok(DataBlock::new_from_columns(columns))
+}
+
+/// The serialized data of an aggregating index of a block.
+pub struct AggIndexState {
+    pub(crate) index_id: String,
+    pub(crate) data: Vec<u8>,
+    pub(crate) location: Location,
+}
+
+impl AggIndexState {
+    pub fn try_create(
+        index: &AggregatingIndex,
+        source_schema: &TableSchema,
+        block: &DataBlock,
+        location: Location,
+    ) -> Result<Self> {
+        let index_block = build_agg_index_block(index, source_schema, block)?;
+        let mut data = Vec::with_capacity(DEFAULT_BLOCK_INDEX_BUFFER_SIZE);
+        blocks_to_parquet(
+            index.schema(),
+            vec![index_block],
+            &mut data,
+            TableCompression::None,
+        )?;
+        Ok(Self {
+            index_id: index.id.clone(),
+            data,
+            location,
+        })
+    }
+
+    /// Build the data of all the aggregating indexes of a new block.
+    pub fn try_create_all(
+        indexes: &[AggregatingIndex],
+        source_schema: &TableSchema,
+        block: &DataBlock,
+        location_gen: &TableMetaLocationGenerator,
+        block_id: &Uuid,
+    ) -> Result<Vec<Self>> {
+        indexes
+            .iter()
+            .map(|index| {
+                let location = location_gen.block_agg_index_location(&index.id, block_id);
+                Self::try_create(index, source_schema, block, location)
+            })
+            .collect()
+    }
+
+    pub fn locations(states: &[Self]) -> BTreeMap<String, Location> {
+        states
+            .iter()
+            .map(|state| (state.index_id.clone(), state.location.clone()))
+            .collect()
+    }
+
+    pub async fn write_all(states: &[Self], data_accessor: &Operator) -> Result<()> {
+        for state in states {
+            io::write_data(&state.data, data_accessor, &state.location.0).await?;
+        }
+        Ok(())
+    }
+}
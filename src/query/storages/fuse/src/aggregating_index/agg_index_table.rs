@@ -0,0 +1,190 @@
+//  Copyright 2023 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_catalog::catalog::StorageDescription;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PartitionsShuffleKind;
+use common_catalog::plan::Projection;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::TableInfo;
+use common_pipeline_core::Pipeline;
+use storages_common_table_meta::table::AggregatingIndex;
+
+use super::agg_index_source::AggIndexSource;
+use super::AggIndexPartInfo;
+use super::AGG_INDEX_ENGINE;
+use super::OPT_KEY_AGGREGATING_INDEX_ID;
+use crate::io::SegmentsIO;
+use crate::FuseTable;
+
+/// The data of an aggregating index of a fuse table, one block of the index for each block of
+/// the table.
+pub struct AggIndexTable {
+    table_info: TableInfo,
+    index: AggregatingIndex,
+    fuse_table: Box<FuseTable>,
+}
+
+impl AggIndexTable {
+    /// Create the index table while planning a query on the fuse table.
+    pub fn create(fuse_table: &FuseTable, index: &AggregatingIndex) -> Self {
+        let mut table_info = fuse_table.get_table_info().clone();
+        table_info.desc = format!("{} (aggregating index {})", table_info.desc, index.name);
+        table_info.meta.engine = AGG_INDEX_ENGINE.to_string();
+        table_info.meta.schema = index.schema();
+        table_info
+            .meta
+            .options
+            .insert(OPT_KEY_AGGREGATING_INDEX_ID.to_string(), index.id.clone());
+
+        AggIndexTable {
+            table_info,
+            index: index.clone(),
+            fuse_table: Box::new(fuse_table.clone()),
+        }
+    }
+
+    /// Re-create the index table from the table info of the plan, to read the partitions.
+    pub fn try_create(table_info: TableInfo) -> Result<Box<dyn Table>> {
+        let index_id = table_info
+            .options()
+            .get(OPT_KEY_AGGREGATING_INDEX_ID)
+            .ok_or_else(|| {
+                ErrorCode::Internal(format!(
+                    "Need `{}` when creating AggIndexTable",
+                    OPT_KEY_AGGREGATING_INDEX_ID
+                ))
+            })?;
+        let index = AggregatingIndex::from_table_options(table_info.options())?
+            .into_iter()
+            .find(|index| &index.id == index_id)
+            .ok_or_else(|| {
+                ErrorCode::UnknownAggregatingIndex(format!(
+                    "Unknown aggregating index {} of table {}",
+                    index_id, table_info.desc
+                ))
+            })?;
+
+        // the columns of the index are enough to read the blocks.
+        let mut fuse_table_info = table_info.clone();
+        fuse_table_info.meta.schema = index.source_schema();
+        let fuse_table = FuseTable::do_create(fuse_table_info)?;
+
+        Ok(Box::new(AggIndexTable {
+            table_info,
+            index,
+            fuse_table,
+        }))
+    }
+
+    pub fn description() -> StorageDescription {
+        StorageDescription {
+            engine_name: AGG_INDEX_ENGINE.to_string(),
+            comment: "AGGREGATING INDEX STORAGE (PRE-AGGREGATED BLOCKS OF A FUSE TABLE)"
+                .to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for AggIndexTable {
+    fn is_local(&self) -> bool {
+        false
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let segment_locations = match self.fuse_table.read_table_snapshot().await? {
+            Some(snapshot) => snapshot.segments.clone(),
+            None => vec![],
+        };
+
+        let segments_io = SegmentsIO::create(
+            ctx,
+            self.fuse_table.get_operator(),
+            self.fuse_table.schema(),
+        );
+        let mut read_rows = 0;
+        let mut read_bytes = 0;
+        let mut parts = vec![];
+        for segment in segments_io.read_segments(&segment_locations).await? {
+            for block_meta in &segment?.blocks {
+                read_rows += block_meta.live_row_count() as usize;
+                if block_meta.agg_index_location(&self.index.id).is_none() {
+                    read_bytes += block_meta.block_size as usize;
+                }
+                parts.push(AggIndexPartInfo::create(block_meta.as_ref().clone()));
+            }
+        }
+
+        let statistics = PartStatistics::new_exact(read_rows, read_bytes, parts.len(), parts.len());
+        Ok((
+            statistics,
+            Partitions::create_nolazy(PartitionsShuffleKind::Mod, parts),
+        ))
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let projection = match plan.push_downs.as_ref().and_then(|p| p.projection.as_ref()) {
+            Some(Projection::Columns(indices)) => Some(indices.clone()),
+            Some(Projection::InnerColumns(_)) => {
+                return Err(ErrorCode::Internal(
+                    "Cannot read inner columns of an aggregating index",
+                ));
+            }
+            None => None,
+        };
+
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        let parallelism = plan.parts.len().min(max_threads).max(1);
+        pipeline.add_source(
+            |output| {
+                AggIndexSource::create(
+                    ctx.clone(),
+                    output,
+                    self.index.clone(),
+                    &self.fuse_table,
+                    projection.clone(),
+                )
+            },
+            parallelism,
+        )
+    }
+}
@@ -0,0 +1,35 @@
+//  Copyright 2023 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! Aggregating indexes of fuse tables.
+//!
+//! An aggregating index pre-aggregates each block of the table by the group by columns of the
+//! index, the result is written next to the block when the block is written by an insert or a
+//! compaction. A query aggregating the table by a subset of the group by columns is rewritten to
+//! re-aggregate the index data, which is computed on the fly for the blocks without it.
+
+mod agg_index_part;
+mod agg_index_source;
+mod agg_index_state;
+mod agg_index_table;
+
+pub use agg_index_part::AggIndexPartInfo;
+pub use agg_index_state::build_agg_index_block;
+pub use agg_index_state::AggIndexState;
+pub use agg_index_table::AggIndexTable;
+
+pub const AGG_INDEX_ENGINE: &str = "AGGREGATING_INDEX";
+
+/// Id of the aggregating index read by an [AggIndexTable].
+pub const OPT_KEY_AGGREGATING_INDEX_ID: &str = "aggregating_index_id";
@@ -21,9 +21,14 @@ pub const FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD: &str = "row_avg_depth_threshold"
 pub const FUSE_TBL_BLOCK_PREFIX: &str = "_b";
 pub const FUSE_TBL_BLOCK_INDEX_PREFIX: &str = "_i";
 pub const FUSE_TBL_XOR_BLOOM_INDEX_PREFIX: &str = "_i_b_v2";
+pub const FUSE_TBL_AGG_INDEX_PREFIX: &str = "_i_a";
 pub const FUSE_TBL_SEGMENT_PREFIX: &str = "_sg";
+pub const FUSE_TBL_DELETION_VECTOR_PREFIX: &str = "_dv";
 pub const FUSE_TBL_SNAPSHOT_PREFIX: &str = "_ss";
 pub const FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX: &str = "_ts";
+pub const FUSE_TBL_CLONE_REFERENCE_PREFIX: &str = "_cl";
+pub const FUSE_TBL_CLONE_OWNERS: &str = "clone_owners";
+pub const FUSE_TBL_CLONE_RETAINED: &str = "clone_retained";
 pub const FUSE_TBL_LAST_SNAPSHOT_HINT: &str = "last_snapshot_location_hint";
 
 pub const DEFAULT_BLOCK_PER_SEGMENT: usize = 1000;
@@ -28,6 +28,9 @@ use common_expression::ColumnId;
 use common_expression::Scalar;
 use storages_common_table_meta::meta::ColumnMeta;
 use storages_common_table_meta::meta::Compression;
+use storages_common_table_meta::meta::DeletionVector;
+use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::StatisticsOfColumns;
 
 #[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq)]
 pub struct FusePartInfo {
@@ -42,6 +45,13 @@ pub struct FusePartInfo {
     pub sort_min_max: Option<(Scalar, Scalar)>,
     /// page range in the file
     pub range: Option<Range<usize>>,
+
+    /// statistics of the columns read, used to prune the part by runtime filters
+    pub columns_stat: StatisticsOfColumns,
+    /// location and size of the bloom filter index of the block
+    pub bloom_index: Option<(Location, u64)>,
+    /// rows of the block marked as deleted, which should be filtered out while reading
+    pub deletion_vector: Option<DeletionVector>,
 }
 
 #[typetag::serde(name = "fuse")]
@@ -65,6 +75,7 @@ impl PartInfo for FusePartInfo {
 }
 
 impl FusePartInfo {
+    #[allow(clippy::too_many_arguments)]
     pub fn create(
         location: String,
         format_version: u64,
@@ -73,6 +84,9 @@ impl FusePartInfo {
         compression: Compression,
         sort_min_max: Option<(Scalar, Scalar)>,
         range: Option<Range<usize>>,
+        columns_stat: StatisticsOfColumns,
+        bloom_index: Option<(Location, u64)>,
+        deletion_vector: Option<DeletionVector>,
     ) -> Arc<Box<dyn PartInfo>> {
         Arc::new(Box::new(FusePartInfo {
             location,
@@ -82,6 +96,9 @@ impl FusePartInfo {
             compression,
             sort_min_max,
             range,
+            columns_stat,
+            bloom_index,
+            deletion_vector,
         }))
     }
 
@@ -21,10 +21,13 @@ use std::sync::Arc;
 
 use common_catalog::catalog::StorageDescription;
 use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::MergeIntoInfo;
+use common_catalog::plan::MergeIntoSource;
 use common_catalog::plan::PartStatistics;
 use common_catalog::plan::Partitions;
 use common_catalog::plan::PushDownInfo;
 use common_catalog::table::AppendMode;
+use common_catalog::table::ColumnHistogram;
 use common_catalog::table::ColumnStatistics;
 use common_catalog::table::ColumnStatisticsProvider;
 use common_catalog::table::CompactTarget;
@@ -38,6 +41,7 @@ use common_expression::ColumnId;
 use common_expression::DataBlock;
 use common_expression::FieldIndex;
 use common_expression::RemoteExpr;
+use common_expression::Scalar;
 use common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use common_io::constants::DEFAULT_BLOCK_MAX_ROWS;
 use common_meta_app::schema::DatabaseType;
@@ -52,12 +56,14 @@ use common_storage::StorageMetricsLayer;
 use opendal::Operator;
 use storages_common_cache::LoadParams;
 use storages_common_table_meta::meta::ClusterKey;
+use storages_common_table_meta::meta::ColumnHistogram as FuseColumnHistogram;
 use storages_common_table_meta::meta::ColumnStatistics as FuseColumnStatistics;
 use storages_common_table_meta::meta::Statistics as FuseStatistics;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::TableSnapshotStatistics;
 use storages_common_table_meta::meta::Versioned;
 use storages_common_table_meta::table::table_storage_prefix;
+use storages_common_table_meta::table::AggregatingIndex;
 use storages_common_table_meta::table::TableCompression;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
@@ -66,6 +72,7 @@ use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
 use uuid::Uuid;
 
+use crate::aggregating_index::AggIndexTable;
 use crate::io::MetaReaders;
 use crate::io::TableMetaLocationGenerator;
 use crate::io::WriteSettings;
@@ -301,6 +308,18 @@ impl FuseTable {
     pub fn cluster_key_str(&self) -> Option<&String> {
         self.cluster_key_meta.as_ref().map(|(_, key)| key)
     }
+
+    /// The aggregating indexes to be maintained for the new blocks, an index is dropped
+    /// implicitly once its columns are dropped or modified.
+    pub fn aggregating_indexes(&self) -> Result<Vec<AggregatingIndex>> {
+        let schema = self.schema();
+        Ok(
+            AggregatingIndex::from_table_options(self.table_info.options())?
+                .into_iter()
+                .filter(|index| index.is_valid_for(&schema))
+                .collect(),
+        )
+    }
 }
 
 #[async_trait::async_trait]
@@ -523,12 +542,14 @@ impl Table for FuseTable {
                     row_count: snapshot.summary.row_count,
                     // save row count first
                     column_distinct_values: Some(table_statistics.column_distinct_values.clone()),
+                    column_histograms: table_statistics.column_histograms.clone(),
                 }
             } else {
                 FuseTableColumnStatisticsProvider {
                     column_stats: stats.clone(),
                     row_count: snapshot.summary.row_count,
                     column_distinct_values: None,
+                    column_histograms: HashMap::new(),
                 }
             }
         } else {
@@ -571,6 +592,27 @@ impl Table for FuseTable {
             .await
     }
 
+    async fn merge_into(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        merge_into_info: MergeIntoInfo,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        self.do_merge_into(ctx, merge_into_info, pipeline).await
+    }
+
+    async fn replace_into(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        source: Arc<dyn MergeIntoSource>,
+        on_conflict_fields: Vec<FieldIndex>,
+        conflict_keys: Vec<Vec<Scalar>>,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        self.do_replace_into(ctx, source, on_conflict_fields, conflict_keys, pipeline)
+            .await
+    }
+
     fn get_block_compact_thresholds(&self) -> BlockThresholds {
         let max_rows_per_block =
             self.get_option(FUSE_OPT_KEY_ROW_PER_BLOCK, DEFAULT_BLOCK_MAX_ROWS);
@@ -612,6 +654,20 @@ impl Table for FuseTable {
     fn support_prewhere(&self) -> bool {
         matches!(self.storage_format, FuseStorageFormat::Native)
     }
+
+    fn aggregating_index_table(&self, index_id: &str) -> Result<Arc<dyn Table>> {
+        let index = self
+            .aggregating_indexes()?
+            .into_iter()
+            .find(|index| index.id == index_id)
+            .ok_or_else(|| {
+                ErrorCode::UnknownAggregatingIndex(format!(
+                    "Unknown aggregating index {} of table {}",
+                    index_id, self.table_info.desc
+                ))
+            })?;
+        Ok(Arc::new(AggIndexTable::create(self, &index)))
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -639,6 +695,7 @@ impl FromStr for FuseStorageFormat {
 struct FuseTableColumnStatisticsProvider {
     column_stats: HashMap<ColumnId, FuseColumnStatistics>,
     pub column_distinct_values: Option<HashMap<ColumnId, u64>>,
+    pub column_histograms: HashMap<ColumnId, FuseColumnHistogram>,
     pub row_count: u64,
 }
 
@@ -653,6 +710,17 @@ impl ColumnStatisticsProvider for FuseTableColumnStatisticsProvider {
                 .column_distinct_values
                 .as_ref()
                 .map_or(self.row_count, |map| map.get(&column_id).map_or(0, |v| *v)),
+            histogram: self
+                .column_histograms
+                .get(&column_id)
+                .map(|h| ColumnHistogram {
+                    buckets: h
+                        .buckets
+                        .iter()
+                        .map(|b| (b.upper_bound.clone(), b.num_values, b.num_distinct))
+                        .collect(),
+                    most_common_values: h.most_common_values.clone(),
+                }),
         })
     }
 }
@@ -0,0 +1,223 @@
+//  Copyright 2023 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::HashSet;
+use std::time::Duration;
+
+use common_base::base::tokio::time::sleep;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_kvapi::kvapi::KVApi;
+use common_meta_types::KVMeta;
+use common_meta_types::MatchSeq;
+use common_meta_types::Operation;
+use common_meta_types::SeqV;
+use common_meta_types::UpsertKV;
+use common_users::UserApiProvider;
+use futures::TryStreamExt;
+use opendal::ObjectMetakey;
+use opendal::ObjectMode;
+use opendal::Operator;
+use serde::Deserialize;
+use serde::Serialize;
+use tracing::warn;
+
+/// The key prefix of the locks on the clone references in the meta service.
+const CLONE_REFERENCE_LOCK_KEY_PREFIX: &str = "__fd_clone_reference_locks";
+/// The lock expires if it's not extended in time, in case the holder is gone.
+const CLONE_REFERENCE_LOCK_TTL_SECS: u64 = 300;
+const CLONE_REFERENCE_LOCK_RETRY_INTERVAL: Duration = Duration::from_millis(100);
+
+/// The segments and data files of a table that are referenced by a table cloned from it.
+///
+/// The reference is written by the cloned table into the `_cl` directory of the table that
+/// owns the files, gc of the owner never purges the referenced files. The cloned table drops
+/// the files from the reference once they are no longer used by it, and removes the reference
+/// once it is empty.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct CloneReference {
+    pub segments: HashSet<String>,
+    // blocks, bloom indexes and deletion vectors
+    pub files: HashSet<String>,
+}
+
+impl CloneReference {
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty() && self.files.is_empty()
+    }
+
+    pub fn merge(&mut self, other: CloneReference) {
+        self.segments.extend(other.segments);
+        self.files.extend(other.files);
+    }
+}
+
+pub struct CloneReferenceIO;
+
+impl CloneReferenceIO {
+    /// Read a single reference, returns an empty reference if it does not exist.
+    pub async fn read(dal: &Operator, location: &str) -> Result<CloneReference> {
+        match dal.object(location).read().await {
+            Ok(bytes) => Ok(serde_json::from_slice(&bytes)?),
+            Err(e) if e.kind() == opendal::ErrorKind::ObjectNotFound => {
+                Ok(CloneReference::default())
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub async fn write(dal: &Operator, location: &str, reference: &CloneReference) -> Result<()> {
+        let bytes = serde_json::to_vec(reference)?;
+        dal.object(location).write(bytes).await?;
+        Ok(())
+    }
+
+    /// Read all the references to the files of a table, `prefix` is the directory of the references.
+    pub async fn read_all(dal: &Operator, prefix: &str) -> Result<CloneReference> {
+        let mut references = CloneReference::default();
+        let mut ds = match dal.object(prefix).list().await {
+            Ok(ds) => ds,
+            Err(e) if e.kind() == opendal::ErrorKind::ObjectNotFound => return Ok(references),
+            Err(e) => return Err(e.into()),
+        };
+        while let Some(de) = ds.try_next().await? {
+            let meta = de.metadata(ObjectMetakey::Mode).await?;
+            if meta.mode() != ObjectMode::FILE {
+                warn!(
+                    "found not clone reference file in {:}, found: {:?}",
+                    prefix, de
+                );
+                continue;
+            }
+            let bytes = dal.object(de.path()).read().await?;
+            let reference: CloneReference = serde_json::from_slice(&bytes)?;
+            references.merge(reference);
+        }
+        Ok(references)
+    }
+
+    /// Drop the files that are no longer used by the cloned table from its reference.
+    pub async fn release(dal: &Operator, location: &str, released: &CloneReference) -> Result<()> {
+        let mut reference = Self::read(dal, location).await?;
+        reference
+            .segments
+            .retain(|loc| !released.segments.contains(loc));
+        reference.files.retain(|loc| !released.files.contains(loc));
+
+        Self::write_or_delete(dal, location, &reference).await
+    }
+
+    /// Write the reference, or delete it if it's empty.
+    pub async fn write_or_delete(
+        dal: &Operator,
+        location: &str,
+        reference: &CloneReference,
+    ) -> Result<()> {
+        if reference.is_empty() {
+            dal.object(location).delete().await?;
+        } else {
+            Self::write(dal, location, reference).await?;
+        }
+        Ok(())
+    }
+
+    /// Read the prefixes of the tables owning the files a cloned table is cloned from, returns
+    /// `None` if the table is not cloned.
+    pub async fn read_owners(dal: &Operator, location: &str) -> Result<Option<Vec<String>>> {
+        match dal.object(location).read().await {
+            Ok(bytes) => Ok(Some(serde_json::from_slice(&bytes)?)),
+            Err(e) if e.kind() == opendal::ErrorKind::ObjectNotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub async fn write_owners(dal: &Operator, location: &str, owners: &[String]) -> Result<()> {
+        let bytes = serde_json::to_vec(owners)?;
+        dal.object(location).write(bytes).await?;
+        Ok(())
+    }
+}
+
+/// A lock on the clone references of a table in the meta service.
+///
+/// A table cloned from the table holds the lock while writing its references, and the gc of
+/// the table holds it from reading the references until the files are purged, so a file is
+/// either seen as referenced by the gc, or purged before the reference to it is written, which
+/// the cloned table checks after writing the references.
+pub struct CloneReferenceLock {
+    key: String,
+    seq: u64,
+}
+
+impl CloneReferenceLock {
+    /// Acquire the lock on the references to the files of the table of `prefix`, waits until
+    /// the lock is released or expired if it's held by others.
+    pub async fn acquire(prefix: &str) -> Result<Self> {
+        let key = format!("{}/{}", CLONE_REFERENCE_LOCK_KEY_PREFIX, prefix);
+        loop {
+            if let Some(seq) = Self::upsert(&key, MatchSeq::Exact(0)).await? {
+                return Ok(Self { key, seq });
+            }
+            sleep(CLONE_REFERENCE_LOCK_RETRY_INTERVAL).await;
+        }
+    }
+
+    /// Extend the lock, returns an error if the lock has expired and has been taken by others,
+    /// in which case the files must not be purged.
+    pub async fn extend(&mut self) -> Result<()> {
+        match Self::upsert(&self.key, MatchSeq::Exact(self.seq)).await? {
+            Some(seq) => {
+                self.seq = seq;
+                Ok(())
+            }
+            None => Err(ErrorCode::Internal(format!(
+                "Lost the lock {} on the clone references",
+                self.key
+            ))),
+        }
+    }
+
+    pub async fn release(self) -> Result<()> {
+        let meta_client = UserApiProvider::instance().get_meta_store_client();
+        meta_client
+            .upsert_kv(UpsertKV::new(
+                &self.key,
+                MatchSeq::Exact(self.seq),
+                Operation::Delete,
+                None,
+            ))
+            .await?;
+        Ok(())
+    }
+
+    /// Returns the new seq of the key if it's updated.
+    async fn upsert(key: &str, seq: MatchSeq) -> Result<Option<u64>> {
+        let meta_client = UserApiProvider::instance().get_meta_store_client();
+        let expire_at = SeqV::<()>::now_ms() / 1000 + CLONE_REFERENCE_LOCK_TTL_SECS;
+        let reply = meta_client
+            .upsert_kv(UpsertKV::new(
+                key,
+                seq,
+                Operation::Update(vec![]),
+                Some(KVMeta {
+                    expire_at: Some(expire_at),
+                }),
+            ))
+            .await?;
+        if !reply.is_changed() {
+            return Ok(None);
+        }
+        Ok(reply.result.map(|value| value.seq))
+    }
+}
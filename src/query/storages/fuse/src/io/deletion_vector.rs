@@ -0,0 +1,95 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::ops::Not;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use opendal::Operator;
+use storages_common_table_meta::meta::DeletionVector;
+
+// The deletion vector of a block is stored as a standalone object:
+//
+// | number of rows: u64 little endian | bitmap bytes, bit `i` is set if row `i` is deleted |
+
+/// Serialize the bitmap of the deleted rows of a block.
+pub fn serialize_deletion_vector(deleted: &Bitmap) -> Vec<u8> {
+    let (_, offset, _) = deleted.as_slice();
+    let aligned = if offset == 0 {
+        deleted.clone()
+    } else {
+        deleted.iter().collect::<MutableBitmap>().into()
+    };
+
+    let (bytes, _, len) = aligned.as_slice();
+    let num_bytes = (len + 7) / 8;
+    let mut buf = Vec::with_capacity(std::mem::size_of::<u64>() + num_bytes);
+    buf.extend_from_slice(&(len as u64).to_le_bytes());
+    buf.extend_from_slice(&bytes[..num_bytes]);
+    buf
+}
+
+/// Deserialize the bitmap of the deleted rows of a block.
+pub fn deserialize_deletion_vector(data: &[u8]) -> Result<Bitmap> {
+    let header = std::mem::size_of::<u64>();
+    if data.len() < header {
+        return Err(ErrorCode::StorageOther(format!(
+            "invalid deletion vector, expect at least {} bytes, got {}",
+            header,
+            data.len()
+        )));
+    }
+    let mut len_bytes = [0u8; 8];
+    len_bytes.copy_from_slice(&data[..header]);
+    let len = u64::from_le_bytes(len_bytes) as usize;
+    Ok(Bitmap::try_new(data[header..].to_vec(), len)?)
+}
+
+/// Load the bitmap of the deleted rows, if the block has a deletion vector.
+pub async fn read_deletion_vector(
+    dal: &Operator,
+    deletion_vector: Option<&DeletionVector>,
+) -> Result<Option<Bitmap>> {
+    match deletion_vector {
+        None => Ok(None),
+        Some(v) => {
+            let data = dal.object(&v.location.0).read().await?;
+            Ok(Some(deserialize_deletion_vector(&data)?))
+        }
+    }
+}
+
+pub fn sync_read_deletion_vector(
+    dal: &Operator,
+    deletion_vector: Option<&DeletionVector>,
+) -> Result<Option<Bitmap>> {
+    match deletion_vector {
+        None => Ok(None),
+        Some(v) => {
+            let data = dal.object(&v.location.0).blocking_read()?;
+            Ok(Some(deserialize_deletion_vector(&data)?))
+        }
+    }
+}
+
+/// Remove the rows marked as deleted from the block.
+pub fn filter_deleted_rows(block: DataBlock, deleted: Option<&Bitmap>) -> Result<DataBlock> {
+    match deleted {
+        None => Ok(block),
+        Some(deleted) => block.filter_with_bitmap(&deleted.not()),
+    }
+}
@@ -16,14 +16,21 @@ use std::marker::PhantomData;
 
 use common_exception::Result;
 use common_expression::DataBlock;
+use storages_common_table_meta::meta::DeletionVector;
 use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::SegmentInfo;
 use storages_common_table_meta::meta::SnapshotVersion;
 use storages_common_table_meta::meta::TableSnapshotStatisticsVersion;
 use storages_common_table_meta::meta::Versioned;
+use storages_common_table_meta::table::AggregatingIndex;
 use uuid::Uuid;
 
+use crate::constants::FUSE_TBL_AGG_INDEX_PREFIX;
 use crate::constants::FUSE_TBL_BLOCK_PREFIX;
+use crate::constants::FUSE_TBL_CLONE_OWNERS;
+use crate::constants::FUSE_TBL_CLONE_REFERENCE_PREFIX;
+use crate::constants::FUSE_TBL_CLONE_RETAINED;
+use crate::constants::FUSE_TBL_DELETION_VECTOR_PREFIX;
 use crate::constants::FUSE_TBL_SEGMENT_PREFIX;
 use crate::constants::FUSE_TBL_SNAPSHOT_PREFIX;
 use crate::constants::FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX;
@@ -91,6 +98,35 @@ impl TableMetaLocationGenerator {
         )
     }
 
+    /// The location of the data of the aggregating index `index_id` of a block.
+    pub fn block_agg_index_location(&self, index_id: &str, block_id: &Uuid) -> Location {
+        (
+            format!(
+                "{}/{}/{}/{}_v{}.parquet",
+                &self.prefix,
+                FUSE_TBL_AGG_INDEX_PREFIX,
+                index_id,
+                block_id.as_simple(),
+                AggregatingIndex::VERSION,
+            ),
+            AggregatingIndex::VERSION,
+        )
+    }
+
+    pub fn gen_deletion_vector_location(&self) -> Location {
+        let uuid = Uuid::new_v4();
+        (
+            format!(
+                "{}/{}/{}_v{}.bin",
+                &self.prefix,
+                FUSE_TBL_DELETION_VECTOR_PREFIX,
+                uuid.as_simple(),
+                DeletionVector::VERSION,
+            ),
+            DeletionVector::VERSION,
+        )
+    }
+
     pub fn gen_segment_info_location(&self) -> String {
         let segment_uuid = Uuid::new_v4().simple().to_string();
         format!(
@@ -133,6 +169,50 @@ impl TableMetaLocationGenerator {
     pub fn gen_last_snapshot_hint_location(&self) -> String {
         format!("{}/{}", &self.prefix, FUSE_TBL_LAST_SNAPSHOT_HINT)
     }
+
+    pub fn clone_reference_prefix(&self) -> String {
+        format!("{}/{}/", &self.prefix, FUSE_TBL_CLONE_REFERENCE_PREFIX)
+    }
+
+    /// The location of the prefixes of the tables owning the files this table is cloned from.
+    pub fn clone_owners_location(&self) -> String {
+        format!("{}/{}", &self.prefix, FUSE_TBL_CLONE_OWNERS)
+    }
+
+    /// The location of the files of this table that gc has kept for the tables cloned from it,
+    /// after the snapshots of this table referencing them are purged.
+    pub fn clone_retained_location(&self) -> String {
+        format!("{}/{}", &self.prefix, FUSE_TBL_CLONE_RETAINED)
+    }
+
+    /// The location of the references of the table `table_id`, which is cloned from this table,
+    /// to the files of this table.
+    pub fn clone_reference_location(&self, table_id: u64) -> String {
+        format!(
+            "{}/{}/{}.json",
+            &self.prefix, FUSE_TBL_CLONE_REFERENCE_PREFIX, table_id,
+        )
+    }
+
+    /// The prefix of the table that the segment or data file of `location` belongs to.
+    ///
+    /// A cloned table references the files of the table it is cloned from, which are
+    /// not located under its own prefix.
+    pub fn location_prefix(location: &str) -> Option<&str> {
+        [
+            FUSE_TBL_SEGMENT_PREFIX,
+            FUSE_TBL_BLOCK_PREFIX,
+            FUSE_TBL_XOR_BLOOM_INDEX_PREFIX,
+            FUSE_TBL_AGG_INDEX_PREFIX,
+            FUSE_TBL_DELETION_VECTOR_PREFIX,
+        ]
+        .iter()
+        .find_map(|dir| {
+            location
+                .find(&format!("/{}/", dir))
+                .map(|pos| &location[..pos])
+        })
+    }
 }
 
 trait SnapshotLocationCreator {
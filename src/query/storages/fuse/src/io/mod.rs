@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod clone_reference;
+mod deletion_vector;
 mod files;
 mod locations;
 mod read;
@@ -19,6 +21,14 @@ mod segments;
 mod snapshots;
 mod write;
 
+pub use clone_reference::CloneReference;
+pub use clone_reference::CloneReferenceIO;
+pub use clone_reference::CloneReferenceLock;
+pub use deletion_vector::deserialize_deletion_vector;
+pub use deletion_vector::filter_deleted_rows;
+pub use deletion_vector::read_deletion_vector;
+pub use deletion_vector::serialize_deletion_vector;
+pub use deletion_vector::sync_read_deletion_vector;
 pub use files::Files;
 pub use locations::TableMetaLocationGenerator;
 pub use read::BlockReader;
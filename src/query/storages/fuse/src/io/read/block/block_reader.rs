@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use common_arrow::arrow::datatypes::Field;
@@ -27,6 +28,7 @@ use common_expression::ColumnId;
 use common_expression::DataField;
 use common_expression::DataSchema;
 use common_expression::FieldIndex;
+use common_expression::FunctionContext;
 use common_expression::Scalar;
 use common_expression::TableField;
 use common_expression::TableSchemaRef;
@@ -35,6 +37,8 @@ use common_storage::ColumnNode;
 use common_storage::ColumnNodes;
 use opendal::Operator;
 
+use super::block_reader_evolution::EvolvedColumn;
+
 // TODO: make BlockReader as a trait.
 #[derive(Clone)]
 pub struct BlockReader {
@@ -45,6 +49,8 @@ pub struct BlockReader {
     pub(crate) project_column_nodes: Vec<ColumnNode>,
     pub(crate) parquet_schema_descriptor: SchemaDescriptor,
     pub(crate) default_vals: Vec<Scalar>,
+    pub(crate) evolved_columns: HashMap<ColumnId, Vec<EvolvedColumn>>,
+    pub(crate) func_ctx: FunctionContext,
 }
 
 fn inner_project_field_default_values(default_vals: &[Scalar], paths: &[usize]) -> Result<Scalar> {
@@ -123,6 +129,7 @@ impl BlockReader {
             .map(|c| (*c).clone())
             .collect();
         let project_indices = Self::build_projection_indices(&project_column_nodes);
+        let evolved_columns = Self::build_evolved_columns(&project_column_nodes)?;
 
         Ok(Arc::new(BlockReader {
             operator,
@@ -132,6 +139,8 @@ impl BlockReader {
             project_column_nodes,
             parquet_schema_descriptor,
             default_vals,
+            evolved_columns,
+            func_ctx: ctx.get_function_context()?,
         }))
     }
 
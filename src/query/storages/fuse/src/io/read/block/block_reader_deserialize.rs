@@ -25,7 +25,9 @@ use storages_common_table_meta::meta::ColumnMeta;
 use storages_common_table_meta::meta::Compression;
 
 use super::BlockReader;
+use crate::io::filter_deleted_rows;
 use crate::io::read::block::block_reader_merge_io::DataItem;
+use crate::io::read_deletion_vector;
 use crate::io::ReadSettings;
 use crate::io::UncompressedBuffer;
 use crate::FuseStorageFormat;
@@ -77,7 +79,7 @@ impl BlockReader {
 
         let num_rows = meta.row_count as usize;
 
-        match storage_format {
+        let block = match storage_format {
             FuseStorageFormat::Parquet => self.deserialize_parquet_chunks_with_buffer(
                 &meta.location.0,
                 num_rows,
@@ -85,7 +87,7 @@ impl BlockReader {
                 columns_meta,
                 column_chunks,
                 None,
-            ),
+            )?,
             FuseStorageFormat::Native => self.deserialize_native_chunks_with_buffer(
                 &meta.location.0,
                 num_rows,
@@ -93,7 +95,11 @@ impl BlockReader {
                 columns_meta,
                 column_chunks,
                 None,
-            ),
-        }
+            )?,
+        };
+
+        // Drop the rows marked as deleted, they are compacted away once the block is rewritten.
+        let deleted = read_deletion_vector(&self.operator, meta.deletion_vector.as_ref()).await?;
+        filter_deleted_rows(block, deleted.as_ref())
     }
 }
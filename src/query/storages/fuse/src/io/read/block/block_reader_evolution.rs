@@ -0,0 +1,154 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::datatypes::Field;
+use common_arrow::arrow::datatypes::Schema as ArrowSchema;
+use common_arrow::arrow::error::Error as ArrowError;
+use common_arrow::arrow::io::parquet::write::to_parquet_schema;
+use common_arrow::native::read::column_iter_to_arrays;
+use common_arrow::native::read::reader::NativeReader;
+use common_arrow::native::read::ArrayIter;
+use common_arrow::parquet::metadata::ColumnDescriptor;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::ColumnId;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::TableField;
+use common_expression::Value;
+use common_functions::scalars::BUILTIN_FUNCTIONS;
+use common_storage::ColumnNode;
+use storages_common_table_meta::meta::ColumnMeta;
+
+use super::block_reader_native::Reader;
+use crate::io::BlockReader;
+
+/// A previous version of a projected column whose data type has been modified.
+///
+/// Blocks written before the modification store the column by `column_id` and `data_type`,
+/// the column is read as it was written and then cast to the current data type.
+#[derive(Clone)]
+pub struct EvolvedColumn {
+    pub column_id: ColumnId,
+    pub field: Field,
+    pub data_type: DataType,
+    pub column_descriptor: ColumnDescriptor,
+}
+
+impl BlockReader {
+    // Build the previous versions of the projected columns, keyed by the current column id.
+    pub(crate) fn build_evolved_columns(
+        column_nodes: &[ColumnNode],
+    ) -> Result<HashMap<ColumnId, Vec<EvolvedColumn>>> {
+        let mut evolved_columns = HashMap::new();
+        for column_node in column_nodes {
+            // only non-nested columns can be modified.
+            if column_node.evolved_from.is_empty() || column_node.leaf_column_ids.len() != 1 {
+                continue;
+            }
+
+            let mut columns = Vec::with_capacity(column_node.evolved_from.len());
+            for (column_id, field) in &column_node.evolved_from {
+                let parquet_schema = to_parquet_schema(&ArrowSchema::from(vec![field.clone()]))?;
+                let table_field = TableField::from(field);
+                columns.push(EvolvedColumn {
+                    column_id: *column_id,
+                    field: field.clone(),
+                    data_type: table_field.data_type().into(),
+                    column_descriptor: parquet_schema.columns()[0].clone(),
+                });
+            }
+            evolved_columns.insert(column_node.leaf_column_ids[0], columns);
+        }
+        Ok(evolved_columns)
+    }
+
+    /// Returns the previous version of the column that the block stores instead of `column_id`,
+    /// if the block was written before the data type of the column was modified.
+    pub(crate) fn evolved_column(
+        &self,
+        column_id: ColumnId,
+        column_metas: &HashMap<ColumnId, ColumnMeta>,
+    ) -> Option<&EvolvedColumn> {
+        if column_metas.contains_key(&column_id) {
+            return None;
+        }
+        self.evolved_columns
+            .get(&column_id)?
+            .iter()
+            .find(|column| column_metas.contains_key(&column.column_id))
+    }
+
+    /// Cast the array read by the previous version of a column to the current data type.
+    pub(crate) fn cast_evolved_array(
+        func_ctx: FunctionContext,
+        evolved: &EvolvedColumn,
+        data_type: &DataType,
+        array: &dyn Array,
+    ) -> Result<Box<dyn Array>> {
+        let num_rows = array.len();
+        let column = Column::from_arrow(array, &evolved.data_type);
+        let block = DataBlock::new(
+            vec![BlockEntry {
+                data_type: evolved.data_type.clone(),
+                value: Value::Column(column),
+            }],
+            num_rows,
+        );
+        let expr = Expr::Cast {
+            span: None,
+            is_try: false,
+            expr: Box::new(Expr::ColumnRef {
+                span: None,
+                id: 0,
+                data_type: evolved.data_type.clone(),
+                display_name: evolved.field.name.clone(),
+            }),
+            dest_type: data_type.clone(),
+        };
+        let evaluator = Evaluator::new(&block, func_ctx, &BUILTIN_FUNCTIONS);
+        let value = evaluator.run(&expr)?;
+        Ok(value.convert_to_full_column(data_type, num_rows).as_arrow())
+    }
+
+    /// Build the native array iterator of a previous version of a column,
+    /// the arrays are casted to the current data type of the column.
+    pub fn build_evolved_native_array_iter(
+        func_ctx: FunctionContext,
+        column_node: &ColumnNode,
+        evolved: EvolvedColumn,
+        readers: Vec<NativeReader<Reader>>,
+    ) -> Result<ArrayIter<'static>> {
+        let data_type: DataType = TableField::from(&column_node.field).data_type().into();
+        let array_iter = column_iter_to_arrays(
+            readers,
+            vec![evolved.column_descriptor.clone()],
+            evolved.field.clone(),
+            false,
+        )?;
+        Ok(Box::new(array_iter.map(move |array| {
+            array.and_then(|array| {
+                Self::cast_evolved_array(func_ctx, &evolved, &data_type, array.as_ref())
+                    .map_err(|err| ArrowError::External(String::new(), Box::new(err)))
+            })
+        })))
+    }
+}
@@ -157,7 +157,11 @@ impl BlockReader {
                 continue;
             }
 
-            // if all cache missed, prepare the ranges to be read
+            // if all cache missed, prepare the ranges to be read,
+            // the block may store a previous version of the column.
+            let column_id = self
+                .evolved_column(*column_id, columns_meta)
+                .map_or(column_id, |evolved| &evolved.column_id);
             if let Some(column_meta) = columns_meta.get(column_id) {
                 let (offset, len) = column_meta.offset_length();
                 ranges.push((*column_id, offset..(offset + len)));
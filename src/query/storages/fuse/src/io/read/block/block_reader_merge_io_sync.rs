@@ -112,6 +112,10 @@ impl BlockReader {
                 cached_column_array.push((*column_id, cache_array));
                 continue;
             }
+            // the block may store a previous version of the column.
+            let column_id = self
+                .evolved_column(*column_id, &part.columns_meta)
+                .map_or(column_id, |evolved| &evolved.column_id);
             if let Some(column_meta) = part.columns_meta.get(column_id) {
                 let (offset, len) = column_meta.offset_length();
                 ranges.push((*column_id, offset..(offset + len)));
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::collections::VecDeque;
 use std::io::BufReader;
@@ -30,6 +31,7 @@ use common_expression::Column;
 use common_expression::ColumnId;
 use common_expression::DataBlock;
 use common_expression::Value;
+use common_storage::ColumnNode;
 use opendal::Object;
 use storages_common_table_meta::meta::ColumnMeta;
 
@@ -61,12 +63,7 @@ impl BlockReader {
         let mut join_handlers = Vec::with_capacity(self.project_column_nodes.len());
 
         for (index, column_node) in self.project_column_nodes.iter().enumerate() {
-            let metas: Vec<ColumnMeta> = column_node
-                .leaf_column_ids
-                .iter()
-                .filter_map(|column_id| part.columns_meta.get(column_id))
-                .cloned()
-                .collect::<Vec<_>>();
+            let metas = self.native_column_metas(column_node, &part.columns_meta);
 
             join_handlers.push(Self::read_native_columns_data(
                 self.operator.object(&part.location),
@@ -138,12 +135,7 @@ impl BlockReader {
         for (index, column_node) in self.project_column_nodes.iter().enumerate() {
             let op = self.operator.clone();
             let location = part.location.clone();
-            let metas: Vec<ColumnMeta> = column_node
-                .leaf_column_ids
-                .iter()
-                .filter_map(|column_id| part.columns_meta.get(column_id))
-                .cloned()
-                .collect::<Vec<_>>();
+            let metas = self.native_column_metas(column_node, &part.columns_meta);
 
             let readers = Self::sync_read_native_column(op.object(&location), metas, &part.range)?;
             results.insert(index, readers);
@@ -177,6 +169,24 @@ impl BlockReader {
         Ok(native_readers)
     }
 
+    // The metas of the leaf columns to read, falls back to the previous version of the column
+    // if the block was written before the data type of the column was modified.
+    fn native_column_metas(
+        &self,
+        column_node: &ColumnNode,
+        columns_meta: &HashMap<ColumnId, ColumnMeta>,
+    ) -> Vec<ColumnMeta> {
+        if let Some(evolved) = self.evolved_column(column_node.leaf_column_ids[0], columns_meta) {
+            return vec![columns_meta[&evolved.column_id].clone()];
+        }
+        column_node
+            .leaf_column_ids
+            .iter()
+            .filter_map(|column_id| columns_meta.get(column_id))
+            .cloned()
+            .collect::<Vec<_>>()
+    }
+
     pub fn fill_missing_native_column_values(
         &self,
         data_block: DataBlock,
@@ -184,7 +194,14 @@ impl BlockReader {
     ) -> Result<DataBlock> {
         let part = FusePartInfo::from_part(&parts[0])?;
 
-        let data_block_column_ids: HashSet<ColumnId> = part.columns_meta.keys().cloned().collect();
+        let mut data_block_column_ids: HashSet<ColumnId> =
+            part.columns_meta.keys().cloned().collect();
+        // the columns stored by a previous version have been read as well.
+        for column_id in self.evolved_columns.keys() {
+            if self.evolved_column(*column_id, &part.columns_meta).is_some() {
+                data_block_column_ids.insert(*column_id);
+            }
+        }
         let default_vals = self.default_vals.clone();
 
         DataBlock::create_with_default_value_and_block(
@@ -26,8 +26,10 @@ use common_arrow::parquet::metadata::ColumnDescriptor;
 use common_catalog::plan::PartInfoPtr;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::DataType;
 use common_expression::ColumnId;
 use common_expression::DataBlock;
+use common_expression::TableField;
 use common_storage::ColumnNode;
 use storages_common_cache::CacheAccessor;
 use storages_common_cache::TableDataCacheKey;
@@ -37,6 +39,7 @@ use storages_common_table_meta::meta::Compression;
 
 use super::block_reader_deserialize::DeserializedArray;
 use super::block_reader_deserialize::FieldDeserializationContext;
+use super::block_reader_evolution::EvolvedColumn;
 use crate::fuse_part::FusePartInfo;
 use crate::io::read::block::block_reader_merge_io::DataItem;
 use crate::io::BlockReader;
@@ -195,6 +198,13 @@ impl BlockReader {
     ) -> Result<Option<DeserializedArray<'a>>> {
         let indices = &column.leaf_indices;
         let column_chunks = deserialization_context.column_chunks;
+
+        if let Some(evolved) =
+            self.evolved_column(column.leaf_column_ids[0], deserialization_context.column_metas)
+        {
+            return self.deserialize_evolved_native_field(deserialization_context, column, evolved);
+        }
+
         // column passed in may be a compound field (with sub leaves),
         // or a leaf column of compound field
         let is_nested = column.has_children();
@@ -268,4 +278,47 @@ impl BlockReader {
             Ok(None)
         }
     }
+
+    // The block stores a previous version of the column, read it with the data type it was
+    // written with and cast it to the current one.
+    fn deserialize_evolved_native_field<'a>(
+        &self,
+        deserialization_context: &'a FieldDeserializationContext,
+        column: &ColumnNode,
+        evolved: &EvolvedColumn,
+    ) -> Result<Option<DeserializedArray<'a>>> {
+        let column_id = column.leaf_column_ids[0];
+        let column_chunks = deserialization_context.column_chunks;
+        // the casted array is cached by the current column id.
+        if let Some(DataItem::ColumnArray(column_array)) = column_chunks.get(&column_id) {
+            return Ok(Some(DeserializedArray::Cached(column_array)));
+        }
+
+        let column_meta = &deserialization_context.column_metas[&evolved.column_id];
+        let data = match column_chunks.get(&evolved.column_id) {
+            Some(DataItem::RawData(data)) => *data,
+            _ => return Err(ErrorCode::StorageOther("unexpected: column data not found")),
+        };
+        let array_iter = Self::chunks_to_native_array_iter(
+            column,
+            vec![column_meta],
+            vec![data],
+            vec![evolved.column_descriptor.clone()],
+            evolved.field.clone(),
+        )?;
+        let mut arrays = vec![];
+        for array in array_iter {
+            arrays.push(array?);
+        }
+        let arrays = arrays.iter().map(|c| c.as_ref()).collect::<Vec<_>>();
+        let array = compute::concatenate::concatenate(&arrays)?;
+
+        let data_type: DataType = TableField::from(&column.field).data_type().into();
+        let array = Self::cast_evolved_array(self.func_ctx, evolved, &data_type, array.as_ref())?;
+        Ok(Some(DeserializedArray::Deserialized((
+            column_id,
+            array,
+            data.len(),
+        ))))
+    }
 }
@@ -14,6 +14,7 @@
 //
 mod block_reader;
 mod block_reader_deserialize;
+mod block_reader_evolution;
 mod block_reader_merge_io;
 mod block_reader_merge_io_async;
 mod block_reader_merge_io_sync;
@@ -68,7 +68,8 @@ impl VersionedReader<SegmentInfo> for (SegmentInfoVersion, TableSchemaRef) {
     where R: AsyncRead + Unpin + Send {
         let schema = &self.1;
         let r = match &self.0 {
-            SegmentInfoVersion::V2(v) => load_by_version(reader, v).await?,
+            SegmentInfoVersion::V3(v) => load_by_version(reader, v).await?,
+            SegmentInfoVersion::V2(v) => SegmentInfo::from_v2(load_by_version(reader, v).await?),
             SegmentInfoVersion::V1(v) => {
                 let data = load_by_version(reader, v).await?;
                 let fields = schema.leaf_fields();
@@ -18,6 +18,7 @@
 #![feature(io_error_other)]
 #![feature(once_cell)]
 
+pub mod aggregating_index;
 mod constants;
 mod fuse_lazy_part;
 mod fuse_part;
@@ -26,6 +27,7 @@ pub mod io;
 pub mod operations;
 pub mod pruning;
 pub mod statistics;
+pub mod stream;
 pub mod table_functions;
 
 mod metrics;
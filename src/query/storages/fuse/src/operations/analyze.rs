@@ -15,17 +15,31 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use common_catalog::plan::Projection;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::ColumnId;
+use common_expression::FieldIndex;
+use common_expression::ScalarRef;
+use common_expression::TableDataType;
+use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::ColumnHistogram;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::TableSnapshotStatistics;
 use tracing::warn;
 
+use crate::io::ReadSettings;
 use crate::io::SegmentsIO;
+use crate::statistics::build_column_histogram;
+use crate::statistics::DEFAULT_HISTOGRAM_BUCKETS;
+use crate::statistics::DEFAULT_MOST_COMMON_VALUES;
 use crate::FuseTable;
 
+/// Max number of blocks read by `ANALYZE TABLE` to build the column histograms.
+const ANALYZE_SAMPLE_BLOCKS: usize = 16;
+
 impl FuseTable {
     pub async fn do_analyze(&self, ctx: &Arc<dyn TableContext>) -> Result<()> {
         // 1. Read table snapshot.
@@ -49,15 +63,16 @@ impl FuseTable {
             let mut sum_map = HashMap::new();
             let mut row_count_sum = 0;
             let mut block_count_sum: u64 = 0;
+            let mut block_metas = vec![];
 
             let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
             let segments = segments_io.read_segments(&snapshot.segments).await?;
             for segment in segments {
                 let segment = segment?;
                 segment.blocks.iter().for_each(|block| {
-                    let block = block.as_ref();
                     let row_count = block.row_count;
                     if row_count != 0 {
+                        block_metas.push(block.clone());
                         block_count_sum += 1;
                         row_count_sum += row_count;
                         for (i, col_stat) in block.col_stats.iter() {
@@ -84,8 +99,13 @@ impl FuseTable {
                 ndv_map.insert(*i, (density_avg * row_count_sum as f64) as u64);
             }
 
-            // 3. Generate new table statistics
-            let table_statistics = TableSnapshotStatistics::new(ndv_map);
+            // 3. Sample blocks to build histograms.
+            let column_histograms = self
+                .sample_column_histograms(ctx, &block_metas, row_count_sum, &ndv_map)
+                .await?;
+
+            // 4. Generate new table statistics
+            let table_statistics = TableSnapshotStatistics::new(ndv_map, column_histograms);
             let table_statistics_location = self
                 .meta_location_generator
                 .snapshot_statistics_location_from_uuid(
@@ -93,7 +113,7 @@ impl FuseTable {
                     table_statistics.format_version(),
                 )?;
 
-            // 4. Save table statistics
+            // 5. Save table statistics
             let mut new_snapshot = TableSnapshot::from_previous(&snapshot);
             new_snapshot.table_statistics_location = Some(table_statistics_location);
             FuseTable::commit_to_meta_server(
@@ -109,4 +129,76 @@ impl FuseTable {
 
         Ok(())
     }
+
+    async fn sample_column_histograms(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        block_metas: &[Arc<BlockMeta>],
+        row_count: u64,
+        ndv_map: &HashMap<ColumnId, u64>,
+    ) -> Result<HashMap<ColumnId, ColumnHistogram>> {
+        let mut column_histograms = HashMap::new();
+
+        // Only the columns that the optimizer could compare with constants.
+        let schema = self.schema();
+        let field_indices: Vec<FieldIndex> = schema
+            .fields()
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| {
+                matches!(
+                    f.data_type().remove_nullable(),
+                    TableDataType::Number(_) | TableDataType::String
+                )
+            })
+            .map(|(i, _)| i)
+            .collect();
+        if field_indices.is_empty() || block_metas.is_empty() {
+            return Ok(column_histograms);
+        }
+
+        // Read evenly spaced blocks.
+        let step = (block_metas.len() + ANALYZE_SAMPLE_BLOCKS - 1) / ANALYZE_SAMPLE_BLOCKS;
+        let block_reader =
+            self.create_block_reader(Projection::Columns(field_indices.clone()), ctx.clone())?;
+        let settings = ReadSettings::from_ctx(ctx)?;
+        let mut samples = vec![vec![]; field_indices.len()];
+        let mut sampled_rows = 0;
+        for meta in block_metas.iter().step_by(step) {
+            let block = block_reader
+                .read_by_meta(&settings, meta.as_ref(), &self.storage_format)
+                .await?;
+            let num_rows = block.num_rows();
+            sampled_rows += num_rows;
+            for (entry, values) in block.columns().iter().zip(samples.iter_mut()) {
+                let column = entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, num_rows);
+                values.extend(
+                    column
+                        .iter()
+                        .filter(|v| !matches!(v, ScalarRef::Null))
+                        .map(|v| v.to_owned()),
+                );
+            }
+        }
+        if sampled_rows == 0 {
+            return Ok(column_histograms);
+        }
+
+        let scale = row_count as f64 / sampled_rows as f64;
+        for (index, values) in field_indices.iter().zip(samples.into_iter()) {
+            let column_id = schema.column_id_of_index(*index)?;
+            if let Some(histogram) = build_column_histogram(
+                values,
+                scale,
+                ndv_map.get(&column_id).cloned(),
+                DEFAULT_HISTOGRAM_BUCKETS,
+                DEFAULT_MOST_COMMON_VALUES,
+            ) {
+                column_histograms.insert(column_id, histogram);
+            }
+        }
+        Ok(column_histograms)
+    }
 }
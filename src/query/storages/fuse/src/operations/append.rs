@@ -47,6 +47,7 @@ impl FuseTable {
     ) -> Result<()> {
         let block_compact_thresholds = self.get_block_compact_thresholds();
         let write_settings = self.get_write_settings();
+        let agg_indexes = self.aggregating_indexes()?;
 
         match append_mode {
             AppendMode::Normal => {
@@ -117,6 +118,7 @@ impl FuseTable {
                     cluster_stats_gen.clone(),
                     block_compact_thresholds,
                     self.table_info.schema(),
+                    agg_indexes.clone(),
                     Some(transform_output_port),
                 )
             })?;
@@ -131,6 +133,7 @@ impl FuseTable {
                     cluster_stats_gen.clone(),
                     block_compact_thresholds,
                     self.table_info.schema(),
+                    agg_indexes.clone(),
                     None,
                 )
             })?;
@@ -0,0 +1,139 @@
+//  Copyright 2023 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use storages_common_cache::LoadParams;
+use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::Location;
+
+use crate::io::MetaReaders;
+use crate::io::SegmentsIO;
+use crate::io::TableMetaLocationGenerator;
+use crate::stream::ChangeRows;
+use crate::FuseTable;
+
+/// A version of a block: the location of the block, and of its deletion vector if any.
+///
+/// Deleting rows of a block only changes its deletion vector, so both of them identify the
+/// rows visible in the block.
+type BlockVersion = (String, Option<String>);
+
+impl FuseTable {
+    /// The blocks inserted and deleted since the snapshot at `offset`, `None` means the
+    /// table was empty.
+    ///
+    /// The changes are derived by comparing the block lists of the two snapshots, segments
+    /// are immutable, thus only the segments not shared by the snapshots are read. A block
+    /// rewritten since the offset (e.g. by compaction or update) shows up as its old version
+    /// deleted and its new version inserted. A block whose deletion vector changed, i.e. some
+    /// of its rows have been deleted in place, shows up as only the newly deleted rows of its
+    /// current version.
+    pub async fn changes_since(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        offset: Option<&str>,
+    ) -> Result<(Vec<Arc<BlockMeta>>, Vec<(Arc<BlockMeta>, ChangeRows)>)> {
+        let current_segments = match self.read_table_snapshot().await? {
+            Some(snapshot) => snapshot.segments.clone(),
+            None => vec![],
+        };
+        let offset_segments = match offset {
+            Some(location) => {
+                let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+                let params = LoadParams {
+                    location: location.to_string(),
+                    len_hint: None,
+                    ver: TableMetaLocationGenerator::snapshot_version(location),
+                };
+                reader.read(&params).await?.segments.clone()
+            }
+            None => vec![],
+        };
+
+        let current_set: HashSet<&Location> = current_segments.iter().collect();
+        let offset_set: HashSet<&Location> = offset_segments.iter().collect();
+        let added = current_segments
+            .iter()
+            .filter(|location| !offset_set.contains(location))
+            .cloned()
+            .collect::<Vec<_>>();
+        let removed = offset_segments
+            .iter()
+            .filter(|location| !current_set.contains(location))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let added_blocks = self.collect_block_versions(ctx.clone(), &added).await?;
+        let removed_blocks = self.collect_block_versions(ctx, &removed).await?;
+
+        // the versions of the blocks at the offset, by block location.
+        let removed_locations: HashMap<&str, &Arc<BlockMeta>> = removed_blocks
+            .iter()
+            .map(|((location, _), block_meta)| (location.as_str(), block_meta))
+            .collect();
+
+        let mut inserted = vec![];
+        let mut deleted = vec![];
+        for (version, block_meta) in &added_blocks {
+            if removed_blocks.contains_key(version) {
+                continue;
+            }
+            match removed_locations.get(version.0.as_str()) {
+                Some(prev) => deleted.push((
+                    block_meta.clone(),
+                    ChangeRows::DeletedSince(prev.deletion_vector.clone()),
+                )),
+                None => inserted.push(block_meta.clone()),
+            }
+        }
+        let added_locations: HashSet<&str> = added_blocks
+            .keys()
+            .map(|(location, _)| location.as_str())
+            .collect();
+        for ((location, _), block_meta) in &removed_blocks {
+            if !added_locations.contains(location.as_str()) {
+                deleted.push((block_meta.clone(), ChangeRows::Visible));
+            }
+        }
+        Ok((inserted, deleted))
+    }
+
+    async fn collect_block_versions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        segment_locations: &[Location],
+    ) -> Result<HashMap<BlockVersion, Arc<BlockMeta>>> {
+        let segments_io = SegmentsIO::create(ctx, self.operator.clone(), self.schema());
+        let mut blocks = HashMap::new();
+        for segment in segments_io.read_segments(segment_locations).await? {
+            for block_meta in &segment?.blocks {
+                let version = (
+                    block_meta.location.0.clone(),
+                    block_meta
+                        .deletion_vector
+                        .as_ref()
+                        .map(|deletion_vector| deletion_vector.location.0.clone()),
+                );
+                blocks.insert(version, block_meta.clone());
+            }
+        }
+        Ok(blocks)
+    }
+}
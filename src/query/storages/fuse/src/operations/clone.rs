@@ -0,0 +1,261 @@
+//  Copyright 2023 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::TableStatistics;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use futures::future::try_join_all;
+use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::TableSnapshot;
+use storages_common_table_meta::meta::Versioned;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use uuid::Uuid;
+
+use crate::io::CloneReference;
+use crate::io::CloneReferenceIO;
+use crate::io::CloneReferenceLock;
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Make this newly created table a zero-copy clone of `source`.
+    ///
+    /// The snapshot of this table references the segments of `source` (which may be a historical
+    /// instance navigated to), no data is copied. References to the shared files are written to
+    /// the tables owning them, so that their gc keeps the files while this table uses them.
+    pub async fn do_clone_from(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        source: &FuseTable,
+    ) -> Result<()> {
+        let source_snapshot = match source.read_table_snapshot().await? {
+            Some(snapshot) => snapshot,
+            // nothing to share, leave the table empty.
+            None => return Ok(()),
+        };
+
+        // 1. reference the shared files.
+        if let Some(missing) = self
+            .register_clone_references(&ctx, &source_snapshot.segments)
+            .await?
+        {
+            return Err(ErrorCode::StorageNotFound(format!(
+                "Cannot clone table {}, the file {} of it has been purged, the cloned table {} is left empty",
+                source.table_info.desc, missing, self.table_info.desc
+            )));
+        }
+
+        // 2. write down the snapshot, which starts a new history.
+        let new_snapshot = TableSnapshot::new(
+            Uuid::new_v4(),
+            &None,
+            None,
+            source_snapshot.schema.clone(),
+            source_snapshot.summary.clone(),
+            source_snapshot.segments.clone(),
+            source_snapshot.cluster_key_meta.clone(),
+            // table statistics are not shared
+            None,
+        );
+        let new_snapshot_loc = self
+            .meta_location_generator
+            .snapshot_location_from_uuid(&new_snapshot.snapshot_id, TableSnapshot::VERSION)?;
+        let bytes = serde_json::to_vec(&new_snapshot)?;
+        self.operator.object(&new_snapshot_loc).write(bytes).await?;
+
+        // 3. commit the snapshot
+        let mut new_table_meta = self.table_info.meta.clone();
+        new_table_meta.options.insert(
+            OPT_KEY_SNAPSHOT_LOCATION.to_owned(),
+            new_snapshot_loc.clone(),
+        );
+        let summary = &new_snapshot.summary;
+        new_table_meta.statistics = TableStatistics {
+            number_of_rows: summary.row_count,
+            data_bytes: summary.uncompressed_byte_size,
+            compressed_data_bytes: summary.compressed_byte_size,
+            index_data_bytes: summary.index_size,
+        };
+
+        let catalog = ctx.get_catalog(self.table_info.catalog())?;
+        catalog
+            .update_table_meta(&self.table_info, UpdateTableMetaReq {
+                table_id: self.table_info.ident.table_id,
+                seq: MatchSeq::Exact(self.table_info.ident.seq),
+                new_table_meta,
+            })
+            .await?;
+
+        // try keep a hit file of last snapshot
+        Self::write_last_snapshot_hint(
+            &self.operator,
+            &self.meta_location_generator,
+            new_snapshot_loc,
+        )
+        .await;
+
+        Ok(())
+    }
+
+    /// Reference the files of other tables used by `segments`, grouped by the table owning them.
+    /// The segments may be shared with the table the owner is cloned from as well.
+    ///
+    /// The references are written while holding the lock on the references of the owner, so the
+    /// gc of the owner either sees them, or has purged the files before they are written. The
+    /// latter is checked once the references are written: the references are removed, and the
+    /// first missing file is returned.
+    async fn register_clone_references(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        segments: &[Location],
+    ) -> Result<Option<String>> {
+        let prefix = self.meta_location_generator.prefix();
+        let locations = self.get_block_locations(ctx.clone(), segments).await?;
+        let mut references: HashMap<&str, CloneReference> = HashMap::new();
+        for (segment, _) in segments {
+            match TableMetaLocationGenerator::location_prefix(segment) {
+                Some(owner) if owner != prefix => {
+                    let reference = references.entry(owner).or_default();
+                    reference.segments.insert(segment.clone());
+                }
+                _ => {}
+            }
+        }
+        for file in locations
+            .block_location
+            .iter()
+            .chain(locations.bloom_location.iter())
+            .chain(locations.deletion_vector_location.iter())
+            .chain(locations.agg_index_location.iter())
+        {
+            match TableMetaLocationGenerator::location_prefix(file) {
+                Some(owner) if owner != prefix => {
+                    let reference = references.entry(owner).or_default();
+                    reference.files.insert(file.clone());
+                }
+                _ => {}
+            }
+        }
+        if references.is_empty() {
+            return Ok(None);
+        }
+
+        let owners = references
+            .keys()
+            .map(|owner| owner.to_string())
+            .collect::<Vec<_>>();
+        CloneReferenceIO::write_owners(
+            &self.operator,
+            &self.meta_location_generator.clone_owners_location(),
+            &owners,
+        )
+        .await?;
+
+        let table_id = self.table_info.ident.table_id;
+        let mut reference_locations = Vec::with_capacity(references.len());
+        for (owner, reference) in &references {
+            let location = TableMetaLocationGenerator::with_prefix(owner.to_string())
+                .clone_reference_location(table_id);
+            let lock = CloneReferenceLock::acquire(owner).await?;
+            CloneReferenceIO::write(&self.operator, &location, reference).await?;
+            lock.release().await?;
+            reference_locations.push(location);
+        }
+
+        let shared_files = references
+            .values()
+            .flat_map(|reference| reference.segments.iter().chain(reference.files.iter()))
+            .collect::<Vec<_>>();
+        let missing = self.find_missing_file(ctx, &shared_files).await?;
+        if missing.is_some() {
+            for location in &reference_locations {
+                self.operator.object(location).delete().await?;
+            }
+        }
+        Ok(missing)
+    }
+
+    /// Release the references of this cloned table to the files of the tables it is cloned from,
+    /// so that the owner tables are able to purge them. It's called once this table is dropped.
+    pub async fn release_clone_references(&self) -> Result<()> {
+        let owners = match CloneReferenceIO::read_owners(
+            &self.operator,
+            &self.meta_location_generator.clone_owners_location(),
+        )
+        .await?
+        {
+            Some(owners) => owners,
+            None => return Ok(()),
+        };
+        let table_id = self.table_info.ident.table_id;
+        for owner in owners {
+            let location =
+                TableMetaLocationGenerator::with_prefix(owner).clone_reference_location(table_id);
+            self.operator.object(&location).delete().await?;
+        }
+        Ok(())
+    }
+
+    /// Reference the shared files of the current snapshot again once this cloned table is
+    /// undropped. Only the files of the current snapshot are referenced, the files used by the
+    /// history of this table may have been purged by the owner tables.
+    pub async fn restore_clone_references(&self, ctx: Arc<dyn TableContext>) -> Result<()> {
+        let owners = CloneReferenceIO::read_owners(
+            &self.operator,
+            &self.meta_location_generator.clone_owners_location(),
+        )
+        .await?;
+        let snapshot = match (owners, self.read_table_snapshot().await?) {
+            (Some(_), Some(snapshot)) => snapshot,
+            _ => return Ok(()),
+        };
+        if let Some(missing) = self
+            .register_clone_references(&ctx, &snapshot.segments)
+            .await?
+        {
+            return Err(ErrorCode::StorageNotFound(format!(
+                "The file {} of the cloned table {} has been purged by the table it is cloned from",
+                missing, self.table_info.desc
+            )));
+        }
+        Ok(())
+    }
+
+    /// Returns the first of `files` which does not exist.
+    async fn find_missing_file(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        files: &[&String],
+    ) -> Result<Option<String>> {
+        let chunk_size = ctx.get_settings().get_max_storage_io_requests()? as usize;
+        for chunk in files.chunks(chunk_size.max(1)) {
+            let exists = try_join_all(
+                chunk
+                    .iter()
+                    .map(|file| self.operator.object(file.as_str()).is_exist()),
+            )
+            .await?;
+            if let Some((file, _)) = chunk.iter().zip(exists).find(|(_, exist)| !exist) {
+                return Ok(Some(file.to_string()));
+            }
+        }
+        Ok(None)
+    }
+}
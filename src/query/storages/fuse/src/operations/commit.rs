@@ -30,6 +30,7 @@ use common_expression::TableSchema;
 use common_expression::TableSchemaRef;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableStatistics;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_types::MatchSeq;
 use common_sql::field_default_value;
@@ -115,7 +116,9 @@ impl FuseTable {
             .with_max_elapsed_time(Some(max_elapsed))
             .build();
 
-        let transient = self.transient();
+        // The historical data of transient table can not be purged before the
+        // transaction commits.
+        let transient = self.transient() && !ctx.txn_mgr().lock().is_active();
         loop {
             match tbl.try_commit(ctx.clone(), &operation_log, overwrite).await {
                 Ok(_) => {
@@ -346,6 +349,18 @@ impl FuseTable {
         table_statistics: Option<TableSnapshotStatistics>,
         operator: &Operator,
     ) -> Result<()> {
+        // the new offsets of the streams consumed by the query are committed along with the table.
+        let stream_offsets = ctx.get_stream_offsets();
+        if let Some((stream_catalog, _)) = stream_offsets
+            .iter()
+            .find(|(catalog, _)| catalog != &table_info.meta.catalog)
+        {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Consuming a stream of catalog {} while writing to catalog {} is not supported",
+                stream_catalog, table_info.meta.catalog
+            )));
+        }
+
         let snapshot_location = location_generator
             .snapshot_location_from_uuid(&snapshot.snapshot_id, snapshot.format_version())?;
         let need_to_save_statistics =
@@ -395,8 +410,31 @@ impl FuseTable {
             new_table_meta,
         };
 
-        // 3. let's roll
-        let reply = catalog.update_table_meta(table_info, req).await;
+        // 3. in an explicit transaction, the new table meta is committed with the transaction.
+        {
+            let txn_mgr = ctx.txn_mgr();
+            let mut txn_mgr = txn_mgr.lock();
+            if txn_mgr.is_active() {
+                txn_mgr.update_table_meta(&table_info.meta.catalog, req);
+                for (stream_catalog, stream_req) in stream_offsets {
+                    txn_mgr.update_table_meta(&stream_catalog, stream_req);
+                }
+                TableSnapshot::cache().put(snapshot_location, Arc::new(snapshot));
+                return Ok(());
+            }
+        }
+
+        // 4. let's roll
+        let reply = if stream_offsets.is_empty() {
+            catalog.update_table_meta(table_info, req).await.map(|_| ())
+        } else {
+            let mut update_table_metas = vec![req];
+            update_table_metas.extend(stream_offsets.into_iter().map(|(_, req)| req));
+            catalog
+                .update_multi_table_meta(UpdateMultiTableMetaReq { update_table_metas })
+                .await
+                .map(|_| ())
+        };
         match reply {
             Ok(_) => {
                 // upsert snapshot statistics cache
@@ -143,6 +143,7 @@ impl FuseTable {
         let all_column_indices = self.all_column_indices();
         let projection = Projection::Columns(all_column_indices);
         let block_reader = self.create_block_reader(projection, ctx.clone())?;
+        let agg_indexes = self.aggregating_indexes()?;
 
         pipeline.add_transform(|input, output| {
             CompactTransform::try_create(
@@ -154,6 +155,7 @@ impl FuseTable {
                 self.meta_location_generator().clone(),
                 self.operator.clone(),
                 self.schema(),
+                agg_indexes.clone(),
                 thresholds,
                 self.get_write_settings(),
             )
@@ -193,7 +193,7 @@ impl FuseTable {
         base_snapshot: &TableSnapshot,
         pipeline: &mut Pipeline,
     ) -> Result<()> {
-        let projection = Projection::Columns(col_indices.clone());
+        let projection = Projection::Columns(col_indices);
         self.mutation_block_pruning(
             ctx.clone(),
             Some(filter.clone()),
@@ -210,29 +210,9 @@ impl FuseTable {
                 .project_column_ref(|name| schema.index_of(name).unwrap()),
         ));
 
-        let all_column_indices = self.all_column_indices();
-        let remain_column_indices: Vec<usize> = all_column_indices
-            .into_iter()
-            .filter(|index| !col_indices.contains(index))
-            .collect();
-        let mut source_col_indices = col_indices;
-        let remain_reader = if remain_column_indices.is_empty() {
-            Arc::new(None)
-        } else {
-            source_col_indices.extend_from_slice(&remain_column_indices);
-            Arc::new(Some(
-                (*self.create_block_reader(
-                    Projection::Columns(remain_column_indices),
-                    ctx.clone(),
-                )?)
-                .clone(),
-            ))
-        };
-
-        // resort the block.
-        let mut projection = (0..source_col_indices.len()).collect::<Vec<_>>();
-        projection.sort_by_key(|&i| source_col_indices[i]);
-        let ops = vec![BlockOperator::Project { projection }];
+        // The matched rows are marked as deleted by the deletion vector of the block,
+        // only the columns referenced by the filter need to be read.
+        let remain_reader = Arc::new(None);
 
         let max_threads = ctx.get_settings().get_max_threads()? as usize;
         // Add source pipe.
@@ -245,7 +225,7 @@ impl FuseTable {
                     filter.clone(),
                     block_reader.clone(),
                     remain_reader.clone(),
-                    ops.clone(),
+                    vec![],
                     self.storage_format,
                 )
             },
@@ -38,9 +38,11 @@ use storages_common_table_meta::meta::ColumnMeta;
 use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::SegmentInfo;
 use storages_common_table_meta::meta::Statistics;
+use storages_common_table_meta::table::AggregatingIndex;
 use storages_common_table_meta::table::TableCompression;
 
 use super::AppendOperationLogEntry;
+use crate::aggregating_index::AggIndexState;
 use crate::io;
 use crate::io::TableMetaLocationGenerator;
 use crate::io::WriteSettings;
@@ -110,6 +112,7 @@ enum State {
         meta_data: HashMap<ColumnId, ColumnMeta>,
         block_statistics: BlockStatistics,
         bloom_index_state: Option<BloomIndexState>,
+        agg_index_states: Vec<AggIndexState>,
     },
     GenerateSegment,
     SerializedSegment {
@@ -134,6 +137,7 @@ pub struct FuseTableSink {
     cluster_stats_gen: ClusterStatsGenerator,
 
     source_schema: TableSchemaRef,
+    agg_indexes: Vec<AggregatingIndex>,
     write_settings: WriteSettings,
     // A dummy output port for distributed insert select to connect Exchange Sink.
     output: Option<Arc<OutputPort>>,
@@ -150,6 +154,7 @@ impl FuseTableSink {
         cluster_stats_gen: ClusterStatsGenerator,
         thresholds: BlockThresholds,
         source_schema: TableSchemaRef,
+        agg_indexes: Vec<AggregatingIndex>,
         output: Option<Arc<OutputPort>>,
     ) -> Result<ProcessorPtr> {
         Ok(ProcessorPtr::create(Box::new(FuseTableSink {
@@ -162,6 +167,7 @@ impl FuseTableSink {
             write_settings,
             cluster_stats_gen,
             source_schema,
+            agg_indexes,
             output,
         })))
     }
@@ -239,6 +245,14 @@ impl Processor for FuseTableSink {
                     &self.source_schema,
                 )?;
 
+                let agg_index_states = AggIndexState::try_create_all(
+                    &self.agg_indexes,
+                    &self.source_schema,
+                    &block,
+                    &self.meta_locations,
+                    &block_id,
+                )?;
+
                 // we need a configuration of block size threshold here
                 let mut data = Vec::with_capacity(DEFAULT_BLOCK_BUFFER_SIZE);
                 let (size, meta_data) =
@@ -250,6 +264,7 @@ impl Processor for FuseTableSink {
                     block_statistics,
                     meta_data,
                     bloom_index_state,
+                    agg_index_states,
                 };
             }
             State::GenerateSegment => {
@@ -298,6 +313,7 @@ impl Processor for FuseTableSink {
                 meta_data,
                 block_statistics,
                 bloom_index_state,
+                agg_index_states,
             } => {
                 let start = Instant::now();
 
@@ -337,6 +353,9 @@ impl Processor for FuseTableSink {
                     }
                 }
 
+                // write aggregating indexes
+                AggIndexState::write_all(&agg_index_states, &self.data_accessor).await?;
+
                 let (bloom_index_location, bloom_index_size) =
                     if let Some(bloom_index_state) = bloom_index_state {
                         (
@@ -354,6 +373,7 @@ impl Processor for FuseTableSink {
                     bloom_index_location,
                     bloom_index_size,
                     self.write_settings.table_compression.into(),
+                    AggIndexState::locations(&agg_index_states),
                 )?;
 
                 if self.accumulator.summary_block_count >= self.write_settings.block_per_seg as u64
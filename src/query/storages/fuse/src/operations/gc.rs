@@ -36,16 +36,77 @@ use storages_common_table_meta::meta::TableSnapshotStatistics;
 use tracing::info;
 use tracing::warn;
 
+use crate::io::CloneReference;
+use crate::io::CloneReferenceIO;
+use crate::io::CloneReferenceLock;
 use crate::io::Files;
 use crate::io::ListSnapshotLiteOption;
 use crate::io::SegmentsIO;
 use crate::io::SnapshotsIO;
+use crate::io::TableMetaLocationGenerator;
 use crate::FuseTable;
+use crate::FUSE_TBL_XOR_BLOOM_INDEX_PREFIX;
 
 #[derive(Default)]
-struct LocationTuple {
-    block_location: HashSet<String>,
-    bloom_location: HashSet<String>,
+pub(crate) struct LocationTuple {
+    pub(crate) block_location: HashSet<String>,
+    pub(crate) bloom_location: HashSet<String>,
+    pub(crate) deletion_vector_location: HashSet<String>,
+    pub(crate) agg_index_location: HashSet<String>,
+}
+
+// The files that are shared with other tables by cloning.
+struct SharedLocations {
+    prefix: String,
+    // files of this table that are referenced by the tables cloned from it.
+    referenced_by_clones: CloneReference,
+    // files of this table that are kept for the tables cloned from it, which are purged by a later
+    // gc once the references to them are released.
+    retained: CloneReference,
+    // files of the tables that this table is cloned from, which are no longer used by this table,
+    // grouped by the prefix of the owner table.
+    released: HashMap<String, CloneReference>,
+}
+
+impl SharedLocations {
+    // Returns true if the segment is shared with other tables and must not be purged.
+    fn is_shared_segment(&mut self, location: &str) -> bool {
+        if self.referenced_by_clones.segments.contains(location) {
+            self.retained.segments.insert(location.to_string());
+            return true;
+        }
+        match self.foreign_prefix(location) {
+            Some(owner) => {
+                let released = self.released.entry(owner).or_default();
+                released.segments.insert(location.to_string());
+                true
+            }
+            None => false,
+        }
+    }
+
+    // Returns true if the block, bloom index, deletion vector or aggregating index file is shared
+    // with other tables and must not be purged.
+    fn is_shared_file(&mut self, location: &str) -> bool {
+        if self.referenced_by_clones.files.contains(location) {
+            self.retained.files.insert(location.to_string());
+            return true;
+        }
+        match self.foreign_prefix(location) {
+            Some(owner) => {
+                let released = self.released.entry(owner).or_default();
+                released.files.insert(location.to_string());
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn foreign_prefix(&self, location: &str) -> Option<String> {
+        TableMetaLocationGenerator::location_prefix(location)
+            .filter(|prefix| *prefix != self.prefix)
+            .map(|prefix| prefix.to_string())
+    }
 }
 
 impl FuseTable {
@@ -69,6 +130,15 @@ impl FuseTable {
             Ok(v) => v,
         };
 
+        // 0. Files shared with other tables by cloning, the references are read right before
+        // purging each chunk of segments.
+        let mut shared_locations = SharedLocations {
+            prefix: self.meta_location_generator.prefix().to_string(),
+            referenced_by_clones: CloneReference::default(),
+            retained: CloneReference::default(),
+            released: HashMap::new(),
+        };
+
         // 1. Root snapshot.
         let mut segments_referenced_by_root = HashSet::new();
         let mut locations_referenced_by_root = Default::default();
@@ -180,6 +250,8 @@ impl FuseTable {
                 if keep_last_snapshot && segments_referenced_by_root.contains(segment) {
                     continue;
                 }
+                // The segments referenced by the tables cloned from this table are skipped
+                // when purging the chunks.
                 segments_to_be_purged.insert(segment.clone());
             }
         }
@@ -219,8 +291,18 @@ impl FuseTable {
             let segment_locations = Vec::from_iter(segments_to_be_purged);
             for chunk in segment_locations.chunks(chunk_size) {
                 let locations = self.get_block_locations(ctx.clone(), chunk).await?;
+                // Hold the lock on the references until the chunk is purged, so no table starts
+                // to reference the files of the chunk meanwhile, see `register_clone_references`.
+                let mut lock =
+                    CloneReferenceLock::acquire(self.meta_location_generator.prefix()).await?;
+                shared_locations.referenced_by_clones = CloneReferenceIO::read_all(
+                    &self.operator,
+                    &self.meta_location_generator.clone_reference_prefix(),
+                )
+                .await?;
 
-                // 1. Try to purge block file chunks.
+                // 1. Try to purge block file chunks, deletion vectors and aggregating indexes go
+                // along with their blocks.
                 {
                     let mut block_locations_to_be_pruged = HashSet::new();
                     for loc in &locations.block_location {
@@ -229,9 +311,39 @@ impl FuseTable {
                         {
                             continue;
                         }
+                        if shared_locations.is_shared_file(loc) {
+                            continue;
+                        }
+                        block_locations_to_be_pruged.insert(loc.to_string());
+                    }
+                    for loc in &locations.deletion_vector_location {
+                        if keep_last_snapshot
+                            && locations_referenced_by_root
+                                .deletion_vector_location
+                                .contains(loc)
+                        {
+                            continue;
+                        }
+                        if shared_locations.is_shared_file(loc) {
+                            continue;
+                        }
+                        block_locations_to_be_pruged.insert(loc.to_string());
+                    }
+                    for loc in &locations.agg_index_location {
+                        if keep_last_snapshot
+                            && locations_referenced_by_root
+                                .agg_index_location
+                                .contains(loc)
+                        {
+                            continue;
+                        }
+                        if shared_locations.is_shared_file(loc) {
+                            continue;
+                        }
                         block_locations_to_be_pruged.insert(loc.to_string());
                     }
                     status_block_to_be_purged_count += block_locations_to_be_pruged.len();
+                    lock.extend().await?;
                     self.try_purge_location_files(ctx.clone(), block_locations_to_be_pruged)
                         .await?;
                 }
@@ -245,9 +357,13 @@ impl FuseTable {
                         {
                             continue;
                         }
+                        if shared_locations.is_shared_file(loc) {
+                            continue;
+                        }
                         bloom_locations_to_be_pruged.insert(loc.to_string());
                     }
                     status_bloom_to_be_purged_count += bloom_locations_to_be_pruged.len();
+                    lock.extend().await?;
                     self.try_purge_location_files_and_cache::<BloomIndexMeta>(
                         ctx.clone(),
                         bloom_locations_to_be_pruged,
@@ -260,15 +376,18 @@ impl FuseTable {
                     let segment_locations_to_be_purged = HashSet::from_iter(
                         chunk
                             .iter()
+                            .filter(|loc| !shared_locations.is_shared_segment(&loc.0))
                             .map(|loc| loc.0.clone())
                             .collect::<Vec<String>>(),
                     );
+                    lock.extend().await?;
                     self.try_purge_location_files_and_cache::<SegmentInfo>(
                         ctx.clone(),
                         segment_locations_to_be_purged,
                     )
                     .await?;
                 }
+                lock.release().await?;
 
                 // Refresh status.
                 {
@@ -286,6 +405,26 @@ impl FuseTable {
                     info!(status);
                 }
             }
+
+            // Purge the files kept for the tables cloned from this table, which are no longer
+            // referenced by them.
+            self.purge_retained_files(
+                &ctx,
+                shared_locations.retained,
+                keep_last_snapshot,
+                &segments_referenced_by_root,
+                &locations_referenced_by_root,
+            )
+            .await?;
+
+            // Release the files of the tables this table is cloned from, which are no longer
+            // used by this table, so that the owner table is able to purge them.
+            let table_id = self.table_info.ident.table_id;
+            for (owner_prefix, released) in shared_locations.released {
+                let location = TableMetaLocationGenerator::with_prefix(owner_prefix)
+                    .clone_reference_location(table_id);
+                CloneReferenceIO::release(&self.operator, &location, &released).await?;
+            }
         }
 
         // 5. Purge snapshots by chunk size(max_storage_io_requests).
@@ -397,6 +536,80 @@ impl FuseTable {
     }
 
     // Purge file by location chunks.
+    // Purge the files kept for the tables cloned from this table which are no longer referenced
+    // by them, and keep the others along with `retained`, the files kept by this gc.
+    async fn purge_retained_files(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        mut retained: CloneReference,
+        keep_last_snapshot: bool,
+        segments_referenced_by_root: &HashSet<Location>,
+        locations_referenced_by_root: &LocationTuple,
+    ) -> Result<()> {
+        let location = self.meta_location_generator.clone_retained_location();
+        let mut lock = CloneReferenceLock::acquire(self.meta_location_generator.prefix()).await?;
+        let previous = CloneReferenceIO::read(&self.operator, &location).await?;
+        if previous.is_empty() && retained.is_empty() {
+            return lock.release().await;
+        }
+        let referenced_by_clones = CloneReferenceIO::read_all(
+            &self.operator,
+            &self.meta_location_generator.clone_reference_prefix(),
+        )
+        .await?;
+
+        // The files used by the root snapshot are purged along with the snapshot by a later gc.
+        let root_segments = segments_referenced_by_root
+            .iter()
+            .map(|loc| &loc.0)
+            .collect::<HashSet<_>>();
+        let is_used_by_root = |loc: &String| {
+            keep_last_snapshot
+                && (root_segments.contains(loc)
+                    || locations_referenced_by_root.block_location.contains(loc)
+                    || locations_referenced_by_root.bloom_location.contains(loc)
+                    || locations_referenced_by_root
+                        .deletion_vector_location
+                        .contains(loc)
+                    || locations_referenced_by_root
+                        .agg_index_location
+                        .contains(loc))
+        };
+
+        let mut segments_to_be_purged = HashSet::new();
+        for segment in previous.segments {
+            if referenced_by_clones.segments.contains(&segment) {
+                retained.segments.insert(segment);
+            } else if !is_used_by_root(&segment) {
+                segments_to_be_purged.insert(segment);
+            }
+        }
+        let bloom_dir = format!("/{}/", FUSE_TBL_XOR_BLOOM_INDEX_PREFIX);
+        let mut blooms_to_be_purged = HashSet::new();
+        let mut files_to_be_purged = HashSet::new();
+        for file in previous.files {
+            if referenced_by_clones.files.contains(&file) {
+                retained.files.insert(file);
+            } else if is_used_by_root(&file) {
+                continue;
+            } else if file.contains(&bloom_dir) {
+                blooms_to_be_purged.insert(file);
+            } else {
+                files_to_be_purged.insert(file);
+            }
+        }
+
+        lock.extend().await?;
+        self.try_purge_location_files(ctx.clone(), files_to_be_purged)
+            .await?;
+        self.try_purge_location_files_and_cache::<BloomIndexMeta>(ctx.clone(), blooms_to_be_purged)
+            .await?;
+        self.try_purge_location_files_and_cache::<SegmentInfo>(ctx.clone(), segments_to_be_purged)
+            .await?;
+        CloneReferenceIO::write_or_delete(&self.operator, &location, &retained).await?;
+        lock.release().await
+    }
+
     async fn try_purge_location_files(
         &self,
         ctx: Arc<dyn TableContext>,
@@ -425,13 +638,15 @@ impl FuseTable {
             .await
     }
 
-    async fn get_block_locations(
+    pub(crate) async fn get_block_locations(
         &self,
         ctx: Arc<dyn TableContext>,
         segment_locations: &[Location],
     ) -> Result<LocationTuple> {
         let mut blocks = HashSet::new();
         let mut blooms = HashSet::new();
+        let mut deletion_vectors = HashSet::new();
+        let mut agg_indexes = HashSet::new();
 
         let fuse_segments = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
         let segments = fuse_segments.read_segments(segment_locations).await?;
@@ -459,12 +674,23 @@ impl FuseTable {
                         .unwrap_or_default()
                         .0,
                 );
+                if let Some(deletion_vector) = &block_meta.deletion_vector {
+                    deletion_vectors.insert(deletion_vector.location.0.clone());
+                }
+                agg_indexes.extend(
+                    block_meta
+                        .agg_index_locations
+                        .values()
+                        .map(|location| location.0.clone()),
+                );
             }
         }
 
         Ok(LocationTuple {
             block_location: blocks,
             bloom_location: blooms,
+            deletion_vector_location: deletion_vectors,
+            agg_index_location: agg_indexes,
         })
     }
 }
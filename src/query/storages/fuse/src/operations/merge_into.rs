@@ -0,0 +1,159 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_base::runtime::GlobalIORuntime;
+use common_catalog::plan::MergeIntoInfo;
+use common_catalog::plan::Projection;
+use common_catalog::table::AppendMode;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::RemoteExpr;
+use common_pipeline_sources::BlocksSource;
+use parking_lot::Mutex;
+
+use crate::operations::mutation::MergeIntoState;
+use crate::operations::mutation::MergeIntoTransform;
+use crate::operations::mutation::MutationAction;
+use crate::operations::mutation::MutationSink;
+use crate::operations::mutation::MutationSource;
+use crate::operations::mutation::SerializeDataTransform;
+use crate::pipelines::Pipeline;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// MERGE INTO target USING source ON ... WHEN MATCHED ... WHEN NOT MATCHED ...
+    /// The blocks of the table are read as the update does, and joined with the source,
+    /// the matched rows are updated or deleted, and the inserted rows are written into a
+    /// new segment, all the changes are committed in one snapshot.
+    pub async fn do_merge_into(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        merge_into_info: MergeIntoInfo,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        self.merge_into_with_filter(ctx, merge_into_info, None, pipeline)
+            .await
+    }
+
+    /// Merge the source into the blocks of the table which are not pruned by the filter.
+    pub(crate) async fn merge_into_with_filter(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        merge_into_info: MergeIntoInfo,
+        filter: Option<RemoteExpr<String>>,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        if merge_into_info.source.num_rows() == 0 {
+            // no source rows, nothing to merge
+            return Ok(());
+        }
+
+        let state = MergeIntoState::try_create(
+            ctx.get_function_context()?,
+            merge_into_info,
+            self.schema().num_fields(),
+            self.get_block_compact_thresholds().max_rows_per_block,
+        )?;
+
+        let snapshot = match self.read_table_snapshot().await? {
+            Some(snapshot) if snapshot.summary.row_count != 0 => snapshot,
+            _ => {
+                // empty table, all the source rows are unmatched.
+                return self.try_add_merge_into_insertion(ctx, state, pipeline);
+            }
+        };
+
+        let projection = Projection::Columns(self.all_column_indices());
+        self.mutation_block_pruning(ctx.clone(), filter, projection.clone(), &snapshot)
+            .await?;
+
+        let block_reader = self.create_block_reader(projection, ctx.clone())?;
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        pipeline.add_source(
+            |output| {
+                MutationSource::try_create(
+                    ctx.clone(),
+                    MutationAction::Update,
+                    output,
+                    Arc::new(None),
+                    block_reader.clone(),
+                    Arc::new(None),
+                    vec![],
+                    self.storage_format,
+                )
+            },
+            max_threads,
+        )?;
+
+        pipeline.add_transform(|input, output| {
+            MergeIntoTransform::try_create(state.clone(), input, output)
+        })?;
+
+        let cluster_stats_gen = self.cluster_stats_gen(ctx.clone())?;
+        pipeline.add_transform(|input, output| {
+            SerializeDataTransform::try_create(
+                ctx.clone(),
+                input,
+                output,
+                self,
+                cluster_stats_gen.clone(),
+            )
+        })?;
+
+        self.try_add_mutation_transform(ctx.clone(), snapshot.segments.clone(), pipeline)?;
+
+        pipeline.add_sink(|input| {
+            MutationSink::try_create(self, ctx.clone(), snapshot.clone(), input)
+        })?;
+        Ok(())
+    }
+
+    fn try_add_merge_into_insertion(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        state: Arc<MergeIntoState>,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let blocks = state.unmatched_blocks()?;
+        if blocks.is_empty() {
+            return Ok(());
+        }
+
+        let blocks = Arc::new(Mutex::new(VecDeque::from(blocks)));
+        pipeline.add_source(
+            |output| BlocksSource::create(ctx.clone(), output, blocks.clone()),
+            1,
+        )?;
+        self.do_append_data(ctx.clone(), pipeline, AppendMode::Normal, false)?;
+
+        let table: Arc<dyn Table> = Arc::new(self.clone());
+        pipeline.set_on_finished(move |may_error| {
+            if may_error.is_none() {
+                let ctx = ctx.clone();
+                let table = table.clone();
+                let append_entries = ctx.consume_precommit_blocks();
+                return GlobalIORuntime::instance().block_on(async move {
+                    table.commit_insertion(ctx, append_entries, false).await
+                });
+            }
+
+            Err(may_error.as_ref().unwrap().clone())
+        });
+        Ok(())
+    }
+}
@@ -14,17 +14,21 @@
 
 mod analyze;
 mod append;
+mod changes;
+mod clone;
 mod commit;
 mod compact;
 mod delete;
 mod fuse_sink;
 mod gc;
+mod merge_into;
 mod mutation;
 mod navigate;
 mod operation_log;
 mod read_data;
 mod read_partitions;
 mod recluster;
+mod replace_into;
 mod truncate;
 mod update;
 
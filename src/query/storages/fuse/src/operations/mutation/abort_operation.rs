@@ -26,6 +26,10 @@ pub struct AbortOperation {
     pub segments: Vec<String>,
     pub blocks: Vec<String>,
     pub bloom_filter_indexes: Vec<String>,
+    #[serde(default)]
+    pub deletion_vectors: Vec<String>,
+    #[serde(default)]
+    pub agg_indexes: Vec<String>,
 }
 
 impl AbortOperation {
@@ -34,6 +38,8 @@ impl AbortOperation {
         self.blocks.extend(rhs.blocks.clone());
         self.bloom_filter_indexes
             .extend(rhs.bloom_filter_indexes.clone());
+        self.deletion_vectors.extend(rhs.deletion_vectors.clone());
+        self.agg_indexes.extend(rhs.agg_indexes.clone());
     }
 
     pub fn add_block(&mut self, block: &BlockMeta) {
@@ -42,6 +48,16 @@ impl AbortOperation {
         if let Some(index) = block.bloom_filter_index_location.clone() {
             self.bloom_filter_indexes.push(index.0);
         }
+        self.agg_indexes.extend(
+            block
+                .agg_index_locations
+                .values()
+                .map(|location| location.0.clone()),
+        );
+    }
+
+    pub fn add_deletion_vector(&mut self, deletion_vector: String) {
+        self.deletion_vectors.push(deletion_vector);
     }
 
     pub fn add_segment(&mut self, segment: String) {
@@ -54,6 +70,8 @@ impl AbortOperation {
             .blocks
             .into_iter()
             .chain(self.bloom_filter_indexes.into_iter())
+            .chain(self.deletion_vectors.into_iter())
+            .chain(self.agg_indexes.into_iter())
             .chain(self.segments.into_iter());
         fuse_file.remove_file_in_batch(locations).await
     }
@@ -167,6 +167,11 @@ impl CompactPartBuilder {
         segments.len() != 1
             || (segments[0].summary.block_count > 1
                 && segments[0].summary.perfect_block_count != segments[0].summary.block_count)
+            // fold the deleted rows back into rewritten blocks.
+            || segments[0]
+                .blocks
+                .iter()
+                .any(|block| block.deletion_vector.is_some())
     }
 
     fn add(&mut self, segment: Arc<SegmentInfo>) -> Vec<Vec<Arc<SegmentInfo>>> {
@@ -160,7 +160,7 @@ impl CompactTaskBuilder {
     }
 
     fn add(&mut self, block: &Arc<BlockMeta>, thresholds: BlockThresholds) -> Vec<CompactTask> {
-        self.total_rows += block.row_count as usize;
+        self.total_rows += block.live_row_count() as usize;
         self.total_size += block.block_size as usize;
 
         if !thresholds.check_large_enough(self.total_rows, self.total_size) {
@@ -34,11 +34,13 @@ use storages_common_index::BloomIndex;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::SegmentInfo;
 use storages_common_table_meta::meta::StatisticsOfColumns;
+use storages_common_table_meta::table::AggregatingIndex;
 use storages_common_table_meta::table::TableCompression;
 
 use super::compact_meta::CompactSourceMeta;
 use super::compact_part::CompactTask;
 use super::CompactSinkMeta;
+use crate::aggregating_index::AggIndexState;
 use crate::io;
 use crate::io::write_data;
 use crate::io::BlockReader;
@@ -91,6 +93,7 @@ pub struct CompactTransform {
     location_gen: TableMetaLocationGenerator,
     dal: Operator,
     schema: TableSchemaRef,
+    agg_indexes: Vec<AggregatingIndex>,
 
     // Limit the memory size of the block read.
     max_memory: u64,
@@ -114,6 +117,7 @@ impl CompactTransform {
         location_gen: TableMetaLocationGenerator,
         dal: Operator,
         schema: TableSchemaRef,
+        agg_indexes: Vec<AggregatingIndex>,
         thresholds: BlockThresholds,
         write_settings: WriteSettings,
     ) -> Result<ProcessorPtr> {
@@ -133,6 +137,7 @@ impl CompactTransform {
             location_gen,
             dal,
             schema,
+            agg_indexes,
             max_memory,
             max_io_requests,
             compact_tasks: VecDeque::new(),
@@ -264,6 +269,15 @@ impl Processor for CompactTransform {
                         None => (None, 0u64, None),
                     };
 
+                    // build aggregating indexes.
+                    let agg_index_states = AggIndexState::try_create_all(
+                        &self.agg_indexes,
+                        &self.schema,
+                        &new_block,
+                        &self.location_gen,
+                        &block_id,
+                    )?;
+
                     // serialize data block.
                     let mut block_data = Vec::with_capacity(DEFAULT_BLOCK_BUFFER_SIZE);
                     let (file_size, col_metas) = io::write_block(
@@ -285,7 +299,8 @@ impl Processor for CompactTransform {
                         index_location.clone(),
                         index_size,
                         self.write_settings.table_compression.into(),
-                    );
+                    )
+                    .with_agg_index_locations(AggIndexState::locations(&agg_index_states));
                     self.abort_operation.add_block(&new_meta);
                     self.block_metas.push(Arc::new(new_meta));
 
@@ -294,6 +309,7 @@ impl Processor for CompactTransform {
                         block_location: block_location.0,
                         index_data,
                         index_location: index_location.map(|l| l.0),
+                        agg_index_states,
                     });
                 }
                 self.state = State::SerializedBlocks(serialize_states);
@@ -342,8 +358,15 @@ impl Processor for CompactTransform {
                 let block_reader = self.block_reader.as_ref();
                 while let Some(task) = self.compact_tasks.pop_front() {
                     let metas = task.get_block_metas();
-                    // Only one block, no need to do a compact.
-                    if metas.len() == 1 {
+                    // Only one block, no need to do a compact, unless there are deleted rows
+                    // to be folded into a rewritten block, or aggregating indexes to be built.
+                    if metas.len() == 1
+                        && metas[0].deletion_vector.is_none()
+                        && self
+                            .agg_indexes
+                            .iter()
+                            .all(|index| metas[0].agg_index_locations.contains_key(&index.id))
+                    {
                         stats_of_columns.push(vec![]);
                         trivals.push_back(metas[0].clone());
                         continue;
@@ -365,7 +388,7 @@ impl Processor for CompactTransform {
                     let mut meta_stats = Vec::with_capacity(metas.len());
                     for meta in metas {
                         let progress_values = ProgressValues {
-                            rows: meta.row_count as usize,
+                            rows: meta.live_row_count() as usize,
                             bytes: meta.block_size as usize,
                         };
                         self.scan_progress.incr(&progress_values);
@@ -421,6 +444,8 @@ impl Processor for CompactTransform {
                         {
                             write_data(&index_data, dal, &index_location).await?;
                         }
+                        // write aggregating indexes.
+                        AggIndexState::write_all(&state.agg_index_states, dal).await?;
                         // write block data.
                         write_data(&state.block_data, dal, &state.block_location).await
                     });
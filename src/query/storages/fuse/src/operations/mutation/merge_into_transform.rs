@@ -0,0 +1,383 @@
+//  Copyright 2023 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_catalog::plan::MergeIntoInfo;
+use common_catalog::plan::MergeIntoSource;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::BooleanType;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FieldIndex;
+use common_expression::FunctionContext;
+use common_expression::Value;
+use common_functions::scalars::BUILTIN_FUNCTIONS;
+
+use crate::operations::mutation::AppendDataMeta;
+use crate::pipelines::processors::port::InputPort;
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::processors::processor::Event;
+use crate::pipelines::processors::processor::ProcessorPtr;
+use crate::pipelines::processors::Processor;
+
+struct MatchedOperation {
+    condition: Option<Expr>,
+    // None means delete.
+    update_list: Option<Vec<(FieldIndex, Expr)>>,
+}
+
+struct UnmatchedOperation {
+    condition: Option<Expr>,
+    values: Vec<Expr>,
+}
+
+/// The state of MERGE INTO shared by all the [`MergeIntoTransform`]s.
+///
+/// Every block of the table is joined with the [`MergeIntoSource`], which records the
+/// source rows that have been matched, so the last transform to finish can insert the
+/// rows that were never matched.
+pub struct MergeIntoState {
+    func_ctx: FunctionContext,
+    num_fields: usize,
+    max_rows_per_block: usize,
+
+    source: Arc<dyn MergeIntoSource>,
+    matched: Vec<MatchedOperation>,
+    unmatched: Vec<UnmatchedOperation>,
+
+    attached: AtomicUsize,
+}
+
+impl MergeIntoState {
+    pub fn try_create(
+        func_ctx: FunctionContext,
+        merge_into_info: MergeIntoInfo,
+        num_fields: usize,
+        max_rows_per_block: usize,
+    ) -> Result<Arc<Self>> {
+        let MergeIntoInfo {
+            source,
+            matched,
+            unmatched,
+        } = merge_into_info;
+
+        let matched = matched
+            .into_iter()
+            .map(|op| MatchedOperation {
+                condition: op.condition.map(|expr| expr.as_expr(&BUILTIN_FUNCTIONS)),
+                update_list: op.update_list.map(|update_list| {
+                    update_list
+                        .into_iter()
+                        .map(|(idx, expr)| (idx, expr.as_expr(&BUILTIN_FUNCTIONS)))
+                        .collect()
+                }),
+            })
+            .collect();
+        let unmatched = unmatched
+            .into_iter()
+            .map(|op| UnmatchedOperation {
+                condition: op.condition.map(|expr| expr.as_expr(&BUILTIN_FUNCTIONS)),
+                values: op
+                    .values
+                    .iter()
+                    .map(|expr| expr.as_expr(&BUILTIN_FUNCTIONS))
+                    .collect(),
+            })
+            .collect();
+
+        Ok(Arc::new(MergeIntoState {
+            func_ctx,
+            num_fields,
+            max_rows_per_block,
+            source,
+            matched,
+            unmatched,
+            attached: AtomicUsize::new(0),
+        }))
+    }
+
+    fn attach(&self) {
+        self.attached.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Returns true if it is the last one to detach.
+    fn detach(&self) -> bool {
+        self.attached.fetch_sub(1, Ordering::SeqCst) == 1
+    }
+
+    /// Apply the MATCHED clauses to a block of the table.
+    ///
+    /// Returns `None` if the block is not changed, otherwise returns the new block, which
+    /// is empty if all the rows are deleted.
+    pub fn merge_block(&self, block: &DataBlock) -> Result<Option<DataBlock>> {
+        let num_rows = block.num_rows();
+        let (target_rows, pairs) = self.source.probe(block)?;
+        if target_rows.is_empty() {
+            return Ok(None);
+        }
+
+        let mut matched = vec![false; num_rows];
+        for row in &target_rows {
+            if matched[*row as usize] {
+                return Err(ErrorCode::BadArguments(
+                    "Duplicate rows in the source of MERGE INTO matched the same row of the target table",
+                ));
+            }
+            matched[*row as usize] = true;
+        }
+
+        let conditions = self
+            .matched
+            .iter()
+            .map(|op| op.condition.as_ref())
+            .collect::<Vec<_>>();
+        let assigned = assign_operations(&self.func_ctx, &pairs, &conditions)?;
+        if assigned.iter().all(|op| op.is_empty()) {
+            return Ok(None);
+        }
+
+        // The rows which are updated or deleted will be removed from the block.
+        let mut removed = vec![false; num_rows];
+        for rows in &assigned {
+            for row in rows {
+                removed[target_rows[*row as usize] as usize] = true;
+            }
+        }
+        let remain_rows = (0..num_rows as u32)
+            .filter(|row| !removed[*row as usize])
+            .collect::<Vec<_>>();
+
+        let mut blocks = vec![block.take(&remain_rows)?.convert_to_full()];
+        for (op, rows) in self.matched.iter().zip(assigned.iter()) {
+            let update_list = match &op.update_list {
+                Some(update_list) if !rows.is_empty() => update_list,
+                _ => continue,
+            };
+            let updated = pairs.take(rows)?;
+            let mut entries = (0..self.num_fields)
+                .map(|idx| updated.get_by_offset(idx).clone())
+                .collect::<Vec<_>>();
+            for (idx, expr) in update_list {
+                let column = eval_column(&self.func_ctx, &updated, expr)?;
+                entries[*idx] = BlockEntry {
+                    data_type: expr.data_type().clone(),
+                    value: Value::Column(column),
+                };
+            }
+            blocks.push(DataBlock::new(entries, rows.len()).convert_to_full());
+        }
+        Ok(Some(DataBlock::concat(&blocks)?))
+    }
+
+    /// Apply the NOT MATCHED clauses to the source rows which are not matched by any row
+    /// of the table.
+    pub fn unmatched_blocks(&self) -> Result<Vec<DataBlock>> {
+        if self.unmatched.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let conditions = self
+            .unmatched
+            .iter()
+            .map(|op| op.condition.as_ref())
+            .collect::<Vec<_>>();
+        let max_rows = self.max_rows_per_block.max(1);
+        let mut blocks = vec![];
+        for unmatched in self.source.unmatched_blocks()? {
+            let assigned = assign_operations(&self.func_ctx, &unmatched, &conditions)?;
+            for (op, rows) in self.unmatched.iter().zip(assigned.iter()) {
+                if rows.is_empty() {
+                    continue;
+                }
+                let inserted = unmatched.take(rows)?;
+                let entries = op
+                    .values
+                    .iter()
+                    .map(|expr| {
+                        Ok(BlockEntry {
+                            data_type: expr.data_type().clone(),
+                            value: Value::Column(eval_column(&self.func_ctx, &inserted, expr)?),
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                let block = DataBlock::new(entries, rows.len());
+                let num_rows = block.num_rows();
+                blocks.extend(
+                    (0..num_rows)
+                        .step_by(max_rows)
+                        .map(|start| block.slice(start..(start + max_rows).min(num_rows))),
+                );
+            }
+        }
+        Ok(blocks)
+    }
+}
+
+/// Returns the rows that each operation applies to, a row belongs to the first operation
+/// whose condition is satisfied.
+fn assign_operations(
+    func_ctx: &FunctionContext,
+    block: &DataBlock,
+    conditions: &[Option<&Expr>],
+) -> Result<Vec<Vec<u32>>> {
+    let num_rows = block.num_rows();
+    let mut assigned = vec![false; num_rows];
+    let mut result = Vec::with_capacity(conditions.len());
+    for condition in conditions {
+        let predicates = match condition {
+            Some(expr) => eval_predicate(func_ctx, block, expr)?,
+            None => vec![true; num_rows],
+        };
+        let mut rows = vec![];
+        for (row, predicate) in predicates.into_iter().enumerate() {
+            if predicate && !assigned[row] {
+                assigned[row] = true;
+                rows.push(row as u32);
+            }
+        }
+        result.push(rows);
+    }
+    Ok(result)
+}
+
+fn eval_column(func_ctx: &FunctionContext, block: &DataBlock, expr: &Expr) -> Result<Column> {
+    let evaluator = Evaluator::new(block, *func_ctx, &BUILTIN_FUNCTIONS);
+    Ok(evaluator
+        .run(expr)?
+        .convert_to_full_column(expr.data_type(), block.num_rows()))
+}
+
+fn eval_predicate(func_ctx: &FunctionContext, block: &DataBlock, expr: &Expr) -> Result<Vec<bool>> {
+    let evaluator = Evaluator::new(block, *func_ctx, &BUILTIN_FUNCTIONS);
+    let predicates = evaluator
+        .run(expr)
+        .map_err(|e| e.add_message("eval filter failed:"))?
+        .try_downcast::<BooleanType>()
+        .unwrap();
+    Ok(match predicates {
+        Value::Scalar(v) => vec![v; block.num_rows()],
+        Value::Column(bitmap) => bitmap.iter().collect(),
+    })
+}
+
+/// Applies MERGE INTO to the blocks of the table read by the `MutationSource`.
+///
+/// The changed blocks are passed to the `SerializeDataTransform` with their original meta,
+/// and the unchanged blocks are passed as empty blocks without meta. The last transform
+/// to finish also outputs the inserted blocks with [`AppendDataMeta`].
+pub struct MergeIntoTransform {
+    state: Arc<MergeIntoState>,
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+
+    input_data: Option<DataBlock>,
+    output_data: VecDeque<DataBlock>,
+    detached: bool,
+}
+
+impl MergeIntoTransform {
+    pub fn try_create(
+        state: Arc<MergeIntoState>,
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+    ) -> Result<ProcessorPtr> {
+        state.attach();
+        Ok(ProcessorPtr::create(Box::new(MergeIntoTransform {
+            state,
+            input,
+            output,
+            input_data: None,
+            output_data: VecDeque::new(),
+            detached: false,
+        })))
+    }
+}
+
+impl Processor for MergeIntoTransform {
+    fn name(&self) -> String {
+        "MergeIntoTransform".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            self.input.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            self.input.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(data_block) = self.output_data.pop_front() {
+            self.output.push_data(Ok(data_block));
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.input_data.is_some() {
+            return Ok(Event::Sync);
+        }
+
+        if self.input.has_data() {
+            self.input_data = Some(self.input.pull_data().unwrap()?);
+            return Ok(Event::Sync);
+        }
+
+        if self.input.is_finished() {
+            if !self.detached {
+                return Ok(Event::Sync);
+            }
+            self.output.finish();
+            return Ok(Event::Finished);
+        }
+
+        self.input.set_need_data();
+        Ok(Event::NeedData)
+    }
+
+    fn process(&mut self) -> Result<()> {
+        if let Some(mut data_block) = self.input_data.take() {
+            let block = match data_block.take_meta() {
+                Some(meta) => match self.state.merge_block(&data_block)? {
+                    Some(new_block) => new_block.add_meta(Some(meta))?,
+                    None => DataBlock::empty(),
+                },
+                None => DataBlock::empty(),
+            };
+            self.output_data.push_back(block);
+        } else if !self.detached {
+            self.detached = true;
+            if self.state.detach() {
+                for block in self.state.unmatched_blocks()? {
+                    self.output_data
+                        .push_back(block.add_meta(Some(AppendDataMeta::create()))?);
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -22,6 +22,7 @@ use common_expression::BlockMetaInfoPtr;
 use storages_common_pruner::BlockMetaIndex;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ClusterStatistics;
+use storages_common_table_meta::meta::DeletionVector;
 use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::Statistics;
 
@@ -76,11 +77,103 @@ impl SerializeDataMeta {
     }
 }
 
+/// Marks a block that is appended to the table by the mutation, e.g. the
+/// NOT MATCHED rows of MERGE INTO.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AppendDataMeta;
+
+#[typetag::serde(name = "append_data_meta")]
+impl BlockMetaInfo for AppendDataMeta {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        Box::new(self.clone())
+    }
+
+    fn equals(&self, info: &Box<dyn BlockMetaInfo>) -> bool {
+        info.as_any().downcast_ref::<AppendDataMeta>().is_some()
+    }
+}
+
+impl AppendDataMeta {
+    pub fn create() -> BlockMetaInfoPtr {
+        Box::new(AppendDataMeta)
+    }
+
+    pub fn from_meta(info: &BlockMetaInfoPtr) -> Result<&AppendDataMeta> {
+        match info.as_any().downcast_ref::<AppendDataMeta>() {
+            Some(part_ref) => Ok(part_ref),
+            None => Err(ErrorCode::Internal(
+                "Cannot downcast from BlockMetaInfo to AppendDataMeta.",
+            )),
+        }
+    }
+}
+
+/// Marks some rows of a block as deleted, without rewriting the block.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DeletionVectorMeta {
+    pub index: BlockMetaIndex,
+    /// serialized bitmap of all the deleted rows of the block, including the ones deleted before.
+    pub data: Vec<u8>,
+    pub deleted_rows: u64,
+}
+
+#[typetag::serde(name = "deletion_vector_meta")]
+impl BlockMetaInfo for DeletionVectorMeta {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        Box::new(self.clone())
+    }
+
+    fn equals(&self, info: &Box<dyn BlockMetaInfo>) -> bool {
+        match info.as_any().downcast_ref::<DeletionVectorMeta>() {
+            None => false,
+            Some(other) => self == other,
+        }
+    }
+}
+
+impl DeletionVectorMeta {
+    pub fn create(index: BlockMetaIndex, data: Vec<u8>, deleted_rows: u64) -> BlockMetaInfoPtr {
+        Box::new(DeletionVectorMeta {
+            index,
+            data,
+            deleted_rows,
+        })
+    }
+
+    pub fn from_meta(info: &BlockMetaInfoPtr) -> Result<&DeletionVectorMeta> {
+        match info.as_any().downcast_ref::<DeletionVectorMeta>() {
+            Some(part_ref) => Ok(part_ref),
+            None => Err(ErrorCode::Internal(
+                "Cannot downcast from BlockMetaInfo to DeletionVectorMeta.",
+            )),
+        }
+    }
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub enum Mutation {
     DoNothing,
     Replaced(Arc<BlockMeta>),
     Deleted,
+    Appended(Arc<BlockMeta>),
+    // Some rows of the block are deleted, the block is kept with the new deletion vector.
+    MarkDeleted(DeletionVector),
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
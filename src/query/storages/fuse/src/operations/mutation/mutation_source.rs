@@ -13,15 +13,16 @@
 //  limitations under the License.
 
 use std::any::Any;
-use std::ops::Not;
 use std::sync::Arc;
 
+use common_arrow::arrow::bitmap::Bitmap;
 use common_base::base::Progress;
 use common_base::base::ProgressValues;
 use common_catalog::plan::PartInfoPtr;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::filter_helper::FilterHelpers;
 use common_expression::types::BooleanType;
 use common_expression::types::DataType;
 use common_expression::BlockEntry;
@@ -35,8 +36,12 @@ use storages_common_pruner::BlockMetaIndex;
 use storages_common_table_meta::meta::ClusterStatistics;
 
 use crate::fuse_part::FusePartInfo;
+use crate::io::filter_deleted_rows;
+use crate::io::read_deletion_vector;
+use crate::io::serialize_deletion_vector;
 use crate::io::BlockReader;
 use crate::io::ReadSettings;
+use crate::operations::mutation::DeletionVectorMeta;
 use crate::operations::mutation::MutationPartInfo;
 use crate::operations::mutation::SerializeDataMeta;
 use crate::pipelines::processors::port::OutputPort;
@@ -53,17 +58,19 @@ pub enum MutationAction {
 
 enum State {
     ReadData(Option<PartInfoPtr>),
-    FilterData(PartInfoPtr, MergeIOReadResult),
+    FilterData(PartInfoPtr, MergeIOReadResult, Option<Bitmap>),
     ReadRemain {
         part: PartInfoPtr,
         data_block: DataBlock,
         filter: Value<BooleanType>,
+        deleted: Option<Bitmap>,
     },
     MergeRemain {
         part: PartInfoPtr,
         merged_io_read_result: MergeIOReadResult,
         data_block: DataBlock,
         filter: Value<BooleanType>,
+        deleted: Option<Bitmap>,
     },
     PerformOperator(DataBlock),
     Output(Option<PartInfoPtr>, DataBlock),
@@ -115,6 +122,54 @@ impl MutationSource {
             storage_format,
         })))
     }
+
+    /// Marks the rows matching the predicates as deleted, the block itself is left untouched.
+    fn mark_deleted(
+        &mut self,
+        predicates: Value<BooleanType>,
+        deleted: Option<Bitmap>,
+        num_rows: usize,
+    ) -> Result<()> {
+        let matched: Bitmap = FilterHelpers::filter_to_bitmap(predicates, num_rows).into();
+        let (deleted, affect_rows) = match deleted {
+            None => {
+                let affect_rows = matched.len() - matched.unset_bits();
+                (matched, affect_rows)
+            }
+            Some(deleted) => {
+                // rows that have been deleted before are not affected again.
+                let live = !&deleted;
+                let affected = &matched & &live;
+                let affect_rows = affected.len() - affected.unset_bits();
+                (&deleted | &matched, affect_rows)
+            }
+        };
+
+        if affect_rows == 0 {
+            // Do nothing.
+            self.state = State::Output(self.ctx.get_partition(), DataBlock::empty());
+            return Ok(());
+        }
+
+        let progress_values = ProgressValues {
+            rows: affect_rows,
+            bytes: 0,
+        };
+        self.scan_progress.incr(&progress_values);
+
+        let meta = if deleted.unset_bits() == 0 {
+            // all the rows should be removed.
+            SerializeDataMeta::create(self.index.clone(), self.origin_stats.clone())
+        } else {
+            DeletionVectorMeta::create(
+                self.index.clone(),
+                serialize_deletion_vector(&deleted),
+                (deleted.len() - deleted.unset_bits()) as u64,
+            )
+        };
+        self.state = State::Output(self.ctx.get_partition(), DataBlock::empty_with_meta(meta));
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -171,13 +226,17 @@ impl Processor for MutationSource {
 
     fn process(&mut self) -> Result<()> {
         match std::mem::replace(&mut self.state, State::Finish) {
-            State::FilterData(part, read_res) => {
+            State::FilterData(part, read_res, deleted) => {
                 let chunks = read_res.columns_chunks()?;
                 let mut data_block = self.block_reader.deserialize_chunks(
                     part.clone(),
                     chunks,
                     &self.storage_format,
                 )?;
+                if matches!(self.action, MutationAction::Update) {
+                    // The updated block is rewritten without the deleted rows.
+                    data_block = filter_deleted_rows(data_block, deleted.as_ref())?;
+                }
                 let num_rows = data_block.num_rows();
 
                 if let Some(filter) = self.filter.as_ref() {
@@ -192,6 +251,10 @@ impl Processor for MutationSource {
                         .try_downcast::<BooleanType>()
                         .unwrap();
 
+                    if matches!(self.action, MutationAction::Deletion) {
+                        return self.mark_deleted(predicates, deleted, num_rows);
+                    }
+
                     let affect_rows = match &predicates {
                         Value::Scalar(v) => {
                             if *v {
@@ -210,48 +273,19 @@ impl Processor for MutationSource {
                         };
                         self.scan_progress.incr(&progress_values);
 
-                        match self.action {
-                            MutationAction::Deletion => {
-                                if affect_rows == num_rows {
-                                    // all the rows should be removed.
-                                    let meta = SerializeDataMeta::create(
-                                        self.index.clone(),
-                                        self.origin_stats.clone(),
-                                    );
-                                    self.state = State::Output(
-                                        self.ctx.get_partition(),
-                                        DataBlock::empty_with_meta(meta),
-                                    );
-                                } else {
-                                    let predicate_col = predicates.into_column().unwrap();
-                                    let filter = predicate_col.not();
-                                    data_block = data_block.filter_with_bitmap(&filter)?;
-                                    if self.remain_reader.is_none() {
-                                        self.state = State::PerformOperator(data_block);
-                                    } else {
-                                        self.state = State::ReadRemain {
-                                            part,
-                                            data_block,
-                                            filter: Value::Column(filter),
-                                        }
-                                    }
-                                }
-                            }
-                            MutationAction::Update => {
-                                if self.remain_reader.is_none() {
-                                    data_block.add_column(BlockEntry {
-                                        data_type: DataType::Boolean,
-                                        value: Value::upcast(predicates),
-                                    });
-                                    self.state = State::PerformOperator(data_block);
-                                } else {
-                                    self.state = State::ReadRemain {
-                                        part,
-                                        data_block,
-                                        filter: predicates,
-                                    };
-                                }
-                            }
+                        if self.remain_reader.is_none() {
+                            data_block.add_column(BlockEntry {
+                                data_type: DataType::Boolean,
+                                value: Value::upcast(predicates),
+                            });
+                            self.state = State::PerformOperator(data_block);
+                        } else {
+                            self.state = State::ReadRemain {
+                                part,
+                                data_block,
+                                filter: predicates,
+                                deleted,
+                            };
                         }
                     } else {
                         // Do nothing.
@@ -271,29 +305,21 @@ impl Processor for MutationSource {
                 merged_io_read_result,
                 mut data_block,
                 filter,
+                deleted,
             } => {
                 if let Some(remain_reader) = self.remain_reader.as_ref() {
                     let chunks = merged_io_read_result.columns_chunks()?;
                     let remain_block =
                         remain_reader.deserialize_chunks(part, chunks, &self.storage_format)?;
+                    let remain_block = filter_deleted_rows(remain_block, deleted.as_ref())?;
 
-                    match self.action {
-                        MutationAction::Deletion => {
-                            let remain_block = remain_block.filter_boolean_value(&filter)?;
-                            for col in remain_block.columns() {
-                                data_block.add_column(col.clone());
-                            }
-                        }
-                        MutationAction::Update => {
-                            for col in remain_block.columns() {
-                                data_block.add_column(col.clone());
-                            }
-                            data_block.add_column(BlockEntry {
-                                data_type: DataType::Boolean,
-                                value: Value::upcast(filter),
-                            });
-                        }
+                    for col in remain_block.columns() {
+                        data_block.add_column(col.clone());
                     }
+                    data_block.add_column(BlockEntry {
+                        data_type: DataType::Boolean,
+                        value: Value::upcast(filter),
+                    });
                 } else {
                     return Err(ErrorCode::Internal("It's a bug. Need remain reader"));
                 };
@@ -332,12 +358,18 @@ impl Processor for MutationSource {
                         &fuse_part.columns_meta,
                     )
                     .await?;
-                self.state = State::FilterData(inner_part, read_res);
+                let deleted = read_deletion_vector(
+                    &self.block_reader.operator,
+                    fuse_part.deletion_vector.as_ref(),
+                )
+                .await?;
+                self.state = State::FilterData(inner_part, read_res, deleted);
             }
             State::ReadRemain {
                 part,
                 data_block,
                 filter,
+                deleted,
             } => {
                 if let Some(remain_reader) = self.remain_reader.as_ref() {
                     let fuse_part = FusePartInfo::from_part(&part)?;
@@ -355,6 +387,7 @@ impl Processor for MutationSource {
                         merged_io_read_result: read_res,
                         data_block,
                         filter,
+                        deleted,
                     };
                 } else {
                     return Err(ErrorCode::Internal("It's a bug. No remain reader"));
@@ -27,6 +27,7 @@ use opendal::Operator;
 use storages_common_cache::CacheAccessor;
 use storages_common_cache_manager::CacheManager;
 use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::DeletionVector;
 use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::SegmentInfo;
 use storages_common_table_meta::meta::Statistics;
@@ -46,7 +47,14 @@ use crate::pipelines::processors::Processor;
 use crate::statistics::reducers::merge_statistics_mut;
 use crate::statistics::reducers::reduce_block_metas;
 
-type MutationMap = HashMap<usize, (Vec<(usize, Arc<BlockMeta>)>, Vec<usize>)>;
+type MutationMap = HashMap<
+    usize,
+    (
+        Vec<(usize, Arc<BlockMeta>)>,
+        Vec<usize>,
+        Vec<(usize, DeletionVector)>,
+    ),
+>;
 
 struct SerializedData {
     data: Vec<u8>,
@@ -83,6 +91,7 @@ pub struct MutationTransform {
 
     inputs: Vec<Arc<InputPort>>,
     input_metas: MutationMap,
+    appended_blocks: Vec<Arc<BlockMeta>>,
     cur_input_index: usize,
     output: Arc<OutputPort>,
     output_data: Option<DataBlock>,
@@ -111,6 +120,7 @@ impl MutationTransform {
             abort_operation: AbortOperation::default(),
             inputs,
             input_metas: HashMap::new(),
+            appended_blocks: vec![],
             cur_input_index: 0,
             output,
             output_data: None,
@@ -237,15 +247,30 @@ impl Processor for MutationTransform {
                     Mutation::Replaced(block_meta) => {
                         self.input_metas
                             .entry(meta.index.segment_idx)
-                            .and_modify(|v| v.0.push((meta.index.block_idx, block_meta.clone())))
-                            .or_insert((vec![(meta.index.block_idx, block_meta.clone())], vec![]));
+                            .or_default()
+                            .0
+                            .push((meta.index.block_idx, block_meta.clone()));
                         self.abort_operation.add_block(block_meta);
                     }
                     Mutation::Deleted => {
                         self.input_metas
                             .entry(meta.index.segment_idx)
-                            .and_modify(|v| v.1.push(meta.index.block_idx))
-                            .or_insert((vec![], vec![meta.index.block_idx]));
+                            .or_default()
+                            .1
+                            .push(meta.index.block_idx);
+                    }
+                    Mutation::MarkDeleted(deletion_vector) => {
+                        self.input_metas
+                            .entry(meta.index.segment_idx)
+                            .or_default()
+                            .2
+                            .push((meta.index.block_idx, deletion_vector.clone()));
+                        self.abort_operation
+                            .add_deletion_vector(deletion_vector.location.0.clone());
+                    }
+                    Mutation::Appended(block_meta) => {
+                        self.appended_blocks.push(block_meta.clone());
+                        self.abort_operation.add_block(block_meta);
                     }
                     Mutation::DoNothing => (),
                 }
@@ -257,7 +282,7 @@ impl Processor for MutationTransform {
                 let mut segments_editor =
                     BTreeMap::<_, _>::from_iter(segments.into_iter().enumerate());
                 for (seg_idx, seg_info) in segment_infos.iter().enumerate() {
-                    if let Some((replaced, deleted)) = self.input_metas.get(&seg_idx) {
+                    if let Some((replaced, deleted, marked)) = self.input_metas.get(&seg_idx) {
                         // prepare the new segment
                         let mut new_segment =
                             SegmentInfo::new(seg_info.blocks.clone(), seg_info.summary.clone());
@@ -274,6 +299,14 @@ impl Processor for MutationTransform {
                         for idx in deleted {
                             block_editor.remove(idx);
                         }
+                        for (idx, deletion_vector) in marked {
+                            if let Some(block_meta) = block_editor.get_mut(idx) {
+                                *block_meta = Arc::new(BlockMeta {
+                                    deletion_vector: Some(deletion_vector.clone()),
+                                    ..block_meta.as_ref().clone()
+                                });
+                            }
+                        }
                         // assign back the mutated blocks to segment
                         new_segment.blocks = block_editor.into_values().collect();
                         if new_segment.blocks.is_empty() {
@@ -301,7 +334,24 @@ impl Processor for MutationTransform {
                 }
 
                 // assign back the mutated segments to snapshot
-                let segments = segments_editor.into_values().collect();
+                let mut segments: Vec<Location> = segments_editor.into_values().collect();
+
+                // the appended blocks are gathered into a new segment.
+                if !self.appended_blocks.is_empty() {
+                    let blocks = std::mem::take(&mut self.appended_blocks);
+                    let new_summary = reduce_block_metas(&blocks, self.thresholds)?;
+                    merge_statistics_mut(&mut summary, &new_summary)?;
+                    let new_segment = SegmentInfo::new(blocks, new_summary);
+
+                    let location = self.location_gen.gen_segment_info_location();
+                    self.abort_operation.add_segment(location.clone());
+                    segments.insert(0, (location.clone(), new_segment.format_version()));
+                    serialized_data.push(SerializedData {
+                        data: serde_json::to_vec(&new_segment)?,
+                        location,
+                        segment: Arc::new(new_segment),
+                    });
+                }
                 self.state = State::SerializedSegments {
                     serialized_data,
                     segments,
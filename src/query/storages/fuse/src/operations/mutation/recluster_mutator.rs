@@ -109,7 +109,7 @@ impl TableMutator for ReclusterMutator {
                     .and_modify(|v| v.1.push(i))
                     .or_insert((vec![], vec![i]));
 
-                total_rows += meta.row_count;
+                total_rows += meta.live_row_count();
                 total_bytes += meta.block_size;
             }
 
@@ -28,12 +28,17 @@ use opendal::Operator;
 use storages_common_pruner::BlockMetaIndex;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ClusterStatistics;
+use storages_common_table_meta::meta::DeletionVector;
+use storages_common_table_meta::table::AggregatingIndex;
 use storages_common_table_meta::table::TableCompression;
 
+use crate::aggregating_index::AggIndexState;
 use crate::io::write_block;
 use crate::io::write_data;
 use crate::io::TableMetaLocationGenerator;
 use crate::io::WriteSettings;
+use crate::operations::mutation::AppendDataMeta;
+use crate::operations::mutation::DeletionVectorMeta;
 use crate::operations::mutation::Mutation;
 use crate::operations::mutation::MutationTransformMeta;
 use crate::operations::mutation::SerializeDataMeta;
@@ -50,12 +55,14 @@ pub struct SerializeState {
     pub block_location: String,
     pub index_data: Option<Vec<u8>>,
     pub index_location: Option<String>,
+    pub agg_index_states: Vec<AggIndexState>,
 }
 
 enum State {
     Consume,
     NeedSerialize(DataBlock),
     Serialized(SerializeState, Arc<BlockMeta>),
+    SerializedDeletionVector { data: Vec<u8>, deleted_rows: u64 },
     Output(Mutation),
 }
 
@@ -72,8 +79,11 @@ pub struct SerializeDataTransform {
     cluster_stats_gen: ClusterStatsGenerator,
 
     schema: TableSchemaRef,
+    agg_indexes: Vec<AggregatingIndex>,
     index: BlockMetaIndex,
     origin_stats: Option<ClusterStatistics>,
+    // Whether the current block is appended to the table instead of replacing an existing one.
+    is_append: bool,
     table_compression: TableCompression,
 }
 
@@ -96,8 +106,10 @@ impl SerializeDataTransform {
             write_settings: table.get_write_settings(),
             cluster_stats_gen,
             schema: table.schema(),
+            agg_indexes: table.aggregating_indexes()?,
             index: BlockMetaIndex::default(),
             origin_stats: None,
+            is_append: false,
             table_compression: table.table_compression,
         })))
     }
@@ -118,7 +130,10 @@ impl Processor for SerializeDataTransform {
             return Ok(Event::Sync);
         }
 
-        if matches!(self.state, State::Serialized(_, _)) {
+        if matches!(
+            self.state,
+            State::Serialized(_, _) | State::SerializedDeletionVector { .. }
+        ) {
             return Ok(Event::Async);
         }
 
@@ -148,6 +163,23 @@ impl Processor for SerializeDataTransform {
         let mut input_data = self.input.pull_data().unwrap()?;
         let meta = input_data.take_meta();
         if let Some(meta) = meta {
+            self.is_append = AppendDataMeta::from_meta(&meta).is_ok();
+            if self.is_append {
+                self.index = BlockMetaIndex::default();
+                self.origin_stats = None;
+                self.state = State::NeedSerialize(input_data);
+                return Ok(Event::Sync);
+            }
+
+            if let Ok(meta) = DeletionVectorMeta::from_meta(&meta) {
+                self.index = meta.index.clone();
+                self.state = State::SerializedDeletionVector {
+                    data: meta.data.clone(),
+                    deleted_rows: meta.deleted_rows,
+                };
+                return Ok(Event::Async);
+            }
+
             let meta = SerializeDataMeta::from_meta(&meta)?;
             self.index = meta.index.clone();
             self.origin_stats = meta.cluster_stats.clone();
@@ -186,6 +218,13 @@ impl Processor for SerializeDataTransform {
                     .map(|i| i.column_distinct_count.clone());
                 let col_stats =
                     gen_columns_statistics(&block, column_distinct_count, &self.schema)?;
+                let agg_index_states = AggIndexState::try_create_all(
+                    &self.agg_indexes,
+                    &self.schema,
+                    &block,
+                    &self.location_gen,
+                    &block_id,
+                )?;
 
                 // serialize data block.
                 let mut block_data = Vec::with_capacity(DEFAULT_BLOCK_BUFFER_SIZE);
@@ -206,18 +245,21 @@ impl Processor for SerializeDataTransform {
                     };
 
                 // new block meta.
-                let new_meta = Arc::new(BlockMeta::new(
-                    row_count,
-                    block_size,
-                    file_size,
-                    col_stats,
-                    col_metas,
-                    cluster_stats,
-                    block_location.clone(),
-                    index_location.clone(),
-                    index_size,
-                    self.table_compression.into(),
-                ));
+                let new_meta = Arc::new(
+                    BlockMeta::new(
+                        row_count,
+                        block_size,
+                        file_size,
+                        col_stats,
+                        col_metas,
+                        cluster_stats,
+                        block_location.clone(),
+                        index_location.clone(),
+                        index_size,
+                        self.table_compression.into(),
+                    )
+                    .with_agg_index_locations(AggIndexState::locations(&agg_index_states)),
+                );
 
                 self.state = State::Serialized(
                     SerializeState {
@@ -225,6 +267,7 @@ impl Processor for SerializeDataTransform {
                         block_location: block_location.0,
                         index_data,
                         index_location: index_location.map(|l| l.0),
+                        agg_index_states,
                     },
                     new_meta,
                 );
@@ -254,8 +297,24 @@ impl Processor for SerializeDataTransform {
                 {
                     write_data(&index_data, &self.dal, &index_location).await?;
                 }
+                // write aggregating indexes.
+                AggIndexState::write_all(&serialize_state.agg_index_states, &self.dal).await?;
 
-                self.state = State::Output(Mutation::Replaced(block_meta));
+                self.state = if self.is_append {
+                    State::Output(Mutation::Appended(block_meta))
+                } else {
+                    State::Output(Mutation::Replaced(block_meta))
+                };
+            }
+            State::SerializedDeletionVector { data, deleted_rows } => {
+                let location = self.location_gen.gen_deletion_vector_location();
+                write_data(&data, &self.dal, &location.0).await?;
+
+                self.state = State::Output(Mutation::MarkDeleted(DeletionVector {
+                    location,
+                    size: data.len() as u64,
+                    deleted_rows,
+                }));
             }
             _ => return Err(ErrorCode::Internal("It's a bug.")),
         }
@@ -69,6 +69,7 @@ pub fn build_fuse_native_source_pipeline(
                         output,
                         block_reader.clone(),
                         partitions.clone(),
+                        plan,
                     )?,
                 );
             }
@@ -92,6 +93,7 @@ pub fn build_fuse_native_source_pipeline(
                         output,
                         block_reader.clone(),
                         partitions.clone(),
+                        plan,
                     )?,
                 );
             }
@@ -141,6 +143,7 @@ pub fn build_fuse_parquet_source_pipeline(
                         output,
                         block_reader.clone(),
                         partitions.clone(),
+                        plan,
                     )?,
                 );
             }
@@ -162,6 +165,7 @@ pub fn build_fuse_parquet_source_pipeline(
                         output,
                         block_reader.clone(),
                         partitions.clone(),
+                        plan,
                     )?,
                 );
             }
@@ -180,6 +184,7 @@ pub fn build_fuse_parquet_source_pipeline(
         DeserializeDataTransform::create(
             ctx.clone(),
             block_reader.clone(),
+            plan,
             transform_input,
             transform_output,
         )
@@ -19,6 +19,7 @@ mod native_data_source_reader;
 mod parquet_data_source;
 mod parquet_data_source_deserializer;
 mod parquet_data_source_reader;
+mod runtime_filter;
 
 pub use fuse_source::build_fuse_parquet_source_pipeline;
 pub use parquet_data_source::DataSourceMeta;
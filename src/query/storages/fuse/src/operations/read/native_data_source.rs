@@ -17,6 +17,7 @@ use std::collections::BTreeMap;
 use std::fmt::Debug;
 use std::fmt::Formatter;
 
+use common_arrow::arrow::bitmap::Bitmap;
 use common_arrow::native::read::reader::NativeReader;
 use common_catalog::plan::PartInfoPtr;
 use common_expression::BlockMetaInfo;
@@ -31,11 +32,21 @@ pub type DataChunks = BTreeMap<usize, Vec<NativeReader<Box<dyn NativeReaderExt>>
 pub struct NativeDataSourceMeta {
     pub part: Vec<PartInfoPtr>,
     pub chunks: Vec<DataChunks>,
+    /// the deleted rows of each part, if the part has a deletion vector
+    pub deleted: Vec<Option<Bitmap>>,
 }
 
 impl NativeDataSourceMeta {
-    pub fn create(part: Vec<PartInfoPtr>, chunks: Vec<DataChunks>) -> BlockMetaInfoPtr {
-        Box::new(NativeDataSourceMeta { part, chunks })
+    pub fn create(
+        part: Vec<PartInfoPtr>,
+        chunks: Vec<DataChunks>,
+        deleted: Vec<Option<Bitmap>>,
+    ) -> BlockMetaInfoPtr {
+        Box::new(NativeDataSourceMeta {
+            part,
+            chunks,
+            deleted,
+        })
     }
 }
 
@@ -18,6 +18,7 @@ use std::collections::HashSet;
 use std::collections::VecDeque;
 use std::sync::Arc;
 
+use common_arrow::arrow::bitmap::Bitmap;
 use common_arrow::arrow::bitmap::MutableBitmap;
 use common_arrow::native::read::column_iter_to_arrays;
 use common_arrow::native::read::reader::NativeReader;
@@ -56,6 +57,7 @@ use crate::io::NativeReaderExt;
 use crate::metrics::metrics_inc_pruning_prewhere_nums;
 use crate::operations::read::native_data_source::DataChunks;
 use crate::operations::read::native_data_source::NativeDataSourceMeta;
+use crate::operations::read::runtime_filter::RuntimeFilterPruner;
 
 pub struct NativeDeserializeDataTransform {
     func_ctx: FunctionContext,
@@ -68,6 +70,10 @@ pub struct NativeDeserializeDataTransform {
     output_data: Option<DataBlock>,
     parts: VecDeque<PartInfoPtr>,
     chunks: VecDeque<DataChunks>,
+    // The deleted rows of each part, if the part has a deletion vector.
+    deleted: VecDeque<Option<Bitmap>>,
+    // The offset of the rows of the current page in the part.
+    page_offset: usize,
 
     prewhere_columns: Vec<usize>,
     remain_columns: Vec<usize>,
@@ -86,6 +92,8 @@ pub struct NativeDeserializeDataTransform {
     array_iters: BTreeMap<usize, ArrayIter<'static>>,
     // The Page numbers of each ArrayIter can skip.
     array_skip_pages: BTreeMap<usize, usize>,
+
+    runtime_filter: Option<RuntimeFilterPruner>,
 }
 
 impl NativeDeserializeDataTransform {
@@ -134,6 +142,8 @@ impl NativeDeserializeDataTransform {
         let output_schema: DataSchema = plan.schema().into();
 
         let func_ctx = ctx.get_function_context()?;
+        let runtime_filter =
+            RuntimeFilterPruner::try_create(ctx, plan, block_reader.operator.clone())?;
         let prewhere_schema = src_schema.project(&prewhere_columns);
         let prewhere_filter = Self::build_prewhere_filter_expr(plan, &prewhere_schema)?;
 
@@ -158,6 +168,8 @@ impl NativeDeserializeDataTransform {
                 output_data: None,
                 parts: VecDeque::new(),
                 chunks: VecDeque::new(),
+                deleted: VecDeque::new(),
+                page_offset: 0,
 
                 prewhere_columns,
                 remain_columns,
@@ -170,6 +182,7 @@ impl NativeDeserializeDataTransform {
                 inited: false,
                 array_iters: BTreeMap::new(),
                 array_skip_pages: BTreeMap::new(),
+                runtime_filter,
             },
         )))
     }
@@ -253,8 +266,7 @@ impl NativeDeserializeDataTransform {
                 // Default value satisfies the filter, update the value of top-k column.
                 if let Some((_, sorter, index)) = self.top_k.as_mut() {
                     if !self.array_iters.contains_key(index) {
-                        let part = FusePartInfo::from_part(&self.parts[0])?;
-                        let num_rows = part.nums_rows;
+                        let num_rows = self.part_live_rows()?;
 
                         let data_type = self.src_schema.field(*index).data_type().clone();
                         let default_val = self.block_reader.default_vals[*index].clone();
@@ -273,7 +285,9 @@ impl NativeDeserializeDataTransform {
     fn finish_process(&mut self) -> Result<()> {
         let _ = self.chunks.pop_front();
         let _ = self.parts.pop_front().unwrap();
+        let _ = self.deleted.pop_front();
 
+        self.page_offset = 0;
         self.inited = false;
         self.array_iters.clear();
         self.array_skip_pages.clear();
@@ -282,11 +296,11 @@ impl NativeDeserializeDataTransform {
 
     /// All columns are default values, not need to read.
     fn finish_process_with_default_values(&mut self) -> Result<()> {
+        let num_rows = self.part_live_rows()?;
         let _ = self.chunks.pop_front();
-        let part = self.parts.pop_front().unwrap();
-        let part = FusePartInfo::from_part(&part)?;
+        let _ = self.parts.pop_front().unwrap();
+        let _ = self.deleted.pop_front();
 
-        let num_rows = part.nums_rows;
         let data_block = self.block_reader.build_default_values_block(num_rows)?;
         let data_block = data_block.resort(&self.src_schema, &self.output_schema)?;
 
@@ -300,19 +314,20 @@ impl NativeDeserializeDataTransform {
 
     /// Empty projection use empty block.
     fn finish_process_with_empty_block(&mut self) -> Result<()> {
+        let num_rows = self.part_live_rows()?;
         let _ = self.chunks.pop_front();
-        let part = self.parts.pop_front().unwrap();
-        let part = FusePartInfo::from_part(&part)?;
+        let _ = self.parts.pop_front().unwrap();
+        let _ = self.deleted.pop_front();
 
-        let num_rows = part.nums_rows;
         let data_block = DataBlock::new(vec![], num_rows);
         self.add_block(data_block)?;
         Ok(())
     }
 
     /// Update the number of pages that can be skipped per column.
-    fn finish_process_skip_page(&mut self) -> Result<()> {
+    fn finish_process_skip_page(&mut self, num_rows: usize) -> Result<()> {
         self.skipped_page += 1;
+        self.page_offset += num_rows;
         for (i, skip_num) in self.array_skip_pages.iter_mut() {
             if self.read_columns.contains(i) {
                 continue;
@@ -322,6 +337,26 @@ impl NativeDeserializeDataTransform {
         Ok(())
     }
 
+    /// Number of the rows of the current part that are not deleted.
+    fn part_live_rows(&self) -> Result<usize> {
+        let part = FusePartInfo::from_part(&self.parts[0])?;
+        Ok(match self.deleted.front() {
+            Some(Some(deleted)) => part.nums_rows - (deleted.len() - deleted.unset_bits()),
+            _ => part.nums_rows,
+        })
+    }
+
+    /// Rows of the current page that are not deleted, if the current part has a deletion vector.
+    fn page_live_rows(&self, num_rows: usize) -> Option<Bitmap> {
+        match self.deleted.front() {
+            Some(Some(deleted)) => {
+                let page = deleted.clone().sliced(self.page_offset, num_rows);
+                Some(!&page)
+            }
+            _ => None,
+        }
+    }
+
     fn build_array_iter(
         column_node: &ColumnNode,
         leaves: Vec<ColumnDescriptor>,
@@ -377,6 +412,7 @@ impl Processor for NativeDeserializeDataTransform {
                 {
                     self.parts = VecDeque::from(std::mem::take(&mut source_meta.part));
                     self.chunks = VecDeque::from(std::mem::take(&mut source_meta.chunks));
+                    self.deleted = VecDeque::from(std::mem::take(&mut source_meta.deleted));
                     return Ok(Event::Sync);
                 }
             }
@@ -413,13 +449,27 @@ impl Processor for NativeDeserializeDataTransform {
                 }
 
                 self.inited = true;
+                let part = FusePartInfo::from_part(&self.parts[0])?;
                 for (index, column_node) in
                     self.block_reader.project_column_nodes.iter().enumerate()
                 {
                     let readers = chunks.remove(&index).unwrap();
                     if !readers.is_empty() {
-                        let leaves = self.column_leaves.get(index).unwrap().clone();
-                        let array_iter = Self::build_array_iter(column_node, leaves, readers)?;
+                        let array_iter = match self
+                            .block_reader
+                            .evolved_column(column_node.leaf_column_ids[0], &part.columns_meta)
+                        {
+                            Some(evolved) => BlockReader::build_evolved_native_array_iter(
+                                self.func_ctx,
+                                column_node,
+                                evolved.clone(),
+                                readers,
+                            )?,
+                            None => {
+                                let leaves = self.column_leaves.get(index).unwrap().clone();
+                                Self::build_array_iter(column_node, leaves, readers)?
+                            }
+                        };
                         self.array_iters.insert(index, array_iter);
                         self.array_skip_pages.insert(index, 0);
                     }
@@ -455,7 +505,7 @@ impl Processor for NativeDeserializeDataTransform {
                                 arrays.push((*index, array));
                                 self.array_iters.insert(*index, array_iter);
                                 if sorter.never_match_any(&col) {
-                                    return self.finish_process_skip_page();
+                                    return self.finish_process_skip_page(col.len());
                                 }
                             }
                             None => {
@@ -513,9 +563,17 @@ impl Processor for NativeDeserializeDataTransform {
                             .try_downcast::<BooleanType>()
                             .unwrap();
 
+                        // Rows marked as deleted never match the filter.
+                        let num_rows = prewhere_block.num_rows();
+                        let filter = match (filter, self.page_live_rows(num_rows)) {
+                            (Value::Scalar(true), Some(live)) => Value::Column(live),
+                            (Value::Column(bitmap), Some(live)) => Value::Column(&bitmap & &live),
+                            (filter, _) => filter,
+                        };
+
                         // Step 3: Apply the filter, if it's all filtered, we can skip the remain columns.
                         if FilterHelpers::is_all_unset(&filter) {
-                            return self.finish_process_skip_page();
+                            return self.finish_process_skip_page(num_rows);
                         }
 
                         // Step 4: Apply the filter to topk and update the bitmap, this will filter more results
@@ -540,7 +598,7 @@ impl Processor for NativeDeserializeDataTransform {
                         };
 
                         if FilterHelpers::is_all_unset(&filter) {
-                            return self.finish_process_skip_page();
+                            return self.finish_process_skip_page(num_rows);
                         }
                         Some(filter)
                     }
@@ -570,11 +628,15 @@ impl Processor for NativeDeserializeDataTransform {
             }
 
             let block = self.block_reader.build_block(arrays, None)?;
+            let num_rows = block.num_rows();
             let block = if let Some(filter) = filter {
                 block.filter_boolean_value(&filter)?
+            } else if let Some(live) = self.page_live_rows(num_rows) {
+                block.filter_with_bitmap(&live)?
             } else {
                 block
             };
+            self.page_offset += num_rows;
 
             // Step 6: fill missing field default value if need
             let block = if need_to_fill_data {
@@ -584,8 +646,16 @@ impl Processor for NativeDeserializeDataTransform {
                 block
             };
 
+            // Step 7: Filter the rows by the runtime filters of the scan
+            let block = match &mut self.runtime_filter {
+                Some(runtime_filter) => {
+                    runtime_filter.filter_block(block, &self.block_reader.projected_schema)?
+                }
+                None => block,
+            };
+
             let block = block.resort(&self.src_schema, &self.output_schema)?;
-            // Step 7: Add the block to output data
+            // Step 8: Add the block to output data
             self.add_block(block)?;
         }
 
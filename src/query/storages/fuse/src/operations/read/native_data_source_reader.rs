@@ -15,10 +15,13 @@
 use std::any::Any;
 use std::sync::Arc;
 
+use common_arrow::arrow::bitmap::Bitmap;
 use common_base::base::tokio;
+use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::PartInfoPtr;
 use common_catalog::plan::StealablePartitions;
 use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataBlock;
 use common_pipeline_core::processors::port::OutputPort;
@@ -28,9 +31,13 @@ use common_pipeline_core::processors::Processor;
 use common_pipeline_sources::SyncSource;
 use common_pipeline_sources::SyncSourcer;
 
+use crate::fuse_part::FusePartInfo;
+use crate::io::read_deletion_vector;
+use crate::io::sync_read_deletion_vector;
 use crate::io::BlockReader;
 use crate::operations::read::native_data_source::DataChunks;
 use crate::operations::read::native_data_source::NativeDataSourceMeta;
+use crate::operations::read::runtime_filter::RuntimeFilterPruner;
 
 pub struct ReadNativeDataSource<const BLOCKING_IO: bool> {
     id: usize,
@@ -39,8 +46,9 @@ pub struct ReadNativeDataSource<const BLOCKING_IO: bool> {
     block_reader: Arc<BlockReader>,
 
     output: Arc<OutputPort>,
-    output_data: Option<(Vec<PartInfoPtr>, Vec<DataChunks>)>,
+    output_data: Option<(Vec<PartInfoPtr>, Vec<DataChunks>, Vec<Option<Bitmap>>)>,
     partitions: StealablePartitions,
+    runtime_filter: Option<RuntimeFilterPruner>,
 }
 
 impl ReadNativeDataSource<true> {
@@ -50,8 +58,11 @@ impl ReadNativeDataSource<true> {
         output: Arc<OutputPort>,
         block_reader: Arc<BlockReader>,
         partitions: StealablePartitions,
+        plan: &DataSourcePlan,
     ) -> Result<ProcessorPtr> {
         let batch_size = ctx.get_settings().get_storage_fetch_part_num()? as usize;
+        let runtime_filter =
+            RuntimeFilterPruner::try_create(ctx.clone(), plan, block_reader.operator.clone())?;
         SyncSourcer::create(ctx.clone(), output.clone(), ReadNativeDataSource::<true> {
             id,
             output,
@@ -60,6 +71,7 @@ impl ReadNativeDataSource<true> {
             finished: false,
             output_data: None,
             partitions,
+            runtime_filter,
         })
     }
 }
@@ -71,8 +83,11 @@ impl ReadNativeDataSource<false> {
         output: Arc<OutputPort>,
         block_reader: Arc<BlockReader>,
         partitions: StealablePartitions,
+        plan: &DataSourcePlan,
     ) -> Result<ProcessorPtr> {
         let batch_size = ctx.get_settings().get_storage_fetch_part_num()? as usize;
+        let runtime_filter =
+            RuntimeFilterPruner::try_create(ctx.clone(), plan, block_reader.operator.clone())?;
         Ok(ProcessorPtr::create(Box::new(ReadNativeDataSource::<
             false,
         > {
@@ -83,6 +98,7 @@ impl ReadNativeDataSource<false> {
             finished: false,
             output_data: None,
             partitions,
+            runtime_filter,
         })))
     }
 }
@@ -91,13 +107,29 @@ impl SyncSource for ReadNativeDataSource<true> {
     const NAME: &'static str = "SyncReadNativeDataSource";
 
     fn generate(&mut self) -> Result<Option<DataBlock>> {
-        match self.partitions.steal_one(self.id) {
-            None => Ok(None),
-            Some(part) => Ok(Some(DataBlock::empty_with_meta(
-                NativeDataSourceMeta::create(vec![part.clone()], vec![
-                    self.block_reader.sync_read_native_columns_data(part)?,
-                ]),
-            ))),
+        loop {
+            return match self.partitions.steal_one(self.id) {
+                None => Ok(None),
+                Some(part) => {
+                    if let Some(runtime_filter) = &mut self.runtime_filter {
+                        if !runtime_filter.should_keep(&part)? {
+                            continue;
+                        }
+                    }
+
+                    let deleted = sync_read_deletion_vector(
+                        &self.block_reader.operator,
+                        FusePartInfo::from_part(&part)?.deletion_vector.as_ref(),
+                    )?;
+                    Ok(Some(DataBlock::empty_with_meta(
+                        NativeDataSourceMeta::create(
+                            vec![part.clone()],
+                            vec![self.block_reader.sync_read_native_columns_data(part)?],
+                            vec![deleted],
+                        ),
+                    )))
+                }
+            };
         }
     }
 }
@@ -126,8 +158,9 @@ impl Processor for ReadNativeDataSource<false> {
             return Ok(Event::NeedConsume);
         }
 
-        if let Some((part, data)) = self.output_data.take() {
-            let output = DataBlock::empty_with_meta(NativeDataSourceMeta::create(part, data));
+        if let Some((part, data, deleted)) = self.output_data.take() {
+            let output =
+                DataBlock::empty_with_meta(NativeDataSourceMeta::create(part, data, deleted));
             self.output.push_data(Ok(output));
             // return Ok(Event::NeedConsume);
         }
@@ -136,7 +169,17 @@ impl Processor for ReadNativeDataSource<false> {
     }
 
     async fn async_process(&mut self) -> Result<()> {
-        let parts = self.partitions.steal(self.id, self.batch_size);
+        let mut parts = self.partitions.steal(self.id, self.batch_size);
+        if let Some(runtime_filter) = &mut self.runtime_filter {
+            // Steal more parts if all of them are pruned by the runtime filters.
+            while !parts.is_empty() {
+                parts = runtime_filter.prune(parts).await?;
+                if !parts.is_empty() {
+                    break;
+                }
+                parts = self.partitions.steal(self.id, self.batch_size);
+            }
+        }
 
         if !parts.is_empty() {
             let mut chunks = Vec::with_capacity(parts.len());
@@ -146,13 +189,23 @@ impl Processor for ReadNativeDataSource<false> {
 
                 chunks.push(async move {
                     let handler = tokio::spawn(async move {
-                        block_reader.async_read_native_columns_data(part).await
+                        let deleted = read_deletion_vector(
+                            &block_reader.operator,
+                            FusePartInfo::from_part(&part)?.deletion_vector.as_ref(),
+                        )
+                        .await?;
+                        let data = block_reader.async_read_native_columns_data(part).await?;
+                        Ok::<_, ErrorCode>((data, deleted))
                     });
                     handler.await.unwrap()
                 });
             }
 
-            self.output_data = Some((parts, futures::future::try_join_all(chunks).await?));
+            let (data, deleted): (Vec<_>, Vec<_>) = futures::future::try_join_all(chunks)
+                .await?
+                .into_iter()
+                .unzip();
+            self.output_data = Some((parts, data, deleted));
             return Ok(());
         }
 
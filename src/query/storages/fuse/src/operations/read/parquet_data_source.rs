@@ -16,6 +16,7 @@ use std::any::Any;
 use std::fmt::Debug;
 use std::fmt::Formatter;
 
+use common_arrow::arrow::bitmap::Bitmap;
 use common_catalog::plan::PartInfoPtr;
 use common_exception::Result;
 use common_expression::BlockMetaInfo;
@@ -28,11 +29,21 @@ use crate::io::MergeIOReadResult;
 pub struct DataSourceMeta {
     pub part: Vec<PartInfoPtr>,
     pub data: Vec<MergeIOReadResult>,
+    /// the deleted rows of each part, if the part has a deletion vector
+    pub deleted: Vec<Option<Bitmap>>,
 }
 
 impl DataSourceMeta {
-    pub fn create(part: Vec<PartInfoPtr>, data: Vec<MergeIOReadResult>) -> BlockMetaInfoPtr {
-        Box::new(DataSourceMeta { part, data })
+    pub fn create(
+        part: Vec<PartInfoPtr>,
+        data: Vec<MergeIOReadResult>,
+        deleted: Vec<Option<Bitmap>>,
+    ) -> BlockMetaInfoPtr {
+        Box::new(DataSourceMeta {
+            part,
+            data,
+            deleted,
+        })
     }
 }
 
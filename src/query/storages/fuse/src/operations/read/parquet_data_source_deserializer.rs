@@ -16,8 +16,10 @@ use std::any::Any;
 use std::sync::Arc;
 use std::time::Instant;
 
+use common_arrow::arrow::bitmap::Bitmap;
 use common_base::base::Progress;
 use common_base::base::ProgressValues;
+use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::PartInfoPtr;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
@@ -29,11 +31,13 @@ use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_core::processors::Processor;
 
 use crate::fuse_part::FusePartInfo;
+use crate::io::filter_deleted_rows;
 use crate::io::BlockReader;
 use crate::io::MergeIOReadResult;
 use crate::io::UncompressedBuffer;
 use crate::metrics::metrics_inc_remote_io_deserialize_milliseconds;
 use crate::operations::read::parquet_data_source::DataSourceMeta;
+use crate::operations::read::runtime_filter::RuntimeFilterPruner;
 
 pub struct DeserializeDataTransform {
     scan_progress: Arc<Progress>,
@@ -44,7 +48,9 @@ pub struct DeserializeDataTransform {
     output_data: Option<DataBlock>,
     parts: Vec<PartInfoPtr>,
     chunks: Vec<MergeIOReadResult>,
+    deleted: Vec<Option<Bitmap>>,
     uncompressed_buffer: Arc<UncompressedBuffer>,
+    runtime_filter: Option<RuntimeFilterPruner>,
 }
 
 unsafe impl Send for DeserializeDataTransform {}
@@ -53,11 +59,14 @@ impl DeserializeDataTransform {
     pub fn create(
         ctx: Arc<dyn TableContext>,
         block_reader: Arc<BlockReader>,
+        plan: &DataSourcePlan,
         input: Arc<InputPort>,
         output: Arc<OutputPort>,
     ) -> Result<ProcessorPtr> {
         let buffer_size = ctx.get_settings().get_parquet_uncompressed_buffer_size()? as usize;
         let scan_progress = ctx.get_scan_progress();
+        let runtime_filter =
+            RuntimeFilterPruner::try_create(ctx, plan, block_reader.operator.clone())?;
         Ok(ProcessorPtr::create(Box::new(DeserializeDataTransform {
             scan_progress,
             block_reader,
@@ -66,7 +75,9 @@ impl DeserializeDataTransform {
             output_data: None,
             parts: vec![],
             chunks: vec![],
+            deleted: vec![],
             uncompressed_buffer: UncompressedBuffer::new(buffer_size),
+            runtime_filter,
         })))
     }
 }
@@ -113,6 +124,7 @@ impl Processor for DeserializeDataTransform {
                 {
                     self.parts = source_meta.part.clone();
                     self.chunks = std::mem::take(&mut source_meta.data);
+                    self.deleted = std::mem::take(&mut source_meta.deleted);
                     return Ok(Event::Sync);
                 }
             }
@@ -133,6 +145,7 @@ impl Processor for DeserializeDataTransform {
     fn process(&mut self) -> Result<()> {
         let part = self.parts.pop();
         let chunks = self.chunks.pop();
+        let deleted = self.deleted.pop().flatten();
         if let Some((part, read_res)) = part.zip(chunks) {
             let start = Instant::now();
 
@@ -147,6 +160,7 @@ impl Processor for DeserializeDataTransform {
                 columns_chunks,
                 Some(self.uncompressed_buffer.clone()),
             )?;
+            let data_block = filter_deleted_rows(data_block, deleted.as_ref())?;
 
             // Perf.
             {
@@ -159,6 +173,13 @@ impl Processor for DeserializeDataTransform {
             };
             self.scan_progress.incr(&progress_values);
 
+            let data_block = match &mut self.runtime_filter {
+                Some(runtime_filter) => {
+                    runtime_filter.filter_block(data_block, &self.block_reader.projected_schema)?
+                }
+                None => data_block,
+            };
+
             self.output_data = Some(data_block);
         }
 
@@ -15,10 +15,13 @@
 use std::any::Any;
 use std::sync::Arc;
 
+use common_arrow::arrow::bitmap::Bitmap;
 use common_base::base::tokio;
+use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::PartInfoPtr;
 use common_catalog::plan::StealablePartitions;
 use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataBlock;
 use common_pipeline_core::processors::port::OutputPort;
@@ -29,9 +32,12 @@ use common_pipeline_sources::SyncSource;
 use common_pipeline_sources::SyncSourcer;
 
 use crate::fuse_part::FusePartInfo;
+use crate::io::read_deletion_vector;
+use crate::io::sync_read_deletion_vector;
 use crate::io::BlockReader;
 use crate::io::ReadSettings;
 use crate::operations::read::parquet_data_source::DataSourceMeta;
+use crate::operations::read::runtime_filter::RuntimeFilterPruner;
 use crate::MergeIOReadResult;
 
 pub struct ReadParquetDataSource<const BLOCKING_IO: bool> {
@@ -41,8 +47,13 @@ pub struct ReadParquetDataSource<const BLOCKING_IO: bool> {
     block_reader: Arc<BlockReader>,
 
     output: Arc<OutputPort>,
-    output_data: Option<(Vec<PartInfoPtr>, Vec<MergeIOReadResult>)>,
+    output_data: Option<(
+        Vec<PartInfoPtr>,
+        Vec<MergeIOReadResult>,
+        Vec<Option<Bitmap>>,
+    )>,
     partitions: StealablePartitions,
+    runtime_filter: Option<RuntimeFilterPruner>,
 }
 
 impl<const BLOCKING_IO: bool> ReadParquetDataSource<BLOCKING_IO> {
@@ -52,8 +63,11 @@ impl<const BLOCKING_IO: bool> ReadParquetDataSource<BLOCKING_IO> {
         output: Arc<OutputPort>,
         block_reader: Arc<BlockReader>,
         partitions: StealablePartitions,
+        plan: &DataSourcePlan,
     ) -> Result<ProcessorPtr> {
         let batch_size = ctx.get_settings().get_storage_fetch_part_num()? as usize;
+        let runtime_filter =
+            RuntimeFilterPruner::try_create(ctx.clone(), plan, block_reader.operator.clone())?;
 
         if BLOCKING_IO {
             SyncSourcer::create(ctx.clone(), output.clone(), ReadParquetDataSource::<true> {
@@ -64,6 +78,7 @@ impl<const BLOCKING_IO: bool> ReadParquetDataSource<BLOCKING_IO> {
                 finished: false,
                 output_data: None,
                 partitions,
+                runtime_filter,
             })
         } else {
             Ok(ProcessorPtr::create(Box::new(ReadParquetDataSource::<
@@ -76,6 +91,7 @@ impl<const BLOCKING_IO: bool> ReadParquetDataSource<BLOCKING_IO> {
                 finished: false,
                 output_data: None,
                 partitions,
+                runtime_filter,
             })))
         }
     }
@@ -85,16 +101,27 @@ impl SyncSource for ReadParquetDataSource<true> {
     const NAME: &'static str = "SyncReadParquetDataSource";
 
     fn generate(&mut self) -> Result<Option<DataBlock>> {
-        match self.partitions.steal_one(self.id) {
-            None => Ok(None),
-            Some(part) => Ok(Some(DataBlock::empty_with_meta(DataSourceMeta::create(
+        while let Some(part) = self.partitions.steal_one(self.id) {
+            if let Some(runtime_filter) = &mut self.runtime_filter {
+                if !runtime_filter.should_keep(&part)? {
+                    continue;
+                }
+            }
+
+            let deleted = sync_read_deletion_vector(
+                &self.block_reader.operator,
+                FusePartInfo::from_part(&part)?.deletion_vector.as_ref(),
+            )?;
+            return Ok(Some(DataBlock::empty_with_meta(DataSourceMeta::create(
                 vec![part.clone()],
                 vec![self.block_reader.sync_read_columns_data_by_merge_io(
                     &ReadSettings::from_ctx(&self.partitions.ctx)?,
                     part,
                 )?],
-            )))),
+                vec![deleted],
+            ))));
         }
+        Ok(None)
     }
 }
 
@@ -122,8 +149,8 @@ impl Processor for ReadParquetDataSource<false> {
             return Ok(Event::NeedConsume);
         }
 
-        if let Some((part, data)) = self.output_data.take() {
-            let output = DataBlock::empty_with_meta(DataSourceMeta::create(part, data));
+        if let Some((part, data, deleted)) = self.output_data.take() {
+            let output = DataBlock::empty_with_meta(DataSourceMeta::create(part, data, deleted));
 
             self.output.push_data(Ok(output));
             // return Ok(Event::NeedConsume);
@@ -133,7 +160,17 @@ impl Processor for ReadParquetDataSource<false> {
     }
 
     async fn async_process(&mut self) -> Result<()> {
-        let parts = self.partitions.steal(self.id, self.batch_size);
+        let mut parts = self.partitions.steal(self.id, self.batch_size);
+        if let Some(runtime_filter) = &mut self.runtime_filter {
+            // Steal more parts if all of them are pruned by the runtime filters.
+            while !parts.is_empty() {
+                parts = runtime_filter.prune(parts).await?;
+                if !parts.is_empty() {
+                    break;
+                }
+                parts = self.partitions.steal(self.id, self.batch_size);
+            }
+        }
 
         if !parts.is_empty() {
             let mut chunks = Vec::with_capacity(parts.len());
@@ -146,20 +183,30 @@ impl Processor for ReadParquetDataSource<false> {
                     tokio::spawn(async move {
                         let part = FusePartInfo::from_part(&part)?;
 
-                        block_reader
+                        let data = block_reader
                             .read_columns_data_by_merge_io(
                                 &settings,
                                 &part.location,
                                 &part.columns_meta,
                             )
-                            .await
+                            .await?;
+                        let deleted = read_deletion_vector(
+                            &block_reader.operator,
+                            part.deletion_vector.as_ref(),
+                        )
+                        .await?;
+                        Ok::<_, ErrorCode>((data, deleted))
                     })
                     .await
                     .unwrap()
                 });
             }
 
-            self.output_data = Some((parts, futures::future::try_join_all(chunks).await?));
+            let (data, deleted): (Vec<_>, Vec<_>) = futures::future::try_join_all(chunks)
+                .await?
+                .into_iter()
+                .unzip();
+            self.output_data = Some((parts, data, deleted));
             return Ok(());
         }
 
@@ -0,0 +1,337 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::PartInfoPtr;
+use common_catalog::runtime_filter_info::RuntimeFilter;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::type_check::check_function;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::UInt64Type;
+use common_expression::types::ValueType;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::TableSchemaRef;
+use common_functions::scalars::BUILTIN_FUNCTIONS;
+use opendal::Operator;
+use storages_common_index::filters::Filter;
+use storages_common_index::BloomIndex;
+use storages_common_pruner::RangePruner;
+use storages_common_pruner::RangePrunerCreator;
+
+use crate::fuse_part::FusePartInfo;
+use crate::pruning::BloomPruner;
+use crate::pruning::BloomPrunerCreator;
+
+/// Prunes the parts and filters the rows of a table scan with the runtime filters
+/// pushed down from the build side of hash joins.
+///
+/// The runtime filters are registered while the scan is running, so they are fetched
+/// from the context again before each part is checked.
+pub struct RuntimeFilterPruner {
+    ctx: Arc<dyn TableContext>,
+    scan_id: usize,
+    func_ctx: FunctionContext,
+    table_schema: TableSchemaRef,
+    dal: Operator,
+
+    num_filters: usize,
+    filters: Vec<RuntimeFilter>,
+    range_pruner: Option<Arc<dyn RangePruner + Send + Sync>>,
+    bloom_pruner: Option<Arc<dyn BloomPruner + Send + Sync>>,
+}
+
+impl RuntimeFilterPruner {
+    /// Returns `None` if runtime filter is disabled.
+    pub fn try_create(
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        dal: Operator,
+    ) -> Result<Option<Self>> {
+        if !ctx.get_settings().get_enable_runtime_filter()? {
+            return Ok(None);
+        }
+
+        Ok(Some(RuntimeFilterPruner {
+            func_ctx: ctx.get_function_context()?,
+            ctx,
+            scan_id: plan.scan_id,
+            table_schema: plan.source_info.schema(),
+            dal,
+            num_filters: 0,
+            filters: vec![],
+            range_pruner: None,
+            bloom_pruner: None,
+        }))
+    }
+
+    /// Fetch the runtime filters of the scan, and rebuild the pruners if there are new ones.
+    fn refresh(&mut self) -> Result<()> {
+        let info = match self.ctx.get_runtime_filter_with_id(self.scan_id) {
+            Some(info) if info.len() != self.num_filters => info,
+            _ => return Ok(()),
+        };
+        self.num_filters = info.len();
+        self.filters = info
+            .filters()
+            .iter()
+            .filter(|filter| {
+                self.table_schema
+                    .field_with_name(&filter.column_name)
+                    .is_ok()
+            })
+            .cloned()
+            .collect();
+
+        let mut range_exprs = Vec::with_capacity(self.filters.len());
+        let mut bloom_exprs = Vec::with_capacity(self.filters.len());
+        for filter in &self.filters {
+            let field = self.table_schema.field_with_name(&filter.column_name)?;
+            let column = Expr::ColumnRef {
+                span: None,
+                id: field.name().clone(),
+                data_type: field.data_type().into(),
+                display_name: field.name().clone(),
+            };
+            range_exprs.push(range_filter_expr(&column, filter)?);
+            if let Some(inlist) = &filter.inlist {
+                if !inlist.is_empty() {
+                    bloom_exprs.push(inlist_filter_expr(&column, filter, inlist)?);
+                }
+            }
+        }
+
+        let range_expr = conjunction(range_exprs)?;
+        let bloom_expr = conjunction(bloom_exprs)?;
+        self.range_pruner = Some(RangePrunerCreator::try_create(
+            self.func_ctx,
+            &self.table_schema,
+            range_expr.as_ref(),
+        )?);
+        self.bloom_pruner = BloomPrunerCreator::create(
+            self.func_ctx,
+            &self.table_schema,
+            self.dal.clone(),
+            bloom_expr.as_ref(),
+        )?;
+        Ok(())
+    }
+
+    /// Check the part with the column statistics, returns false if it can be pruned.
+    pub fn should_keep(&mut self, part: &PartInfoPtr) -> Result<bool> {
+        self.refresh()?;
+        match &self.range_pruner {
+            None => Ok(true),
+            Some(range_pruner) => {
+                let part = FusePartInfo::from_part(part)?;
+                Ok(range_pruner.should_keep(&part.columns_stat))
+            }
+        }
+    }
+
+    /// Check the part with the column statistics and the bloom index, returns false if it
+    /// can be pruned.
+    async fn async_should_keep(&mut self, part: &PartInfoPtr) -> Result<bool> {
+        if !self.should_keep(part)? {
+            return Ok(false);
+        }
+
+        if let Some(bloom_pruner) = &self.bloom_pruner {
+            let part = FusePartInfo::from_part(part)?;
+            if let Some((location, size)) = &part.bloom_index {
+                let column_ids = part.columns_meta.keys().cloned().collect();
+                return Ok(bloom_pruner
+                    .should_keep(&Some(location.clone()), *size, column_ids)
+                    .await);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Prune the parts with the column statistics and the bloom index.
+    pub async fn prune(&mut self, parts: Vec<PartInfoPtr>) -> Result<Vec<PartInfoPtr>> {
+        let mut kept = Vec::with_capacity(parts.len());
+        for part in parts {
+            if self.async_should_keep(&part).await? {
+                kept.push(part);
+            }
+        }
+        Ok(kept)
+    }
+
+    /// Filter out the rows that can not match any build key, the `schema` is the schema of
+    /// the block.
+    pub fn filter_block(&mut self, block: DataBlock, schema: &TableSchemaRef) -> Result<DataBlock> {
+        self.refresh()?;
+        let num_rows = block.num_rows();
+        if self.filters.is_empty() || num_rows == 0 {
+            return Ok(block);
+        }
+
+        let mut bitmap = MutableBitmap::from_len_set(num_rows);
+        for filter in &self.filters {
+            if let Ok(index) = schema.index_of(&filter.column_name) {
+                let entry = block.get_by_offset(index);
+                let column = entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, num_rows);
+                apply_runtime_filter(self.func_ctx, filter, &column, &mut bitmap)?;
+            }
+        }
+
+        let bitmap: Bitmap = bitmap.into();
+        match bitmap.unset_bits() {
+            0 => Ok(block),
+            _ => block.filter_with_bitmap(&bitmap),
+        }
+    }
+}
+
+/// `column >= min AND column <= max`, or false if there is no build key.
+fn range_filter_expr(column: &Expr<String>, filter: &RuntimeFilter) -> Result<Expr<String>> {
+    match &filter.min_max {
+        None => Ok(Expr::Constant {
+            span: None,
+            scalar: Scalar::Boolean(false),
+            data_type: DataType::Boolean,
+        }),
+        Some((min, max)) => {
+            let gte = compare_expr("gte", column, filter, min)?;
+            let lte = compare_expr("lte", column, filter, max)?;
+            check_function(None, "and", &[], &[gte, lte], &BUILTIN_FUNCTIONS)
+        }
+    }
+}
+
+/// `column = v1 OR column = v2 OR ...`, which can be checked by the bloom index.
+fn inlist_filter_expr(
+    column: &Expr<String>,
+    filter: &RuntimeFilter,
+    inlist: &[Scalar],
+) -> Result<Expr<String>> {
+    let exprs = inlist
+        .iter()
+        .map(|value| compare_expr("eq", column, filter, value))
+        .collect::<Result<Vec<_>>>()?;
+    disjunction(exprs)
+}
+
+fn compare_expr(
+    name: &str,
+    column: &Expr<String>,
+    filter: &RuntimeFilter,
+    value: &Scalar,
+) -> Result<Expr<String>> {
+    let constant = Expr::Constant {
+        span: None,
+        scalar: value.clone(),
+        data_type: filter.data_type.clone(),
+    };
+    check_function(
+        None,
+        name,
+        &[],
+        &[column.clone(), constant],
+        &BUILTIN_FUNCTIONS,
+    )
+}
+
+fn conjunction(exprs: Vec<Expr<String>>) -> Result<Option<Expr<String>>> {
+    exprs.into_iter().try_fold(None, |acc, expr| {
+        Ok(Some(match acc {
+            None => expr,
+            Some(acc) => check_function(None, "and", &[], &[acc, expr], &BUILTIN_FUNCTIONS)?,
+        }))
+    })
+}
+
+/// Build a balanced `OR` tree, the IN-list may be long.
+fn disjunction(mut exprs: Vec<Expr<String>>) -> Result<Expr<String>> {
+    if exprs.len() == 1 {
+        return Ok(exprs.pop().unwrap());
+    }
+    let right = exprs.split_off(exprs.len() / 2);
+    let left = disjunction(exprs)?;
+    let right = disjunction(right)?;
+    check_function(None, "or", &[], &[left, right], &BUILTIN_FUNCTIONS)
+}
+
+/// Unset the rows of the bitmap whose value can not match any build key of the filter.
+fn apply_runtime_filter(
+    func_ctx: FunctionContext,
+    filter: &RuntimeFilter,
+    column: &Column,
+    bitmap: &mut MutableBitmap,
+) -> Result<()> {
+    let (column, validity) = match column {
+        Column::Nullable(column) => (&column.column, Some(&column.validity)),
+        column => (column, None),
+    };
+    let (min, max) = match (&filter.min_max, column) {
+        (Some((min, max)), column) if !matches!(column, Column::Null { .. }) => (min, max),
+        _ => {
+            // Null never matches, and there is no build key.
+            for row in 0..column.len() {
+                bitmap.set(row, false);
+            }
+            return Ok(());
+        }
+    };
+
+    // The IN-list is exact, fallback to the bloom filters if there are too many build keys.
+    let digests = match (&filter.inlist, filter.blooms.is_empty()) {
+        (None, false) => {
+            let digests = BloomIndex::calculate_column_digest(
+                func_ctx,
+                column,
+                &filter.data_type,
+                &DataType::Number(NumberDataType::UInt64),
+            )?;
+            Some(UInt64Type::try_downcast_column(&digests).unwrap())
+        }
+        _ => None,
+    };
+
+    for (row, value) in column.iter().enumerate() {
+        if !bitmap.get(row) {
+            continue;
+        }
+        let keep = validity.map_or(true, |validity| validity.get_bit(row))
+            && value >= min.as_ref()
+            && value <= max.as_ref()
+            && match (&filter.inlist, &digests) {
+                (Some(inlist), _) => inlist
+                    .binary_search_by(|key| key.as_ref().cmp(&value))
+                    .is_ok(),
+                (None, Some(digests)) => filter
+                    .blooms
+                    .iter()
+                    .any(|bloom| bloom.contains_digest(digests[row])),
+                (None, None) => true,
+            };
+        if !keep {
+            bitmap.set(row, false);
+        }
+    }
+    Ok(())
+}
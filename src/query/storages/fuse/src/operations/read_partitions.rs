@@ -28,6 +28,7 @@ use common_catalog::plan::TopK;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
+use common_expression::ColumnId;
 use common_expression::TableSchemaRef;
 use common_meta_app::schema::TableInfo;
 use common_storage::ColumnNodes;
@@ -39,7 +40,9 @@ use storages_common_cache_manager::CachedObject;
 use storages_common_index::Index;
 use storages_common_index::RangeIndex;
 use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::ColumnMeta;
 use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::StatisticsOfColumns;
 use tracing::debug;
 use tracing::info;
 
@@ -228,13 +231,19 @@ impl FuseTable {
         let mut block_metas = block_metas.to_vec();
         if let Some(top_k) = &top_k {
             block_metas.sort_by(|a, b| {
-                let a = a.1.col_stats.get(&top_k.column_id).unwrap();
-                let b = b.1.col_stats.get(&top_k.column_id).unwrap();
+                let a = a.1.col_stats.get(&top_k.column_id);
+                let b = b.1.col_stats.get(&top_k.column_id);
 
-                if top_k.asc {
-                    (a.min.as_ref(), a.max.as_ref()).cmp(&(b.min.as_ref(), b.max.as_ref()))
-                } else {
-                    (b.max.as_ref(), b.min.as_ref()).cmp(&(a.max.as_ref(), a.min.as_ref()))
+                match (a, b) {
+                    (Some(a), Some(b)) if top_k.asc => {
+                        (a.min.as_ref(), a.max.as_ref()).cmp(&(b.min.as_ref(), b.max.as_ref()))
+                    }
+                    (Some(a), Some(b)) => {
+                        (b.max.as_ref(), b.min.as_ref()).cmp(&(a.max.as_ref(), a.min.as_ref()))
+                    }
+                    // blocks written before the column was added or modified have no statistics
+                    // of it, they can't be ordered and are read first.
+                    (a, b) => a.is_some().cmp(&b.is_some()),
                 }
             });
         }
@@ -283,7 +292,7 @@ impl FuseTable {
 
         let mut remaining = limit;
         for (range, block_meta) in block_metas.iter() {
-            let rows = block_meta.row_count as usize;
+            let rows = block_meta.live_row_count() as usize;
             partitions.partitions.push(Self::all_columns_part(
                 schema,
                 range.clone(),
@@ -333,7 +342,7 @@ impl FuseTable {
                 projection,
             ));
 
-            let rows = block_meta.row_count as usize;
+            let rows = block_meta.live_row_count() as usize;
 
             statistics.read_rows += rows;
             for column in &columns {
@@ -384,11 +393,16 @@ impl FuseTable {
         let rows_count = meta.row_count;
         let location = meta.location.0.clone();
         let format_version = meta.location.1;
+        let columns_stat = Self::part_columns_stat(meta, &columns_meta);
+        let bloom_index = meta
+            .bloom_filter_index_location
+            .clone()
+            .map(|location| (location, meta.bloom_filter_index_size));
 
-        let sort_min_max = top_k.as_ref().map(|top_k| {
-            let stat = meta.col_stats.get(&top_k.column_id).unwrap();
-            (stat.min.clone(), stat.max.clone())
-        });
+        let sort_min_max = top_k
+            .as_ref()
+            .and_then(|top_k| meta.col_stats.get(&top_k.column_id))
+            .map(|stat| (stat.min.clone(), stat.max.clone()));
 
         FusePartInfo::create(
             location,
@@ -398,9 +412,23 @@ impl FuseTable {
             meta.compression(),
             sort_min_max,
             range,
+            columns_stat,
+            bloom_index,
+            meta.deletion_vector.clone(),
         )
     }
 
+    fn part_columns_stat(
+        meta: &BlockMeta,
+        columns_meta: &HashMap<ColumnId, ColumnMeta>,
+    ) -> StatisticsOfColumns {
+        meta.col_stats
+            .iter()
+            .filter(|(column_id, _)| columns_meta.contains_key(column_id))
+            .map(|(column_id, stat)| (*column_id, stat.clone()))
+            .collect()
+    }
+
     fn projection_part(
         meta: &BlockMeta,
         range: Option<Range<usize>>,
@@ -418,16 +446,37 @@ impl FuseTable {
                     columns_meta.insert(*column_id, column_meta.clone());
                 }
             }
+
+            // the block was written before the data type of the column was modified,
+            // take the most recent previous version of the column it stores.
+            if !column.evolved_from.is_empty()
+                && column
+                    .leaf_column_ids
+                    .iter()
+                    .all(|column_id| !meta.col_metas.contains_key(column_id))
+            {
+                if let Some((column_id, column_meta)) = column
+                    .evolved_from
+                    .iter()
+                    .find_map(|(id, _)| meta.col_metas.get(id).map(|m| (*id, m)))
+                {
+                    columns_meta.insert(column_id, column_meta.clone());
+                }
+            }
         }
 
         let rows_count = meta.row_count;
         let location = meta.location.0.clone();
         let format_version = meta.location.1;
+        let columns_stat = Self::part_columns_stat(meta, &columns_meta);
+        let bloom_index = meta
+            .bloom_filter_index_location
+            .clone()
+            .map(|location| (location, meta.bloom_filter_index_size));
 
-        let sort_min_max = top_k.map(|top_k| {
-            let stat = meta.col_stats.get(&top_k.column_id).unwrap();
-            (stat.min.clone(), stat.max.clone())
-        });
+        let sort_min_max = top_k
+            .and_then(|top_k| meta.col_stats.get(&top_k.column_id))
+            .map(|stat| (stat.min.clone(), stat.max.clone()));
 
         // TODO
         // row_count should be a hint value of  LIMIT,
@@ -440,6 +489,9 @@ impl FuseTable {
             meta.compression(),
             sort_min_max,
             range,
+            columns_stat,
+            bloom_index,
+            meta.deletion_vector.clone(),
         )
     }
 }
@@ -131,6 +131,7 @@ impl FuseTable {
             description,
             tbl_args: self.table_args(),
             push_downs: None,
+            scan_id: 0,
         };
 
         ctx.set_partitions(plan.parts.clone())?;
@@ -198,6 +199,7 @@ impl FuseTable {
             )?))
         })?;
 
+        let agg_indexes = self.aggregating_indexes()?;
         pipeline.add_sink(|input| {
             FuseTableSink::try_create(
                 input,
@@ -208,6 +210,7 @@ impl FuseTable {
                 cluster_stats_gen.clone(),
                 block_compact_thresholds,
                 self.table_info.schema(),
+                agg_indexes.clone(),
                 None,
             )
         })?;
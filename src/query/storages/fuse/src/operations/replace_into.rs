@@ -0,0 +1,199 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_catalog::plan::MergeIntoInfo;
+use common_catalog::plan::MergeIntoSource;
+use common_catalog::plan::MergeMatchedOperation;
+use common_catalog::plan::MergeUnmatchedOperation;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::type_check::check_function;
+use common_expression::Expr;
+use common_expression::FieldIndex;
+use common_expression::RemoteExpr;
+use common_expression::Scalar;
+use common_expression::TableSchemaRef;
+use common_functions::scalars::BUILTIN_FUNCTIONS;
+
+use crate::pipelines::Pipeline;
+use crate::FuseTable;
+
+/// If the source has more distinct keys than this, the blocks are pruned by the
+/// min/max of the keys instead of the keys themselves.
+const MAX_REPLACE_INTO_PRUNING_KEYS: usize = 1024;
+
+impl FuseTable {
+    /// REPLACE INTO t ON (keys) ...
+    ///
+    /// It is done as `MERGE INTO t USING source ON keys WHEN MATCHED THEN DELETE
+    /// WHEN NOT MATCHED THEN INSERT *`, where the source passes all of its rows to the
+    /// NOT MATCHED clause. So every row of the table having the same keys is deleted,
+    /// even if there are several of them, and every row of the source is inserted.
+    /// The keys of the source are pushed down as a filter, so only the blocks that may
+    /// contain the keys are read, by the min/max statistics and the bloom index of the blocks.
+    pub async fn do_replace_into(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        source: Arc<dyn MergeIntoSource>,
+        on_conflict_fields: Vec<FieldIndex>,
+        conflict_keys: Vec<Vec<Scalar>>,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        if source.num_rows() == 0 {
+            return Ok(());
+        }
+
+        let mut keys = HashSet::with_capacity(conflict_keys.len());
+        for key in conflict_keys {
+            if !keys.insert(key) {
+                return Err(ErrorCode::BadArguments(
+                    "Duplicate rows in the source of REPLACE INTO have the same conflict keys",
+                ));
+            }
+        }
+
+        let schema = self.schema();
+        let filter = build_pruning_filter(&schema, &on_conflict_fields, keys);
+
+        let column_ref = |idx: usize| {
+            let field = schema.field(idx);
+            RemoteExpr::ColumnRef {
+                span: None,
+                id: idx,
+                data_type: field.data_type().into(),
+                display_name: field.name().clone(),
+            }
+        };
+        let merge_into_info = MergeIntoInfo {
+            source,
+            matched: vec![MergeMatchedOperation {
+                condition: None,
+                update_list: None,
+            }],
+            unmatched: vec![MergeUnmatchedOperation {
+                condition: None,
+                values: (0..schema.num_fields()).map(column_ref).collect(),
+            }],
+        };
+
+        self.merge_into_with_filter(ctx, merge_into_info, filter, pipeline)
+            .await
+    }
+}
+
+/// Build the filter to find the blocks which may conflict with the source.
+///
+/// If there are not too many keys, the filter is `(k1 = v1 AND k2 = v2) OR ...`, which
+/// can be applied to both the min/max statistics and the bloom index. Otherwise the
+/// filter is `k1 >= min1 AND k1 <= max1 AND ...`, which is only for the min/max statistics.
+fn build_pruning_filter(
+    schema: &TableSchemaRef,
+    on_conflict_fields: &[FieldIndex],
+    keys: HashSet<Vec<Scalar>>,
+) -> Option<RemoteExpr<String>> {
+    if keys.is_empty() {
+        return None;
+    }
+
+    let column_refs = on_conflict_fields
+        .iter()
+        .map(|idx| {
+            let field = schema.field(*idx);
+            Expr::ColumnRef {
+                span: None,
+                id: field.name().clone(),
+                data_type: field.data_type().into(),
+                display_name: field.name().clone(),
+            }
+        })
+        .collect::<Vec<_>>();
+    let compare = |name: &str, column_ref: &Expr<String>, scalar: Scalar| {
+        let constant = Expr::Constant {
+            span: None,
+            scalar,
+            data_type: column_ref.data_type().clone(),
+        };
+        check_function(
+            None,
+            name,
+            &[],
+            &[column_ref.clone(), constant],
+            &BUILTIN_FUNCTIONS,
+        )
+    };
+
+    let filter = if keys.len() <= MAX_REPLACE_INTO_PRUNING_KEYS {
+        keys.into_iter()
+            .map(|key| {
+                let preds = column_refs
+                    .iter()
+                    .zip(key)
+                    .map(|(column_ref, scalar)| compare("eq", column_ref, scalar))
+                    .collect::<Result<Vec<_>>>()?;
+                conjunction("and", preds)
+            })
+            .collect::<Result<Vec<_>>>()
+            .and_then(|preds| conjunction("or", preds))
+    } else {
+        let mut preds = vec![];
+        for (idx, column_ref) in column_refs.iter().enumerate() {
+            let (min, max) = match (
+                keys.iter().map(|key| &key[idx]).min(),
+                keys.iter().map(|key| &key[idx]).max(),
+            ) {
+                (Some(min), Some(max)) => (min.clone(), max.clone()),
+                _ => continue,
+            };
+            preds.push(compare("gte", column_ref, min));
+            preds.push(compare("lte", column_ref, max));
+        }
+        preds
+            .into_iter()
+            .collect::<Result<Vec<_>>>()
+            .and_then(|preds| conjunction("and", preds))
+    };
+
+    // The pruning is only an optimization, fall back to read all the blocks.
+    filter.ok().map(|expr| expr.as_remote_expr())
+}
+
+/// Combine the predicates with `and` or `or` into a balanced tree, to keep the
+/// expression shallow when there are many keys.
+fn conjunction(name: &str, mut preds: Vec<Expr<String>>) -> Result<Expr<String>> {
+    while preds.len() > 1 {
+        let mut combined = Vec::with_capacity((preds.len() + 1) / 2);
+        let mut iter = preds.into_iter();
+        while let Some(left) = iter.next() {
+            match iter.next() {
+                Some(right) => combined.push(check_function(
+                    None,
+                    name,
+                    &[],
+                    &[left, right],
+                    &BUILTIN_FUNCTIONS,
+                )?),
+                None => combined.push(left),
+            }
+        }
+        preds = combined;
+    }
+    preds
+        .pop()
+        .ok_or_else(|| ErrorCode::Internal("No predicate to combine"))
+}
@@ -67,15 +67,26 @@ impl FuseTable {
 
             let table_id = self.table_info.ident.table_id;
             let table_version = self.table_info.ident.seq;
-            let catalog = ctx.get_catalog(self.table_info.catalog())?;
+            let req = UpdateTableMetaReq {
+                table_id,
+                seq: MatchSeq::Exact(table_version),
+                new_table_meta,
+            };
 
-            catalog
-                .update_table_meta(&self.table_info, UpdateTableMetaReq {
-                    table_id,
-                    seq: MatchSeq::Exact(table_version),
-                    new_table_meta,
-                })
-                .await?;
+            // in an explicit transaction (`DELETE FROM t` without a filter), the new table
+            // meta is committed with the transaction, like the other mutations. The copied
+            // files of the table are kept, as a `DELETE` with a filter does.
+            {
+                let txn_mgr = ctx.txn_mgr();
+                let mut txn_mgr = txn_mgr.lock();
+                if txn_mgr.is_active() {
+                    txn_mgr.update_table_meta(self.table_info.catalog(), req);
+                    return Ok(());
+                }
+            }
+
+            let catalog = ctx.get_catalog(self.table_info.catalog())?;
+            catalog.update_table_meta(&self.table_info, req).await?;
 
             catalog
                 .truncate_table(&self.table_info, TruncateTableReq { table_id })
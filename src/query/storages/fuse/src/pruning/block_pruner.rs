@@ -91,7 +91,7 @@ impl BlockPruner {
                 }
 
                 let block_meta = block_meta.clone();
-                let row_count = block_meta.row_count;
+                let row_count = block_meta.live_row_count();
                 if range_pruner.should_keep(&block_meta.col_stats) {
                     // Perf.
                     {
@@ -138,7 +138,7 @@ impl BlockPruner {
 
                                 let (keep, range) =
                                     page_pruner.should_keep(&block_meta.cluster_stats);
-                                (block_idx, keep, range)
+                                (block_idx, keep, Self::page_range(&block_meta, range))
                             } else {
                                 (block_idx, keep, None)
                             }
@@ -218,7 +218,7 @@ impl BlockPruner {
             if limit_pruner.exceeded() {
                 break;
             }
-            let row_count = block_meta.row_count;
+            let row_count = block_meta.live_row_count();
             if range_pruner.should_keep(&block_meta.col_stats)
                 && limit_pruner.within_limit(row_count)
             {
@@ -231,6 +231,7 @@ impl BlockPruner {
                 }
 
                 let (keep, range) = page_pruner.should_keep(&block_meta.cluster_stats);
+                let range = Self::page_range(block_meta, range);
                 if keep {
                     result.push((
                         BlockMetaIndex {
@@ -251,4 +252,13 @@ impl BlockPruner {
 
         Ok(result)
     }
+
+    // Rows of the deletion vector are addressed from the beginning of the block,
+    // blocks that have deleted rows are always read as a whole.
+    fn page_range(block_meta: &BlockMeta, range: Option<Range<usize>>) -> Option<Range<usize>> {
+        match block_meta.deletion_vector {
+            Some(_) => None,
+            None => range,
+        }
+    }
 }
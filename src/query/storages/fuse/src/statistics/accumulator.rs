@@ -12,6 +12,7 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -50,6 +51,7 @@ impl StatisticsAccumulator {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn add_block(
         &mut self,
         file_size: u64,
@@ -58,6 +60,7 @@ impl StatisticsAccumulator {
         bloom_filter_index_location: Option<Location>,
         bloom_filter_index_size: u64,
         block_compression: meta::Compression,
+        agg_index_locations: BTreeMap<String, Location>,
     ) -> Result<()> {
         self.file_size += file_size;
         self.index_size += bloom_filter_index_size;
@@ -80,34 +83,39 @@ impl StatisticsAccumulator {
             self.perfect_block_count += 1;
         }
 
-        self.blocks_metas.push(Arc::new(BlockMeta::new(
-            row_count,
-            block_size,
-            file_size,
-            col_stats,
-            col_metas,
-            cluster_stats,
-            data_location,
-            bloom_filter_index_location,
-            bloom_filter_index_size,
-            block_compression,
-        )));
+        self.blocks_metas.push(Arc::new(
+            BlockMeta::new(
+                row_count,
+                block_size,
+                file_size,
+                col_stats,
+                col_metas,
+                cluster_stats,
+                data_location,
+                bloom_filter_index_location,
+                bloom_filter_index_size,
+                block_compression,
+            )
+            .with_agg_index_locations(agg_index_locations),
+        ));
 
         Ok(())
     }
 
     pub fn add_with_block_meta(&mut self, block_meta: BlockMeta) {
-        self.summary_row_count += block_meta.row_count;
+        self.summary_row_count += block_meta.live_row_count();
         self.summary_block_count += 1;
         self.in_memory_size += block_meta.block_size;
         self.file_size += block_meta.file_size;
         self.index_size += block_meta.bloom_filter_index_size;
         self.blocks_statistics.push(block_meta.col_stats.clone());
 
-        if self.thresholds.check_large_enough(
-            block_meta.row_count as usize,
-            block_meta.block_size as usize,
-        ) {
+        if block_meta.deletion_vector.is_none()
+            && self.thresholds.check_large_enough(
+                block_meta.row_count as usize,
+                block_meta.block_size as usize,
+            )
+        {
             self.perfect_block_count += 1;
         }
 
@@ -0,0 +1,96 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use common_expression::Scalar;
+use storages_common_table_meta::meta::ColumnHistogram;
+use storages_common_table_meta::meta::HistogramBucket;
+
+pub const DEFAULT_HISTOGRAM_BUCKETS: usize = 100;
+pub const DEFAULT_MOST_COMMON_VALUES: usize = 20;
+
+/// Build an equi-depth histogram and the most common values of a column from sampled values.
+///
+/// `values` are the non-null sampled values, every sampled value stands for `scale` rows of
+/// the table. `ndv` is the estimated number of distinct values of the whole column, which is
+/// used to scale the distinct count of the buckets since it does not grow linearly with rows.
+pub fn build_column_histogram(
+    mut values: Vec<Scalar>,
+    scale: f64,
+    ndv: Option<u64>,
+    num_buckets: usize,
+    num_most_common_values: usize,
+) -> Option<ColumnHistogram> {
+    if values.is_empty() || num_buckets < 2 {
+        return None;
+    }
+    values.sort();
+
+    // Run-length encode the sorted values.
+    let mut runs: Vec<(Scalar, usize)> = Vec::new();
+    for value in values.into_iter() {
+        match runs.last_mut() {
+            Some((last, count)) if *last == value => *count += 1,
+            _ => runs.push((value, 1)),
+        }
+    }
+
+    let num_values: usize = runs.iter().map(|(_, count)| *count).sum();
+    let sample_ndv = runs.len() as f64;
+    let ndv_scale = ndv.map_or(1.0, |ndv| (ndv as f64 / sample_ndv).max(1.0));
+
+    // A value is common if it appears more than twice as often as the average value.
+    let avg_count = num_values as f64 / sample_ndv;
+    let mut most_common_values: Vec<(Scalar, usize)> = runs
+        .iter()
+        .filter(|(_, count)| *count > 1 && *count as f64 > avg_count * 2.0)
+        .cloned()
+        .collect();
+    most_common_values.sort_by(|a, b| b.1.cmp(&a.1));
+    most_common_values.truncate(num_most_common_values);
+
+    // The first bucket is a dummy bucket which only records the min value,
+    // the following buckets split the remaining values at value boundaries.
+    let mut buckets = Vec::with_capacity(num_buckets);
+    let (min, min_count) = &runs[0];
+    buckets.push(HistogramBucket {
+        upper_bound: min.clone(),
+        num_values: *min_count as f64 * scale,
+        num_distinct: 1.0,
+    });
+
+    let depth = (num_values - min_count) as f64 / (num_buckets - 1) as f64;
+    let mut bucket_values = 0;
+    let mut bucket_distinct = 0;
+    for (idx, (value, count)) in runs.iter().enumerate().skip(1) {
+        bucket_values += count;
+        bucket_distinct += 1;
+        if bucket_values as f64 >= depth || idx == runs.len() - 1 {
+            buckets.push(HistogramBucket {
+                upper_bound: value.clone(),
+                num_values: bucket_values as f64 * scale,
+                num_distinct: bucket_distinct as f64 * ndv_scale,
+            });
+            bucket_values = 0;
+            bucket_distinct = 0;
+        }
+    }
+
+    Some(ColumnHistogram {
+        buckets,
+        most_common_values: most_common_values
+            .into_iter()
+            .map(|(value, count)| (value, count as f64 * scale))
+            .collect(),
+    })
+}
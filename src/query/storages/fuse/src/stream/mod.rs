@@ -0,0 +1,39 @@
+//  Copyright 2023 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! Change streams of fuse tables.
+//!
+//! A stream remembers a snapshot of a fuse table as its offset, reading the stream returns the
+//! rows inserted and deleted since the offset. The offset advances to the snapshot read when the
+//! stream is consumed by a DML statement, atomically with the table written by the statement.
+
+mod stream_part;
+mod stream_source;
+mod stream_table;
+
+pub use stream_part::ChangeAction;
+pub use stream_part::ChangeRows;
+pub use stream_part::StreamPartInfo;
+pub use stream_table::StreamTable;
+
+pub const STREAM_ENGINE: &str = "STREAM";
+
+pub const OPT_KEY_TABLE_DATABASE: &str = "table_database";
+pub const OPT_KEY_TABLE_NAME: &str = "table_name";
+pub const OPT_KEY_TABLE_ID: &str = "table_id";
+/// Location of the snapshot of the table the stream is at, absent if the table was empty.
+pub const OPT_KEY_OFFSET_SNAPSHOT_LOCATION: &str = "offset_snapshot_location";
+
+/// The column of a stream telling whether the row is inserted or deleted.
+pub const CHANGE_ACTION_COLUMN: &str = "change$action";
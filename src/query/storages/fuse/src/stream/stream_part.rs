@@ -0,0 +1,102 @@
+//  Copyright 2023 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::any::Any;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::Arc;
+
+use common_catalog::plan::PartInfo;
+use common_catalog::plan::PartInfoPtr;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::DeletionVector;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChangeAction {
+    Insert,
+    Delete,
+}
+
+impl ChangeAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChangeAction::Insert => "INSERT",
+            ChangeAction::Delete => "DELETE",
+        }
+    }
+}
+
+/// The rows of a block a stream part reads.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub enum ChangeRows {
+    /// The rows not marked in the deletion vector of the block.
+    Visible,
+    /// The rows marked in the deletion vector of the block but not in the given one, which
+    /// is the deletion vector the block had at the offset: the rows deleted in place since.
+    DeletedSince(Option<DeletionVector>),
+}
+
+/// A block inserted or deleted since the offset of a stream.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+pub struct StreamPartInfo {
+    pub action: ChangeAction,
+    pub block_meta: BlockMeta,
+    pub rows: ChangeRows,
+}
+
+#[typetag::serde(name = "stream")]
+impl PartInfo for StreamPartInfo {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, info: &Box<dyn PartInfo>) -> bool {
+        match info.as_any().downcast_ref::<StreamPartInfo>() {
+            None => false,
+            Some(other) => self == other,
+        }
+    }
+
+    fn hash(&self) -> u64 {
+        let mut s = DefaultHasher::new();
+        self.action.as_str().hash(&mut s);
+        self.block_meta.location.0.hash(&mut s);
+        if let Some(v) = &self.block_meta.deletion_vector {
+            v.location.0.hash(&mut s);
+        }
+        s.finish()
+    }
+}
+
+impl StreamPartInfo {
+    pub fn create(action: ChangeAction, block_meta: BlockMeta, rows: ChangeRows) -> PartInfoPtr {
+        Arc::new(Box::new(StreamPartInfo {
+            action,
+            block_meta,
+            rows,
+        }))
+    }
+
+    pub fn from_part(info: &PartInfoPtr) -> Result<&StreamPartInfo> {
+        match info.as_any().downcast_ref::<StreamPartInfo>() {
+            Some(part_ref) => Ok(part_ref),
+            None => Err(ErrorCode::Internal(
+                "Cannot downcast from PartInfo to StreamPartInfo.",
+            )),
+        }
+    }
+}
@@ -0,0 +1,130 @@
+//  Copyright 2023 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::ops::Not;
+use std::sync::Arc;
+
+use common_catalog::plan::Projection;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::Scalar;
+use common_expression::Value;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sources::AsyncSource;
+use common_pipeline_sources::AsyncSourcer;
+use opendal::Operator;
+
+use super::ChangeRows;
+use super::StreamPartInfo;
+use super::StreamTable;
+use crate::io::filter_deleted_rows;
+use crate::io::read_deletion_vector;
+use crate::io::BlockReader;
+use crate::io::ReadSettings;
+use crate::FuseStorageFormat;
+use crate::FuseTable;
+
+/// Reads the blocks changed since the offset of a stream, tagging their rows with the
+/// `change$action` column.
+pub struct StreamSource {
+    ctx: Arc<dyn TableContext>,
+    stream: StreamTable,
+    /// the reader of the table the stream is on, created once the first block is read.
+    reader: Option<(Arc<BlockReader>, FuseStorageFormat, Operator)>,
+}
+
+impl StreamSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        stream: StreamTable,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, StreamSource {
+            ctx,
+            stream,
+            reader: None,
+        })
+    }
+
+    async fn init_reader(&mut self) -> Result<()> {
+        let table = self.stream.source_table(self.ctx.clone()).await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+
+        // the columns of the stream are the columns of the table, followed by `change$action`.
+        let schema = self.stream.schema();
+        let projection = Projection::Columns((0..schema.fields().len() - 1).collect());
+        let block_reader = BlockReader::create(
+            fuse_table.get_operator(),
+            schema,
+            projection,
+            self.ctx.clone(),
+        )?;
+        self.reader = Some((
+            block_reader,
+            fuse_table.storage_format,
+            fuse_table.get_operator(),
+        ));
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for StreamSource {
+    const NAME: &'static str = "StreamSource";
+
+    #[async_trait::unboxed_simple]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        let part = match self.ctx.get_partition() {
+            Some(part) => part,
+            None => return Ok(None),
+        };
+        if self.reader.is_none() {
+            self.init_reader().await?;
+        }
+        let (block_reader, storage_format, operator) = self.reader.as_ref().unwrap();
+
+        let part = StreamPartInfo::from_part(&part)?;
+        let settings = ReadSettings::from_ctx(&self.ctx)?;
+        let block = block_reader
+            .read_by_meta(&settings, &part.block_meta, storage_format)
+            .await?;
+        let deleted =
+            read_deletion_vector(operator, part.block_meta.deletion_vector.as_ref()).await?;
+        let mut block = match &part.rows {
+            ChangeRows::Visible => filter_deleted_rows(block, deleted.as_ref())?,
+            ChangeRows::DeletedSince(prev) => {
+                // the rows deleted in place since the offset are the rows deleted now but
+                // not at the offset.
+                let prev = read_deletion_vector(operator, prev.as_ref()).await?;
+                match (deleted, prev) {
+                    (None, _) => block.slice(0..0),
+                    (Some(deleted), None) => block.filter_with_bitmap(&deleted)?,
+                    (Some(deleted), Some(prev)) => {
+                        block.filter_with_bitmap(&(&deleted & &prev.not()))?
+                    }
+                }
+            }
+        };
+        block.add_column(BlockEntry {
+            data_type: DataType::String,
+            value: Value::Scalar(Scalar::String(part.action.as_str().as_bytes().to_vec())),
+        });
+        Ok(Some(block))
+    }
+}
@@ -0,0 +1,220 @@
+//  Copyright 2023 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_catalog::catalog::StorageDescription;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PartitionsShuffleKind;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use common_pipeline_core::Pipeline;
+
+use super::stream_source::StreamSource;
+use super::ChangeAction;
+use super::ChangeRows;
+use super::StreamPartInfo;
+use super::OPT_KEY_OFFSET_SNAPSHOT_LOCATION;
+use super::OPT_KEY_TABLE_DATABASE;
+use super::OPT_KEY_TABLE_ID;
+use super::OPT_KEY_TABLE_NAME;
+use super::STREAM_ENGINE;
+use crate::FuseTable;
+
+pub struct StreamTable {
+    table_info: TableInfo,
+}
+
+impl StreamTable {
+    pub fn try_create(table_info: TableInfo) -> Result<Box<dyn Table>> {
+        for key in [OPT_KEY_TABLE_DATABASE, OPT_KEY_TABLE_NAME, OPT_KEY_TABLE_ID] {
+            if !table_info.options().contains_key(key) {
+                return Err(ErrorCode::Internal(format!(
+                    "Need `{}` when creating StreamTable",
+                    key
+                )));
+            }
+        }
+        Ok(Box::new(StreamTable { table_info }))
+    }
+
+    pub fn description() -> StorageDescription {
+        StorageDescription {
+            engine_name: STREAM_ENGINE.to_string(),
+            comment: "STREAM STORAGE (CHANGES OF A FUSE TABLE)".to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn try_from_table(tbl: &dyn Table) -> Result<&StreamTable> {
+        tbl.as_any().downcast_ref::<StreamTable>().ok_or_else(|| {
+            ErrorCode::Internal(format!(
+                "expects table of engine STREAM, but got {}",
+                tbl.engine()
+            ))
+        })
+    }
+
+    pub fn table_database(&self) -> &str {
+        &self.table_info.options()[OPT_KEY_TABLE_DATABASE]
+    }
+
+    pub fn table_name(&self) -> &str {
+        &self.table_info.options()[OPT_KEY_TABLE_NAME]
+    }
+
+    /// Location of the snapshot the stream is at, `None` if the table was empty.
+    pub fn offset(&self) -> Option<&str> {
+        self.table_info
+            .options()
+            .get(OPT_KEY_OFFSET_SNAPSHOT_LOCATION)
+            .map(|location| location.as_str())
+    }
+
+    /// The table the stream is on, which must not have been dropped or re-created.
+    pub async fn source_table(&self, ctx: Arc<dyn TableContext>) -> Result<Arc<dyn Table>> {
+        let table = ctx
+            .get_table(
+                self.table_info.catalog(),
+                self.table_database(),
+                self.table_name(),
+            )
+            .await?;
+        let table_id = self.table_info.options()[OPT_KEY_TABLE_ID].parse::<u64>()?;
+        if table.get_id() != table_id {
+            return Err(ErrorCode::UnknownTable(format!(
+                "Table {}.{} of stream {} has been dropped or re-created",
+                self.table_database(),
+                self.table_name(),
+                self.table_info.name
+            )));
+        }
+        Ok(table)
+    }
+
+    /// Remember the new offset of the stream in the context, it is committed when a DML
+    /// statement consuming the stream commits.
+    fn record_offset(&self, ctx: &dyn TableContext, snapshot_location: Option<String>) {
+        if snapshot_location.as_deref() == self.offset() {
+            return;
+        }
+
+        let mut new_table_meta = self.table_info.meta.clone();
+        match snapshot_location {
+            Some(location) => {
+                new_table_meta
+                    .options
+                    .insert(OPT_KEY_OFFSET_SNAPSHOT_LOCATION.to_owned(), location);
+            }
+            None => {
+                new_table_meta
+                    .options
+                    .remove(OPT_KEY_OFFSET_SNAPSHOT_LOCATION);
+            }
+        }
+        ctx.add_stream_offset(self.table_info.catalog(), UpdateTableMetaReq {
+            table_id: self.table_info.ident.table_id,
+            seq: MatchSeq::Exact(self.table_info.ident.seq),
+            new_table_meta,
+        });
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for StreamTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let table = self.source_table(ctx.clone()).await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+
+        let (inserted, deleted) = match fuse_table.changes_since(ctx.clone(), self.offset()).await {
+            Ok(changes) => changes,
+            Err(e) if e.code() == ErrorCode::STORAGE_NOT_FOUND => {
+                return Err(ErrorCode::StorageNotFound(format!(
+                    "Stream {} is stale, the snapshot at its offset has been purged: {}",
+                    self.table_info.name, e
+                )));
+            }
+            Err(e) => return Err(e),
+        };
+        self.record_offset(ctx.as_ref(), fuse_table.snapshot_loc().await?);
+
+        let mut read_rows = 0;
+        let mut read_bytes = 0;
+        let parts = inserted
+            .into_iter()
+            .map(|block_meta| (ChangeAction::Insert, block_meta, ChangeRows::Visible))
+            .chain(
+                deleted
+                    .into_iter()
+                    .map(|(block_meta, rows)| (ChangeAction::Delete, block_meta, rows)),
+            )
+            .map(|(action, block_meta, rows)| {
+                read_rows += match &rows {
+                    ChangeRows::Visible => block_meta.live_row_count(),
+                    ChangeRows::DeletedSince(prev) => {
+                        let prev_deleted = prev.as_ref().map_or(0, |v| v.deleted_rows);
+                        block_meta.row_count - block_meta.live_row_count() - prev_deleted
+                    }
+                } as usize;
+                read_bytes += block_meta.block_size as usize;
+                StreamPartInfo::create(action, block_meta.as_ref().clone(), rows)
+            })
+            .collect::<Vec<_>>();
+
+        let statistics = PartStatistics::new_exact(read_rows, read_bytes, parts.len(), parts.len());
+        Ok((
+            statistics,
+            Partitions::create_nolazy(PartitionsShuffleKind::Mod, parts),
+        ))
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        let parallelism = plan.parts.len().min(max_threads).max(1);
+        pipeline.add_source(
+            |output| {
+                StreamSource::create(ctx.clone(), output, StreamTable {
+                    table_info: self.table_info.clone(),
+                })
+            },
+            parallelism,
+        )
+    }
+}
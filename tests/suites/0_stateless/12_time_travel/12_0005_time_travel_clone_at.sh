@@ -0,0 +1,36 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+
+## Create table t12_0005
+echo "create table t12_0005(c int)" | $MYSQL_CLIENT_CONNECT
+echo "two insertions"
+echo "insert into t12_0005 values(1),(2)" | $MYSQL_CLIENT_CONNECT
+
+echo "insert into t12_0005 values(3)" | $MYSQL_CLIENT_CONNECT
+
+## Get the previous snapshot id of the latest snapshot
+SNAPSHOT_ID=$(echo "select previous_snapshot_id from fuse_snapshot('default','t12_0005') where row_count=3 " | $MYSQL_CLIENT_CONNECT)
+
+echo "clone at the snapshot of first insertion, which should contain 2 rows"
+echo "create table t12_0005_snapshot clone t12_0005 at (snapshot => '$SNAPSHOT_ID')" | $MYSQL_CLIENT_CONNECT
+echo "select count(*) from t12_0005_snapshot" | $MYSQL_CLIENT_CONNECT
+
+# Get a time point at/after the first insertion.
+TIMEPOINT=$(echo "select timestamp from fuse_snapshot('default', 't12_0005') where row_count=2" | $MYSQL_CLIENT_CONNECT)
+
+echo "clone at the time point of first insertion, which should contain 2 rows"
+echo "create table t12_0005_timestamp clone t12_0005 at (TIMESTAMP => '$TIMEPOINT'::TIMESTAMP)" | $MYSQL_CLIENT_CONNECT
+echo "select count(*) from t12_0005_timestamp" | $MYSQL_CLIENT_CONNECT
+
+echo "purge the source table, the clones should not be affected"
+echo "optimize table t12_0005 all" | $MYSQL_CLIENT_CONNECT
+echo "select count(*) from t12_0005_snapshot" | $MYSQL_CLIENT_CONNECT
+echo "select count(*) from t12_0005" | $MYSQL_CLIENT_CONNECT
+
+## Drop table.
+echo "drop table t12_0005" | $MYSQL_CLIENT_CONNECT
+echo "drop table t12_0005_snapshot" | $MYSQL_CLIENT_CONNECT
+echo "drop table t12_0005_timestamp" | $MYSQL_CLIENT_CONNECT